@@ -61,6 +61,79 @@ where
     sample()
 }
 
+mod bench_matmul {
+    use super::*;
+
+    pub fn faer_matmul<E: ComplexField>(bencher: Bencher, n: usize, par: faer::Parallelism)
+    where
+        Standard: Distribution<E>,
+    {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let A = random_mat::<E>(rng, n, n);
+        let B = random_mat::<E>(rng, n, n);
+        let mut C = Mat::<E>::zeros(n, n);
+        bencher.bench(|| {
+            faer::linalg::matmul::matmul(
+                C.as_mut(),
+                A.as_ref(),
+                B.as_ref(),
+                None,
+                E::faer_one(),
+                par,
+            );
+        })
+    }
+
+    pub fn faer_matvec<E: ComplexField>(bencher: Bencher, PlotArg(n): PlotArg)
+    where
+        Standard: Distribution<E>,
+    {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let A = random_mat::<E>(rng, n, n);
+        let b = random_mat::<E>(rng, n, 1);
+        let mut c = Mat::<E>::zeros(n, 1);
+        bencher.bench(|| {
+            faer::linalg::matmul::matmul(
+                c.as_mut(),
+                A.as_ref(),
+                b.as_ref(),
+                None,
+                E::faer_one(),
+                faer::Parallelism::None,
+            );
+        })
+    }
+
+    pub fn faer_seq_matmul<E: ComplexField>(bencher: Bencher, PlotArg(n): PlotArg)
+    where
+        Standard: Distribution<E>,
+    {
+        faer_matmul::<E>(bencher, n, faer::Parallelism::None)
+    }
+
+    pub fn faer_par_matmul<E: ComplexField>(bencher: Bencher, PlotArg(n): PlotArg)
+    where
+        Standard: Distribution<E>,
+    {
+        faer_matmul::<E>(bencher, n, faer::Parallelism::Rayon(0))
+    }
+
+    pub fn nalgebra_matmul<E: TypeDispatch>(bencher: Bencher, PlotArg(n): PlotArg)
+    where
+        Standard: Distribution<E::Type>,
+    {
+        if n > NALGEBRA_LIMIT {
+            return bencher.skip();
+        }
+        let rng = &mut StdRng::seed_from_u64(0);
+        let A = random_mat::<E::Type>(rng, n, n);
+        let B = random_mat::<E::Type>(rng, n, n);
+        let A = nalgebra::DMatrix::from_fn(n, n, |i, j| A.read(i, j));
+        let B = nalgebra::DMatrix::from_fn(n, n, |i, j| B.read(i, j));
+        bencher.bench(|| &A * &B)
+    }
+}
+
 mod bench_cholesky {
     use super::*;
 
@@ -712,6 +785,120 @@ mod bench_selfadjoint_evd {
     }
 }
 
+mod bench_householder {
+    use super::*;
+    use faer::linalg::householder::*;
+
+    fn householder_faer<E: ComplexField>(
+        bencher: Bencher,
+        n: usize,
+        par: faer::Parallelism,
+        right: bool,
+        unblocked: bool,
+    ) where
+        Standard: Distribution<E>,
+    {
+        let blocksize = if unblocked {
+            1
+        } else {
+            faer::linalg::qr::no_pivoting::compute::recommended_blocksize::<E>(n, n)
+        };
+
+        let rng = &mut StdRng::seed_from_u64(0);
+        let mut qr = random_mat::<E>(rng, n, n);
+        let mut householder = Mat::<E>::zeros(blocksize, n);
+        faer::linalg::qr::no_pivoting::compute::qr_in_place(
+            qr.as_mut(),
+            householder.as_mut(),
+            par,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                faer::linalg::qr::no_pivoting::compute::qr_in_place_req::<E>(
+                    n,
+                    n,
+                    blocksize,
+                    par,
+                    Default::default(),
+                )
+                .unwrap(),
+            )),
+            Default::default(),
+        );
+
+        let A = random_mat::<E>(rng, n, n);
+        let mut dst = A.clone();
+        if right {
+            let mut mem = GlobalPodBuffer::new(
+                apply_block_householder_sequence_on_the_right_in_place_req::<E>(n, blocksize, n)
+                    .unwrap(),
+            );
+            bencher.bench(|| {
+                dst.copy_from(&A);
+                apply_block_householder_sequence_on_the_right_in_place_with_conj(
+                    qr.as_ref(),
+                    householder.as_ref(),
+                    faer::Conj::No,
+                    dst.as_mut(),
+                    par,
+                    PodStack::new(&mut mem),
+                );
+            })
+        } else {
+            let mut mem = GlobalPodBuffer::new(
+                apply_block_householder_sequence_on_the_left_in_place_req::<E>(n, blocksize, n)
+                    .unwrap(),
+            );
+            bencher.bench(|| {
+                dst.copy_from(&A);
+                apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                    qr.as_ref(),
+                    householder.as_ref(),
+                    faer::Conj::No,
+                    dst.as_mut(),
+                    par,
+                    PodStack::new(&mut mem),
+                );
+            })
+        }
+    }
+
+    pub fn faer_seq_householder_left<E: ComplexField>(bencher: Bencher, PlotArg(n): PlotArg)
+    where
+        Standard: Distribution<E>,
+    {
+        householder_faer::<E>(bencher, n, faer::Parallelism::None, false, false)
+    }
+
+    pub fn faer_seq_householder_right<E: ComplexField>(bencher: Bencher, PlotArg(n): PlotArg)
+    where
+        Standard: Distribution<E>,
+    {
+        householder_faer::<E>(bencher, n, faer::Parallelism::None, true, false)
+    }
+
+    pub fn faer_par_householder_left<E: ComplexField>(bencher: Bencher, PlotArg(n): PlotArg)
+    where
+        Standard: Distribution<E>,
+    {
+        householder_faer::<E>(bencher, n, faer::Parallelism::Rayon(0), false, false)
+    }
+
+    pub fn faer_par_householder_right<E: ComplexField>(bencher: Bencher, PlotArg(n): PlotArg)
+    where
+        Standard: Distribution<E>,
+    {
+        householder_faer::<E>(bencher, n, faer::Parallelism::Rayon(0), true, false)
+    }
+
+    pub fn faer_seq_householder_unblocked_left<E: ComplexField>(
+        bencher: Bencher,
+        PlotArg(n): PlotArg,
+    ) where
+        Standard: Distribution<E>,
+    {
+        householder_faer::<E>(bencher, n, faer::Parallelism::None, false, true)
+    }
+}
+
 fn register_for<E: TypeDispatch>(bench: &mut Bench)
 where
     Standard: Distribution<E> + Distribution<E::Type>,
@@ -721,6 +908,16 @@ where
     ]
     .map(PlotArg);
 
+    bench.register_many(
+        list![
+            bench_matmul::faer_seq_matmul::<E>,
+            bench_matmul::faer_par_matmul::<E>,
+            bench_matmul::faer_matvec::<E>,
+            bench_matmul::nalgebra_matmul::<E>,
+        ],
+        args,
+    );
+
     bench.register_many(
         list![
             bench_cholesky::faer_seq_cholesky::<E>,
@@ -731,6 +928,17 @@ where
         args,
     );
 
+    bench.register_many(
+        list![
+            bench_householder::faer_seq_householder_left::<E>,
+            bench_householder::faer_seq_householder_right::<E>,
+            bench_householder::faer_par_householder_left::<E>,
+            bench_householder::faer_par_householder_right::<E>,
+            bench_householder::faer_seq_householder_unblocked_left::<E>,
+        ],
+        args,
+    );
+
     bench.register_many(
         list![
             bench_qr::faer_seq_qr::<E>,
@@ -3348,6 +3348,115 @@ impl ComplexField for Symbolic {
     }
 }
 
+/// Storage-only entity implementations for the half precision floating point types. They can be
+/// stored in matrices, but the arithmetic is performed after conversion to `f32`.
+#[cfg(feature = "half")]
+mod half_impl {
+    use super::*;
+
+    macro_rules! impl_half_entity {
+        ($ty: ty) => {
+            unsafe impl Entity for $ty {
+                type Unit = $ty;
+                type Index = usize;
+                type SimdUnit<S: pulp::Simd> = $ty;
+                type SimdMask<S: pulp::Simd> = bool;
+                type SimdIndex<S: pulp::Simd> = usize;
+                type Group = IdentityGroup;
+                type Iter<I: Iterator> = I;
+                const N_COMPONENTS: usize = 1;
+                const UNIT: GroupCopyFor<Self, ()> = ();
+
+                type PrefixUnit<'a, S: Simd> = &'a [Self];
+                type SuffixUnit<'a, S: Simd> = &'a [Self];
+                type PrefixMutUnit<'a, S: Simd> = &'a mut [Self];
+                type SuffixMutUnit<'a, S: Simd> = &'a mut [Self];
+
+                #[inline(always)]
+                fn faer_first<T>(group: GroupFor<Self, T>) -> T {
+                    group
+                }
+
+                #[inline(always)]
+                fn faer_from_units(group: GroupFor<Self, Self::Unit>) -> Self {
+                    group
+                }
+
+                #[inline(always)]
+                fn faer_into_units(self) -> GroupFor<Self, Self::Unit> {
+                    self
+                }
+
+                #[inline(always)]
+                fn faer_as_ref<T>(group: &GroupFor<Self, T>) -> GroupFor<Self, &T> {
+                    group
+                }
+
+                #[inline(always)]
+                fn faer_as_mut<T>(group: &mut GroupFor<Self, T>) -> GroupFor<Self, &mut T> {
+                    group
+                }
+
+                #[inline(always)]
+                fn faer_as_ptr<T>(group: *mut GroupFor<Self, T>) -> GroupFor<Self, *mut T> {
+                    group
+                }
+
+                #[inline(always)]
+                fn faer_map_impl<T, U>(
+                    group: GroupFor<Self, T>,
+                    f: &mut impl FnMut(T) -> U,
+                ) -> GroupFor<Self, U> {
+                    (*f)(group)
+                }
+
+                #[inline(always)]
+                fn faer_zip<T, U>(
+                    first: GroupFor<Self, T>,
+                    second: GroupFor<Self, U>,
+                ) -> GroupFor<Self, (T, U)> {
+                    (first, second)
+                }
+
+                #[inline(always)]
+                fn faer_unzip<T, U>(
+                    zipped: GroupFor<Self, (T, U)>,
+                ) -> (GroupFor<Self, T>, GroupFor<Self, U>) {
+                    zipped
+                }
+
+                #[inline(always)]
+                fn faer_map_with_context<Ctx, T, U>(
+                    ctx: Ctx,
+                    group: GroupFor<Self, T>,
+                    f: &mut impl FnMut(Ctx, T) -> (Ctx, U),
+                ) -> (Ctx, GroupFor<Self, U>) {
+                    (*f)(ctx, group)
+                }
+
+                #[inline(always)]
+                fn faer_into_iter<I: IntoIterator>(
+                    iter: GroupFor<Self, I>,
+                ) -> Self::Iter<I::IntoIter> {
+                    iter.into_iter()
+                }
+            }
+
+            unsafe impl Conjugate for $ty {
+                type Conj = $ty;
+                type Canonical = $ty;
+                #[inline(always)]
+                fn canonicalize(self) -> Self::Canonical {
+                    self
+                }
+            }
+        };
+    }
+
+    impl_half_entity!(half::f16);
+    impl_half_entity!(half::bf16);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,517 @@
+use crate::{
+    assert,
+    col::{Col, ColRef},
+    get_global_parallelism,
+    linalg::{
+        matmul::matmul_with_conj,
+        solvers::{PartialPivLu, SolverCore, SpSolverCore},
+    },
+    linop::{BiLinOp, LinOp},
+    mat::{Mat, MatMut, MatRef},
+    sparse::{FaerError, SparseColMat, SymbolicSparseColMat},
+    utils::vec::VecGroup,
+    ComplexField, Conj, Conjugate, Entity, Index, Parallelism, SignedIndex,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+/// Block diagonal matrix, made up of dense blocks placed along the diagonal. Entries outside of
+/// the blocks are implicitly zero.
+///
+/// The blocks may be rectangular, in which case the matrix itself is rectangular, but solving and
+/// computing the determinant require every block to be square.
+#[derive(Clone, Debug)]
+pub struct BlockDiag<E: Entity> {
+    blocks: alloc::vec::Vec<Mat<E>>,
+    // `row_offsets[k]..row_offsets[k + 1]` is the range of rows spanned by the `k`-th block, and
+    // similarly for the columns
+    row_offsets: alloc::vec::Vec<usize>,
+    col_offsets: alloc::vec::Vec<usize>,
+}
+
+/// LU decomposition of a block diagonal matrix, made up of the decompositions with partial
+/// pivoting of each of its blocks.
+pub struct BlockDiagLu<E: Entity> {
+    blocks: alloc::vec::Vec<PartialPivLu<E>>,
+    offsets: alloc::vec::Vec<usize>,
+}
+
+fn offsets<T>(blocks: &[T], dim: impl Fn(&T) -> usize) -> alloc::vec::Vec<usize> {
+    let mut offsets = alloc::vec::Vec::with_capacity(blocks.len() + 1);
+    offsets.push(0usize);
+    for block in blocks {
+        offsets.push(offsets.last().unwrap() + dim(block));
+    }
+    offsets
+}
+
+impl<E: Entity> BlockDiag<E> {
+    /// Creates a block diagonal matrix from its diagonal blocks.
+    pub fn new(blocks: alloc::vec::Vec<Mat<E>>) -> Self {
+        let row_offsets = offsets(&blocks, |block| block.nrows());
+        let col_offsets = offsets(&blocks, |block| block.ncols());
+        Self {
+            blocks,
+            row_offsets,
+            col_offsets,
+        }
+    }
+
+    /// Returns the number of rows of the matrix.
+    #[inline(always)]
+    pub fn nrows(&self) -> usize {
+        *self.row_offsets.last().unwrap()
+    }
+
+    /// Returns the number of columns of the matrix.
+    #[inline(always)]
+    pub fn ncols(&self) -> usize {
+        *self.col_offsets.last().unwrap()
+    }
+
+    /// Returns the number of diagonal blocks.
+    #[inline(always)]
+    pub fn nblocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns a view over the `k`-th diagonal block.
+    ///
+    /// # Panics
+    /// Panics if `k >= self.nblocks()`.
+    #[inline]
+    #[track_caller]
+    pub fn block(&self, k: usize) -> MatRef<'_, E> {
+        self.blocks[k].as_ref()
+    }
+
+    /// Returns a mutable view over the `k`-th diagonal block.
+    ///
+    /// # Panics
+    /// Panics if `k >= self.nblocks()`.
+    #[inline]
+    #[track_caller]
+    pub fn block_mut(&mut self, k: usize) -> MatMut<'_, E> {
+        self.blocks[k].as_mut()
+    }
+
+    /// Returns the row and column offsets of the `k`-th diagonal block.
+    ///
+    /// # Panics
+    /// Panics if `k >= self.nblocks()`.
+    #[inline]
+    #[track_caller]
+    pub fn block_offset(&self, k: usize) -> (usize, usize) {
+        assert!(k < self.nblocks());
+        (self.row_offsets[k], self.col_offsets[k])
+    }
+
+    /// Returns the diagonal blocks.
+    #[inline(always)]
+    pub fn into_blocks(self) -> alloc::vec::Vec<Mat<E>> {
+        self.blocks
+    }
+}
+
+impl<E: ComplexField> BlockDiag<E> {
+    /// Returns the matrix as a dense matrix.
+    pub fn to_mat(&self) -> Mat<E> {
+        let mut out = Mat::<E>::zeros(self.nrows(), self.ncols());
+        for (k, block) in self.blocks.iter().enumerate() {
+            let (i, j) = self.block_offset(k);
+            out.as_mut()
+                .submatrix_mut(i, j, block.nrows(), block.ncols())
+                .copy_from(block);
+        }
+        out
+    }
+
+    /// Returns the matrix as a sparse matrix, where every entry of the diagonal blocks is
+    /// explicitly stored.
+    pub fn to_sparse<I: Index>(&self) -> Result<SparseColMat<I, E>, FaerError> {
+        let nnz = self
+            .blocks
+            .iter()
+            .try_fold(0usize, |nnz, block| {
+                nnz.checked_add(block.nrows().checked_mul(block.ncols())?)
+            })
+            .ok_or(FaerError::IndexOverflow)?;
+        let max = I::Signed::MAX.zx();
+        if nnz > max || self.nrows() > max || self.ncols() > max {
+            return Err(FaerError::IndexOverflow);
+        }
+
+        let mut col_ptrs = alloc::vec::Vec::new();
+        let mut row_indices = alloc::vec::Vec::new();
+        let mut values = VecGroup::<E>::new();
+        col_ptrs
+            .try_reserve_exact(self.ncols() + 1)
+            .map_err(|_| FaerError::OutOfMemory)?;
+        row_indices
+            .try_reserve_exact(nnz)
+            .map_err(|_| FaerError::OutOfMemory)?;
+        values
+            .try_reserve_exact(nnz)
+            .map_err(|_| FaerError::OutOfMemory)?;
+
+        col_ptrs.push(I::truncate(0));
+        for (k, block) in self.blocks.iter().enumerate() {
+            let (row_offset, _) = self.block_offset(k);
+            for j in 0..block.ncols() {
+                for i in 0..block.nrows() {
+                    row_indices.push(I::truncate(row_offset + i));
+                    values.push(block.read(i, j).faer_into_units());
+                }
+                col_ptrs.push(I::truncate(row_indices.len()));
+            }
+        }
+
+        Ok(SparseColMat {
+            symbolic: SymbolicSparseColMat::new_checked(
+                self.nrows(),
+                self.ncols(),
+                col_ptrs,
+                None,
+                row_indices,
+            ),
+            values,
+        })
+    }
+
+    /// Returns the LU decomposition with partial pivoting of each diagonal block.
+    ///
+    /// # Panics
+    /// Panics if any of the blocks is not square.
+    #[track_caller]
+    pub fn partial_piv_lu(&self) -> BlockDiagLu<E> {
+        BlockDiagLu {
+            blocks: self
+                .blocks
+                .iter()
+                .map(|block| PartialPivLu::new(block.as_ref()))
+                .collect(),
+            offsets: self.row_offsets.clone(),
+        }
+    }
+
+    /// Returns the solution of the equation `self * X = rhs`.
+    ///
+    /// # Panics
+    /// Panics if any of the blocks is not square, or if `rhs.nrows() != self.nrows()`.
+    #[track_caller]
+    pub fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: MatRef<'_, ViewE>) -> Mat<E> {
+        let mut out = rhs.to_owned();
+        self.partial_piv_lu()
+            .solve_in_place_with_conj_impl(out.as_mut(), Conj::No);
+        out
+    }
+
+    /// Returns the determinant of `self`, which is the product of the determinants of the
+    /// diagonal blocks.
+    ///
+    /// # Panics
+    /// Panics if any of the blocks is not square.
+    #[track_caller]
+    pub fn determinant(&self) -> E {
+        let mut det = E::faer_one();
+        for block in &self.blocks {
+            det = det.faer_mul(block.determinant());
+        }
+        det
+    }
+
+    fn apply_impl(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        transpose: bool,
+        conj: Conj,
+        parallelism: Parallelism,
+    ) {
+        let (out_offsets, rhs_offsets) = if transpose {
+            (&self.col_offsets, &self.row_offsets)
+        } else {
+            (&self.row_offsets, &self.col_offsets)
+        };
+        assert!(all(
+            out.nrows() == *out_offsets.last().unwrap(),
+            rhs.nrows() == *rhs_offsets.last().unwrap(),
+            out.ncols() == rhs.ncols(),
+        ));
+
+        let mut out = out;
+        let k = rhs.ncols();
+        for (b, block) in self.blocks.iter().enumerate() {
+            let block = if transpose {
+                block.transpose()
+            } else {
+                block.as_ref()
+            };
+            let out_len = out_offsets[b + 1] - out_offsets[b];
+            let rhs_len = rhs_offsets[b + 1] - rhs_offsets[b];
+            let rhs = rhs.submatrix(rhs_offsets[b], 0, rhs_len, k);
+            let out = out.rb_mut().submatrix_mut(out_offsets[b], 0, out_len, k);
+            matmul_with_conj(
+                out,
+                block,
+                conj,
+                rhs,
+                Conj::No,
+                None,
+                E::faer_one(),
+                parallelism,
+            );
+        }
+    }
+}
+
+impl<E: ComplexField> LinOp<E> for BlockDiag<E> {
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        _ = rhs_ncols;
+        _ = parallelism;
+        Ok(StackReq::empty())
+    }
+
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    #[track_caller]
+    fn apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = stack;
+        self.apply_impl(out, rhs, false, Conj::No, parallelism)
+    }
+
+    #[track_caller]
+    fn conj_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = stack;
+        self.apply_impl(out, rhs, false, Conj::Yes, parallelism)
+    }
+}
+
+impl<E: ComplexField> BiLinOp<E> for BlockDiag<E> {
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.apply_req(rhs_ncols, parallelism)
+    }
+
+    #[track_caller]
+    fn transpose_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = stack;
+        self.apply_impl(out, rhs, true, Conj::No, parallelism)
+    }
+
+    #[track_caller]
+    fn adjoint_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = stack;
+        self.apply_impl(out, rhs, true, Conj::Yes, parallelism)
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<MatRef<'_, E>> for &BlockDiag<E> {
+    type Output = Mat<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: MatRef<'_, E>) -> Self::Output {
+        let mut out = Mat::<E>::zeros(self.nrows(), rhs.ncols());
+        self.apply_impl(out.as_mut(), rhs, false, Conj::No, get_global_parallelism());
+        out
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<ColRef<'_, E>> for &BlockDiag<E> {
+    type Output = Col<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: ColRef<'_, E>) -> Self::Output {
+        let out = self * rhs.as_2d();
+        Col::from_fn(out.nrows(), |i| out.read(i, 0))
+    }
+}
+
+impl<E: Entity> BlockDiagLu<E> {
+    /// Returns the number of diagonal blocks.
+    #[inline(always)]
+    pub fn nblocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns the decomposition of the `k`-th diagonal block.
+    ///
+    /// # Panics
+    /// Panics if `k >= self.nblocks()`.
+    #[inline]
+    #[track_caller]
+    pub fn block(&self, k: usize) -> &PartialPivLu<E> {
+        &self.blocks[k]
+    }
+}
+
+impl<E: ComplexField> BlockDiagLu<E> {
+    fn for_each_block(&self, rhs: MatMut<'_, E>, f: impl Fn(&PartialPivLu<E>, MatMut<'_, E>)) {
+        assert!(rhs.nrows() == *self.offsets.last().unwrap());
+        let mut rhs = rhs;
+        let k = rhs.ncols();
+        for (b, lu) in self.blocks.iter().enumerate() {
+            let len = self.offsets[b + 1] - self.offsets[b];
+            f(lu, rhs.rb_mut().submatrix_mut(self.offsets[b], 0, len, k));
+        }
+    }
+
+    fn block_diag(&self, f: impl Fn(&PartialPivLu<E>) -> Mat<E>) -> Mat<E> {
+        let n = *self.offsets.last().unwrap();
+        let mut out = Mat::<E>::zeros(n, n);
+        for (b, lu) in self.blocks.iter().enumerate() {
+            let len = self.offsets[b + 1] - self.offsets[b];
+            out.as_mut()
+                .submatrix_mut(self.offsets[b], self.offsets[b], len, len)
+                .copy_from(f(lu));
+        }
+        out
+    }
+}
+
+impl<E: ComplexField> SpSolverCore<E> for BlockDiagLu<E> {
+    #[track_caller]
+    fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        self.for_each_block(rhs, |lu, rhs| lu.solve_in_place_with_conj_impl(rhs, conj))
+    }
+
+    #[track_caller]
+    fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        self.for_each_block(rhs, |lu, rhs| {
+            lu.solve_transpose_in_place_with_conj_impl(rhs, conj)
+        })
+    }
+
+    fn nrows(&self) -> usize {
+        *self.offsets.last().unwrap()
+    }
+
+    fn ncols(&self) -> usize {
+        *self.offsets.last().unwrap()
+    }
+}
+
+impl<E: ComplexField> SolverCore<E> for BlockDiagLu<E> {
+    fn reconstruct(&self) -> Mat<E> {
+        self.block_diag(|lu| lu.reconstruct())
+    }
+
+    fn inverse(&self) -> Mat<E> {
+        self.block_diag(|lu| lu.inverse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::solvers::SpSolver};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::prelude::*;
+
+    fn random_block(rng: &mut StdRng, m: usize, n: usize) -> Mat<c64> {
+        Mat::from_fn(m, n, |i, j| {
+            let diag = if i == j { 4.0 } else { 0.0 };
+            c64::new(diag + rng.gen::<f64>(), rng.gen())
+        })
+    }
+
+    #[test]
+    fn test_block_diag() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let parallelism = Parallelism::Rayon(4);
+
+        let a = BlockDiag::new(
+            [0, 3, 1, 7, 4]
+                .into_iter()
+                .map(|n| random_block(rng, n, n))
+                .collect(),
+        );
+        let n = a.nrows();
+        assert!(a.ncols() == n);
+        assert!(a.block_offset(3) == (4, 4));
+
+        let dense = a.to_mat();
+        let sparse = a.to_sparse::<usize>().unwrap();
+        assert!((sparse.to_dense() - &dense).norm_max() == 0.0);
+
+        let rhs = Mat::<c64>::from_fn(n, 2, |_, _| c64::new(rng.gen(), rng.gen()));
+        assert!((&a * rhs.as_ref() - &dense * &rhs).norm_max() < 1e-10);
+
+        let mut out = Mat::<c64>::zeros(n, 2);
+        a.adjoint_apply(
+            out.as_mut(),
+            rhs.as_ref(),
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(StackReq::empty())),
+        );
+        assert!((&out - dense.adjoint() * &rhs).norm_max() < 1e-10);
+
+        let sol = a.solve(rhs.as_ref());
+        assert!((&dense * &sol - &rhs).norm_max() < 1e-10);
+
+        let lu = a.partial_piv_lu();
+        assert!(lu.nblocks() == 5);
+        let sol = lu.solve_transpose(&rhs);
+        assert!((dense.transpose() * &sol - &rhs).norm_max() < 1e-10);
+        assert!((lu.reconstruct() - &dense).norm_max() < 1e-10);
+        assert!((lu.inverse() * &dense - Mat::<c64>::identity(n, n)).norm_max() < 1e-10);
+
+        let det = a.determinant();
+        let expected = dense.determinant();
+        assert!((det - expected).faer_abs() < 1e-10 * expected.faer_abs());
+    }
+
+    #[test]
+    fn test_block_diag_rectangular() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let a = BlockDiag::new(alloc::vec![
+            random_block(rng, 2, 3),
+            random_block(rng, 4, 1),
+        ]);
+        assert!(all(a.nrows() == 6, a.ncols() == 4));
+
+        let dense = a.to_mat();
+        let sparse = a.to_sparse::<u32>().unwrap();
+        assert!((sparse.to_dense() - &dense).norm_max() == 0.0);
+
+        let rhs = Mat::<c64>::from_fn(4, 3, |_, _| c64::new(rng.gen(), rng.gen()));
+        assert!((&a * rhs.as_ref() - &dense * &rhs).norm_max() < 1e-10);
+    }
+}
@@ -0,0 +1,518 @@
+//! C ABI for dense `f64` matrices and their factorizations.
+//!
+//! The functions in this module use the C calling convention and unmangled names, so that they
+//! can be called from C, C++ or Fortran once faer is linked into a `staticlib` or `cdylib` crate.
+//! A header can be generated with `cbindgen`. Owned matrices and factorizations are exposed as
+//! opaque handles that must be released with the corresponding `_free` function. Matrices owned by
+//! the caller are passed as [`FaerMatView`] or [`FaerMatViewMut`], which describe a strided buffer
+//! without taking ownership of it.
+//!
+//! Functions that can fail return a [`FaerStatus`]. Panics are caught at the boundary and reported
+//! as [`FaerStatus::Panic`] instead of unwinding into the caller.
+//!
+//! ```
+//! use faer::capi::*;
+//!
+//! // column-major storage owned by the caller
+//! let a = [4.0, 1.0, 1.0, 3.0];
+//! let mut b = [1.0, 2.0];
+//!
+//! let a = FaerMatView::col_major(a.as_ptr(), 2, 2, 2);
+//! let b = FaerMatViewMut::col_major(b.as_mut_ptr(), 2, 1, 2);
+//!
+//! unsafe {
+//!     let mut lu = core::ptr::null_mut();
+//!     assert!(faer_lu_new(a, &mut lu) == FaerStatus::Ok);
+//!     assert!(faer_lu_solve(lu, b) == FaerStatus::Ok);
+//!     faer_lu_free(lu);
+//! }
+//! ```
+
+use crate::{
+    linalg::solvers::{Cholesky, PartialPivLu, Qr, SpSolver, SpSolverCore, SpSolverLstsq},
+    mat::{self, MatMut, MatRef},
+    Mat, Side,
+};
+use alloc::boxed::Box;
+use core::ptr::{self, NonNull};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Status code returned by the fallible functions of the C API.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FaerStatus {
+    /// The operation succeeded.
+    Ok = 0,
+    /// A required pointer was null.
+    NullPointer = 1,
+    /// The dimensions of the arguments are incompatible.
+    DimensionMismatch = 2,
+    /// The matrix was expected to be square.
+    NotSquare = 3,
+    /// The matrix is not positive definite.
+    NotPositiveDefinite = 4,
+    /// A panic occurred inside faer.
+    Panic = 5,
+}
+
+/// Read-only view over a matrix stored in a caller-owned buffer.
+///
+/// The element at row `i` and column `j` is located at `data + i * row_stride + j * col_stride`.
+/// `data` may be null if the matrix is empty.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct FaerMatView {
+    /// Pointer to the first element.
+    pub data: *const f64,
+    /// Number of rows.
+    pub nrows: usize,
+    /// Number of columns.
+    pub ncols: usize,
+    /// Offset between consecutive rows, in elements.
+    pub row_stride: isize,
+    /// Offset between consecutive columns, in elements.
+    pub col_stride: isize,
+}
+
+/// Mutable view over a matrix stored in a caller-owned buffer.
+///
+/// See [`FaerMatView`] for the layout.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct FaerMatViewMut {
+    /// Pointer to the first element.
+    pub data: *mut f64,
+    /// Number of rows.
+    pub nrows: usize,
+    /// Number of columns.
+    pub ncols: usize,
+    /// Offset between consecutive rows, in elements.
+    pub row_stride: isize,
+    /// Offset between consecutive columns, in elements.
+    pub col_stride: isize,
+}
+
+impl FaerMatView {
+    /// Returns a view over a column-major buffer with leading dimension `ld`.
+    #[inline]
+    pub fn col_major(data: *const f64, nrows: usize, ncols: usize, ld: usize) -> Self {
+        Self {
+            data,
+            nrows,
+            ncols,
+            row_stride: 1,
+            col_stride: ld as isize,
+        }
+    }
+
+    unsafe fn as_ref<'a>(self) -> Result<MatRef<'a, f64>, FaerStatus> {
+        let data = checked_ptr(self.data as *mut f64, self.nrows, self.ncols)?;
+        Ok(mat::from_raw_parts(
+            data,
+            self.nrows,
+            self.ncols,
+            self.row_stride,
+            self.col_stride,
+        ))
+    }
+}
+
+impl FaerMatViewMut {
+    /// Returns a view over a column-major buffer with leading dimension `ld`.
+    #[inline]
+    pub fn col_major(data: *mut f64, nrows: usize, ncols: usize, ld: usize) -> Self {
+        Self {
+            data,
+            nrows,
+            ncols,
+            row_stride: 1,
+            col_stride: ld as isize,
+        }
+    }
+
+    unsafe fn as_mut<'a>(self) -> Result<MatMut<'a, f64>, FaerStatus> {
+        let data = checked_ptr(self.data, self.nrows, self.ncols)?;
+        Ok(mat::from_raw_parts_mut(
+            data,
+            self.nrows,
+            self.ncols,
+            self.row_stride,
+            self.col_stride,
+        ))
+    }
+}
+
+fn checked_ptr(data: *mut f64, nrows: usize, ncols: usize) -> Result<*mut f64, FaerStatus> {
+    if !data.is_null() {
+        Ok(data)
+    } else if nrows == 0 || ncols == 0 {
+        Ok(NonNull::dangling().as_ptr())
+    } else {
+        Err(FaerStatus::NullPointer)
+    }
+}
+
+fn status(f: impl FnOnce() -> Result<(), FaerStatus>) -> FaerStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => FaerStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => FaerStatus::Panic,
+    }
+}
+
+unsafe fn new_handle<T, U>(
+    out: *mut *mut T,
+    a: FaerMatView,
+    f: impl FnOnce(MatRef<'_, f64>) -> Result<U, FaerStatus>,
+    wrap: impl FnOnce(U) -> T,
+) -> FaerStatus {
+    if out.is_null() {
+        return FaerStatus::NullPointer;
+    }
+    *out = ptr::null_mut();
+    status(|| {
+        let a = a.as_ref()?;
+        if a.nrows() != a.ncols() {
+            return Err(FaerStatus::NotSquare);
+        }
+        *out = Box::into_raw(Box::new(wrap(f(a)?)));
+        Ok(())
+    })
+}
+
+unsafe fn free_handle<T>(handle: *mut T) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Dense matrix owned by faer.
+pub struct FaerMat(Mat<f64>);
+
+/// Partial pivoting LU decomposition of a square matrix.
+pub struct FaerLu(PartialPivLu<f64>);
+
+/// Cholesky decomposition of a symmetric positive definite matrix.
+pub struct FaerCholesky(Cholesky<f64>);
+
+/// QR decomposition of a matrix with at least as many rows as columns.
+pub struct FaerQr(Qr<f64>);
+
+/// Allocates a zero-initialized matrix. Returns null if the size of the matrix in bytes exceeds
+/// `isize::MAX`. Like the other allocations made by faer, running out of memory aborts the process.
+///
+/// The matrix must be released with [`faer_mat_free`].
+#[no_mangle]
+pub extern "C" fn faer_mat_new(nrows: usize, ncols: usize) -> *mut FaerMat {
+    catch_unwind(|| Box::into_raw(Box::new(FaerMat(Mat::zeros(nrows, ncols)))))
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Allocates a matrix containing a copy of `src`. Returns null if `src` has a null data pointer
+/// and isn't empty.
+///
+/// The matrix must be released with [`faer_mat_free`].
+///
+/// # Safety
+/// `src` must describe a valid matrix.
+#[no_mangle]
+pub unsafe extern "C" fn faer_mat_copy(src: FaerMatView) -> *mut FaerMat {
+    let mut out = ptr::null_mut();
+    status(|| {
+        out = Box::into_raw(Box::new(FaerMat(src.as_ref()?.to_owned())));
+        Ok(())
+    });
+    out
+}
+
+/// Releases a matrix allocated by faer. Does nothing if `mat` is null.
+///
+/// # Safety
+/// `mat` must be null or a matrix returned by faer that hasn't been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn faer_mat_free(mat: *mut FaerMat) {
+    free_handle(mat)
+}
+
+/// Returns a view over the contents of `mat`, which stays valid until `mat` is released.
+///
+/// # Safety
+/// `mat` must be a valid matrix returned by faer.
+#[no_mangle]
+pub unsafe extern "C" fn faer_mat_view(mat: *mut FaerMat) -> FaerMatViewMut {
+    let mat = &mut (*mat).0;
+    FaerMatViewMut {
+        data: mat.as_ptr_mut(),
+        nrows: mat.nrows(),
+        ncols: mat.ncols(),
+        row_stride: mat.row_stride(),
+        col_stride: mat.col_stride(),
+    }
+}
+
+/// Computes the LU decomposition of the square matrix `a`, and stores a handle to it in `out`.
+///
+/// The decomposition must be released with [`faer_lu_free`].
+///
+/// # Safety
+/// `a` must describe a valid matrix, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn faer_lu_new(a: FaerMatView, out: *mut *mut FaerLu) -> FaerStatus {
+    new_handle(out, a, |a| Ok(a.partial_piv_lu()), FaerLu)
+}
+
+/// Solves `A * X = rhs`, where `A` is the matrix of the decomposition, and stores the result in
+/// `rhs`.
+///
+/// # Safety
+/// `lu` must be a valid decomposition returned by faer, and `rhs` must describe a valid matrix.
+#[no_mangle]
+pub unsafe extern "C" fn faer_lu_solve(lu: *const FaerLu, rhs: FaerMatViewMut) -> FaerStatus {
+    if lu.is_null() {
+        return FaerStatus::NullPointer;
+    }
+    let lu = &(*lu).0;
+    status(|| {
+        let rhs = rhs.as_mut()?;
+        if rhs.nrows() != lu.nrows() {
+            return Err(FaerStatus::DimensionMismatch);
+        }
+        lu.solve_in_place(rhs);
+        Ok(())
+    })
+}
+
+/// Releases an LU decomposition. Does nothing if `lu` is null.
+///
+/// # Safety
+/// `lu` must be null or a decomposition returned by faer that hasn't been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn faer_lu_free(lu: *mut FaerLu) {
+    free_handle(lu)
+}
+
+/// Computes the Cholesky decomposition of the symmetric positive definite matrix `a`, reading
+/// only its lower triangular half, and stores a handle to it in `out`.
+///
+/// The decomposition must be released with [`faer_cholesky_free`].
+///
+/// # Safety
+/// `a` must describe a valid matrix, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn faer_cholesky_new(
+    a: FaerMatView,
+    out: *mut *mut FaerCholesky,
+) -> FaerStatus {
+    new_handle(
+        out,
+        a,
+        |a| {
+            a.cholesky(Side::Lower)
+                .map_err(|_| FaerStatus::NotPositiveDefinite)
+        },
+        FaerCholesky,
+    )
+}
+
+/// Solves `A * X = rhs`, where `A` is the matrix of the decomposition, and stores the result in
+/// `rhs`.
+///
+/// # Safety
+/// `cholesky` must be a valid decomposition returned by faer, and `rhs` must describe a valid
+/// matrix.
+#[no_mangle]
+pub unsafe extern "C" fn faer_cholesky_solve(
+    cholesky: *const FaerCholesky,
+    rhs: FaerMatViewMut,
+) -> FaerStatus {
+    if cholesky.is_null() {
+        return FaerStatus::NullPointer;
+    }
+    let cholesky = &(*cholesky).0;
+    status(|| {
+        let rhs = rhs.as_mut()?;
+        if rhs.nrows() != cholesky.nrows() {
+            return Err(FaerStatus::DimensionMismatch);
+        }
+        cholesky.solve_in_place(rhs);
+        Ok(())
+    })
+}
+
+/// Releases a Cholesky decomposition. Does nothing if `cholesky` is null.
+///
+/// # Safety
+/// `cholesky` must be null or a decomposition returned by faer that hasn't been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn faer_cholesky_free(cholesky: *mut FaerCholesky) {
+    free_handle(cholesky)
+}
+
+/// Computes the QR decomposition of `a`, which must have at least as many rows as columns, and
+/// stores a handle to it in `out`.
+///
+/// The decomposition must be released with [`faer_qr_free`].
+///
+/// # Safety
+/// `a` must describe a valid matrix, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn faer_qr_new(a: FaerMatView, out: *mut *mut FaerQr) -> FaerStatus {
+    if out.is_null() {
+        return FaerStatus::NullPointer;
+    }
+    *out = ptr::null_mut();
+    status(|| {
+        let a = a.as_ref()?;
+        if a.nrows() < a.ncols() {
+            return Err(FaerStatus::DimensionMismatch);
+        }
+        *out = Box::into_raw(Box::new(FaerQr(a.qr())));
+        Ok(())
+    })
+}
+
+/// Solves `A * X = rhs` in the sense of least squares, where `A` is the matrix of the
+/// decomposition, and stores the result in the top rows of `rhs`.
+///
+/// # Safety
+/// `qr` must be a valid decomposition returned by faer, and `rhs` must describe a valid matrix.
+#[no_mangle]
+pub unsafe extern "C" fn faer_qr_solve_lstsq(qr: *const FaerQr, rhs: FaerMatViewMut) -> FaerStatus {
+    if qr.is_null() {
+        return FaerStatus::NullPointer;
+    }
+    let qr = &(*qr).0;
+    status(|| {
+        let rhs = rhs.as_mut()?;
+        if rhs.nrows() != qr.nrows() {
+            return Err(FaerStatus::DimensionMismatch);
+        }
+        qr.solve_lstsq_in_place(rhs);
+        Ok(())
+    })
+}
+
+/// Releases a QR decomposition. Does nothing if `qr` is null.
+///
+/// # Safety
+/// `qr` must be null or a decomposition returned by faer that hasn't been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn faer_qr_free(qr: *mut FaerQr) {
+    free_handle(qr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, mat};
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_mat() {
+        unsafe {
+            let a = faer_mat_new(3, 2);
+            let view = faer_mat_view(a);
+            assert!(all(view.nrows == 3, view.ncols == 2, view.row_stride == 1));
+            *view.data.offset(view.col_stride) = 1.0;
+            assert!((*a).0 == mat![[0.0, 1.0], [0.0, 0.0], [0.0, 0.0]]);
+
+            // row-major external buffer
+            let data = [1.0, 2.0, 3.0, 4.0];
+            let b = faer_mat_copy(FaerMatView {
+                data: data.as_ptr(),
+                nrows: 2,
+                ncols: 2,
+                row_stride: 2,
+                col_stride: 1,
+            });
+            assert!((*b).0 == mat![[1.0, 2.0], [3.0, 4.0]]);
+
+            assert!(faer_mat_new(usize::MAX, 2).is_null());
+            assert!(faer_mat_copy(FaerMatView::col_major(ptr::null(), 2, 2, 2)).is_null());
+            assert!(!faer_mat_copy(FaerMatView::col_major(ptr::null(), 0, 2, 0)).is_null());
+
+            faer_mat_free(a);
+            faer_mat_free(b);
+            faer_mat_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = [4.0, 1.0, 1.0, 3.0];
+        let a = FaerMatView::col_major(a.as_ptr(), 2, 2, 2);
+        let expected = [1.0 / 11.0, 7.0 / 11.0];
+
+        unsafe {
+            let mut lu = ptr::null_mut();
+            assert!(faer_lu_new(a, &mut lu) == FaerStatus::Ok);
+            let mut b = [1.0, 2.0];
+            assert!(
+                faer_lu_solve(lu, FaerMatViewMut::col_major(b.as_mut_ptr(), 2, 1, 2))
+                    == FaerStatus::Ok
+            );
+            assert_approx_eq!(b[0], expected[0]);
+            assert_approx_eq!(b[1], expected[1]);
+            let mut c = [1.0, 2.0, 3.0];
+            assert!(
+                faer_lu_solve(lu, FaerMatViewMut::col_major(c.as_mut_ptr(), 3, 1, 3))
+                    == FaerStatus::DimensionMismatch
+            );
+            faer_lu_free(lu);
+
+            let mut cholesky = ptr::null_mut();
+            assert!(faer_cholesky_new(a, &mut cholesky) == FaerStatus::Ok);
+            let mut b = [1.0, 2.0];
+            assert!(
+                faer_cholesky_solve(cholesky, FaerMatViewMut::col_major(b.as_mut_ptr(), 2, 1, 2))
+                    == FaerStatus::Ok
+            );
+            assert_approx_eq!(b[0], expected[0]);
+            assert_approx_eq!(b[1], expected[1]);
+            faer_cholesky_free(cholesky);
+
+            let not_pd = [-1.0, 0.0, 0.0, 1.0];
+            let mut cholesky = ptr::null_mut();
+            assert!(
+                faer_cholesky_new(
+                    FaerMatView::col_major(not_pd.as_ptr(), 2, 2, 2),
+                    &mut cholesky
+                ) == FaerStatus::NotPositiveDefinite
+            );
+            assert!(cholesky.is_null());
+
+            let mut lu = ptr::null_mut();
+            assert!(
+                faer_lu_new(FaerMatView::col_major(not_pd.as_ptr(), 1, 2, 1), &mut lu)
+                    == FaerStatus::NotSquare
+            );
+            assert!(faer_lu_new(a, ptr::null_mut()) == FaerStatus::NullPointer);
+            assert!(
+                faer_lu_solve(
+                    ptr::null(),
+                    FaerMatViewMut::col_major(b.as_mut_ptr(), 2, 1, 2)
+                ) == FaerStatus::NullPointer
+            );
+        }
+    }
+
+    #[test]
+    fn test_lstsq() {
+        // fits y = 1 + 2x
+        let a = [1.0, 1.0, 1.0, 0.0, 1.0, 2.0];
+        let mut b = [1.0, 3.0, 5.0];
+        unsafe {
+            let mut qr = ptr::null_mut();
+            assert!(
+                faer_qr_new(FaerMatView::col_major(a.as_ptr(), 3, 2, 3), &mut qr) == FaerStatus::Ok
+            );
+            assert!(
+                faer_qr_solve_lstsq(qr, FaerMatViewMut::col_major(b.as_mut_ptr(), 3, 1, 3))
+                    == FaerStatus::Ok
+            );
+            faer_qr_free(qr);
+        }
+        assert_approx_eq!(b[0], 1.0);
+        assert_approx_eq!(b[1], 2.0);
+    }
+}
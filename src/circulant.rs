@@ -0,0 +1,340 @@
+use crate::{
+    assert,
+    col::{Col, ColRef},
+    get_global_parallelism,
+    linop::{BiLinOp, LinOp},
+    mat::{Mat, MatMut, MatRef},
+    utils::{
+        fft::{
+            circulant_apply, circulant_apply_req, cmul, fft_in_place, fft_req, from_parts,
+            CirculantOp,
+        },
+        scratch::ScratchBuffer,
+    },
+    ComplexField, Conjugate, Parallelism, RealField,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use num_complex::Complex;
+
+/// Circulant matrix, where each column is the previous one rotated down by one element. The
+/// matrix is stored as its first column, and the entry at `(i, j)` is `first_col[(i - j) mod n]`.
+///
+/// Circulant matrices are diagonalized by the discrete Fourier transform, so that products,
+/// solves, the determinant and the eigenvalues are computed in `O(n log n)` operations.
+#[derive(Clone, Debug)]
+pub struct Circulant<E: ComplexField> {
+    first_col: Col<E>,
+    // eigenvalues, i.e. the discrete Fourier transform of the first column
+    spectrum_re: alloc::vec::Vec<E::Real>,
+    spectrum_im: alloc::vec::Vec<E::Real>,
+}
+
+impl<E: ComplexField> Circulant<E> {
+    /// Creates a circulant matrix from its first column.
+    pub fn new(first_col: Col<E>) -> Self {
+        let n = first_col.nrows();
+        let mut spectrum_re: alloc::vec::Vec<E::Real> =
+            (0..n).map(|i| first_col.read(i).faer_real()).collect();
+        let mut spectrum_im: alloc::vec::Vec<E::Real> =
+            (0..n).map(|i| first_col.read(i).faer_imag()).collect();
+        fft_in_place(
+            &mut spectrum_re,
+            &mut spectrum_im,
+            false,
+            PodStack::new(&mut ScratchBuffer::new(fft_req::<E::Real>(n).unwrap())),
+        );
+
+        Self {
+            first_col,
+            spectrum_re,
+            spectrum_im,
+        }
+    }
+
+    /// Returns the dimension of the matrix.
+    #[inline(always)]
+    pub fn dim(&self) -> usize {
+        self.first_col.nrows()
+    }
+
+    /// Returns a view over the first column of the matrix.
+    #[inline(always)]
+    pub fn first_col(&self) -> ColRef<'_, E> {
+        self.first_col.as_ref()
+    }
+
+    /// Reads the value at the given indices.
+    ///
+    /// # Panics
+    /// Panics if `row >= self.dim()` or `col >= self.dim()`.
+    #[inline]
+    #[track_caller]
+    pub fn read(&self, row: usize, col: usize) -> E {
+        let n = self.dim();
+        assert!(all(row < n, col < n));
+        self.first_col
+            .read(if row >= col { row - col } else { row + n - col })
+    }
+
+    /// Returns the matrix as a dense matrix.
+    pub fn to_mat(&self) -> Mat<E> {
+        let n = self.dim();
+        Mat::from_fn(n, n, |i, j| self.read(i, j))
+    }
+
+    /// Returns the eigenvalues of `self`, as complex values. The `k`-th eigenvalue corresponds to
+    /// the eigenvector `(ω^(jk))_j`, where `ω = exp(2πi / n)`.
+    pub fn eigenvalues(&self) -> alloc::vec::Vec<Complex<E::Real>> {
+        core::iter::zip(&self.spectrum_re, &self.spectrum_im)
+            .map(|(&re, &im)| Complex { re, im })
+            .collect()
+    }
+
+    /// Returns the determinant of `self`.
+    pub fn determinant(&self) -> E {
+        let mut re = E::Real::faer_one();
+        let mut im = E::Real::faer_zero();
+        for (&sr, &si) in core::iter::zip(&self.spectrum_re, &self.spectrum_im) {
+            (re, im) = cmul(re, im, sr, si);
+        }
+        // the imaginary part vanishes for real matrices, since the eigenvalues come in conjugate
+        // pairs
+        from_parts(re, im)
+    }
+
+    /// Solves the equation `self * X = rhs`, and stores the result in `rhs`.
+    ///
+    /// If `self` is singular, the solution contains non-finite values.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve_in_place(&self, rhs: MatMut<'_, E>) {
+        let n = self.dim();
+        assert!(rhs.nrows() == n);
+        let tmp = rhs.to_owned();
+        circulant_apply(
+            rhs,
+            tmp.as_ref(),
+            &self.spectrum_re,
+            &self.spectrum_im,
+            CirculantOp::Identity,
+            true,
+            PodStack::new(&mut ScratchBuffer::new(
+                circulant_apply_req::<E::Real>(n).unwrap(),
+            )),
+        );
+    }
+
+    /// Returns the solution of the equation `self * X = rhs`.
+    ///
+    /// See [`Self::solve_in_place`].
+    #[track_caller]
+    pub fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: MatRef<'_, ViewE>) -> Mat<E> {
+        let mut out = rhs.to_owned();
+        self.solve_in_place(out.as_mut());
+        out
+    }
+
+    // applies `op(self)`, or its inverse if `inverse` is `true`
+    pub(crate) fn apply_impl(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        op: CirculantOp,
+        inverse: bool,
+        stack: PodStack<'_>,
+    ) {
+        let n = self.dim();
+        assert!(all(
+            out.nrows() == n,
+            rhs.nrows() == n,
+            out.ncols() == rhs.ncols(),
+        ));
+        circulant_apply(
+            out,
+            rhs,
+            &self.spectrum_re,
+            &self.spectrum_im,
+            op,
+            inverse,
+            stack,
+        );
+    }
+}
+
+impl<E: ComplexField> LinOp<E> for Circulant<E> {
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        _ = rhs_ncols;
+        _ = parallelism;
+        circulant_apply_req::<E::Real>(self.dim())
+    }
+
+    fn nrows(&self) -> usize {
+        self.dim()
+    }
+
+    fn ncols(&self) -> usize {
+        self.dim()
+    }
+
+    #[track_caller]
+    fn apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.apply_impl(out, rhs, CirculantOp::Identity, false, stack)
+    }
+
+    #[track_caller]
+    fn conj_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.apply_impl(out, rhs, CirculantOp::Conj, false, stack)
+    }
+}
+
+impl<E: ComplexField> BiLinOp<E> for Circulant<E> {
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.apply_req(rhs_ncols, parallelism)
+    }
+
+    #[track_caller]
+    fn transpose_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.apply_impl(out, rhs, CirculantOp::Transpose, false, stack)
+    }
+
+    #[track_caller]
+    fn adjoint_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.apply_impl(out, rhs, CirculantOp::Adjoint, false, stack)
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<MatRef<'_, E>> for &Circulant<E> {
+    type Output = Mat<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: MatRef<'_, E>) -> Self::Output {
+        let parallelism = get_global_parallelism();
+        let mut out = Mat::<E>::zeros(self.dim(), rhs.ncols());
+        self.apply(
+            out.as_mut(),
+            rhs,
+            parallelism,
+            PodStack::new(&mut ScratchBuffer::new(
+                self.apply_req(rhs.ncols(), parallelism).unwrap(),
+            )),
+        );
+        out
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<ColRef<'_, E>> for &Circulant<E> {
+    type Output = Col<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: ColRef<'_, E>) -> Self::Output {
+        let out = self * rhs.as_2d();
+        Col::from_fn(out.nrows(), |i| out.read(i, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_circulant() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let parallelism = Parallelism::Rayon(4);
+        for n in [0, 1, 2, 3, 8, 15, 40] {
+            let c = Circulant::new(Col::from_fn(n, |_| c64::new(rng.gen(), rng.gen())));
+            let dense = c.to_mat();
+            let rhs = Mat::<c64>::from_fn(n, 3, |_, _| c64::new(rng.gen(), rng.gen()));
+
+            assert!((&c * rhs.as_ref() - &dense * &rhs).norm_max() < 1e-10);
+            let req = c.transpose_apply_req(3, parallelism).unwrap();
+            let mut out = Mat::<c64>::zeros(n, 3);
+            c.transpose_apply(
+                out.as_mut(),
+                rhs.as_ref(),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(req)),
+            );
+            assert!((&out - dense.transpose() * &rhs).norm_max() < 1e-10);
+            c.adjoint_apply(
+                out.as_mut(),
+                rhs.as_ref(),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(req)),
+            );
+            assert!((&out - dense.adjoint() * &rhs).norm_max() < 1e-10);
+
+            let sol = c.solve(rhs.as_ref());
+            assert!((&dense * &sol - &rhs).norm_max() < 1e-8);
+
+            let det = c.determinant();
+            let expected = dense.determinant();
+            assert!((det - expected).faer_abs() < 1e-8 * expected.faer_abs().max(1.0));
+
+            // every eigenvalue must match one of the eigenvalues of the dense matrix
+            let expected = dense.eigenvalues::<c64>();
+            for a in c.eigenvalues() {
+                let a = c64::from(a);
+                let dist = expected
+                    .iter()
+                    .map(|&e| (a - e).faer_abs())
+                    .fold(f64::INFINITY, f64::min);
+                assert!(dist < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_circulant_real() {
+        let c = Circulant::new(Col::from_fn(5, |i| [4.0, 1.0, -2.0, 0.5, 3.0][i]));
+        let dense = c.to_mat();
+        assert!((c.determinant() - dense.determinant()).abs() < 1e-10);
+
+        let rhs = Mat::<f64>::from_fn(5, 2, |i, j| (i * 2 + j) as f64);
+        let sol = c.solve(rhs.as_ref());
+        assert!((&dense * &sol - &rhs).norm_max() < 1e-10);
+
+        let eigs = c.eigenvalues();
+        assert!((c64::from(eigs[0]) - c64::new(6.5, 0.0)).faer_abs() < 1e-12);
+    }
+}
@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     diag::{DiagMut, DiagRef},
     mat,
-    row::{RowMut, RowRef},
+    row::{AsRowRef, RowMut, RowRef},
     unzipped, zipped,
 };
 use core::mem::MaybeUninit;
@@ -462,6 +462,82 @@ impl<'a, E: Entity> ColMut<'a, E> {
         );
     }
 
+    /// Divides `self` by its L2 norm, so that it becomes a unit vector. If the norm of `self` is
+    /// zero, `self` is left unchanged.
+    #[track_caller]
+    pub fn normalize(&mut self)
+    where
+        E: ComplexField,
+    {
+        self.normalize_with(Norm::L2, ZeroNormPolicy::Keep)
+    }
+
+    /// Divides `self` by its norm, as specified by `norm`, with vectors whose norm is zero being
+    /// handled according to `zero_policy`.
+    ///
+    /// # Panics
+    /// The function panics if the norm of `self` is zero and `zero_policy` is
+    /// [`ZeroNormPolicy::Panic`].
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, Norm, ZeroNormPolicy};
+    ///
+    /// let mut x = col![1.0, -3.0, 4.0f64];
+    /// x.normalize_with(Norm::L1, ZeroNormPolicy::Keep);
+    /// assert_eq!(x, col![0.125, -0.375, 0.5]);
+    ///
+    /// let mut zero = col![0.0, 0.0f64];
+    /// zero.normalize_with(Norm::Max, ZeroNormPolicy::Nan);
+    /// assert!(zero.read(0).is_nan());
+    /// ```
+    #[track_caller]
+    pub fn normalize_with(&mut self, norm: Norm, zero_policy: ZeroNormPolicy)
+    where
+        E: ComplexField,
+    {
+        let value = match norm {
+            Norm::L1 => self.norm_l1(),
+            Norm::L2 => self.norm_l2(),
+            Norm::Max => self.norm_max(),
+        };
+
+        if value == E::Real::faer_zero() {
+            match zero_policy {
+                ZeroNormPolicy::Keep => {}
+                ZeroNormPolicy::Nan => self.fill(E::faer_nan()),
+                ZeroNormPolicy::Panic => panic!("cannot normalize a vector whose norm is zero"),
+            }
+        } else {
+            let inv = value.faer_inv();
+            zipped!(self.rb_mut().as_2d_mut()).for_each(
+                #[inline(always)]
+                |unzipped!(mut x)| x.write(x.read().faer_scale_real(inv)),
+            );
+        }
+    }
+
+    /// Returns `self` divided by its L2 norm. See [`ColMut::normalize`].
+    #[track_caller]
+    pub fn normalized(&self) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().normalized()
+    }
+
+    /// Returns `self` divided by its norm, as specified by `norm`. See
+    /// [`ColMut::normalize_with`].
+    #[track_caller]
+    pub fn normalized_with(&self, norm: Norm, zero_policy: ZeroNormPolicy) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().normalized_with(norm, zero_policy)
+    }
+
     /// Returns a view over the transpose of `self`.
     #[inline(always)]
     #[must_use]
@@ -690,6 +766,51 @@ impl<'a, E: Entity> ColMut<'a, E> {
         self.rb().as_2d().sum()
     }
 
+    /// Returns the dot product of `self` and `rhs`, without conjugating either of the operands.
+    /// See [`ColRef::dot`].
+    #[doc(alias = "dotu")]
+    #[inline]
+    #[track_caller]
+    pub fn dot<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsColRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().dot(rhs)
+    }
+
+    /// Returns the inner product of `self` and `rhs`, `sum(conj(self[i]) * rhs[i])`. See
+    /// [`ColRef::dotc`].
+    #[doc(alias = "vdot")]
+    #[inline]
+    #[track_caller]
+    pub fn dotc<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsColRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().dotc(rhs)
+    }
+
+    /// Returns the outer product of `self` and `rhs`. See [`ColRef::outer`].
+    #[inline]
+    pub fn outer<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().outer(rhs)
+    }
+
     /// Kroneckor product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -837,6 +958,12 @@ impl<'a, E: Entity> core::fmt::Debug for ColMut<'a, E> {
     }
 }
 
+impl<E: Entity + core::fmt::Display> core::fmt::Display for ColMut<'_, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.as_ref(), f)
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<usize> for ColMut<'_, E> {
     type Output = E;
 
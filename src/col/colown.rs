@@ -3,7 +3,7 @@ use crate::{
     debug_assert,
     diag::{Diag, DiagMut, DiagRef},
     mat::matalloc::{align_for, is_vectorizable, MatUnit, RawMat, RawMatUnit},
-    row::{RowMut, RowRef},
+    row::{AsRowRef, RowMut, RowRef},
     utils::DivCeil,
 };
 use core::mem::{ManuallyDrop, MaybeUninit};
@@ -109,6 +109,139 @@ impl<E: Entity> Col<E> {
         Self::from_fn(nrows, |_| constant)
     }
 
+    /// Returns a column containing `nrows` evenly spaced values over the interval `[start, end]`.
+    ///
+    /// The first and last elements are exactly equal to `start` and `end`. If `nrows` is `1`,
+    /// the column only contains `start`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, Col};
+    ///
+    /// assert_eq!(Col::linspace(0.0, 1.0, 5), col![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    #[inline]
+    pub fn linspace(start: E, end: E, nrows: usize) -> Self
+    where
+        E: ComplexField,
+    {
+        let denom = E::Real::faer_from_f64(nrows.saturating_sub(1) as f64);
+        let delta = end.faer_sub(start);
+        Self::from_fn(nrows, |i| {
+            if i == 0 {
+                start
+            } else if i + 1 == nrows {
+                end
+            } else {
+                let t = E::Real::faer_from_f64(i as f64).faer_div(denom);
+                start.faer_add(delta.faer_scale_real(t))
+            }
+        })
+    }
+
+    /// Returns a column containing the values `start + i * step` that lie in the half-open
+    /// interval `[start, end)`, in order.
+    ///
+    /// # Panics
+    /// The function panics if `step` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, Col};
+    ///
+    /// assert_eq!(Col::arange(1.0, 2.0, 0.25), col![1.0, 1.25, 1.5, 1.75]);
+    /// assert_eq!(Col::arange(3.0, 0.0, -1.0), col![3.0, 2.0, 1.0]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn arange(start: E, end: E, step: E) -> Self
+    where
+        E: RealField,
+    {
+        assert!(step != E::faer_zero());
+        let at = |i: usize| start.faer_add(step.faer_mul(E::faer_from_f64(i as f64)));
+        let in_range = |x: E| {
+            if step > E::faer_zero() {
+                x < end
+            } else {
+                x > end
+            }
+        };
+
+        let mut nrows = 0;
+        while in_range(at(nrows)) {
+            nrows += 1;
+        }
+        Self::from_fn(nrows, at)
+    }
+
+    /// Returns a column containing `nrows` values spaced evenly on a log scale, from
+    /// `base^start` to `base^end`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, Col};
+    ///
+    /// let x = Col::logspace(0.0, 3.0, 4, 10.0);
+    /// assert!((x - col![1.0, 10.0, 100.0, 1000.0]).norm_max() < 1e-10);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn logspace(start: E, end: E, nrows: usize, base: E) -> Self
+    where
+        E: RealField + num_traits::Float,
+    {
+        let mut this = Self::linspace(start, end, nrows);
+        for i in 0..nrows {
+            let x = this.read(i);
+            this.write(i, num_traits::Float::powf(base, x));
+        }
+        this
+    }
+
+    /// Returns a column containing `nrows` values in geometric progression, from `start` to
+    /// `end`.
+    ///
+    /// The first and last elements are exactly equal to `start` and `end`.
+    ///
+    /// # Panics
+    /// The function panics if `start` and `end` are not both positive or both negative.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, Col};
+    ///
+    /// let x = Col::geomspace(1.0, 8.0, 4);
+    /// assert!((x - col![1.0, 2.0, 4.0, 8.0]).norm_max() < 1e-14);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    #[track_caller]
+    pub fn geomspace(start: E, end: E, nrows: usize) -> Self
+    where
+        E: RealField + num_traits::Float,
+    {
+        let zero = E::faer_zero();
+        assert!((start > zero && end > zero) || (start < zero && end < zero));
+
+        let ratio = end.faer_div(start);
+        let mut this = Self::linspace(zero, E::faer_one(), nrows);
+        for i in 0..nrows {
+            let t = this.read(i);
+            let x = if i == 0 {
+                start
+            } else if i + 1 == nrows {
+                end
+            } else {
+                start.faer_mul(num_traits::Float::powf(ratio, t))
+            };
+            this.write(i, x);
+        }
+        this
+    }
+
     /// Returns the number of rows of the column.
     #[inline(always)]
     pub fn nrows(&self) -> usize {
@@ -172,6 +305,14 @@ impl<E: Entity> Col<E> {
         self.as_mut().as_2d_mut()
     }
 
+    /// Returns a view over a matrix containing `self` repeated `ncols` times, without copying the
+    /// data. See [`ColRef::repeat_cols`].
+    #[doc(alias = "broadcast")]
+    #[inline(always)]
+    pub fn repeat_cols(&self, ncols: usize) -> MatRef<'_, E> {
+        self.as_ref().repeat_cols(ncols)
+    }
+
     /// Returns raw pointers to the element at the given index.
     #[inline(always)]
     pub fn ptr_at(&self, row: usize) -> GroupFor<E, *const E::Unit> {
@@ -411,6 +552,13 @@ impl<E: Entity> Col<E> {
         }
     }
 
+    /// Resizes the vector in-place so that the new number of rows is `new_nrows`.
+    /// The existing elements are kept, and new elements are set to `value`.
+    #[inline]
+    pub fn resize(&mut self, new_nrows: usize, value: E) {
+        self.resize_with(new_nrows, |_| value);
+    }
+
     /// Truncates the matrix so that its new number of rows is `new_nrows`.  
     /// The new dimension must be smaller than the current dimension of the vector.
     ///
@@ -638,6 +786,45 @@ impl<E: Entity> Col<E> {
         self.as_mut().fill(constant)
     }
 
+    /// Divides `self` by its L2 norm. See [`ColMut::normalize`].
+    #[track_caller]
+    pub fn normalize(&mut self)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().normalize()
+    }
+
+    /// Divides `self` by its norm, as specified by `norm`. See [`ColMut::normalize_with`].
+    #[track_caller]
+    pub fn normalize_with(&mut self, norm: Norm, zero_policy: ZeroNormPolicy)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().normalize_with(norm, zero_policy)
+    }
+
+    /// Returns `self` divided by its L2 norm. See [`ColMut::normalize`].
+    #[track_caller]
+    pub fn normalized(&self) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().normalized()
+    }
+
+    /// Returns `self` divided by its norm, as specified by `norm`. See
+    /// [`ColMut::normalize_with`].
+    #[track_caller]
+    pub fn normalized_with(&self, norm: Norm, zero_policy: ZeroNormPolicy) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().normalized_with(norm, zero_policy)
+    }
+
     /// Returns a view over the transpose of `self`.
     #[inline]
     pub fn transpose(&self) -> RowRef<'_, E> {
@@ -871,6 +1058,72 @@ impl<E: Entity> Col<E> {
         self.as_ref().as_2d().sum()
     }
 
+    /// Returns the dot product of `self` and `rhs`, without conjugating either of the operands.
+    /// See [`ColRef::dot`].
+    #[doc(alias = "dotu")]
+    #[inline]
+    #[track_caller]
+    pub fn dot<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsColRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().dot(rhs)
+    }
+
+    /// Returns the inner product of `self` and `rhs`, `sum(conj(self[i]) * rhs[i])`. See
+    /// [`ColRef::dotc`].
+    #[doc(alias = "vdot")]
+    #[inline]
+    #[track_caller]
+    pub fn dotc<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsColRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().dotc(rhs)
+    }
+
+    /// Returns the outer product of `self` and `rhs`. See [`ColRef::outer`].
+    #[inline]
+    pub fn outer<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().outer(rhs)
+    }
+
+    /// Returns the permutation that sorts `self` in ascending order. See [`ColRef::argsort`].
+    #[inline]
+    #[track_caller]
+    pub fn argsort(&self) -> crate::perm::Perm<usize>
+    where
+        E: RealField,
+    {
+        self.as_ref().argsort()
+    }
+
+    /// Returns the permutation that sorts `self` in ascending order. See
+    /// [`ColRef::argsort_unstable`].
+    #[inline]
+    #[track_caller]
+    pub fn argsort_unstable(&self) -> crate::perm::Perm<usize>
+    where
+        E: RealField,
+    {
+        self.as_ref().argsort_unstable()
+    }
+
     /// Returns the column as a contiguous slice if its row stride is equal to `1`.
     ///
     /// # Note
@@ -973,6 +1226,12 @@ impl<E: Entity> core::fmt::Debug for Col<E> {
     }
 }
 
+impl<E: Entity + core::fmt::Display> core::fmt::Display for Col<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.as_ref(), f)
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<usize> for Col<E> {
     type Output = E;
 
@@ -1,5 +1,9 @@
 use super::*;
-use crate::{assert, debug_assert, diag::DiagRef, row::RowRef};
+use crate::{
+    assert, debug_assert,
+    diag::DiagRef,
+    row::{AsRowRef, RowRef},
+};
 
 /// Immutable view over a column vector, similar to an immutable reference to a strided
 /// [prim@slice].
@@ -122,6 +126,16 @@ impl<'a, E: Entity> ColRef<'a, E> {
         unsafe { crate::mat::from_raw_parts(self.as_ptr(), nrows, 1, row_stride, isize::MAX) }
     }
 
+    /// Returns a view over a matrix containing `self` repeated `ncols` times, without copying the
+    /// data.
+    ///
+    /// This can be used to broadcast a column against a matrix in [`zipped!`](crate::zipped).
+    #[doc(alias = "broadcast")]
+    #[inline(always)]
+    pub fn repeat_cols(self, ncols: usize) -> MatRef<'a, E> {
+        crate::mat::from_repeated_col(self, ncols)
+    }
+
     /// Returns raw pointers to the element at the given index.
     #[inline(always)]
     pub fn ptr_at(self, row: usize) -> GroupFor<E, *const E::Unit> {
@@ -420,6 +434,30 @@ impl<'a, E: Entity> ColRef<'a, E> {
         mat
     }
 
+    /// Returns `self` divided by its L2 norm. If the norm of `self` is zero, the returned vector is
+    /// a copy of `self`. See [`ColMut::normalize`].
+    #[track_caller]
+    pub fn normalized(&self) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.normalized_with(Norm::L2, ZeroNormPolicy::Keep)
+    }
+
+    /// Returns `self` divided by its norm, as specified by `norm`. See
+    /// [`ColMut::normalize_with`].
+    #[track_caller]
+    pub fn normalized_with(&self, norm: Norm, zero_policy: ZeroNormPolicy) -> Col<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        let mut col = self.to_owned();
+        col.normalize_with(norm, zero_policy);
+        col
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -482,6 +520,185 @@ impl<'a, E: Entity> ColRef<'a, E> {
         self.as_2d().sum()
     }
 
+    /// Returns the dot product of `self` and `rhs`, `sum(self[i] * rhs[i])`, without conjugating
+    /// either of the operands.
+    ///
+    /// For complex vectors, the inner product that conjugates `self` is computed by
+    /// [`ColRef::dotc`].
+    ///
+    /// # Panics
+    /// The function panics if `self.nrows() != rhs.nrows()`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat, row};
+    ///
+    /// let a = col![1.0, 2.0, 3.0f64];
+    /// let b = col![4.0, 5.0, 6.0f64];
+    ///
+    /// assert_eq!(a.as_ref().dot(&b), 32.0);
+    /// assert_eq!(
+    ///     a.as_ref().outer(row![1.0, -1.0f64]),
+    ///     mat![[1.0, -1.0], [2.0, -2.0], [3.0, -3.0]],
+    /// );
+    /// ```
+    #[doc(alias = "dotu")]
+    #[inline]
+    #[track_caller]
+    pub fn dot<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsColRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        let rhs = rhs.as_col_ref();
+        assert!(self.nrows() == rhs.nrows());
+        let (lhs, conj_lhs) = self.as_2d().canonicalize();
+        let (rhs, conj_rhs) = rhs.as_2d().canonicalize();
+        crate::linalg::matmul::inner_prod::inner_prod_with_conj(lhs, conj_lhs, rhs, conj_rhs)
+    }
+
+    /// Returns the inner product of `self` and `rhs`, `sum(conj(self[i]) * rhs[i])`.
+    ///
+    /// # Panics
+    /// The function panics if `self.nrows() != rhs.nrows()`.
+    #[doc(alias = "vdot")]
+    #[inline]
+    #[track_caller]
+    pub fn dotc<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsColRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        let rhs = rhs.as_col_ref();
+        assert!(self.nrows() == rhs.nrows());
+        let (lhs, conj_lhs) = self.as_2d().canonicalize();
+        let (rhs, conj_rhs) = rhs.as_2d().canonicalize();
+        crate::linalg::matmul::inner_prod::inner_prod_with_conj(
+            lhs,
+            conj_lhs.compose(Conj::Yes),
+            rhs,
+            conj_rhs,
+        )
+    }
+
+    /// Returns the outer product of `self` and `rhs`, the matrix whose element at `(i, j)` is
+    /// `self[i] * rhs[j]`.
+    #[inline]
+    pub fn outer<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        *self * rhs.as_row_ref()
+    }
+
+    /// Returns the maximum of `self` along with its index, or `None` if `self` is empty.
+    ///
+    /// NaN values are ignored, unless all the elements are NaN. Ties are resolved in favor of the
+    /// element with the smallest index.
+    #[inline]
+    pub fn argmax(&self) -> Option<(E, usize)>
+    where
+        E: RealField,
+    {
+        self.argmax_with_policy(crate::NanPolicy::Ignore)
+    }
+
+    /// Returns the maximum of `self` along with its index, or `None` if `self` is empty, handling
+    /// NaN values according to `nan_policy`.
+    #[inline]
+    pub fn argmax_with_policy(&self, nan_policy: crate::NanPolicy) -> Option<(E, usize)>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::extrema::argmax((*self).as_2d(), nan_policy)
+            .map(|(value, i, _)| (value, i))
+    }
+
+    /// Returns the minimum of `self` along with its index, or `None` if `self` is empty.
+    ///
+    /// NaN values are ignored, unless all the elements are NaN. Ties are resolved in favor of the
+    /// element with the smallest index.
+    #[inline]
+    pub fn argmin(&self) -> Option<(E, usize)>
+    where
+        E: RealField,
+    {
+        self.argmin_with_policy(crate::NanPolicy::Ignore)
+    }
+
+    /// Returns the minimum of `self` along with its index, or `None` if `self` is empty, handling
+    /// NaN values according to `nan_policy`.
+    #[inline]
+    pub fn argmin_with_policy(&self, nan_policy: crate::NanPolicy) -> Option<(E, usize)>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::extrema::argmin((*self).as_2d(), nan_policy)
+            .map(|(value, i, _)| (value, i))
+    }
+
+    /// Returns the maximum of `self` with respect to `compare`, along with its index, or `None`
+    /// if `self` is empty. Ties are resolved in favor of the element with the smallest index.
+    #[inline]
+    pub fn max_by(&self, compare: impl FnMut(&E, &E) -> core::cmp::Ordering) -> Option<(E, usize)> {
+        crate::linalg::reductions::extrema::max_by((*self).as_2d(), compare)
+            .map(|(value, i, _)| (value, i))
+    }
+
+    /// Returns the minimum of `self` with respect to `compare`, along with its index, or `None`
+    /// if `self` is empty. Ties are resolved in favor of the element with the smallest index.
+    #[inline]
+    pub fn min_by(&self, compare: impl FnMut(&E, &E) -> core::cmp::Ordering) -> Option<(E, usize)> {
+        crate::linalg::reductions::extrema::min_by((*self).as_2d(), compare)
+            .map(|(value, i, _)| (value, i))
+    }
+
+    /// Returns the permutation that sorts `self` in ascending order. See
+    /// [`Perm::from_argsort`](crate::perm::Perm::from_argsort).
+    ///
+    /// The sort is stable, and `NaN` values are placed at the end.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::col;
+    ///
+    /// let x = col![3.0, -1.0, 2.0f64];
+    /// let perm = x.as_ref().argsort();
+    ///
+    /// assert_eq!(perm.as_ref().arrays().0, &[1, 2, 0]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn argsort(&self) -> crate::perm::Perm<usize>
+    where
+        E: RealField,
+    {
+        crate::perm::Perm::from_argsort((*self).rb())
+    }
+
+    /// Returns the permutation that sorts `self` in ascending order. See
+    /// [`Perm::from_argsort_unstable`](crate::perm::Perm::from_argsort_unstable).
+    ///
+    /// The sort is not stable, and `NaN` values are placed at the end.
+    #[inline]
+    #[track_caller]
+    pub fn argsort_unstable(&self) -> crate::perm::Perm<usize>
+    where
+        E: RealField,
+    {
+        crate::perm::Perm::from_argsort_unstable((*self).rb())
+    }
+
     /// Kroneckor product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -582,6 +799,12 @@ impl<'a, E: Entity> core::fmt::Debug for ColRef<'a, E> {
     }
 }
 
+impl<E: Entity + core::fmt::Display> core::fmt::Display for ColRef<'_, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::utils::display::fmt_summary(f, "Col", &[self.nrows()], self.as_2d().transpose())
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<usize> for ColRef<'_, E> {
     type Output = E;
 
@@ -1,4 +1,4 @@
-use crate::{mat::*, utils::slice::*, Conj};
+use crate::{mat::*, utils::slice::*, Conj, Norm, ZeroNormPolicy};
 use coe::Coerce;
 use core::{marker::PhantomData, ptr::NonNull};
 use faer_entity::*;
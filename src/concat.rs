@@ -0,0 +1,235 @@
+//! Matrix concatenation.
+//!
+//! [`hstack`], [`vstack`] and [`block`] build a [`Mat`] from slices of matrix views, while the
+//! [`hstack!`](crate::hstack), [`vstack!`](crate::vstack) and [`block!`](crate::block) macros
+//! accept any type that can be viewed as a matrix, including column and row vectors.
+//!
+//! Unlike [`concat!`](crate::concat), [`block`] requires the blocks to form a regular grid: the
+//! blocks in the same block row must have the same number of rows, and the blocks in the same
+//! block column must have the same number of columns.
+
+use crate::{assert, mat::Mat, ComplexField, Conjugate, MatRef};
+
+/// Concatenates the given matrices horizontally.
+///
+/// # Panics
+/// Panics if the matrices don't all have the same number of rows.
+#[track_caller]
+pub fn hstack<E: Conjugate>(blocks: &[MatRef<'_, E>]) -> Mat<E::Canonical>
+where
+    E::Canonical: ComplexField,
+{
+    let nrows = blocks.first().map(|b| b.nrows()).unwrap_or(0);
+    let mut ncols = 0;
+    for b in blocks {
+        assert!(b.nrows() == nrows);
+        ncols += b.ncols();
+    }
+
+    let mut out = Mat::<E::Canonical>::zeros(nrows, ncols);
+    let mut col = 0;
+    for b in blocks {
+        out.as_mut()
+            .submatrix_mut(0, col, nrows, b.ncols())
+            .copy_from(b);
+        col += b.ncols();
+    }
+    out
+}
+
+/// Concatenates the given matrices vertically.
+///
+/// # Panics
+/// Panics if the matrices don't all have the same number of columns.
+#[track_caller]
+pub fn vstack<E: Conjugate>(blocks: &[MatRef<'_, E>]) -> Mat<E::Canonical>
+where
+    E::Canonical: ComplexField,
+{
+    let ncols = blocks.first().map(|b| b.ncols()).unwrap_or(0);
+    let mut nrows = 0;
+    for b in blocks {
+        assert!(b.ncols() == ncols);
+        nrows += b.nrows();
+    }
+
+    let mut out = Mat::<E::Canonical>::zeros(nrows, ncols);
+    let mut row = 0;
+    for b in blocks {
+        out.as_mut()
+            .submatrix_mut(row, 0, b.nrows(), ncols)
+            .copy_from(b);
+        row += b.nrows();
+    }
+    out
+}
+
+/// Builds a matrix from a grid of blocks, where `blocks[i][j]` is the block at the `i`-th block
+/// row and `j`-th block column.
+///
+/// # Panics
+/// Panics if any of the following conditions is violated:
+/// - all the block rows have the same number of blocks,
+/// - the blocks in the same block row have the same number of rows,
+/// - the blocks in the same block column have the same number of columns.
+#[track_caller]
+pub fn block<E: Conjugate>(blocks: &[&[MatRef<'_, E>]]) -> Mat<E::Canonical>
+where
+    E::Canonical: ComplexField,
+{
+    let nblock_cols = blocks.first().map(|row| row.len()).unwrap_or(0);
+    let mut nrows = 0;
+    let mut ncols = 0;
+    for (i, row) in blocks.iter().enumerate() {
+        assert!(row.len() == nblock_cols);
+        let height = row.first().map(|b| b.nrows()).unwrap_or(0);
+        for (j, b) in row.iter().enumerate() {
+            assert!(all(b.nrows() == height, b.ncols() == blocks[0][j].ncols()));
+            if i == 0 {
+                ncols += b.ncols();
+            }
+        }
+        nrows += height;
+    }
+
+    let mut out = Mat::<E::Canonical>::zeros(nrows, ncols);
+    let mut row_start = 0;
+    for row in blocks {
+        let mut col_start = 0;
+        for b in row.iter() {
+            out.as_mut()
+                .submatrix_mut(row_start, col_start, b.nrows(), b.ncols())
+                .copy_from(b);
+            col_start += b.ncols();
+        }
+        row_start += row.first().map(|b| b.nrows()).unwrap_or(0);
+    }
+    out
+}
+
+/// Concatenates the arguments horizontally. See [`concat::hstack`](crate::concat::hstack).
+///
+/// ```
+/// use faer::{col, hstack, mat};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0]];
+/// let b = col![5.0, 6.0];
+///
+/// assert_eq!(hstack![a, b], mat![[1.0, 2.0, 5.0], [3.0, 4.0, 6.0]]);
+/// ```
+#[macro_export]
+macro_rules! hstack {
+    () => {
+        {
+            compile_error!("the type of the matrix is ambiguous");
+        }
+    };
+
+    ($($v:expr),+ $(,)?) => {
+        {
+            $crate::concat::hstack(&[$($crate::mat::As2D::as_2d_ref(&$v)),+])
+        }
+    };
+}
+
+/// Concatenates the arguments vertically. See [`concat::vstack`](crate::concat::vstack).
+///
+/// ```
+/// use faer::{mat, row, vstack};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0]];
+/// let b = row![5.0, 6.0];
+///
+/// assert_eq!(vstack![a, b], mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+/// ```
+#[macro_export]
+macro_rules! vstack {
+    () => {
+        {
+            compile_error!("the type of the matrix is ambiguous");
+        }
+    };
+
+    ($($v:expr),+ $(,)?) => {
+        {
+            $crate::concat::vstack(&[$($crate::mat::As2D::as_2d_ref(&$v)),+])
+        }
+    };
+}
+
+/// Builds a matrix from a grid of blocks. See [`concat::block`](crate::concat::block).
+///
+/// ```
+/// use faer::{block, mat, Mat};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0]];
+/// let b = Mat::<f64>::zeros(2, 1);
+/// let c = mat![[5.0, 6.0]];
+/// let d = mat![[7.0]];
+///
+/// assert_eq!(
+///     block![[a, b], [c, d]],
+///     mat![[1.0, 2.0, 0.0], [3.0, 4.0, 0.0], [5.0, 6.0, 7.0]],
+/// );
+/// ```
+#[macro_export]
+macro_rules! block {
+    () => {
+        {
+            compile_error!("the type of the matrix is ambiguous");
+        }
+    };
+
+    ($([$($v:expr),* $(,)?] ),+ $(,)?) => {
+        {
+            $crate::concat::block(&[$(&[$($crate::mat::As2D::as_2d_ref(&$v)),*] as &[_]),+])
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, col, mat, row};
+
+    #[test]
+    fn test_stack() {
+        let a = mat![[1.0, 2.0], [3.0, 4.0]];
+        let b = col![5.0, 6.0];
+        let c = row![7.0, 8.0];
+
+        let h = hstack![a, b, a.as_ref()];
+        assert!(h == mat![[1.0, 2.0, 5.0, 1.0, 2.0], [3.0, 4.0, 6.0, 3.0, 4.0]]);
+        let v = vstack![a, c];
+        assert!(v == mat![[1.0, 2.0], [3.0, 4.0], [7.0, 8.0]]);
+
+        assert!(hstack::<f64>(&[]).nrows() == 0);
+        assert!(
+            vstack(&[a.as_ref(), a.transpose()])
+                == mat![[1.0, 2.0], [3.0, 4.0], [1.0, 3.0], [2.0, 4.0]]
+        );
+    }
+
+    #[test]
+    fn test_block() {
+        let a = mat![[1.0, 2.0], [3.0, 4.0]];
+        let b = col![5.0, 6.0];
+        let c = row![7.0, 8.0];
+        let d = mat![[9.0]];
+
+        let m = block![[a, b], [c, d]];
+        assert!(m == mat![[1.0, 2.0, 5.0], [3.0, 4.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let m = block(&[&[a.as_ref()], &[c.as_2d()]]);
+        assert!(m == vstack![a, c]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_block_misaligned() {
+        let a = mat![[1.0, 2.0], [3.0, 4.0]];
+        let b = col![5.0, 6.0];
+        let c = mat![[7.0, 8.0, 9.0]];
+        let _ = block![[a, b], [c, b]];
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use crate::col::{Col, ColMut, ColRef};
+use crate::col::{Col, ColBatch, ColBatchMut, ColMut, ColRef};
 
 /// Diagonal matrix.
 pub struct Diag<E: Entity> {
@@ -40,4 +40,94 @@ impl<E: Entity> Diag<E> {
             inner: self.inner.as_mut(),
         }
     }
+
+    /// Returns the dimension of the matrix.
+    #[inline(always)]
+    pub fn dim(&self) -> usize {
+        self.inner.nrows()
+    }
+}
+
+impl<E: ComplexField> Diag<E> {
+    /// Returns the diagonal matrix whose diagonal elements are `f(d)` for each diagonal element
+    /// `d` of `self`.
+    #[inline]
+    pub fn map(&self, f: impl Fn(E) -> E) -> Diag<E> {
+        self.as_ref().map(f)
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// Zero diagonal elements produce non-finite values.
+    #[inline]
+    pub fn inverse(&self) -> Diag<E> {
+        self.as_ref().inverse()
+    }
+
+    /// Returns the principal square root of `self`, computed elementwise.
+    #[inline]
+    pub fn sqrt(&self) -> Diag<E> {
+        self.as_ref().sqrt()
+    }
+
+    /// Returns `self` raised to the real power `exponent`, computed elementwise.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn powf(&self, exponent: E) -> Diag<E>
+    where
+        E: RealField + num_traits::Float,
+    {
+        self.as_ref().powf(exponent)
+    }
+
+    /// Returns the sum of the diagonal elements of `self`.
+    #[inline]
+    pub fn sum(&self) -> E {
+        self.as_ref().sum()
+    }
+
+    /// Returns the product of the diagonal elements of `self`.
+    #[inline]
+    pub fn prod(&self) -> E {
+        self.as_ref().prod()
+    }
+
+    /// Returns the largest diagonal element of `self`, or `None` if `self` is empty. See
+    /// [`DiagRef::max`].
+    #[inline]
+    pub fn max(&self) -> Option<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().max()
+    }
+
+    /// Returns the smallest diagonal element of `self`, or `None` if `self` is empty. See
+    /// [`DiagRef::min`].
+    #[inline]
+    pub fn min(&self) -> Option<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().min()
+    }
+
+    /// Solves the equation `self * X = rhs`, and stores the result in `rhs`.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve_in_place(&self, rhs: impl ColBatchMut<E>) {
+        self.as_ref().solve_in_place(rhs)
+    }
+
+    /// Solves the equation `self * X = rhs`, and returns the result.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(&self, rhs: B) -> B::Owned {
+        self.as_ref().solve(rhs)
+    }
 }
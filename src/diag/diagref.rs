@@ -1,5 +1,9 @@
 use super::*;
-use crate::col::ColRef;
+use crate::{
+    assert,
+    col::{Col, ColBatch, ColBatchMut, ColRef},
+    mat::{As2D, As2DMut},
+};
 
 /// Diagonal matrix view.
 pub struct DiagRef<'a, E: Entity> {
@@ -18,6 +22,115 @@ impl<'a, E: Entity> DiagRef<'a, E> {
     pub fn as_ref(&self) -> DiagRef<'_, E> {
         *self
     }
+
+    /// Returns the dimension of the matrix.
+    #[inline(always)]
+    pub fn dim(&self) -> usize {
+        self.inner.nrows()
+    }
+}
+
+impl<E: ComplexField> DiagRef<'_, E> {
+    /// Returns the diagonal matrix whose diagonal elements are `f(d)` for each diagonal element
+    /// `d` of `self`.
+    #[inline]
+    pub fn map(&self, f: impl Fn(E) -> E) -> Diag<E> {
+        Diag {
+            inner: Col::from_fn(self.dim(), |i| f(self.inner.read(i))),
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// Zero diagonal elements produce non-finite values.
+    #[inline]
+    pub fn inverse(&self) -> Diag<E> {
+        self.map(E::faer_inv)
+    }
+
+    /// Returns the principal square root of `self`, computed elementwise.
+    #[inline]
+    pub fn sqrt(&self) -> Diag<E> {
+        self.map(E::faer_sqrt)
+    }
+
+    /// Returns `self` raised to the real power `exponent`, computed elementwise.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn powf(&self, exponent: E) -> Diag<E>
+    where
+        E: RealField + num_traits::Float,
+    {
+        self.map(|d| num_traits::Float::powf(d, exponent))
+    }
+
+    /// Returns the sum of the diagonal elements of `self`.
+    #[inline]
+    pub fn sum(&self) -> E {
+        self.inner.sum()
+    }
+
+    /// Returns the product of the diagonal elements of `self`.
+    #[inline]
+    pub fn prod(&self) -> E {
+        let mut prod = E::faer_one();
+        for i in 0..self.dim() {
+            prod = prod.faer_mul(self.inner.read(i));
+        }
+        prod
+    }
+
+    /// Returns the largest diagonal element of `self`, or `None` if `self` is empty.
+    ///
+    /// NaN values are ignored, unless all the elements are NaN.
+    #[inline]
+    pub fn max(&self) -> Option<E>
+    where
+        E: RealField,
+    {
+        self.inner.argmax().map(|(max, _)| max)
+    }
+
+    /// Returns the smallest diagonal element of `self`, or `None` if `self` is empty.
+    ///
+    /// NaN values are ignored, unless all the elements are NaN.
+    #[inline]
+    pub fn min(&self) -> Option<E>
+    where
+        E: RealField,
+    {
+        self.inner.argmin().map(|(min, _)| min)
+    }
+
+    /// Solves the equation `self * X = rhs`, and stores the result in `rhs`.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve_in_place(&self, rhs: impl ColBatchMut<E>) {
+        let mut rhs = rhs;
+        let mut rhs = rhs.as_2d_mut();
+        assert!(rhs.nrows() == self.dim());
+
+        for i in 0..self.dim() {
+            let inv = self.inner.read(i).faer_inv();
+            for j in 0..rhs.ncols() {
+                rhs.write(i, j, rhs.read(i, j).faer_mul(inv));
+            }
+        }
+    }
+
+    /// Solves the equation `self * X = rhs`, and returns the result.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(&self, rhs: B) -> B::Owned {
+        let mut rhs = B::new_owned_copied(&rhs);
+        self.solve_in_place(rhs.as_2d_mut());
+        rhs
+    }
 }
 
 impl<E: Entity> Clone for DiagRef<'_, E> {
@@ -9,3 +9,59 @@ pub use diagmut::DiagMut;
 
 mod diagown;
 pub use diagown::Diag;
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert, col, mat, Col, Mat};
+
+    #[test]
+    fn test_diag_arithmetic() {
+        let d = col![1.0, 4.0, 16.0].column_vector_into_diagonal();
+        let e = col![2.0, 2.0, 2.0].column_vector_into_diagonal();
+        assert!(d.dim() == 3);
+
+        let sum = &d + &e;
+        assert!(sum.column_vector() == col![3.0, 6.0, 18.0]);
+        assert!(d.sqrt().column_vector() == col![1.0, 2.0, 4.0]);
+        assert!(d.powf(0.5) == d.sqrt());
+        assert!(d.inverse().column_vector() == col![1.0, 0.25, 0.0625]);
+
+        let rhs = mat![[1.0, 2.0], [4.0, 8.0], [16.0, 32.0]];
+        let sol = d.solve(&rhs);
+        assert!(sol == mat![[1.0, 2.0], [1.0, 2.0], [1.0, 2.0]]);
+        assert!(d.as_ref() * &sol == rhs);
+        assert!(&sol * e.as_ref() == Mat::from_fn(3, 2, |_, j| 2.0 * (j + 1) as f64));
+
+        let mut x = Col::<f64>::from_fn(3, |i| rhs.read(i, 0));
+        d.solve_in_place(x.as_mut());
+        assert!(x == col![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_diag_reductions() {
+        let d = col![2.0, -3.0, 4.0].column_vector_into_diagonal();
+        assert!(d.sum() == 3.0);
+        assert!(d.prod() == -24.0);
+        assert!(d.max() == Some(4.0));
+        assert!(d.min() == Some(-3.0));
+
+        let empty = Col::<f64>::zeros(0).column_vector_into_diagonal();
+        assert!(empty.sum() == 0.0);
+        assert!(empty.prod() == 1.0);
+        assert!(empty.max().is_none());
+
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        assert!(a.trace() == 6.0);
+        assert!(a.diag_product() == 5.0);
+        assert!(a.as_ref().trace() == a.diagonal().sum());
+
+        let b = Mat::from_fn(400, 400, |i, j| if i == j { 10.0 } else { 1.0 });
+        let (sign, log) = b.diag_log_product();
+        assert!(sign == 1.0);
+        assert!((log - 400.0 * 10.0f64.ln()).abs() < 1e-9);
+        assert!(b.diag_product().is_infinite());
+
+        let (sign, log) = mat![[1.0, 0.0], [0.0, 0.0]].diag_log_product();
+        assert!(sign == 0.0 && log == f64::NEG_INFINITY);
+    }
+}
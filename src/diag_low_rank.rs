@@ -0,0 +1,524 @@
+use crate::{
+    assert,
+    col::{Col, ColRef},
+    diag::{Diag, DiagRef},
+    get_global_parallelism,
+    linalg::{
+        matmul::matmul_with_conj,
+        solvers::{PartialPivLu, SolverCore, SpSolverCore},
+        temp_mat_req, temp_mat_uninit,
+    },
+    linop::{BiLinOp, LinOp},
+    mat::{Mat, MatMut, MatRef},
+    utils::scratch::ScratchBuffer,
+    ComplexField, Conj, Conjugate, Entity, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+/// Matrix of the form `D + U * C * V^H`, where `D` is an `n×n` diagonal matrix, `U` and `V` are
+/// `n×k` matrices, and `C` is a `k×k` core matrix, with `k` typically much smaller than `n`.
+///
+/// Products are computed without forming the dense matrix, and systems are solved with the
+/// Woodbury identity, which only requires factorizing a `k×k` matrix.
+#[derive(Clone, Debug)]
+pub struct DiagLowRank<E: Entity> {
+    diag: Diag<E>,
+    u: Mat<E>,
+    core: Mat<E>,
+    v: Mat<E>,
+}
+
+/// Factorization of a [`DiagLowRank`] matrix based on the Woodbury identity
+/// $$(D + UCV^H)^{-1} = D^{-1} - D^{-1}UC(I + V^HD^{-1}UC)^{-1}V^HD^{-1}.$$
+///
+/// Only the `k×k` capacitance matrix $I + V^HD^{-1}UC$ is factorized, so the core matrix `C` is
+/// allowed to be singular, but the diagonal `D` must be invertible.
+pub struct Woodbury<E: Entity> {
+    inner: DiagLowRank<E>,
+    capacitance: PartialPivLu<E>,
+}
+
+impl<E: Entity> DiagLowRank<E> {
+    /// Creates the matrix `diag + u * core * v^H`.
+    ///
+    /// # Panics
+    /// Panics if `u` and `v` don't have the same dimensions, if `u.nrows()` is not equal to the
+    /// dimension of `diag`, or if `core` is not a square matrix of dimension `u.ncols()`.
+    #[track_caller]
+    pub fn new(diag: Diag<E>, u: Mat<E>, core: Mat<E>, v: Mat<E>) -> Self {
+        let n = diag.column_vector().nrows();
+        let k = u.ncols();
+        assert!(all(
+            u.nrows() == n,
+            v.nrows() == n,
+            v.ncols() == k,
+            core.nrows() == k,
+            core.ncols() == k,
+        ));
+        Self { diag, u, core, v }
+    }
+
+    /// Returns the dimension of the matrix.
+    #[inline(always)]
+    pub fn dim(&self) -> usize {
+        self.u.nrows()
+    }
+
+    /// Returns the rank `k` of the low-rank term.
+    #[inline(always)]
+    pub fn rank(&self) -> usize {
+        self.u.ncols()
+    }
+
+    /// Returns a view over the diagonal term `D`.
+    #[inline(always)]
+    pub fn diagonal(&self) -> DiagRef<'_, E> {
+        self.diag.as_ref()
+    }
+
+    /// Returns a view over the left factor `U`.
+    #[inline(always)]
+    pub fn u(&self) -> MatRef<'_, E> {
+        self.u.as_ref()
+    }
+
+    /// Returns a view over the core matrix `C`.
+    #[inline(always)]
+    pub fn core(&self) -> MatRef<'_, E> {
+        self.core.as_ref()
+    }
+
+    /// Returns a view over the right factor `V`.
+    #[inline(always)]
+    pub fn v(&self) -> MatRef<'_, E> {
+        self.v.as_ref()
+    }
+
+    /// Returns the diagonal term, the left factor, the core matrix and the right factor.
+    #[inline(always)]
+    pub fn into_parts(self) -> (Diag<E>, Mat<E>, Mat<E>, Mat<E>) {
+        (self.diag, self.u, self.core, self.v)
+    }
+}
+
+impl<E: ComplexField> DiagLowRank<E> {
+    /// Returns the matrix as a dense matrix.
+    pub fn to_mat(&self) -> Mat<E> {
+        let mut out = &self.u * &self.core * self.v.adjoint();
+        let d = self.diag.column_vector();
+        for i in 0..self.dim() {
+            out.write(i, i, out.read(i, i).faer_add(d.read(i)));
+        }
+        out
+    }
+
+    /// Returns the Woodbury factorization of `self`.
+    pub fn woodbury(&self) -> Woodbury<E> {
+        self.clone().into_woodbury()
+    }
+
+    /// Returns the Woodbury factorization of `self`, reusing its storage.
+    pub fn into_woodbury(self) -> Woodbury<E> {
+        let k = self.rank();
+        let d = self.diag.column_vector();
+
+        // I + V^H D^{-1} U C
+        let d_inv_u = Mat::<E>::from_fn(self.dim(), k, |i, j| {
+            self.u.read(i, j).faer_mul(d.read(i).faer_inv())
+        });
+        let mut capacitance = self.v.adjoint() * d_inv_u * &self.core;
+        for i in 0..k {
+            capacitance.write(i, i, capacitance.read(i, i).faer_add(E::faer_one()));
+        }
+
+        Woodbury {
+            capacitance: PartialPivLu::new(capacitance.as_ref()),
+            inner: self,
+        }
+    }
+
+    /// Returns the solution of the equation `self * X = rhs`.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: MatRef<'_, ViewE>) -> Mat<E> {
+        let mut out = rhs.to_owned();
+        self.woodbury()
+            .solve_in_place_with_conj_impl(out.as_mut(), Conj::No);
+        out
+    }
+
+    /// Returns the determinant of `self`, computed with the matrix determinant lemma
+    /// $$\det(D + UCV^H) = \det(D) \det(I + V^HD^{-1}UC).$$
+    pub fn determinant(&self) -> E {
+        self.woodbury().determinant()
+    }
+
+    // computes `op(self) * rhs`, where `op` is the transpose if `transpose` is `true`, followed by
+    // the conjugate if `conj` is `Conj::Yes`
+    fn apply_impl(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        transpose: bool,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let n = self.dim();
+        let k = self.rank();
+        assert!(all(
+            out.nrows() == n,
+            rhs.nrows() == n,
+            out.ncols() == rhs.ncols(),
+        ));
+
+        // op(U C V^H) = L M R^T
+        let (left, mid, right) = if transpose {
+            (self.v.as_ref(), self.core.transpose(), self.u.as_ref())
+        } else {
+            (self.u.as_ref(), self.core.as_ref(), self.v.as_ref())
+        };
+        let left_conj = if transpose { Conj::Yes } else { Conj::No }.compose(conj);
+        let right_conj = left_conj.compose(Conj::Yes);
+
+        let mut out = out;
+        let (mut tmp0, stack) = temp_mat_uninit::<E>(k, rhs.ncols(), stack);
+        let (mut tmp1, _) = temp_mat_uninit::<E>(k, rhs.ncols(), stack);
+        matmul_with_conj(
+            tmp0.rb_mut(),
+            right.transpose(),
+            right_conj,
+            rhs,
+            Conj::No,
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+        matmul_with_conj(
+            tmp1.rb_mut(),
+            mid,
+            conj,
+            tmp0.rb(),
+            Conj::No,
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+
+        let d = self.diag.column_vector();
+        for j in 0..rhs.ncols() {
+            for i in 0..n {
+                let d = d.read(i);
+                let d = if conj == Conj::Yes { d.faer_conj() } else { d };
+                out.write(i, j, d.faer_mul(rhs.read(i, j)));
+            }
+        }
+        matmul_with_conj(
+            out.rb_mut(),
+            left,
+            left_conj,
+            tmp1.rb(),
+            Conj::No,
+            Some(E::faer_one()),
+            E::faer_one(),
+            parallelism,
+        );
+    }
+}
+
+impl<E: ComplexField> LinOp<E> for DiagLowRank<E> {
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        _ = parallelism;
+        let tmp = temp_mat_req::<E>(self.rank(), rhs_ncols)?;
+        StackReq::try_all_of([tmp, tmp])
+    }
+
+    fn nrows(&self) -> usize {
+        self.dim()
+    }
+
+    fn ncols(&self) -> usize {
+        self.dim()
+    }
+
+    #[track_caller]
+    fn apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.apply_impl(out, rhs, false, Conj::No, parallelism, stack)
+    }
+
+    #[track_caller]
+    fn conj_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.apply_impl(out, rhs, false, Conj::Yes, parallelism, stack)
+    }
+}
+
+impl<E: ComplexField> BiLinOp<E> for DiagLowRank<E> {
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.apply_req(rhs_ncols, parallelism)
+    }
+
+    #[track_caller]
+    fn transpose_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.apply_impl(out, rhs, true, Conj::No, parallelism, stack)
+    }
+
+    #[track_caller]
+    fn adjoint_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.apply_impl(out, rhs, true, Conj::Yes, parallelism, stack)
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<MatRef<'_, E>> for &DiagLowRank<E> {
+    type Output = Mat<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: MatRef<'_, E>) -> Self::Output {
+        let parallelism = get_global_parallelism();
+        let mut out = Mat::<E>::zeros(self.dim(), rhs.ncols());
+        self.apply(
+            out.as_mut(),
+            rhs,
+            parallelism,
+            PodStack::new(&mut ScratchBuffer::new(
+                self.apply_req(rhs.ncols(), parallelism).unwrap(),
+            )),
+        );
+        out
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<ColRef<'_, E>> for &DiagLowRank<E> {
+    type Output = Col<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: ColRef<'_, E>) -> Self::Output {
+        let out = self * rhs.as_2d();
+        Col::from_fn(out.nrows(), |i| out.read(i, 0))
+    }
+}
+
+impl<E: ComplexField> Woodbury<E> {
+    /// Returns the determinant of the factorized matrix.
+    pub fn determinant(&self) -> E {
+        let factor = self.capacitance.compute_u();
+        let mut det = E::faer_one();
+        for i in 0..factor.nrows() {
+            det = det.faer_mul(factor.read(i, i));
+        }
+        if self.capacitance.transposition_count() % 2 == 1 {
+            det = det.faer_neg();
+        }
+        let d = self.inner.diag.column_vector();
+        for i in 0..self.inner.dim() {
+            det = det.faer_mul(d.read(i));
+        }
+        det
+    }
+
+    // solves `op(A) * X = rhs` in place, where `op` is the identity or the transpose
+    fn solve_impl(&self, rhs: MatMut<'_, E>, transpose: bool) {
+        let DiagLowRank { diag, u, core, v } = &self.inner;
+        let n = self.inner.dim();
+        assert!(rhs.nrows() == n);
+        let d = diag.column_vector();
+
+        let mut rhs = rhs;
+        let scale_by_d_inv = |mut x: MatMut<'_, E>| {
+            for j in 0..x.ncols() {
+                for i in 0..n {
+                    x.write(i, j, x.read(i, j).faer_mul(d.read(i).faer_inv()));
+                }
+            }
+        };
+
+        // x = D^{-1} b - D^{-1} U C K^{-1} V^H D^{-1} b
+        // x = D^{-1} b - D^{-1} conj(V) K^{-T} C^T U^T D^{-1} b
+        scale_by_d_inv(rhs.rb_mut());
+        let mut tmp = if transpose {
+            core.transpose() * (u.transpose() * rhs.rb())
+        } else {
+            v.adjoint() * rhs.rb()
+        };
+        if transpose {
+            self.capacitance
+                .solve_transpose_in_place_with_conj_impl(tmp.as_mut(), Conj::No);
+        } else {
+            self.capacitance
+                .solve_in_place_with_conj_impl(tmp.as_mut(), Conj::No);
+        }
+        let mut w = if transpose {
+            v.conjugate() * &tmp
+        } else {
+            u * (core * &tmp)
+        };
+        scale_by_d_inv(w.as_mut());
+        for j in 0..rhs.ncols() {
+            for i in 0..n {
+                rhs.write(i, j, rhs.read(i, j).faer_sub(w.read(i, j)));
+            }
+        }
+    }
+
+    fn solve_with_conj_impl(&self, rhs: MatMut<'_, E>, transpose: bool, conj: Conj) {
+        // conj(A)^{-1} b = conj(A^{-1} conj(b))
+        let mut rhs = rhs;
+        let conjugate = |mut x: MatMut<'_, E>| {
+            for j in 0..x.ncols() {
+                for i in 0..x.nrows() {
+                    x.write(i, j, x.read(i, j).faer_conj());
+                }
+            }
+        };
+        if conj == Conj::Yes {
+            conjugate(rhs.rb_mut());
+        }
+        self.solve_impl(rhs.rb_mut(), transpose);
+        if conj == Conj::Yes {
+            conjugate(rhs.rb_mut());
+        }
+    }
+}
+
+impl<E: ComplexField> SpSolverCore<E> for Woodbury<E> {
+    #[track_caller]
+    fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        self.solve_with_conj_impl(rhs, false, conj)
+    }
+
+    #[track_caller]
+    fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        self.solve_with_conj_impl(rhs, true, conj)
+    }
+
+    fn nrows(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn ncols(&self) -> usize {
+        self.inner.dim()
+    }
+}
+
+impl<E: ComplexField> SolverCore<E> for Woodbury<E> {
+    fn reconstruct(&self) -> Mat<E> {
+        self.inner.to_mat()
+    }
+
+    fn inverse(&self) -> Mat<E> {
+        let n = self.inner.dim();
+        let mut inv = Mat::<E>::identity(n, n);
+        self.solve_in_place_with_conj_impl(inv.as_mut(), Conj::No);
+        inv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::solvers::SpSolver};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_diag_low_rank() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let parallelism = Parallelism::Rayon(4);
+        let n = 30;
+        let k = 4;
+
+        let mut random =
+            |m: usize, n: usize| Mat::<c64>::from_fn(m, n, |_, _| c64::new(rng.gen(), rng.gen()));
+        let u = random(n, k);
+        let v = random(n, k);
+        let core = random(k, k);
+        let diag = Col::<c64>::from_fn(n, |i| c64::new(2.0 + i as f64, 1.0));
+        let a = DiagLowRank::new(diag.column_vector_into_diagonal(), u, core, v);
+        let dense = a.to_mat();
+
+        let rhs = random(n, 3);
+        assert!((&a * rhs.as_ref() - &dense * &rhs).norm_max() < 1e-10);
+        for (transpose, conj) in [(false, true), (true, false), (true, true)] {
+            let mut out = Mat::<c64>::zeros(n, 3);
+            let stack = &mut GlobalPodBuffer::new(a.apply_req(3, parallelism).unwrap());
+            let expected = match (transpose, conj) {
+                (false, true) => {
+                    a.conj_apply(
+                        out.as_mut(),
+                        rhs.as_ref(),
+                        parallelism,
+                        PodStack::new(stack),
+                    );
+                    dense.conjugate() * &rhs
+                }
+                (true, false) => {
+                    a.transpose_apply(
+                        out.as_mut(),
+                        rhs.as_ref(),
+                        parallelism,
+                        PodStack::new(stack),
+                    );
+                    dense.transpose() * &rhs
+                }
+                _ => {
+                    a.adjoint_apply(
+                        out.as_mut(),
+                        rhs.as_ref(),
+                        parallelism,
+                        PodStack::new(stack),
+                    );
+                    dense.adjoint() * &rhs
+                }
+            };
+            assert!((&out - &expected).norm_max() < 1e-10);
+        }
+
+        let sol = a.solve(rhs.as_ref());
+        assert!((&dense * &sol - &rhs).norm_max() < 1e-10);
+
+        let woodbury = a.woodbury();
+        let sol = woodbury.solve_transpose(&rhs);
+        assert!((dense.transpose() * &sol - &rhs).norm_max() < 1e-10);
+        let sol = woodbury.solve_conj(&rhs);
+        assert!((dense.conjugate() * &sol - &rhs).norm_max() < 1e-10);
+        assert!((woodbury.inverse() * &dense - Mat::<c64>::identity(n, n)).norm_max() < 1e-10);
+
+        let det = a.determinant();
+        let expected = dense.determinant();
+        assert!((det - expected).faer_abs() < 1e-8 * expected.faer_abs());
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{
+    assert,
+    col::{Col, ColRef},
+    get_global_parallelism,
+    linop::{BiLinOp, LinOp},
+    mat::{Mat, MatMut, MatRef},
+    row::{Row, RowRef},
+    toeplitz::Toeplitz,
+    utils::scratch::ScratchBuffer,
+    ComplexField, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// Square Hankel matrix, whose entries are constant along each anti-diagonal, so that the entry
+/// at `(i, j)` only depends on `i + j`.
+///
+/// A Hankel matrix `H` is stored as the Toeplitz matrix `T = H * J`, where `J` is the exchange
+/// matrix that reverses the order of the rows, which allows computing products in `O(n log n)`
+/// operations.
+#[derive(Clone, Debug)]
+pub struct Hankel<E: ComplexField> {
+    first_col: Col<E>,
+    last_row: Row<E>,
+    toeplitz: Toeplitz<E>,
+}
+
+impl<E: ComplexField> Hankel<E> {
+    /// Creates a Hankel matrix from its first column and last row. The first element of
+    /// `last_row` is ignored, and the bottom-left element is taken from `first_col` instead.
+    ///
+    /// # Panics
+    /// Panics if `first_col.nrows() != last_row.ncols()`.
+    #[track_caller]
+    pub fn new(first_col: Col<E>, last_row: Row<E>) -> Self {
+        let n = first_col.nrows();
+        assert!(last_row.ncols() == n);
+
+        // T[i, j] = H[i, n - 1 - j]
+        let toeplitz = Toeplitz::new(
+            Col::from_fn(n, |i| {
+                if i == 0 {
+                    first_col.read(n - 1)
+                } else {
+                    last_row.read(i)
+                }
+            }),
+            Row::from_fn(n, |j| first_col.read(n - 1 - j)),
+        );
+
+        Self {
+            first_col,
+            last_row,
+            toeplitz,
+        }
+    }
+
+    /// Returns the dimension of the matrix.
+    #[inline(always)]
+    pub fn dim(&self) -> usize {
+        self.first_col.nrows()
+    }
+
+    /// Returns a view over the first column of the matrix.
+    #[inline(always)]
+    pub fn first_col(&self) -> ColRef<'_, E> {
+        self.first_col.as_ref()
+    }
+
+    /// Returns a view over the last row of the matrix. Its first element is not used.
+    #[inline(always)]
+    pub fn last_row(&self) -> RowRef<'_, E> {
+        self.last_row.as_ref()
+    }
+
+    /// Reads the value at the given indices.
+    ///
+    /// # Panics
+    /// Panics if `row >= self.dim()` or `col >= self.dim()`.
+    #[inline]
+    #[track_caller]
+    pub fn read(&self, row: usize, col: usize) -> E {
+        let n = self.dim();
+        assert!(all(row < n, col < n));
+        if row + col < n {
+            self.first_col.read(row + col)
+        } else {
+            self.last_row.read(row + col + 1 - n)
+        }
+    }
+
+    /// Returns the matrix as a dense matrix.
+    pub fn to_mat(&self) -> Mat<E> {
+        let n = self.dim();
+        Mat::from_fn(n, n, |i, j| self.read(i, j))
+    }
+
+    /// Returns a view over the Toeplitz matrix `self * J`, where `J` is the exchange matrix, i.e.,
+    /// `self` with its columns in reverse order.
+    #[inline(always)]
+    pub fn as_toeplitz(&self) -> &Toeplitz<E> {
+        &self.toeplitz
+    }
+
+    /// Returns the Toeplitz matrix `self * J`, where `J` is the exchange matrix, i.e., `self` with
+    /// its columns in reverse order.
+    #[inline(always)]
+    pub fn into_toeplitz(self) -> Toeplitz<E> {
+        self.toeplitz
+    }
+}
+
+impl<E: ComplexField> LinOp<E> for Hankel<E> {
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.toeplitz.apply_req(rhs_ncols, parallelism)
+    }
+
+    fn nrows(&self) -> usize {
+        self.dim()
+    }
+
+    fn ncols(&self) -> usize {
+        self.dim()
+    }
+
+    #[track_caller]
+    fn apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        // H x = T (J x)
+        self.toeplitz
+            .apply(out, rhs.reverse_rows(), parallelism, stack)
+    }
+
+    #[track_caller]
+    fn conj_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.toeplitz
+            .conj_apply(out, rhs.reverse_rows(), parallelism, stack)
+    }
+}
+
+// hankel matrices are symmetric
+impl<E: ComplexField> BiLinOp<E> for Hankel<E> {
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.apply_req(rhs_ncols, parallelism)
+    }
+
+    #[track_caller]
+    fn transpose_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.apply(out, rhs, parallelism, stack)
+    }
+
+    #[track_caller]
+    fn adjoint_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.conj_apply(out, rhs, parallelism, stack)
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<MatRef<'_, E>> for &Hankel<E> {
+    type Output = Mat<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: MatRef<'_, E>) -> Self::Output {
+        let parallelism = get_global_parallelism();
+        let mut out = Mat::<E>::zeros(self.dim(), rhs.ncols());
+        self.apply(
+            out.as_mut(),
+            rhs,
+            parallelism,
+            PodStack::new(&mut ScratchBuffer::new(
+                self.apply_req(rhs.ncols(), parallelism).unwrap(),
+            )),
+        );
+        out
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<ColRef<'_, E>> for &Hankel<E> {
+    type Output = Col<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: ColRef<'_, E>) -> Self::Output {
+        let out = self * rhs.as_2d();
+        Col::from_fn(out.nrows(), |i| out.read(i, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_hankel() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let parallelism = Parallelism::Rayon(4);
+        for n in [0, 1, 2, 3, 10, 33] {
+            let h = Hankel::new(
+                Col::from_fn(n, |_| c64::new(rng.gen(), rng.gen())),
+                Row::from_fn(n, |_| c64::new(rng.gen(), rng.gen())),
+            );
+            let dense = h.to_mat();
+            assert!(dense == dense.transpose().to_owned());
+            for i in 0..n {
+                assert!(dense.read(i, 0) == h.first_col().read(i));
+                if i > 0 {
+                    assert!(dense.read(n - 1, i) == h.last_row().read(i));
+                }
+            }
+
+            let toeplitz = h.as_toeplitz().to_mat();
+            assert!(toeplitz == dense.as_ref().reverse_cols().to_owned());
+
+            let rhs = Mat::<c64>::from_fn(n, 3, |_, _| c64::new(rng.gen(), rng.gen()));
+            assert!((&h * rhs.as_ref() - &dense * &rhs).norm_max() < 1e-10);
+
+            let mut out = Mat::<c64>::zeros(n, 3);
+            h.adjoint_apply(
+                out.as_mut(),
+                rhs.as_ref(),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    h.transpose_apply_req(3, parallelism).unwrap(),
+                )),
+            );
+            assert!((&out - dense.adjoint() * &rhs).norm_max() < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,583 @@
+//! Zero-copy archival of dense and sparse matrices with [`rkyv`](::rkyv).
+//!
+//! [`Mat`] implements [`Archive`], and its archived form [`ArchivedMat`] can be viewed as a
+//! [`MatRef`] pointing into the archive's buffer, without deserializing it. The same holds for
+//! [`SparseColMat`] and [`SparseRowMat`], whose archived forms can be viewed as
+//! [`SparseColMatRef`] and [`SparseRowMatRef`]. This makes it possible to memory-map a large
+//! precomputed operator and use it directly.
+//!
+//! Dense matrices are stored in column-major order. Sparse matrices are stored in compressed form,
+//! even if they were uncompressed, and their indices are stored as [`Index::FixedWidth`], i.e.
+//! `usize` indices are stored as `u32` or `u64` depending on the platform, so archives of such
+//! matrices are not portable across pointer widths. The scalars are stored in the native byte
+//! order.
+//!
+//! Archives from untrusted sources should be accessed with [`rkyv::check_archived_root`], which
+//! validates the archive, including the structure of sparse matrices.
+//!
+//! ```
+//! use faer::{mat, Mat};
+//!
+//! let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+//! let bytes = rkyv::to_bytes::<_, 256>(&a).unwrap();
+//!
+//! let archived = rkyv::check_archived_root::<Mat<f64>>(&bytes).unwrap();
+//! let view = archived.as_ref();
+//! assert!(view == a.as_ref());
+//! ```
+//!
+//! [`rkyv::check_archived_root`]: ::rkyv::check_archived_root
+
+use crate::{
+    complex_native::{c32, c64},
+    mat::MatRef,
+    sparse::{
+        SparseColMat, SparseColMatRef, SparseRowMat, SparseRowMatRef, SymbolicSparseColMat,
+        SymbolicSparseColMatRef, SymbolicSparseRowMat, SymbolicSparseRowMatRef,
+    },
+    Index, Mat, SignedIndex,
+};
+use ::rkyv::{
+    bytecheck::CheckBytes,
+    out_field,
+    ser::{ScratchSpace, Serializer},
+    validation::ArchiveContext,
+    vec::{ArchivedVec, VecResolver},
+    Archive, Archived, Deserialize, Fallible, Serialize,
+};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use faer_entity::SimpleEntity;
+
+/// Error returned when an archived matrix fails validation.
+#[derive(Debug)]
+pub struct ArchiveError {
+    message: String,
+}
+
+impl ArchiveError {
+    fn new(message: impl fmt::Display) -> Self {
+        Self {
+            message: alloc::format!("{message}"),
+        }
+    }
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ArchiveError {}
+
+macro_rules! impl_scalar {
+    ($($ty: ty),*) => {$(
+        impl Archive for $ty {
+            type Archived = $ty;
+            type Resolver = ();
+
+            #[inline]
+            unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+                out.write(*self);
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for $ty {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: Fallible + ?Sized> Deserialize<$ty, D> for $ty {
+            #[inline]
+            fn deserialize(&self, _: &mut D) -> Result<$ty, D::Error> {
+                Ok(*self)
+            }
+        }
+
+        impl<C: ?Sized> CheckBytes<C> for $ty {
+            type Error = core::convert::Infallible;
+
+            // every bit pattern is a valid value
+            #[inline]
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                _: &mut C,
+            ) -> Result<&'a Self, Self::Error> {
+                Ok(&*value)
+            }
+        }
+    )*};
+}
+
+impl_scalar!(c32, c64);
+
+/// Archived form of [`Mat`].
+#[repr(C)]
+pub struct ArchivedMat<E> {
+    nrows: Archived<u64>,
+    ncols: Archived<u64>,
+    data: ArchivedVec<E>,
+}
+
+/// Resolver for [`ArchivedMat`].
+pub struct MatResolver {
+    data: VecResolver,
+}
+
+impl<E: SimpleEntity> ArchivedMat<E> {
+    /// Returns the number of rows of the matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows as usize
+    }
+
+    /// Returns the number of columns of the matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols as usize
+    }
+
+    /// Returns a view over the archived matrix.
+    #[inline]
+    pub fn as_ref(&self) -> MatRef<'_, E> {
+        crate::mat::from_column_major_slice::<E>(self.data.as_slice(), self.nrows(), self.ncols())
+    }
+}
+
+impl<E: SimpleEntity + Archive<Archived = E>> Archive for Mat<E> {
+    type Archived = ArchivedMat<E>;
+    type Resolver = MatResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        let (fp, fo) = out_field!(out.nrows);
+        (self.nrows() as u64).resolve(pos + fp, (), fo);
+        let (fp, fo) = out_field!(out.ncols);
+        (self.ncols() as u64).resolve(pos + fp, (), fo);
+        let (fp, fo) = out_field!(out.data);
+        ArchivedVec::resolve_from_len(self.nrows() * self.ncols(), pos + fp, resolver.data, fo);
+    }
+}
+
+impl<S, E> Serialize<S> for Mat<E>
+where
+    S: Serializer + ScratchSpace + ?Sized,
+    E: SimpleEntity + Archive<Archived = E> + Serialize<S>,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let data = (0..self.ncols())
+            .flat_map(|j| self.col_as_slice(j).iter().copied())
+            .collect::<Vec<E>>();
+        Ok(MatResolver {
+            data: ArchivedVec::serialize_from_slice(&data, serializer)?,
+        })
+    }
+}
+
+impl<D, E> Deserialize<Mat<E>, D> for ArchivedMat<E>
+where
+    D: Fallible + ?Sized,
+    E: SimpleEntity,
+{
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Mat<E>, D::Error> {
+        Ok(self.as_ref().to_owned())
+    }
+}
+
+impl<C, E> CheckBytes<C> for ArchivedMat<E>
+where
+    C: ArchiveContext + ?Sized,
+    ArchivedVec<E>: CheckBytes<C>,
+{
+    type Error = ArchiveError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        let nrows = *u64::check_bytes(core::ptr::addr_of!((*value).nrows), context).unwrap();
+        let ncols = *u64::check_bytes(core::ptr::addr_of!((*value).ncols), context).unwrap();
+        let data = ArchivedVec::<E>::check_bytes(core::ptr::addr_of!((*value).data), context)
+            .map_err(ArchiveError::new)?;
+
+        let len = usize::try_from(nrows)
+            .ok()
+            .zip(usize::try_from(ncols).ok())
+            .and_then(|(nrows, ncols)| nrows.checked_mul(ncols));
+        if len != Some(data.len()) {
+            return Err(ArchiveError::new(
+                "the length of the data doesn't match the matrix dimensions",
+            ));
+        }
+        Ok(&*value)
+    }
+}
+
+// checks the structure of a compressed sparse matrix, where `outer` is the number of columns for
+// column-major matrices, and the number of rows for row-major matrices
+fn check_compressed<I: Index>(
+    inner: u64,
+    outer: u64,
+    ptrs: &[I],
+    indices: &[I],
+    nnz: usize,
+) -> Result<(usize, usize), ArchiveError> {
+    let max = I::Signed::MAX.zx() as u64;
+    if inner > max || outer > max {
+        return Err(ArchiveError::new(
+            "the matrix dimensions exceed the maximum index value",
+        ));
+    }
+    let (inner, outer) = (inner as usize, outer as usize);
+    if ptrs.len() != outer + 1 {
+        return Err(ArchiveError::new("invalid length of the index pointers"));
+    }
+    if ptrs[0].zx() != 0
+        || ptrs.windows(2).any(|w| w[0] > w[1])
+        || ptrs[outer].zx() != indices.len()
+    {
+        return Err(ArchiveError::new("invalid index pointers"));
+    }
+    if indices.iter().any(|i| i.zx() >= inner) {
+        return Err(ArchiveError::new("indices must be less than the dimension"));
+    }
+    if ptrs.windows(2).any(|w| {
+        indices[w[0].zx()..w[1].zx()]
+            .windows(2)
+            .any(|w| w[0] > w[1])
+    }) {
+        return Err(ArchiveError::new(
+            "indices must be sorted within each column or row",
+        ));
+    }
+    if nnz != indices.len() {
+        return Err(ArchiveError::new(
+            "the number of values doesn't match the number of indices",
+        ));
+    }
+    Ok((inner, outer))
+}
+
+// sorts the indices of a column or row along with their values, since the archived structure must
+// be sorted even if the source matrix isn't
+fn sort_indices<I: Index, E: Copy>(indices: &mut [I], values: &mut [E]) {
+    if indices.windows(2).any(|w| w[0] > w[1]) {
+        let mut sorted: Vec<(I, E)> = indices
+            .iter()
+            .copied()
+            .zip(values.iter().copied())
+            .collect();
+        sorted.sort_by_key(|&(i, _)| i);
+        for ((i, value), (sorted_i, sorted_value)) in
+            indices.iter_mut().zip(values.iter_mut()).zip(sorted)
+        {
+            *i = sorted_i;
+            *value = sorted_value;
+        }
+    }
+}
+
+macro_rules! impl_sparse {
+    (
+        $archived: ident,
+        $resolver: ident,
+        $ty: ident,
+        $ref: ident,
+        $symbolic: ident,
+        $symbolic_ref: ident,
+        $ptrs: ident,
+        $indices: ident,
+        $indices_of: ident,
+        $values_of: ident,
+        $inner: ident,
+        $outer: ident $(,)?
+    ) => {
+        #[doc = concat!("Archived form of [`", stringify!($ty), "`].")]
+        #[repr(C)]
+        pub struct $archived<I: Index, E> {
+            nrows: Archived<u64>,
+            ncols: Archived<u64>,
+            $ptrs: ArchivedVec<I::FixedWidth>,
+            $indices: ArchivedVec<I::FixedWidth>,
+            values: ArchivedVec<E>,
+        }
+
+        #[doc = concat!("Resolver for [`", stringify!($archived), "`].")]
+        pub struct $resolver {
+            $ptrs: VecResolver,
+            $indices: VecResolver,
+            values: VecResolver,
+        }
+
+        impl<I: Index, E: SimpleEntity> $archived<I, E> {
+            /// Returns the number of rows of the matrix.
+            #[inline]
+            pub fn nrows(&self) -> usize {
+                self.nrows as usize
+            }
+
+            /// Returns the number of columns of the matrix.
+            #[inline]
+            pub fn ncols(&self) -> usize {
+                self.ncols as usize
+            }
+
+            /// Returns a view over the archived matrix.
+            #[inline]
+            pub fn as_ref(&self) -> $ref<'_, I, E> {
+                let $ptrs = bytemuck::cast_slice::<I::FixedWidth, I>(self.$ptrs.as_slice());
+                let $indices = bytemuck::cast_slice::<I::FixedWidth, I>(self.$indices.as_slice());
+                // SAFETY: the structure, including the order of the indices, is checked on
+                // validation, and is valid by construction otherwise
+                let symbolic = unsafe {
+                    $symbolic_ref::new_unchecked(self.nrows(), self.ncols(), $ptrs, None, $indices)
+                };
+                $ref::new(symbolic, self.values.as_slice())
+            }
+        }
+
+        impl<I, E> Archive for $ty<I, E>
+        where
+            I: Index,
+            E: SimpleEntity + Archive<Archived = E>,
+            I::FixedWidth: Archive<Archived = I::FixedWidth>,
+        {
+            type Archived = $archived<I, E>;
+            type Resolver = $resolver;
+
+            #[inline]
+            unsafe fn resolve(
+                &self,
+                pos: usize,
+                resolver: Self::Resolver,
+                out: *mut Self::Archived,
+            ) {
+                let this = self.as_ref();
+                let nnz = this.compute_nnz();
+                let (fp, fo) = out_field!(out.nrows);
+                (this.nrows() as u64).resolve(pos + fp, (), fo);
+                let (fp, fo) = out_field!(out.ncols);
+                (this.ncols() as u64).resolve(pos + fp, (), fo);
+                let (fp, fo) = out_field!(out.$ptrs);
+                ArchivedVec::resolve_from_len(this.$outer() + 1, pos + fp, resolver.$ptrs, fo);
+                let (fp, fo) = out_field!(out.$indices);
+                ArchivedVec::resolve_from_len(nnz, pos + fp, resolver.$indices, fo);
+                let (fp, fo) = out_field!(out.values);
+                ArchivedVec::resolve_from_len(nnz, pos + fp, resolver.values, fo);
+            }
+        }
+
+        impl<S, I, E> Serialize<S> for $ty<I, E>
+        where
+            S: Serializer + ScratchSpace + ?Sized,
+            I: Index,
+            E: SimpleEntity + Archive<Archived = E> + Serialize<S>,
+            I::FixedWidth: Archive<Archived = I::FixedWidth> + Serialize<S>,
+        {
+            fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+                let this = self.as_ref();
+                let nnz = this.compute_nnz();
+                let mut ptrs = Vec::with_capacity(this.$outer() + 1);
+                let mut indices = Vec::with_capacity(nnz);
+                let mut values = Vec::with_capacity(nnz);
+                ptrs.push(I::truncate(0));
+                for k in 0..this.$outer() {
+                    let start = indices.len();
+                    indices.extend_from_slice(this.$indices_of(k));
+                    values.extend_from_slice(this.$values_of(k));
+                    sort_indices(&mut indices[start..], &mut values[start..]);
+                    ptrs.push(I::truncate(indices.len()));
+                }
+
+                Ok($resolver {
+                    $ptrs: ArchivedVec::serialize_from_slice(I::canonicalize(&ptrs), serializer)?,
+                    $indices: ArchivedVec::serialize_from_slice(
+                        I::canonicalize(&indices),
+                        serializer,
+                    )?,
+                    values: ArchivedVec::serialize_from_slice(&values, serializer)?,
+                })
+            }
+        }
+
+        impl<D, I, E> Deserialize<$ty<I, E>, D> for $archived<I, E>
+        where
+            D: Fallible + ?Sized,
+            I: Index,
+            E: SimpleEntity,
+        {
+            fn deserialize(&self, _: &mut D) -> Result<$ty<I, E>, D::Error> {
+                let this = self.as_ref();
+                Ok($ty::new(
+                    $symbolic::new_checked(
+                        this.nrows(),
+                        this.ncols(),
+                        this.$ptrs().to_vec(),
+                        None,
+                        this.$indices().to_vec(),
+                    ),
+                    this.values().to_vec(),
+                ))
+            }
+        }
+
+        impl<C, I, E> CheckBytes<C> for $archived<I, E>
+        where
+            C: ArchiveContext + ?Sized,
+            I: Index,
+            ArchivedVec<I::FixedWidth>: CheckBytes<C>,
+            ArchivedVec<E>: CheckBytes<C>,
+        {
+            type Error = ArchiveError;
+
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                context: &mut C,
+            ) -> Result<&'a Self, Self::Error> {
+                let nrows =
+                    *u64::check_bytes(core::ptr::addr_of!((*value).nrows), context).unwrap();
+                let ncols =
+                    *u64::check_bytes(core::ptr::addr_of!((*value).ncols), context).unwrap();
+                let ptrs = ArchivedVec::<I::FixedWidth>::check_bytes(
+                    core::ptr::addr_of!((*value).$ptrs),
+                    context,
+                )
+                .map_err(ArchiveError::new)?;
+                let indices = ArchivedVec::<I::FixedWidth>::check_bytes(
+                    core::ptr::addr_of!((*value).$indices),
+                    context,
+                )
+                .map_err(ArchiveError::new)?;
+                let values =
+                    ArchivedVec::<E>::check_bytes(core::ptr::addr_of!((*value).values), context)
+                        .map_err(ArchiveError::new)?;
+
+                check_compressed::<I>(
+                    $inner,
+                    $outer,
+                    bytemuck::cast_slice(ptrs.as_slice()),
+                    bytemuck::cast_slice(indices.as_slice()),
+                    values.len(),
+                )?;
+                Ok(&*value)
+            }
+        }
+    };
+}
+
+impl_sparse!(
+    ArchivedSparseColMat,
+    SparseColMatResolver,
+    SparseColMat,
+    SparseColMatRef,
+    SymbolicSparseColMat,
+    SymbolicSparseColMatRef,
+    col_ptrs,
+    row_indices,
+    row_indices_of_col_raw,
+    values_of_col,
+    nrows,
+    ncols,
+);
+impl_sparse!(
+    ArchivedSparseRowMat,
+    SparseRowMatResolver,
+    SparseRowMat,
+    SparseRowMatRef,
+    SymbolicSparseRowMat,
+    SymbolicSparseRowMatRef,
+    row_ptrs,
+    col_indices,
+    col_indices_of_row_raw,
+    values_of_row,
+    ncols,
+    nrows,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+
+    #[test]
+    fn test_mat() {
+        let a = Mat::<c64>::from_fn(7, 5, |i, j| c64::new(i as f64, j as f64));
+        let bytes = ::rkyv::to_bytes::<_, 256>(&a).unwrap();
+        let archived = ::rkyv::check_archived_root::<Mat<c64>>(&bytes).unwrap();
+        assert!(all(archived.nrows() == 7, archived.ncols() == 5));
+        assert!(archived.as_ref() == a.as_ref());
+
+        let b: Mat<c64> = archived.deserialize(&mut ::rkyv::Infallible).unwrap();
+        assert!(b == a);
+
+        // strided source matrices are stored contiguously
+        let at = a.transpose().to_owned();
+        let bytes = ::rkyv::to_bytes::<_, 256>(&at).unwrap();
+        let archived = ::rkyv::check_archived_root::<Mat<c64>>(&bytes).unwrap();
+        assert!(archived.as_ref() == a.transpose());
+    }
+
+    #[test]
+    fn test_sparse() {
+        let a = SparseColMat::<u32, f64>::try_new_from_triplets(
+            4,
+            3,
+            &[(0, 0, 1.0), (3, 0, -2.0), (1, 2, 3.5), (2, 2, 4.0)],
+        )
+        .unwrap();
+
+        let bytes = ::rkyv::to_bytes::<_, 256>(&a).unwrap();
+        let archived = ::rkyv::check_archived_root::<SparseColMat<u32, f64>>(&bytes).unwrap();
+        let view = archived.as_ref();
+        assert!(view.col_ptrs() == a.col_ptrs());
+        assert!(view.row_indices() == a.row_indices());
+        assert!(view.to_dense() == a.to_dense());
+
+        let b: SparseColMat<u32, f64> = archived.deserialize(&mut ::rkyv::Infallible).unwrap();
+        assert!(b.to_dense() == a.to_dense());
+
+        let a = a.to_row_major().unwrap();
+        let bytes = ::rkyv::to_bytes::<_, 256>(&a).unwrap();
+        let archived = ::rkyv::check_archived_root::<SparseRowMat<u32, f64>>(&bytes).unwrap();
+        assert!(archived.as_ref().to_dense() == a.to_dense());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let check = |inner, outer, ptrs: &[u32], indices: &[u32], nnz| {
+            check_compressed::<u32>(inner, outer, ptrs, indices, nnz).is_ok()
+        };
+        assert!(check(2, 2, &[0, 0, 1], &[1], 1));
+        assert!(!check(2, 2, &[0, 0, 1], &[2], 1));
+        assert!(!check(2, 2, &[0, 1, 0], &[1], 1));
+        assert!(!check(2, 2, &[0, 0, 1], &[1], 2));
+        assert!(!check(2, 3, &[0, 0, 1], &[1], 1));
+        assert!(!check(u64::MAX, 2, &[0, 0, 1], &[1], 1));
+
+        // indices are sorted within each column, but not across columns
+        assert!(check(3, 2, &[0, 2, 4], &[0, 2, 1, 1], 4));
+        assert!(!check(3, 2, &[0, 2, 4], &[2, 0, 1, 1], 4));
+    }
+
+    #[test]
+    fn test_unsorted_source() {
+        let a = SparseColMat::<u32, f64>::new(
+            SymbolicSparseColMat::new_unsorted_checked(
+                3,
+                2,
+                alloc::vec![0, 2, 3],
+                None,
+                alloc::vec![2, 0, 1],
+            ),
+            alloc::vec![1.0, 2.0, 3.0],
+        );
+        let bytes = ::rkyv::to_bytes::<_, 256>(&a).unwrap();
+        let archived = ::rkyv::check_archived_root::<SparseColMat<u32, f64>>(&bytes).unwrap();
+        assert!(archived.as_ref().row_indices() == &[0, 2, 1]);
+        assert!(archived.as_ref().to_dense() == a.to_dense());
+    }
+}
@@ -0,0 +1,196 @@
+//! Conversions between faer's dense types and [Apache Arrow](https://arrow.apache.org) arrays.
+//!
+//! A primitive array (e.g. `Float64Array`) without nulls can be viewed as a [`ColRef`], and a
+//! `FixedSizeListArray` of primitive values without nulls, where each list is a row of the matrix,
+//! can be viewed as a row-major [`MatRef`]. Both views point into the arrow buffers, without
+//! copying them. A [`RecordBatch`] whose columns are primitive arrays of the same type can also
+//! be converted to a [`Mat`], which requires copying since the columns are stored in separate
+//! buffers.
+//!
+//! The conversions from faer's types to arrow arrays copy the data into newly allocated arrow
+//! buffers.
+//!
+//! ```
+//! use arrow_array::Float64Array;
+//! use faer::{col, io::arrow};
+//!
+//! let array = Float64Array::from(vec![1.0, 2.0, 3.0]);
+//! let x = arrow::col_from_arrow::<f64>(&array).unwrap();
+//! assert!(x == col![1.0, 2.0, 3.0]);
+//!
+//! let array = arrow::col_to_arrow(x);
+//! assert!(array.values().as_ref() == [1.0, 2.0, 3.0]);
+//! ```
+
+use crate::{
+    col::{self, ColRef},
+    mat::MatRef,
+    Mat,
+};
+use alloc::{format, sync::Arc, vec::Vec};
+use arrow_array::{
+    types::{ArrowPrimitiveType, Float32Type, Float64Type},
+    Array, ArrayRef, FixedSizeListArray, PrimitiveArray, RecordBatch,
+};
+use arrow_buffer::ScalarBuffer;
+use arrow_schema::{ArrowError, Field, Schema};
+use faer_entity::SimpleEntity;
+
+/// Trait implemented for native types that have a corresponding arrow primitive type.
+pub trait ArrowEntity: SimpleEntity {
+    /// Arrow primitive type whose native type is `Self`.
+    type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+impl ArrowEntity for f32 {
+    type ArrowType = Float32Type;
+}
+impl ArrowEntity for f64 {
+    type ArrowType = Float64Type;
+}
+
+fn invalid(msg: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(msg.into())
+}
+
+fn primitive_values<E: ArrowEntity>(array: &dyn Array) -> Result<&[E], ArrowError> {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<E::ArrowType>>()
+        .ok_or_else(|| {
+            invalid(&format!(
+                "expected an array of type {}, found {}",
+                E::ArrowType::DATA_TYPE,
+                array.data_type()
+            ))
+        })?;
+    if array.null_count() != 0 {
+        return Err(invalid("the array contains null values"));
+    }
+    Ok(array.values())
+}
+
+/// Returns a view over the values of `array`.
+///
+/// An error is returned if the array contains null values.
+pub fn col_from_arrow<E: ArrowEntity>(
+    array: &PrimitiveArray<E::ArrowType>,
+) -> Result<ColRef<'_, E>, ArrowError> {
+    Ok(col::from_slice::<E>(primitive_values::<E>(array)?))
+}
+
+/// Copies the values of `col` into a new arrow array.
+pub fn col_to_arrow<E: ArrowEntity>(col: ColRef<'_, E>) -> PrimitiveArray<E::ArrowType> {
+    let values = (0..col.nrows()).map(|i| col.read(i)).collect::<Vec<E>>();
+    PrimitiveArray::new(ScalarBuffer::from(values), None)
+}
+
+/// Returns a view over the values of `array`, as a matrix whose rows are the lists of the array.
+///
+/// An error is returned if the values of the lists are not of type `E`, or if the array or its
+/// values contain null values.
+pub fn mat_from_arrow<E: ArrowEntity>(
+    array: &FixedSizeListArray,
+) -> Result<MatRef<'_, E>, ArrowError> {
+    if array.null_count() != 0 {
+        return Err(invalid("the array contains null lists"));
+    }
+    let nrows = array.len();
+    let ncols = array.value_length() as usize;
+    let values = primitive_values::<E>(array.values().as_ref())?;
+    let values = values
+        .get(..nrows * ncols)
+        .ok_or_else(|| invalid("the values of the array are too short"))?;
+    Ok(crate::mat::from_row_major_slice::<E>(values, nrows, ncols))
+}
+
+/// Copies the rows of `mat` into a new arrow array of fixed size lists.
+///
+/// An error is returned if the number of columns doesn't fit in an `i32`.
+pub fn mat_to_arrow<E: ArrowEntity>(mat: MatRef<'_, E>) -> Result<FixedSizeListArray, ArrowError> {
+    let size = i32::try_from(mat.ncols()).map_err(|_| invalid("too many columns"))?;
+    let values = (0..mat.nrows())
+        .flat_map(|i| (0..mat.ncols()).map(move |j| mat.read(i, j)))
+        .collect::<Vec<E>>();
+    let values: ArrayRef = Arc::new(PrimitiveArray::<E::ArrowType>::new(
+        ScalarBuffer::from(values),
+        None,
+    ));
+    let field = Arc::new(Field::new("item", E::ArrowType::DATA_TYPE, false));
+    FixedSizeListArray::try_new(field, size, values, None)
+}
+
+/// Copies the columns of `batch` into a new matrix.
+///
+/// An error is returned if a column is not of type `E`, or contains null values.
+pub fn mat_from_record_batch<E: ArrowEntity>(batch: &RecordBatch) -> Result<Mat<E>, ArrowError> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| primitive_values::<E>(column.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Mat::from_fn(batch.num_rows(), columns.len(), |i, j| {
+        columns[j][i]
+    }))
+}
+
+/// Copies the columns of `mat` into a new record batch, whose columns are named `column_0`,
+/// `column_1`, etc.
+pub fn mat_to_record_batch<E: ArrowEntity>(mat: MatRef<'_, E>) -> Result<RecordBatch, ArrowError> {
+    let fields = (0..mat.ncols())
+        .map(|j| Field::new(format!("column_{j}"), E::ArrowType::DATA_TYPE, false))
+        .collect::<Vec<_>>();
+    let columns = (0..mat.ncols())
+        .map(|j| Arc::new(col_to_arrow(mat.col(j))) as ArrayRef)
+        .collect::<Vec<_>>();
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, mat};
+    use arrow_array::{Float32Array, Float64Array};
+
+    #[test]
+    fn test_col() {
+        let array = Float64Array::from(alloc::vec![1.0, 2.0, 3.0, 4.0]);
+        let x = col_from_arrow::<f64>(&array).unwrap();
+        // the view points into the arrow buffer
+        assert!(x.as_ptr() == array.values().as_ptr());
+
+        let sliced = array.slice(1, 2);
+        assert!(col_from_arrow::<f64>(&sliced).unwrap() == x.subrows(1, 2));
+
+        let array = Float64Array::from(alloc::vec![Some(1.0), None]);
+        assert!(col_from_arrow::<f64>(&array).is_err());
+
+        let x = crate::col![1.0f32, 2.0, 3.0];
+        assert!(col_to_arrow(x.as_ref()) == Float32Array::from(alloc::vec![1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_mat() {
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f64]];
+        let array = mat_to_arrow(a.as_ref()).unwrap();
+        assert!(array.len() == 2);
+        assert!(array.value_length() == 3);
+
+        let view = mat_from_arrow::<f64>(&array).unwrap();
+        assert!(view == a.as_ref());
+        assert!(mat_from_arrow::<f32>(&array).is_err());
+
+        let sliced = array.slice(1, 1);
+        assert!(mat_from_arrow::<f64>(&sliced).unwrap() == a.as_ref().subrows(1, 1));
+    }
+
+    #[test]
+    fn test_record_batch() {
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f32]];
+        let batch = mat_to_record_batch(a.as_ref()).unwrap();
+        assert!(batch.num_columns() == 3);
+        assert!(batch.schema().field(1).name() == "column_1");
+        assert!(mat_from_record_batch::<f32>(&batch).unwrap() == a);
+        assert!(mat_from_record_batch::<f64>(&batch).is_err());
+    }
+}
@@ -0,0 +1,519 @@
+//! Compact binary format for dense and sparse matrices.
+//!
+//! This format is intended for checkpointing large matrices, and avoids the overhead of generic
+//! serialization frameworks: the values are written in a single pass as raw bytes, in the native
+//! byte order of the writer. Files written on a machine with a different byte order are converted
+//! when read.
+//!
+//! Every file starts with a 16-byte header:
+//!
+//! | offset | size | contents                                                                  |
+//! |--------|------|---------------------------------------------------------------------------|
+//! | 0      | 8    | magic string `b"FAERMAT\0"`                                               |
+//! | 8      | 1    | format version, currently `1`                                             |
+//! | 9      | 1    | byte order, `0` for little endian and `1` for big endian                  |
+//! | 10     | 1    | layout, `0` for dense (column-major), `1` for CSC, `2` for CSR            |
+//! | 11     | 1    | scalar type, `0` for `f32`, `1` for `f64`, `2` for `c32`, `3` for `c64`   |
+//! | 12     | 1    | index width in bytes, `4` or `8` for sparse matrices, `0` otherwise       |
+//! | 13     | 3    | reserved, zero                                                            |
+//!
+//! The header is followed by the number of rows and columns, as `u64`s.
+//!
+//! For dense matrices, the values follow in column-major order. For sparse matrices, the number of
+//! nonzeros follows as a `u64`, then the index pointers, the indices and the values.
+//!
+//! ```
+//! use faer::{mat, Mat};
+//!
+//! let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+//! let mut bytes = Vec::new();
+//! a.write_binary(&mut bytes).unwrap();
+//! assert!(bytes.len() == 16 + 16 + 4 * 8);
+//!
+//! let b = Mat::<f64>::read_binary(&*bytes).unwrap();
+//! assert!(a == b);
+//! ```
+
+use crate::{
+    complex_native::{c32, c64},
+    sparse::{SparseColMat, SparseRowMat, SymbolicSparseColMat, SymbolicSparseRowMat},
+    Index, Mat, SignedIndex,
+};
+use alloc::vec::Vec;
+use faer_entity::SimpleEntity;
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8; 8] = b"FAERMAT\0";
+const VERSION: u8 = 1;
+
+const DENSE: u8 = 0;
+const CSC: u8 = 1;
+const CSR: u8 = 2;
+
+/// Scalar type that can be stored in the binary format.
+pub trait BinaryEntity: SimpleEntity {
+    #[doc(hidden)]
+    const DTYPE: u8;
+    /// Size in bytes of a real component, which determines how values are byte swapped.
+    #[doc(hidden)]
+    const COMPONENT_SIZE: usize;
+}
+
+impl BinaryEntity for f32 {
+    const DTYPE: u8 = 0;
+    const COMPONENT_SIZE: usize = 4;
+}
+impl BinaryEntity for f64 {
+    const DTYPE: u8 = 1;
+    const COMPONENT_SIZE: usize = 8;
+}
+impl BinaryEntity for c32 {
+    const DTYPE: u8 = 2;
+    const COMPONENT_SIZE: usize = 4;
+}
+impl BinaryEntity for c64 {
+    const DTYPE: u8 = 3;
+    const COMPONENT_SIZE: usize = 8;
+}
+
+fn invalid(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+struct Header {
+    swap: bool,
+    index_width: u8,
+    nrows: usize,
+    ncols: usize,
+}
+
+fn write_header(
+    writer: &mut impl Write,
+    layout: u8,
+    dtype: u8,
+    index_width: u8,
+    nrows: usize,
+    ncols: usize,
+) -> io::Result<()> {
+    let mut header = [0u8; 16];
+    header[..8].copy_from_slice(MAGIC);
+    header[8] = VERSION;
+    header[9] = cfg!(target_endian = "big") as u8;
+    header[10] = layout;
+    header[11] = dtype;
+    header[12] = index_width;
+    writer.write_all(&header)?;
+    writer.write_all(&(nrows as u64).to_ne_bytes())?;
+    writer.write_all(&(ncols as u64).to_ne_bytes())
+}
+
+fn read_u64(reader: &mut impl Read, swap: bool) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    let value = u64::from_ne_bytes(bytes);
+    Ok(if swap { value.swap_bytes() } else { value })
+}
+
+fn read_usize(reader: &mut impl Read, swap: bool) -> io::Result<usize> {
+    usize::try_from(read_u64(reader, swap)?).map_err(|_| invalid("dimension overflow"))
+}
+
+fn read_header(reader: &mut impl Read, layout: u8, dtype: u8) -> io::Result<Header> {
+    let mut header = [0u8; 16];
+    reader.read_exact(&mut header)?;
+    if &header[..8] != MAGIC {
+        return Err(invalid("not a faer binary matrix file"));
+    }
+    if header[8] != VERSION {
+        return Err(invalid("unsupported format version"));
+    }
+    if header[9] > 1 {
+        return Err(invalid("invalid byte order"));
+    }
+    if header[10] != layout {
+        return Err(invalid(
+            "the file contains a matrix with a different layout",
+        ));
+    }
+    if header[11] != dtype {
+        return Err(invalid(
+            "the file contains a matrix with a different scalar type",
+        ));
+    }
+    let swap = (header[9] == 1) != cfg!(target_endian = "big");
+    let nrows = read_usize(reader, swap)?;
+    let ncols = read_usize(reader, swap)?;
+    Ok(Header {
+        swap,
+        index_width: header[12],
+        nrows,
+        ncols,
+    })
+}
+
+// maximum number of bytes allocated ahead of the data that was actually read, so that a corrupt or
+// malicious header can't trigger a huge allocation before the stream runs out
+const READ_CHUNK_BYTES: usize = 1 << 20;
+
+// reads `len` plain values, whose components of size `component_size` are byte swapped if needed
+fn read_pod<T: bytemuck::Pod>(
+    reader: &mut impl Read,
+    len: usize,
+    component_size: usize,
+    swap: bool,
+) -> io::Result<Vec<T>> {
+    let chunk_len = Ord::max(READ_CHUNK_BYTES / core::mem::size_of::<T>(), 1);
+    let mut values = Vec::new();
+    while values.len() < len {
+        let start = values.len();
+        let n = Ord::min(chunk_len, len - start);
+        values
+            .try_reserve(n)
+            .map_err(|_| invalid("the matrix is too large to be allocated"))?;
+        values.resize(start + n, T::zeroed());
+        reader.read_exact(bytemuck::cast_slice_mut::<T, u8>(&mut values[start..]))?;
+    }
+    if swap {
+        bytemuck::cast_slice_mut::<T, u8>(&mut values)
+            .chunks_exact_mut(component_size)
+            .for_each(|component| component.reverse());
+    }
+    Ok(values)
+}
+
+fn read_values<E: BinaryEntity>(
+    reader: &mut impl Read,
+    len: usize,
+    swap: bool,
+) -> io::Result<Vec<E>> {
+    read_pod(reader, len, E::COMPONENT_SIZE, swap)
+}
+
+fn read_indices<I: Index>(
+    reader: &mut impl Read,
+    len: usize,
+    header: &Header,
+) -> io::Result<Vec<I>> {
+    let max = I::Signed::MAX.zx() as u64;
+    let convert = |value: u64| {
+        if value > max {
+            Err(invalid("index overflow"))
+        } else {
+            Ok(I::truncate(value as usize))
+        }
+    };
+    match header.index_width {
+        4 => read_pod::<u32>(reader, len, 4, header.swap)?
+            .into_iter()
+            .map(|value| convert(value as u64))
+            .collect(),
+        8 => read_pod::<u64>(reader, len, 8, header.swap)?
+            .into_iter()
+            .map(convert)
+            .collect(),
+        _ => Err(invalid("invalid index width")),
+    }
+}
+
+fn write_compressed<'a, I: Index, E: BinaryEntity>(
+    writer: &mut impl Write,
+    layout: u8,
+    nrows: usize,
+    ncols: usize,
+    outer: usize,
+    slices: impl Fn(usize) -> (&'a [I], &'a [E]),
+) -> io::Result<()> {
+    write_header(
+        writer,
+        layout,
+        E::DTYPE,
+        core::mem::size_of::<I>() as u8,
+        nrows,
+        ncols,
+    )?;
+
+    // the matrix may have gaps between its inner vectors, which are skipped
+    let mut ptrs = Vec::with_capacity(outer + 1);
+    let mut nnz = 0usize;
+    ptrs.push(I::truncate(0));
+    for j in 0..outer {
+        nnz += slices(j).0.len();
+        ptrs.push(I::truncate(nnz));
+    }
+    writer.write_all(&(nnz as u64).to_ne_bytes())?;
+    writer.write_all(bytemuck::cast_slice(&ptrs))?;
+    for j in 0..outer {
+        writer.write_all(bytemuck::cast_slice(slices(j).0))?;
+    }
+    for j in 0..outer {
+        writer.write_all(bytemuck::cast_slice(slices(j).1))?;
+    }
+    Ok(())
+}
+
+// returns the index pointers, the indices and the values, after checking that they describe a
+// valid compressed matrix and sorting the indices of each column or row
+fn read_compressed<I: Index, E: BinaryEntity>(
+    reader: &mut impl Read,
+    header: &Header,
+    inner: usize,
+    outer: usize,
+) -> io::Result<(Vec<I>, Vec<I>, Vec<E>)> {
+    let max = I::Signed::MAX.zx();
+    if inner > max || outer > max {
+        return Err(invalid(
+            "the matrix dimensions exceed the maximum index value",
+        ));
+    }
+    let nnz = read_usize(reader, header.swap)?;
+    let ptrs = read_indices::<I>(reader, outer + 1, header)?;
+    if ptrs[0].zx() != 0 || ptrs.windows(2).any(|w| w[0] > w[1]) || ptrs[outer].zx() != nnz {
+        return Err(invalid("invalid index pointers"));
+    }
+    let indices = read_indices::<I>(reader, nnz, header)?;
+    if indices.iter().any(|i| i.zx() >= inner) {
+        return Err(invalid("indices must be less than the dimension"));
+    }
+    let mut indices = indices;
+    let mut values = read_values::<E>(reader, nnz, header.swap)?;
+    for j in 0..outer {
+        let range = ptrs[j].zx()..ptrs[j + 1].zx();
+        if indices[range.clone()].windows(2).any(|w| w[0] > w[1]) {
+            let mut sorted: Vec<(I, E)> = range.clone().map(|k| (indices[k], values[k])).collect();
+            sorted.sort_by_key(|&(i, _)| i);
+            for (k, (i, value)) in range.zip(sorted) {
+                indices[k] = i;
+                values[k] = value;
+            }
+        }
+    }
+    Ok((ptrs, indices, values))
+}
+
+impl<E: BinaryEntity> Mat<E> {
+    /// Writes `self` to `writer` in faer's binary format. See [`crate::io::binary`] for a
+    /// description of the format.
+    pub fn write_binary(&self, mut writer: impl Write) -> io::Result<()> {
+        write_header(&mut writer, DENSE, E::DTYPE, 0, self.nrows(), self.ncols())?;
+        for j in 0..self.ncols() {
+            writer.write_all(bytemuck::cast_slice(self.col_as_slice(j)))?;
+        }
+        Ok(())
+    }
+
+    /// Reads a matrix from `reader` in faer's binary format.
+    ///
+    /// An error is returned if the data is not a dense matrix with scalar type `E`.
+    pub fn read_binary(mut reader: impl Read) -> io::Result<Self> {
+        let header = read_header(&mut reader, DENSE, E::DTYPE)?;
+        let (nrows, ncols) = (header.nrows, header.ncols);
+        let len = nrows
+            .checked_mul(ncols)
+            .ok_or_else(|| invalid("dimension overflow"))?;
+        let values = read_values::<E>(&mut reader, len, header.swap)?;
+        Ok(Mat::from_fn(nrows, ncols, |i, j| values[i + j * nrows]))
+    }
+}
+
+impl<I: Index, E: BinaryEntity> SparseColMat<I, E> {
+    /// Writes `self` to `writer` in faer's binary format. See [`crate::io::binary`] for a
+    /// description of the format.
+    pub fn write_binary(&self, mut writer: impl Write) -> io::Result<()> {
+        let this = self.as_ref();
+        write_compressed(
+            &mut writer,
+            CSC,
+            this.nrows(),
+            this.ncols(),
+            this.ncols(),
+            |j| (this.row_indices_of_col_raw(j), this.values_of_col(j)),
+        )
+    }
+
+    /// Reads a matrix from `reader` in faer's binary format.
+    ///
+    /// An error is returned if the data is not a sparse column-major matrix with scalar type `E`,
+    /// or if its indices don't fit in `I`.
+    pub fn read_binary(mut reader: impl Read) -> io::Result<Self> {
+        let header = read_header(&mut reader, CSC, E::DTYPE)?;
+        let (nrows, ncols) = (header.nrows, header.ncols);
+        let (col_ptrs, row_indices, values) =
+            read_compressed::<I, E>(&mut reader, &header, nrows, ncols)?;
+        let symbolic = SymbolicSparseColMat::new_checked(nrows, ncols, col_ptrs, None, row_indices);
+        Ok(SparseColMat::new(symbolic, values))
+    }
+}
+
+impl<I: Index, E: BinaryEntity> SparseRowMat<I, E> {
+    /// Writes `self` to `writer` in faer's binary format. See [`crate::io::binary`] for a
+    /// description of the format.
+    pub fn write_binary(&self, mut writer: impl Write) -> io::Result<()> {
+        let this = self.as_ref();
+        write_compressed(
+            &mut writer,
+            CSR,
+            this.nrows(),
+            this.ncols(),
+            this.nrows(),
+            |i| (this.col_indices_of_row_raw(i), this.values_of_row(i)),
+        )
+    }
+
+    /// Reads a matrix from `reader` in faer's binary format.
+    ///
+    /// An error is returned if the data is not a sparse row-major matrix with scalar type `E`, or
+    /// if its indices don't fit in `I`.
+    pub fn read_binary(mut reader: impl Read) -> io::Result<Self> {
+        let header = read_header(&mut reader, CSR, E::DTYPE)?;
+        let (nrows, ncols) = (header.nrows, header.ncols);
+        let (row_ptrs, col_indices, values) =
+            read_compressed::<I, E>(&mut reader, &header, ncols, nrows)?;
+        let symbolic = SymbolicSparseRowMat::new_checked(nrows, ncols, row_ptrs, None, col_indices);
+        Ok(SparseRowMat::new(symbolic, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert;
+
+    #[test]
+    fn test_dense() {
+        let a = Mat::<c64>::from_fn(4, 3, |i, j| c64::new(i as f64, j as f64));
+        let mut bytes = Vec::new();
+        a.write_binary(&mut bytes).unwrap();
+        assert!(bytes.len() == 32 + 12 * 16);
+        assert!(Mat::<c64>::read_binary(&*bytes).unwrap() == a);
+
+        assert!(Mat::<f64>::read_binary(&*bytes).is_err());
+        assert!(Mat::<c64>::read_binary(&bytes[..bytes.len() - 1]).is_err());
+
+        let empty = Mat::<f32>::zeros(0, 5);
+        let mut bytes = Vec::new();
+        empty.write_binary(&mut bytes).unwrap();
+        let read = Mat::<f32>::read_binary(&*bytes).unwrap();
+        assert!(all(read.nrows() == 0, read.ncols() == 5));
+    }
+
+    #[test]
+    fn test_byte_order() {
+        // a 1x2 f32 matrix written on a machine with the opposite byte order
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[VERSION, !cfg!(target_endian = "big") as u8, DENSE, 0, 0]);
+        bytes.extend_from_slice(&[0, 0, 0]);
+        bytes.extend_from_slice(&1u64.swap_bytes().to_ne_bytes());
+        bytes.extend_from_slice(&2u64.swap_bytes().to_ne_bytes());
+        bytes.extend_from_slice(&1.5f32.to_bits().swap_bytes().to_ne_bytes());
+        bytes.extend_from_slice(&(-2.0f32).to_bits().swap_bytes().to_ne_bytes());
+
+        let a = Mat::<f32>::read_binary(&*bytes).unwrap();
+        assert!(a == crate::mat![[1.5, -2.0f32]]);
+    }
+
+    // reader that records the largest buffer it was asked to fill
+    struct TrackingReader<'a> {
+        bytes: &'a [u8],
+        max_request: usize,
+    }
+
+    impl Read for TrackingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.max_request = Ord::max(self.max_request, buf.len());
+            self.bytes.read(buf)
+        }
+    }
+
+    #[test]
+    fn test_truncated_huge_header() {
+        // the header claims far more data than the stream contains, which must fail without
+        // allocating the whole matrix upfront. the memory is only allocated as the data is read,
+        // so the reader is never asked to fill more than one chunk
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[VERSION, cfg!(target_endian = "big") as u8, DENSE, 1, 0]);
+        bytes.extend_from_slice(&[0, 0, 0]);
+        bytes.extend_from_slice(&(1u64 << 15).to_ne_bytes());
+        bytes.extend_from_slice(&(1u64 << 15).to_ne_bytes());
+        bytes.extend_from_slice(&1.0f64.to_ne_bytes());
+
+        let mut reader = TrackingReader {
+            bytes: &bytes,
+            max_request: 0,
+        };
+        let err = Mat::<f64>::read_binary(&mut reader).unwrap_err();
+        assert!(err.kind() == io::ErrorKind::UnexpectedEof);
+        assert!(reader.max_request <= READ_CHUNK_BYTES);
+
+        let mut bytes = bytes[..32].to_vec();
+        bytes[16..24].copy_from_slice(&(usize::MAX as u64).to_ne_bytes());
+        bytes[24..32].copy_from_slice(&1u64.to_ne_bytes());
+        let mut reader = TrackingReader {
+            bytes: &bytes,
+            max_request: 0,
+        };
+        let err = Mat::<f64>::read_binary(&mut reader).unwrap_err();
+        assert!(err.kind() == io::ErrorKind::UnexpectedEof);
+        assert!(reader.max_request <= READ_CHUNK_BYTES);
+
+        // same for the index pointers of a sparse matrix with a huge number of columns
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[VERSION, cfg!(target_endian = "big") as u8, CSC, 1, 8]);
+        bytes.extend_from_slice(&[0, 0, 0]);
+        bytes.extend_from_slice(&1u64.to_ne_bytes());
+        bytes.extend_from_slice(&(1u64 << 40).to_ne_bytes());
+        bytes.extend_from_slice(&(1u64 << 40).to_ne_bytes());
+        bytes.extend_from_slice(&0u64.to_ne_bytes());
+
+        let mut reader = TrackingReader {
+            bytes: &bytes,
+            max_request: 0,
+        };
+        let err = SparseColMat::<usize, f64>::read_binary(&mut reader).unwrap_err();
+        assert!(err.kind() == io::ErrorKind::UnexpectedEof);
+        assert!(reader.max_request <= READ_CHUNK_BYTES);
+    }
+
+    #[test]
+    fn test_sparse() {
+        let a = SparseColMat::<u32, f64>::try_new_from_triplets(
+            4,
+            3,
+            &[(0, 0, 1.0), (2, 0, 2.0), (1, 2, 3.0), (3, 2, 4.0)],
+        )
+        .unwrap();
+        let mut bytes = Vec::new();
+        a.write_binary(&mut bytes).unwrap();
+        assert!(bytes.len() == 32 + 8 + 4 * 4 + 4 * 4 + 4 * 8);
+
+        // indices are converted to the requested width
+        let b = SparseColMat::<usize, f64>::read_binary(&*bytes).unwrap();
+        assert!(b.to_dense() == a.to_dense());
+        assert!(SparseRowMat::<u32, f64>::read_binary(&*bytes).is_err());
+
+        let at = a.as_ref().transpose().to_owned().unwrap();
+        let mut bytes = Vec::new();
+        at.write_binary(&mut bytes).unwrap();
+        let bt = SparseRowMat::<u32, f64>::read_binary(&*bytes).unwrap();
+        assert!(bt.to_dense() == at.to_dense());
+
+        // out of bounds row index
+        let mut bytes = Vec::new();
+        a.write_binary(&mut bytes).unwrap();
+        let offset = 32 + 8 + 4 * 4;
+        bytes[offset..offset + 4].copy_from_slice(&4u32.to_ne_bytes());
+        assert!(SparseColMat::<u32, f64>::read_binary(&*bytes).is_err());
+
+        // unsorted row indices are sorted along with their values
+        let mut bytes = Vec::new();
+        a.write_binary(&mut bytes).unwrap();
+        bytes[offset..offset + 8].rotate_left(4);
+        let values = offset + 4 * 4;
+        bytes[values..values + 16].rotate_left(8);
+        let b = SparseColMat::<u32, f64>::read_binary(&*bytes).unwrap();
+        assert!(b.row_indices_of_col_raw(0) == &[0, 2]);
+        assert!(b.to_dense() == a.to_dense());
+    }
+}
@@ -0,0 +1,452 @@
+//! Reading and writing matrices in CSV format.
+//!
+//! Each record of the file is a row of the matrix. Fields may be quoted with double quotes, in
+//! which case they may contain the delimiter, line breaks, and escaped quotes (`""`). Whitespace
+//! around fields is ignored, as are empty lines.
+//!
+//! ```
+//! use faer::{io::csv, mat};
+//!
+//! let data = "x,y,z\n1.0,2.0,3.0\n4.0,5.0,6.0\n";
+//!
+//! let mut options = csv::CsvOptions::default();
+//! options.has_header = true;
+//! options.columns = csv::ColumnSelection::Names(vec!["z".into(), "x".into()]);
+//!
+//! let a = csv::read_mat::<f64>(data.as_bytes(), &options).unwrap();
+//! assert!(a == mat![[3.0, 1.0], [6.0, 4.0]]);
+//!
+//! let mut writer = csv::CsvWriter::new(Vec::new());
+//! writer.write_header(&["z", "x"]).unwrap();
+//! writer.write_mat(a.as_ref()).unwrap();
+//! let bytes = writer.finish().unwrap();
+//! assert!(bytes == b"z,x\n3,1\n6,4\n");
+//! ```
+
+use crate::{mat::MatRef, row::RowRef, Entity, Mat};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// Columns of the file that are read.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColumnSelection {
+    /// All the columns, in order.
+    #[default]
+    All,
+    /// The columns with the given indices, in the given order.
+    Indices(Vec<usize>),
+    /// The columns with the given names, in the given order. Requires the file to have a header.
+    Names(Vec<String>),
+}
+
+/// Options for reading CSV files.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CsvOptions {
+    /// Field delimiter. Defaults to `b','`.
+    pub delimiter: u8,
+    /// Whether the first record is a header containing the column names. Defaults to `false`.
+    pub has_header: bool,
+    /// Columns that are read. Defaults to all the columns.
+    pub columns: ColumnSelection,
+}
+
+impl Default for CsvOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_header: false,
+            columns: ColumnSelection::All,
+        }
+    }
+}
+
+/// Error that can occur when reading a CSV file.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CsvError {
+    /// Error from the underlying reader.
+    Io(io::Error),
+    /// A field couldn't be parsed.
+    Parse {
+        /// Line number of the record, starting from one.
+        line: usize,
+        /// Index of the column in the file.
+        column: usize,
+        /// Contents of the field.
+        field: String,
+    },
+    /// A record doesn't have the same number of fields as the first one.
+    RaggedRecord {
+        /// Line number of the record, starting from one.
+        line: usize,
+        /// Number of fields of the first record.
+        expected: usize,
+        /// Number of fields of the record.
+        found: usize,
+    },
+    /// A selected column doesn't exist.
+    UnknownColumn(String),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "{err}"),
+            CsvError::Parse {
+                line,
+                column,
+                field,
+            } => write!(
+                f,
+                "couldn't parse field {field:?} in column {column} at line {line}"
+            ),
+            CsvError::RaggedRecord {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {expected} fields, found {found} at line {line}"
+            ),
+            CsvError::UnknownColumn(column) => write!(f, "unknown column {column}"),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    #[inline]
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+struct Records<R> {
+    reader: R,
+    delimiter: u8,
+    // line number of the next line to be read
+    line: usize,
+    buf: String,
+}
+
+impl<R: BufRead> Records<R> {
+    // reads the next non-empty record, and returns it along with the line number it starts at
+    fn next(&mut self) -> Result<Option<(usize, Vec<String>)>, CsvError> {
+        loop {
+            self.buf.clear();
+            let start = self.line;
+            if self.reader.read_line(&mut self.buf)? == 0 {
+                return Ok(None);
+            }
+            self.line += 1;
+
+            // quoted fields may span multiple lines
+            while self.buf.bytes().filter(|&b| b == b'"').count() % 2 == 1 {
+                if self.reader.read_line(&mut self.buf)? == 0 {
+                    break;
+                }
+                self.line += 1;
+            }
+
+            let record = self.buf.trim_end_matches(['\n', '\r']);
+            if record.trim().is_empty() {
+                continue;
+            }
+            return Ok(Some((start, split(record, self.delimiter as char))));
+        }
+    }
+}
+
+fn split(record: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = record.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' {
+            quoted = true;
+        } else if c == delimiter {
+            fields.push(core::mem::take(&mut field).trim().into());
+        } else {
+            field.push(c);
+        }
+    }
+    fields.push(field.trim().into());
+    fields
+}
+
+/// Reads a matrix from `reader`, and returns the names of the selected columns (or an empty list
+/// if the file has no header) along with the matrix.
+pub fn read_mat_with_header<E: Entity + FromStr>(
+    reader: impl Read,
+    options: &CsvOptions,
+) -> Result<(Vec<String>, Mat<E>), CsvError> {
+    let mut records = Records {
+        reader: BufReader::new(reader),
+        delimiter: options.delimiter,
+        line: 1,
+        buf: String::new(),
+    };
+
+    let header = if options.has_header {
+        records.next()?.map(|(_, header)| header)
+    } else {
+        None
+    };
+    let mut first = records.next()?;
+    let ncols_file = match (&header, &first) {
+        (Some(header), _) => header.len(),
+        (None, Some((_, record))) => record.len(),
+        (None, None) => 0,
+    };
+
+    let selected = match &options.columns {
+        ColumnSelection::All => (0..ncols_file).collect::<Vec<_>>(),
+        ColumnSelection::Indices(indices) => {
+            if let Some(&j) = indices.iter().find(|&&j| j >= ncols_file) {
+                return Err(CsvError::UnknownColumn(alloc::format!("{j}")));
+            }
+            indices.clone()
+        }
+        ColumnSelection::Names(names) => {
+            let header = header.as_deref().unwrap_or(&[]);
+            names
+                .iter()
+                .map(|name| {
+                    header
+                        .iter()
+                        .position(|column| column == name)
+                        .ok_or_else(|| CsvError::UnknownColumn(name.clone()))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        }
+    };
+    let names = match &header {
+        Some(header) => selected.iter().map(|&j| header[j].clone()).collect(),
+        None => Vec::new(),
+    };
+
+    // the values are accumulated in row-major order
+    let mut values = Vec::<E>::new();
+    let mut nrows = 0usize;
+    loop {
+        let (line, record) = match first.take() {
+            Some(record) => record,
+            None => match records.next()? {
+                Some(record) => record,
+                None => break,
+            },
+        };
+        if record.len() != ncols_file {
+            return Err(CsvError::RaggedRecord {
+                line,
+                expected: ncols_file,
+                found: record.len(),
+            });
+        }
+        for &j in &selected {
+            let value = record[j].parse::<E>().map_err(|_| CsvError::Parse {
+                line,
+                column: j,
+                field: record[j].clone(),
+            })?;
+            values.push(value);
+        }
+        nrows += 1;
+    }
+
+    let ncols = selected.len();
+    Ok((
+        names,
+        Mat::from_fn(nrows, ncols, |i, j| values[i * ncols + j]),
+    ))
+}
+
+/// Reads a matrix from `reader`.
+///
+/// The scalars may be of any type implementing [`FromStr`], e.g. `f64` or `f32`.
+pub fn read_mat<E: Entity + FromStr>(
+    reader: impl Read,
+    options: &CsvOptions,
+) -> Result<Mat<E>, CsvError> {
+    read_mat_with_header(reader, options).map(|(_, mat)| mat)
+}
+
+/// Streaming writer for CSV files.
+///
+/// The rows are written as they're provided, so that large matrices can be written in chunks
+/// without being stored in memory at once.
+pub struct CsvWriter<W: Write> {
+    writer: W,
+    delimiter: u8,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Creates a writer that writes to `writer`, with `,` as the delimiter.
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            delimiter: b',',
+        }
+    }
+
+    /// Sets the field delimiter.
+    #[inline]
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    fn write_record<T: fmt::Display>(&mut self, fields: impl Iterator<Item = T>) -> io::Result<()> {
+        let delimiter = self.delimiter as char;
+        for (k, field) in fields.enumerate() {
+            if k > 0 {
+                write!(self.writer, "{delimiter}")?;
+            }
+            let field = alloc::format!("{field}");
+            if field.contains([delimiter, '"', '\n', '\r']) {
+                write!(self.writer, "\"{}\"", field.replace('"', "\"\""))?;
+            } else {
+                self.writer.write_all(field.as_bytes())?;
+            }
+        }
+        writeln!(self.writer)
+    }
+
+    /// Writes a header record with the given column names.
+    pub fn write_header(&mut self, names: &[&str]) -> io::Result<()> {
+        self.write_record(names.iter())
+    }
+
+    /// Writes `row` as a record.
+    pub fn write_row<E: Entity + fmt::Display>(&mut self, row: RowRef<'_, E>) -> io::Result<()> {
+        self.write_record((0..row.ncols()).map(|j| row.read(j)))
+    }
+
+    /// Writes the rows of `mat` as records.
+    pub fn write_mat<E: Entity + fmt::Display>(&mut self, mat: MatRef<'_, E>) -> io::Result<()> {
+        for i in 0..mat.nrows() {
+            self.write_row(mat.row(i))?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the writer, and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, mat};
+
+    #[test]
+    fn test_read() {
+        let data = "1; 2;3\n\n4;5 ; 6\r\n";
+        let mut options = CsvOptions::default();
+        options.delimiter = b';';
+        let a = read_mat::<f64>(data.as_bytes(), &options).unwrap();
+        assert!(a == mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        options.columns = ColumnSelection::Indices(alloc::vec![2, 0]);
+        let a = read_mat::<f32>(data.as_bytes(), &options).unwrap();
+        assert!(a == mat![[3.0, 1.0], [6.0, 4.0f32]]);
+
+        options.columns = ColumnSelection::Indices(alloc::vec![3]);
+        assert!(matches!(
+            read_mat::<f64>(data.as_bytes(), &options),
+            Err(CsvError::UnknownColumn(_))
+        ));
+
+        let a = read_mat::<f64>("".as_bytes(), &CsvOptions::default()).unwrap();
+        assert!(all(a.nrows() == 0, a.ncols() == 0));
+    }
+
+    #[test]
+    fn test_header() {
+        let data = "\"a, b\",c,\"say \"\"hi\"\"\"\n1,2,3\n";
+        let mut options = CsvOptions::default();
+        options.has_header = true;
+        let (names, a) = read_mat_with_header::<f64>(data.as_bytes(), &options).unwrap();
+        assert!(names == ["a, b", "c", "say \"hi\""]);
+        assert!(a == mat![[1.0, 2.0, 3.0]]);
+
+        options.columns = ColumnSelection::Names(alloc::vec!["c".into(), "d".into()]);
+        assert!(matches!(
+            read_mat::<f64>(data.as_bytes(), &options),
+            Err(CsvError::UnknownColumn(name)) if name == "d"
+        ));
+
+        // header only
+        options.columns = ColumnSelection::All;
+        let (names, a) = read_mat_with_header::<f64>("x,y\n".as_bytes(), &options).unwrap();
+        assert!(names == ["x", "y"]);
+        assert!(all(a.nrows() == 0, a.ncols() == 2));
+    }
+
+    #[test]
+    fn test_errors() {
+        let options = CsvOptions::default();
+        assert!(matches!(
+            read_mat::<f64>("1,2\n3\n".as_bytes(), &options),
+            Err(CsvError::RaggedRecord {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(matches!(
+            read_mat::<f64>("1,2\n\n3,x\n".as_bytes(), &options),
+            Err(CsvError::Parse {
+                line: 3,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let a = Mat::<f64>::from_fn(5, 3, |i, j| (i as f64) / (j as f64 + 3.0));
+        let mut writer = CsvWriter::new(Vec::new()).with_delimiter(b'\t');
+        writer
+            .write_header(&["first", "second\tcolumn", "third"])
+            .unwrap();
+        writer.write_mat(a.as_ref().subrows(0, 2)).unwrap();
+        writer.write_mat(a.as_ref().subrows(2, 3)).unwrap();
+        let bytes = writer.finish().unwrap();
+
+        let mut options = CsvOptions::default();
+        options.delimiter = b'\t';
+        options.has_header = true;
+        let (names, b) = read_mat_with_header::<f64>(&*bytes, &options).unwrap();
+        assert!(names == ["first", "second\tcolumn", "third"]);
+        assert!(b == a);
+    }
+}
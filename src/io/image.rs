@@ -0,0 +1,195 @@
+//! Conversions between images of the [`image`](https://docs.rs/image) crate and matrices.
+//!
+//! The pixel at coordinates `(x, y)` is stored at row `y` and column `x` of the matrix, so that
+//! an image of size `width × height` corresponds to a matrix with `height` rows and `width`
+//! columns. Channel values are scaled to `[0, 1]`, and scaled back (and clamped) when converting
+//! a matrix to an image.
+//!
+//! Color images are split into one matrix per channel. The channels of an image with `f32`
+//! subpixels can also be viewed in place with [`channel_view`], since the interleaved layout of
+//! the image buffer can be described with a row stride and a column stride.
+//!
+//! ```
+//! use faer::io::image::{gray_to_mat, mat_to_gray};
+//! use image::GrayImage;
+//!
+//! let img = GrayImage::from_fn(4, 3, |x, y| image::Luma([(x + 10 * y) as u8]));
+//! let a = gray_to_mat(&img);
+//! assert!(a.nrows() == 3 && a.ncols() == 4);
+//! assert!(a.read(2, 1) == 21.0 / 255.0);
+//!
+//! assert!(mat_to_gray(a.as_ref()) == img);
+//! ```
+
+use crate::{
+    assert,
+    mat::{self, MatRef},
+    Mat,
+};
+use ::image::{DynamicImage, GrayImage, ImageBuffer, Luma, Pixel, Primitive};
+use alloc::vec::Vec;
+use core::ops::Deref;
+use num_traits::NumCast;
+
+#[inline]
+fn to_unit<T: Primitive>(value: T) -> f32 {
+    value.to_f32().unwrap_or(0.0) / T::DEFAULT_MAX_VALUE.to_f32().unwrap_or(1.0)
+}
+
+#[inline]
+fn from_unit<T: Primitive>(value: f32) -> T {
+    let max = T::DEFAULT_MAX_VALUE.to_f32().unwrap_or(1.0);
+    let value = value.clamp(0.0, 1.0) * max;
+    // integer subpixels are rounded, floating point subpixels have a maximum value of one
+    let value = if max == 1.0 { value } else { value.round() };
+    <T as NumCast>::from(value).unwrap_or(T::DEFAULT_MIN_VALUE)
+}
+
+/// Converts the channel `channel` of `img` to a matrix.
+///
+/// # Panics
+/// Panics if `channel` is not smaller than the number of channels of the pixel type.
+#[track_caller]
+pub fn channel_to_mat<P: Pixel, C: Deref<Target = [P::Subpixel]>>(
+    img: &ImageBuffer<P, C>,
+    channel: usize,
+) -> Mat<f32> {
+    assert!(channel < P::CHANNEL_COUNT as usize);
+    Mat::from_fn(img.height() as usize, img.width() as usize, |i, j| {
+        to_unit(img.get_pixel(j as u32, i as u32).channels()[channel])
+    })
+}
+
+/// Converts each channel of `img` to a matrix.
+pub fn channels_to_mats<P: Pixel, C: Deref<Target = [P::Subpixel]>>(
+    img: &ImageBuffer<P, C>,
+) -> Vec<Mat<f32>> {
+    (0..P::CHANNEL_COUNT as usize)
+        .map(|channel| channel_to_mat(img, channel))
+        .collect()
+}
+
+/// Converts the grayscale image `img` to a matrix.
+pub fn gray_to_mat<T: Primitive, C: Deref<Target = [T]>>(
+    img: &ImageBuffer<Luma<T>, C>,
+) -> Mat<f32> {
+    channel_to_mat(img, 0)
+}
+
+/// Converts `img` to grayscale, and then to a matrix.
+pub fn dynamic_to_mat(img: &DynamicImage) -> Mat<f32> {
+    gray_to_mat(&img.to_luma32f())
+}
+
+/// Converts the matrices in `channels`, one per channel, to an image.
+///
+/// # Panics
+/// Panics if the number of matrices differs from the number of channels of the pixel type, if the
+/// matrices don't all have the same dimensions, or if the dimensions don't fit in a `u32`.
+#[track_caller]
+pub fn mats_to_channels<P: Pixel>(
+    channels: &[MatRef<'_, f32>],
+) -> ImageBuffer<P, Vec<P::Subpixel>> {
+    assert!(channels.len() == P::CHANNEL_COUNT as usize);
+    let (nrows, ncols) = channels
+        .first()
+        .map(|mat| (mat.nrows(), mat.ncols()))
+        .unwrap_or((0, 0));
+    for mat in channels {
+        assert!(all(mat.nrows() == nrows, mat.ncols() == ncols));
+    }
+    let height = u32::try_from(nrows).unwrap();
+    let width = u32::try_from(ncols).unwrap();
+
+    let mut img = ImageBuffer::<P, Vec<P::Subpixel>>::new(width, height);
+    for (x, y, pixel) in img.enumerate_pixels_mut() {
+        for (channel, value) in pixel.channels_mut().iter_mut().enumerate() {
+            *value = from_unit(channels[channel].read(y as usize, x as usize));
+        }
+    }
+    img
+}
+
+/// Converts `mat` to a grayscale image with 8-bit values.
+///
+/// # Panics
+/// Panics if the dimensions of `mat` don't fit in a `u32`.
+#[track_caller]
+pub fn mat_to_gray(mat: MatRef<'_, f32>) -> GrayImage {
+    mats_to_channels::<Luma<u8>>(&[mat])
+}
+
+/// Returns a view over the channel `channel` of `img`, without copying. The values are not
+/// rescaled.
+///
+/// # Panics
+/// Panics if `channel` is not smaller than the number of channels of the pixel type.
+#[track_caller]
+pub fn channel_view<P: Pixel<Subpixel = f32>, C: Deref<Target = [f32]>>(
+    img: &ImageBuffer<P, C>,
+    channel: usize,
+) -> MatRef<'_, f32> {
+    let n_channels = P::CHANNEL_COUNT as usize;
+    assert!(channel < n_channels);
+    let (height, width) = (img.height() as usize, img.width() as usize);
+    let data: &[f32] = img;
+    let data = &data[..height * width * n_channels];
+
+    if height == 0 || width == 0 {
+        return mat::from_column_major_slice::<f32>(&[], height, width);
+    }
+    // the subpixels are stored row by row, with the channels of each pixel interleaved
+    unsafe {
+        mat::from_raw_parts(
+            data.as_ptr().add(channel),
+            height,
+            width,
+            (width * n_channels) as isize,
+            n_channels as isize,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::image::{Rgb, Rgb32FImage, RgbImage};
+
+    #[test]
+    fn test_gray() {
+        let img = GrayImage::from_fn(5, 2, |x, y| Luma([(50 * x + y) as u8]));
+        let a = gray_to_mat(&img);
+        assert!(all(a.nrows() == 2, a.ncols() == 5));
+        assert!(a.read(1, 4) == 201.0 / 255.0);
+        assert!(mat_to_gray(a.as_ref()) == img);
+
+        // values are clamped
+        let b = Mat::<f32>::from_fn(1, 2, |_, j| if j == 0 { -1.0 } else { 2.0 });
+        assert!(mat_to_gray(b.as_ref()).into_raw() == [0, 255]);
+
+        let dynamic = DynamicImage::ImageLuma8(img);
+        let b = dynamic_to_mat(&dynamic);
+        for j in 0..5 {
+            for i in 0..2 {
+                assert!((b.read(i, j) - a.read(i, j)).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_color() {
+        let img = RgbImage::from_fn(3, 4, |x, y| Rgb([x as u8, y as u8, (x * y) as u8]));
+        let channels = channels_to_mats(&img);
+        assert!(channels.len() == 3);
+        assert!(channels[2].read(3, 2) == 6.0 / 255.0);
+
+        let views = channels.iter().map(|mat| mat.as_ref()).collect::<Vec<_>>();
+        assert!(mats_to_channels::<Rgb<u8>>(&views) == img);
+
+        let img = Rgb32FImage::from_fn(3, 4, |x, y| Rgb([x as f32, y as f32, 0.5]));
+        let green = channel_view(&img, 1);
+        assert!(all(green.nrows() == 4, green.ncols() == 3));
+        assert!(green.read(3, 1) == 3.0);
+        assert!(green == channel_to_mat(&img, 1));
+    }
+}
@@ -0,0 +1,763 @@
+//! Reading and writing MATLAB's level 5 `.mat` files, as written by `save` with the `-v6` or
+//! `-v7` options (the default format of MATLAB and Octave).
+//!
+//! Dense numeric arrays of any class (`double`, `single`, and the integer classes) can be read
+//! into a [`Mat`], and sparse arrays into a [`Mat`] or a [`SparseColMat`]. Arrays with more than
+//! two dimensions, and non-numeric variables such as cell arrays, structures and strings are
+//! skipped when reading. Compressed variables (the default of `-v7`) are supported. The older
+//! level 4 format and the HDF5 based `-v7.3` format are not supported.
+//!
+//! Files are written uncompressed, with dense matrices stored with the class matching their scalar
+//! type, and sparse matrices with the `double` class (the only one supported by MATLAB for sparse
+//! arrays).
+//!
+//! ```
+//! use faer::{io::matfile::*, mat};
+//!
+//! let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+//!
+//! let mut writer = MatFileWriter::new(Vec::new()).unwrap();
+//! writer.write_mat("a", a.as_ref()).unwrap();
+//! let bytes = writer.finish().unwrap();
+//!
+//! let file = MatFile::new(&*bytes).unwrap();
+//! assert!(file.read_mat::<f64>("a").unwrap() == a);
+//! ```
+
+use crate::{
+    complex_native::{c32, c64},
+    mat::MatRef,
+    sparse::{SparseColMat, SparseColMatRef, SymbolicSparseColMat},
+    Index, Mat, SignedIndex,
+};
+use alloc::{string::String, vec::Vec};
+use faer_entity::SimpleEntity;
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+// data types
+const MI_INT8: u32 = 1;
+const MI_UINT8: u32 = 2;
+const MI_INT16: u32 = 3;
+const MI_UINT16: u32 = 4;
+const MI_INT32: u32 = 5;
+const MI_UINT32: u32 = 6;
+const MI_SINGLE: u32 = 7;
+const MI_DOUBLE: u32 = 9;
+const MI_INT64: u32 = 12;
+const MI_UINT64: u32 = 13;
+const MI_MATRIX: u32 = 14;
+const MI_COMPRESSED: u32 = 15;
+
+// array classes
+const MX_SPARSE_CLASS: u32 = 5;
+const MX_DOUBLE_CLASS: u32 = 6;
+const MX_SINGLE_CLASS: u32 = 7;
+const MX_UINT64_CLASS: u32 = 15;
+
+const FLAG_COMPLEX: u32 = 0x0800;
+
+/// Trait implemented for native types that can be read from and written to a `.mat` file.
+pub trait MatFileEntity: SimpleEntity {
+    /// Whether the type is complex.
+    const IS_COMPLEX: bool;
+    #[doc(hidden)]
+    const CLASS: (u32, u32);
+
+    /// Creates a value from its real and imaginary parts, discarding the imaginary part if the
+    /// type is real.
+    fn from_parts(re: f64, im: f64) -> Self;
+    /// Returns the real and imaginary parts of the value.
+    fn to_parts(self) -> (f64, f64);
+}
+
+impl MatFileEntity for f32 {
+    const IS_COMPLEX: bool = false;
+    const CLASS: (u32, u32) = (MX_SINGLE_CLASS, MI_SINGLE);
+
+    #[inline]
+    fn from_parts(re: f64, _: f64) -> Self {
+        re as f32
+    }
+    #[inline]
+    fn to_parts(self) -> (f64, f64) {
+        (self as f64, 0.0)
+    }
+}
+impl MatFileEntity for f64 {
+    const IS_COMPLEX: bool = false;
+    const CLASS: (u32, u32) = (MX_DOUBLE_CLASS, MI_DOUBLE);
+
+    #[inline]
+    fn from_parts(re: f64, _: f64) -> Self {
+        re
+    }
+    #[inline]
+    fn to_parts(self) -> (f64, f64) {
+        (self, 0.0)
+    }
+}
+impl MatFileEntity for c32 {
+    const IS_COMPLEX: bool = true;
+    const CLASS: (u32, u32) = (MX_SINGLE_CLASS, MI_SINGLE);
+
+    #[inline]
+    fn from_parts(re: f64, im: f64) -> Self {
+        c32::new(re as f32, im as f32)
+    }
+    #[inline]
+    fn to_parts(self) -> (f64, f64) {
+        (self.re as f64, self.im as f64)
+    }
+}
+impl MatFileEntity for c64 {
+    const IS_COMPLEX: bool = true;
+    const CLASS: (u32, u32) = (MX_DOUBLE_CLASS, MI_DOUBLE);
+
+    #[inline]
+    fn from_parts(re: f64, im: f64) -> Self {
+        c64::new(re, im)
+    }
+    #[inline]
+    fn to_parts(self) -> (f64, f64) {
+        (self.re, self.im)
+    }
+}
+
+fn invalid(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+enum Array {
+    Dense {
+        nrows: usize,
+        ncols: usize,
+        re: Vec<f64>,
+        im: Option<Vec<f64>>,
+    },
+    Sparse {
+        nrows: usize,
+        ncols: usize,
+        col_ptrs: Vec<usize>,
+        row_indices: Vec<usize>,
+        re: Vec<f64>,
+        im: Option<Vec<f64>>,
+    },
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> Cursor<'a> {
+    fn u32(&self, bytes: &[u8]) -> u32 {
+        let bytes = bytes[..4].try_into().unwrap();
+        if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        }
+    }
+
+    // reads the next data element, returning its type and data
+    fn element(&mut self) -> io::Result<(u32, &'a [u8])> {
+        if self.data.len() < 8 {
+            return Err(invalid("truncated data element"));
+        }
+        let tag = self.u32(self.data);
+        let (ty, data, len) = if tag >> 16 != 0 {
+            // small data element format
+            let size = (tag >> 16) as usize;
+            if size > 4 {
+                return Err(invalid("invalid small data element"));
+            }
+            (tag & 0xFFFF, &self.data[4..4 + size], 8)
+        } else {
+            let size = self.u32(&self.data[4..]) as usize;
+            if self.data.len() - 8 < size {
+                return Err(invalid("truncated data element"));
+            }
+            // compressed elements are not padded
+            let padded = if tag == MI_COMPRESSED {
+                size
+            } else {
+                Ord::min(size + (8 - size % 8) % 8, self.data.len() - 8)
+            };
+            (tag, &self.data[8..8 + size], 8 + padded)
+        };
+        self.data = &self.data[len..];
+        Ok((ty, data))
+    }
+
+    fn numeric(&self, ty: u32, data: &[u8]) -> io::Result<Vec<f64>> {
+        macro_rules! convert {
+            ($ty: ty) => {{
+                const N: usize = core::mem::size_of::<$ty>();
+                if data.len() % N != 0 {
+                    return Err(invalid("invalid numeric data length"));
+                }
+                data.chunks_exact(N)
+                    .map(|bytes| {
+                        let bytes = bytes.try_into().unwrap();
+                        if self.big_endian {
+                            <$ty>::from_be_bytes(bytes) as f64
+                        } else {
+                            <$ty>::from_le_bytes(bytes) as f64
+                        }
+                    })
+                    .collect()
+            }};
+        }
+
+        Ok(match ty {
+            MI_INT8 => convert!(i8),
+            MI_UINT8 => convert!(u8),
+            MI_INT16 => convert!(i16),
+            MI_UINT16 => convert!(u16),
+            MI_INT32 => convert!(i32),
+            MI_UINT32 => convert!(u32),
+            MI_SINGLE => convert!(f32),
+            MI_DOUBLE => convert!(f64),
+            MI_INT64 => convert!(i64),
+            MI_UINT64 => convert!(u64),
+            _ => return Err(invalid("unsupported numeric data type")),
+        })
+    }
+
+    fn indices(&self, ty: u32, data: &[u8]) -> io::Result<Vec<usize>> {
+        self.numeric(ty, data)?
+            .into_iter()
+            .map(|x| {
+                if x >= 0.0 && x == x.trunc() && x <= usize::MAX as f64 {
+                    Ok(x as usize)
+                } else {
+                    Err(invalid("invalid index"))
+                }
+            })
+            .collect()
+    }
+
+    // parses the contents of a matrix element. returns `None` for unsupported arrays
+    fn matrix(&mut self) -> io::Result<Option<(String, Array)>> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        let (_, flags) = self.element()?;
+        if flags.len() < 8 {
+            return Err(invalid("invalid array flags"));
+        }
+        let flags = self.u32(flags);
+        let class = flags & 0xFF;
+        let is_complex = flags & FLAG_COMPLEX != 0;
+
+        let (ty, dims) = self.element()?;
+        let dims = self.indices(ty, dims)?;
+        let (_, name) = self.element()?;
+        let name = String::from_utf8(name.to_vec()).map_err(|_| invalid("invalid array name"))?;
+
+        if dims.len() != 2 {
+            return Ok(None);
+        }
+        let (nrows, ncols) = (dims[0], dims[1]);
+        let len = nrows
+            .checked_mul(ncols)
+            .ok_or_else(|| invalid("invalid dimensions"))?;
+
+        if class == MX_SPARSE_CLASS {
+            let (ty, row_indices) = self.element()?;
+            let mut row_indices = self.indices(ty, row_indices)?;
+            let (ty, col_ptrs) = self.element()?;
+            let col_ptrs = self.indices(ty, col_ptrs)?;
+
+            if col_ptrs.len() != ncols + 1
+                || col_ptrs[0] != 0
+                || col_ptrs.windows(2).any(|w| w[0] > w[1])
+                || col_ptrs[ncols] > row_indices.len()
+            {
+                return Err(invalid("invalid sparse column pointers"));
+            }
+            let nnz = col_ptrs[ncols];
+            row_indices.truncate(nnz);
+            if row_indices.iter().any(|&i| i >= nrows) {
+                return Err(invalid("invalid sparse row indices"));
+            }
+
+            let part = |this: &mut Self| -> io::Result<Vec<f64>> {
+                let (ty, values) = this.element()?;
+                let mut values = this.numeric(ty, values)?;
+                if values.len() < nnz {
+                    return Err(invalid("invalid sparse values"));
+                }
+                values.truncate(nnz);
+                Ok(values)
+            };
+            // logical sparse arrays may omit their values
+            let re = if self.data.is_empty() {
+                alloc::vec![1.0; nnz]
+            } else {
+                part(self)?
+            };
+            let im = if is_complex { Some(part(self)?) } else { None };
+
+            Ok(Some((
+                name,
+                Array::Sparse {
+                    nrows,
+                    ncols,
+                    col_ptrs,
+                    row_indices,
+                    re,
+                    im,
+                },
+            )))
+        } else if (MX_DOUBLE_CLASS..=MX_UINT64_CLASS).contains(&class) {
+            let part = |this: &mut Self| -> io::Result<Vec<f64>> {
+                let (ty, values) = this.element()?;
+                let values = this.numeric(ty, values)?;
+                if values.len() != len {
+                    return Err(invalid("invalid array data length"));
+                }
+                Ok(values)
+            };
+            let re = part(self)?;
+            let im = if is_complex { Some(part(self)?) } else { None };
+
+            Ok(Some((
+                name,
+                Array::Dense {
+                    nrows,
+                    ncols,
+                    re,
+                    im,
+                },
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Contents of a `.mat` file, loaded into memory.
+pub struct MatFile {
+    variables: Vec<(String, Array)>,
+}
+
+impl MatFile {
+    /// Reads the `.mat` file at the given path.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a `.mat` file from `reader`.
+    pub fn new(reader: impl Read) -> io::Result<Self> {
+        let mut reader = reader;
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        if data.len() < 128 {
+            return Err(invalid("truncated header"));
+        }
+        let big_endian = match &data[126..128] {
+            b"IM" => false,
+            b"MI" => true,
+            _ => return Err(invalid("not a level 5 MAT-file")),
+        };
+
+        let mut variables = Vec::new();
+        let mut cursor = Cursor {
+            data: &data[128..],
+            big_endian,
+        };
+        while !cursor.data.is_empty() {
+            let (ty, element) = cursor.element()?;
+            let decompressed;
+            let (ty, element) = if ty == MI_COMPRESSED {
+                let mut out = Vec::new();
+                flate2::read::ZlibDecoder::new(element).read_to_end(&mut out)?;
+                decompressed = out;
+                let mut inner = Cursor {
+                    data: &decompressed,
+                    big_endian,
+                };
+                inner.element()?
+            } else {
+                (ty, element)
+            };
+
+            if ty == MI_MATRIX {
+                let mut inner = Cursor {
+                    data: element,
+                    big_endian,
+                };
+                if let Some(variable) = inner.matrix()? {
+                    variables.push(variable);
+                }
+            }
+        }
+
+        Ok(Self { variables })
+    }
+
+    fn get(&self, name: &str) -> io::Result<&Array> {
+        self.variables
+            .iter()
+            .find(|(var, _)| var == name)
+            .map(|(_, array)| array)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "variable not found"))
+    }
+
+    /// Returns the names of the variables that can be read from the file.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.variables.iter().map(|(name, _)| &**name)
+    }
+
+    /// Returns whether the variable with the given name is a sparse array, or `None` if it
+    /// doesn't exist.
+    pub fn is_sparse(&self, name: &str) -> Option<bool> {
+        self.get(name)
+            .ok()
+            .map(|array| matches!(array, Array::Sparse { .. }))
+    }
+
+    /// Reads the variable with the given name as a dense matrix.
+    ///
+    /// Sparse arrays are converted to dense matrices. An error is returned if the variable
+    /// doesn't exist, or if it is complex and `E` is a real type.
+    pub fn read_mat<E: MatFileEntity>(&self, name: &str) -> io::Result<Mat<E>> {
+        match self.get(name)? {
+            Array::Dense {
+                nrows,
+                ncols,
+                re,
+                im,
+            } => {
+                check_complex::<E>(im)?;
+                Ok(Mat::from_fn(*nrows, *ncols, |i, j| {
+                    let idx = i + j * nrows;
+                    E::from_parts(re[idx], im.as_ref().map(|im| im[idx]).unwrap_or(0.0))
+                }))
+            }
+            Array::Sparse { nrows, ncols, .. } => {
+                let sparse = self.read_sparse::<usize, E>(name)?;
+                let mut mat = Mat::<E>::zeros(*nrows, *ncols);
+                let sparse = sparse.as_ref();
+                for j in 0..*ncols {
+                    for (i, &value) in sparse.row_indices_of_col(j).zip(sparse.values_of_col(j)) {
+                        mat.write(i, j, value);
+                    }
+                }
+                Ok(mat)
+            }
+        }
+    }
+
+    /// Reads the variable with the given name as a sparse matrix.
+    ///
+    /// An error is returned if the variable doesn't exist, if it is not a sparse array, if it is
+    /// complex and `E` is a real type, or if its dimensions or number of nonzeros don't fit in `I`.
+    pub fn read_sparse<I: Index, E: MatFileEntity>(
+        &self,
+        name: &str,
+    ) -> io::Result<SparseColMat<I, E>> {
+        match self.get(name)? {
+            Array::Dense { .. } => Err(invalid("the variable is not a sparse array")),
+            Array::Sparse {
+                nrows,
+                ncols,
+                col_ptrs,
+                row_indices,
+                re,
+                im,
+            } => {
+                check_complex::<E>(im)?;
+                let max = I::Signed::MAX.zx();
+                if *nrows > max || *ncols > max || row_indices.len() > max {
+                    return Err(invalid("the sparse array is too large for the index type"));
+                }
+                let symbolic = SymbolicSparseColMat::new_unsorted_checked(
+                    *nrows,
+                    *ncols,
+                    col_ptrs.iter().map(|&p| I::truncate(p)).collect(),
+                    None,
+                    row_indices.iter().map(|&i| I::truncate(i)).collect(),
+                );
+                let values = (0..re.len())
+                    .map(|k| E::from_parts(re[k], im.as_ref().map(|im| im[k]).unwrap_or(0.0)))
+                    .collect();
+                Ok(SparseColMat::new(symbolic, values))
+            }
+        }
+    }
+}
+
+fn check_complex<E: MatFileEntity>(im: &Option<Vec<f64>>) -> io::Result<()> {
+    if im.is_some() && !E::IS_COMPLEX {
+        Err(invalid(
+            "the variable is complex, but the requested type is real",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Writer for a `.mat` file, which can be loaded with MATLAB's or Octave's `load`.
+pub struct MatFileWriter<W: Write> {
+    writer: W,
+}
+
+impl MatFileWriter<BufWriter<File>> {
+    /// Creates a `.mat` file at the given path. The file is truncated if it already exists.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+// appends a data element with the given type and data, padded to a multiple of 8 bytes
+fn push_element(out: &mut Vec<u8>, ty: u32, data: &[u8]) -> io::Result<()> {
+    let size = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "the array is too large"))?;
+    out.extend_from_slice(&ty.to_le_bytes());
+    out.extend_from_slice(&size.to_le_bytes());
+    out.extend_from_slice(data);
+    out.extend(core::iter::repeat(0u8).take((8 - data.len() % 8) % 8));
+    Ok(())
+}
+
+fn push_values(out: &mut Vec<u8>, ty: u32, values: impl Iterator<Item = f64>) -> io::Result<()> {
+    let mut data = Vec::new();
+    for value in values {
+        if ty == MI_SINGLE {
+            data.extend_from_slice(&(value as f32).to_le_bytes());
+        } else {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    push_element(out, ty, &data)
+}
+
+fn push_indices(out: &mut Vec<u8>, indices: impl Iterator<Item = usize>) -> io::Result<()> {
+    let mut data = Vec::new();
+    for index in indices {
+        let index = i32::try_from(index)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "the array is too large"))?;
+        data.extend_from_slice(&index.to_le_bytes());
+    }
+    push_element(out, MI_INT32, &data)
+}
+
+impl<W: Write> MatFileWriter<W> {
+    /// Creates a writer that writes the file to `writer`, and writes the file header.
+    pub fn new(writer: W) -> io::Result<Self> {
+        let mut writer = writer;
+        let mut header = [b' '; 128];
+        let text = b"MATLAB 5.0 MAT-file, created by faer";
+        header[..text.len()].copy_from_slice(text);
+        // subsystem data offset
+        header[116..124].fill(0);
+        // version and endianness indicator
+        header[124..126].copy_from_slice(&0x0100u16.to_le_bytes());
+        header[126..128].copy_from_slice(b"IM");
+        writer.write_all(&header)?;
+        Ok(Self { writer })
+    }
+
+    fn write_variable(
+        &mut self,
+        name: &str,
+        class: u32,
+        is_complex: bool,
+        nzmax: usize,
+        nrows: usize,
+        ncols: usize,
+        write_data: impl FnOnce(&mut Vec<u8>) -> io::Result<()>,
+    ) -> io::Result<()> {
+        let valid_name = name.len() <= 63
+            && name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid variable name",
+            ));
+        }
+
+        let mut contents = Vec::new();
+        let flags = class | if is_complex { FLAG_COMPLEX } else { 0 };
+        let nzmax = u32::try_from(nzmax)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "the array is too large"))?;
+        let mut flags_data = Vec::new();
+        flags_data.extend_from_slice(&flags.to_le_bytes());
+        flags_data.extend_from_slice(&nzmax.to_le_bytes());
+        push_element(&mut contents, MI_UINT32, &flags_data)?;
+        push_indices(&mut contents, [nrows, ncols].into_iter())?;
+        push_element(&mut contents, MI_INT8, name.as_bytes())?;
+        write_data(&mut contents)?;
+
+        let mut element = Vec::new();
+        push_element(&mut element, MI_MATRIX, &contents)?;
+        self.writer.write_all(&element)
+    }
+
+    /// Writes the matrix `mat` as a dense array with the given name.
+    ///
+    /// An error is returned if the name is not a valid MATLAB variable name.
+    pub fn write_mat<E: MatFileEntity>(
+        &mut self,
+        name: &str,
+        mat: MatRef<'_, E>,
+    ) -> io::Result<()> {
+        let (class, ty) = E::CLASS;
+        let (nrows, ncols) = (mat.nrows(), mat.ncols());
+        let values = move || (0..ncols).flat_map(move |j| (0..nrows).map(move |i| mat.read(i, j)));
+        self.write_variable(name, class, E::IS_COMPLEX, 0, nrows, ncols, |out| {
+            push_values(out, ty, values().map(|x| x.to_parts().0))?;
+            if E::IS_COMPLEX {
+                push_values(out, ty, values().map(|x| x.to_parts().1))?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Writes the matrix `mat` as a sparse array with the given name.
+    ///
+    /// Duplicate entries are summed by MATLAB when the file is loaded. An error is returned if the
+    /// name is not a valid MATLAB variable name.
+    pub fn write_sparse<I: Index, E: MatFileEntity>(
+        &mut self,
+        name: &str,
+        mat: SparseColMatRef<'_, I, E>,
+    ) -> io::Result<()> {
+        let (nrows, ncols) = (mat.nrows(), mat.ncols());
+        let nnz = mat.compute_nnz();
+        let values = move || (0..ncols).flat_map(move |j| mat.values_of_col(j).iter().copied());
+        self.write_variable(
+            name,
+            MX_SPARSE_CLASS,
+            E::IS_COMPLEX,
+            nnz,
+            nrows,
+            ncols,
+            |out| {
+                push_indices(out, (0..ncols).flat_map(|j| mat.row_indices_of_col(j)))?;
+                let mut col_ptrs = alloc::vec![0usize; ncols + 1];
+                for j in 0..ncols {
+                    col_ptrs[j + 1] = col_ptrs[j] + mat.col_range(j).len();
+                }
+                push_indices(out, col_ptrs.into_iter())?;
+                push_values(out, MI_DOUBLE, values().map(|x| x.to_parts().0))?;
+                if E::IS_COMPLEX {
+                    push_values(out, MI_DOUBLE, values().map(|x| x.to_parts().1))?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Flushes the file, and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert;
+
+    #[test]
+    fn test_dense_roundtrip() {
+        let a = Mat::<f64>::from_fn(4, 3, |i, j| (i + 10 * j) as f64);
+        let b = Mat::<c32>::from_fn(2, 5, |i, j| c32::new(i as f32, -(j as f32)));
+
+        let mut writer = MatFileWriter::new(Vec::new()).unwrap();
+        writer.write_mat("a", a.as_ref()).unwrap();
+        writer.write_mat("b_transpose", b.transpose()).unwrap();
+        assert!(writer.write_mat("1a", a.as_ref()).is_err());
+        let bytes = writer.finish().unwrap();
+        assert!(bytes.len() % 8 == 0);
+
+        let file = MatFile::new(&*bytes).unwrap();
+        assert!(file.names().collect::<Vec<_>>() == ["a", "b_transpose"]);
+        assert!(file.is_sparse("a") == Some(false));
+        assert!(file.is_sparse("c").is_none());
+        assert!(file.read_mat::<f64>("a").unwrap() == a);
+        assert!(
+            file.read_mat::<c64>("a").unwrap()
+                == Mat::from_fn(4, 3, |i, j| c64::new(a.read(i, j), 0.0))
+        );
+        assert!(file.read_mat::<c32>("b_transpose").unwrap() == b.transpose());
+        assert!(file.read_mat::<f32>("b_transpose").is_err());
+        assert!(file.read_sparse::<usize, f64>("a").is_err());
+        assert!(file.read_mat::<f64>("c").unwrap_err().kind() == io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_sparse_roundtrip() {
+        let a = SparseColMat::<u32, c64>::try_new_from_triplets(
+            5,
+            4,
+            &[
+                (0, 0, c64::new(1.0, 2.0)),
+                (3, 0, c64::new(-1.0, 0.0)),
+                (2, 2, c64::new(0.5, 0.5)),
+                (4, 3, c64::new(3.0, -3.0)),
+            ],
+        )
+        .unwrap();
+
+        let mut writer = MatFileWriter::new(Vec::new()).unwrap();
+        writer.write_sparse("a", a.as_ref()).unwrap();
+        let bytes = writer.finish().unwrap();
+
+        let file = MatFile::new(&*bytes).unwrap();
+        assert!(file.is_sparse("a") == Some(true));
+        let b = file.read_sparse::<u32, c64>("a").unwrap();
+        assert!(b.as_ref().col_ptrs() == a.as_ref().col_ptrs());
+        assert!(b.as_ref().row_indices() == a.as_ref().row_indices());
+        assert!(b.as_ref().values() == a.as_ref().values());
+        assert!(file.read_mat::<c64>("a").unwrap() == a.to_dense());
+        assert!(file.read_sparse::<usize, f64>("a").is_err());
+    }
+
+    #[test]
+    fn test_compressed_big_endian() {
+        // `x = int16([1 -2; 3 4])` as written by a big endian machine, with the variable
+        // compressed
+        let mut contents = Vec::new();
+        let mut push = |ty: u32, data: &[u8]| {
+            contents.extend_from_slice(&ty.to_be_bytes());
+            contents.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            contents.extend_from_slice(data);
+            contents.extend(core::iter::repeat(0u8).take((8 - data.len() % 8) % 8));
+        };
+        push(MI_UINT32, &[0, 0, 0, 10, 0, 0, 0, 0]);
+        push(MI_INT32, &[0, 0, 0, 2, 0, 0, 0, 2]);
+        push(MI_INT8, b"x");
+        push(MI_INT16, &[0, 1, 0, 3, 255, 254, 0, 4]);
+
+        let mut element = Vec::new();
+        element.extend_from_slice(&MI_MATRIX.to_be_bytes());
+        element.extend_from_slice(&(contents.len() as u32).to_be_bytes());
+        element.extend_from_slice(&contents);
+
+        let mut encoder =
+            flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&element).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut bytes = alloc::vec![b' '; 128];
+        bytes[124..126].copy_from_slice(&0x0100u16.to_be_bytes());
+        bytes[126..128].copy_from_slice(b"MI");
+        bytes.extend_from_slice(&MI_COMPRESSED.to_be_bytes());
+        bytes.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&compressed);
+
+        let file = MatFile::new(&*bytes).unwrap();
+        assert!(file.read_mat::<f64>("x").unwrap() == crate::mat![[1.0, -2.0], [3.0, 4.0f64]]);
+    }
+}
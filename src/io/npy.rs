@@ -0,0 +1,756 @@
+//! Reading and writing dense matrices and vectors in NumPy's `.npy` format, and in zipped `.npz`
+//! bundles of `.npy` files.
+//!
+//! Matrices are written in column-major (Fortran) order, and vectors as one-dimensional arrays, so
+//! that they can be loaded with `numpy.load`. Files written by NumPy can be read whether they're
+//! stored in row-major or column-major order.
+//!
+//! ```
+//! use faer::{io::npy, mat};
+//!
+//! let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+//!
+//! let mut bytes = Vec::new();
+//! npy::write_mat(&mut bytes, a.as_ref()).unwrap();
+//! let b = npy::read_mat::<f64>(&*bytes).unwrap();
+//! assert!(a == b);
+//! ```
+
+use crate::{
+    assert,
+    col::ColRef,
+    complex_native::{c32, c64},
+    mat::MatRef,
+    Col, Mat,
+};
+use std::io::{self, Read, Write};
+
+/// Memory view over a buffer in `npy` format.
+#[cfg(feature = "npy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npy")))]
+pub struct Npy<'a> {
+    aligned_bytes: &'a [u8],
+    nrows: usize,
+    ncols: usize,
+    ndim: usize,
+    prefix_len: usize,
+    dtype: NpyDType,
+    fortran_order: bool,
+    native_endian: bool,
+}
+
+/// Data type of an `npy` buffer.
+#[cfg(feature = "npy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npy")))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NpyDType {
+    /// 32-bit floating point.
+    F32,
+    /// 64-bit floating point.
+    F64,
+    /// 32-bit complex floating point.
+    C32,
+    /// 64-bit complex floating point.
+    C64,
+    /// Unknown type.
+    Other,
+}
+
+impl NpyDType {
+    // size of a real scalar component, i.e. half the size of the complex types
+    fn component_size(self) -> Option<usize> {
+        match self {
+            NpyDType::F32 | NpyDType::C32 => Some(4),
+            NpyDType::F64 | NpyDType::C64 => Some(8),
+            NpyDType::Other => None,
+        }
+    }
+
+    fn size(self) -> Option<usize> {
+        match self {
+            NpyDType::F32 | NpyDType::F64 => self.component_size(),
+            NpyDType::C32 | NpyDType::C64 => self.component_size().map(|size| 2 * size),
+            NpyDType::Other => None,
+        }
+    }
+}
+
+/// Trait implemented for native types that can be read from a `npy` buffer.
+#[cfg(feature = "npy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npy")))]
+pub trait FromNpy: faer_entity::SimpleEntity {
+    /// Data type of the buffer data.
+    const DTYPE: NpyDType;
+}
+
+#[cfg(feature = "npy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npy")))]
+impl FromNpy for f32 {
+    const DTYPE: NpyDType = NpyDType::F32;
+}
+#[cfg(feature = "npy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npy")))]
+impl FromNpy for f64 {
+    const DTYPE: NpyDType = NpyDType::F64;
+}
+#[cfg(feature = "npy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npy")))]
+impl FromNpy for c32 {
+    const DTYPE: NpyDType = NpyDType::C32;
+}
+#[cfg(feature = "npy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npy")))]
+impl FromNpy for c64 {
+    const DTYPE: NpyDType = NpyDType::C64;
+}
+
+#[cfg(feature = "npy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npy")))]
+impl<'a> Npy<'a> {
+    fn parse_npyz(
+        data: &[u8],
+        npyz: npyz::NpyFile<&[u8]>,
+    ) -> Result<(NpyDType, usize, usize, usize, bool, bool), io::Error> {
+        let ver_major = data[6] - b'\x00';
+        let length = if ver_major <= 1 {
+            2usize
+        } else if ver_major <= 3 {
+            4usize
+        } else {
+            return Err(io::Error::new(io::ErrorKind::Other, "unsupported version"));
+        };
+        let header_len = if length == 2 {
+            u16::from_le_bytes(data[8..10].try_into().unwrap()) as usize
+        } else {
+            u32::from_le_bytes(data[8..12].try_into().unwrap()) as usize
+        };
+        let native_endian = match npyz.dtype() {
+            npyz::DType::Plain(str) => matches!(
+                (str.endianness(), cfg!(target_endian = "little")),
+                (npyz::Endianness::Irrelevant, _)
+                    | (npyz::Endianness::Little, true)
+                    | (npyz::Endianness::Big, false)
+            ),
+            _ => true,
+        };
+        let dtype = || -> NpyDType {
+            match npyz.dtype() {
+                npyz::DType::Plain(str) => {
+                    let is_complex = match str.type_char() {
+                        npyz::TypeChar::Float => false,
+                        npyz::TypeChar::Complex => true,
+                        _ => return NpyDType::Other,
+                    };
+
+                    let byte_size = str.size_field();
+                    if byte_size == 8 && is_complex {
+                        NpyDType::C32
+                    } else if byte_size == 16 && is_complex {
+                        NpyDType::C64
+                    } else if byte_size == 4 && !is_complex {
+                        NpyDType::F32
+                    } else if byte_size == 8 && !is_complex {
+                        NpyDType::F64
+                    } else {
+                        NpyDType::Other
+                    }
+                }
+                _ => NpyDType::Other,
+            }
+        };
+
+        let dtype = dtype();
+        let order = npyz.header().order();
+        let shape = npyz.shape();
+        if shape.len() > 2 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "arrays with more than two dimensions are not supported",
+            ));
+        }
+        let nrows = shape.get(0).copied().unwrap_or(1) as usize;
+        let ncols = shape.get(1).copied().unwrap_or(1) as usize;
+        let prefix_len = 8 + length + header_len;
+        let fortran_order = order == npyz::Order::Fortran;
+        Ok((
+            dtype,
+            nrows,
+            ncols,
+            prefix_len,
+            fortran_order,
+            native_endian,
+        ))
+    }
+
+    /// Parse a npy file from a memory buffer.
+    ///
+    /// An error is returned if the buffer is not a valid `npy` file, or if it is too short to
+    /// contain all the elements of the array.
+    #[inline]
+    pub fn new(data: &'a [u8]) -> Result<Self, io::Error> {
+        let npyz = npyz::NpyFile::new(data)?;
+        let ndim = npyz.shape().len();
+
+        let (dtype, nrows, ncols, prefix_len, fortran_order, native_endian) =
+            Self::parse_npyz(data, npyz)?;
+
+        if let Some(size) = dtype.size() {
+            let payload_len = nrows
+                .checked_mul(ncols)
+                .and_then(|len| len.checked_mul(size));
+            let available = data.len().checked_sub(prefix_len);
+            match (payload_len, available) {
+                (Some(payload_len), Some(available)) if payload_len <= available => {}
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "the buffer is too short to contain the array data",
+                    ))
+                }
+            }
+        }
+
+        Ok(Self {
+            aligned_bytes: data,
+            prefix_len,
+            nrows,
+            ncols,
+            ndim,
+            dtype,
+            fortran_order,
+            native_endian,
+        })
+    }
+
+    /// Returns the number of rows of the array, or its length if it is one-dimensional.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns of the array, or one if it is one-dimensional.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns the number of dimensions of the array, which is at most two.
+    #[inline]
+    pub fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Returns the data type of the memory buffer.
+    #[inline]
+    pub fn dtype(&self) -> NpyDType {
+        self.dtype
+    }
+
+    /// Checks if the memory buffer is aligned, in which case the data can be referenced in-place.
+    #[inline]
+    pub fn is_aligned(&self) -> bool {
+        self.aligned_bytes.as_ptr().align_offset(64) == 0
+    }
+
+    /// Checks if the data is stored in the byte order of the target, in which case it can be
+    /// referenced in-place. Otherwise, it is byte-swapped when read with [`Npy::to_mat`].
+    #[inline]
+    pub fn is_native_endian(&self) -> bool {
+        self.native_endian
+    }
+
+    /// If the memory buffer is aligned, the data is stored in native byte order, and the provided
+    /// type matches the one stored in the buffer, returns a matrix view over the data.
+    #[inline]
+    pub fn as_aligned_ref<E: FromNpy>(&self) -> MatRef<'_, E> {
+        assert!(self.is_aligned());
+        assert!(self.is_native_endian());
+        assert!(self.dtype == E::DTYPE);
+
+        if self.fortran_order {
+            crate::mat::from_column_major_slice(
+                bytemuck::cast_slice(&self.aligned_bytes[self.prefix_len..]),
+                self.nrows,
+                self.ncols,
+            )
+        } else {
+            crate::mat::from_row_major_slice(
+                bytemuck::cast_slice(&self.aligned_bytes[self.prefix_len..]),
+                self.nrows,
+                self.ncols,
+            )
+        }
+    }
+
+    /// If the provided type matches the one stored in the buffer, returns a matrix containing the
+    /// data.
+    #[inline]
+    pub fn to_mat<E: FromNpy>(&self) -> Mat<E> {
+        assert!(self.dtype == E::DTYPE);
+
+        let mut mat = Mat::<E>::with_capacity(self.nrows, self.ncols);
+        unsafe { mat.set_dims(self.nrows, self.ncols) };
+
+        let data = &self.aligned_bytes[self.prefix_len..];
+
+        if self.fortran_order {
+            for j in 0..self.ncols {
+                bytemuck::cast_slice_mut(mat.col_as_slice_mut(j)).copy_from_slice(
+                    &data[j * self.nrows * core::mem::size_of::<E>()..]
+                        [..self.nrows * core::mem::size_of::<E>()],
+                )
+            }
+        } else {
+            for j in 0..self.ncols {
+                for i in 0..self.nrows {
+                    bytemuck::cast_slice_mut(&mut mat.col_as_slice_mut(j)[i..i + 1])
+                        .copy_from_slice(
+                            &data[(i * self.ncols + j) * core::mem::size_of::<E>()..]
+                                [..core::mem::size_of::<E>()],
+                        )
+                }
+            }
+        };
+
+        if !self.native_endian {
+            let component_size = self.dtype.component_size().unwrap();
+            for j in 0..self.ncols {
+                let col: &mut [u8] = bytemuck::cast_slice_mut(mat.col_as_slice_mut(j));
+                for x in col.chunks_exact_mut(component_size) {
+                    x.reverse();
+                }
+            }
+        }
+
+        mat
+    }
+    /// If the provided type matches the one stored in the buffer, and the array is one-dimensional
+    /// or has a single column, returns a column vector containing the data.
+    #[inline]
+    pub fn to_col<E: FromNpy>(&self) -> Col<E> {
+        assert!(self.ncols == 1);
+        let mat = self.to_mat::<E>();
+        Col::from_fn(self.nrows, |i| mat.read(i, 0))
+    }
+
+    fn check_dtype<E: FromNpy>(&self) -> io::Result<()> {
+        if self.dtype == E::DTYPE {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the data type of the array doesn't match the requested type",
+            ))
+        }
+    }
+}
+
+fn descr(dtype: NpyDType) -> &'static str {
+    let little = cfg!(target_endian = "little");
+    match (dtype, little) {
+        (NpyDType::F32, true) => "<f4",
+        (NpyDType::F64, true) => "<f8",
+        (NpyDType::C32, true) => "<c8",
+        (NpyDType::C64, true) => "<c16",
+        (NpyDType::F32, false) => ">f4",
+        (NpyDType::F64, false) => ">f8",
+        (NpyDType::C32, false) => ">c8",
+        (NpyDType::C64, false) => ">c16",
+        (NpyDType::Other, _) => unreachable!(),
+    }
+}
+
+// writes a version 1.0 header, padded so that the data starts at a multiple of 64 bytes, like
+// numpy does
+fn write_header(writer: &mut dyn Write, dtype: NpyDType, shape: &[usize]) -> io::Result<()> {
+    let shape = match shape {
+        [n] => format!("({n},)"),
+        [m, n] => format!("({m}, {n})"),
+        _ => unreachable!(),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': True, 'shape': {shape}, }}",
+        descr(dtype)
+    );
+    // magic string, version, header length, header, newline
+    let unpadded = 6 + 2 + 2 + header.len() + 1;
+    header.extend(core::iter::repeat(' ').take((64 - unpadded % 64) % 64));
+    header.push('\n');
+    let header_len = u16::try_from(header.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "the header is too long"))?;
+
+    writer.write_all(b"\x93NUMPY\x01\x00")?;
+    writer.write_all(&header_len.to_le_bytes())?;
+    writer.write_all(header.as_bytes())
+}
+
+fn write_col_data<E: FromNpy>(writer: &mut dyn Write, col: ColRef<'_, E>) -> io::Result<()> {
+    if col.row_stride() == 1 {
+        writer.write_all(bytemuck::cast_slice(col.try_as_slice().unwrap()))
+    } else {
+        for i in 0..col.nrows() {
+            writer.write_all(bytemuck::bytes_of(&col.read(i)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the matrix `mat` to `writer` in the `npy` format, as a two-dimensional array stored in
+/// column-major order.
+pub fn write_mat<E: FromNpy>(writer: impl Write, mat: MatRef<'_, E>) -> io::Result<()> {
+    let mut writer = writer;
+    write_header(&mut writer, E::DTYPE, &[mat.nrows(), mat.ncols()])?;
+    for j in 0..mat.ncols() {
+        write_col_data(&mut writer, mat.col(j))?;
+    }
+    Ok(())
+}
+
+/// Writes the vector `col` to `writer` in the `npy` format, as a one-dimensional array.
+pub fn write_col<E: FromNpy>(writer: impl Write, col: ColRef<'_, E>) -> io::Result<()> {
+    let mut writer = writer;
+    write_header(&mut writer, E::DTYPE, &[col.nrows()])?;
+    write_col_data(&mut writer, col)
+}
+
+/// Reads a matrix in the `npy` format from `reader`.
+///
+/// One-dimensional arrays are read as matrices with a single column. An error is returned if the
+/// data is not a valid `npy` buffer, or if its data type doesn't match `E`.
+pub fn read_mat<E: FromNpy>(reader: impl Read) -> io::Result<Mat<E>> {
+    let mut reader = reader;
+    let mut data = alloc::vec::Vec::new();
+    reader.read_to_end(&mut data)?;
+    let npy = Npy::new(&data)?;
+    npy.check_dtype::<E>()?;
+    Ok(npy.to_mat())
+}
+
+/// Reads a vector in the `npy` format from `reader`.
+///
+/// An error is returned if the data is not a valid `npy` buffer, if its data type doesn't match
+/// `E`, or if the array is neither one-dimensional nor has a single column.
+pub fn read_col<E: FromNpy>(reader: impl Read) -> io::Result<Col<E>> {
+    let mut reader = reader;
+    let mut data = alloc::vec::Vec::new();
+    reader.read_to_end(&mut data)?;
+    let npy = Npy::new(&data)?;
+    npy.check_dtype::<E>()?;
+    if npy.ncols() != 1 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the array is not a vector",
+        ));
+    }
+    Ok(npy.to_col())
+}
+
+#[cfg(feature = "npz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "npz")))]
+pub use npz::{NpzReader, NpzWriter};
+
+#[cfg(feature = "npz")]
+mod npz {
+    use super::*;
+    use std::{
+        fs::File,
+        io::{BufReader, BufWriter, Seek},
+        path::Path,
+    };
+
+    /// Reader for a `.npz` bundle, i.e. a zip archive of named `.npy` files, as written by
+    /// `numpy.savez` or `numpy.savez_compressed`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "npz")))]
+    pub struct NpzReader<R: Read + Seek> {
+        archive: zip::ZipArchive<R>,
+    }
+
+    impl NpzReader<BufReader<File>> {
+        /// Opens the `.npz` file at the given path.
+        pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+            Self::new(BufReader::new(File::open(path)?))
+        }
+    }
+
+    impl<R: Read + Seek> NpzReader<R> {
+        /// Creates a reader over the zip archive contained in `reader`.
+        pub fn new(reader: R) -> io::Result<Self> {
+            Ok(Self {
+                archive: zip::ZipArchive::new(reader)?,
+            })
+        }
+
+        /// Returns the names of the arrays in the archive, without the `.npy` extension.
+        pub fn names(&self) -> impl Iterator<Item = &str> {
+            self.archive
+                .file_names()
+                .map(|name| name.strip_suffix(".npy").unwrap_or(name))
+        }
+
+        fn read_bytes(&mut self, name: &str) -> io::Result<alloc::vec::Vec<u8>> {
+            let npy_name = format!("{name}.npy");
+            let name = if self.archive.file_names().any(|file| file == npy_name) {
+                &*npy_name
+            } else {
+                name
+            };
+            let mut file = self.archive.by_name(name)?;
+            let mut data = alloc::vec::Vec::new();
+            file.read_to_end(&mut data)?;
+            Ok(data)
+        }
+
+        /// Reads the array with the given name as a matrix.
+        ///
+        /// One-dimensional arrays are read as matrices with a single column. An error is returned
+        /// if the array doesn't exist, or if its data type doesn't match `E`.
+        pub fn read_mat<E: FromNpy>(&mut self, name: &str) -> io::Result<Mat<E>> {
+            read_mat(&*self.read_bytes(name)?)
+        }
+
+        /// Reads the array with the given name as a vector.
+        ///
+        /// An error is returned if the array doesn't exist, if its data type doesn't match `E`, or
+        /// if it is neither one-dimensional nor has a single column.
+        pub fn read_col<E: FromNpy>(&mut self, name: &str) -> io::Result<Col<E>> {
+            read_col(&*self.read_bytes(name)?)
+        }
+    }
+
+    /// Writer for a `.npz` bundle, which can be loaded with `numpy.load`.
+    ///
+    /// The arrays are stored uncompressed, like `numpy.savez` does.
+    #[cfg_attr(docsrs, doc(cfg(feature = "npz")))]
+    pub struct NpzWriter<W: Write + Seek> {
+        zip: zip::ZipWriter<W>,
+    }
+
+    impl NpzWriter<BufWriter<File>> {
+        /// Creates a `.npz` file at the given path. The file is truncated if it already exists.
+        pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+            Ok(Self::new(BufWriter::new(File::create(path)?)))
+        }
+    }
+
+    impl<W: Write + Seek> NpzWriter<W> {
+        /// Creates a writer that writes the zip archive to `writer`.
+        pub fn new(writer: W) -> Self {
+            Self {
+                zip: zip::ZipWriter::new(writer),
+            }
+        }
+
+        fn start(&mut self, name: &str) -> io::Result<()> {
+            let options = zip::write::FileOptions::default()
+                .compression_method(zip::CompressionMethod::Stored)
+                .large_file(true);
+            self.zip.start_file(format!("{name}.npy"), options)?;
+            Ok(())
+        }
+
+        /// Adds the matrix `mat` to the archive under the given name.
+        pub fn write_mat<E: FromNpy>(&mut self, name: &str, mat: MatRef<'_, E>) -> io::Result<()> {
+            self.start(name)?;
+            write_mat(&mut self.zip, mat)
+        }
+
+        /// Adds the vector `col` to the archive under the given name.
+        pub fn write_col<E: FromNpy>(&mut self, name: &str, col: ColRef<'_, E>) -> io::Result<()> {
+            self.start(name)?;
+            write_col(&mut self.zip, col)
+        }
+
+        /// Writes the central directory of the archive, and returns the underlying writer.
+        ///
+        /// The archive is also finished when the writer is dropped, but errors are then ignored.
+        pub fn finish(mut self) -> io::Result<W> {
+            Ok(self.zip.finish()?)
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+pub use mmap::MmapNpy;
+
+#[cfg(feature = "mmap")]
+mod mmap {
+    use super::*;
+    use std::{fs::File, path::Path};
+
+    /// Read-only memory map of a `.npy` file.
+    ///
+    /// The mapping is page-aligned, so that if the file was written by numpy or by this module,
+    /// the data can be referenced in-place with [`Npy::as_aligned_ref`] without being read into
+    /// memory first.
+    #[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+    pub struct MmapNpy {
+        mmap: memmap2::Mmap,
+    }
+
+    impl MmapNpy {
+        /// Maps the `.npy` file at the given path into memory.
+        ///
+        /// # Safety
+        /// The file must not be modified, including by other processes, while the map is alive.
+        pub unsafe fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+            let mmap = memmap2::Mmap::map(&File::open(path)?)?;
+            Npy::new(&mmap)?;
+            Ok(Self { mmap })
+        }
+
+        /// Returns a view over the mapped buffer.
+        #[inline]
+        pub fn npy(&self) -> Npy<'_> {
+            Npy::new(&self.mmap).unwrap()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mat;
+
+    #[test]
+    fn test_roundtrip() {
+        let a = Mat::<c64>::from_fn(5, 3, |i, j| c64::new(i as f64, j as f64));
+        let mut bytes = alloc::vec::Vec::new();
+        write_mat(&mut bytes, a.as_ref()).unwrap();
+        assert!(bytes.starts_with(b"\x93NUMPY\x01\x00"));
+        let header = "{'descr': '<c16', 'fortran_order': True, 'shape': (5, 3), }";
+        assert!(bytes[10..].starts_with(header.as_bytes()));
+
+        let npy = Npy::new(&bytes).unwrap();
+        assert!(npy.dtype() == NpyDType::C64);
+        assert!(all(npy.nrows() == 5, npy.ncols() == 3, npy.ndim() == 2));
+        assert!(read_mat::<c64>(&*bytes).unwrap() == a);
+        assert!(read_mat::<c32>(&*bytes).is_err());
+        assert!(read_col::<c64>(&*bytes).is_err());
+
+        // strided views
+        let mut bytes = alloc::vec::Vec::new();
+        write_mat(&mut bytes, a.transpose()).unwrap();
+        assert!(read_mat::<c64>(&*bytes).unwrap() == a.transpose());
+
+        let x = a.col(1).to_owned();
+        let mut bytes = alloc::vec::Vec::new();
+        write_col(&mut bytes, a.row(2).transpose()).unwrap();
+        let npy = Npy::new(&bytes).unwrap();
+        assert!(all(npy.nrows() == 3, npy.ncols() == 1, npy.ndim() == 1));
+        assert!(read_col::<c64>(&*bytes).unwrap() == a.row(2).transpose().to_owned());
+
+        let mut bytes = alloc::vec::Vec::new();
+        write_col(&mut bytes, x.as_ref()).unwrap();
+        assert!(read_col::<c64>(&*bytes).unwrap() == x);
+    }
+
+    #[test]
+    fn test_row_major() {
+        // numpy.save(f, numpy.array([[1, 2, 3], [4, 5, 6]], dtype=numpy.float32))
+        let header = "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }";
+        let mut bytes = alloc::vec::Vec::new();
+        bytes.extend_from_slice(b"\x93NUMPY\x01\x00");
+        let padding = 63 - (10 + header.len()) % 64;
+        bytes.extend_from_slice(&((header.len() + padding + 1) as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend(core::iter::repeat(b' ').take(padding));
+        bytes.push(b'\n');
+        for x in [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0] {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+
+        let a = read_mat::<f32>(&*bytes).unwrap();
+        assert!(a == mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f32]]);
+    }
+
+    #[test]
+    fn test_big_endian() {
+        // numpy.save(f, numpy.array([1.5, -2.0], dtype='>f8'))
+        let header = "{'descr': '>f8', 'fortran_order': False, 'shape': (2,), }";
+        let mut bytes = alloc::vec::Vec::new();
+        bytes.extend_from_slice(b"\x93NUMPY\x01\x00");
+        let padding = 63 - (10 + header.len()) % 64;
+        bytes.extend_from_slice(&((header.len() + padding + 1) as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend(core::iter::repeat(b' ').take(padding));
+        bytes.push(b'\n');
+        for x in [1.5f64, -2.0] {
+            bytes.extend_from_slice(&x.to_be_bytes());
+        }
+
+        let npy = Npy::new(&bytes).unwrap();
+        assert!(npy.is_native_endian() == cfg!(target_endian = "big"));
+        assert!(read_col::<f64>(&*bytes).unwrap() == crate::col![1.5, -2.0]);
+
+        // complex numbers are swapped component-wise
+        let a = Mat::<c32>::from_fn(3, 2, |i, j| c32::new(i as f32, -(j as f32) - 0.5));
+        let mut bytes = alloc::vec::Vec::new();
+        write_mat(&mut bytes, a.as_ref()).unwrap();
+        let data_start = bytes.len() - 3 * 2 * 8;
+        let descr = if cfg!(target_endian = "little") {
+            b"'<c8'"
+        } else {
+            b"'>c8'"
+        };
+        let pos = bytes.windows(5).position(|w| w == descr).unwrap();
+        bytes[pos + 1] = if cfg!(target_endian = "little") {
+            b'>'
+        } else {
+            b'<'
+        };
+        for x in bytes[data_start..].chunks_exact_mut(4) {
+            x.reverse();
+        }
+        assert!(read_mat::<c32>(&*bytes).unwrap() == a);
+    }
+
+    #[test]
+    fn test_truncated() {
+        let a = Mat::<f64>::from_fn(4, 3, |i, j| (i + j) as f64);
+        let mut bytes = alloc::vec::Vec::new();
+        write_mat(&mut bytes, a.as_ref()).unwrap();
+        assert!(read_mat::<f64>(&*bytes).unwrap() == a);
+
+        bytes.pop();
+        let err = read_mat::<f64>(&*bytes).unwrap_err();
+        assert!(err.kind() == io::ErrorKind::InvalidData);
+        assert!(Npy::new(&bytes).is_err());
+    }
+
+    #[cfg(feature = "npz")]
+    #[test]
+    fn test_npz() {
+        let a = Mat::<f64>::from_fn(4, 7, |i, j| (i + 10 * j) as f64);
+        let x = Col::<f32>::from_fn(6, |i| i as f32);
+
+        let mut writer = NpzWriter::new(std::io::Cursor::new(alloc::vec::Vec::new()));
+        writer.write_mat("a", a.as_ref()).unwrap();
+        writer.write_col("x", x.as_ref()).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let mut reader = NpzReader::new(std::io::Cursor::new(bytes)).unwrap();
+        let mut names = reader.names().collect::<alloc::vec::Vec<_>>();
+        names.sort();
+        assert!(names == ["a", "x"]);
+        assert!(reader.read_mat::<f64>("a").unwrap() == a);
+        assert!(reader.read_col::<f32>("x").unwrap() == x);
+        assert!(reader.read_mat::<f64>("x").is_err());
+        assert!(reader.read_mat::<f64>("b").is_err());
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_mmap() {
+        let a = Mat::<f64>::from_fn(9, 4, |i, j| (i * j) as f64);
+        let path = std::env::temp_dir().join(format!("faer_npy_test_{}.npy", std::process::id()));
+        write_mat(std::fs::File::create(&path).unwrap(), a.as_ref()).unwrap();
+
+        let mmap = unsafe { MmapNpy::open(&path).unwrap() };
+        let npy = mmap.npy();
+        assert!(npy.is_aligned());
+        assert!(npy.as_aligned_ref::<f64>() == a.as_ref());
+        drop(mmap);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
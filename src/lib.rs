@@ -158,11 +158,46 @@
 //!   as cpu feature detection at runtime.
 //! - `rayon`: enabled by default. Enables the `rayon` parallel backend and enables global
 //!   parallelism by default.
-//! - `serde`: Enables serialization and deserialization of [`Mat`].
+//! - `serde`: Enables serialization and deserialization of [`Mat`], [`Col`], [`Row`],
+//!   [`Diag`](diag::Diag), [`Perm`](perm::Perm), and of sparse column matrices (see
+//!   [Serialization format](#serialization-format)).
 //! - `npy`: Enables conversions to/from numpy's matrix file format.
+//! - `npz`: Enables reading and writing zipped bundles of numpy matrices.
+//! - `mmap`: Enables memory-mapped loading of numpy matrix files.
+//! - `matfile`: Enables reading and writing MATLAB's `.mat` files.
+//! - `rkyv`: Enables zero-copy archival of dense and sparse matrices with `rkyv`.
+//! - `arrow`: Enables conversions between dense matrices and Apache Arrow arrays.
+//! - `image`: Enables conversions between images of the `image` crate and matrices.
+//! - `capi`: Exposes a C ABI for creating matrices and computing factorizations and solves.
+//! - `pyo3`: Enables viewing Python buffers such as numpy arrays as matrices, and converting
+//! matrices back to numpy arrays.
+//! - `lapack`: Delegates eigendecompositions and singular value decompositions of `f32` and `f64`
+//! matrices to a system LAPACK, and exposes [`linalg::lapack::matmul`] for explicitly delegated
+//! matrix multiplication, for reproducing reference results or using vendor libraries such as MKL
+//! or Accelerate. faer doesn't link the library itself, so every binary built with this feature
+//! (including tests, examples and doctests) must link one, e.g. with the `openblas-src` or
+//! `intel-mkl-src` crates.
 //! - `perf-warn`: Produces performance warnings when matrix operations are called with suboptimal
 //! data layout.
 //! - `nightly`: Requires the nightly compiler. Enables experimental SIMD features such as AVX512.
+//!
+//! # Serialization format
+//!
+//! With the `serde` feature, the dense types are serialized as structures with a `version` field,
+//! currently always `1`, followed by their dimensions and their elements in column-major order:
+//! - [`Mat`]: `{ version, nrows, ncols, data }`, where `data` contains the `nrows * ncols`
+//! elements, column by column,
+//! - [`Col`]: `{ version, nrows, data }`,
+//! - [`Row`]: `{ version, ncols, data }`,
+//! - [`Diag`](diag::Diag): `{ version, dim, data }`, where `data` contains the diagonal elements,
+//! - [`Perm`](perm::Perm): `{ version, forward }`, where `forward` contains the forward
+//! permutation indices.
+//!
+//! For example, the matrix `[[1.0, 2.0], [3.0, 4.0]]` is serialized to JSON as
+//! `{"version":1,"nrows":2,"ncols":2,"data":[1.0,3.0,2.0,4.0]}`. Deserialization fails if the
+//! version is not supported or if the length of the data doesn't match the dimensions. Matrices
+//! serialized without a `version` field, which stored their elements in row-major order, can still
+//! be deserialized.
 
 #![allow(clippy::type_complexity)]
 #![allow(clippy::too_many_arguments)]
@@ -192,20 +227,38 @@ pub use reborrow;
 /// Various utilities for low level implementations in generic code.
 pub mod utils;
 
+/// Block diagonal matrix type.
+pub mod block_diag;
+/// Circulant matrix type.
+pub mod circulant;
 /// Column vector type.
 pub mod col;
+/// Matrix concatenation.
+pub mod concat;
 /// Diagonal matrix type.
 pub mod diag;
+/// Diagonal plus low-rank matrix type.
+pub mod diag_low_rank;
+/// Hankel matrix type.
+pub mod hankel;
 /// Matrix-free linear operator traits and algorithms.
 pub mod linop;
 /// Matrix type.
 pub mod mat;
+/// Packed storage for symmetric and Hermitian matrices.
+pub mod packed;
 /// Permutation matrices.
 pub mod perm;
 /// Row vector type.
 pub mod row;
+/// Stack-allocated matrix and column vector types with compile time dimensions.
+pub mod small;
 /// Sparse data structures and algorithms.
 pub mod sparse;
+/// Toeplitz matrix type.
+pub mod toeplitz;
+/// Tridiagonal matrix type.
+pub mod tridiag;
 
 pub use col::{Col, ColMut, ColRef};
 pub use mat::{Mat, MatMut, MatRef};
@@ -225,6 +278,37 @@ pub enum Side {
     Upper,
 }
 
+/// Specifies how NaN values are handled when searching for the extrema of a matrix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// NaN values are skipped, and a NaN is only returned if all the values are NaN.
+    Ignore,
+    /// The first NaN value is returned if any is found.
+    Propagate,
+}
+
+/// Specifies the norm used to normalize a vector.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Norm {
+    /// Sum of the absolute values of the elements.
+    L1,
+    /// Square root of the sum of the squared absolute values of the elements.
+    L2,
+    /// Maximum absolute value of the elements.
+    Max,
+}
+
+/// Specifies how vectors whose norm is zero are handled when normalizing them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ZeroNormPolicy {
+    /// The vector is left unchanged.
+    Keep,
+    /// The elements of the vector are set to NaN.
+    Nan,
+    /// The normalization panics.
+    Panic,
+}
+
 /// Whether a matrix should be implicitly conjugated when read or not.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Conj {
@@ -275,6 +359,23 @@ impl Conj {
 ///     }
 /// }
 /// ```
+///
+/// # Broadcasting
+/// Columns, rows and scalars can be broadcast against a matrix by zipping a view that repeats
+/// them, which is created without copying using [`ColRef::repeat_cols`],
+/// [`RowRef::repeat_rows`] or [`mat::from_repeated_ref`].
+///
+/// ```
+/// use faer::{mat, row, unzipped, zipped};
+///
+/// let mut a = mat![[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]];
+/// let mean = row![1.5, 3.5, 5.5];
+///
+/// zipped!(a.as_mut(), mean.as_ref().repeat_rows(2))
+///     .for_each(|unzipped!(mut a, mean)| a.write(a.read() - mean.read()));
+///
+/// assert_eq!(a, mat![[-0.5, -0.5, -0.5], [0.5, 0.5, 0.5]]);
+/// ```
 #[macro_export]
 macro_rules! zipped {
     ($head: expr $(,)?) => {
@@ -900,14 +1001,24 @@ pub mod io;
 #[cfg(feature = "serde")]
 mod serde;
 
+/// C ABI for dense matrices and factorizations.
+#[cfg(feature = "capi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "capi")))]
+pub mod capi;
+
+/// Helpers for writing Python extensions backed by faer.
+#[cfg(feature = "pyo3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pyo3")))]
+pub mod python;
+
 /// faer prelude. Includes useful types and traits for solving linear systems.
 pub mod prelude {
     pub use crate::{
         col,
         complex_native::{c32, c64},
         linalg::solvers::{
-            Solver, SolverCore, SolverLstsq, SolverLstsqCore, SpSolver, SpSolverCore,
-            SpSolverLstsq, SpSolverLstsqCore,
+            Solve, SolveLstsq, SolveTranspose, Solver, SolverCore, SolverLstsq, SolverLstsqCore,
+            SpSolver, SpSolverCore, SpSolverLstsq, SpSolverLstsqCore,
         },
         mat, row, unzipped, zipped, Col, ColMut, ColRef, Mat, MatMut, MatRef, Row, RowMut, RowRef,
     };
@@ -1202,6 +1313,26 @@ mod tests {
         assert!(m.read(0, 1) == -1.0);
     }
 
+    #[test]
+    fn resize_fill() {
+        let mut m = mat![[1.0, 2.0], [3.0, 4.0]];
+        m.resize(3, 3, 7.0);
+        assert!(m == mat![[1.0, 2.0, 7.0], [3.0, 4.0, 7.0], [7.0, 7.0, 7.0]]);
+
+        m.truncate(1, 2);
+        assert!(m == mat![[1.0, 2.0]]);
+
+        let mut c = col![1.0, 2.0];
+        c.resize(4, 0.0);
+        assert!(c == col![1.0, 2.0, 0.0, 0.0]);
+        c.resize(1, 0.0);
+        assert!(c == col![1.0]);
+
+        let mut r = row![1.0];
+        r.resize(3, -1.0);
+        assert!(r == row![1.0, -1.0, -1.0]);
+    }
+
     #[test]
     fn resize_zst() {
         // miri test
@@ -1556,6 +1687,591 @@ mod tests {
         mat::from_column_major_slice::<'_, f64>(&slice, 3, 2);
     }
 
+    #[test]
+    fn reshape() {
+        let mut data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0_f64];
+        let view = mat::from_column_major_slice::<f64>(&data, 2, 3);
+
+        assert_eq!(view.reshape(3, 2), mat![[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        assert_eq!(view.into_col(), col![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(view.into_row(), row![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(view.col(1).as_2d().reshape(1, 2), mat![[3.0, 4.0]]);
+        assert_eq!(view.row(1).as_2d().reshape(3, 1), mat![[2.0], [4.0], [6.0]]);
+
+        // reversed views are evenly spaced in column-major order
+        assert_eq!(
+            view.reverse_rows_and_cols().into_col(),
+            col![6.0, 5.0, 4.0, 3.0, 2.0, 1.0]
+        );
+        // as are single rows, but not transposes or submatrices with a column stride gap
+        assert!(view.subrows(0, 1).try_reshape(3, 1).is_some());
+        assert!(view.transpose().try_reshape(2, 3).is_none());
+        assert!(mat::from_column_major_slice::<f64>(&data, 3, 2)
+            .subrows(0, 2)
+            .try_reshape(1, 4)
+            .is_none());
+
+        let mut view = mat::from_column_major_slice_mut::<f64>(&mut data, 2, 3);
+        view.as_mut().into_col_mut().write(5, -1.0);
+        view.as_mut().reshape_mut(3, 2).write(0, 1, -2.0);
+        assert_eq!(data, [1.0, 2.0, 3.0, -2.0, 5.0, -1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn reshape_wrong_size() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0_f64];
+        mat::from_column_major_slice::<f64>(&data, 2, 3).reshape(4, 2);
+    }
+
+    #[test]
+    fn map() {
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0_f64]];
+        let b = a.as_ref().transpose().map(|x| x as f32 * 2.0);
+        assert_eq!(b, mat![[2.0, 8.0], [4.0, 10.0], [6.0, 12.0_f32]]);
+
+        let c = a.map_with_index(|i, j, x| x - (3 * i + j) as f64);
+        assert_eq!(c, Mat::from_fn(2, 3, |_, _| 1.0));
+    }
+
+    #[test]
+    fn masked() {
+        let a = mat![[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0_f64]];
+        let negative = |i, j| a.read(i, j) < 0.0;
+
+        assert_eq!(a.select_where(negative), col![-4.0, -2.0, -6.0]);
+        assert_eq!(a.select_where(|_, _| false).nrows(), 0);
+
+        let mut b = Mat::<f64>::zeros(2, 3);
+        b.fill_where(negative, 1.0);
+        assert_eq!(b, mat![[0.0, 1.0, 0.0], [1.0, 0.0, 1.0]]);
+
+        let mut c = a.clone();
+        c.assign_where(negative, &b);
+        assert_eq!(c, mat![[1.0, 1.0, 3.0], [1.0, 5.0, 1.0]]);
+
+        let mut d = a.clone();
+        d.assign_where(|i, j| i == j, mat::from_repeated_ref::<f64>(&7.0, 2, 3));
+        assert_eq!(d, mat![[7.0, -2.0, 3.0], [-4.0, 7.0, -6.0]]);
+    }
+
+    #[test]
+    fn select_and_scatter() {
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0_f64]];
+
+        assert_eq!(
+            a.select_rows(&[2usize, 2, 0]),
+            mat![[7.0, 8.0, 9.0], [7.0, 8.0, 9.0], [1.0, 2.0, 3.0]]
+        );
+        assert_eq!(
+            a.as_ref().transpose().select_cols(&[1u32, 0]),
+            mat![[4.0, 1.0], [5.0, 2.0], [6.0, 3.0]]
+        );
+        assert_eq!(a.select_rows::<usize>(&[]).nrows(), 0);
+
+        let mut b = Mat::<f64>::zeros(3, 3);
+        b.scatter_rows(&[2usize, 0], a.as_ref().subrows(0, 2));
+        assert_eq!(b, mat![[4.0, 5.0, 6.0], [0.0, 0.0, 0.0], [1.0, 2.0, 3.0]]);
+
+        let mut c = Mat::<f64>::zeros(3, 2);
+        c.scatter_cols(&[1usize, 1, 0], &a);
+        assert_eq!(c, mat![[3.0, 2.0], [6.0, 5.0], [9.0, 8.0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn select_rows_out_of_bounds() {
+        let a = mat![[1.0, 2.0], [3.0, 4.0_f64]];
+        a.select_rows(&[0usize, 2]);
+    }
+
+    #[test]
+    fn repeat() {
+        let a = mat![[1.0, 2.0, 3.0_f64]];
+        assert_eq!(
+            a.repeat(2, 2),
+            mat![
+                [1.0, 2.0, 3.0, 1.0, 2.0, 3.0],
+                [1.0, 2.0, 3.0, 1.0, 2.0, 3.0]
+            ]
+        );
+        assert_eq!(
+            a.transpose().repeat(1, 2),
+            mat![[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]
+        );
+        assert_eq!(a.repeat(0, 3).nrows(), 0);
+        assert_eq!(a.repeat(0, 3).ncols(), 9);
+
+        let c = col![1.0, 2.0_f64];
+        assert_eq!(c.repeat_cols(2), mat![[1.0, 1.0], [2.0, 2.0]]);
+        let r = row![1.0, 2.0_f64];
+        assert_eq!(r.repeat_rows(2), mat![[1.0, 2.0], [1.0, 2.0]]);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let expected = Mat::from_fn(5, 10, |i, j| (i + 10 * j) as f64);
+
+        let mut a = Mat::<f64>::new();
+        for j in (0..10).rev() {
+            a.insert_col(0, expected.col(j));
+        }
+        assert_eq!(a, expected);
+
+        let mut b = Mat::<f64>::new();
+        for i in [4, 0, 2, 1, 3] {
+            let pos = (0..b.nrows())
+                .filter(|&k| b.read(k, 0) < expected.read(i, 0))
+                .count();
+            b.insert_row(pos, expected.row(i));
+        }
+        assert_eq!(b, expected);
+
+        let skip = |j: usize| if j < 3 { j } else { j + 1 };
+        assert_eq!(a.remove_col(3), expected.col(3));
+        assert_eq!(
+            a.remove_row(0),
+            Row::from_fn(9, |j| expected.read(0, skip(j)))
+        );
+        assert_eq!(
+            a.remove_col(8),
+            Col::from_fn(4, |i| expected.read(i + 1, 9))
+        );
+        assert_eq!(a, Mat::from_fn(4, 8, |i, j| expected.read(i + 1, skip(j))));
+
+        a.insert_col(8, Col::from_fn(4, |_| -1.0));
+        a.insert_row(4, Row::from_fn(9, |_| -2.0));
+        assert_eq!(a.read(4, 8), -2.0);
+        assert_eq!(a.col(8).subrows(0, 4), Col::from_fn(4, |_| -1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_col_mismatched() {
+        let mut a = mat![[1.0, 2.0], [3.0, 4.0_f64]];
+        a.insert_col(1, col![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn slice_copy() {
+        let data: [f64; 6] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+        let mut a = Mat::<f64>::zeros(2, 3);
+        a.copy_from_row_major_slice(&data);
+        assert!(a == mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        a.copy_from_col_major_slice(&data);
+        assert!(a == mat![[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+
+        // strided and reversed views
+        let mut big = Mat::<f64>::zeros(4, 6);
+        let mut view = big.as_mut().reverse_rows_mut().submatrix_mut(0, 1, 3, 2);
+        view.copy_from_row_major_slice(&data);
+        assert!(big.as_ref().submatrix(1, 1, 3, 2) == mat![[5.0, 6.0], [3.0, 4.0], [1.0, 2.0]]);
+
+        let mut out = [0.0; 6];
+        big.as_ref()
+            .reverse_rows()
+            .submatrix(0, 1, 3, 2)
+            .write_to_row_major_slice(&mut out);
+        assert!(out == data);
+        a.write_to_col_major_slice(&mut out);
+        assert!(out == data);
+        a.as_ref().transpose().write_to_col_major_slice(&mut out);
+        assert!(out == [1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_copy_mismatched() {
+        let mut a = Mat::<f64>::zeros(2, 3);
+        a.copy_from_col_major_slice(&[1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn push_pop_cols() {
+        let mut a = Mat::<f64>::with_capacity(3, 8);
+        let ptr = a.as_ptr();
+        for j in 0..5 {
+            a.push_col(Col::from_fn(3, |i| (i + 3 * j) as f64));
+        }
+        a.push_cols(Mat::from_fn(3, 3, |i, j| (i + 3 * (j + 5)) as f64));
+        assert!(a.as_ptr() == ptr);
+        assert!(a == Mat::from_fn(3, 8, |i, j| (i + 3 * j) as f64));
+
+        // growing past the capacity reallocates while keeping the contents
+        a.push_cols(a.clone().as_ref().subcols(0, 2));
+        assert!(a.col_capacity() >= 10);
+        assert!(a.as_ref().subcols(8, 2) == a.as_ref().subcols(0, 2));
+
+        assert!(a.pop_col() == Some(col![3.0, 4.0, 5.0]));
+        assert!(a.ncols() == 9);
+        let mut empty = Mat::<f64>::new();
+        assert!(empty.pop_col().is_none());
+        empty.push_cols(mat![[1.0], [2.0]]);
+        assert!(empty == mat![[1.0], [2.0]]);
+    }
+
+    #[test]
+    fn push_rows() {
+        let mut a = Mat::<f64>::new();
+        for i in 0..100 {
+            a.push_row(Row::from_fn(3, |j| (i + 100 * j) as f64));
+        }
+        assert!(a == Mat::from_fn(100, 3, |i, j| (i + 100 * j) as f64));
+        assert!(a.row_capacity() < 256);
+
+        let row_capacity = a.row_capacity();
+        a.truncate_rows(10);
+        assert!(a == Mat::from_fn(10, 3, |i, j| (i + 100 * j) as f64));
+        assert!(a.row_capacity() == row_capacity);
+        a.push_row(row![-1.0, -2.0, -3.0]);
+        assert!(a.row(10) == row![-1.0, -2.0, -3.0]);
+    }
+
+    #[test]
+    fn raw_parts_round_trip() {
+        let mut a = Mat::<f64>::with_capacity(5, 4);
+        a.resize_with(3, 2, |i, j| (i + 10 * j) as f64);
+
+        let (ptr, nrows, ncols, row_cap, col_cap) = a.into_raw_parts();
+        assert!((nrows, ncols, row_cap, col_cap) == (3, 2, 5, 4));
+        unsafe { *ptr.offset(row_cap as isize + 1) = -1.0 };
+
+        let mut a = unsafe { Mat::<f64>::from_raw_parts(ptr, 2, ncols, row_cap, col_cap) };
+        assert!(a == mat![[0.0, 10.0], [1.0, -1.0]]);
+
+        // the storage is owned by the matrix again, so it can be grown and dropped
+        a.resize(6, 5, 2.0);
+        assert!(a.read(1, 1) == -1.0);
+        assert!(a.read(5, 4) == 2.0);
+
+        let z = Mat::from_fn(2, 3, |_, _| faer_entity::Symbolic);
+        let (ptr, nrows, ncols, row_cap, col_cap) = z.into_raw_parts();
+        let z = unsafe {
+            Mat::<faer_entity::Symbolic>::from_raw_parts(ptr, nrows, ncols, row_cap, col_cap)
+        };
+        assert!((z.nrows(), z.ncols()) == (2, 3));
+    }
+
+    #[test]
+    fn tril_triu() {
+        let a = Mat::from_fn(3, 5, |i, j| (1 + i + 3 * j) as f64);
+
+        for offset in -4..7 {
+            let lower = a.tril(offset);
+            let upper = a.triu(offset);
+            for j in 0..5 {
+                for i in 0..3 {
+                    let d = j as isize - i as isize;
+                    let x = a.read(i, j);
+                    assert_eq!(lower.read(i, j), if d <= offset { x } else { 0.0 });
+                    assert_eq!(upper.read(i, j), if d >= offset { x } else { 0.0 });
+                }
+            }
+            assert_eq!(a.transpose().tril(offset), a.triu(-offset).transpose());
+        }
+
+        let mut b = a.clone();
+        b.zero_below_diag(1);
+        b.as_mut().zero_above_diag(1);
+        assert_eq!(
+            b,
+            mat![
+                [0.0, 4.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 8.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 12.0, 0.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn hermitian_helpers() {
+        use complex_native::c64;
+
+        let a = Mat::from_fn(3, 3, |i, j| {
+            c64::new((i + 3 * j) as f64, i as f64 - j as f64)
+        });
+        let h = a.hermitian_part();
+        let s = a.skew_part();
+        assert_eq!(&h + &s, a);
+        assert_eq!(h.adjoint(), h);
+        assert_eq!(s.adjoint(), -&s);
+
+        let expected = |keep_lower: bool| {
+            Mat::from_fn(3, 3, |i, j| {
+                if i == j || (i > j) == keep_lower {
+                    a.read(i, j)
+                } else {
+                    a.read(j, i).conj()
+                }
+            })
+        };
+        let mut lower = a.clone();
+        lower.symmetrize_lower();
+        assert_eq!(lower, expected(true));
+        let mut upper = a.clone();
+        upper.symmetrize_upper();
+        assert_eq!(upper, expected(false));
+    }
+
+    #[test]
+    fn dot_and_outer() {
+        use complex_native::c64;
+
+        let a = Col::from_fn(100, |i| c64::new(i as f64, 1.0));
+        let b = Col::from_fn(100, |i| c64::new(1.0, -(i as f64)));
+
+        let mut dot = c64::new(0.0, 0.0);
+        let mut dotc = c64::new(0.0, 0.0);
+        for i in 0..100 {
+            dot += a.read(i) * b.read(i);
+            dotc += a.read(i).conj() * b.read(i);
+        }
+        assert_eq!(a.dot(&b), dot);
+        assert_eq!(a.dotc(&b), dotc);
+        assert_eq!(a.as_ref().conjugate().dot(&b), dotc);
+        assert_eq!(a.as_ref().dotc(b.as_ref().conjugate()), dot.conj());
+        assert_eq!(a.transpose().dot(b.transpose()), dot);
+        assert_eq!(a.transpose().dotc(b.transpose()), dotc);
+
+        let reversed = a.as_ref().reverse_rows();
+        let mut dot_reversed = c64::new(0.0, 0.0);
+        for i in 0..100 {
+            dot_reversed += a.read(99 - i) * b.read(i);
+        }
+        assert_eq!(reversed.dot(&b), dot_reversed);
+
+        let outer = a.outer(b.transpose());
+        assert_eq!(outer, Mat::from_fn(100, 100, |i, j| a.read(i) * b.read(j)));
+    }
+
+    #[test]
+    fn normalize() {
+        use complex_native::c64;
+
+        let mut x = col![2.0, -2.0, 2.0, 2.0_f64];
+        x.normalize();
+        assert_eq!(x, col![0.5, -0.5, 0.5, 0.5]);
+        assert_eq!(col![0.0, 0.0_f64].normalized(), col![0.0, 0.0]);
+        assert_eq!(
+            col![1.0, -2.0_f64].normalized_with(Norm::Max, ZeroNormPolicy::Keep),
+            col![0.5, -1.0]
+        );
+
+        let z = Col::from_fn(2, |i| c64::new(0.0, 2.0 * i as f64));
+        assert_eq!(
+            z.as_ref().conjugate().normalized(),
+            col![c64::new(0.0, 0.0), c64::new(0.0, -1.0)]
+        );
+
+        let mut a = mat![[2.0, 0.0], [4.0, 0.0], [0.0, 0.0_f64]];
+        a.normalize_rows(Norm::L1, ZeroNormPolicy::Nan);
+        assert_eq!(a.get(..2, ..), mat![[1.0, 0.0], [1.0, 0.0]]);
+        assert!(a.read(2, 0).is_nan() && a.read(2, 1).is_nan());
+
+        let mut a = mat![[1.0, 0.0], [1.0, 0.0], [-1.0, 0.0], [1.0, 0.0_f64]];
+        a.normalize_cols(Norm::L2, ZeroNormPolicy::Keep);
+        assert_eq!(a, mat![[0.5, 0.0], [0.5, 0.0], [-0.5, 0.0], [0.5, 0.0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn normalize_zero_panic() {
+        let mut a = mat![[1.0, 0.0], [1.0, 0.0_f64]];
+        a.normalize_cols(Norm::L1, ZeroNormPolicy::Panic);
+    }
+
+    #[test]
+    fn tiles() {
+        let a = Mat::from_fn(7, 5, |i, j| (i + 7 * j) as f64);
+
+        let tiles: alloc::vec::Vec<_> = a.tiles(3, 2).collect();
+        assert_eq!(tiles.len(), 9);
+        assert_eq!(tiles[1], a.get(3..6, 0..2));
+        assert_eq!(tiles[8], a.get(6..7, 4..5));
+        assert_eq!(a.tiles(3, 2).rev().next().unwrap(), tiles[8]);
+        assert_eq!(a.get(.., ..0).tiles(3, 2).count(), 0);
+
+        let mut b = Mat::<f64>::zeros(7, 5);
+        for (mut dst, src) in b.tiles_mut(3, 2).zip(a.tiles(3, 2)) {
+            dst.copy_from(src);
+        }
+        assert_eq!(b, a);
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            let mut c = Mat::<f64>::zeros(7, 5);
+            c.par_tiles_mut(3, 2)
+                .zip(a.par_tiles(3, 2))
+                .for_each(|(mut dst, src)| dst.copy_from(src));
+            assert_eq!(c, a);
+        }
+    }
+
+    #[test]
+    fn diagonal_offset() {
+        let a = Mat::from_fn(3, 5, |i, j| (i + 3 * j) as f64);
+
+        for offset in -4..7 {
+            let diag = a.diagonal_offset(offset);
+            let row = if offset < 0 { -offset as usize } else { 0 };
+            let col = if offset > 0 { offset as usize } else { 0 };
+            let len = Ord::min(3usize.saturating_sub(row), 5usize.saturating_sub(col));
+            assert_eq!(diag, Col::from_fn(len, |k| a.read(row + k, col + k)));
+            assert_eq!(a.transpose().diagonal_offset(-offset), diag);
+        }
+
+        let mut b = Mat::<f64>::zeros(3, 3);
+        b.diagonal_offset_mut(1).fill(1.0);
+        b.as_mut().diagonal_offset_mut(-2).fill(2.0);
+        assert_eq!(b, mat![[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [2.0, 0.0, 0.0]]);
+    }
+
+    #[test]
+    fn col_row_iter() {
+        let a = Mat::from_fn(3, 4, |i, j| (i + 3 * j) as f64);
+
+        assert_eq!(a.col_iter().len(), 4);
+        assert_eq!(a.row_iter().len(), 3);
+        for (j, col) in a.col_iter().enumerate() {
+            assert_eq!(col, a.col(j));
+        }
+        for (i, row) in a.row_iter().rev().enumerate() {
+            assert_eq!(row, a.row(2 - i));
+        }
+
+        let mut b = Mat::<f64>::zeros(3, 4);
+        for (j, mut col) in b.col_iter_mut().enumerate() {
+            col.copy_from(a.col(j));
+        }
+        assert_eq!(b, a);
+        for mut row in b.row_iter_mut() {
+            row.fill(1.0);
+        }
+        assert_eq!(b, Mat::from_fn(3, 4, |_, _| 1.0));
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            let mut c = Mat::<f64>::zeros(3, 4);
+            c.par_col_iter_mut()
+                .zip(a.par_col_iter())
+                .for_each(|(mut dst, src)| dst.copy_from(src));
+            assert_eq!(c, a);
+            c.par_row_iter_mut().for_each(|mut row| row.fill(1.0));
+            assert_eq!(c.par_row_iter().count(), 3);
+            assert_eq!(c, b);
+        }
+    }
+
+    #[test]
+    fn col_ranges() {
+        assert_eq!(Col::<f64>::linspace(1.0, 2.0, 0).nrows(), 0);
+        assert_eq!(Col::linspace(1.0, 2.0, 1), col![1.0]);
+        assert_eq!(Col::linspace(2.0, -2.0, 3), col![2.0, 0.0, -2.0]);
+
+        use complex_native::c64;
+        let z = Col::linspace(c64::new(0.0, 0.0), c64::new(2.0, -4.0), 3);
+        assert_eq!(z.read(1), c64::new(1.0, -2.0));
+
+        assert_eq!(Col::arange(0.0, 0.0, 1.0).nrows(), 0);
+        assert_eq!(Col::arange(0.0, 3.0, 1.0), col![0.0, 1.0, 2.0]);
+
+        let x = Col::geomspace(-1.0, -16.0, 5);
+        assert!((x - col![-1.0, -2.0, -4.0, -8.0, -16.0]).norm_max() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn arange_zero_step() {
+        Col::arange(0.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn banded() {
+        assert_eq!(
+            Mat::<f64>::eye_offset(3, -2),
+            mat![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]
+        );
+        assert_eq!(Mat::<f64>::eye_offset(2, 5), Mat::zeros(2, 2));
+        assert_eq!(Mat::<f64>::eye_offset(4, 0), Mat::identity(4, 4));
+
+        let d = col![1.0, 2.0];
+        let a = Mat::from_diags(&[(1, d.as_ref()), (1, d.as_ref()), (-1, d.as_ref())]);
+        assert_eq!(a, mat![[0.0, 2.0, 0.0], [1.0, 0.0, 4.0], [0.0, 2.0, 0.0]]);
+        assert_eq!(Mat::<f64>::from_diags(&[]).nrows(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_diags_mismatched() {
+        let d = col![1.0, 2.0];
+        Mat::from_diags(&[(0, d.as_ref()), (2, d.as_ref())]);
+    }
+
+    #[test]
+    fn from_cols_and_rows() {
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0_f64]];
+
+        assert_eq!(Mat::from_cols((0..3).map(|j| a.col(j))), a);
+        assert_eq!(Mat::from_rows((0..2).map(|i| a.row(i))), a);
+        assert_eq!(
+            Mat::from_cols([a.col(2), a.col(0)]),
+            mat![[3.0, 1.0], [6.0, 4.0]]
+        );
+
+        let empty = Mat::<f64>::from_rows(core::iter::empty::<Row<f64>>());
+        assert_eq!((empty.nrows(), empty.ncols()), (0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_cols_mismatched() {
+        let _ = Mat::from_cols([col![1.0, 2.0], col![3.0]]);
+    }
+
+    #[test]
+    fn from_iter() {
+        let expected = mat![[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]];
+        assert_eq!(
+            Mat::from_col_major_iter(3, 2, (1..7).map(|x| x as f64)),
+            expected
+        );
+        assert_eq!(
+            Mat::from_row_major_iter(3, 2, [1.0, 4.0, 2.0, 5.0, 3.0, 6.0]),
+            expected
+        );
+
+        let cols: Mat<f64> = (0..2)
+            .map(|j| Col::from_fn(3, |i| expected.read(i, j)))
+            .collect();
+        assert_eq!(cols, expected);
+        let rows: Mat<f64> = (0..3)
+            .map(|i| Row::from_fn(2, |j| expected.read(i, j)))
+            .collect();
+        assert_eq!(rows, expected);
+
+        let empty: Mat<f64> = core::iter::empty::<Col<f64>>().collect();
+        assert!(all(empty.nrows() == 0, empty.ncols() == 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_iter_too_small() {
+        Mat::from_col_major_iter(3, 2, [1.0, 2.0, 3.0, 4.0, 5.0_f64]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_iter_mismatched_cols() {
+        let _: Mat<f64> = [Col::<f64>::zeros(2), Col::<f64>::zeros(3)]
+            .into_iter()
+            .collect();
+    }
+
     #[test]
     fn test_is_finite() {
         use complex_native::c32;
@@ -0,0 +1,452 @@
+//! Runtime tuning of the block sizes used by the factorizations.
+//!
+//! By default, the block sizes are chosen from fixed heuristics, which are tuned for common
+//! hardware but can be suboptimal on some processors. When autotuning is enabled with
+//! [`set_autotuning`], the high level factorizations benchmark a few candidate configurations
+//! around the heuristic one the first time they are used with a given scalar type, shape and
+//! amount of parallelism, and then reuse the fastest one for the rest of the process.
+//!
+//! The benchmarks are run on a matrix of the same shape as the input, with its dimensions capped to
+//! `1024`, so that the first factorization of each shape is slower than the following ones.
+//!
+//! Autotuning currently applies to:
+//! - the block size of the QR decompositions ([`Qr`](crate::linalg::solvers::Qr) and
+//! [`ColPivQr`](crate::linalg::solvers::ColPivQr)),
+//! - the [`PanelScheduler`] and panel width of the LU decomposition with partial pivoting
+//! ([`PartialPivLu`](crate::linalg::solvers::PartialPivLu)) and of the Cholesky decomposition
+//! ([`Cholesky`](crate::linalg::solvers::Cholesky)).
+//!
+//! The matrix multiplication is not tuned, since its blocking is chosen by the `gemm` backend from
+//! the cache sizes of the processor.
+//!
+//! Autotuning requires the `std` feature, and has no effect otherwise.
+//!
+//! # Example
+//! ```
+//! use faer::{linalg::autotune, Mat};
+//!
+//! autotune::set_autotuning(true);
+//!
+//! let a = Mat::<f64>::from_fn(300, 200, |i, j| ((i * 7 + j * 3) % 11) as f64);
+//! let blocksize = autotune::qr_blocksize::<f64>(a.nrows(), a.ncols());
+//! assert!(blocksize >= 1);
+//!
+//! // later calls reuse the tuned value
+//! assert!(autotune::qr_blocksize::<f64>(a.nrows(), a.ncols()) == blocksize);
+//!
+//! autotune::set_autotuning(false);
+//! ```
+
+use crate::{
+    linalg::{qr::no_pivoting::compute::recommended_blocksize, PanelScheduler},
+    ComplexField,
+};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+static AUTOTUNING: AtomicBool = AtomicBool::new(false);
+
+// below this size, the lookahead schedules can't overlap enough work to be worth benchmarking
+#[cfg(feature = "std")]
+const MIN_SCHEDULER_DIM: usize = 128;
+
+/// Enables or disables the autotuning of the block sizes. It is disabled by default.
+pub fn set_autotuning(enabled: bool) {
+    AUTOTUNING.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether the autotuning of the block sizes is enabled.
+pub fn is_autotuning_enabled() -> bool {
+    AUTOTUNING.load(Ordering::Relaxed)
+}
+
+/// Returns the block size used by the high level QR decompositions for a matrix with the given
+/// shape.
+///
+/// This is the tuned block size if autotuning is enabled, possibly running the benchmarks if this
+/// shape wasn't tuned yet, and
+/// [`recommended_blocksize`](crate::linalg::qr::no_pivoting::compute::recommended_blocksize)
+/// otherwise.
+pub fn qr_blocksize<E: ComplexField>(nrows: usize, ncols: usize) -> usize {
+    let recommended = recommended_blocksize::<E>(nrows, ncols);
+
+    #[cfg(feature = "std")]
+    if is_autotuning_enabled() && recommended > 1 {
+        return tuning::qr_blocksize::<E>(nrows, ncols, recommended);
+    }
+
+    recommended
+}
+
+/// Returns the scheduler used by the high level LU decomposition with partial pivoting for a
+/// matrix with the given shape.
+///
+/// This is the tuned scheduler if autotuning is enabled, possibly running the benchmarks if this
+/// shape wasn't tuned yet, and [`PanelScheduler::ForkJoin`] otherwise.
+pub fn lu_scheduler<E: ComplexField>(nrows: usize, ncols: usize) -> PanelScheduler {
+    #[cfg(feature = "std")]
+    if is_autotuning_enabled() && Ord::min(nrows, ncols) >= MIN_SCHEDULER_DIM {
+        return tuning::lu_scheduler::<E>(nrows, ncols);
+    }
+
+    let _ = (nrows, ncols);
+    PanelScheduler::ForkJoin
+}
+
+/// Returns the scheduler used by the high level Cholesky decomposition for a matrix with the given
+/// dimension.
+///
+/// This is the tuned scheduler if autotuning is enabled, possibly running the benchmarks if this
+/// dimension wasn't tuned yet, and [`PanelScheduler::ForkJoin`] otherwise.
+pub fn llt_scheduler<E: ComplexField>(dim: usize) -> PanelScheduler {
+    #[cfg(feature = "std")]
+    if is_autotuning_enabled() && dim >= MIN_SCHEDULER_DIM {
+        return tuning::llt_scheduler::<E>(dim);
+    }
+
+    let _ = dim;
+    PanelScheduler::ForkJoin
+}
+
+// the lookahead panel widths that are benchmarked against the fork-join schedule
+#[cfg(feature = "std")]
+fn scheduler_candidates(size: usize) -> [PanelScheduler; 4] {
+    let nb = crate::linalg::lookahead_block_size(size, 0);
+    [
+        PanelScheduler::ForkJoin,
+        PanelScheduler::Lookahead { block_size: nb / 2 },
+        PanelScheduler::Lookahead { block_size: nb },
+        PanelScheduler::Lookahead { block_size: nb * 2 },
+    ]
+}
+
+/// Discards the tuned values, so that they are benchmarked again on their next use.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn clear_cache() {
+    tuning::QR_CACHE.lock().unwrap().clear();
+    tuning::LU_CACHE.lock().unwrap().clear();
+    tuning::LLT_CACHE.lock().unwrap().clear();
+}
+
+#[cfg(feature = "std")]
+mod tuning {
+    use super::scheduler_candidates;
+    use crate::{
+        get_global_parallelism,
+        linalg::{
+            cholesky::llt::compute::{cholesky_in_place, cholesky_in_place_req, LltParams},
+            lu::partial_pivoting::compute::{
+                lu_in_place, lu_in_place_req, PartialPivLuComputeParams,
+            },
+            qr::no_pivoting::compute::{qr_in_place, qr_in_place_req},
+            PanelScheduler,
+        },
+        utils::thread::parallelism_degree,
+        ComplexField, Mat,
+    };
+    use core::any::TypeId;
+    use dyn_stack::{GlobalPodBuffer, PodStack};
+    use std::{sync::Mutex, time::Duration};
+
+    const MAX_DIM: usize = 1024;
+    const N_RUNS: usize = 2;
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    struct Key {
+        ty: TypeId,
+        // shape of the benchmarked matrix
+        nrows: usize,
+        ncols: usize,
+        // the heuristic value, which can differ between shapes that are capped to the same one
+        recommended: usize,
+        n_threads: usize,
+    }
+
+    impl Key {
+        fn new<E: ComplexField>(nrows: usize, ncols: usize, recommended: usize) -> Self {
+            Self {
+                ty: TypeId::of::<E>(),
+                nrows: Ord::min(nrows, MAX_DIM),
+                ncols: Ord::min(ncols, MAX_DIM),
+                recommended,
+                n_threads: parallelism_degree(get_global_parallelism()),
+            }
+        }
+    }
+
+    pub(super) struct Entry<T> {
+        key: Key,
+        tuned: T,
+    }
+
+    pub(super) static QR_CACHE: Mutex<alloc::vec::Vec<Entry<usize>>> =
+        Mutex::new(alloc::vec::Vec::new());
+    pub(super) static LU_CACHE: Mutex<alloc::vec::Vec<Entry<PanelScheduler>>> =
+        Mutex::new(alloc::vec::Vec::new());
+    pub(super) static LLT_CACHE: Mutex<alloc::vec::Vec<Entry<PanelScheduler>>> =
+        Mutex::new(alloc::vec::Vec::new());
+
+    // returns the cached value for `key`, or the fastest of `candidates`, the first of which is the
+    // default one
+    fn tune<T: Copy + PartialEq>(
+        cache: &Mutex<alloc::vec::Vec<Entry<T>>>,
+        key: Key,
+        candidates: &[T],
+        time: impl Fn(T) -> Duration,
+    ) -> T {
+        let lookup = |cache: &[Entry<T>]| cache.iter().find(|e| e.key == key).map(|e| e.tuned);
+        if let Some(tuned) = lookup(&cache.lock().unwrap()) {
+            return tuned;
+        }
+
+        // the benchmarks run without holding the lock, so that other shapes can be looked up in
+        // the meantime
+        let mut tuned = candidates[0];
+        let mut best = time(tuned);
+        for &candidate in &candidates[1..] {
+            if candidate == candidates[0] {
+                continue;
+            }
+            let elapsed = time(candidate);
+            if elapsed < best {
+                best = elapsed;
+                tuned = candidate;
+            }
+        }
+
+        let mut cache = cache.lock().unwrap();
+        // another thread may have tuned the same shape concurrently
+        match lookup(&cache) {
+            Some(tuned) => tuned,
+            None => {
+                cache.push(Entry { key, tuned });
+                tuned
+            }
+        }
+    }
+
+    // deterministic, well conditioned input, which is also hermitian positive definite
+    fn input<E: ComplexField>(nrows: usize, ncols: usize) -> Mat<E> {
+        Mat::<E>::from_fn(nrows, ncols, |i, j| {
+            E::faer_from_f64(((i + j) % 17) as f64 + if i == j { 17.0 * nrows as f64 } else { 0.0 })
+        })
+    }
+
+    fn min_time(mut run: impl FnMut() -> Duration) -> Duration {
+        let mut best = Duration::MAX;
+        for _ in 0..N_RUNS {
+            best = Ord::min(best, run());
+        }
+        best
+    }
+
+    fn time_qr<E: ComplexField>(nrows: usize, ncols: usize, blocksize: usize) -> Duration {
+        let parallelism = get_global_parallelism();
+        let size = Ord::min(nrows, ncols);
+        let params = Default::default();
+        let mut mem = GlobalPodBuffer::new(
+            qr_in_place_req::<E>(nrows, ncols, blocksize, parallelism, params).unwrap(),
+        );
+
+        min_time(|| {
+            let mut matrix = input::<E>(nrows, ncols);
+            let mut householder = Mat::<E>::zeros(blocksize, size);
+
+            let start = std::time::Instant::now();
+            qr_in_place(
+                matrix.as_mut(),
+                householder.as_mut(),
+                parallelism,
+                PodStack::new(&mut mem),
+                params,
+            );
+            start.elapsed()
+        })
+    }
+
+    fn time_lu<E: ComplexField>(nrows: usize, ncols: usize, scheduler: PanelScheduler) -> Duration {
+        let parallelism = get_global_parallelism();
+        let mut params = PartialPivLuComputeParams::default();
+        params.scheduler = scheduler;
+        let mut mem = GlobalPodBuffer::new(
+            lu_in_place_req::<usize, E>(nrows, ncols, parallelism, params).unwrap(),
+        );
+        let mut perm = alloc::vec![0usize; nrows];
+        let mut perm_inv = alloc::vec![0usize; nrows];
+
+        min_time(|| {
+            let mut matrix = input::<E>(nrows, ncols);
+
+            let start = std::time::Instant::now();
+            lu_in_place(
+                matrix.as_mut(),
+                &mut perm,
+                &mut perm_inv,
+                parallelism,
+                PodStack::new(&mut mem),
+                params,
+            );
+            start.elapsed()
+        })
+    }
+
+    fn time_llt<E: ComplexField>(dim: usize, scheduler: PanelScheduler) -> Duration {
+        let parallelism = get_global_parallelism();
+        let mut params = LltParams::default();
+        params.scheduler = scheduler;
+        let mut mem =
+            GlobalPodBuffer::new(cholesky_in_place_req::<E>(dim, parallelism, params).unwrap());
+
+        min_time(|| {
+            let mut matrix = input::<E>(dim, dim);
+
+            let start = std::time::Instant::now();
+            cholesky_in_place(
+                matrix.as_mut(),
+                Default::default(),
+                parallelism,
+                PodStack::new(&mut mem),
+                params,
+            )
+            .unwrap();
+            start.elapsed()
+        })
+    }
+
+    pub(super) fn qr_blocksize<E: ComplexField>(
+        nrows: usize,
+        ncols: usize,
+        recommended: usize,
+    ) -> usize {
+        let key = Key::new::<E>(nrows, ncols, recommended);
+        let (m, n) = (key.nrows, key.ncols);
+        let size = Ord::min(m, n);
+
+        let mut candidates = [recommended, recommended / 2, recommended * 2];
+        for c in &mut candidates {
+            *c = (*c).clamp(1, size);
+        }
+
+        let tuned = tune(&QR_CACHE, key, &candidates, |blocksize| {
+            time_qr::<E>(m, n, blocksize)
+        });
+        tuned.min(Ord::min(nrows, ncols))
+    }
+
+    pub(super) fn lu_scheduler<E: ComplexField>(nrows: usize, ncols: usize) -> PanelScheduler {
+        let size = Ord::min(nrows, ncols);
+        let key = Key::new::<E>(nrows, ncols, crate::linalg::lookahead_block_size(size, 0));
+        let (m, n) = (key.nrows, key.ncols);
+
+        tune(&LU_CACHE, key, &scheduler_candidates(size), |scheduler| {
+            time_lu::<E>(m, n, scheduler)
+        })
+    }
+
+    pub(super) fn llt_scheduler<E: ComplexField>(dim: usize) -> PanelScheduler {
+        let key = Key::new::<E>(dim, dim, crate::linalg::lookahead_block_size(dim, 0));
+        let n = key.nrows;
+
+        tune(&LLT_CACHE, key, &scheduler_candidates(dim), |scheduler| {
+            time_llt::<E>(n, scheduler)
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::solvers::SolverCore, mat::Mat, Side};
+
+    // restores the previous value of the global flag when dropped, including when an assertion
+    // fails
+    struct AutotuningGuard(bool);
+
+    impl AutotuningGuard {
+        fn set(enabled: bool) -> Self {
+            let guard = Self(is_autotuning_enabled());
+            set_autotuning(enabled);
+            guard
+        }
+    }
+
+    impl Drop for AutotuningGuard {
+        fn drop(&mut self) {
+            set_autotuning(self.0);
+        }
+    }
+
+    #[test]
+    fn test_autotune_qr() {
+        let (m, n) = (200, 150);
+        let recommended = recommended_blocksize::<c64>(m, n);
+
+        let tuned = tuning::qr_blocksize::<c64>(m, n, recommended);
+        assert!(all(
+            tuned >= recommended / 2,
+            tuned <= recommended * 2,
+            tuning::qr_blocksize::<c64>(m, n, recommended) == tuned,
+        ));
+
+        // a shape that is capped to a different one is tuned separately
+        let tuned_tall = tuning::qr_blocksize::<c64>(2000, 150, recommended);
+        assert!(all(
+            tuned_tall >= recommended / 2,
+            tuned_tall <= recommended * 2
+        ));
+    }
+
+    #[test]
+    fn test_autotune_schedulers() {
+        let candidates = scheduler_candidates(300);
+
+        let lu = tuning::lu_scheduler::<c64>(300, 200);
+        assert!(candidates.contains(&lu));
+        assert!(tuning::lu_scheduler::<c64>(300, 200) == lu);
+
+        let llt = tuning::llt_scheduler::<c64>(300);
+        assert!(candidates.contains(&llt));
+        assert!(tuning::llt_scheduler::<c64>(300) == llt);
+    }
+
+    #[test]
+    fn test_autotune_enabled() {
+        let (m, n) = (200, 150);
+        let recommended = recommended_blocksize::<c64>(m, n);
+        let a = Mat::<c64>::from_fn(m, m, |i, j| {
+            c64::new(
+                (i + j) as f64 + if i == j { 1e3 } else { 0.0 },
+                (i * j % 5) as f64,
+            )
+        });
+
+        {
+            // autotuning only changes the block sizes and schedules, so enabling it doesn't affect
+            // the results of the other tests
+            let _guard = AutotuningGuard::set(true);
+            let tuned = qr_blocksize::<c64>(m, n);
+            assert!(all(tuned >= recommended / 2, tuned <= recommended * 2));
+
+            // tiny matrices are not benchmarked
+            assert!(qr_blocksize::<c64>(4, 4) == recommended_blocksize::<c64>(4, 4));
+            assert!(lu_scheduler::<c64>(4, 4) == PanelScheduler::ForkJoin);
+            assert!(llt_scheduler::<c64>(4) == PanelScheduler::ForkJoin);
+
+            // the tuned values are used by the high level decompositions
+            let b = a.get(.., ..n);
+            let qr = b.qr();
+            assert!((qr.compute_q() * qr.compute_r() - b).norm_max() < 1e-9);
+
+            let lu = a.partial_piv_lu();
+            assert!((lu.reconstruct() - &a).norm_max() < 1e-9);
+
+            let h = &a * a.adjoint();
+            let llt = h.cholesky(Side::Lower).unwrap();
+            assert!((llt.reconstruct() - &h).norm_max() < 1e-6 * h.norm_max());
+        }
+
+        let _guard = AutotuningGuard::set(false);
+        assert!(qr_blocksize::<c64>(m, n) == recommended);
+        assert!(lu_scheduler::<c64>(m, m) == PanelScheduler::ForkJoin);
+    }
+}
@@ -0,0 +1,561 @@
+//! Factorization and solution of batches of small independent linear systems.
+//!
+//! A batch of `batch_size` matrices of dimension `dim` is stored in a single matrix with
+//! `batch_size` rows and `dim * dim` columns, where the element at row `i` and column `j` of the
+//! matrix with index `b` is stored at row `b` and column `i + j * dim`, i.e., each row holds one
+//! matrix in column-major order. Right-hand sides with `k` columns are stored similarly, in a matrix
+//! with `batch_size` rows and `dim * k` columns.
+//!
+//! With a column-major storage, the corresponding elements of all the matrices of the batch are
+//! contiguous in memory, so that each step of the algorithms operates on whole columns and is
+//! vectorized across the batch. The batch is also split between threads according to the provided
+//! [`Parallelism`].
+//!
+//! # Example
+//! ```
+//! use faer::{linalg::batch, Mat, Parallelism};
+//!
+//! let dim = 4;
+//! let batch_size = 1000;
+//!
+//! // a batch of diagonally dominant matrices, and one right-hand side per matrix
+//! let mut matrices = Mat::<f64>::from_fn(batch_size, dim * dim, |b, idx| {
+//!     let (i, j) = (idx % dim, idx / dim);
+//!     if i == j {
+//!         dim as f64 + b as f64
+//!     } else {
+//!         1.0
+//!     }
+//! });
+//! let original = matrices.clone();
+//! let mut rhs = Mat::<f64>::from_fn(batch_size, dim, |b, i| (b + i) as f64);
+//! let b = rhs.clone();
+//!
+//! batch::cholesky_in_place(matrices.as_mut(), dim, Parallelism::None).unwrap();
+//! batch::cholesky_solve_in_place(matrices.as_ref(), dim, rhs.as_mut(), Parallelism::None);
+//!
+//! // check the solution of the last system
+//! let a = Mat::<f64>::from_fn(dim, dim, |i, j| original.read(batch_size - 1, i + j * dim));
+//! let x = Mat::<f64>::from_fn(dim, 1, |i, _| rhs.read(batch_size - 1, i));
+//! let b = Mat::<f64>::from_fn(dim, 1, |i, _| b.read(batch_size - 1, i));
+//! assert!((&a * &x - &b).norm_max() < 1e-10);
+//! ```
+
+use crate::{
+    assert,
+    mat::{MatMut, MatRef},
+    unzipped,
+    utils::thread::{for_each_raw, par_split_indices, parallelism_degree, Ptr},
+    zipped, ComplexField, Parallelism,
+};
+use reborrow::*;
+
+// minimum number of matrices handled by each thread
+const MIN_CHUNK_SIZE: usize = 64;
+
+/// This error signifies that some of the matrices of a batch are not numerically positive
+/// definite, in which case their computed factors are unspecified.
+#[derive(Debug, Clone)]
+pub struct BatchCholeskyError {
+    /// The indices of the matrices that are not positive definite, in increasing order.
+    pub failed: alloc::vec::Vec<usize>,
+}
+
+impl core::fmt::Display for BatchCholeskyError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for BatchCholeskyError {}
+
+// splits `0..batch_size` into chunks, and calls `op(start, len)` on each of them, possibly in
+// parallel
+fn for_each_chunk(
+    batch_size: usize,
+    parallelism: Parallelism,
+    op: impl Send + Sync + Fn(usize, usize),
+) {
+    let n_tasks = Ord::max(
+        1,
+        Ord::min(parallelism_degree(parallelism), batch_size / MIN_CHUNK_SIZE),
+    );
+    if n_tasks == 1 {
+        op(0, batch_size);
+        return;
+    }
+    for_each_raw(
+        n_tasks,
+        |idx| {
+            let (start, len) = par_split_indices(batch_size, idx, n_tasks);
+            op(start, len);
+        },
+        parallelism,
+    );
+}
+
+fn cholesky_chunk<E: ComplexField>(matrices: MatMut<'_, E>, dim: usize) {
+    let n = dim;
+    let mut a = matrices;
+
+    for k in 0..n {
+        let (mut left, mut right) = a.rb_mut().split_at_col_mut((k + 1) * n);
+
+        // the square root of a negative or NaN value is NaN, which marks the matrix as not
+        // positive definite
+        zipped!(left.rb_mut().col_mut(k + k * n)).for_each(|unzipped!(mut x)| {
+            x.write(E::faer_from_real(x.read().faer_real().faer_sqrt()))
+        });
+        for i in k + 1..n {
+            let (x, d) = left.rb_mut().two_cols_mut(i + k * n, k + k * n);
+            zipped!(x, d.rb()).for_each(|unzipped!(mut x, d)| {
+                x.write(x.read().faer_scale_real(d.read().faer_real().faer_inv()))
+            });
+        }
+
+        let left = left.rb();
+        for j in k + 1..n {
+            let l_jk = left.col(j + k * n);
+            for i in j..n {
+                let l_ik = left.col(i + k * n);
+                zipped!(right.rb_mut().col_mut(i + j * n - (k + 1) * n), l_ik, l_jk).for_each(
+                    |unzipped!(mut x, l_ik, l_jk)| {
+                        x.write(
+                            x.read()
+                                .faer_sub(l_ik.read().faer_mul(l_jk.read().faer_conj())),
+                        )
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Computes the Cholesky factorization `A = LLᴴ` of each of the Hermitian positive definite
+/// matrices of the batch `matrices`, and stores `L` in their lower triangular part. The strictly
+/// upper triangular part is not accessed.
+///
+/// See the [module-level documentation](self) for the storage layout of the batch.
+///
+/// # Errors
+/// Returns the indices of the matrices that are not numerically positive definite, whose computed
+/// factors are unspecified. The factorization of the other matrices is unaffected.
+///
+/// # Panics
+/// Panics if `matrices` doesn't have `dim * dim` columns.
+#[track_caller]
+pub fn cholesky_in_place<E: ComplexField>(
+    matrices: MatMut<'_, E>,
+    dim: usize,
+    parallelism: Parallelism,
+) -> Result<(), BatchCholeskyError> {
+    assert!(matrices.ncols() == dim * dim);
+    let batch_size = matrices.nrows();
+
+    for_each_chunk(batch_size, parallelism, |start, len| {
+        let matrices = unsafe { matrices.rb().subrows(start, len).const_cast() };
+        cholesky_chunk(matrices, dim);
+    });
+
+    let zero = E::Real::faer_zero();
+    let mut failed = alloc::vec::Vec::new();
+    for b in 0..batch_size {
+        // comparisons with NaN are false
+        if !(0..dim).all(|k| matrices.read(b, k + k * dim).faer_real() > zero) {
+            failed.push(b);
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(BatchCholeskyError { failed })
+    }
+}
+
+fn cholesky_solve_chunk<E: ComplexField>(factors: MatRef<'_, E>, dim: usize, rhs: MatMut<'_, E>) {
+    let n = dim;
+    let l = factors;
+    let mut x = rhs;
+    if n == 0 {
+        return;
+    }
+    let k = x.ncols() / n;
+
+    for c in 0..k {
+        let mut x = x.rb_mut().subcols_mut(c * n, n);
+
+        // L y = b
+        for j in 0..n {
+            zipped!(x.rb_mut().col_mut(j), l.col(j + j * n)).for_each(|unzipped!(mut x, d)| {
+                x.write(x.read().faer_scale_real(d.read().faer_real().faer_inv()))
+            });
+            for i in j + 1..n {
+                let (x_i, x_j) = x.rb_mut().two_cols_mut(i, j);
+                zipped!(x_i, x_j.rb(), l.col(i + j * n)).for_each(
+                    |unzipped!(mut x_i, x_j, l_ij)| {
+                        x_i.write(x_i.read().faer_sub(l_ij.read().faer_mul(x_j.read())))
+                    },
+                );
+            }
+        }
+
+        // Lᴴ x = y
+        for j in (0..n).rev() {
+            zipped!(x.rb_mut().col_mut(j), l.col(j + j * n)).for_each(|unzipped!(mut x, d)| {
+                x.write(x.read().faer_scale_real(d.read().faer_real().faer_inv()))
+            });
+            for i in 0..j {
+                let (x_i, x_j) = x.rb_mut().two_cols_mut(i, j);
+                zipped!(x_i, x_j.rb(), l.col(j + i * n)).for_each(
+                    |unzipped!(mut x_i, x_j, l_ji)| {
+                        x_i.write(
+                            x_i.read()
+                                .faer_sub(l_ji.read().faer_conj().faer_mul(x_j.read())),
+                        )
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Given the Cholesky factors of the batch of matrices computed by [`cholesky_in_place`], solves
+/// the linear system `A * X = B` for each matrix `A` of the batch and the corresponding
+/// right-hand side `B` stored in `rhs`, and stores the result in `rhs`.
+///
+/// See the [module-level documentation](self) for the storage layout of the batch.
+///
+/// # Panics
+/// Panics if `factors` doesn't have `dim * dim` columns, if `rhs` doesn't have the same number of
+/// rows as `factors`, or if its number of columns isn't a multiple of `dim`.
+#[track_caller]
+pub fn cholesky_solve_in_place<E: ComplexField>(
+    factors: MatRef<'_, E>,
+    dim: usize,
+    rhs: MatMut<'_, E>,
+    parallelism: Parallelism,
+) {
+    assert!(all(
+        factors.ncols() == dim * dim,
+        rhs.nrows() == factors.nrows(),
+        rhs.ncols() % Ord::max(dim, 1) == 0,
+    ));
+
+    for_each_chunk(factors.nrows(), parallelism, |start, len| {
+        let rhs = unsafe { rhs.rb().subrows(start, len).const_cast() };
+        cholesky_solve_chunk(factors.subrows(start, len), dim, rhs);
+    });
+}
+
+fn lu_chunk<E: ComplexField>(matrices: MatMut<'_, E>, dim: usize, transpositions: &mut [usize]) {
+    let n = dim;
+    let mut a = matrices;
+    let batch_size = a.nrows();
+
+    for k in 0..n {
+        // the pivoting is done independently for each matrix
+        for b in 0..batch_size {
+            let mut pivot = k;
+            let mut pivot_abs = a.read(b, k + k * n).faer_abs();
+            for i in k + 1..n {
+                let abs = a.read(b, i + k * n).faer_abs();
+                if abs > pivot_abs {
+                    pivot = i;
+                    pivot_abs = abs;
+                }
+            }
+            transpositions[b * n + k] = pivot;
+            if pivot != k {
+                for j in 0..n {
+                    let tmp = a.read(b, k + j * n);
+                    a.write(b, k + j * n, a.read(b, pivot + j * n));
+                    a.write(b, pivot + j * n, tmp);
+                }
+            }
+        }
+
+        let (mut left, mut right) = a.rb_mut().split_at_col_mut((k + 1) * n);
+        for i in k + 1..n {
+            let (x, d) = left.rb_mut().two_cols_mut(i + k * n, k + k * n);
+            zipped!(x, d.rb())
+                .for_each(|unzipped!(mut x, d)| x.write(x.read().faer_mul(d.read().faer_inv())));
+        }
+
+        let left = left.rb();
+        for j in k + 1..n {
+            for i in k + 1..n {
+                let (x, u_kj) = right
+                    .rb_mut()
+                    .two_cols_mut(i + j * n - (k + 1) * n, k + j * n - (k + 1) * n);
+                zipped!(x, left.col(i + k * n), u_kj.rb()).for_each(
+                    |unzipped!(mut x, l_ik, u_kj)| {
+                        x.write(x.read().faer_sub(l_ik.read().faer_mul(u_kj.read())))
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Computes the LU factorization with partial pivoting `PA = LU` of each of the matrices of the
+/// batch `matrices`, and stores `L` (with an implicit unit diagonal) and `U` in place.
+///
+/// The row permutation `P` of the matrix with index `b` is stored as a sequence of transpositions
+/// in `transpositions[b * dim..(b + 1) * dim]`, where row `k` was swapped with row
+/// `transpositions[b * dim + k]` at the `k`-th step of the factorization.
+///
+/// See the [module-level documentation](self) for the storage layout of the batch.
+///
+/// # Panics
+/// Panics if `matrices` doesn't have `dim * dim` columns, or if `transpositions` doesn't have a
+/// length equal to `dim` times the number of matrices.
+#[track_caller]
+pub fn lu_in_place<E: ComplexField>(
+    matrices: MatMut<'_, E>,
+    dim: usize,
+    transpositions: &mut [usize],
+    parallelism: Parallelism,
+) {
+    assert!(all(
+        matrices.ncols() == dim * dim,
+        transpositions.len() == matrices.nrows() * dim,
+    ));
+
+    let transpositions = Ptr(transpositions.as_mut_ptr());
+    for_each_chunk(matrices.nrows(), parallelism, |start, len| {
+        let transpositions = transpositions;
+        let matrices = unsafe { matrices.rb().subrows(start, len).const_cast() };
+        let transpositions = unsafe {
+            core::slice::from_raw_parts_mut(transpositions.0.add(start * dim), len * dim)
+        };
+        lu_chunk(matrices, dim, transpositions);
+    });
+}
+
+fn lu_solve_chunk<E: ComplexField>(
+    factors: MatRef<'_, E>,
+    dim: usize,
+    transpositions: &[usize],
+    rhs: MatMut<'_, E>,
+) {
+    let n = dim;
+    let lu = factors;
+    let mut x = rhs;
+    let batch_size = x.nrows();
+    if n == 0 {
+        return;
+    }
+    let k = x.ncols() / n;
+
+    for c in 0..k {
+        let mut x = x.rb_mut().subcols_mut(c * n, n);
+
+        for b in 0..batch_size {
+            for j in 0..n {
+                let p = transpositions[b * n + j];
+                if p != j {
+                    let tmp = x.read(b, j);
+                    x.write(b, j, x.read(b, p));
+                    x.write(b, p, tmp);
+                }
+            }
+        }
+
+        // L y = P b
+        for j in 0..n {
+            for i in j + 1..n {
+                let (x_i, x_j) = x.rb_mut().two_cols_mut(i, j);
+                zipped!(x_i, x_j.rb(), lu.col(i + j * n)).for_each(
+                    |unzipped!(mut x_i, x_j, l_ij)| {
+                        x_i.write(x_i.read().faer_sub(l_ij.read().faer_mul(x_j.read())))
+                    },
+                );
+            }
+        }
+
+        // U x = y
+        for j in (0..n).rev() {
+            zipped!(x.rb_mut().col_mut(j), lu.col(j + j * n)).for_each(|unzipped!(mut x, u_jj)| {
+                x.write(x.read().faer_mul(u_jj.read().faer_inv()))
+            });
+            for i in 0..j {
+                let (x_i, x_j) = x.rb_mut().two_cols_mut(i, j);
+                zipped!(x_i, x_j.rb(), lu.col(i + j * n)).for_each(
+                    |unzipped!(mut x_i, x_j, u_ij)| {
+                        x_i.write(x_i.read().faer_sub(u_ij.read().faer_mul(x_j.read())))
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Given the LU factors of the batch of matrices computed by [`lu_in_place`], solves the linear
+/// system `A * X = B` for each matrix `A` of the batch and the corresponding right-hand side `B`
+/// stored in `rhs`, and stores the result in `rhs`.
+///
+/// See the [module-level documentation](self) for the storage layout of the batch.
+///
+/// # Panics
+/// Panics if `factors` doesn't have `dim * dim` columns, if `transpositions` doesn't have a length
+/// equal to `dim` times the number of matrices, if `rhs` doesn't have the same number of rows as
+/// `factors`, or if its number of columns isn't a multiple of `dim`.
+#[track_caller]
+pub fn lu_solve_in_place<E: ComplexField>(
+    factors: MatRef<'_, E>,
+    dim: usize,
+    transpositions: &[usize],
+    rhs: MatMut<'_, E>,
+    parallelism: Parallelism,
+) {
+    assert!(all(
+        factors.ncols() == dim * dim,
+        transpositions.len() == factors.nrows() * dim,
+        rhs.nrows() == factors.nrows(),
+        rhs.ncols() % Ord::max(dim, 1) == 0,
+    ));
+
+    for_each_chunk(factors.nrows(), parallelism, |start, len| {
+        let rhs = unsafe { rhs.rb().subrows(start, len).const_cast() };
+        lu_solve_chunk(
+            factors.subrows(start, len),
+            dim,
+            &transpositions[start * dim..(start + len) * dim],
+            rhs,
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{complex_native::c64, mat::Mat};
+
+    fn unpack<E: ComplexField>(batch: MatRef<'_, E>, b: usize, nrows: usize) -> Mat<E> {
+        Mat::from_fn(nrows, batch.ncols() / nrows, |i, j| {
+            batch.read(b, i + j * nrows)
+        })
+    }
+
+    #[test]
+    fn test_batch_cholesky() {
+        for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+            for dim in [0, 1, 4, 7, 16] {
+                let batch_size = 300;
+                let k = 2;
+                let mut matrices = Mat::<c64>::zeros(batch_size, dim * dim);
+                for b in 0..batch_size {
+                    let a = Mat::<c64>::from_fn(dim, dim, |_, _| {
+                        c64::new(rand::random(), rand::random())
+                    });
+                    let a = &a * a.adjoint() + Mat::<c64>::identity(dim, dim);
+                    for j in 0..dim {
+                        for i in 0..dim {
+                            matrices.write(b, i + j * dim, a.read(i, j));
+                        }
+                    }
+                }
+                let original = matrices.clone();
+                let mut rhs = Mat::<c64>::from_fn(batch_size, dim * k, |_, _| {
+                    c64::new(rand::random(), rand::random())
+                });
+                let original_rhs = rhs.clone();
+
+                cholesky_in_place(matrices.as_mut(), dim, parallelism).unwrap();
+                cholesky_solve_in_place(matrices.as_ref(), dim, rhs.as_mut(), parallelism);
+                if dim == 0 {
+                    continue;
+                }
+
+                for b in 0..batch_size {
+                    let a = unpack(original.as_ref(), b, dim);
+                    let x = unpack(rhs.as_ref(), b, dim);
+                    let rhs = unpack(original_rhs.as_ref(), b, dim);
+                    assert!((&a * &x - &rhs).norm_max() < 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_cholesky_failure() {
+        let dim = 3;
+        let mut matrices = Mat::<f64>::from_fn(5, dim * dim, |b, idx| {
+            let (i, j) = (idx % dim, idx / dim);
+            if i == j {
+                if b % 2 == 1 && i == 2 {
+                    -1.0
+                } else {
+                    4.0
+                }
+            } else {
+                1.0
+            }
+        });
+        let err = cholesky_in_place(matrices.as_mut(), dim, Parallelism::None).unwrap_err();
+        assert!(err.failed == [1, 3]);
+        assert!(matrices.read(0, 0) == 2.0);
+    }
+
+    #[test]
+    fn test_batch_lu() {
+        for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+            for dim in [0, 1, 4, 7, 16] {
+                let batch_size = 300;
+                let k = 3;
+                let mut matrices =
+                    Mat::<f64>::from_fn(batch_size, dim * dim, |_, _| rand::random());
+                let original = matrices.clone();
+                let mut rhs = Mat::<f64>::from_fn(batch_size, dim * k, |_, _| rand::random());
+                let original_rhs = rhs.clone();
+                let mut transpositions = alloc::vec![0usize; batch_size * dim];
+
+                lu_in_place(matrices.as_mut(), dim, &mut transpositions, parallelism);
+                lu_solve_in_place(
+                    matrices.as_ref(),
+                    dim,
+                    &transpositions,
+                    rhs.as_mut(),
+                    parallelism,
+                );
+                if dim == 0 {
+                    continue;
+                }
+
+                for b in 0..batch_size {
+                    let a = unpack(original.as_ref(), b, dim);
+                    let x = unpack(rhs.as_ref(), b, dim);
+                    let rhs = unpack(original_rhs.as_ref(), b, dim);
+                    let err = (&a * &x - &rhs).norm_max();
+                    assert!(err < 1e-8 * (1.0 + x.norm_max()));
+
+                    // the factors match the ones of the dense partial pivoting LU
+                    let lu = a.partial_piv_lu();
+                    let factors = unpack(matrices.as_ref(), b, dim);
+                    let expected = lu.compute_l() * lu.compute_u();
+                    let l = Mat::<f64>::from_fn(dim, dim, |i, j| {
+                        if i == j {
+                            1.0
+                        } else if i > j {
+                            factors.read(i, j)
+                        } else {
+                            0.0
+                        }
+                    });
+                    let u = Mat::<f64>::from_fn(dim, dim, |i, j| {
+                        if i <= j {
+                            factors.read(i, j)
+                        } else {
+                            0.0
+                        }
+                    });
+                    assert!((&l * &u - &expected).norm_max() < 1e-10);
+                }
+            }
+        }
+    }
+}
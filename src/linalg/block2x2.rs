@@ -0,0 +1,194 @@
+//! Partitioned solves for 2×2 block systems.
+//!
+//! The functions in this module operate on a square matrix partitioned as
+//! $$K = \begin{bmatrix} A & B \\ C & D \end{bmatrix},$$
+//! where $A$ and $D$ are square. Given a factorization of $A$ and of its Schur complement
+//! $S = D - C A^{-1} B$, the system $K x = r$ can be solved without forming $K$, using the block
+//! $LDU$ decomposition
+//! $$K = \begin{bmatrix} I & 0 \\ C A^{-1} & I \end{bmatrix}
+//! \begin{bmatrix} A & 0 \\ 0 & S \end{bmatrix}
+//! \begin{bmatrix} I & A^{-1} B \\ 0 & I \end{bmatrix}.$$
+//!
+//! This structure appears for example in saddle point systems, where $A$ is symmetric positive
+//! definite and $D$ is zero.
+
+use crate::{
+    assert,
+    linalg::{matmul::matmul, solvers::SpSolver, temp_mat_req, temp_mat_uninit},
+    ComplexField, Entity, MatMut, MatRef, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+/// Computes the size and alignment of required workspace for computing the Schur complement of
+/// a block of dimension `a_dim` in a matrix whose bottom right block has dimension `d_dim`.
+pub fn schur_complement_req<E: Entity>(
+    a_dim: usize,
+    d_dim: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    _ = parallelism;
+    temp_mat_req::<E>(a_dim, d_dim)
+}
+
+/// Computes the size and alignment of required workspace for solving a 2×2 block system whose
+/// top left block has dimension `a_dim`, with a right hand side that has `rhs_ncols` columns.
+pub fn solve_in_place_req<E: Entity>(
+    a_dim: usize,
+    rhs_ncols: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    _ = parallelism;
+    temp_mat_req::<E>(a_dim, rhs_ncols)
+}
+
+/// Computes the Schur complement `D - C * A⁻¹ * B` of the block `A`, and stores the result in
+/// `dst`.
+///
+/// # Panics
+/// Panics if the blocks have incompatible dimensions, or if `A` or `D` is not square.
+#[track_caller]
+pub fn schur_complement<E: ComplexField>(
+    dst: MatMut<'_, E>,
+    a: &impl SpSolver<E>,
+    b: MatRef<'_, E>,
+    c: MatRef<'_, E>,
+    d: MatRef<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    let a_dim = a.nrows();
+    let d_dim = d.nrows();
+    assert!(all(
+        a.ncols() == a_dim,
+        d.ncols() == d_dim,
+        b.nrows() == a_dim,
+        b.ncols() == d_dim,
+        c.nrows() == d_dim,
+        c.ncols() == a_dim,
+        dst.nrows() == d_dim,
+        dst.ncols() == d_dim,
+    ));
+
+    let mut dst = dst;
+    let (mut a_inv_b, _) = temp_mat_uninit::<E>(a_dim, d_dim, stack);
+    a_inv_b.copy_from(b);
+    a.solve_in_place(a_inv_b.rb_mut());
+
+    dst.copy_from(d);
+    matmul(
+        dst,
+        c,
+        a_inv_b.rb(),
+        Some(E::faer_one()),
+        E::faer_one().faer_neg(),
+        parallelism,
+    );
+}
+
+/// Solves the equation `K * X = rhs`, where `K` is the block matrix `[A, B; C, D]`, and stores
+/// the result in `rhs`.
+///
+/// `a` is a factorization of `A`, and `schur` is a factorization of its Schur complement
+/// `D - C * A⁻¹ * B`, which can be computed with [`schur_complement`]. The block `D` is only
+/// needed through `schur`, and is not taken as a parameter.
+///
+/// # Panics
+/// Panics if the blocks have incompatible dimensions, or if `A` or the Schur complement is not
+/// square.
+#[track_caller]
+pub fn solve_in_place<E: ComplexField>(
+    a: &impl SpSolver<E>,
+    schur: &impl SpSolver<E>,
+    b: MatRef<'_, E>,
+    c: MatRef<'_, E>,
+    rhs: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    let a_dim = a.nrows();
+    let d_dim = schur.nrows();
+    assert!(all(
+        a.ncols() == a_dim,
+        schur.ncols() == d_dim,
+        b.nrows() == a_dim,
+        b.ncols() == d_dim,
+        c.nrows() == d_dim,
+        c.ncols() == a_dim,
+        rhs.nrows() == a_dim + d_dim,
+    ));
+
+    let k = rhs.ncols();
+    let (mut x1, mut x2) = rhs.split_at_row_mut(a_dim);
+
+    // forward substitution with the block lower triangular factor
+    a.solve_in_place(x1.rb_mut());
+    matmul(
+        x2.rb_mut(),
+        c,
+        x1.rb(),
+        Some(E::faer_one()),
+        E::faer_one().faer_neg(),
+        parallelism,
+    );
+    schur.solve_in_place(x2.rb_mut());
+
+    // back substitution with the block upper triangular factor
+    let (mut tmp, _) = temp_mat_uninit::<E>(a_dim, k, stack);
+    matmul(tmp.rb_mut(), b, x2.rb(), None, E::faer_one(), parallelism);
+    a.solve_in_place(tmp.rb_mut());
+    x1 -= tmp.rb();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Mat};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_block2x2() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let parallelism = Parallelism::Rayon(4);
+        let mut random =
+            |m: usize, n: usize| Mat::<c64>::from_fn(m, n, |_, _| c64::new(rng.gen(), rng.gen()));
+
+        for (a_dim, d_dim) in [(5, 3), (1, 4), (8, 0), (0, 2)] {
+            let n = a_dim + d_dim;
+            let k = random(n, n);
+            let (a, b, c, d) = k.as_ref().split_at(a_dim, a_dim);
+
+            let a_lu = a.partial_piv_lu();
+            let mut s = Mat::<c64>::zeros(d_dim, d_dim);
+            schur_complement(
+                s.as_mut(),
+                &a_lu,
+                b,
+                c,
+                d,
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    schur_complement_req::<c64>(a_dim, d_dim, parallelism).unwrap(),
+                )),
+            );
+            let expected = d - c * a_lu.solve(b);
+            assert!((&s - &expected).norm_max() < 1e-10);
+
+            let rhs = random(n, 2);
+            let mut x = rhs.clone();
+            solve_in_place(
+                &a_lu,
+                &s.partial_piv_lu(),
+                b,
+                c,
+                x.as_mut(),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    solve_in_place_req::<c64>(a_dim, 2, parallelism).unwrap(),
+                )),
+            );
+            assert!((&k * &x - &rhs).norm_max() < 1e-8);
+        }
+    }
+}
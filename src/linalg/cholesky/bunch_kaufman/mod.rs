@@ -925,6 +925,50 @@ pub mod solve {
         temp_mat_req::<E>(dim, rhs_ncols)
     }
 
+    /// Computes the size and alignment of required workspace for solving a linear system defined by
+    /// a matrix out of place, given its Bunch-Kaufman decomposition.
+    #[track_caller]
+    pub fn solve_req<I: Index, E: Entity>(
+        dim: usize,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        solve_in_place_req::<I, E>(dim, rhs_ncols, parallelism)
+    }
+
+    /// Given the Bunch-Kaufman factors of a matrix $A$ and a matrix $B$ stored in `rhs`, this
+    /// function computes the solution of the linear system:
+    /// $$\text{Op}_A(A)X = B.$$
+    ///
+    /// $\text{Op}_A$ is either the identity or the conjugation depending on the value of
+    /// `conj`.
+    ///
+    /// The solution of the linear system is stored in `dst`, and `rhs` is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `lb_factors` is not a square matrix.
+    /// - Panics if `subdiag` is not a column vector with the same number of rows as the dimension
+    ///   of `lb_factors`.
+    /// - Panics if `rhs` doesn't have the same number of rows as the dimension of `lb_factors`.
+    /// - Panics if `dst` doesn't have the same shape as `rhs`.
+    /// - Panics if the provided memory in `stack` is insufficient (see [`solve_req`]).
+    #[track_caller]
+    pub fn solve_with_conj<I: Index, E: ComplexField>(
+        dst: MatMut<'_, E>,
+        lb_factors: MatRef<'_, E>,
+        subdiag: MatRef<'_, E>,
+        conj: Conj,
+        perm: PermRef<'_, I>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let mut dst = dst;
+        zipped!(dst.rb_mut(), rhs).for_each(|unzipped!(mut dst, src)| dst.write(src.read()));
+        solve_in_place_with_conj(lb_factors, subdiag, conj, perm, dst, parallelism, stack)
+    }
+
     /// Given the Bunch-Kaufman factors of a matrix $A$ and a matrix $B$ stored in `rhs`, this
     /// function computes the solution of the linear system:
     /// $$\text{Op}_A(A)X = B.$$
@@ -1136,6 +1180,22 @@ mod tests {
                 PodStack::new(&mut mem),
             );
 
+            let mut y = Mat::<c64>::zeros(n, rhs.ncols());
+            let mut mem = GlobalPodBuffer::new(
+                solve::solve_req::<usize, c64>(n, rhs.ncols(), Parallelism::None).unwrap(),
+            );
+            solve::solve_with_conj(
+                y.as_mut(),
+                ldl.as_ref(),
+                subdiag.as_ref(),
+                Conj::Yes,
+                perm.rb(),
+                rhs.as_ref(),
+                Parallelism::None,
+                PodStack::new(&mut mem),
+            );
+            assert!(y == x);
+
             let err = a.conjugate() * &x - &rhs;
             let mut max = 0.0;
             zipped!(err.as_ref()).for_each(|unzipped!(err)| {
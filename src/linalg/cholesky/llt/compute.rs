@@ -3,7 +3,7 @@ use crate::{
     assert, debug_assert,
     linalg::{
         cholesky::ldlt_diagonal::compute::RankUpdate, entity::SimdCtx,
-        matmul::triangular::BlockStructure, triangular_solve,
+        matmul::triangular::BlockStructure, triangular_solve, PanelScheduler,
     },
     unzipped, zipped, ComplexField, Entity, MatMut, Parallelism,
 };
@@ -135,7 +135,10 @@ fn cholesky_in_place_left_looking_impl<E: ComplexField>(
 /// LLT factorization tuning parameters.
 #[derive(Default, Copy, Clone)]
 #[non_exhaustive]
-pub struct LltParams {}
+pub struct LltParams {
+    /// Scheduling strategy for the panel factorizations and trailing updates.
+    pub scheduler: PanelScheduler,
+}
 
 /// Dynamic LLT regularization.
 /// Values below `epsilon` in absolute value, or with a negative sign are set to `delta` with
@@ -242,6 +245,157 @@ fn cholesky_in_place_impl<E: ComplexField>(
     }
 }
 
+// factors the diagonal block of a panel whose updates from the previous panels have been applied,
+// then solves for the subdiagonal part of the panel
+fn cholesky_panel_impl<E: ComplexField>(
+    offset: usize,
+    count: &mut usize,
+    panel: MatMut<'_, E>,
+    regularization: LltRegularization<E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: LltParams,
+) -> Result<(), CholeskyError> {
+    let block_size = panel.ncols();
+    let (mut l11, mut a21) = panel.split_at_row_mut(block_size);
+
+    cholesky_in_place_impl(
+        offset,
+        count,
+        l11.rb_mut(),
+        regularization,
+        parallelism,
+        stack,
+        params,
+    )?;
+
+    triangular_solve::solve_lower_triangular_in_place(
+        l11.into_const().conjugate(),
+        a21.rb_mut().transpose_mut(),
+        parallelism,
+    );
+    Ok(())
+}
+
+fn cholesky_in_place_lookahead_impl<E: ComplexField>(
+    count: &mut usize,
+    matrix: MatMut<'_, E>,
+    regularization: LltRegularization<E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: LltParams,
+    block_size: usize,
+) -> Result<(), CholeskyError> {
+    let mut matrix = matrix;
+    let mut stack = stack;
+    let n = matrix.nrows();
+
+    let nb = Ord::min(block_size, n);
+    cholesky_panel_impl(
+        0,
+        count,
+        matrix.rb_mut().subcols_mut(0, nb),
+        regularization,
+        parallelism,
+        stack.rb_mut(),
+        params,
+    )?;
+
+    let mut j = 0;
+    while j + nb < n {
+        let j_next = j + nb;
+        let nb_next = Ord::min(block_size, n - j_next);
+        let j_trailing = j_next + nb_next;
+
+        //
+        //      L00
+        // A =  L10  A11
+        //      L20  A21  A22
+        //
+        // the first column block is already factored. the next panel [A11; A21] is updated
+        // and factored while A22 is updated concurrently, since both updates only read from
+        // the first column block
+        //
+        let (_, _, bottom_left, bottom_right) = matrix
+            .rb_mut()
+            .submatrix_mut(j_next, j, n - j_next, n - j)
+            .split_at_mut(0, nb);
+        let bottom_left = bottom_left.into_const();
+        let (l10, l20) = bottom_left.split_at_row(nb_next);
+        let (next_panel, trailing) = bottom_right.split_at_col_mut(nb_next);
+        let (_, mut a22) = trailing.split_at_row_mut(nb_next);
+
+        let mut panel_result = Ok(());
+        let mut panel_count = 0usize;
+        {
+            let panel_result = &mut panel_result;
+            let panel_count = &mut panel_count;
+            let stack = stack.rb_mut();
+            // the trailing update is most of the work, so the threads are split in proportion
+            // to the flop counts of the two tasks
+            let panel_work = ((n - j_next) * nb_next * (nb + nb_next)) as f64;
+            let trailing_work = (n - j_trailing) as f64 * (n - j_trailing) as f64 * nb as f64;
+            crate::utils::thread::join_raw_weighted(
+                move |parallelism| {
+                    let mut next_panel = next_panel;
+                    {
+                        let (a11, a21) = next_panel.rb_mut().split_at_row_mut(nb_next);
+                        crate::linalg::matmul::triangular::matmul(
+                            a11,
+                            BlockStructure::TriangularLower,
+                            l10,
+                            BlockStructure::Rectangular,
+                            l10.adjoint(),
+                            BlockStructure::Rectangular,
+                            Some(E::faer_one()),
+                            E::faer_one().faer_neg(),
+                            parallelism,
+                        );
+                        crate::linalg::matmul::matmul(
+                            a21,
+                            l20,
+                            l10.adjoint(),
+                            Some(E::faer_one()),
+                            E::faer_one().faer_neg(),
+                            parallelism,
+                        );
+                    }
+                    *panel_result = cholesky_panel_impl(
+                        j_next,
+                        panel_count,
+                        next_panel,
+                        regularization,
+                        parallelism,
+                        stack,
+                        params,
+                    );
+                },
+                |parallelism| {
+                    crate::linalg::matmul::triangular::matmul(
+                        a22.rb_mut(),
+                        BlockStructure::TriangularLower,
+                        l20,
+                        BlockStructure::Rectangular,
+                        l20.adjoint(),
+                        BlockStructure::Rectangular,
+                        Some(E::faer_one()),
+                        E::faer_one().faer_neg(),
+                        parallelism,
+                    );
+                },
+                panel_work,
+                trailing_work,
+                parallelism,
+            );
+        }
+        *count += panel_count;
+        panel_result?;
+
+        j = j_next;
+    }
+    Ok(())
+}
+
 /// Info about the result of the LLT factorization.
 #[derive(Copy, Clone, Debug)]
 pub struct LltInfo {
@@ -276,8 +430,17 @@ pub fn cholesky_in_place<E: ComplexField>(
     stack: PodStack<'_>,
     params: LltParams,
 ) -> Result<LltInfo, CholeskyError> {
-    let _ = params;
     assert!(matrix.ncols() == matrix.nrows());
+    #[cfg(feature = "instrument")]
+    let _scope = {
+        use crate::utils::instrument::*;
+        let n = matrix.nrows();
+        Scope::new(
+            "cholesky",
+            flops::<E>(n as f64 * n as f64 * n as f64 / 3.0),
+            bytes::<E>(n * (n + 1)),
+        )
+    };
     #[cfg(feature = "perf-warn")]
     if matrix.row_stride().unsigned_abs() != 1 && crate::__perf_warn!(CHOLESKY_WARN) {
         if matrix.col_stride().unsigned_abs() == 1 {
@@ -288,15 +451,31 @@ pub fn cholesky_in_place<E: ComplexField>(
     }
 
     let mut count = 0;
-    cholesky_in_place_impl(
-        0,
-        &mut count,
-        matrix,
-        regularization,
-        parallelism,
-        stack,
-        params,
-    )?;
+    match params.scheduler {
+        PanelScheduler::Lookahead { block_size } => {
+            let block_size = crate::linalg::lookahead_block_size(matrix.nrows(), block_size);
+            cholesky_in_place_lookahead_impl(
+                &mut count,
+                matrix,
+                regularization,
+                parallelism,
+                stack,
+                params,
+                block_size,
+            )?;
+        }
+        PanelScheduler::ForkJoin => {
+            cholesky_in_place_impl(
+                0,
+                &mut count,
+                matrix,
+                regularization,
+                parallelism,
+                stack,
+                params,
+            )?;
+        }
+    }
     Ok(LltInfo {
         dynamic_regularization_count: count,
     })
@@ -40,7 +40,9 @@ mod tests {
 
     use super::{compute::*, inverse::*, reconstruct::*, solve::*, update::*};
     use crate::{
-        complex_native::c64, linalg::matmul as mul, ComplexField, Conj, Mat, MatRef, Parallelism,
+        complex_native::c64,
+        linalg::{matmul as mul, PanelScheduler},
+        ComplexField, Conj, Mat, MatRef, Parallelism,
     };
 
     type E = c64;
@@ -144,6 +146,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lookahead() {
+        for (n, block_size) in [
+            (0, 0),
+            (1, 0),
+            (50, 8),
+            (63, 16),
+            (64, 16),
+            (300, 0),
+            (513, 64),
+        ] {
+            let a = random_positive_definite(n);
+
+            let mut fork_join = a.clone();
+            cholesky_in_place(
+                fork_join.as_mut(),
+                Default::default(),
+                Parallelism::None,
+                PodStack::new(&mut []),
+                Default::default(),
+            )
+            .unwrap();
+
+            for parallelism in [Parallelism::None, Parallelism::Rayon(8)] {
+                let mut lookahead = a.clone();
+                let mut params = LltParams::default();
+                params.scheduler = PanelScheduler::Lookahead { block_size };
+                cholesky_in_place(
+                    lookahead.as_mut(),
+                    Default::default(),
+                    parallelism,
+                    PodStack::new(&mut []),
+                    params,
+                )
+                .unwrap();
+
+                for j in 0..n {
+                    for i in j..n {
+                        assert_approx_eq!(lookahead.read(i, j), fork_join.read(i, j));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookahead_not_positive_definite() {
+        let n = 100;
+        let mut a = random_positive_definite(n);
+        a.write(70, 70, E::faer_from_f64(-1e10));
+
+        let mut params = LltParams::default();
+        params.scheduler = PanelScheduler::Lookahead { block_size: 16 };
+        let err = cholesky_in_place(
+            a.as_mut(),
+            Default::default(),
+            Parallelism::Rayon(4),
+            PodStack::new(&mut []),
+            params,
+        )
+        .unwrap_err();
+        assert_eq!(err.non_positive_definite_minor, 71);
+    }
+
     #[test]
     fn test_solve() {
         for n in 0..20 {
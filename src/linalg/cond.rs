@@ -0,0 +1,287 @@
+//! Condition number estimation.
+//!
+//! The functions in this module estimate the condition number of a square matrix from a
+//! factorization of the matrix, using a few linear system solves instead of explicitly forming the
+//! inverse, similarly to LAPACK's `xGECON` and `xPOCON`.
+
+use crate::{assert, col::*, linalg::solvers::SpSolver, ComplexField};
+
+const MAX_ITER: usize = 5;
+
+// sum of the absolute values of the elements
+fn abs_sum<E: ComplexField>(x: ColRef<'_, E>) -> E::Real {
+    let mut acc = E::Real::faer_zero();
+    for i in 0..x.nrows() {
+        acc = acc.faer_add(x.read(i).faer_abs());
+    }
+    acc
+}
+
+// index of the element with the largest absolute value
+fn abs_argmax<E: ComplexField>(x: ColRef<'_, E>) -> usize {
+    let mut best = 0;
+    let mut best_abs = E::Real::faer_zero();
+    for i in 0..x.nrows() {
+        let abs = x.read(i).faer_abs();
+        if abs > best_abs {
+            best = i;
+            best_abs = abs;
+        }
+    }
+    best
+}
+
+// replaces each element by its sign, with zero elements mapped to one
+fn make_sign<E: ComplexField>(mut x: ColMut<'_, E>) {
+    for i in 0..x.nrows() {
+        let xi = x.read(i);
+        let abs = xi.faer_abs();
+        if abs > E::Real::faer_zero() {
+            x.write(i, xi.faer_scale_real(abs.faer_inv()));
+        } else {
+            x.write(i, E::faer_one());
+        }
+    }
+}
+
+/// Estimates the 1-norm of `A⁻¹`, where `A` is an `n×n` invertible matrix, using the method of
+/// Hager, as improved by Higham.
+///
+/// `solve` must overwrite its argument `x` with `A⁻¹x`, and `solve_conj_transpose` must overwrite
+/// it with `A⁻ᴴx`. They are respectively called at most `6` and `5` times.
+///
+/// The estimate is a lower bound of `‖A⁻¹‖₁`, which is exact in most cases, and rarely off by more
+/// than a factor of `3`.
+pub fn estimate_inverse_norm_1<E: ComplexField>(
+    n: usize,
+    solve: impl FnMut(ColMut<'_, E>),
+    solve_conj_transpose: impl FnMut(ColMut<'_, E>),
+) -> E::Real {
+    let mut solve = solve;
+    let mut solve_conj_transpose = solve_conj_transpose;
+
+    if n == 0 {
+        return E::Real::faer_zero();
+    }
+
+    let inv_n = E::Real::faer_from_f64(n as f64).faer_inv();
+    let mut x = Col::<E>::from_fn(n, |_| E::faer_from_real(inv_n));
+    solve(x.as_mut());
+    if n == 1 {
+        return x.read(0).faer_abs();
+    }
+    let mut est = abs_sum(x.as_ref());
+
+    make_sign(x.as_mut());
+    solve_conj_transpose(x.as_mut());
+    let mut j = abs_argmax(x.as_ref());
+
+    for iter in 2..=MAX_ITER {
+        // x = A⁻¹ e_j
+        x.fill_zero();
+        x.write(j, E::faer_one());
+        solve(x.as_mut());
+
+        let est_old = est;
+        est = abs_sum(x.as_ref());
+        if est <= est_old {
+            est = est_old;
+            break;
+        }
+
+        make_sign(x.as_mut());
+        solve_conj_transpose(x.as_mut());
+        let j_last = j;
+        j = abs_argmax(x.as_ref());
+        if x.read(j_last).faer_abs() == x.read(j).faer_abs() || iter == MAX_ITER {
+            break;
+        }
+    }
+
+    // the alternating sign vector guards against the cases where the iteration above
+    // underestimates the norm
+    let scale = E::Real::faer_from_f64((n - 1) as f64).faer_inv();
+    let mut sign = E::Real::faer_one();
+    for i in 0..n {
+        let value = E::Real::faer_one().faer_add(E::Real::faer_from_f64(i as f64).faer_mul(scale));
+        x.write(i, E::faer_from_real(value.faer_mul(sign)));
+        sign = sign.faer_neg();
+    }
+    solve(x.as_mut());
+    let alt_est = abs_sum(x.as_ref()).faer_mul(E::Real::faer_from_f64(2.0 / (3.0 * n as f64)));
+
+    if alt_est > est {
+        alt_est
+    } else {
+        est
+    }
+}
+
+/// Estimates the reciprocal of the condition number of an invertible matrix `A` in the 1-norm,
+/// `1 / (‖A‖₁ ‖A⁻¹‖₁)`, given a factorization `solver` of `A`, and the 1-norm of `A`, i.e., the
+/// maximum over the columns of `A` of the sum of the absolute values of their elements.
+///
+/// `‖A⁻¹‖₁` is estimated with [`estimate_inverse_norm_1`]. A value close to zero indicates that
+/// the matrix is close to being singular.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::cond::estimate_rcond_1, mat};
+///
+/// let a = mat![[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0f64]];
+/// let norm_1 = a.col_iter().map(|col| col.norm_l1()).fold(0.0, f64::max);
+///
+/// // the exact value is `1 / (6 * 3 / 7)`
+/// let rcond = estimate_rcond_1(&a.partial_piv_lu(), norm_1);
+/// assert!((rcond - 7.0 / 18.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+/// The function panics if the factorized matrix is not square.
+#[track_caller]
+pub fn estimate_rcond_1<E: ComplexField>(solver: &impl SpSolver<E>, norm_1: E::Real) -> E::Real {
+    assert!(solver.nrows() == solver.ncols());
+    rcond_impl(solver.nrows(), norm_1, |n| {
+        estimate_inverse_norm_1(
+            n,
+            |x| solver.solve_in_place(x),
+            |x| solver.solve_conj_transpose_in_place(x),
+        )
+    })
+}
+
+/// Estimates the reciprocal of the condition number of an invertible matrix `A` in the ∞-norm,
+/// `1 / (‖A‖∞ ‖A⁻¹‖∞)`, given a factorization `solver` of `A`, and the ∞-norm of `A`, i.e., the
+/// maximum over the rows of `A` of the sum of the absolute values of their elements.
+///
+/// Since `‖A⁻¹‖∞ = ‖A⁻ᴴ‖₁`, it is estimated with [`estimate_inverse_norm_1`], with the roles of
+/// the two solves swapped.
+///
+/// # Panics
+/// The function panics if the factorized matrix is not square.
+#[track_caller]
+pub fn estimate_rcond_inf<E: ComplexField>(
+    solver: &impl SpSolver<E>,
+    norm_inf: E::Real,
+) -> E::Real {
+    assert!(solver.nrows() == solver.ncols());
+    rcond_impl(solver.nrows(), norm_inf, |n| {
+        estimate_inverse_norm_1(
+            n,
+            |x| solver.solve_conj_transpose_in_place(x),
+            |x| solver.solve_in_place(x),
+        )
+    })
+}
+
+fn rcond_impl<E: ComplexField>(
+    n: usize,
+    norm: E::Real,
+    inverse_norm: impl FnOnce(usize) -> E::Real,
+) -> E::Real {
+    if n == 0 {
+        return E::Real::faer_one();
+    }
+    if norm == E::Real::faer_zero() {
+        return E::Real::faer_zero();
+    }
+
+    let inverse_norm = inverse_norm(n);
+    if inverse_norm == E::Real::faer_zero() {
+        E::Real::faer_zero()
+    } else {
+        inverse_norm.faer_inv().faer_mul(norm.faer_inv())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::solvers::SolverCore, mat::Mat};
+
+    fn norm_1<E: ComplexField>(a: crate::mat::MatRef<'_, E>) -> E::Real {
+        let mut norm = E::Real::faer_zero();
+        for col in a.col_iter() {
+            let col_norm = abs_sum(col);
+            if col_norm > norm {
+                norm = col_norm;
+            }
+        }
+        norm
+    }
+
+    #[test]
+    fn test_rcond_real() {
+        let n = 20;
+        let a = Mat::<f64>::from_fn(n, n, |i, j| {
+            if i == j {
+                10.0
+            } else {
+                ((3 * i + 7 * j) % 11) as f64 / 11.0 - 0.5
+            }
+        });
+
+        let lu = a.partial_piv_lu();
+        let exact = norm_1(lu.inverse().as_ref());
+        let est = estimate_inverse_norm_1::<f64>(
+            n,
+            |x| lu.solve_in_place(x),
+            |x| lu.solve_conj_transpose_in_place(x),
+        );
+        assert!(est <= exact * (1.0 + 1e-12));
+        assert!(est >= exact / 3.0);
+
+        let rcond = estimate_rcond_1(&lu, norm_1(a.as_ref()));
+        assert!((rcond - 1.0 / (norm_1(a.as_ref()) * est)).abs() < 1e-14);
+
+        // symmetric positive definite matrix through its cholesky factorization
+        let spd = a.as_ref() * a.transpose();
+        let llt = spd.cholesky(crate::Side::Lower).unwrap();
+        let exact = norm_1(llt.inverse().as_ref());
+        let est = estimate_inverse_norm_1::<f64>(
+            n,
+            |x| llt.solve_in_place(x),
+            |x| llt.solve_conj_transpose_in_place(x),
+        );
+        assert!(est <= exact * (1.0 + 1e-12));
+        assert!(est >= exact / 3.0);
+    }
+
+    #[test]
+    fn test_rcond_ill_conditioned() {
+        let n = 8;
+        // hilbert matrix
+        let a = Mat::<f64>::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let rcond = estimate_rcond_1(&a.partial_piv_lu(), norm_1(a.as_ref()));
+        assert!(rcond < 1e-9);
+
+        let identity = Mat::<f64>::identity(n, n);
+        let rcond = estimate_rcond_1(&identity.partial_piv_lu(), 1.0);
+        assert!((rcond - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rcond_complex() {
+        let n = 10;
+        let a = Mat::<c64>::from_fn(n, n, |i, j| {
+            if i == j {
+                c64::new(5.0, 1.0)
+            } else {
+                c64::new(
+                    ((2 * i + 5 * j) % 7) as f64 / 7.0,
+                    ((i + 3 * j) % 5) as f64 / 5.0,
+                )
+            }
+        });
+
+        let lu = a.partial_piv_lu();
+        let exact = norm_1(lu.inverse().as_ref());
+        let est = estimate_inverse_norm_1::<c64>(
+            n,
+            |x| lu.solve_in_place(x),
+            |x| lu.solve_conj_transpose_in_place(x),
+        );
+        assert!(est <= exact * (1.0 + 1e-12));
+        assert!(est >= exact / 3.0);
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
         householder::{
             apply_block_householder_on_the_right_in_place_req,
             apply_block_householder_on_the_right_in_place_with_conj, make_householder_in_place,
-            upgrade_householder_factor,
+            upgrade_householder_factor_from_coeffs, HouseholderBlockingParams,
         },
         matmul::{inner_prod::inner_prod_with_conj, matmul, triangular::BlockStructure},
         temp_mat_req, temp_mat_uninit, temp_mat_zeroed,
@@ -421,6 +421,21 @@ pub fn make_hessenberg_in_place<E: ComplexField>(
     householder: MatMut<'_, E>,
     parallelism: Parallelism,
     stack: PodStack<'_>,
+) {
+    make_hessenberg_in_place_with_params(a, householder, parallelism, stack, Default::default())
+}
+
+/// Same as [`make_hessenberg_in_place`], but the block Householder factors are computed following
+/// the schedule in `params`.
+///
+/// The blocked reduction, which is used for large matrices, builds its block Householder factors
+/// directly, and ignores `params`.
+pub fn make_hessenberg_in_place_with_params<E: ComplexField>(
+    a: MatMut<'_, E>,
+    householder: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: HouseholderBlockingParams,
 ) {
     assert!(a.nrows() == a.ncols());
     assert!(a.row_stride() == 1);
@@ -433,7 +448,7 @@ pub fn make_hessenberg_in_place<E: ComplexField>(
 
         make_hessenberg_in_place_qgvdg_blocked(a, z, householder, parallelism, stack);
     } else {
-        make_hessenberg_in_place_basic(a, householder, parallelism, stack);
+        make_hessenberg_in_place_basic(a, householder, parallelism, stack, params);
     }
 }
 
@@ -442,6 +457,7 @@ fn make_hessenberg_in_place_basic<E: ComplexField>(
     householder: MatMut<'_, E>,
     parallelism: Parallelism,
     stack: PodStack<'_>,
+    params: HouseholderBlockingParams,
 ) {
     assert!(a.nrows() == a.ncols());
     assert!(a.row_stride() == 1);
@@ -601,7 +617,7 @@ fn make_hessenberg_in_place_basic<E: ComplexField>(
         for k in 0..bs {
             householder.write(k, k, householder.read(0, k));
         }
-        upgrade_householder_factor(householder, essentials, bs, 1, parallelism);
+        upgrade_householder_factor_from_coeffs(householder, essentials, parallelism, params);
         k_base += bs;
     }
 
@@ -1118,6 +1134,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_make_hessenberg_blocking_params() {
+        let blocking_params = [
+            HouseholderBlockingParams {
+                inner_blocksize: Some(|bs| if bs % 4 == 0 { bs / 4 } else { 1 }),
+                recursion_threshold: Some(2),
+            },
+            HouseholderBlockingParams {
+                inner_blocksize: Some(|bs| if bs % 2 == 0 { bs / 2 } else { 1 }),
+                recursion_threshold: Some(1),
+            },
+        ];
+
+        for n in [10, 64, 200] {
+            for householder_blocksize in [8, 16] {
+                let a = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+                let householder_blocksize = Ord::min(householder_blocksize, n - 1);
+                let req = make_hessenberg_in_place_req::<c64>(
+                    n,
+                    householder_blocksize,
+                    Parallelism::None,
+                );
+
+                let mut expected_h = a.clone();
+                let mut expected_householder = Mat::zeros(n - 1, householder_blocksize);
+                make_hessenberg_in_place(
+                    expected_h.as_mut(),
+                    expected_householder.as_mut(),
+                    Parallelism::None,
+                    make_stack!(req),
+                );
+
+                for params in blocking_params {
+                    let mut h = a.clone();
+                    let mut householder = Mat::zeros(n - 1, householder_blocksize);
+                    make_hessenberg_in_place_with_params(
+                        h.as_mut(),
+                        householder.as_mut(),
+                        Parallelism::None,
+                        make_stack!(req),
+                        params,
+                    );
+
+                    for j in 0..n {
+                        for i in 0..n {
+                            assert_approx_eq!(h.read(i, j), expected_h.read(i, j));
+                        }
+                    }
+                    for j in 0..householder_blocksize {
+                        for i in 0..n - 1 {
+                            assert_approx_eq!(
+                                householder.read(i, j),
+                                expected_householder.read(i, j)
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_qgvdg_real_unblk() {
         let n = 10;
@@ -1149,6 +1226,7 @@ mod tests {
                 householder_blocksize,
                 Parallelism::None,
             )),
+            Default::default(),
         );
         dbgf::dbgf!("6.2?", &h, &householder);
     }
@@ -1184,6 +1262,7 @@ mod tests {
                 householder_blocksize,
                 Parallelism::None,
             )),
+            Default::default(),
         );
         dbgf::dbgf!("6.2?", &h, &householder);
     }
@@ -1220,6 +1299,7 @@ mod tests {
                 householder_blocksize,
                 Parallelism::None,
             )),
+            Default::default(),
         );
         dbgf::dbgf!("6.2?", &h, &householder);
     }
@@ -1254,6 +1334,7 @@ mod tests {
                 householder_blocksize,
                 Parallelism::None,
             )),
+            Default::default(),
         );
         dbgf::dbgf!("6.2?", &h, &householder);
     }
@@ -11,7 +11,7 @@ use crate::{
             apply_block_householder_sequence_on_the_right_in_place_with_conj,
             apply_block_householder_sequence_transpose_on_the_left_in_place_req,
             apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj,
-            make_householder_in_place,
+            make_householder_in_place, HouseholderBlockingParams,
         },
         matmul::matmul,
         temp_mat_req,
@@ -682,6 +682,8 @@ pub struct EvdParams {
     /// Threshold of percent of aggressive-early-deflation window that must converge to skip a
     /// sweep
     pub nibble_threshold: Option<usize>,
+    /// Block Householder tuning parameters for the Hessenberg reduction
+    pub blocking: HouseholderBlockingParams,
 }
 
 pub fn default_recommended_shift_count(dim: usize, _active_block_dim: usize) -> usize {
@@ -55,6 +55,8 @@ pub mod hessenberg_cplx_evd;
 #[doc(hidden)]
 pub mod hessenberg_real_evd;
 
+pub mod update;
+
 /// Indicates whether the eigenvectors are fully computed, partially computed, or skipped.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ComputeVectors {
@@ -202,6 +204,15 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
         return;
     }
 
+    #[cfg(feature = "lapack")]
+    let (s, u) = {
+        let (mut s, mut u) = (s, u);
+        if crate::linalg::lapack::hermitian_evd(matrix, s.rb_mut(), u.rb_mut()) {
+            return;
+        }
+        (s, u)
+    };
+
     let (mut trid, stack) = temp_mat_uninit::<E>(n, n, stack);
     let householder_blocksize =
         crate::linalg::qr::no_pivoting::compute::recommended_blocksize::<E>(n - 1, n - 1);
@@ -976,6 +987,15 @@ pub fn compute_evd_real_custom_epsilon<E: RealField>(
         return;
     }
 
+    #[cfg(feature = "lapack")]
+    let (s_re, s_im, u) = {
+        let (mut s_re, mut s_im, mut u) = (s_re, s_im, u);
+        if crate::linalg::lapack::real_evd(matrix, s_re.rb_mut(), s_im.rb_mut(), u.rb_mut()) {
+            return;
+        }
+        (s_re, s_im, u)
+    };
+
     let householder_blocksize = recommended_blocksize::<E>(n - 1, n - 1);
 
     let mut u = u;
@@ -998,11 +1018,12 @@ pub fn compute_evd_real_custom_epsilon<E: RealField>(
             temp_mat_uninit(householder_blocksize, n - 1, stack.rb_mut());
         let mut householder = householder.as_mut();
 
-        hessenberg::make_hessenberg_in_place(
+        hessenberg::make_hessenberg_in_place_with_params(
             h.rb_mut(),
             householder.rb_mut().transpose_mut(),
             parallelism,
             stack.rb_mut(),
+            params.blocking,
         );
         if u.is_some() {
             apply_block_householder_sequence_on_the_right_in_place_with_conj(
@@ -1336,11 +1357,12 @@ pub fn compute_evd_complex_custom_epsilon<E: ComplexField>(
             temp_mat_uninit(n - 1, householder_blocksize, stack.rb_mut());
         let mut householder = householder.as_mut();
 
-        hessenberg::make_hessenberg_in_place(
+        hessenberg::make_hessenberg_in_place_with_params(
             h.rb_mut(),
             householder.rb_mut(),
             parallelism,
             stack.rb_mut(),
+            params.blocking,
         );
         if u.is_some() {
             apply_block_householder_sequence_on_the_right_in_place_with_conj(
@@ -0,0 +1,311 @@
+//! Updates of a self-adjoint eigendecomposition after a rank-one modification of the matrix.
+//!
+//! Given the eigendecomposition `A = U diag(s) Uᴴ` of a Hermitian matrix, the eigenvalues of
+//! `A + ρ v vᴴ` are the roots of the secular equation
+//! `1 + ρ Σᵢ |zᵢ|² / (sᵢ - λ) = 0`, where `z = Uᴴ v`, and its eigenvectors are obtained from
+//! `(diag(s) - λ)⁻¹ z`. The roots are computed with a safeguarded bisection, and the vector `z` is
+//! recomputed from them as described by Gu and Eisenstat, so that the updated eigenvectors are
+//! numerically orthogonal.
+
+use crate::{assert, col::*, mat::*, ComplexField, RealField};
+
+const MAX_BISECTION_ITER: usize = 200;
+
+// root of the secular equation with poles at `d`, which is `d[origin] + tau`
+#[derive(Copy, Clone)]
+struct Root<R> {
+    origin: usize,
+    tau: R,
+}
+
+// secular function `1 + rho Σ z2[i] / ((d[i] - d[origin]) - tau)`
+fn secular<R: RealField>(d: &[R], z2: &[R], rho: R, origin: usize, tau: R) -> R {
+    let mut acc = R::faer_zero();
+    for i in 0..d.len() {
+        let delta = d[i].faer_sub(d[origin]).faer_sub(tau);
+        acc = acc.faer_add(z2[i].faer_mul(delta.faer_inv()));
+    }
+    R::faer_one().faer_add(rho.faer_mul(acc))
+}
+
+// finds the root in `(d[origin] + lo, d[origin] + hi)`, using bisection on the offset from the
+// closest pole, which preserves the relative accuracy of the distances to the poles
+fn bisect<R: RealField>(d: &[R], z2: &[R], rho: R, origin: usize, lo: R, hi: R) -> Root<R> {
+    let half = R::faer_from_f64(0.5);
+    let mut lo = lo;
+    let mut hi = hi;
+    for _ in 0..MAX_BISECTION_ITER {
+        let mid = lo.faer_add(hi).faer_mul(half);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        // the secular function is increasing between two consecutive poles
+        if secular(d, z2, rho, origin, mid) < R::faer_zero() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Root {
+        origin,
+        tau: lo.faer_add(hi).faer_mul(half),
+    }
+}
+
+// eigenvalues of `diag(d) + rho z zᴴ`, where `d` is strictly increasing, the elements of `z` are
+// nonzero, and `rho` is positive. `z2` contains the squared absolute values of `z`
+fn secular_roots<R: RealField>(d: &[R], z2: &[R], rho: R) -> alloc::vec::Vec<Root<R>> {
+    let k = d.len();
+    let half = R::faer_from_f64(0.5);
+    let mut roots = alloc::vec::Vec::with_capacity(k);
+    for j in 0..k {
+        if j + 1 < k {
+            let gap = d[j + 1].faer_sub(d[j]);
+            let mid = gap.faer_mul(half);
+            // pick the closest pole as the origin
+            if secular(d, z2, rho, j, mid) >= R::faer_zero() {
+                roots.push(bisect(d, z2, rho, j, R::faer_zero(), mid));
+            } else {
+                roots.push(bisect(d, z2, rho, j + 1, mid.faer_sub(gap), R::faer_zero()));
+            }
+        } else {
+            // the last root is at most `rho ‖z‖²` away from the largest pole
+            let mut norm2 = R::faer_zero();
+            for &z2 in z2 {
+                norm2 = norm2.faer_add(z2);
+            }
+            roots.push(bisect(d, z2, rho, j, R::faer_zero(), rho.faer_mul(norm2)));
+        }
+    }
+    roots
+}
+
+/// Returns the eigendecomposition of the Hermitian matrix `A + rho * v * vᴴ`, as a pair of the
+/// eigenvectors and the eigenvalues in nondecreasing order, given the eigendecomposition
+/// `A = u * diag(s) * uᴴ`, where `u` is unitary and `s` contains real values.
+///
+/// # Example
+/// ```
+/// use faer::{col, linalg::evd::update::rank_one, Mat};
+///
+/// let u = Mat::<f64>::identity(3, 3);
+/// let s = col![1.0, 2.0, 3.0];
+/// let v = col![0.0, 0.0, 1.0];
+///
+/// // the last diagonal element of the identity matrix is increased by `2`
+/// let (_, s) = rank_one(u.as_ref(), s.as_ref(), 2.0, v.as_ref());
+/// assert!((s - col![1.0, 2.0, 5.0]).norm_max() < 1e-12);
+/// ```
+///
+/// # Panics
+/// Panics if `u` is not square, or if the dimensions of `u`, `s` and `v` don't match.
+#[track_caller]
+pub fn rank_one<E: ComplexField>(
+    u: MatRef<'_, E>,
+    s: ColRef<'_, E>,
+    rho: E::Real,
+    v: ColRef<'_, E>,
+) -> (Mat<E>, Col<E>) {
+    let n = s.nrows();
+    assert!(all(u.nrows() == n, u.ncols() == n, v.nrows() == n));
+
+    let zero = E::Real::faer_zero();
+    let rho_is_negative = rho < zero;
+    // `A + rho v vᴴ = -((-A) + (-rho) v vᴴ)`, so we can assume that `rho` is nonnegative
+    let sign = if rho_is_negative {
+        E::Real::faer_one().faer_neg()
+    } else {
+        E::Real::faer_one()
+    };
+    let rho = rho.faer_mul(sign);
+
+    let z = u.adjoint() * v;
+
+    // sort the eigenvalues in increasing order
+    let mut perm: alloc::vec::Vec<usize> = (0..n).collect();
+    let d_of = |i: usize| s.read(i).faer_real().faer_mul(sign);
+    perm.sort_by(|&i, &j| d_of(i).partial_cmp(&d_of(j)).unwrap());
+
+    let d: alloc::vec::Vec<E::Real> = perm.iter().map(|&i| d_of(i)).collect();
+    let mut zs: alloc::vec::Vec<E> = perm.iter().map(|&i| z.read(i)).collect();
+    let mut q = Mat::<E>::from_fn(n, n, |i, j| u.read(i, perm[j]));
+
+    let mut d_max = zero;
+    let mut z_norm2 = zero;
+    for i in 0..n {
+        if d[i].faer_abs() > d_max {
+            d_max = d[i].faer_abs();
+        }
+        z_norm2 = z_norm2.faer_add(zs[i].faer_abs2());
+    }
+    let z_norm = z_norm2.faer_sqrt();
+    let tol = E::Real::faer_epsilon()
+        .faer_mul(E::Real::faer_from_f64(8.0))
+        .faer_mul(if rho.faer_mul(z_norm2) > d_max {
+            rho.faer_mul(z_norm2)
+        } else {
+            d_max
+        });
+
+    // deflation: the components of `z` that are negligible, and the repeated eigenvalues
+    let mut active = alloc::vec![false; n];
+    let mut last: Option<usize> = None;
+    for i in 0..n {
+        if rho.faer_mul(z_norm).faer_mul(zs[i].faer_abs()) <= tol {
+            continue;
+        }
+        if let Some(j) = last {
+            if d[i].faer_sub(d[j]) <= tol {
+                // rotate the basis so that `z[j]` is zeroed and `z[i]` absorbs its magnitude
+                let r = zs[i].faer_abs2().faer_add(zs[j].faer_abs2()).faer_sqrt();
+                let r_inv = r.faer_inv();
+                let (zi, zj) = (zs[i].faer_scale_real(r_inv), zs[j].faer_scale_real(r_inv));
+                for row in 0..n {
+                    let qj = q.read(row, j);
+                    let qi = q.read(row, i);
+                    // [qj, qi] * Gᴴ, with G = [[zi, -zj], [conj(zj), conj(zi)]]
+                    q.write(
+                        row,
+                        j,
+                        qj.faer_mul(zi.faer_conj())
+                            .faer_sub(qi.faer_mul(zj.faer_conj())),
+                    );
+                    q.write(row, i, qj.faer_mul(zj).faer_add(qi.faer_mul(zi)));
+                }
+                zs[j] = E::faer_zero();
+                zs[i] = E::faer_from_real(r);
+                active[j] = false;
+            }
+        }
+        active[i] = true;
+        last = Some(i);
+    }
+
+    let idx: alloc::vec::Vec<usize> = (0..n).filter(|&i| active[i]).collect();
+    let k = idx.len();
+    let dk: alloc::vec::Vec<E::Real> = idx.iter().map(|&i| d[i]).collect();
+    let z2: alloc::vec::Vec<E::Real> = idx.iter().map(|&i| zs[i].faer_abs2()).collect();
+    let roots = secular_roots(&dk, &z2, rho);
+
+    // `delta(i, j) = d[i] - lambda[j]`, computed accurately from the root offsets
+    let delta = |i: usize, j: usize| {
+        let root = roots[j];
+        dk[i].faer_sub(dk[root.origin]).faer_sub(root.tau)
+    };
+
+    // gu-eisenstat recomputation of `z`, such that the computed roots are the exact eigenvalues
+    // of `diag(dk) + rho ẑ ẑᴴ`
+    let z_hat: alloc::vec::Vec<E> = (0..k)
+        .map(|i| {
+            let mut prod = delta(i, k - 1).faer_abs().faer_mul(rho.faer_inv());
+            for j in 0..k - 1 {
+                let denom = if j < i {
+                    dk[i].faer_sub(dk[j])
+                } else {
+                    dk[i].faer_sub(dk[j + 1])
+                };
+                prod = prod.faer_mul(delta(i, j).faer_abs().faer_mul(denom.faer_abs().faer_inv()));
+            }
+            let zi = zs[idx[i]];
+            zi.faer_scale_real(prod.faer_sqrt().faer_mul(zi.faer_abs().faer_inv()))
+        })
+        .collect();
+
+    // eigenvalues and eigenvectors of all the pairs, deflated ones included
+    let mut pairs: alloc::vec::Vec<(E::Real, Col<E>)> = alloc::vec::Vec::with_capacity(n);
+    for i in 0..n {
+        if !active[i] {
+            pairs.push((d[i], q.col(i).to_owned()));
+        }
+    }
+    let q_active = Mat::<E>::from_fn(n, k, |row, j| q.read(row, idx[j]));
+    for j in 0..k {
+        let mut w = Col::<E>::from_fn(k, |i| z_hat[i].faer_scale_real(delta(i, j).faer_inv()));
+        let norm = w.norm_l2();
+        w = Col::<E>::from_fn(k, |i| w.read(i).faer_scale_real(norm.faer_inv()));
+        let lambda = dk[roots[j].origin].faer_add(roots[j].tau);
+        pairs.push((lambda, &q_active * &w));
+    }
+
+    // undo the sign change, and sort the pairs
+    for pair in pairs.iter_mut() {
+        pair.0 = pair.0.faer_mul(sign);
+    }
+    pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let s = Col::<E>::from_fn(n, |i| E::faer_from_real(pairs[i].0));
+    let u = Mat::<E>::from_fn(n, n, |i, j| pairs[j].1.read(i));
+    (u, s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{complex_native::c64, Side};
+
+    #[track_caller]
+    fn check(A: MatRef<'_, c64>, (u, s): (Mat<c64>, Col<c64>)) {
+        let n = A.nrows();
+        let reconstructed = u.as_ref() * s.as_ref().column_vector_as_diagonal() * u.adjoint();
+        assert!((reconstructed - A).norm_max() < 1e-10);
+        assert!((u.adjoint() * &u - Mat::<c64>::identity(n, n)).norm_max() < 1e-10);
+        for i in 1..n {
+            assert!(s.read(i - 1).re <= s.read(i).re);
+        }
+    }
+
+    #[test]
+    fn test_rank_one_update() {
+        let n = 20;
+        let H = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+        let A = &H + H.adjoint();
+        let evd = A.selfadjoint_eigendecomposition(Side::Lower);
+        let u = evd.u();
+        let s = evd.s().column_vector();
+        let v = Col::from_fn(n, |_| c64::new(rand::random(), rand::random()));
+
+        for rho in [1.0, -1.0, 1e-3, 1e3] {
+            let updated = rank_one(u, s, rho, v.as_ref());
+            let v = v.as_2d();
+            check(
+                (&A + crate::scale(c64::new(rho, 0.0)) * (v * v.adjoint())).as_ref(),
+                updated,
+            );
+        }
+
+        let updated = rank_one(u, s, 0.0, v.as_ref());
+        check(A.as_ref(), updated);
+    }
+
+    #[test]
+    fn test_rank_one_update_deflation() {
+        let n = 8;
+        // repeated eigenvalues, and a vector with zero components
+        let d = [1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 4.0, 5.0];
+        let u = Mat::<c64>::identity(n, n);
+        let s = Col::<c64>::from_fn(n, |i| c64::new(d[i], 0.0));
+        let v = Col::<c64>::from_fn(n, |i| {
+            if i == 4 || i == 7 {
+                c64::new(0.0, 0.0)
+            } else {
+                c64::new(rand::random(), rand::random())
+            }
+        });
+        let A = Mat::<c64>::from_fn(n, n, |i, j| {
+            if i == j {
+                s.read(i)
+            } else {
+                c64::new(0.0, 0.0)
+            }
+        });
+
+        for rho in [2.0, -0.5] {
+            let updated = rank_one(u.as_ref(), s.as_ref(), rho, v.as_ref());
+            let v = v.as_2d();
+            check(
+                (&A + crate::scale(c64::new(rho, 0.0)) * (v * v.adjoint())).as_ref(),
+                updated,
+            );
+        }
+    }
+}
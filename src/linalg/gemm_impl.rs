@@ -0,0 +1,157 @@
+use crate::{
+    assert, get_global_parallelism, linalg::matmul::matmul, mat::*, unzipped, zipped, ComplexField,
+    Conjugate,
+};
+use reborrow::*;
+
+/// Computes the fused matrix product `acc = alpha * lhs * rhs + beta * c`, where `c` is `acc`
+/// itself if it is `None`, without allocating any temporaries.
+///
+/// The operands may be matrices, columns, rows, or views over any of them. Transposed, conjugated
+/// and adjoint operands are passed as the corresponding views (e.g.
+/// [`MatRef::transpose`], [`MatRef::conjugate`] or [`MatRef::adjoint`]), which don't copy the
+/// data. The conjugation is applied on the fly by the multiplication kernels.
+///
+/// When `c` is `None` and `beta` is zero, or when `c` is `Some(_)`, the preexisting values of
+/// `acc` are not read, so that they may be arbitrary (including NaN).
+///
+/// The multiplication uses the global parallelism (see
+/// [`get_global_parallelism`](crate::get_global_parallelism)).
+///
+/// # Panics
+///
+/// Panics if the matrix dimensions are not compatible, i.e. unless
+/// - `acc.nrows() == lhs.nrows()`,
+/// - `acc.ncols() == rhs.ncols()`,
+/// - `lhs.ncols() == rhs.nrows()`,
+/// - `c` has the same dimensions as `acc`, if it is `Some(_)`.
+///
+/// # Example
+///
+/// ```
+/// use faer::{linalg::gemm, mat};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0]];
+/// let b = mat![[5.0, 6.0], [7.0, 8.0]];
+///
+/// // acc = 2 * a * b^T + acc
+/// let mut acc = mat![[1.0, 1.0], [1.0, 1.0]];
+/// gemm(&mut acc, 2.0, &a, b.transpose(), 1.0, None);
+/// assert!(acc == mat![[35.0, 47.0], [79.0, 107.0]]);
+///
+/// // acc = a * b - c
+/// let c = mat![[1.0, 2.0], [3.0, 4.0]];
+/// gemm(&mut acc, 1.0, &a, &b, -1.0, Some(c.as_ref()));
+/// assert!(acc == mat![[18.0, 20.0], [40.0, 46.0]]);
+/// ```
+#[track_caller]
+pub fn gemm<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>(
+    acc: impl As2DMut<E>,
+    alpha: E,
+    lhs: impl As2D<LhsE>,
+    rhs: impl As2D<RhsE>,
+    beta: E,
+    c: Option<MatRef<'_, E>>,
+) {
+    let mut acc = acc;
+    let mut acc = acc.as_2d_mut();
+    let lhs = lhs.as_2d_ref();
+    let rhs = rhs.as_2d_ref();
+    assert!(all(
+        acc.nrows() == lhs.nrows(),
+        acc.ncols() == rhs.ncols(),
+        lhs.ncols() == rhs.nrows(),
+    ));
+
+    let beta_is_zero = beta == E::faer_zero();
+    let accumulate = match c {
+        Some(c) => {
+            assert!(all(c.nrows() == acc.nrows(), c.ncols() == acc.ncols()));
+            if !beta_is_zero {
+                zipped!(acc.rb_mut(), c)
+                    .for_each(|unzipped!(mut dst, src)| dst.write(src.read().faer_mul(beta)));
+                Some(E::faer_one())
+            } else {
+                None
+            }
+        }
+        None => {
+            if beta_is_zero {
+                None
+            } else {
+                Some(beta)
+            }
+        }
+    };
+
+    matmul(acc, lhs, rhs, accumulate, alpha, get_global_parallelism());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{complex_native::c64, Col, Mat};
+    use assert_approx_eq::assert_approx_eq;
+
+    fn random(nrows: usize, ncols: usize) -> Mat<c64> {
+        Mat::from_fn(nrows, ncols, |_, _| {
+            c64::new(rand::random(), rand::random())
+        })
+    }
+
+    #[test]
+    fn test_gemm() {
+        let (m, n, k) = (13, 7, 9);
+        let a = random(m, k);
+        let b = random(n, k);
+        let c = random(m, n);
+        let alpha = c64::new(0.5, 2.0);
+        let beta = c64::new(-1.0, 0.25);
+
+        let ab = &a * b.adjoint();
+
+        let mut acc = c.clone();
+        gemm(&mut acc, alpha, &a, b.adjoint(), beta, None);
+        for j in 0..n {
+            for i in 0..m {
+                assert_approx_eq!(acc.read(i, j), alpha * ab.read(i, j) + beta * c.read(i, j));
+            }
+        }
+
+        let mut acc = Mat::from_fn(m, n, |_, _| c64::new(f64::NAN, f64::NAN));
+        gemm(
+            acc.as_mut(),
+            alpha,
+            a.as_ref(),
+            b.adjoint(),
+            beta,
+            Some(c.as_ref()),
+        );
+        for j in 0..n {
+            for i in 0..m {
+                assert_approx_eq!(acc.read(i, j), alpha * ab.read(i, j) + beta * c.read(i, j));
+            }
+        }
+
+        let mut acc = Mat::from_fn(m, n, |_, _| c64::new(f64::NAN, f64::NAN));
+        gemm(&mut acc, alpha, &a, b.adjoint(), c64::new(0.0, 0.0), None);
+        for j in 0..n {
+            for i in 0..m {
+                assert_approx_eq!(acc.read(i, j), alpha * ab.read(i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gemm_vector() {
+        let a = random(5, 4);
+        let x = Col::from_fn(4, |i| c64::new(i as f64, 1.0));
+        let mut y = Col::from_fn(5, |_| c64::new(1.0, 0.0));
+
+        let expected = &a * &x;
+        gemm(&mut y, c64::new(1.0, 0.0), &a, &x, c64::new(2.0, 0.0), None);
+        for i in 0..5 {
+            assert_approx_eq!(y.read(i), expected.read(i) + c64::new(2.0, 0.0));
+        }
+    }
+}
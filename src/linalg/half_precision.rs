@@ -0,0 +1,374 @@
+//! Half precision matrices, stored as [`f16`] or [`bf16`], with the arithmetic performed in `f32`.
+//!
+//! Half precision types halve the memory footprint and bandwidth of `f32` matrices, but have too
+//! few significant bits to be used for accumulation. The functions in this module convert blocks of
+//! the inputs to `f32`, perform the computation with the regular `f32` kernels, and only round the
+//! final results to half precision. The conversions use the hardware instructions (F16C on x86,
+//! FP16 on aarch64) when they are available at runtime, which requires the `std` feature.
+//!
+//! # Example
+//! ```
+//! use faer::{
+//!     linalg::half_precision::{self, f16},
+//!     Mat, Parallelism,
+//! };
+//!
+//! let a = Mat::<f16>::from_fn(4, 3, |i, j| f16::from_f32((i + j) as f32));
+//! let b = Mat::<f16>::from_fn(3, 2, |i, j| f16::from_f32((i * j) as f32));
+//! let mut c = Mat::<f16>::zeros(4, 2);
+//!
+//! half_precision::matmul(
+//!     c.as_mut(),
+//!     a.as_ref(),
+//!     b.as_ref(),
+//!     None,
+//!     1.0,
+//!     Parallelism::None,
+//! );
+//!
+//! let target = half_precision::to_f32(a.as_ref()) * half_precision::to_f32(b.as_ref());
+//! assert!(half_precision::to_f32(c.as_ref()) == target);
+//! ```
+
+use crate::{
+    assert,
+    col::{ColMut, ColRef},
+    linalg::matmul::{inner_prod::inner_prod_with_conj, matmul as matmul_f32},
+    mat::{MatMut, MatRef},
+    unzipped, zipped, Conj, Conjugate, Mat, Parallelism,
+};
+use faer_entity::SimpleEntity;
+use half::slice::HalfFloatSliceExt;
+
+pub use half::{bf16, f16};
+
+mod seal {
+    pub trait Seal {}
+    impl Seal for half::f16 {}
+    impl Seal for half::bf16 {}
+}
+
+/// Half precision floating point type, that is stored in matrices and converted to `f32` for the
+/// computations.
+///
+/// This trait is sealed, and is only implemented by [`f16`] and [`bf16`].
+pub trait HalfFloat: SimpleEntity + Conjugate<Canonical = Self> + seal::Seal {
+    /// Converts the value to `f32`. The conversion is exact.
+    fn to_f32(self) -> f32;
+    /// Converts the value from `f32`, rounding to the nearest representable value.
+    fn from_f32(value: f32) -> Self;
+    /// Converts the values of `src` to `f32` and stores them in `dst`.
+    fn to_f32_slice(src: &[Self], dst: &mut [f32]);
+    /// Converts the values of `src` from `f32` and stores them in `dst`.
+    fn from_f32_slice(src: &[f32], dst: &mut [Self]);
+}
+
+macro_rules! impl_half_float {
+    ($ty: ty) => {
+        impl HalfFloat for $ty {
+            #[inline]
+            fn to_f32(self) -> f32 {
+                <$ty>::to_f32(self)
+            }
+            #[inline]
+            fn from_f32(value: f32) -> Self {
+                <$ty>::from_f32(value)
+            }
+            #[inline]
+            fn to_f32_slice(src: &[Self], dst: &mut [f32]) {
+                src.convert_to_f32_slice(dst)
+            }
+            #[inline]
+            fn from_f32_slice(src: &[f32], dst: &mut [Self]) {
+                dst.convert_from_f32_slice(src)
+            }
+        }
+    };
+}
+
+impl_half_float!(f16);
+impl_half_float!(bf16);
+
+// number of rows that are converted at once by the reductions
+const CHUNK: usize = 256;
+// block size of the matrix multiplication
+const BLOCK: usize = 1024;
+
+fn col_to_f32<H: HalfFloat>(mut dst: ColMut<'_, f32>, src: ColRef<'_, H>) {
+    if let (Some(src), Some(dst)) = (src.try_as_slice(), dst.rb_mut().try_as_slice_mut()) {
+        H::to_f32_slice(src, dst);
+        return;
+    }
+    for i in 0..src.nrows() {
+        dst.write(i, src.read(i).to_f32());
+    }
+}
+
+fn col_from_f32<H: HalfFloat>(mut dst: ColMut<'_, H>, src: ColRef<'_, f32>) {
+    if let (Some(src), Some(dst)) = (src.try_as_slice(), dst.rb_mut().try_as_slice_mut()) {
+        H::from_f32_slice(src, dst);
+        return;
+    }
+    for i in 0..src.nrows() {
+        dst.write(i, H::from_f32(src.read(i)));
+    }
+}
+
+/// Converts the values of `src` to `f32` and stores them in `dst`.
+///
+/// # Panics
+/// Panics if `dst` and `src` don't have the same shape.
+#[track_caller]
+pub fn copy_to_f32<H: HalfFloat>(mut dst: MatMut<'_, f32>, src: MatRef<'_, H>) {
+    assert!(all(dst.nrows() == src.nrows(), dst.ncols() == src.ncols()));
+    for j in 0..src.ncols() {
+        col_to_f32(dst.rb_mut().col_mut(j), src.col(j));
+    }
+}
+
+/// Converts the values of `src` from `f32`, rounding to the nearest representable values, and
+/// stores them in `dst`.
+///
+/// # Panics
+/// Panics if `dst` and `src` don't have the same shape.
+#[track_caller]
+pub fn copy_from_f32<H: HalfFloat>(mut dst: MatMut<'_, H>, src: MatRef<'_, f32>) {
+    assert!(all(dst.nrows() == src.nrows(), dst.ncols() == src.ncols()));
+    for j in 0..src.ncols() {
+        col_from_f32(dst.rb_mut().col_mut(j), src.col(j));
+    }
+}
+
+/// Returns a copy of `src` converted to `f32`.
+pub fn to_f32<H: HalfFloat>(src: MatRef<'_, H>) -> Mat<f32> {
+    let mut dst = Mat::<f32>::zeros(src.nrows(), src.ncols());
+    copy_to_f32(dst.as_mut(), src);
+    dst
+}
+
+/// Returns a copy of `src` converted to half precision, rounding to the nearest representable
+/// values.
+pub fn from_f32<H: HalfFloat>(src: MatRef<'_, f32>) -> Mat<H> {
+    let mut dst = Mat::<H>::zeros(src.nrows(), src.ncols());
+    copy_from_f32(dst.as_mut(), src);
+    dst
+}
+
+/// Computes the matrix product `[alpha * acc] + beta * lhs * rhs` and stores the result in `acc`.
+///
+/// The product is computed by blocks, which are converted to `f32` and multiplied with the `f32`
+/// kernels, so that the accumulation is performed in single precision. The result is rounded to
+/// half precision once, when it is stored in `acc`.
+///
+/// If `alpha` is not provided, `acc` is not read.
+///
+/// # Panics
+///
+/// Panics if the matrix dimensions are not compatible for matrix multiplication, i.e.
+///  - `acc.nrows() == lhs.nrows()`
+///  - `acc.ncols() == rhs.ncols()`
+///  - `lhs.ncols() == rhs.nrows()`
+#[track_caller]
+pub fn matmul<H: HalfFloat>(
+    mut acc: MatMut<'_, H>,
+    lhs: MatRef<'_, H>,
+    rhs: MatRef<'_, H>,
+    alpha: Option<f32>,
+    beta: f32,
+    parallelism: Parallelism,
+) {
+    assert!(all(
+        acc.nrows() == lhs.nrows(),
+        acc.ncols() == rhs.ncols(),
+        lhs.ncols() == rhs.nrows(),
+    ));
+    let (m, n, k) = (acc.nrows(), acc.ncols(), lhs.ncols());
+
+    let block_m = Ord::min(m, BLOCK);
+    let block_n = Ord::min(n, BLOCK);
+    let block_k = Ord::min(k, BLOCK);
+    let mut acc_f32 = Mat::<f32>::zeros(block_m, block_n);
+    let mut lhs_f32 = Mat::<f32>::zeros(block_m, block_k);
+    let mut rhs_f32 = Mat::<f32>::zeros(block_k, block_n);
+
+    let mut j = 0;
+    while j < n {
+        let bn = Ord::min(BLOCK, n - j);
+        let mut i = 0;
+        while i < m {
+            let bm = Ord::min(BLOCK, m - i);
+            let mut acc_block = acc_f32.as_mut().submatrix_mut(0, 0, bm, bn);
+            match alpha {
+                None => acc_block.fill_zero(),
+                Some(alpha) => {
+                    copy_to_f32(acc_block.rb_mut(), acc.rb().submatrix(i, j, bm, bn));
+                    zipped!(acc_block.rb_mut())
+                        .for_each(|unzipped!(mut x)| x.write(x.read() * alpha));
+                }
+            }
+
+            let mut depth = 0;
+            while depth < k {
+                let bk = Ord::min(BLOCK, k - depth);
+                let mut lhs_block = lhs_f32.as_mut().submatrix_mut(0, 0, bm, bk);
+                let mut rhs_block = rhs_f32.as_mut().submatrix_mut(0, 0, bk, bn);
+                copy_to_f32(lhs_block.rb_mut(), lhs.submatrix(i, depth, bm, bk));
+                copy_to_f32(rhs_block.rb_mut(), rhs.submatrix(depth, j, bk, bn));
+                matmul_f32(
+                    acc_block.rb_mut(),
+                    lhs_block.rb(),
+                    rhs_block.rb(),
+                    Some(1.0),
+                    beta,
+                    parallelism,
+                );
+                depth += bk;
+            }
+
+            copy_from_f32(acc.rb_mut().submatrix_mut(i, j, bm, bn), acc_block.rb());
+            i += bm;
+        }
+        j += bn;
+    }
+}
+
+/// Returns the dot product of `lhs` and `rhs`, accumulated in `f32`.
+///
+/// # Panics
+/// Panics if `lhs` and `rhs` don't have the same number of rows.
+#[track_caller]
+pub fn inner_prod<H: HalfFloat>(lhs: ColRef<'_, H>, rhs: ColRef<'_, H>) -> f32 {
+    assert!(lhs.nrows() == rhs.nrows());
+    let mut lhs_f32 = [0.0f32; CHUNK];
+    let mut rhs_f32 = [0.0f32; CHUNK];
+
+    let mut acc = 0.0f32;
+    let mut i = 0;
+    while i < lhs.nrows() {
+        let len = Ord::min(CHUNK, lhs.nrows() - i);
+        let lhs_chunk = &mut lhs_f32[..len];
+        let rhs_chunk = &mut rhs_f32[..len];
+        col_to_f32(
+            crate::col::from_slice_mut::<f32>(&mut *lhs_chunk),
+            lhs.subrows(i, len),
+        );
+        col_to_f32(
+            crate::col::from_slice_mut::<f32>(&mut *rhs_chunk),
+            rhs.subrows(i, len),
+        );
+        acc += inner_prod_with_conj(
+            crate::col::from_slice::<f32>(&*lhs_chunk).as_2d(),
+            Conj::No,
+            crate::col::from_slice::<f32>(&*rhs_chunk).as_2d(),
+            Conj::No,
+        );
+        i += len;
+    }
+    acc
+}
+
+// calls `f` on the values of `mat` converted to `f32`, one chunk of a column at a time
+fn for_each_chunk<H: HalfFloat>(mat: MatRef<'_, H>, mut f: impl FnMut(ColRef<'_, f32>)) {
+    let mut buf = [0.0f32; CHUNK];
+    for j in 0..mat.ncols() {
+        let col = mat.col(j);
+        let mut i = 0;
+        while i < col.nrows() {
+            let len = Ord::min(CHUNK, col.nrows() - i);
+            let chunk = &mut buf[..len];
+            col_to_f32(
+                crate::col::from_slice_mut::<f32>(&mut *chunk),
+                col.subrows(i, len),
+            );
+            f(crate::col::from_slice::<f32>(&*chunk));
+            i += len;
+        }
+    }
+}
+
+/// Returns the L2 norm of `mat`, computed in `f32`.
+pub fn norm_l2<H: HalfFloat>(mat: MatRef<'_, H>) -> f32 {
+    let mut acc = 0.0f32;
+    // the partial norms are combined with `hypot` to avoid the overflow of the sum of squares,
+    // which happens for much smaller values in half precision than in `f32`
+    for_each_chunk(mat, |chunk| acc = libm::hypotf(acc, chunk.norm_l2()));
+    acc
+}
+
+/// Returns the maximum absolute value of `mat`, computed in `f32`.
+pub fn norm_max<H: HalfFloat>(mat: MatRef<'_, H>) -> f32 {
+    let mut acc = 0.0f32;
+    for_each_chunk(mat, |chunk| acc = f32::max(acc, chunk.norm_max()));
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert;
+
+    fn test_matmul_for<H: HalfFloat>() {
+        // small integers, so that the f32 products and sums are exact and the only rounding
+        // happens when storing the result
+        let value = |i: usize, j: usize| ((i * 7 + j * 3) % 5) as f32 - 1.0;
+        for (m, n, k) in [(3, 4, 5), (1100, 20, 1030), (15, 1030, 2)] {
+            let lhs = Mat::<f32>::from_fn(m, k, value);
+            let rhs = Mat::<f32>::from_fn(k, n, |i, j| value(j, i));
+            let init = Mat::<f32>::from_fn(m, n, |i, j| value(i, i + j));
+
+            for alpha in [None, Some(2.0)] {
+                let mut target = init.clone();
+                matmul_f32(
+                    target.as_mut(),
+                    lhs.as_ref(),
+                    rhs.as_ref(),
+                    alpha,
+                    -1.0,
+                    Parallelism::None,
+                );
+
+                let mut acc = from_f32::<H>(init.as_ref());
+                matmul(
+                    acc.as_mut(),
+                    from_f32::<H>(lhs.as_ref()).as_ref(),
+                    from_f32::<H>(rhs.as_ref()).as_ref(),
+                    alpha,
+                    -1.0,
+                    Parallelism::None,
+                );
+                assert!(acc == from_f32::<H>(target.as_ref()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_matmul() {
+        test_matmul_for::<f16>();
+        test_matmul_for::<bf16>();
+    }
+
+    #[test]
+    fn test_reductions() {
+        let n = 1000;
+        let mat = Mat::<f16>::from_fn(n, 3, |i, j| f16::from_f32(((i + j) % 3) as f32));
+
+        // the sum of squares exceeds the largest f16 value
+        let target = to_f32(mat.as_ref()).norm_l2();
+        assert!((norm_l2(mat.as_ref()) - target).abs() < 1e-3 * target);
+        assert!(norm_max(mat.as_ref()) == 2.0);
+
+        // contiguous and strided columns
+        let dot = inner_prod(mat.col(0), mat.col(1));
+        let target = inner_prod_with_conj(
+            to_f32(mat.as_ref()).col(0).as_2d(),
+            Conj::No,
+            to_f32(mat.as_ref()).col(1).as_2d(),
+            Conj::No,
+        );
+        assert!(dot == target);
+
+        let t = Mat::<f16>::from_fn(3, n, |i, j| mat.read(j, i));
+        let dot = inner_prod(t.row(0).transpose(), t.row(2).transpose());
+        assert!(dot == inner_prod(mat.col(0), mat.col(2)));
+    }
+}
@@ -30,16 +30,18 @@
 
 use crate::{
     assert,
+    complex_native::c32,
     linalg::{
         matmul::{
             inner_prod, matmul, matmul_with_conj,
+            split_c32::{split_c32_req, SplitC32, SPLIT_C32_MIN_NCOLS},
             triangular::{self, BlockStructure},
         },
         temp_mat_req, temp_mat_uninit, triangular_solve as solve,
     },
     unzipped,
     utils::{simd::*, slice::*, thread::join_raw, DivCeil},
-    zipped, Conj, MatMut, MatRef, Parallelism,
+    zipped, ColRef, Conj, MatMut, MatRef, Parallelism,
 };
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use faer_entity::*;
@@ -103,13 +105,76 @@ pub fn make_householder_in_place<E: ComplexField>(
     }
 }
 
+fn default_inner_blocksize(blocksize: usize) -> usize {
+    if blocksize <= 4 || blocksize % 2 != 0 {
+        1
+    } else {
+        blocksize / 2
+    }
+}
+
+const DEFAULT_RECURSION_THRESHOLD: usize = 8;
+
+/// Block Householder tuning parameters.
+#[derive(Default, Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct HouseholderBlockingParams {
+    /// Block size used to compute the Householder factor of a block of a given size, by
+    /// recursively factorizing and merging smaller blocks. Must be smaller than the block size and
+    /// divide it, `1` disables the recursion. `None` to use the default schedule, which halves even
+    /// block sizes larger than `4`.
+    pub inner_blocksize: Option<fn(blocksize: usize) -> usize>,
+    /// Block size below which the Householder factors of the smaller blocks are ignored, and the
+    /// Householder factor is recomputed directly from the Householder basis instead. `None` to use
+    /// the default threshold of `8`.
+    pub recursion_threshold: Option<usize>,
+}
+
+impl HouseholderBlockingParams {
+    pub(crate) fn normalize(self) -> (fn(usize) -> usize, usize) {
+        (
+            self.inner_blocksize.unwrap_or(default_inner_blocksize),
+            self.recursion_threshold
+                .unwrap_or(DEFAULT_RECURSION_THRESHOLD),
+        )
+    }
+}
+
 #[doc(hidden)]
 pub fn upgrade_householder_factor<E: ComplexField>(
+    householder_factor: MatMut<'_, E>,
+    essentials: MatRef<'_, E>,
+    blocksize: usize,
+    prev_blocksize: usize,
+    parallelism: Parallelism,
+) {
+    upgrade_householder_factor_with_params(
+        householder_factor,
+        essentials,
+        blocksize,
+        prev_blocksize,
+        parallelism,
+        Default::default(),
+    )
+}
+
+/// Computes the Householder factors of a block Householder sequence for the block size
+/// `blocksize`, given the Householder factors for the smaller block size `prev_blocksize`, which
+/// are stored in the diagonal blocks of `householder_factor`.
+///
+/// `householder_factor` is a square matrix, and `essentials` contains the corresponding
+/// Householder basis.
+///
+/// # Panics
+/// Panics if `prev_blocksize` doesn't divide `blocksize`.
+#[track_caller]
+pub fn upgrade_householder_factor_with_params<E: ComplexField>(
     mut householder_factor: MatMut<'_, E>,
     essentials: MatRef<'_, E>,
     blocksize: usize,
     prev_blocksize: usize,
     parallelism: Parallelism,
+    params: HouseholderBlockingParams,
 ) {
     if blocksize == prev_blocksize || householder_factor.nrows() <= prev_blocksize {
         return;
@@ -131,21 +196,23 @@ pub fn upgrade_householder_factor<E: ComplexField>(
             basis_right.submatrix(idx, 0, essentials.nrows() - idx, basis_right.ncols());
         join_raw(
             |parallelism| {
-                upgrade_householder_factor(
+                upgrade_householder_factor_with_params(
                     tau_tl,
                     basis_left,
                     blocksize,
                     prev_blocksize,
                     parallelism,
+                    params,
                 )
             },
             |parallelism| {
-                upgrade_householder_factor(
+                upgrade_householder_factor_with_params(
                     tau_br,
                     basis_right,
                     blocksize,
                     prev_blocksize,
                     parallelism,
+                    params,
                 )
             },
             parallelism,
@@ -153,7 +220,8 @@ pub fn upgrade_householder_factor<E: ComplexField>(
         return;
     }
 
-    if prev_blocksize < 8 {
+    let (_, recursion_threshold) = params.normalize();
+    if prev_blocksize < recursion_threshold {
         // pretend that prev_blocksize == 1, recompute whole top half of matrix
 
         let (basis_top, basis_bot) = essentials.split_at_row(essentials.ncols());
@@ -192,21 +260,23 @@ pub fn upgrade_householder_factor<E: ComplexField>(
             |parallelism| {
                 join_raw(
                     |parallelism| {
-                        upgrade_householder_factor(
+                        upgrade_householder_factor_with_params(
                             tau_tl,
                             basis_left,
                             blocksize,
                             prev_blocksize,
                             parallelism,
+                            params,
                         )
                     },
                     |parallelism| {
-                        upgrade_householder_factor(
+                        upgrade_householder_factor_with_params(
                             tau_br,
                             basis_right,
                             blocksize,
                             prev_blocksize,
                             parallelism,
+                            params,
                         )
                     },
                     parallelism,
@@ -243,6 +313,137 @@ pub fn upgrade_householder_factor<E: ComplexField>(
     }
 }
 
+// computes the householder factors for the block size `blocksize` in the diagonal blocks of
+// `householder_factor`, from the coefficients stored on its diagonal, going through the
+// intermediate block sizes of the schedule in `params`
+fn upgrade_householder_factor_from_coeffs_impl<E: ComplexField>(
+    mut householder_factor: MatMut<'_, E>,
+    essentials: MatRef<'_, E>,
+    blocksize: usize,
+    parallelism: Parallelism,
+    params: HouseholderBlockingParams,
+) {
+    if blocksize <= 1 {
+        return;
+    }
+
+    let (inner_blocksize, recursion_threshold) = params.normalize();
+    let prev_blocksize = inner_blocksize(blocksize);
+    assert!(all(
+        prev_blocksize > 0,
+        prev_blocksize < blocksize,
+        blocksize % prev_blocksize == 0,
+    ));
+
+    // below the threshold, the factors of the smaller blocks are not used
+    if prev_blocksize >= recursion_threshold {
+        upgrade_householder_factor_from_coeffs_impl(
+            householder_factor.rb_mut(),
+            essentials,
+            prev_blocksize,
+            parallelism,
+            params,
+        );
+    }
+    upgrade_householder_factor_with_params(
+        householder_factor,
+        essentials,
+        blocksize,
+        prev_blocksize,
+        parallelism,
+        params,
+    );
+}
+
+/// Computes the Householder factor of a block of a block Householder sequence, from its
+/// Householder basis `essentials` and the coefficients $\tau$ of its Householder reflections, which
+/// are stored on the diagonal of `householder_factor`.
+///
+/// The factor is computed by merging the factors of smaller blocks, following the schedule in
+/// `params`.
+#[track_caller]
+pub(crate) fn upgrade_householder_factor_from_coeffs<E: ComplexField>(
+    householder_factor: MatMut<'_, E>,
+    essentials: MatRef<'_, E>,
+    parallelism: Parallelism,
+    params: HouseholderBlockingParams,
+) {
+    let blocksize = householder_factor.nrows();
+    assert!(householder_factor.ncols() == blocksize);
+    upgrade_householder_factor_from_coeffs_impl(
+        householder_factor,
+        essentials,
+        blocksize,
+        parallelism,
+        params,
+    );
+}
+
+/// Computes the Householder factors of a block Householder sequence for the block size given by
+/// the number of rows of `householder_factor`, from its Householder basis and the coefficients
+/// $\tau$ of its Householder reflections.
+///
+/// The coefficients are the diagonal entries of the Householder factors for any block size, so
+/// that for a sequence whose factors are stored with the block size $b$, the $j$-th coefficient
+/// is the entry at position $(j \bmod b, j)$.
+///
+/// # Panics
+/// Panics if the number of columns of `householder_factor` or the number of rows of
+/// `householder_coeffs` is not equal to the number of columns of `householder_basis`.
+#[track_caller]
+pub fn recompute_householder_factor<E: ComplexField>(
+    householder_factor: MatMut<'_, E>,
+    householder_basis: MatRef<'_, E>,
+    householder_coeffs: ColRef<'_, E>,
+    parallelism: Parallelism,
+) {
+    recompute_householder_factor_with_params(
+        householder_factor,
+        householder_basis,
+        householder_coeffs,
+        parallelism,
+        Default::default(),
+    )
+}
+
+/// Same as [`recompute_householder_factor`], but the factor of each block is computed by merging
+/// the factors of smaller blocks following the schedule in `params`.
+///
+/// # Panics
+/// Panics if the number of columns of `householder_factor` or the number of rows of
+/// `householder_coeffs` is not equal to the number of columns of `householder_basis`.
+#[track_caller]
+pub fn recompute_householder_factor_with_params<E: ComplexField>(
+    householder_factor: MatMut<'_, E>,
+    householder_basis: MatRef<'_, E>,
+    householder_coeffs: ColRef<'_, E>,
+    parallelism: Parallelism,
+    params: HouseholderBlockingParams,
+) {
+    let mut householder_factor = householder_factor;
+    let blocksize = householder_factor.nrows();
+    let size = householder_basis.ncols();
+    let m = householder_basis.nrows();
+    assert!(all(
+        blocksize > 0,
+        householder_factor.ncols() == size,
+        householder_coeffs.nrows() == size,
+        size <= m,
+    ));
+
+    let mut j = 0;
+    while j < size {
+        let bs = Ord::min(blocksize, size - j);
+        let mut householder = householder_factor.rb_mut().submatrix_mut(0, j, bs, bs);
+        let essentials = householder_basis.submatrix(j, j, m - j, bs);
+        for k in 0..bs {
+            householder.write(k, k, householder_coeffs.read(j + k));
+        }
+        upgrade_householder_factor_from_coeffs(householder, essentials, parallelism, params);
+        j += bs;
+    }
+}
+
 /// Computes the size and alignment of required workspace for applying a block Householder
 /// transformation to a right-hand-side matrix in place.
 pub fn apply_block_householder_on_the_left_in_place_req<E: Entity>(
@@ -250,8 +451,10 @@ pub fn apply_block_householder_on_the_left_in_place_req<E: Entity>(
     blocksize: usize,
     rhs_ncols: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = householder_basis_nrows;
-    temp_mat_req::<E>(blocksize, rhs_ncols)
+    StackReq::try_any_of([
+        temp_mat_req::<E>(blocksize, rhs_ncols)?,
+        apply_householder_on_the_left_c32_req::<E>(householder_basis_nrows)?,
+    ])
 }
 
 /// Computes the size and alignment of required workspace for applying the transpose of a block
@@ -261,8 +464,10 @@ pub fn apply_block_householder_transpose_on_the_left_in_place_req<E: Entity>(
     blocksize: usize,
     rhs_ncols: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = householder_basis_nrows;
-    temp_mat_req::<E>(blocksize, rhs_ncols)
+    StackReq::try_any_of([
+        temp_mat_req::<E>(blocksize, rhs_ncols)?,
+        apply_householder_on_the_left_c32_req::<E>(householder_basis_nrows)?,
+    ])
 }
 
 /// Computes the size and alignment of required workspace for applying a block Householder
@@ -272,8 +477,10 @@ pub fn apply_block_householder_on_the_right_in_place_req<E: Entity>(
     blocksize: usize,
     lhs_nrows: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = householder_basis_nrows;
-    temp_mat_req::<E>(blocksize, lhs_nrows)
+    StackReq::try_any_of([
+        temp_mat_req::<E>(lhs_nrows, blocksize)?,
+        apply_householder_on_the_left_c32_req::<E>(householder_basis_nrows)?,
+    ])
 }
 
 /// Computes the size and alignment of required workspace for applying the transpose of a block
@@ -283,8 +490,10 @@ pub fn apply_block_householder_transpose_on_the_right_in_place_req<E: Entity>(
     blocksize: usize,
     lhs_nrows: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = householder_basis_nrows;
-    temp_mat_req::<E>(blocksize, lhs_nrows)
+    StackReq::try_any_of([
+        temp_mat_req::<E>(lhs_nrows, blocksize)?,
+        apply_householder_on_the_left_c32_req::<E>(householder_basis_nrows)?,
+    ])
 }
 
 /// Computes the size and alignment of required workspace for applying the transpose of a sequence
@@ -294,8 +503,10 @@ pub fn apply_block_householder_sequence_transpose_on_the_left_in_place_req<E: En
     blocksize: usize,
     rhs_ncols: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = householder_basis_nrows;
-    temp_mat_req::<E>(blocksize, rhs_ncols)
+    StackReq::try_any_of([
+        temp_mat_req::<E>(blocksize, rhs_ncols)?,
+        apply_householder_on_the_left_c32_req::<E>(householder_basis_nrows)?,
+    ])
 }
 
 /// Computes the size and alignment of required workspace for applying a sequence of block
@@ -305,8 +516,10 @@ pub fn apply_block_householder_sequence_on_the_left_in_place_req<E: Entity>(
     blocksize: usize,
     rhs_ncols: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = householder_basis_nrows;
-    temp_mat_req::<E>(blocksize, rhs_ncols)
+    StackReq::try_any_of([
+        temp_mat_req::<E>(blocksize, rhs_ncols)?,
+        apply_householder_on_the_left_c32_req::<E>(householder_basis_nrows)?,
+    ])
 }
 
 /// Computes the size and alignment of required workspace for applying the transpose of a sequence
@@ -316,8 +529,10 @@ pub fn apply_block_householder_sequence_transpose_on_the_right_in_place_req<E: E
     blocksize: usize,
     lhs_nrows: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = householder_basis_nrows;
-    temp_mat_req::<E>(blocksize, lhs_nrows)
+    StackReq::try_any_of([
+        temp_mat_req::<E>(lhs_nrows, blocksize)?,
+        apply_householder_on_the_left_c32_req::<E>(householder_basis_nrows)?,
+    ])
 }
 
 /// Computes the size and alignment of required workspace for applying a sequence of block
@@ -327,8 +542,57 @@ pub fn apply_block_householder_sequence_on_the_right_in_place_req<E: Entity>(
     blocksize: usize,
     lhs_nrows: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = householder_basis_nrows;
-    temp_mat_req::<E>(blocksize, lhs_nrows)
+    StackReq::try_any_of([
+        temp_mat_req::<E>(lhs_nrows, blocksize)?,
+        apply_householder_on_the_left_c32_req::<E>(householder_basis_nrows)?,
+    ])
+}
+
+/// Computes the size and alignment of the workspace required by
+/// [`apply_householder_on_the_left_c32`] for a Householder basis with `householder_basis_nrows`
+/// rows. It is empty if `E` isn't `c32`.
+pub(crate) fn apply_householder_on_the_left_c32_req<E: Entity>(
+    householder_basis_nrows: usize,
+) -> Result<StackReq, SizeOverflow> {
+    if coe::is_same::<E, c32>() {
+        split_c32_req(householder_basis_nrows.saturating_sub(1))
+    } else {
+        Ok(StackReq::empty())
+    }
+}
+
+/// Applies the Householder reflection $I - \frac{v v^H}{\tau}$, or its conjugate if `conj` is
+/// `Conj::Yes`, to the columns of `matrix` from the left, where $v_0 = 1$ and $v_{1\dots}$ is
+/// stored in `essential`.
+///
+/// `matrix` must be column major. The essential part is converted once to the split layout in
+/// `stack`, so that the per-column dot products and updates only use `f32` arithmetic.
+pub(crate) fn apply_householder_on_the_left_c32(
+    tau_inv: c32,
+    essential: MatRef<'_, c32>,
+    conj: Conj,
+    matrix: MatMut<'_, c32>,
+    stack: PodStack<'_>,
+) {
+    assert!(all(
+        essential.ncols() == 1,
+        essential.row_stride() == 1,
+        matrix.row_stride() == 1,
+        matrix.nrows() == essential.nrows() + 1,
+    ));
+
+    let split = SplitC32::new(essential.try_get_contiguous_col(0), conj, stack);
+    let mut matrix = matrix;
+    for j in 0..matrix.ncols() {
+        let (col0, col) = matrix
+            .rb_mut()
+            .try_get_contiguous_col_mut(j)
+            .split_at_mut(1);
+        let dot = col0[0].faer_add(split.conj_dot(col));
+        let k = dot.faer_mul(tau_inv).faer_neg();
+        col0[0] = col0[0].faer_add(k);
+        split.axpy(col, k);
+    }
 }
 
 #[track_caller]
@@ -348,7 +612,25 @@ fn apply_block_householder_on_the_left_in_place_generic<E: ComplexField>(
     ));
 
     let bs = householder_factor.nrows();
-    if householder_basis.row_stride() == 1 && matrix.row_stride() == 1 && bs == 1 {
+    if householder_basis.row_stride() == 1
+        && matrix.row_stride() == 1
+        && bs == 1
+        && coe::is_same::<E, c32>()
+        && matrix.ncols() >= SPLIT_C32_MIN_NCOLS
+        && matches!(
+            apply_householder_on_the_left_c32_req::<E>(householder_basis.nrows()),
+            Ok(req) if stack.can_hold(req)
+        )
+    {
+        let tau_inv = E::faer_from_real(householder_factor.read(0, 0).faer_real().faer_inv());
+        apply_householder_on_the_left_c32(
+            coe::coerce_static(tau_inv),
+            coe::coerce(householder_basis.split_at_row(1).1),
+            conj_lhs,
+            coe::coerce(matrix),
+            stack,
+        );
+    } else if householder_basis.row_stride() == 1 && matrix.row_stride() == 1 && bs == 1 {
         let arch = E::Simd::default();
 
         struct ApplyOnLeft<'a, C: ConjTy, E: ComplexField> {
@@ -562,6 +844,138 @@ fn apply_block_householder_on_the_left_in_place_generic<E: ComplexField>(
     }
 }
 
+#[track_caller]
+fn apply_block_householder_on_the_right_in_place_generic<E: ComplexField>(
+    householder_basis: MatRef<'_, E>,
+    householder_factor: MatRef<'_, E>,
+    conj_rhs: Conj,
+    matrix: MatMut<'_, E>,
+    forward: bool,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    assert!(all(
+        householder_factor.nrows() == householder_factor.ncols(),
+        householder_basis.ncols() == householder_factor.nrows(),
+        matrix.ncols() == householder_basis.nrows(),
+    ));
+
+    let bs = householder_factor.nrows();
+    let (essentials_top, essentials_bot) = householder_basis.split_at_row(bs);
+    let m = matrix.nrows();
+    let n = matrix.ncols();
+
+    // mat × essentials
+    let (tmp, _) = temp_mat_uninit::<E>(m, bs, stack);
+
+    let mut n_tasks = Ord::min(
+        Ord::min(crate::utils::thread::parallelism_degree(parallelism), m),
+        4,
+    );
+    if (m * n).saturating_mul(4 * bs) < gemm::get_threading_threshold() {
+        n_tasks = 1;
+    }
+
+    let inner_parallelism = match parallelism {
+        Parallelism::None => Parallelism::None,
+        #[cfg(feature = "rayon")]
+        Parallelism::Rayon(mut par) => {
+            if par == 0 {
+                par = rayon::current_num_threads();
+            }
+
+            if par >= 2 * n_tasks {
+                Parallelism::Rayon(par / n_tasks)
+            } else {
+                Parallelism::None
+            }
+        }
+    };
+
+    crate::utils::thread::for_each_raw(
+        n_tasks,
+        |tid| {
+            let (tid_row, tid_m) = crate::utils::thread::par_split_indices(m, tid, n_tasks);
+
+            let mut tmp = unsafe { tmp.rb().subrows(tid_row, tid_m).const_cast() };
+            let (mut matrix_left, mut matrix_right) = unsafe {
+                matrix
+                    .rb()
+                    .subrows(tid_row, tid_m)
+                    .const_cast()
+                    .split_at_col_mut(bs)
+            };
+
+            triangular::matmul_with_conj(
+                tmp.rb_mut(),
+                BlockStructure::Rectangular,
+                matrix_left.rb(),
+                BlockStructure::Rectangular,
+                Conj::No,
+                essentials_top,
+                BlockStructure::UnitTriangularLower,
+                Conj::No.compose(conj_rhs),
+                None,
+                E::faer_one(),
+                inner_parallelism,
+            );
+            matmul_with_conj(
+                tmp.rb_mut(),
+                matrix_right.rb(),
+                Conj::No,
+                essentials_bot,
+                Conj::No.compose(conj_rhs),
+                Some(E::faer_one()),
+                E::faer_one(),
+                inner_parallelism,
+            );
+
+            // tmp × [T^-1|T^-*]
+            if forward {
+                solve::solve_upper_triangular_in_place_with_conj(
+                    householder_factor,
+                    Conj::Yes.compose(conj_rhs),
+                    tmp.rb_mut().transpose_mut(),
+                    inner_parallelism,
+                );
+            } else {
+                solve::solve_lower_triangular_in_place_with_conj(
+                    householder_factor.transpose(),
+                    Conj::No.compose(conj_rhs),
+                    tmp.rb_mut().transpose_mut(),
+                    inner_parallelism,
+                );
+            }
+
+            // tmp × [T^-1|T^-*] × essentials*
+            triangular::matmul_with_conj(
+                matrix_left.rb_mut(),
+                BlockStructure::Rectangular,
+                tmp.rb(),
+                BlockStructure::Rectangular,
+                Conj::No,
+                essentials_top.transpose(),
+                BlockStructure::UnitTriangularUpper,
+                Conj::Yes.compose(conj_rhs),
+                Some(E::faer_one()),
+                E::faer_one().faer_neg(),
+                inner_parallelism,
+            );
+            matmul_with_conj(
+                matrix_right.rb_mut(),
+                tmp.rb(),
+                Conj::No,
+                essentials_bot.transpose(),
+                Conj::Yes.compose(conj_rhs),
+                Some(E::faer_one()),
+                E::faer_one().faer_neg(),
+                inner_parallelism,
+            );
+        },
+        parallelism,
+    );
+}
+
 /// Computes the product of the matrix, multiplied by the given block Householder transformation,
 /// and stores the result in `matrix`.
 #[track_caller]
@@ -573,11 +987,12 @@ pub fn apply_block_householder_on_the_right_in_place_with_conj<E: ComplexField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
 ) {
-    apply_block_householder_transpose_on_the_left_in_place_with_conj(
+    apply_block_householder_on_the_right_in_place_generic(
         householder_basis,
         householder_factor,
         conj_rhs,
-        matrix.transpose_mut(),
+        matrix,
+        false,
         parallelism,
         stack,
     )
@@ -594,11 +1009,12 @@ pub fn apply_block_householder_transpose_on_the_right_in_place_with_conj<E: Comp
     parallelism: Parallelism,
     stack: PodStack<'_>,
 ) {
-    apply_block_householder_on_the_left_in_place_with_conj(
+    apply_block_householder_on_the_right_in_place_generic(
         householder_basis,
         householder_factor,
-        conj_rhs,
-        matrix.transpose_mut(),
+        conj_rhs.compose(Conj::Yes),
+        matrix,
+        true,
         parallelism,
         stack,
     )
@@ -752,14 +1168,35 @@ pub fn apply_block_householder_sequence_on_the_right_in_place_with_conj<E: Compl
     parallelism: Parallelism,
     stack: PodStack<'_>,
 ) {
-    apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj(
-        householder_basis,
-        householder_factor,
-        conj_rhs,
-        matrix.transpose_mut(),
-        parallelism,
-        stack,
-    )
+    let mut matrix = matrix;
+    let mut stack = stack;
+    let blocksize = householder_factor.nrows();
+    assert!(all(
+        blocksize > 0,
+        matrix.ncols() == householder_basis.nrows()
+    ));
+    let m = householder_basis.nrows();
+    let k = matrix.nrows();
+
+    let size = householder_factor.ncols();
+
+    let mut j = 0;
+    while j < size {
+        let bs = Ord::min(blocksize, size - j);
+        let essentials = householder_basis.submatrix(j, j, m - j, bs);
+        let householder = householder_factor.submatrix(0, j, bs, bs);
+
+        apply_block_householder_on_the_right_in_place_with_conj(
+            essentials,
+            householder,
+            conj_rhs,
+            matrix.rb_mut().submatrix_mut(0, j, k, m - j),
+            parallelism,
+            stack.rb_mut(),
+        );
+
+        j += bs;
+    }
 }
 
 /// Computes the product of `matrix`, multiplied by the transpose of a sequence of block Householder
@@ -776,12 +1213,316 @@ pub fn apply_block_householder_sequence_transpose_on_the_right_in_place_with_con
     parallelism: Parallelism,
     stack: PodStack<'_>,
 ) {
-    apply_block_householder_sequence_on_the_left_in_place_with_conj(
-        householder_basis,
-        householder_factor,
-        conj_rhs,
-        matrix.transpose_mut(),
-        parallelism,
-        stack,
-    )
+    let mut matrix = matrix;
+    let mut stack = stack;
+
+    let blocksize = householder_factor.nrows();
+
+    assert!(all(
+        blocksize > 0,
+        matrix.ncols() == householder_basis.nrows()
+    ));
+    let m = householder_basis.nrows();
+    let k = matrix.nrows();
+
+    let size = householder_factor.ncols();
+
+    let mut j = size;
+    let mut bs = size % blocksize;
+    if bs == 0 {
+        bs = blocksize
+    }
+
+    while j > 0 {
+        j -= bs;
+
+        let essentials = householder_basis.submatrix(j, j, m - j, bs);
+        let householder = householder_factor.submatrix(0, j, bs, bs);
+
+        apply_block_householder_transpose_on_the_right_in_place_with_conj(
+            essentials,
+            householder,
+            conj_rhs,
+            matrix.rb_mut().submatrix_mut(0, j, k, m - j),
+            parallelism,
+            stack.rb_mut(),
+        );
+
+        bs = blocksize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        complex_native::{c32, c64},
+        linalg::qr::no_pivoting::compute,
+        Mat,
+    };
+    use rand::prelude::*;
+
+    macro_rules! make_stack {
+        ($req: expr $(,)?) => {
+            ::dyn_stack::PodStack::new(&mut ::dyn_stack::GlobalPodBuffer::new($req.unwrap()))
+        };
+    }
+
+    #[test]
+    fn test_apply_sequence_on_the_right() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let mut random = || c64::new(rng.gen(), rng.gen());
+
+        for (k, n) in [(1, 1), (4, 4), (20, 7), (7, 20), (65, 50)] {
+            let blocksize = compute::recommended_blocksize::<c64>(n, n);
+            let mut qr = Mat::<c64>::from_fn(n, n, |_, _| random());
+            let mut householder = Mat::<c64>::zeros(blocksize, n);
+            compute::qr_in_place(
+                qr.as_mut(),
+                householder.as_mut(),
+                Parallelism::None,
+                make_stack!(compute::qr_in_place_req::<c64>(
+                    n,
+                    n,
+                    blocksize,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+
+            let a = Mat::<c64>::from_fn(k, n, |_, _| random());
+
+            for conj in [Conj::No, Conj::Yes] {
+                for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+                    let mut expected = a.transpose().to_owned();
+                    apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj(
+                        qr.as_ref(),
+                        householder.as_ref(),
+                        conj,
+                        expected.as_mut(),
+                        parallelism,
+                        make_stack!(
+                            apply_block_householder_sequence_transpose_on_the_left_in_place_req::<
+                                c64,
+                            >(n, blocksize, k)
+                        ),
+                    );
+                    let mut actual = a.clone();
+                    apply_block_householder_sequence_on_the_right_in_place_with_conj(
+                        qr.as_ref(),
+                        householder.as_ref(),
+                        conj,
+                        actual.as_mut(),
+                        parallelism,
+                        make_stack!(
+                            apply_block_householder_sequence_on_the_right_in_place_req::<c64>(
+                                n, blocksize, k
+                            )
+                        ),
+                    );
+                    assert!((&actual - expected.transpose()).norm_max() < 1e-10);
+
+                    let mut expected = a.transpose().to_owned();
+                    apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                        qr.as_ref(),
+                        householder.as_ref(),
+                        conj,
+                        expected.as_mut(),
+                        parallelism,
+                        make_stack!(
+                            apply_block_householder_sequence_on_the_left_in_place_req::<c64>(
+                                n, blocksize, k
+                            )
+                        ),
+                    );
+                    let mut actual = a.clone();
+                    apply_block_householder_sequence_transpose_on_the_right_in_place_with_conj(
+                        qr.as_ref(),
+                        householder.as_ref(),
+                        conj,
+                        actual.as_mut(),
+                        parallelism,
+                        make_stack!(
+                            apply_block_householder_sequence_transpose_on_the_right_in_place_req::<
+                                c64,
+                            >(n, blocksize, k)
+                        ),
+                    );
+                    assert!((&actual - expected.transpose()).norm_max() < 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_c32_householder() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let mut random = || c32::new(rng.gen(), rng.gen());
+        let to_c64 = |z: c32| c64::new(z.re as f64, z.im as f64);
+
+        for (m, n, k) in [(4, 4, 4), (20, 7, 5), (65, 50, 9)] {
+            let a = Mat::<c32>::from_fn(m, n, |_, _| random());
+            let b = Mat::<c32>::from_fn(m, k, |_, _| random());
+
+            // blocksize 1 goes through the unblocked QR and the single reflector application
+            let mut qr32 = a.clone();
+            let mut householder32 = Mat::<c32>::zeros(1, Ord::min(m, n));
+            compute::qr_in_place(
+                qr32.as_mut(),
+                householder32.as_mut(),
+                Parallelism::None,
+                make_stack!(compute::qr_in_place_req::<c32>(
+                    m,
+                    n,
+                    1,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+            let mut qr64 = Mat::<c64>::from_fn(m, n, |i, j| to_c64(a.read(i, j)));
+            let mut householder64 = Mat::<c64>::zeros(1, Ord::min(m, n));
+            compute::qr_in_place(
+                qr64.as_mut(),
+                householder64.as_mut(),
+                Parallelism::None,
+                make_stack!(compute::qr_in_place_req::<c64>(
+                    m,
+                    n,
+                    1,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+            for j in 0..n {
+                for i in 0..m {
+                    assert!((to_c64(qr32.read(i, j)) - qr64.read(i, j)).faer_abs() < 1e-3);
+                }
+            }
+
+            for conj in [Conj::No, Conj::Yes] {
+                let mut actual = b.clone();
+                apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                    qr32.as_ref(),
+                    householder32.as_ref(),
+                    conj,
+                    actual.as_mut(),
+                    Parallelism::None,
+                    make_stack!(
+                        apply_block_householder_sequence_on_the_left_in_place_req::<c32>(m, 1, k)
+                    ),
+                );
+                let mut expected = Mat::<c64>::from_fn(m, k, |i, j| to_c64(b.read(i, j)));
+                apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                    qr64.as_ref(),
+                    householder64.as_ref(),
+                    conj,
+                    expected.as_mut(),
+                    Parallelism::None,
+                    make_stack!(
+                        apply_block_householder_sequence_on_the_left_in_place_req::<c64>(m, 1, k)
+                    ),
+                );
+                for j in 0..k {
+                    for i in 0..m {
+                        let err = to_c64(actual.read(i, j)) - expected.read(i, j);
+                        assert!(err.faer_abs() < 1e-3);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_recompute_householder_factor() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let mut random = || c64::new(rng.gen(), rng.gen());
+
+        let (m, n, k) = (200, 40, 3);
+        let a = Mat::<c64>::from_fn(m, n, |_, _| random());
+        let rhs = Mat::<c64>::from_fn(m, k, |_, _| random());
+
+        let blocking_params = [
+            HouseholderBlockingParams::default(),
+            HouseholderBlockingParams {
+                inner_blocksize: Some(|bs| if bs % 4 == 0 { bs / 4 } else { 1 }),
+                recursion_threshold: Some(2),
+            },
+        ];
+
+        let mut first_expected = None::<Mat<c64>>;
+        for blocking in blocking_params {
+            let blocksize = 16;
+            let params = compute::QrComputeParams {
+                blocking,
+                ..Default::default()
+            };
+            let mut qr = a.clone();
+            let mut householder = Mat::<c64>::zeros(blocksize, n);
+            compute::qr_in_place(
+                qr.as_mut(),
+                householder.as_mut(),
+                Parallelism::None,
+                make_stack!(compute::qr_in_place_req::<c64>(
+                    m,
+                    n,
+                    blocksize,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            let mut expected = rhs.clone();
+            apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                qr.as_ref(),
+                householder.as_ref(),
+                Conj::No,
+                expected.as_mut(),
+                Parallelism::None,
+                make_stack!(
+                    apply_block_householder_sequence_on_the_left_in_place_req::<c64>(
+                        m, blocksize, k
+                    )
+                ),
+            );
+
+            match &first_expected {
+                Some(first_expected) => {
+                    assert!((first_expected - &expected).norm_max() < 1e-10);
+                }
+                None => first_expected = Some(expected.clone()),
+            }
+
+            let coeffs = crate::Col::<c64>::from_fn(n, |j| householder.read(j % blocksize, j));
+            for new_blocksize in [1, 5, 40] {
+                let mut new_householder = Mat::<c64>::zeros(new_blocksize, n);
+                recompute_householder_factor(
+                    new_householder.as_mut(),
+                    qr.as_ref(),
+                    coeffs.as_ref(),
+                    Parallelism::None,
+                );
+
+                let mut actual = rhs.clone();
+                apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                    qr.as_ref(),
+                    new_householder.as_ref(),
+                    Conj::No,
+                    actual.as_mut(),
+                    Parallelism::None,
+                    make_stack!(
+                        apply_block_householder_sequence_on_the_left_in_place_req::<c64>(
+                            m,
+                            new_blocksize,
+                            k
+                        )
+                    ),
+                );
+                assert!((&actual - &expected).norm_max() < 1e-10);
+            }
+        }
+    }
 }
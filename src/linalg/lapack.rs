@@ -0,0 +1,869 @@
+//! Delegation of selected operations to a system BLAS/LAPACK implementation.
+//!
+//! The Hermitian eigendecomposition (`?syevd`), the real eigendecomposition (`?geev`) and the
+//! singular value decomposition (`?gesdd`) of `f32` and `f64` matrices are forwarded to the
+//! corresponding routines when the `lapack` feature is enabled. Each internal entry point returns
+//! `false` if the operation can't be delegated, e.g. because the scalar type isn't supported, the
+//! strides of the matrices can't be expressed as a BLAS leading dimension, or the dimensions don't
+//! fit in a Fortran integer, in which case the caller falls back to the native implementation.
+//!
+//! Matrix multiplication is not rerouted, since most products in faer are small updates issued
+//! from inside the factorizations, often from threads that are already busy. Large products can
+//! be delegated explicitly with [`matmul`].
+//!
+//! The routines are declared with the usual Fortran calling convention (trailing underscore, all
+//! arguments passed by pointer, 32-bit integers, and the hidden lengths of the character arguments
+//! passed at the end), and the library providing them is not linked by faer. Every binary built
+//! with the `lapack` feature, including tests, examples, benchmarks and doctests, must link one,
+//! e.g. by depending on `openblas-src` and adding `extern crate openblas_src;`, or by passing the
+//! library to the linker:
+//!
+//! ```sh
+//! RUSTFLAGS="-l openblas" RUSTDOCFLAGS="-l openblas" cargo test --features lapack
+//! ```
+
+use crate::{
+    assert,
+    mat::{self, MatMut, MatRef},
+    ComplexField, Entity, Mat,
+};
+use alloc::vec;
+use core::ffi::c_char;
+use faer_entity::{GroupFor, SimpleEntity};
+
+extern "C" {
+    fn sgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i32,
+        n: *const i32,
+        k: *const i32,
+        alpha: *const f32,
+        a: *const f32,
+        lda: *const i32,
+        b: *const f32,
+        ldb: *const i32,
+        beta: *const f32,
+        c: *mut f32,
+        ldc: *const i32,
+        transa_len: usize,
+        transb_len: usize,
+    );
+    fn dgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i32,
+        n: *const i32,
+        k: *const i32,
+        alpha: *const f64,
+        a: *const f64,
+        lda: *const i32,
+        b: *const f64,
+        ldb: *const i32,
+        beta: *const f64,
+        c: *mut f64,
+        ldc: *const i32,
+        transa_len: usize,
+        transb_len: usize,
+    );
+
+    fn ssyevd_(
+        jobz: *const c_char,
+        uplo: *const c_char,
+        n: *const i32,
+        a: *mut f32,
+        lda: *const i32,
+        w: *mut f32,
+        work: *mut f32,
+        lwork: *const i32,
+        iwork: *mut i32,
+        liwork: *const i32,
+        info: *mut i32,
+        jobz_len: usize,
+        uplo_len: usize,
+    );
+    fn dsyevd_(
+        jobz: *const c_char,
+        uplo: *const c_char,
+        n: *const i32,
+        a: *mut f64,
+        lda: *const i32,
+        w: *mut f64,
+        work: *mut f64,
+        lwork: *const i32,
+        iwork: *mut i32,
+        liwork: *const i32,
+        info: *mut i32,
+        jobz_len: usize,
+        uplo_len: usize,
+    );
+
+    fn sgeev_(
+        jobvl: *const c_char,
+        jobvr: *const c_char,
+        n: *const i32,
+        a: *mut f32,
+        lda: *const i32,
+        wr: *mut f32,
+        wi: *mut f32,
+        vl: *mut f32,
+        ldvl: *const i32,
+        vr: *mut f32,
+        ldvr: *const i32,
+        work: *mut f32,
+        lwork: *const i32,
+        info: *mut i32,
+        jobvl_len: usize,
+        jobvr_len: usize,
+    );
+    fn dgeev_(
+        jobvl: *const c_char,
+        jobvr: *const c_char,
+        n: *const i32,
+        a: *mut f64,
+        lda: *const i32,
+        wr: *mut f64,
+        wi: *mut f64,
+        vl: *mut f64,
+        ldvl: *const i32,
+        vr: *mut f64,
+        ldvr: *const i32,
+        work: *mut f64,
+        lwork: *const i32,
+        info: *mut i32,
+        jobvl_len: usize,
+        jobvr_len: usize,
+    );
+
+    fn sgesdd_(
+        jobz: *const c_char,
+        m: *const i32,
+        n: *const i32,
+        a: *mut f32,
+        lda: *const i32,
+        s: *mut f32,
+        u: *mut f32,
+        ldu: *const i32,
+        vt: *mut f32,
+        ldvt: *const i32,
+        work: *mut f32,
+        lwork: *const i32,
+        iwork: *mut i32,
+        info: *mut i32,
+        jobz_len: usize,
+    );
+    fn dgesdd_(
+        jobz: *const c_char,
+        m: *const i32,
+        n: *const i32,
+        a: *mut f64,
+        lda: *const i32,
+        s: *mut f64,
+        u: *mut f64,
+        ldu: *const i32,
+        vt: *mut f64,
+        ldvt: *const i32,
+        work: *mut f64,
+        lwork: *const i32,
+        iwork: *mut i32,
+        info: *mut i32,
+        jobz_len: usize,
+    );
+}
+
+/// Scalar types supported by the system BLAS/LAPACK routines.
+trait Scalar: SimpleEntity + ComplexField {
+    unsafe fn gemm(
+        transa: c_char,
+        transb: c_char,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: Self,
+        c: *mut Self,
+        ldc: i32,
+    );
+
+    unsafe fn syevd(
+        jobz: c_char,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        w: *mut Self,
+        work: &mut [Self],
+        iwork: &mut [i32],
+    ) -> i32;
+
+    unsafe fn geev(
+        jobvr: c_char,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        wr: *mut Self,
+        wi: *mut Self,
+        vr: *mut Self,
+        ldvr: i32,
+        work: &mut [Self],
+    ) -> i32;
+
+    unsafe fn gesdd(
+        jobz: c_char,
+        m: i32,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        s: *mut Self,
+        u: *mut Self,
+        ldu: i32,
+        vt: *mut Self,
+        ldvt: i32,
+        work: &mut [Self],
+        iwork: &mut [i32],
+    ) -> i32;
+
+    /// Converts the optimal workspace size returned by a workspace query.
+    fn work_len(self) -> usize;
+}
+
+macro_rules! impl_scalar {
+    ($ty: ty, $gemm: ident, $syevd: ident, $geev: ident, $gesdd: ident) => {
+        impl Scalar for $ty {
+            #[inline]
+            unsafe fn gemm(
+                transa: c_char,
+                transb: c_char,
+                m: i32,
+                n: i32,
+                k: i32,
+                alpha: Self,
+                a: *const Self,
+                lda: i32,
+                b: *const Self,
+                ldb: i32,
+                beta: Self,
+                c: *mut Self,
+                ldc: i32,
+            ) {
+                $gemm(
+                    &transa, &transb, &m, &n, &k, &alpha, a, &lda, b, &ldb, &beta, c, &ldc, 1, 1,
+                )
+            }
+
+            #[inline]
+            unsafe fn syevd(
+                jobz: c_char,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                w: *mut Self,
+                work: &mut [Self],
+                iwork: &mut [i32],
+            ) -> i32 {
+                let mut info = 0;
+                $syevd(
+                    &jobz,
+                    &(b'L' as c_char),
+                    &n,
+                    a,
+                    &lda,
+                    w,
+                    work.as_mut_ptr(),
+                    &lwork(work.len()),
+                    iwork.as_mut_ptr(),
+                    &lwork(iwork.len()),
+                    &mut info,
+                    1,
+                    1,
+                );
+                info
+            }
+
+            #[inline]
+            unsafe fn geev(
+                jobvr: c_char,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                wr: *mut Self,
+                wi: *mut Self,
+                vr: *mut Self,
+                ldvr: i32,
+                work: &mut [Self],
+            ) -> i32 {
+                let mut info = 0;
+                let mut vl = [0.0 as Self];
+                $geev(
+                    &(b'N' as c_char),
+                    &jobvr,
+                    &n,
+                    a,
+                    &lda,
+                    wr,
+                    wi,
+                    vl.as_mut_ptr(),
+                    &1,
+                    vr,
+                    &ldvr,
+                    work.as_mut_ptr(),
+                    &lwork(work.len()),
+                    &mut info,
+                    1,
+                    1,
+                );
+                info
+            }
+
+            #[inline]
+            unsafe fn gesdd(
+                jobz: c_char,
+                m: i32,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                s: *mut Self,
+                u: *mut Self,
+                ldu: i32,
+                vt: *mut Self,
+                ldvt: i32,
+                work: &mut [Self],
+                iwork: &mut [i32],
+            ) -> i32 {
+                let mut info = 0;
+                $gesdd(
+                    &jobz,
+                    &m,
+                    &n,
+                    a,
+                    &lda,
+                    s,
+                    u,
+                    &ldu,
+                    vt,
+                    &ldvt,
+                    work.as_mut_ptr(),
+                    &lwork(work.len()),
+                    iwork.as_mut_ptr(),
+                    &mut info,
+                    1,
+                );
+                info
+            }
+
+            #[inline]
+            fn work_len(self) -> usize {
+                Ord::max(self as usize, 1)
+            }
+        }
+    };
+}
+
+impl_scalar!(f32, sgemm_, ssyevd_, sgeev_, sgesdd_);
+impl_scalar!(f64, dgemm_, dsyevd_, dgeev_, dgesdd_);
+
+// workspace slices of length 1 are used for workspace queries
+#[inline]
+fn lwork(len: usize) -> i32 {
+    if len == 1 {
+        -1
+    } else {
+        len as i32
+    }
+}
+
+#[inline]
+fn int(n: usize) -> Option<i32> {
+    i32::try_from(n).ok()
+}
+
+#[inline]
+unsafe fn cast_ref<'a, E: Entity, T: SimpleEntity>(mat: MatRef<'a, E>) -> MatRef<'a, T> {
+    mat::from_raw_parts(
+        coe::coerce_static::<GroupFor<E, *const E::Unit>, *const T>(mat.as_ptr()),
+        mat.nrows(),
+        mat.ncols(),
+        mat.row_stride(),
+        mat.col_stride(),
+    )
+}
+
+#[inline]
+unsafe fn cast_mut<'a, E: Entity, T: SimpleEntity>(mat: MatMut<'a, E>) -> MatMut<'a, T> {
+    let (nrows, ncols, row_stride, col_stride) =
+        (mat.nrows(), mat.ncols(), mat.row_stride(), mat.col_stride());
+    mat::from_raw_parts_mut(
+        coe::coerce_static::<GroupFor<E, *mut E::Unit>, *mut T>(mat.as_ptr_mut()),
+        nrows,
+        ncols,
+        row_stride,
+        col_stride,
+    )
+}
+
+/// Returns the transposition flag and leading dimension that describe `mat` as a BLAS operand, if
+/// it is stored contiguously along its rows or columns.
+fn operand<T: Entity>(mat: MatRef<'_, T>) -> Option<(c_char, i32)> {
+    let (m, n) = (mat.nrows(), mat.ncols());
+    let (rs, cs) = (mat.row_stride(), mat.col_stride());
+    let m1 = Ord::max(m, 1);
+    let n1 = Ord::max(n, 1);
+
+    if rs == 1 && (n <= 1 || cs >= m1 as isize) {
+        let ld = if n <= 1 { m1 } else { cs as usize };
+        Some((b'N' as c_char, int(ld)?))
+    } else if cs == 1 && (m <= 1 || rs >= n1 as isize) {
+        let ld = if m <= 1 { n1 } else { rs as usize };
+        Some((b'T' as c_char, int(ld)?))
+    } else {
+        None
+    }
+}
+
+fn matmul_imp<T: Scalar>(
+    acc: MatMut<'_, T>,
+    lhs: MatRef<'_, T>,
+    rhs: MatRef<'_, T>,
+    alpha: Option<T>,
+    beta: T,
+) -> Option<()> {
+    let (m, n, k) = (acc.nrows(), acc.ncols(), lhs.ncols());
+    if m == 0 || n == 0 || k == 0 {
+        return None;
+    }
+
+    // a row-major destination is handled by computing the transposed product
+    let (mut acc, lhs, rhs) = if operand(acc.rb())?.0 == b'N' as c_char {
+        (acc, lhs, rhs)
+    } else {
+        (acc.transpose_mut(), rhs.transpose(), lhs.transpose())
+    };
+    let (_, ldc) = operand(acc.rb()).filter(|&(trans, _)| trans == b'N' as c_char)?;
+    let (transa, lda) = operand(lhs)?;
+    let (transb, ldb) = operand(rhs)?;
+
+    unsafe {
+        T::gemm(
+            transa,
+            transb,
+            int(acc.nrows())?,
+            int(acc.ncols())?,
+            int(k)?,
+            beta,
+            lhs.as_ptr(),
+            lda,
+            rhs.as_ptr(),
+            ldb,
+            alpha.unwrap_or_else(T::faer_zero),
+            acc.as_ptr_mut(),
+            ldc,
+        );
+    }
+    Some(())
+}
+
+fn hermitian_evd_imp<T: Scalar>(
+    matrix: MatRef<'_, T>,
+    s: MatMut<'_, T>,
+    u: Option<MatMut<'_, T>>,
+) -> Option<()> {
+    let n = matrix.nrows();
+    let n_ = int(n)?;
+    let jobz = if u.is_some() { b'V' } else { b'N' } as c_char;
+
+    let mut a = matrix.to_owned();
+    let lda = int(a.col_stride() as usize)?;
+    let mut w = vec![T::faer_zero(); n];
+
+    let mut query = [T::faer_zero()];
+    let mut iquery = [0i32];
+    let info = unsafe {
+        T::syevd(
+            jobz,
+            n_,
+            a.as_ptr_mut(),
+            lda,
+            w.as_mut_ptr(),
+            &mut query,
+            &mut iquery,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+    let mut work = vec![T::faer_zero(); Ord::max(query[0].work_len(), 2)];
+    let mut iwork = vec![0i32; Ord::max(iquery[0] as usize, 2)];
+    let info = unsafe {
+        T::syevd(
+            jobz,
+            n_,
+            a.as_ptr_mut(),
+            lda,
+            w.as_mut_ptr(),
+            &mut work,
+            &mut iwork,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+
+    { s }.col_mut(0).copy_from(crate::col::from_slice::<T>(&w));
+    if let Some(mut u) = u {
+        u.copy_from(a.as_ref());
+    }
+    Some(())
+}
+
+fn real_evd_imp<T: Scalar>(
+    matrix: MatRef<'_, T>,
+    s_re: MatMut<'_, T>,
+    s_im: MatMut<'_, T>,
+    u: Option<MatMut<'_, T>>,
+) -> Option<()> {
+    let n = matrix.nrows();
+    let n_ = int(n)?;
+    let jobvr = if u.is_some() { b'V' } else { b'N' } as c_char;
+
+    let mut a = matrix.to_owned();
+    let lda = int(a.col_stride() as usize)?;
+    let mut vr = if u.is_some() {
+        Mat::<T>::zeros(n, n)
+    } else {
+        Mat::<T>::zeros(1, 1)
+    };
+    let ldvr = int(vr.col_stride() as usize)?;
+    let mut wr = vec![T::faer_zero(); n];
+    let mut wi = vec![T::faer_zero(); n];
+
+    let mut run = |work: &mut [T]| unsafe {
+        T::geev(
+            jobvr,
+            n_,
+            a.as_ptr_mut(),
+            lda,
+            wr.as_mut_ptr(),
+            wi.as_mut_ptr(),
+            vr.as_ptr_mut(),
+            ldvr,
+            work,
+        )
+    };
+    let mut query = [T::faer_zero()];
+    if run(&mut query) != 0 {
+        return None;
+    }
+    let mut work = vec![T::faer_zero(); Ord::max(query[0].work_len(), 2)];
+    if run(&mut work) != 0 {
+        return None;
+    }
+
+    { s_re }
+        .col_mut(0)
+        .copy_from(crate::col::from_slice::<T>(&wr));
+    { s_im }
+        .col_mut(0)
+        .copy_from(crate::col::from_slice::<T>(&wi));
+    if let Some(mut u) = u {
+        u.copy_from(vr.as_ref());
+    }
+    Some(())
+}
+
+fn svd_imp<T: Scalar>(
+    matrix: MatRef<'_, T>,
+    s: MatMut<'_, T>,
+    u: Option<MatMut<'_, T>>,
+    v: Option<MatMut<'_, T>>,
+) -> Option<()> {
+    let (m, n) = (matrix.nrows(), matrix.ncols());
+    let size = Ord::min(m, n);
+    if size == 0 {
+        return None;
+    }
+
+    // gesdd computes either both or none of the singular vector matrices
+    let full = u.as_ref().map_or(false, |u| u.ncols() > size)
+        || v.as_ref().map_or(false, |v| v.ncols() > size);
+    let (jobz, u_ncols, vt_nrows) = if u.is_none() && v.is_none() {
+        (b'N', 1, 1)
+    } else if full {
+        (b'A', m, n)
+    } else {
+        (b'S', size, size)
+    };
+    let (u_nrows, vt_ncols) = if jobz == b'N' { (1, 1) } else { (m, n) };
+    let jobz = jobz as c_char;
+
+    let mut a = matrix.to_owned();
+    let lda = int(a.col_stride() as usize)?;
+    let mut s_values = vec![T::faer_zero(); size];
+    let mut u_values = Mat::<T>::zeros(u_nrows, u_ncols);
+    let mut vt_values = Mat::<T>::zeros(vt_nrows, vt_ncols);
+    let ldu = int(u_values.col_stride() as usize)?;
+    let ldvt = int(vt_values.col_stride() as usize)?;
+    let mut iwork = vec![0i32; 8 * size];
+
+    let (m_, n_) = (int(m)?, int(n)?);
+    let mut run = |work: &mut [T]| unsafe {
+        T::gesdd(
+            jobz,
+            m_,
+            n_,
+            a.as_ptr_mut(),
+            lda,
+            s_values.as_mut_ptr(),
+            u_values.as_ptr_mut(),
+            ldu,
+            vt_values.as_ptr_mut(),
+            ldvt,
+            work,
+            &mut iwork,
+        )
+    };
+    let mut query = [T::faer_zero()];
+    if run(&mut query) != 0 {
+        return None;
+    }
+    let mut work = vec![T::faer_zero(); Ord::max(query[0].work_len(), 2)];
+    if run(&mut work) != 0 {
+        return None;
+    }
+
+    { s }
+        .col_mut(0)
+        .copy_from(crate::col::from_slice::<T>(&s_values));
+    if let Some(mut u) = u {
+        let ncols = u.ncols();
+        u.copy_from(u_values.as_ref().subcols(0, ncols));
+    }
+    if let Some(mut v) = v {
+        let ncols = v.ncols();
+        v.copy_from(vt_values.as_ref().transpose().subcols(0, ncols));
+    }
+    Some(())
+}
+
+/// Computes `acc = alpha * acc + beta * lhs * rhs` with the system `?gemm`, where `alpha` is
+/// treated as zero if it is `None`.
+///
+/// Returns `false` if the product couldn't be delegated, in which case `acc` is left untouched and
+/// the caller is expected to fall back to [`crate::linalg::matmul::matmul`]. This is the case if
+/// `E` is neither `f32` nor `f64`, if one of the matrices is not stored contiguously along its rows
+/// or columns, or if the dimensions don't fit in a Fortran integer.
+///
+/// The multithreading of the product is controlled by the BLAS library, so this should not be
+/// called from inside a parallel region that already uses all the threads.
+///
+/// # Panics
+/// Panics if the matrix dimensions are not compatible for matrix multiplication.
+#[track_caller]
+pub fn matmul<E: ComplexField>(
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, E>,
+    rhs: MatRef<'_, E>,
+    alpha: Option<E>,
+    beta: E,
+) -> bool {
+    assert!(all(
+        acc.nrows() == lhs.nrows(),
+        acc.ncols() == rhs.ncols(),
+        lhs.ncols() == rhs.nrows(),
+    ));
+    unsafe {
+        if coe::is_same::<E, f64>() {
+            matmul_imp::<f64>(
+                cast_mut(acc),
+                cast_ref(lhs),
+                cast_ref(rhs),
+                alpha.map(coe::coerce_static),
+                coe::coerce_static(beta),
+            )
+            .is_some()
+        } else if coe::is_same::<E, f32>() {
+            matmul_imp::<f32>(
+                cast_mut(acc),
+                cast_ref(lhs),
+                cast_ref(rhs),
+                alpha.map(coe::coerce_static),
+                coe::coerce_static(beta),
+            )
+            .is_some()
+        } else {
+            false
+        }
+    }
+}
+
+/// Computes the Hermitian eigendecomposition of `matrix` with the system `?syevd`, reading only
+/// its lower triangular half. Returns `false` if the decomposition couldn't be delegated.
+pub(crate) fn hermitian_evd<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+) -> bool {
+    unsafe {
+        if coe::is_same::<E, f64>() {
+            hermitian_evd_imp::<f64>(cast_ref(matrix), cast_mut(s), u.map(|u| cast_mut(u)))
+                .is_some()
+        } else if coe::is_same::<E, f32>() {
+            hermitian_evd_imp::<f32>(cast_ref(matrix), cast_mut(s), u.map(|u| cast_mut(u)))
+                .is_some()
+        } else {
+            false
+        }
+    }
+}
+
+/// Computes the eigendecomposition of the real `matrix` with the system `?geev`. Returns `false`
+/// if the decomposition couldn't be delegated.
+pub(crate) fn real_evd<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    s_re: MatMut<'_, E>,
+    s_im: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+) -> bool {
+    unsafe {
+        if coe::is_same::<E, f64>() {
+            real_evd_imp::<f64>(
+                cast_ref(matrix),
+                cast_mut(s_re),
+                cast_mut(s_im),
+                u.map(|u| cast_mut(u)),
+            )
+            .is_some()
+        } else if coe::is_same::<E, f32>() {
+            real_evd_imp::<f32>(
+                cast_ref(matrix),
+                cast_mut(s_re),
+                cast_mut(s_im),
+                u.map(|u| cast_mut(u)),
+            )
+            .is_some()
+        } else {
+            false
+        }
+    }
+}
+
+/// Computes the singular value decomposition of `matrix` with the system `?gesdd`. Returns
+/// `false` if the decomposition couldn't be delegated.
+pub(crate) fn svd<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    v: Option<MatMut<'_, E>>,
+) -> bool {
+    unsafe {
+        if coe::is_same::<E, f64>() {
+            svd_imp::<f64>(
+                cast_ref(matrix),
+                cast_mut(s),
+                u.map(|u| cast_mut(u)),
+                v.map(|v| cast_mut(v)),
+            )
+            .is_some()
+        } else if coe::is_same::<E, f32>() {
+            svd_imp::<f32>(
+                cast_ref(matrix),
+                cast_mut(s),
+                u.map(|u| cast_mut(u)),
+                v.map(|v| cast_mut(v)),
+            )
+            .is_some()
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, linalg::matmul::matmul as faer_matmul, Parallelism};
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_operand() {
+        let a = Mat::<f64>::zeros(3, 4);
+        assert!(operand(a.as_ref()) == Some((b'N' as c_char, a.col_stride() as i32)));
+        assert!(operand(a.as_ref().transpose()).map(|(t, _)| t) == Some(b'T' as c_char));
+        assert!(operand(a.as_ref().subrows(0, 2)).is_some());
+        assert!(operand(a.as_ref().reverse_rows()).is_none());
+    }
+
+    #[test]
+    fn test_matmul() {
+        let lhs = Mat::<f64>::from_fn(5, 3, |i, j| (i + 2 * j) as f64);
+        let rhs = Mat::<f64>::from_fn(3, 4, |i, j| (i as f64) - (j as f64));
+        let target = Mat::<f64>::from_fn(5, 4, |i, j| {
+            (0..3).map(|k| lhs.read(i, k) * rhs.read(k, j)).sum()
+        });
+
+        let mut acc = Mat::<f64>::zeros(4, 5);
+        assert!(matmul(
+            acc.as_mut().transpose_mut(),
+            lhs.as_ref(),
+            rhs.as_ref(),
+            None,
+            1.0
+        ));
+        assert!(acc.transpose() == target);
+
+        // matches the native implementation
+        let mut acc = Mat::<f64>::zeros(5, 4);
+        faer_matmul(
+            acc.as_mut(),
+            lhs.as_ref(),
+            rhs.as_ref(),
+            None,
+            1.0,
+            Parallelism::None,
+        );
+        assert!(acc == target);
+    }
+
+    #[test]
+    fn test_svd() {
+        let a = Mat::<f64>::from_fn(6, 4, |i, j| 1.0 / (i + j + 1) as f64);
+        let mut s = Mat::<f64>::zeros(4, 1);
+        let mut u = Mat::<f64>::zeros(6, 4);
+        let mut v = Mat::<f64>::zeros(4, 4);
+        assert!(svd(
+            a.as_ref(),
+            s.as_mut(),
+            Some(u.as_mut()),
+            Some(v.as_mut())
+        ));
+
+        for j in 0..4 {
+            for i in 0..6 {
+                let value = (0..4)
+                    .map(|k| u.read(i, k) * s.read(k, 0) * v.read(j, k))
+                    .sum();
+                assert_approx_eq!(value, a.read(i, j));
+            }
+        }
+        assert!(s.read(0, 0) >= s.read(3, 0));
+    }
+
+    #[test]
+    fn test_hermitian_evd() {
+        let a = Mat::<f64>::from_fn(5, 5, |i, j| 1.0 / (i + j + 1) as f64);
+        let mut s = Mat::<f64>::zeros(5, 1);
+        let mut u = Mat::<f64>::zeros(5, 5);
+        assert!(hermitian_evd(a.as_ref(), s.as_mut(), Some(u.as_mut())));
+
+        for j in 0..5 {
+            for i in 0..5 {
+                let value = (0..5)
+                    .map(|k| u.read(i, k) * s.read(k, 0) * u.read(j, k))
+                    .sum();
+                assert_approx_eq!(value, a.read(i, j));
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    assert,
+    linalg::solvers::{numerical_rank, SpSolverLstsq},
+    mat::*,
+    *,
+};
+
+/// Solution of a least squares problem, as returned by [`lstsq`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct LstsqResult<E: ComplexField> {
+    /// Minimum norm solution `X` of the least squares problem.
+    pub solution: Mat<E>,
+    /// Norm of each column of the residual `A * X - B`.
+    pub residual_norms: alloc::vec::Vec<E::Real>,
+    /// Effective rank of `A`, i.e., its numerical rank with a relative tolerance of
+    /// `max(nrows, ncols) * ε`.
+    pub rank: usize,
+}
+
+/// Solves the least squares problem `min ‖A * X - B‖`, and returns the solution along with the
+/// residual norms and the effective rank of `A`.
+///
+/// The method is selected depending on the conditioning of `A`. It is first factorized with
+/// a QR decomposition with column pivoting, which reveals its numerical rank. If `A` has full
+/// column rank, the solution is computed from that factorization. Otherwise, the problem is
+/// rank deficient, and the minimum norm solution is computed from the singular value
+/// decomposition of `A`, with the singular values that are negligible compared to the largest one
+/// treated as zero, similarly to `numpy.linalg.lstsq`.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::lstsq, mat};
+///
+/// // fit a line through three points
+/// let a = mat![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0f64]];
+/// let b = mat![[1.0], [2.0], [4.0f64]];
+///
+/// let result = lstsq(a.as_ref(), b.as_ref());
+/// assert!(result.rank == 2);
+/// assert!((result.solution.read(0, 0) - 5.0 / 6.0).abs() < 1e-12);
+/// assert!((result.solution.read(1, 0) - 1.5).abs() < 1e-12);
+/// assert!((result.residual_norms[0] - (1.0f64 / 6.0).sqrt()).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+/// Panics if `A` and `B` don't have the same number of rows.
+#[track_caller]
+pub fn lstsq<E: ComplexField>(
+    A: MatRef<'_, impl Conjugate<Canonical = E>>,
+    B: MatRef<'_, impl Conjugate<Canonical = E>>,
+) -> LstsqResult<E> {
+    assert!(A.nrows() == B.nrows());
+    let m = A.nrows();
+    let n = A.ncols();
+    let tol = E::Real::faer_epsilon().faer_mul(E::Real::faer_from_f64(Ord::max(m, n) as f64));
+
+    let qr = A.col_piv_qr();
+    let size = Ord::min(m, n);
+    let diag: alloc::vec::Vec<_> = (0..size)
+        .map(|i| qr.factors.read(i, i).faer_abs())
+        .collect();
+    let qr_rank = numerical_rank(&diag, tol);
+
+    let (solution, rank) = if qr_rank == n {
+        (qr.solve_lstsq(B), qr_rank)
+    } else {
+        let svd = A.thin_svd();
+        let s = svd.s_diagonal();
+        let values: alloc::vec::Vec<_> = (0..s.nrows()).map(|i| s.read(i).faer_real()).collect();
+        let mut solution = Mat::<E>::zeros(n, B.ncols());
+        crate::linalg::svd::pseudo_inverse::apply_pseudoinverse(
+            s,
+            svd.u(),
+            svd.v(),
+            tol,
+            solution.as_mut(),
+            B,
+            get_global_parallelism(),
+        );
+        (solution, numerical_rank(&values, tol))
+    };
+
+    let residual = A * &solution - B;
+    let residual_norms = residual.col_iter().map(|col| col.norm_l2()).collect();
+
+    LstsqResult {
+        solution,
+        residual_norms,
+        rank,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_native::c64;
+
+    #[track_caller]
+    fn assert_close(a: MatRef<'_, c64>, b: MatRef<'_, c64>) {
+        assert!(all(a.nrows() == b.nrows(), a.ncols() == b.ncols()));
+        assert!((a - b).norm_max() < 1e-8);
+    }
+
+    #[test]
+    fn test_lstsq_full_rank() {
+        let m = 20;
+        let n = 8;
+        let A = Mat::from_fn(m, n, |_, _| c64::new(rand::random(), rand::random()));
+        let B = Mat::from_fn(m, 3, |_, _| c64::new(rand::random(), rand::random()));
+
+        let result = lstsq(A.as_ref(), B.as_ref());
+        assert!(result.rank == n);
+
+        // the residual is orthogonal to the range of `A`
+        let residual = &A * &result.solution - &B;
+        assert!((A.adjoint() * &residual).norm_max() < 1e-8);
+        for j in 0..B.ncols() {
+            assert!((result.residual_norms[j] - residual.col(j).norm_l2()).abs() < 1e-12);
+        }
+
+        assert_close(
+            result.solution.as_ref(),
+            A.col_piv_qr().solve_lstsq(&B).as_ref(),
+        );
+        assert_close(A.lstsq(&B).as_ref(), result.solution.as_ref());
+    }
+
+    #[test]
+    fn test_lstsq_rank_deficient() {
+        let m = 12;
+        let n = 9;
+        let r = 4;
+        let U = Mat::from_fn(m, r, |_, _| c64::new(rand::random(), rand::random()));
+        let V = Mat::from_fn(r, n, |_, _| c64::new(rand::random(), rand::random()));
+        let A = &U * &V;
+        let B = Mat::from_fn(m, 2, |_, _| c64::new(rand::random(), rand::random()));
+
+        let result = lstsq(A.as_ref(), B.as_ref());
+        assert!(result.rank == r);
+        assert_close(result.solution.as_ref(), (A.pinv(1e-10) * &B).as_ref());
+
+        // underdetermined system with full row rank, which has an exact solution
+        let A = Mat::from_fn(4, 7, |_, _| c64::new(rand::random(), rand::random()));
+        let b = Col::from_fn(4, |_| c64::new(rand::random(), rand::random()));
+        let result = lstsq(A.as_ref(), b.as_2d());
+        assert!(result.rank == 4);
+        assert!(result.residual_norms[0] < 1e-8);
+        let x = A.lstsq(&b);
+        assert!(x.nrows() == 7);
+        assert_close(x.as_2d(), result.solution.as_ref());
+    }
+}
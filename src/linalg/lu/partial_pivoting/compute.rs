@@ -1,10 +1,12 @@
 use crate::{
     assert, debug_assert,
-    linalg::{matmul::matmul, triangular_solve::solve_unit_lower_triangular_in_place},
+    linalg::{
+        matmul::matmul, triangular_solve::solve_unit_lower_triangular_in_place, PanelScheduler,
+    },
     perm::PermRef,
     unzipped,
     utils::{simd::*, slice::*},
-    zipped, Index, MatMut, Parallelism, SignedIndex,
+    zipped, Index, MatMut, MatRef, Parallelism, SignedIndex,
 };
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use faer_entity::*;
@@ -330,10 +332,164 @@ pub fn lu_in_place_impl<I: Index, E: ComplexField>(
     n_transpositions
 }
 
-/// LUfactorization tuning parameters.
+// applies the transpositions to the rows of each column of `matrix`
+fn swap_rows<I: Index, E: ComplexField>(mut matrix: MatMut<'_, E>, transpositions: &[I]) {
+    let contiguous = matrix.row_stride() == 1;
+    for j in 0..matrix.ncols() {
+        let mut col = matrix.rb_mut().col_mut(j);
+        for (i, &t) in transpositions.iter().enumerate() {
+            let i_swap = t.to_signed().zx() + i;
+            if contiguous {
+                swap_two_elems_contiguous(col.rb_mut().as_2d_mut(), i, i_swap);
+            } else {
+                swap_two_elems(col.rb_mut().as_2d_mut(), i, i_swap);
+            }
+        }
+    }
+}
+
+// applies the transpositions of panel `[j, j + nb)` to the columns `next` of the matrix (rows
+// `j..`), then updates them with the panel factors `[l11; l21]`
+fn update_from_panel<I: Index, E: ComplexField>(
+    mut next: MatMut<'_, E>,
+    l11: MatRef<'_, E>,
+    l21: MatRef<'_, E>,
+    transpositions: &[I],
+    parallelism: Parallelism,
+) {
+    swap_rows(next.rb_mut(), transpositions);
+    let (mut a12, mut a22) = next.split_at_row_mut(l11.nrows());
+    solve_unit_lower_triangular_in_place(l11, a12.rb_mut(), parallelism);
+    matmul(
+        a22.rb_mut(),
+        l21,
+        a12.rb(),
+        Some(E::faer_one()),
+        E::faer_one().faer_neg(),
+        parallelism,
+    );
+}
+
+// factors the first `transpositions.len()` columns of `matrix` panel by panel, with a lookahead of
+// one panel
+fn lu_in_place_lookahead_impl<I: Index, E: ComplexField>(
+    mut matrix: MatMut<'_, E>,
+    transpositions: &mut [I],
+    parallelism: Parallelism,
+    block_size: usize,
+) -> usize {
+    let m = matrix.nrows();
+    let full_n = matrix.ncols();
+    let size = transpositions.len();
+    debug_assert!(size <= Ord::min(m, full_n));
+
+    if size == 0 {
+        return 0;
+    }
+
+    let nb = Ord::min(block_size, size);
+    let mut n_transpositions = lu_in_place_impl(
+        matrix.rb_mut().submatrix_mut(0, 0, m, nb),
+        0,
+        nb,
+        &mut transpositions[..nb],
+        parallelism,
+    );
+
+    // the row swaps of each panel are only applied to the columns on its right, so that the
+    // panels on the left are not written to while they're being read by the concurrent tasks
+    let mut j = 0;
+    while j + nb < size {
+        let j_next = j + nb;
+        let nb_next = Ord::min(block_size, size - j_next);
+        let j_trailing = j_next + nb_next;
+
+        //
+        // A =  L11  A12  A13
+        //      L21  A22  A23
+        //
+        // the panel [L11; L21] is already factored. the next panel [A12; A22] is updated and
+        // factored while [A13; A23] is updated concurrently, since both updates only read from
+        // the current panel
+        //
+        let (panel, right) = matrix
+            .rb_mut()
+            .submatrix_mut(j, j, m - j, size - j)
+            .split_at_col_mut(nb);
+        let (next_panel, trailing) = right.split_at_col_mut(nb_next);
+        let panel = panel.into_const();
+        let (l11, l21) = panel.split_at_row(nb);
+
+        let (done, rest) = transpositions.split_at_mut(j_next);
+        let current = &done[j..];
+        let next = &mut rest[..nb_next];
+
+        // give most of the threads to the trailing update, which does most of the work
+        let rows = (m - j_next) as f64;
+        let panel_work = rows * nb_next as f64 * (nb + nb_next) as f64;
+        let trailing_work = rows * (size - j_trailing) as f64 * nb as f64;
+
+        let mut panel_transpositions = 0usize;
+        {
+            let panel_transpositions = &mut panel_transpositions;
+            crate::utils::thread::join_raw_weighted(
+                move |parallelism| {
+                    let mut next_panel = next_panel;
+                    update_from_panel(next_panel.rb_mut(), l11, l21, current, parallelism);
+                    *panel_transpositions = lu_in_place_impl(
+                        next_panel.subrows_mut(nb, m - j_next),
+                        0,
+                        nb_next,
+                        next,
+                        parallelism,
+                    );
+                },
+                |parallelism| update_from_panel(trailing, l11, l21, current, parallelism),
+                panel_work,
+                trailing_work,
+                parallelism,
+            );
+        }
+        n_transpositions += panel_transpositions;
+
+        j = j_next;
+    }
+
+    // apply the remaining row swaps to the columns on the left of each panel, as well as to the
+    // columns past `size`
+    let parallelism = if m * full_n > 128 * 128 {
+        parallelism
+    } else {
+        Parallelism::None
+    };
+    let transpositions = &*transpositions;
+    crate::utils::thread::for_each_raw(
+        full_n,
+        |col| {
+            let start = if col < size {
+                Ord::min((col / block_size + 1) * block_size, size)
+            } else {
+                0
+            };
+            let col = unsafe { matrix.rb().col(col).const_cast() };
+            swap_rows(
+                col.subrows_mut(start, m - start).as_2d_mut(),
+                &transpositions[start..],
+            );
+        },
+        parallelism,
+    );
+
+    n_transpositions
+}
+
+/// LU factorization tuning parameters.
 #[derive(Default, Copy, Clone)]
 #[non_exhaustive]
-pub struct PartialPivLuComputeParams {}
+pub struct PartialPivLuComputeParams {
+    /// Scheduling strategy for the panel factorizations and trailing updates.
+    pub scheduler: PanelScheduler,
+}
 
 /// Information about the resulting LU factorization.
 #[derive(Copy, Clone, Debug)]
@@ -392,12 +548,23 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
     stack: PodStack<'_>,
     params: PartialPivLuComputeParams,
 ) -> (PartialPivLuInfo, PermRef<'out, I>) {
-    let _ = &params;
     let truncate = <I::Signed as SignedIndex>::truncate;
 
     assert!(perm.len() == matrix.nrows());
     assert!(perm_inv.len() == matrix.nrows());
 
+    #[cfg(feature = "instrument")]
+    let _scope = {
+        use crate::utils::instrument::*;
+        let (m, n) = (matrix.nrows() as f64, matrix.ncols() as f64);
+        let (big, small) = if m >= n { (m, n) } else { (n, m) };
+        Scope::new(
+            "lu",
+            flops::<E>(big * small * small - small * small * small / 3.0),
+            bytes::<E>(2 * matrix.nrows() * matrix.ncols()),
+        )
+    };
+
     #[cfg(feature = "perf-warn")]
     if (matrix.col_stride().unsigned_abs() == 1 || matrix.row_stride().unsigned_abs() != 1)
         && crate::__perf_warn!(LU_WARN)
@@ -418,7 +585,17 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
     let (transpositions, _) = stack
         .rb_mut()
         .make_with(size, |_| I::from_signed(truncate(0)));
-    let n_transpositions = lu_in_place_impl(matrix.rb_mut(), 0, size, transpositions, parallelism);
+    let n_transpositions = match params.scheduler {
+        PanelScheduler::Lookahead { block_size } => lu_in_place_lookahead_impl(
+            matrix.rb_mut(),
+            transpositions,
+            parallelism,
+            crate::linalg::lookahead_block_size(size, block_size),
+        ),
+        PanelScheduler::ForkJoin => {
+            lu_in_place_impl(matrix.rb_mut(), 0, size, transpositions, parallelism)
+        }
+    };
 
     for (idx, t) in transpositions.iter().enumerate() {
         perm.swap(idx, idx + t.to_signed().zx());
@@ -525,6 +702,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compute_lu_lookahead() {
+        for (m, n, block_size) in [
+            (0, 0, 0),
+            (1, 1, 0),
+            (20, 20, 4),
+            (40, 20, 8),
+            (20, 40, 8),
+            (63, 63, 16),
+            (100, 37, 16),
+            (37, 100, 16),
+            (200, 200, 0),
+            (300, 150, 64),
+        ] {
+            for row_major in [false, true] {
+                for parallelism in [Parallelism::None, Parallelism::Rayon(8)] {
+                    let mut storage = if row_major {
+                        Mat::from_fn(n, m, |_, _| random::<f64>())
+                    } else {
+                        Mat::from_fn(m, n, |_, _| random::<f64>())
+                    };
+                    let mut mat = if row_major {
+                        storage.as_mut().transpose_mut()
+                    } else {
+                        storage.as_mut()
+                    };
+                    let mat_orig = mat.to_owned();
+                    let mut perm = vec![0usize; m];
+                    let mut perm_inv = vec![0; m];
+
+                    let mut params = PartialPivLuComputeParams::default();
+                    params.scheduler = PanelScheduler::Lookahead { block_size };
+
+                    let (_, row_perm) = lu_in_place(
+                        mat.rb_mut(),
+                        &mut perm,
+                        &mut perm_inv,
+                        parallelism,
+                        make_stack!(lu_in_place_req::<usize, f64>(m, n, parallelism, params)),
+                        params,
+                    );
+                    let reconstructed = reconstruct_matrix(mat.rb(), row_perm.rb());
+
+                    for i in 0..m {
+                        for j in 0..n {
+                            assert_approx_eq!(mat_orig.read(i, j), reconstructed.read(i, j));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn compute_lu_non_contiguous() {
         for (m, n) in [
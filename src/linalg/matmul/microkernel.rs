@@ -0,0 +1,406 @@
+//! User-provided matrix multiplication microkernels.
+//!
+//! By default, [`matmul`](super::matmul) dispatches to the optimized kernels of the `gemm` crate
+//! for the natively supported scalar types, and to a generic SIMD implementation for the other
+//! types. A [`MicroKernel`] registered with [`register`] for a scalar type `E` takes precedence
+//! over both, for all the products that are not handled by the matrix-vector and outer product
+//! special cases. This allows plugging in kernels for exotic scalar types, or hand-tuned
+//! implementations targeting instruction sets that aren't supported yet.
+//!
+//! The blocking and the parallelization are handled by `faer`: the destination is split into
+//! tiles of at most [`MicroKernel::mr`] rows and [`MicroKernel::nr`] columns, the inner
+//! dimension is split into chunks of at most [`MicroKernel::kc`] elements, and the kernel is
+//! called once per tile and chunk. Distinct columns of tiles may be processed concurrently from
+//! several threads.
+//!
+//! [`ReferenceMicroKernel`] is a straightforward implementation of the contract, which can serve
+//! as a starting point and as a reference for testing other kernels.
+//!
+//! # Example
+//!
+//! ```
+//! use faer::{
+//!     linalg::matmul::microkernel::{self, MicroKernel, ReferenceMicroKernel},
+//!     mat, Conj, MatMut, MatRef,
+//! };
+//!
+//! // forwards to the reference kernel, with a different tile shape
+//! struct Tall;
+//! impl MicroKernel<f32> for Tall {
+//!     fn mr(&self) -> usize {
+//!         8
+//!     }
+//!     fn nr(&self) -> usize {
+//!         2
+//!     }
+//!     fn compute(
+//!         &self,
+//!         dst: MatMut<'_, f32>,
+//!         lhs: MatRef<'_, f32>,
+//!         conj_lhs: Conj,
+//!         rhs: MatRef<'_, f32>,
+//!         conj_rhs: Conj,
+//!         beta: f32,
+//!     ) {
+//!         ReferenceMicroKernel.compute(dst, lhs, conj_lhs, rhs, conj_rhs, beta)
+//!     }
+//! }
+//!
+//! microkernel::register::<f32>(Tall);
+//! let a = mat![[1.0f32, 2.0], [3.0, 4.0]];
+//! let b = mat![[5.0f32, 6.0], [7.0, 8.0], [9.0, 10.0]];
+//! assert!(&a * b.transpose() == mat![[17.0f32, 23.0, 29.0], [39.0, 53.0, 67.0]]);
+//! microkernel::unregister::<f32>();
+//! ```
+
+use crate::{
+    assert,
+    mat::{MatMut, MatRef},
+    unzipped,
+    utils::{
+        thread::{for_each_raw, par_split_indices, parallelism_degree},
+        DivCeil,
+    },
+    zipped, ComplexField, Conj, Parallelism,
+};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    any::{Any, TypeId},
+    sync::atomic::{AtomicBool, Ordering},
+};
+use reborrow::*;
+use std::sync::RwLock;
+
+/// Matrix multiplication microkernel for the scalar type `E`.
+pub trait MicroKernel<E: ComplexField>: Send + Sync + 'static {
+    /// Maximum number of rows of the destination tiles.
+    ///
+    /// Must be nonzero.
+    fn mr(&self) -> usize;
+
+    /// Maximum number of columns of the destination tiles.
+    ///
+    /// Must be nonzero.
+    fn nr(&self) -> usize;
+
+    /// Maximum depth of the products computed in a single call.
+    ///
+    /// Must be nonzero. Defaults to `256`.
+    #[inline]
+    fn kc(&self) -> usize {
+        256
+    }
+
+    /// Computes `dst += beta * Op_lhs(lhs) * Op_rhs(rhs)`, where `Op_lhs` (resp. `Op_rhs`) is the
+    /// identity if `conj_lhs` (resp. `conj_rhs`) is `Conj::No`, and the conjugation operation
+    /// otherwise.
+    ///
+    /// The caller guarantees that:
+    /// - `dst` has at most `self.mr()` rows and at most `self.nr()` columns,
+    /// - `lhs` has at most `self.kc()` columns,
+    /// - `dst.nrows() == lhs.nrows()`, `lhs.ncols() == rhs.nrows()` and `rhs.ncols() ==
+    /// dst.ncols()`.
+    ///
+    /// The views may have arbitrary strides, and the inputs may contain infinities and NaNs, which
+    /// must be propagated as in the naive triple loop.
+    fn compute(
+        &self,
+        dst: MatMut<'_, E>,
+        lhs: MatRef<'_, E>,
+        conj_lhs: Conj,
+        rhs: MatRef<'_, E>,
+        conj_rhs: Conj,
+        beta: E,
+    );
+}
+
+/// Reference microkernel, computing each element of the destination tile with a scalar loop over
+/// the inner dimension.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ReferenceMicroKernel;
+
+impl<E: ComplexField> MicroKernel<E> for ReferenceMicroKernel {
+    #[inline]
+    fn mr(&self) -> usize {
+        4
+    }
+
+    #[inline]
+    fn nr(&self) -> usize {
+        4
+    }
+
+    fn compute(
+        &self,
+        dst: MatMut<'_, E>,
+        lhs: MatRef<'_, E>,
+        conj_lhs: Conj,
+        rhs: MatRef<'_, E>,
+        conj_rhs: Conj,
+        beta: E,
+    ) {
+        assert!(all(
+            dst.nrows() == lhs.nrows(),
+            dst.ncols() == rhs.ncols(),
+            lhs.ncols() == rhs.nrows(),
+        ));
+        let mut dst = dst;
+        for j in 0..dst.ncols() {
+            for i in 0..dst.nrows() {
+                let mut acc = E::faer_zero();
+                for depth in 0..lhs.ncols() {
+                    let l = lhs.read(i, depth);
+                    let r = rhs.read(depth, j);
+                    let l = if conj_lhs == Conj::Yes {
+                        l.faer_conj()
+                    } else {
+                        l
+                    };
+                    let r = if conj_rhs == Conj::Yes {
+                        r.faer_conj()
+                    } else {
+                        r
+                    };
+                    acc = acc.faer_add(l.faer_mul(r));
+                }
+                dst.write(i, j, dst.read(i, j).faer_add(acc.faer_mul(beta)));
+            }
+        }
+    }
+}
+
+struct Entry {
+    ty: TypeId,
+    // holds an `Arc<dyn MicroKernel<E>>` where `TypeId::of::<E>() == ty`
+    kernel: Box<dyn Any + Send + Sync>,
+}
+
+// the registry is read on every matrix multiplication, and only written when kernels are
+// (un)registered, so the readers don't need to exclude each other
+static REGISTRY: RwLock<Vec<Entry>> = RwLock::new(Vec::new());
+// avoids locking the registry in the common case where no kernel was registered
+static ANY_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// Registers `kernel` as the microkernel used by the matrix multiplication for the scalar type
+/// `E`, replacing any previously registered kernel for that type.
+///
+/// # Panics
+///
+/// Panics if any of the tile dimensions of `kernel` is zero.
+#[track_caller]
+pub fn register<E: ComplexField>(kernel: impl MicroKernel<E>) {
+    assert!(all(kernel.mr() > 0, kernel.nr() > 0, kernel.kc() > 0));
+    let kernel: Arc<dyn MicroKernel<E>> = Arc::new(kernel);
+
+    let mut registry = REGISTRY.write().unwrap();
+    let ty = TypeId::of::<E>();
+    registry.retain(|entry| entry.ty != ty);
+    registry.push(Entry {
+        ty,
+        kernel: Box::new(kernel),
+    });
+    ANY_REGISTERED.store(true, Ordering::Release);
+}
+
+/// Removes the microkernel registered for the scalar type `E`, if any, and returns `true` if one
+/// was registered.
+pub fn unregister<E: ComplexField>() -> bool {
+    let mut registry = REGISTRY.write().unwrap();
+    let ty = TypeId::of::<E>();
+    let len = registry.len();
+    registry.retain(|entry| entry.ty != ty);
+    ANY_REGISTERED.store(!registry.is_empty(), Ordering::Release);
+    registry.len() != len
+}
+
+/// Returns the microkernel registered for the scalar type `E`, if any.
+pub fn registered<E: ComplexField>() -> Option<Arc<dyn MicroKernel<E>>> {
+    if !ANY_REGISTERED.load(Ordering::Acquire) {
+        return None;
+    }
+    let registry = REGISTRY.read().unwrap();
+    let ty = TypeId::of::<E>();
+    registry
+        .iter()
+        .find(|entry| entry.ty == ty)
+        .and_then(|entry| entry.kernel.downcast_ref::<Arc<dyn MicroKernel<E>>>())
+        .cloned()
+}
+
+/// Computes `[alpha * acc] + beta * Op_lhs(lhs) * Op_rhs(rhs)` with the given microkernel, and
+/// stores the result in `acc`. See [`matmul_with_conj`](super::matmul_with_conj) for more
+/// details.
+#[track_caller]
+pub fn matmul_with_conj<E: ComplexField>(
+    kernel: &dyn MicroKernel<E>,
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, E>,
+    conj_lhs: Conj,
+    rhs: MatRef<'_, E>,
+    conj_rhs: Conj,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    assert!(all(
+        acc.nrows() == lhs.nrows(),
+        acc.ncols() == rhs.ncols(),
+        lhs.ncols() == rhs.nrows(),
+    ));
+    let (mr, nr, kc) = (kernel.mr(), kernel.nr(), kernel.kc());
+    assert!(all(mr > 0, nr > 0, kc > 0));
+
+    let mut acc = acc;
+    match alpha {
+        Some(alpha) => {
+            if alpha != E::faer_one() {
+                zipped!(acc.rb_mut())
+                    .for_each(|unzipped!(mut dst)| dst.write(dst.read().faer_mul(alpha)));
+            }
+        }
+        None => acc.fill_zero(),
+    }
+
+    let (m, n, k) = (acc.nrows(), acc.ncols(), lhs.ncols());
+    let n_col_tiles = n.msrv_div_ceil(nr);
+    let n_threads = Ord::min(parallelism_degree(parallelism), Ord::max(n_col_tiles, 1));
+    let acc = acc.into_const();
+
+    for_each_raw(
+        n_threads,
+        |tid| {
+            let (tile_start, n_tiles) = par_split_indices(n_col_tiles, tid, n_threads);
+            for col_tile in tile_start..tile_start + n_tiles {
+                let col = col_tile * nr;
+                let ncols = Ord::min(nr, n - col);
+                // SAFETY: the columns of tiles are disjoint between the threads
+                let mut acc = unsafe { acc.subcols(col, ncols).const_cast() };
+                let rhs = rhs.subcols(col, ncols);
+
+                let mut row = 0;
+                while row < m {
+                    let nrows = Ord::min(mr, m - row);
+                    let mut depth = 0;
+                    while depth < k {
+                        let ndepth = Ord::min(kc, k - depth);
+                        kernel.compute(
+                            acc.rb_mut().subrows_mut(row, nrows),
+                            lhs.submatrix(row, depth, nrows, ndepth),
+                            conj_lhs,
+                            rhs.subrows(depth, ndepth),
+                            conj_rhs,
+                            beta,
+                        );
+                        depth += ndepth;
+                    }
+                    row += nrows;
+                }
+            }
+        },
+        parallelism,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Mat};
+    use assert_approx_eq::assert_approx_eq;
+
+    struct Odd;
+    impl MicroKernel<c64> for Odd {
+        fn mr(&self) -> usize {
+            3
+        }
+        fn nr(&self) -> usize {
+            5
+        }
+        fn kc(&self) -> usize {
+            7
+        }
+        fn compute(
+            &self,
+            dst: MatMut<'_, c64>,
+            lhs: MatRef<'_, c64>,
+            conj_lhs: Conj,
+            rhs: MatRef<'_, c64>,
+            conj_rhs: Conj,
+            beta: c64,
+        ) {
+            assert!(all(dst.nrows() <= 3, dst.ncols() <= 5, lhs.ncols() <= 7));
+            ReferenceMicroKernel.compute(dst, lhs, conj_lhs, rhs, conj_rhs, beta)
+        }
+    }
+
+    #[test]
+    fn test_microkernel_matmul() {
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        for (m, n, k) in [(0, 4, 3), (4, 0, 3), (5, 6, 0), (17, 23, 31), (40, 9, 20)] {
+            let lhs = Mat::from_fn(m, k, random);
+            let rhs = Mat::from_fn(k, n, random);
+            let acc_init = Mat::from_fn(m, n, random);
+            let alpha = c64::new(0.5, -1.0);
+            let beta = c64::new(2.0, 0.25);
+
+            for conj_lhs in [Conj::No, Conj::Yes] {
+                for conj_rhs in [Conj::No, Conj::Yes] {
+                    for (alpha, parallelism) in [
+                        (None, Parallelism::None),
+                        (Some(alpha), Parallelism::None),
+                        (Some(alpha), Parallelism::Rayon(4)),
+                    ] {
+                        let mut acc = acc_init.clone();
+                        matmul_with_conj(
+                            &Odd,
+                            acc.as_mut(),
+                            lhs.as_ref(),
+                            conj_lhs,
+                            rhs.as_ref(),
+                            conj_rhs,
+                            alpha,
+                            beta,
+                            parallelism,
+                        );
+
+                        let mut target = acc_init.clone();
+                        super::super::matmul_with_conj(
+                            target.as_mut(),
+                            lhs.as_ref(),
+                            conj_lhs,
+                            rhs.as_ref(),
+                            conj_rhs,
+                            alpha,
+                            beta,
+                            Parallelism::None,
+                        );
+
+                        for j in 0..n {
+                            for i in 0..m {
+                                assert_approx_eq!(acc.read(i, j), target.read(i, j));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        type E = num_complex::Complex<f32>;
+
+        assert!(registered::<E>().is_none());
+        register::<E>(ReferenceMicroKernel);
+        assert!(registered::<E>().is_some());
+        assert!(registered::<c64>().is_none());
+
+        let lhs = Mat::<E>::from_fn(9, 7, |i, j| E::new(i as f32, j as f32));
+        let rhs = Mat::<E>::from_fn(7, 11, |i, j| E::new(j as f32, 1.0 - i as f32));
+        let prod = &lhs * &rhs;
+
+        assert!(unregister::<E>());
+        assert!(!unregister::<E>());
+        assert!(registered::<E>().is_none());
+        assert!(prod == &lhs * &rhs);
+    }
+}
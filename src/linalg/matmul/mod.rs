@@ -423,6 +423,27 @@ pub mod matvec_colmajor {
             acc.row_stride() == 1,
         ));
 
+        if coe::is_same::<E, c32>() && n >= split_c32::SPLIT_C32_MIN_NCOLS {
+            let mut acc: MatMut<'_, c32> = coe::coerce(acc);
+            let a: MatRef<'_, c32> = coe::coerce(a);
+            let b: MatRef<'_, c32> = coe::coerce(b);
+            let beta: c32 = coe::coerce_static(beta);
+            split_c32::matvec_colmajor(
+                acc.rb_mut().try_get_contiguous_col_mut(0),
+                a,
+                conj_a,
+                |j| {
+                    let b = b.read(j, 0);
+                    let b = match conj_b {
+                        Conj::Yes => b.faer_conj(),
+                        Conj::No => b,
+                    };
+                    b.faer_mul(beta)
+                },
+            );
+            return;
+        }
+
         let mut acc = SliceGroupMut::<'_, E>::new(acc.try_get_contiguous_col_mut(0));
 
         let arch = E::Simd::default();
@@ -1301,6 +1322,22 @@ pub fn matmul_with_conj_gemm_dispatch<E: ComplexField>(
         return;
     }
 
+    #[cfg(feature = "std")]
+    if let Some(kernel) = microkernel::registered::<E>() {
+        microkernel::matmul_with_conj(
+            &*kernel,
+            acc,
+            lhs,
+            conj_lhs,
+            rhs,
+            conj_rhs,
+            alpha,
+            beta,
+            parallelism,
+        );
+        return;
+    }
+
     unsafe {
         if m + n < 32 && k <= 6 {
             macro_rules! small_gemm {
@@ -1568,9 +1605,9 @@ pub fn matmul_with_conj_gemm_dispatch<E: ComplexField>(
         let gemm_parallelism = match parallelism {
             Parallelism::None => gemm::Parallelism::None,
             #[cfg(feature = "rayon")]
-            Parallelism::Rayon(0) => gemm::Parallelism::Rayon(rayon::current_num_threads()),
-            #[cfg(feature = "rayon")]
-            Parallelism::Rayon(n_threads) => gemm::Parallelism::Rayon(n_threads),
+            Parallelism::Rayon(_) => {
+                gemm::Parallelism::Rayon(crate::utils::thread::parallelism_degree(parallelism))
+            }
         };
         if coe::is_same::<f32, E>() {
             let mut acc: MatMut<'_, f32> = coe::coerce(acc);
@@ -1843,6 +1880,16 @@ pub fn matmul_with_conj<E: ComplexField>(
         acc.ncols() == rhs.ncols(),
         lhs.ncols() == rhs.nrows(),
     ));
+    #[cfg(feature = "instrument")]
+    let _scope = {
+        use crate::utils::instrument::*;
+        let (m, n, k) = (acc.nrows(), acc.ncols(), lhs.ncols());
+        Scope::new(
+            "matmul",
+            flops::<E>(2.0 * m as f64 * n as f64 * k as f64),
+            bytes::<E>(m * k + k * n + m * n * (1 + alpha.is_some() as usize)),
+        )
+    };
     matmul_with_conj_gemm_dispatch(
         acc,
         lhs,
@@ -1977,6 +2024,19 @@ macro_rules! stack_mat_16x16_begin {
 /// matrices.
 pub mod triangular;
 
+/// Matrix multiplication using the Strassen-Winograd algorithm, which can be faster than the
+/// regular algorithm for very large matrices, at the cost of a weaker error bound.
+pub mod strassen;
+
+pub(crate) mod split_c32;
+
+mod triple;
+pub use triple::{sandwich_product, sandwich_product_req, triple_product, triple_product_req};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod microkernel;
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -2387,6 +2447,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_triangular_in_place() {
+        use BlockStructure::*;
+        let structures = [
+            TriangularLower,
+            TriangularUpper,
+            StrictTriangularLower,
+            StrictTriangularUpper,
+            UnitTriangularLower,
+            UnitTriangularUpper,
+        ];
+
+        for structure in structures {
+            for n in [0, 1, 7, 16, 17, 50] {
+                let tri = generate_structured_matrix(false, n, n, structure);
+
+                for parallelism in [Parallelism::None, Parallelism::Rayon(8)] {
+                    let mut acc = generate_structured_matrix(true, n, 5, Rectangular);
+                    let mut target = Mat::<f64>::zeros(n, 5);
+                    matmul(
+                        target.as_mut(),
+                        tri.as_ref(),
+                        acc.as_ref(),
+                        None,
+                        2.5,
+                        parallelism,
+                    );
+                    triangular::triangular_x_mat_in_place(
+                        acc.as_mut(),
+                        tri.as_ref(),
+                        structure,
+                        2.5,
+                        parallelism,
+                    );
+                    for j in 0..5 {
+                        for i in 0..n {
+                            assert_approx_eq!(acc.read(i, j), target.read(i, j));
+                        }
+                    }
+
+                    let mut acc = generate_structured_matrix(true, 5, n, Rectangular);
+                    let mut target = Mat::<f64>::zeros(5, n);
+                    matmul(
+                        target.as_mut(),
+                        acc.as_ref(),
+                        tri.as_ref(),
+                        None,
+                        2.5,
+                        parallelism,
+                    );
+                    triangular::mat_x_triangular_in_place(
+                        acc.as_mut(),
+                        tri.as_ref(),
+                        structure,
+                        2.5,
+                        parallelism,
+                    );
+                    for j in 0..n {
+                        for i in 0..5 {
+                            assert_approx_eq!(acc.read(i, j), target.read(i, j));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_triangular() {
         use BlockStructure::*;
@@ -2443,4 +2570,100 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_rank_updates() {
+        use super::triangular::{
+            hermitian_rank_2k_update, hermitian_rank_k_update, symmetric_rank_2k_update,
+            symmetric_rank_k_update,
+        };
+        use crate::{complex_native::c64, Side};
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let (n, k) = (19, 7);
+        let a = Mat::from_fn(n, k, random);
+        let b = Mat::from_fn(n, k, random);
+        let acc_init = Mat::from_fn(n, n, random);
+        let alpha = c64::new(0.5, 0.0);
+        let beta = c64::new(-2.0, 1.0);
+
+        let aah = &a * a.adjoint();
+        let aat = &a * a.transpose();
+        let abh = &a * b.adjoint();
+        let bah = &b * a.adjoint();
+        let abt = &a * b.transpose();
+        let bat = &b * a.transpose();
+
+        for side in [Side::Lower, Side::Upper] {
+            let in_half = |i: usize, j: usize| match side {
+                Side::Lower => i >= j,
+                Side::Upper => i <= j,
+            };
+            let check = |acc: &Mat<c64>, target: &dyn Fn(usize, usize) -> c64| {
+                for j in 0..n {
+                    for i in 0..n {
+                        if in_half(i, j) {
+                            assert_approx_eq!(acc.read(i, j), target(i, j));
+                        } else {
+                            assert!(acc.read(i, j) == acc_init.read(i, j));
+                        }
+                    }
+                }
+            };
+
+            let mut acc = acc_init.clone();
+            hermitian_rank_k_update(
+                acc.as_mut(),
+                side,
+                a.as_ref(),
+                Some(alpha),
+                beta,
+                Parallelism::None,
+            );
+            check(&acc, &|i, j| {
+                alpha * acc_init.read(i, j) + beta * aah.read(i, j)
+            });
+
+            let mut acc = acc_init.clone();
+            symmetric_rank_k_update(
+                acc.as_mut(),
+                side,
+                a.as_ref(),
+                Some(alpha),
+                beta,
+                Parallelism::Rayon(4),
+            );
+            check(&acc, &|i, j| {
+                alpha * acc_init.read(i, j) + beta * aat.read(i, j)
+            });
+
+            let mut acc = acc_init.clone();
+            hermitian_rank_2k_update(
+                acc.as_mut(),
+                side,
+                a.as_ref(),
+                b.as_ref(),
+                Some(alpha),
+                beta,
+                Parallelism::None,
+            );
+            check(&acc, &|i, j| {
+                alpha * acc_init.read(i, j)
+                    + beta * abh.read(i, j)
+                    + beta.faer_conj() * bah.read(i, j)
+            });
+
+            let mut acc = acc_init.clone();
+            symmetric_rank_2k_update(
+                acc.as_mut(),
+                side,
+                a.as_ref(),
+                b.as_ref(),
+                None,
+                beta,
+                Parallelism::None,
+            );
+            check(&acc, &|i, j| beta * (abt.read(i, j) + bat.read(i, j)));
+        }
+    }
 }
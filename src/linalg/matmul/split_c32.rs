@@ -0,0 +1,318 @@
+//! Split-layout SIMD kernels for [`c32`].
+//!
+//! The generic kernels operate on `c32s` registers, where every complex product needs to shuffle the
+//! real and imaginary parts of one of its operands. The kernels in this module instead reinterpret
+//! the interleaved data as `f32`, and keep the contributions of the real and imaginary parts of the
+//! scalar factors separate, so that the inner loops only consist of `f32` fused multiply-adds. The
+//! shuffles are either precomputed once for a vector that is reused many times, or deferred to a
+//! single pass at the end.
+
+use crate::{assert, complex_native::c32, debug_assert, mat::MatRef, Conj};
+use core::iter::zip;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use pulp::Simd;
+
+/// Minimum number of columns for which the split-layout kernels are used. Below that, the cost of
+/// converting the layout isn't amortized.
+pub(crate) const SPLIT_C32_MIN_NCOLS: usize = 4;
+
+/// Number of rows processed at a time by [`matvec_colmajor`].
+const MATVEC_TILE: usize = 256;
+
+#[inline(always)]
+fn as_f32(x: &[c32]) -> &[f32] {
+    bytemuck::cast_slice(x)
+}
+
+#[inline(always)]
+fn as_f32_mut(x: &mut [c32]) -> &mut [f32] {
+    bytemuck::cast_slice_mut(x)
+}
+
+/// Computes `(sum(a * x), sum(b * x))`.
+struct Dot2<'a> {
+    a: &'a [f32],
+    b: &'a [f32],
+    x: &'a [f32],
+}
+
+impl pulp::WithSimd for Dot2<'_> {
+    type Output = (f32, f32);
+
+    #[inline(always)]
+    fn with_simd<S: Simd>(self, simd: S) -> Self::Output {
+        let Self { a, b, x } = self;
+        debug_assert!(all(a.len() == x.len(), b.len() == x.len()));
+
+        let (a_head, a_tail) = S::f32s_as_simd(a);
+        let (b_head, b_tail) = S::f32s_as_simd(b);
+        let (x_head, x_tail) = S::f32s_as_simd(x);
+
+        let zero = simd.f32s_splat(0.0);
+        let mut acc_a = [zero; 2];
+        let mut acc_b = [zero; 2];
+
+        let a2 = a_head.chunks_exact(2);
+        let b2 = b_head.chunks_exact(2);
+        let x2 = x_head.chunks_exact(2);
+        let (a1, b1, x1) = (a2.remainder(), b2.remainder(), x2.remainder());
+        for ((a, b), x) in zip(zip(a2, b2), x2) {
+            acc_a[0] = simd.f32s_mul_add_e(a[0], x[0], acc_a[0]);
+            acc_a[1] = simd.f32s_mul_add_e(a[1], x[1], acc_a[1]);
+            acc_b[0] = simd.f32s_mul_add_e(b[0], x[0], acc_b[0]);
+            acc_b[1] = simd.f32s_mul_add_e(b[1], x[1], acc_b[1]);
+        }
+        for ((a, b), x) in zip(zip(a1, b1), x1) {
+            acc_a[0] = simd.f32s_mul_add_e(*a, *x, acc_a[0]);
+            acc_b[0] = simd.f32s_mul_add_e(*b, *x, acc_b[0]);
+        }
+
+        let mut sum_a = simd.f32s_reduce_sum(simd.f32s_add(acc_a[0], acc_a[1]));
+        let mut sum_b = simd.f32s_reduce_sum(simd.f32s_add(acc_b[0], acc_b[1]));
+        for ((a, b), x) in zip(zip(a_tail, b_tail), x_tail) {
+            sum_a += a * x;
+            sum_b += b * x;
+        }
+        (sum_a, sum_b)
+    }
+}
+
+/// Computes `y += ka * a + kb * b`.
+struct Axpy2<'a> {
+    y: &'a mut [f32],
+    ka: f32,
+    a: &'a [f32],
+    kb: f32,
+    b: &'a [f32],
+}
+
+impl pulp::WithSimd for Axpy2<'_> {
+    type Output = ();
+
+    #[inline(always)]
+    fn with_simd<S: Simd>(self, simd: S) -> Self::Output {
+        let Self { y, ka, a, kb, b } = self;
+        debug_assert!(all(a.len() == y.len(), b.len() == y.len()));
+
+        let (y_head, y_tail) = S::f32s_as_mut_simd(y);
+        let (a_head, a_tail) = S::f32s_as_simd(a);
+        let (b_head, b_tail) = S::f32s_as_simd(b);
+
+        let ka_ = simd.f32s_splat(ka);
+        let kb_ = simd.f32s_splat(kb);
+        for ((y, a), b) in zip(zip(y_head, a_head), b_head) {
+            *y = simd.f32s_mul_add_e(kb_, *b, simd.f32s_mul_add_e(ka_, *a, *y));
+        }
+        for ((y, a), b) in zip(zip(y_tail, a_tail), b_tail) {
+            *y += ka * a + kb * b;
+        }
+    }
+}
+
+/// Computes `r += kr * a` and `s += ks * a`.
+struct DualAxpy<'a> {
+    r: &'a mut [f32],
+    kr: f32,
+    s: &'a mut [f32],
+    ks: f32,
+    a: &'a [f32],
+}
+
+impl pulp::WithSimd for DualAxpy<'_> {
+    type Output = ();
+
+    #[inline(always)]
+    fn with_simd<S: Simd>(self, simd: S) -> Self::Output {
+        let Self { r, kr, s, ks, a } = self;
+        debug_assert!(all(r.len() == a.len(), s.len() == a.len()));
+
+        let (r_head, r_tail) = S::f32s_as_mut_simd(r);
+        let (s_head, s_tail) = S::f32s_as_mut_simd(s);
+        let (a_head, a_tail) = S::f32s_as_simd(a);
+
+        let kr_ = simd.f32s_splat(kr);
+        let ks_ = simd.f32s_splat(ks);
+        for ((r, s), a) in zip(zip(r_head, s_head), a_head) {
+            *r = simd.f32s_mul_add_e(kr_, *a, *r);
+            *s = simd.f32s_mul_add_e(ks_, *a, *s);
+        }
+        for ((r, s), a) in zip(zip(r_tail, s_tail), a_tail) {
+            *r += kr * a;
+            *s += ks * a;
+        }
+    }
+}
+
+/// Complex vector stored alongside its product with the imaginary unit, in interleaved layout.
+///
+/// This allows computing dot products with, and multiples of, the vector using only `f32`
+/// arithmetic, which pays off when the vector is reused many times, e.g., when applying a
+/// Householder reflection to all the columns of a matrix.
+pub(crate) struct SplitC32<'a> {
+    w: &'a [f32],
+    iw: &'a [f32],
+}
+
+/// Computes the size and alignment of the workspace required by [`SplitC32::new`] for a vector
+/// of length `n`.
+pub(crate) fn split_c32_req(n: usize) -> Result<StackReq, SizeOverflow> {
+    let w = StackReq::try_new::<f32>(n.checked_mul(2).ok_or(SizeOverflow)?)?;
+    StackReq::try_all_of([w, w])
+}
+
+impl<'a> SplitC32<'a> {
+    /// Stores `w = Op(v)` in `stack`, where `Op` is the identity if `conj` is `Conj::No`, and the
+    /// conjugation otherwise.
+    #[track_caller]
+    pub fn new(v: &[c32], conj: Conj, stack: PodStack<'a>) -> Self {
+        let (w, stack) = stack.make_raw::<f32>(2 * v.len());
+        let (iw, _) = stack.make_raw::<f32>(2 * v.len());
+        for ((z, w), iw) in zip(zip(v, w.chunks_exact_mut(2)), iw.chunks_exact_mut(2)) {
+            let im = match conj {
+                Conj::No => z.im,
+                Conj::Yes => -z.im,
+            };
+            w.copy_from_slice(&[z.re, im]);
+            iw.copy_from_slice(&[-im, z.re]);
+        }
+        Self { w, iw }
+    }
+
+    /// Returns `conj(w)ᵀ x`.
+    #[track_caller]
+    pub fn conj_dot(&self, x: &[c32]) -> c32 {
+        let x = as_f32(x);
+        assert!(x.len() == self.w.len());
+        let (re, im) = pulp::Arch::new().dispatch(Dot2 {
+            a: self.w,
+            b: self.iw,
+            x,
+        });
+        c32 { re, im }
+    }
+
+    /// Computes `x += k * w`.
+    #[track_caller]
+    pub fn axpy(&self, x: &mut [c32], k: c32) {
+        let y = as_f32_mut(x);
+        assert!(y.len() == self.w.len());
+        pulp::Arch::new().dispatch(Axpy2 {
+            y,
+            ka: k.re,
+            a: self.w,
+            kb: k.im,
+            b: self.iw,
+        });
+    }
+}
+
+/// Computes `acc += Op_a(a) * b`, where `a` is column major and `b(j)` returns the `j`-th element of
+/// the right-hand side, and `Op_a` is the identity if `conj_a` is `Conj::No`, and the conjugation
+/// otherwise.
+///
+/// The products of the columns of `a` with the real and imaginary parts of the right-hand side are
+/// accumulated separately, in stack buffers covering [`MATVEC_TILE`] rows at a time, and combined
+/// in a single pass at the end of each tile.
+#[track_caller]
+pub(crate) fn matvec_colmajor(
+    acc: &mut [c32],
+    a: MatRef<'_, c32>,
+    conj_a: Conj,
+    b: impl Fn(usize) -> c32,
+) {
+    let m = a.nrows();
+    assert!(all(acc.len() == m, a.row_stride() == 1));
+
+    let arch = pulp::Arch::new();
+    for (tile, acc) in acc.chunks_mut(MATVEC_TILE).enumerate() {
+        let rows = 2 * tile * MATVEC_TILE..2 * (tile * MATVEC_TILE + acc.len());
+        let mut r = [0.0f32; 2 * MATVEC_TILE];
+        let mut s = [0.0f32; 2 * MATVEC_TILE];
+        let r = &mut r[..rows.len()];
+        let s = &mut s[..rows.len()];
+
+        for j in 0..a.ncols() {
+            let k = b(j);
+            arch.dispatch(DualAxpy {
+                r: &mut *r,
+                kr: k.re,
+                s: &mut *s,
+                ks: k.im,
+                a: &as_f32(a.try_get_contiguous_col(j))[rows.clone()],
+            });
+        }
+
+        for (acc, (r, s)) in zip(acc, zip(r.chunks_exact(2), s.chunks_exact(2))) {
+            let (re, im) = match conj_a {
+                Conj::No => (r[0] - s[1], r[1] + s[0]),
+                Conj::Yes => (r[0] + s[1], s[0] - r[1]),
+            };
+            acc.re += re;
+            acc.im += im;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, ComplexField, Mat};
+
+    #[test]
+    fn test_split_c32() {
+        let random = |_, _| c32::new(rand::random(), rand::random());
+        for m in [0, 1, 3, 7, 16, 37, 100] {
+            let v = Mat::<c32>::from_fn(m, 1, random);
+            let x = Mat::<c32>::from_fn(m, 1, random);
+            let k = c32::new(0.5, -1.25);
+            let v = v.col_as_slice(0);
+
+            for conj in [Conj::No, Conj::Yes] {
+                let op = |z: c32| match conj {
+                    Conj::No => z,
+                    Conj::Yes => z.faer_conj(),
+                };
+                let mut mem = dyn_stack::GlobalPodBuffer::new(split_c32_req(m).unwrap());
+                let split = SplitC32::new(v, conj, PodStack::new(&mut mem));
+
+                let dot = split.conj_dot(x.col_as_slice(0));
+                let mut target = c32::faer_zero();
+                for i in 0..m {
+                    target += op(v[i]).faer_conj() * x.read(i, 0);
+                }
+                assert!((dot - target).faer_abs() < 1e-4 * (m as f32 + 1.0));
+
+                let mut y = x.clone();
+                split.axpy(y.col_as_slice_mut(0), k);
+                for i in 0..m {
+                    let target = x.read(i, 0) + k * op(v[i]);
+                    assert!((y.read(i, 0) - target).faer_abs() < 1e-5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_c32_matvec() {
+        let random = |_, _| c32::new(rand::random(), rand::random());
+        for (m, n) in [(0, 4), (1, 4), (13, 5), (64, 8), (101, 17), (600, 6)] {
+            let a = Mat::<c32>::from_fn(m, n, random);
+            let b = Mat::<c32>::from_fn(n, 1, random);
+            let acc0 = Mat::<c32>::from_fn(m, 1, random);
+
+            for conj in [Conj::No, Conj::Yes] {
+                let mut acc = acc0.clone();
+                matvec_colmajor(acc.col_as_slice_mut(0), a.as_ref(), conj, |j| b.read(j, 0));
+
+                let op_a = match conj {
+                    Conj::No => a.clone(),
+                    Conj::Yes => a.conjugate().to_owned(),
+                };
+                let target = &acc0 + &op_a * &b;
+                for i in 0..m {
+                    assert!((acc.read(i, 0) - target.read(i, 0)).faer_abs() < 1e-4 * n as f32);
+                }
+            }
+        }
+    }
+}
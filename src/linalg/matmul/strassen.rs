@@ -0,0 +1,470 @@
+use super::*;
+use crate::linalg::{temp_mat_req, temp_mat_uninit};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// Parameters of the Strassen-Winograd matrix multiplication.
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct StrassenParams {
+    /// Smallest dimension above which a level of recursion is applied. Products whose dimensions
+    /// are all smaller than twice this value use a single level of recursion, and products where
+    /// any dimension is smaller than this value are computed with [`matmul`](super::matmul).
+    ///
+    /// Setting this to `usize::MAX` disables the recursion.
+    pub threshold: usize,
+}
+
+impl Default for StrassenParams {
+    #[inline]
+    fn default() -> Self {
+        Self { threshold: 4096 }
+    }
+}
+
+#[inline]
+fn recurses(m: usize, n: usize, k: usize, params: StrassenParams) -> bool {
+    // the threshold is at least 2 so that the halves are never empty
+    Ord::min(Ord::min(m, n), k) >= Ord::max(params.threshold, 2)
+}
+
+/// Computes the size and alignment of required workspace for performing a matrix multiplication
+/// with [`matmul_with_conj`], where `acc` is an `m×n` matrix and the inner dimension is `k`.
+pub fn matmul_req<E: Entity>(
+    m: usize,
+    n: usize,
+    k: usize,
+    params: StrassenParams,
+) -> Result<StackReq, SizeOverflow> {
+    if !recurses(m, n, k, params) {
+        return Ok(StackReq::empty());
+    }
+    let (m, n, k) = (m / 2, n / 2, k / 2);
+    StackReq::try_all_of([
+        temp_mat_req::<E>(m, k)?,
+        temp_mat_req::<E>(k, n)?,
+        temp_mat_req::<E>(m, n)?,
+        matmul_req::<E>(m, n, k, params)?,
+    ])
+}
+
+fn add_to<E: ComplexField>(dst: MatMut<'_, E>, src: MatRef<'_, E>) {
+    zipped!(dst, src)
+        .for_each(|unzipped!(mut dst, src)| dst.write(dst.read().faer_add(src.read())));
+}
+
+// dst = lhs + rhs
+fn add<E: ComplexField>(dst: MatMut<'_, E>, lhs: MatRef<'_, E>, rhs: MatRef<'_, E>) {
+    zipped!(dst, lhs, rhs)
+        .for_each(|unzipped!(mut dst, lhs, rhs)| dst.write(lhs.read().faer_add(rhs.read())));
+}
+
+// dst = lhs - rhs
+fn sub<E: ComplexField>(dst: MatMut<'_, E>, lhs: MatRef<'_, E>, rhs: MatRef<'_, E>) {
+    zipped!(dst, lhs, rhs)
+        .for_each(|unzipped!(mut dst, lhs, rhs)| dst.write(lhs.read().faer_sub(rhs.read())));
+}
+
+// even dimensions, at least one level of recursion
+#[allow(clippy::too_many_arguments)]
+fn strassen<E: ComplexField>(
+    mut acc: MatMut<'_, E>,
+    lhs: MatRef<'_, E>,
+    conj_lhs: Conj,
+    rhs: MatRef<'_, E>,
+    conj_rhs: Conj,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+    params: StrassenParams,
+    stack: PodStack<'_>,
+) {
+    let (m, n, k) = (acc.nrows() / 2, acc.ncols() / 2, lhs.ncols() / 2);
+
+    match alpha {
+        None => acc.fill_zero(),
+        Some(alpha) if alpha != E::faer_one() => {
+            zipped!(acc.rb_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_mul(alpha)))
+        }
+        Some(_) => {}
+    }
+    let one = Some(E::faer_one());
+
+    let (mut c11, mut c12, mut c21, mut c22) = acc.split_at_mut(m, n);
+    let (a11, a12, a21, a22) = lhs.split_at(m, k);
+    let (b11, b12, b21, b22) = rhs.split_at(k, n);
+
+    let (mut s, stack) = temp_mat_uninit::<E>(m, k, stack);
+    let (mut t, stack) = temp_mat_uninit::<E>(k, n, stack);
+    let (mut p, mut stack) = temp_mat_uninit::<E>(m, n, stack);
+
+    // winograd's variant, with the products accumulated into the blocks of `acc`:
+    //
+    // s1 = a21 + a22, s2 = s1 - a11, s3 = a11 - a21, s4 = a12 - s2
+    // t1 = b12 - b11, t2 = b22 - t1, t3 = b22 - b12, t4 = t2 - b21
+    //
+    // p1 = a11 b11, p2 = a12 b21, p3 = s4 b22, p4 = a22 t4
+    // p5 = s1 t1, p6 = s2 t2, p7 = s3 t3
+    //
+    // c11 = p1 + p2
+    // c12 = p1 + p6 + p5 + p3
+    // c21 = p1 + p6 + p7 - p4
+    // c22 = p1 + p6 + p7 + p5
+    let product = |acc: MatMut<'_, E>,
+                   lhs: MatRef<'_, E>,
+                   rhs: MatRef<'_, E>,
+                   alpha: Option<E>,
+                   beta: E,
+                   stack: PodStack<'_>| {
+        matmul_with_conj_impl(
+            acc,
+            lhs,
+            conj_lhs,
+            rhs,
+            conj_rhs,
+            alpha,
+            beta,
+            parallelism,
+            params,
+            stack,
+        )
+    };
+
+    // p1
+    product(p.rb_mut(), a11, b11, None, beta, stack.rb_mut());
+    add_to(c11.rb_mut(), p.rb());
+    add_to(c12.rb_mut(), p.rb());
+    add_to(c21.rb_mut(), p.rb());
+    add_to(c22.rb_mut(), p.rb());
+
+    // p2
+    product(c11.rb_mut(), a12, b21, one, beta, stack.rb_mut());
+
+    // p5
+    add(s.rb_mut(), a21, a22);
+    sub(t.rb_mut(), b12, b11);
+    product(p.rb_mut(), s.rb(), t.rb(), None, beta, stack.rb_mut());
+    add_to(c12.rb_mut(), p.rb());
+    add_to(c22.rb_mut(), p.rb());
+
+    // p6
+    zipped!(s.rb_mut(), a11).for_each(|unzipped!(mut s, a)| s.write(s.read().faer_sub(a.read())));
+    zipped!(t.rb_mut(), b22).for_each(|unzipped!(mut t, b)| t.write(b.read().faer_sub(t.read())));
+    product(p.rb_mut(), s.rb(), t.rb(), None, beta, stack.rb_mut());
+    add_to(c12.rb_mut(), p.rb());
+    add_to(c21.rb_mut(), p.rb());
+    add_to(c22.rb_mut(), p.rb());
+
+    // p3
+    zipped!(s.rb_mut(), a12).for_each(|unzipped!(mut s, a)| s.write(a.read().faer_sub(s.read())));
+    product(c12.rb_mut(), s.rb(), b22, one, beta, stack.rb_mut());
+
+    // p4
+    zipped!(t.rb_mut(), b21).for_each(|unzipped!(mut t, b)| t.write(t.read().faer_sub(b.read())));
+    product(
+        c21.rb_mut(),
+        a22,
+        t.rb(),
+        one,
+        beta.faer_neg(),
+        stack.rb_mut(),
+    );
+
+    // p7
+    sub(s.rb_mut(), a11, a21);
+    sub(t.rb_mut(), b22, b12);
+    product(p.rb_mut(), s.rb(), t.rb(), None, beta, stack.rb_mut());
+    add_to(c21.rb_mut(), p.rb());
+    add_to(c22.rb_mut(), p.rb());
+}
+
+#[allow(clippy::too_many_arguments)]
+fn matmul_with_conj_impl<E: ComplexField>(
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, E>,
+    conj_lhs: Conj,
+    rhs: MatRef<'_, E>,
+    conj_rhs: Conj,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+    params: StrassenParams,
+    stack: PodStack<'_>,
+) {
+    let (m, n, k) = (acc.nrows(), acc.ncols(), lhs.ncols());
+    if !recurses(m, n, k, params) {
+        super::matmul_with_conj(acc, lhs, conj_lhs, rhs, conj_rhs, alpha, beta, parallelism);
+        return;
+    }
+
+    // odd dimensions are handled by peeling off the last row, column, or inner index, and
+    // computing their contribution with the regular matrix multiplication
+    let (m2, n2, k2) = (m / 2 * 2, n / 2 * 2, k / 2 * 2);
+    let (acc_top, acc_bot) = acc.split_at_row_mut(m2);
+    let (lhs_top, lhs_bot) = lhs.split_at_row(m2);
+    let (mut acc_even, acc_right) = acc_top.split_at_col_mut(n2);
+    let (rhs_even, rhs_right) = rhs.split_at_col(n2);
+
+    strassen(
+        acc_even.rb_mut(),
+        lhs_top.subcols(0, k2),
+        conj_lhs,
+        rhs_even.subrows(0, k2),
+        conj_rhs,
+        alpha,
+        beta,
+        parallelism,
+        params,
+        stack,
+    );
+    if k2 < k {
+        super::matmul_with_conj(
+            acc_even,
+            lhs_top.subcols(k2, k - k2),
+            conj_lhs,
+            rhs_even.subrows(k2, k - k2),
+            conj_rhs,
+            Some(E::faer_one()),
+            beta,
+            parallelism,
+        );
+    }
+    if n2 < n {
+        super::matmul_with_conj(
+            acc_right,
+            lhs_top,
+            conj_lhs,
+            rhs_right,
+            conj_rhs,
+            alpha,
+            beta,
+            parallelism,
+        );
+    }
+    if m2 < m {
+        super::matmul_with_conj(
+            acc_bot,
+            lhs_bot,
+            conj_lhs,
+            rhs,
+            conj_rhs,
+            alpha,
+            beta,
+            parallelism,
+        );
+    }
+}
+
+/// Computes the matrix product `[alpha * acc] + beta * Op_lhs(lhs) * Op_rhs(rhs)` and stores the
+/// result in `acc`, using the Strassen-Winograd algorithm for the large blocks.
+///
+/// The semantics are the same as [`matmul_with_conj`](super::matmul_with_conj). Each level of
+/// recursion replaces the eight half sized products of the regular algorithm by seven products and
+/// a few matrix additions, and is applied as long as all the dimensions are at least
+/// `params.threshold`. The smaller blocks are computed with the regular matrix multiplication.
+///
+/// # Accuracy
+/// Unlike the regular matrix multiplication, whose error is bounded componentwise by
+/// `|acc - acc_exact| <= k * eps * |lhs| * |rhs|`, the Strassen-Winograd algorithm only satisfies
+/// a normwise bound of the form `max|acc - acc_exact| <= c * eps * max|lhs| * max|rhs|`, where
+/// `c` grows by a constant factor with each level of recursion. The results are therefore
+/// comparable for matrices whose entries have similar magnitudes, but small entries of the
+/// product can lose most of their relative accuracy when the rows of `lhs` or the columns of `rhs`
+/// are badly scaled. Keeping the threshold large limits the depth of the recursion, and the
+/// growth of the error.
+///
+/// # Panics
+///
+/// Panics if the matrix dimensions are not compatible for matrix multiplication, i.e.
+///  - `acc.nrows() == lhs.nrows()`
+///  - `acc.ncols() == rhs.ncols()`
+///  - `lhs.ncols() == rhs.nrows()`
+///
+/// Panics if the provided memory in `stack` is insufficient (see [`matmul_req`]).
+#[track_caller]
+#[allow(clippy::too_many_arguments)]
+pub fn matmul_with_conj<E: ComplexField>(
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, E>,
+    conj_lhs: Conj,
+    rhs: MatRef<'_, E>,
+    conj_rhs: Conj,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+    params: StrassenParams,
+    stack: PodStack<'_>,
+) {
+    assert!(all(
+        acc.nrows() == lhs.nrows(),
+        acc.ncols() == rhs.ncols(),
+        lhs.ncols() == rhs.nrows(),
+    ));
+    matmul_with_conj_impl(
+        acc,
+        lhs,
+        conj_lhs,
+        rhs,
+        conj_rhs,
+        alpha,
+        beta,
+        parallelism,
+        params,
+        stack,
+    );
+}
+
+/// Computes the matrix product `[alpha * acc] + beta * lhs * rhs` (while optionally conjugating
+/// either or both of the input matrices) and stores the result in `acc`, using the
+/// Strassen-Winograd algorithm for the large blocks.
+///
+/// See [`matmul_with_conj`] for the details and the accuracy of the algorithm.
+///
+/// # Example
+///
+/// ```
+/// use dyn_stack::{GlobalPodBuffer, PodStack};
+/// use faer::{
+///     linalg::matmul::{
+///         matmul,
+///         strassen::{self, StrassenParams},
+///     },
+///     Mat, Parallelism,
+/// };
+///
+/// let n = 100;
+/// let lhs = Mat::<f64>::from_fn(n, n, |i, j| ((i + 2 * j) % 7) as f64);
+/// let rhs = Mat::<f64>::from_fn(n, n, |i, j| ((3 * i + j) % 5) as f64);
+///
+/// let mut params = StrassenParams::default();
+/// // small threshold for the example, the recursion only pays off for much larger matrices
+/// params.threshold = 32;
+///
+/// let mut acc = Mat::<f64>::zeros(n, n);
+/// strassen::matmul(
+///     acc.as_mut(),
+///     lhs.as_ref(),
+///     rhs.as_ref(),
+///     None,
+///     1.0,
+///     Parallelism::None,
+///     params,
+///     PodStack::new(&mut GlobalPodBuffer::new(
+///         strassen::matmul_req::<f64>(n, n, n, params).unwrap(),
+///     )),
+/// );
+///
+/// let mut target = Mat::<f64>::zeros(n, n);
+/// matmul(
+///     target.as_mut(),
+///     lhs.as_ref(),
+///     rhs.as_ref(),
+///     None,
+///     1.0,
+///     Parallelism::None,
+/// );
+/// assert!((&acc - &target).norm_max() < 1e-10);
+/// ```
+#[track_caller]
+#[allow(clippy::too_many_arguments)]
+pub fn matmul<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<Canonical = E>>(
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, LhsE>,
+    rhs: MatRef<'_, RhsE>,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+    params: StrassenParams,
+    stack: PodStack<'_>,
+) {
+    let (lhs, conj_lhs) = lhs.canonicalize();
+    let (rhs, conj_rhs) = rhs.canonicalize();
+    matmul_with_conj::<E>(
+        acc,
+        lhs,
+        conj_lhs,
+        rhs,
+        conj_rhs,
+        alpha,
+        beta,
+        parallelism,
+        params,
+        stack,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, mat::Mat};
+    use dyn_stack::GlobalPodBuffer;
+
+    #[test]
+    fn test_strassen() {
+        let mut params = StrassenParams::default();
+        params.threshold = 4;
+
+        for (m, n, k) in [
+            (16, 16, 16),
+            (17, 19, 23),
+            (9, 40, 11),
+            (3, 30, 30),
+            (33, 8, 5),
+        ] {
+            for conj_lhs in [Conj::No, Conj::Yes] {
+                for conj_rhs in [Conj::No, Conj::Yes] {
+                    for alpha in [None, Some(c64::new(1.0, 0.0)), Some(c64::new(0.5, -2.0))] {
+                        let lhs = Mat::<c64>::from_fn(m, k, |i, j| {
+                            c64::new((i + 2 * j) as f64 % 7.0, (3 * i + j) as f64 % 5.0 - 2.0)
+                        });
+                        let rhs = Mat::<c64>::from_fn(k, n, |i, j| {
+                            c64::new((i * j) as f64 % 3.0, (i + j) as f64 % 4.0)
+                        });
+                        let init = Mat::<c64>::from_fn(m, n, |i, j| c64::new(i as f64, j as f64));
+                        let beta = c64::new(1.5, 0.5);
+
+                        let mut acc = init.clone();
+                        matmul_with_conj(
+                            acc.as_mut(),
+                            lhs.as_ref(),
+                            conj_lhs,
+                            rhs.as_ref(),
+                            conj_rhs,
+                            alpha,
+                            beta,
+                            Parallelism::None,
+                            params,
+                            PodStack::new(&mut GlobalPodBuffer::new(
+                                matmul_req::<c64>(m, n, k, params).unwrap(),
+                            )),
+                        );
+
+                        let mut target = init.clone();
+                        super::super::matmul_with_conj(
+                            target.as_mut(),
+                            lhs.as_ref(),
+                            conj_lhs,
+                            rhs.as_ref(),
+                            conj_rhs,
+                            alpha,
+                            beta,
+                            Parallelism::None,
+                        );
+
+                        assert!((&acc - &target).norm_max() < 1e-10);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strassen_disabled() {
+        let mut params = StrassenParams::default();
+        params.threshold = usize::MAX;
+        assert!(matmul_req::<f64>(64, 64, 64, params).unwrap().size_bytes() == 0);
+
+        params.threshold = 8;
+        assert!(matmul_req::<f64>(7, 64, 64, params).unwrap().size_bytes() == 0);
+        assert!(matmul_req::<f64>(64, 64, 64, params).unwrap().size_bytes() > 0);
+    }
+}
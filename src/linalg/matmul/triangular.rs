@@ -1,5 +1,5 @@
 use super::*;
-use crate::{assert, debug_assert, linalg::zip::Diag, utils::thread::join_raw};
+use crate::{assert, debug_assert, linalg::zip::Diag, utils::thread::join_raw, Side};
 
 #[repr(u8)]
 #[derive(Copy, Clone, Debug)]
@@ -1065,6 +1065,393 @@ pub fn matmul<E: ComplexField, LhsE: Conjugate<Canonical = E>, RhsE: Conjugate<C
     );
 }
 
+// computes `acc = beta * Op(lhs) * acc`, where `lhs` is lower triangular with the given diagonal
+fn lower_x_mat_in_place_impl<E: ComplexField>(
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, E>,
+    diag: DiagonalKind,
+    conj_lhs: Conj,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    let n = lhs.nrows();
+    let mut acc = acc;
+
+    if n <= 16 {
+        let lhs = |i: usize, j: usize| {
+            let x = lhs.read(i, j);
+            if conj_lhs == Conj::Yes {
+                x.faer_conj()
+            } else {
+                x
+            }
+        };
+        // rows are updated from the bottom, so that the rows above still hold their original
+        // values when they are read
+        for i in (0..n).rev() {
+            for j in 0..acc.ncols() {
+                let mut sum = match diag {
+                    DiagonalKind::Zero => E::faer_zero(),
+                    DiagonalKind::Unit => acc.read(i, j),
+                    DiagonalKind::Generic => lhs(i, i).faer_mul(acc.read(i, j)),
+                };
+                for k in 0..i {
+                    sum = sum.faer_add(lhs(i, k).faer_mul(acc.read(k, j)));
+                }
+                acc.write(i, j, beta.faer_mul(sum));
+            }
+        }
+        return;
+    }
+
+    let bs = n / 2;
+    let (lhs_top_left, _, lhs_bot_left, lhs_bot_right) = lhs.split_at(bs, bs);
+    let (mut acc_top, mut acc_bot) = acc.split_at_row_mut(bs);
+
+    lower_x_mat_in_place_impl(
+        acc_bot.rb_mut(),
+        lhs_bot_right,
+        diag,
+        conj_lhs,
+        beta,
+        parallelism,
+    );
+    super::matmul_with_conj(
+        acc_bot.rb_mut(),
+        lhs_bot_left,
+        conj_lhs,
+        acc_top.rb(),
+        Conj::No,
+        Some(E::faer_one()),
+        beta,
+        parallelism,
+    );
+    lower_x_mat_in_place_impl(acc_top, lhs_top_left, diag, conj_lhs, beta, parallelism);
+}
+
+/// Computes the matrix product `beta * Op_lhs(lhs) * acc`, where `lhs` is triangular, and stores
+/// the result in `acc`, without allocating any workspace.
+///
+/// The triangular structure of `lhs` is given by `lhs_structure`. If it is unit triangular, the
+/// diagonal of `lhs` is not accessed, and is assumed to be equal to `1.0`. If it is strictly
+/// triangular, the diagonal is assumed to be equal to `0.0`.
+///
+/// `Op_lhs` is the identity if `conj_lhs` is `Conj::No`, and the conjugation operation if it is
+/// `Conj::Yes`.
+///
+/// # Panics
+///
+/// Panics if any of the following conditions is violated:
+///  - `lhs.nrows() == lhs.ncols()`
+///  - `lhs.ncols() == acc.nrows()`
+///  - `lhs_structure` is not [`BlockStructure::Rectangular`]
+#[track_caller]
+pub fn triangular_x_mat_in_place_with_conj<E: ComplexField>(
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, E>,
+    lhs_structure: BlockStructure,
+    conj_lhs: Conj,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    assert!(all(
+        !lhs_structure.is_dense(),
+        lhs.nrows() == lhs.ncols(),
+        lhs.ncols() == acc.nrows(),
+    ));
+
+    let diag = lhs_structure.diag_kind();
+    if lhs_structure.is_lower() {
+        lower_x_mat_in_place_impl(acc, lhs, diag, conj_lhs, beta, parallelism);
+    } else {
+        // U * B = J * (J * U * J) * (J * B), and J * U * J is lower triangular
+        lower_x_mat_in_place_impl(
+            acc.reverse_rows_mut(),
+            lhs.reverse_rows_and_cols(),
+            diag,
+            conj_lhs,
+            beta,
+            parallelism,
+        );
+    }
+}
+
+/// Computes the matrix product `beta * acc * Op_rhs(rhs)`, where `rhs` is triangular, and stores
+/// the result in `acc`, without allocating any workspace.
+///
+/// The triangular structure of `rhs` is given by `rhs_structure`. If it is unit triangular, the
+/// diagonal of `rhs` is not accessed, and is assumed to be equal to `1.0`. If it is strictly
+/// triangular, the diagonal is assumed to be equal to `0.0`.
+///
+/// `Op_rhs` is the identity if `conj_rhs` is `Conj::No`, and the conjugation operation if it is
+/// `Conj::Yes`.
+///
+/// # Panics
+///
+/// Panics if any of the following conditions is violated:
+///  - `rhs.nrows() == rhs.ncols()`
+///  - `acc.ncols() == rhs.nrows()`
+///  - `rhs_structure` is not [`BlockStructure::Rectangular`]
+#[track_caller]
+pub fn mat_x_triangular_in_place_with_conj<E: ComplexField>(
+    acc: MatMut<'_, E>,
+    rhs: MatRef<'_, E>,
+    rhs_structure: BlockStructure,
+    conj_rhs: Conj,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    triangular_x_mat_in_place_with_conj(
+        acc.transpose_mut(),
+        rhs.transpose(),
+        rhs_structure.transpose(),
+        conj_rhs,
+        beta,
+        parallelism,
+    )
+}
+
+/// Computes the matrix product `beta * lhs * acc`, where `lhs` is triangular, and stores the
+/// result in `acc`, without allocating any workspace.
+///
+/// See [`triangular_x_mat_in_place_with_conj`] for more details.
+#[track_caller]
+#[inline]
+pub fn triangular_x_mat_in_place<E: ComplexField, LhsE: Conjugate<Canonical = E>>(
+    acc: MatMut<'_, E>,
+    lhs: MatRef<'_, LhsE>,
+    lhs_structure: BlockStructure,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    let (lhs, conj_lhs) = lhs.canonicalize();
+    triangular_x_mat_in_place_with_conj(acc, lhs, lhs_structure, conj_lhs, beta, parallelism)
+}
+
+/// Computes the matrix product `beta * acc * rhs`, where `rhs` is triangular, and stores the
+/// result in `acc`, without allocating any workspace.
+///
+/// See [`mat_x_triangular_in_place_with_conj`] for more details.
+#[track_caller]
+#[inline]
+pub fn mat_x_triangular_in_place<E: ComplexField, RhsE: Conjugate<Canonical = E>>(
+    acc: MatMut<'_, E>,
+    rhs: MatRef<'_, RhsE>,
+    rhs_structure: BlockStructure,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    let (rhs, conj_rhs) = rhs.canonicalize();
+    mat_x_triangular_in_place_with_conj(acc, rhs, rhs_structure, conj_rhs, beta, parallelism)
+}
+
+#[inline]
+fn side_structure(side: Side) -> BlockStructure {
+    match side {
+        Side::Lower => BlockStructure::TriangularLower,
+        Side::Upper => BlockStructure::TriangularUpper,
+    }
+}
+
+/// Computes the hermitian rank-k update `[alpha * acc] + beta * a * a^H`, and stores the result
+/// in the triangular half of `acc` given by `side` (diagonal included). The other half of `acc` is
+/// not accessed.
+///
+/// This is the equivalent of the BLAS routine `xHERK`, and requires about half the operations of
+/// the corresponding general matrix product. `alpha` and `beta` should be real for the result to
+/// be hermitian. The product `a^H * a` can be computed by passing `a.adjoint()` as `a`.
+///
+/// # Panics
+///
+/// Panics unless `acc.nrows() == acc.ncols() == a.nrows()`.
+///
+/// # Example
+///
+/// ```
+/// use faer::{linalg::matmul::triangular::hermitian_rank_k_update, mat, Mat, Parallelism, Side};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0]];
+/// let mut gram = Mat::<f64>::zeros(2, 2);
+/// hermitian_rank_k_update(
+///     gram.as_mut(),
+///     Side::Lower,
+///     a.as_ref(),
+///     None,
+///     1.0,
+///     Parallelism::None,
+/// );
+/// assert!(gram == mat![[5.0, 0.0], [11.0, 25.0]]);
+/// ```
+#[track_caller]
+pub fn hermitian_rank_k_update<E: ComplexField, AE: Conjugate<Canonical = E>>(
+    acc: MatMut<'_, E>,
+    side: Side,
+    a: MatRef<'_, AE>,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    assert!(all(acc.nrows() == acc.ncols(), acc.nrows() == a.nrows()));
+    matmul(
+        acc,
+        side_structure(side),
+        a,
+        BlockStructure::Rectangular,
+        a.adjoint(),
+        BlockStructure::Rectangular,
+        alpha,
+        beta,
+        parallelism,
+    );
+}
+
+/// Computes the symmetric rank-k update `[alpha * acc] + beta * a * a^T`, and stores the result
+/// in the triangular half of `acc` given by `side` (diagonal included). The other half of `acc` is
+/// not accessed.
+///
+/// This is the equivalent of the BLAS routine `xSYRK`, and requires about half the operations of
+/// the corresponding general matrix product. The product `a^T * a` can be computed by passing
+/// `a.transpose()` as `a`.
+///
+/// # Panics
+///
+/// Panics unless `acc.nrows() == acc.ncols() == a.nrows()`.
+#[track_caller]
+pub fn symmetric_rank_k_update<E: ComplexField, AE: Conjugate<Canonical = E>>(
+    acc: MatMut<'_, E>,
+    side: Side,
+    a: MatRef<'_, AE>,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    assert!(all(acc.nrows() == acc.ncols(), acc.nrows() == a.nrows()));
+    matmul(
+        acc,
+        side_structure(side),
+        a,
+        BlockStructure::Rectangular,
+        a.transpose(),
+        BlockStructure::Rectangular,
+        alpha,
+        beta,
+        parallelism,
+    );
+}
+
+/// Computes the hermitian rank-2k update `[alpha * acc] + beta * a * b^H + conj(beta) * b * a^H`,
+/// and stores the result in the triangular half of `acc` given by `side` (diagonal included). The
+/// other half of `acc` is not accessed.
+///
+/// This is the equivalent of the BLAS routine `xHER2K`. `alpha` should be real for the result to
+/// be hermitian.
+///
+/// # Panics
+///
+/// Panics unless `acc.nrows() == acc.ncols() == a.nrows() == b.nrows()` and `a.ncols() ==
+/// b.ncols()`.
+#[track_caller]
+pub fn hermitian_rank_2k_update<
+    E: ComplexField,
+    AE: Conjugate<Canonical = E>,
+    BE: Conjugate<Canonical = E>,
+>(
+    acc: MatMut<'_, E>,
+    side: Side,
+    a: MatRef<'_, AE>,
+    b: MatRef<'_, BE>,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    assert!(all(
+        acc.nrows() == acc.ncols(),
+        acc.nrows() == a.nrows(),
+        acc.nrows() == b.nrows(),
+        a.ncols() == b.ncols(),
+    ));
+    let mut acc = acc;
+    let structure = side_structure(side);
+    matmul(
+        acc.rb_mut(),
+        structure,
+        a,
+        BlockStructure::Rectangular,
+        b.adjoint(),
+        BlockStructure::Rectangular,
+        alpha,
+        beta,
+        parallelism,
+    );
+    matmul(
+        acc,
+        structure,
+        b,
+        BlockStructure::Rectangular,
+        a.adjoint(),
+        BlockStructure::Rectangular,
+        Some(E::faer_one()),
+        beta.faer_conj(),
+        parallelism,
+    );
+}
+
+/// Computes the symmetric rank-2k update `[alpha * acc] + beta * (a * b^T + b * a^T)`, and stores
+/// the result in the triangular half of `acc` given by `side` (diagonal included). The other half
+/// of `acc` is not accessed.
+///
+/// This is the equivalent of the BLAS routine `xSYR2K`.
+///
+/// # Panics
+///
+/// Panics unless `acc.nrows() == acc.ncols() == a.nrows() == b.nrows()` and `a.ncols() ==
+/// b.ncols()`.
+#[track_caller]
+pub fn symmetric_rank_2k_update<
+    E: ComplexField,
+    AE: Conjugate<Canonical = E>,
+    BE: Conjugate<Canonical = E>,
+>(
+    acc: MatMut<'_, E>,
+    side: Side,
+    a: MatRef<'_, AE>,
+    b: MatRef<'_, BE>,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+) {
+    assert!(all(
+        acc.nrows() == acc.ncols(),
+        acc.nrows() == a.nrows(),
+        acc.nrows() == b.nrows(),
+        a.ncols() == b.ncols(),
+    ));
+    let mut acc = acc;
+    let structure = side_structure(side);
+    matmul(
+        acc.rb_mut(),
+        structure,
+        a,
+        BlockStructure::Rectangular,
+        b.transpose(),
+        BlockStructure::Rectangular,
+        alpha,
+        beta,
+        parallelism,
+    );
+    matmul(
+        acc,
+        structure,
+        b,
+        BlockStructure::Rectangular,
+        a.transpose(),
+        BlockStructure::Rectangular,
+        Some(E::faer_one()),
+        beta,
+        parallelism,
+    );
+}
+
 unsafe fn matmul_unchecked<E: ComplexField>(
     acc: MatMut<'_, E>,
     acc_structure: BlockStructure,
@@ -0,0 +1,285 @@
+use super::{
+    matmul as matmul_generic,
+    triangular::{self, BlockStructure},
+};
+use crate::{
+    assert,
+    linalg::{temp_mat_req, temp_mat_uninit},
+    mat::{MatMut, MatRef},
+    ComplexField, Conjugate, Entity, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+// number of multiply-adds of `(A * B) * C` and `A * (B * C)`, for `A: m×k1`, `B: k1×k2` and
+// `C: k2×n`
+#[inline]
+fn left_first(m: usize, k1: usize, k2: usize, n: usize) -> bool {
+    let m = m as f64;
+    let k1 = k1 as f64;
+    let k2 = k2 as f64;
+    let n = n as f64;
+    m * k1 * k2 + m * k2 * n <= k1 * k2 * n + m * k1 * n
+}
+
+/// Computes the size and alignment of the workspace required to compute the triple product of
+/// matrices with dimensions `m×k1`, `k1×k2` and `k2×n` with [`triple_product`].
+pub fn triple_product_req<E: Entity>(
+    m: usize,
+    k1: usize,
+    k2: usize,
+    n: usize,
+) -> Result<StackReq, SizeOverflow> {
+    if left_first(m, k1, k2, n) {
+        temp_mat_req::<E>(m, k2)
+    } else {
+        temp_mat_req::<E>(k1, n)
+    }
+}
+
+/// Computes the matrix product `[alpha * acc] + beta * a * b * c` and stores the result in `acc`.
+///
+/// The product is evaluated either as `(a * b) * c` or as `a * (b * c)`, whichever requires fewer
+/// operations, with the intermediate product stored in `stack`.
+///
+/// Performs the operation:
+/// - `acc = beta * a * b * c` if `alpha` is `None` (in this case, the preexisting values in
+/// `acc` are not read, so it is allowed to be a view over uninitialized values if `E: Copy`),
+/// - `acc = alpha * acc + beta * a * b * c` if `alpha` is `Some(_)`.
+///
+/// # Panics
+///
+/// Panics if the matrix dimensions are not compatible for matrix multiplication, i.e. unless
+/// - `acc.nrows() == a.nrows()`,
+/// - `a.ncols() == b.nrows()`,
+/// - `b.ncols() == c.nrows()`,
+/// - `c.ncols() == acc.ncols()`.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`triple_product_req`]).
+///
+/// # Example
+///
+/// ```
+/// use dyn_stack::{GlobalPodBuffer, PodStack};
+/// use faer::{
+///     linalg::matmul::{triple_product, triple_product_req},
+///     mat, Mat, Parallelism,
+/// };
+///
+/// let a = mat![[1.0], [2.0]];
+/// let b = mat![[3.0, 4.0]];
+/// let c = mat![[1.0, 0.0], [0.0, 1.0]];
+///
+/// let mut acc = Mat::<f64>::zeros(2, 2);
+/// triple_product(
+///     acc.as_mut(),
+///     a.as_ref(),
+///     b.as_ref(),
+///     c.as_ref(),
+///     None,
+///     1.0,
+///     Parallelism::None,
+///     PodStack::new(&mut GlobalPodBuffer::new(
+///         triple_product_req::<f64>(2, 1, 2, 2).unwrap(),
+///     )),
+/// );
+/// assert!(acc == mat![[3.0, 4.0], [6.0, 8.0]]);
+/// ```
+#[track_caller]
+pub fn triple_product<
+    E: ComplexField,
+    AE: Conjugate<Canonical = E>,
+    BE: Conjugate<Canonical = E>,
+    CE: Conjugate<Canonical = E>,
+>(
+    acc: MatMut<'_, E>,
+    a: MatRef<'_, AE>,
+    b: MatRef<'_, BE>,
+    c: MatRef<'_, CE>,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    assert!(all(
+        acc.nrows() == a.nrows(),
+        a.ncols() == b.nrows(),
+        b.ncols() == c.nrows(),
+        c.ncols() == acc.ncols(),
+    ));
+    let (m, k1, k2, n) = (a.nrows(), a.ncols(), b.ncols(), c.ncols());
+
+    if left_first(m, k1, k2, n) {
+        let (mut ab, _) = temp_mat_uninit::<E>(m, k2, stack);
+        matmul_generic(ab.rb_mut(), a, b, None, E::faer_one(), parallelism);
+        matmul_generic(acc, ab.rb(), c, alpha, beta, parallelism);
+    } else {
+        let (mut bc, _) = temp_mat_uninit::<E>(k1, n, stack);
+        matmul_generic(bc.rb_mut(), b, c, None, E::faer_one(), parallelism);
+        matmul_generic(acc, a, bc.rb(), alpha, beta, parallelism);
+    }
+}
+
+/// Computes the size and alignment of the workspace required to compute the sandwich product
+/// `a * b * a^H` with [`sandwich_product`], where `a` has dimensions `m×k`.
+pub fn sandwich_product_req<E: Entity>(m: usize, k: usize) -> Result<StackReq, SizeOverflow> {
+    temp_mat_req::<E>(m, k)
+}
+
+/// Computes the hermitian matrix product `[alpha * acc] + beta * a * b * a^H`, where `b` is a
+/// hermitian matrix, and stores the result in `acc`.
+///
+/// This is the congruence transformation that appears e.g. in the covariance update of a Kalman
+/// filter, `F * P * F^H`, and in the Gauss-Newton normal equations. The product `a^H * b * a` can
+/// be computed by passing `a.adjoint()` as `a`.
+///
+/// `a * b` is computed first and stored in `stack`, then only the lower triangular half of the
+/// result is computed, which halves the cost of the second product compared to the general
+/// triple product. The strictly upper triangular half of `acc` is then filled with the adjoint of
+/// the lower half. Only the lower triangular half of `acc` is read if `alpha` is `Some(_)`, and
+/// `alpha` and `beta` should be real for the result to be hermitian.
+///
+/// # Panics
+///
+/// Panics if the matrix dimensions are not compatible, i.e. unless
+/// - `acc.nrows() == acc.ncols() == a.nrows()`,
+/// - `b.nrows() == b.ncols() == a.ncols()`.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`sandwich_product_req`]).
+#[track_caller]
+pub fn sandwich_product<
+    E: ComplexField,
+    AE: Conjugate<Canonical = E>,
+    BE: Conjugate<Canonical = E>,
+>(
+    acc: MatMut<'_, E>,
+    a: MatRef<'_, AE>,
+    b: MatRef<'_, BE>,
+    alpha: Option<E>,
+    beta: E,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    assert!(all(
+        acc.nrows() == acc.ncols(),
+        acc.nrows() == a.nrows(),
+        b.nrows() == b.ncols(),
+        b.nrows() == a.ncols(),
+    ));
+    let (m, k) = (a.nrows(), a.ncols());
+    let mut acc = acc;
+
+    let (mut ab, _) = temp_mat_uninit::<E>(m, k, stack);
+    matmul_generic(ab.rb_mut(), a, b, None, E::faer_one(), parallelism);
+    triangular::matmul(
+        acc.rb_mut(),
+        BlockStructure::TriangularLower,
+        ab.rb(),
+        BlockStructure::Rectangular,
+        a.adjoint(),
+        BlockStructure::Rectangular,
+        alpha,
+        beta,
+        parallelism,
+    );
+
+    for j in 0..m {
+        for i in 0..j {
+            acc.write(i, j, acc.read(j, i).faer_conj());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{complex_native::c64, Mat};
+    use assert_approx_eq::assert_approx_eq;
+    use dyn_stack::GlobalPodBuffer;
+
+    fn random(nrows: usize, ncols: usize) -> Mat<c64> {
+        Mat::from_fn(nrows, ncols, |_, _| {
+            c64::new(rand::random(), rand::random())
+        })
+    }
+
+    #[test]
+    fn test_association_order() {
+        // (1×100 * 100×100) * 100×100 is cheaper than 1×100 * (100×100 * 100×100)
+        assert!(left_first(1, 100, 100, 100));
+        assert!(!left_first(100, 100, 100, 1));
+        assert!(
+            triple_product_req::<f64>(1, 100, 100, 100)
+                .unwrap()
+                .size_bytes()
+                == temp_mat_req::<f64>(1, 100).unwrap().size_bytes()
+        );
+    }
+
+    #[test]
+    fn test_triple_product() {
+        for (m, k1, k2, n) in [(3, 40, 40, 30), (30, 40, 40, 3), (0, 5, 6, 7), (5, 0, 6, 7)] {
+            let a = random(m, k1);
+            let b = random(k1, k2);
+            let c = random(n, k2);
+            let acc_init = random(m, n);
+            let alpha = c64::new(0.5, 1.0);
+            let beta = c64::new(2.0, -1.0);
+
+            let mut acc = acc_init.clone();
+            triple_product(
+                acc.as_mut(),
+                a.as_ref(),
+                b.as_ref(),
+                c.adjoint(),
+                Some(alpha),
+                beta,
+                Parallelism::None,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    triple_product_req::<c64>(m, k1, k2, n).unwrap(),
+                )),
+            );
+
+            let target = &a * (&b * c.adjoint());
+            for j in 0..n {
+                for i in 0..m {
+                    assert_approx_eq!(
+                        acc.read(i, j),
+                        alpha * acc_init.read(i, j) + beta * target.read(i, j)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sandwich_product() {
+        for (m, k) in [(7, 12), (12, 7), (1, 1), (0, 3)] {
+            let a = random(m, k);
+            let r = random(k, k);
+            let b = Mat::from_fn(k, k, |i, j| r.read(i, j) + r.read(j, i).faer_conj());
+
+            let mut acc = Mat::<c64>::from_fn(m, m, |_, _| c64::new(f64::NAN, f64::NAN));
+            sandwich_product(
+                acc.as_mut(),
+                a.as_ref(),
+                b.as_ref(),
+                None,
+                c64::new(2.0, 0.0),
+                Parallelism::Rayon(4),
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    sandwich_product_req::<c64>(m, k).unwrap(),
+                )),
+            );
+
+            let target = &a * &b * a.adjoint();
+            for j in 0..m {
+                for i in 0..m {
+                    assert_approx_eq!(acc.read(i, j), c64::new(2.0, 0.0) * target.read(i, j));
+                }
+            }
+        }
+    }
+}
@@ -63,6 +63,7 @@ pub mod zip;
 
 pub mod householder;
 pub mod matmul;
+pub mod triangular;
 pub mod triangular_inverse;
 pub mod triangular_solve;
 
@@ -73,14 +74,72 @@ pub mod qr;
 pub mod evd;
 pub mod svd;
 
+pub mod block2x2;
+
+pub mod batch;
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+pub mod half_precision;
+
 /// High level linear system solvers.
 pub mod solvers;
 
+pub mod autotune;
+pub mod cond;
+pub mod out_of_core;
+pub mod plan;
+pub mod refinement;
+pub mod scaling;
+pub mod subspace;
+
+pub(crate) mod gemm_impl;
 pub(crate) mod kron_impl;
+#[cfg(feature = "lapack")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lapack")))]
+pub mod lapack;
+pub(crate) mod lstsq_impl;
 mod mat_ops;
 pub(crate) mod reductions;
 
+pub use gemm_impl::gemm;
 pub use kron_impl::kron;
+pub use lstsq_impl::{lstsq, LstsqResult};
+
+/// Scheduling strategy for the blocked dense factorizations (LLT, LU with partial pivoting and
+/// QR).
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PanelScheduler {
+    /// Recursive fork-join factorization, where the trailing update of each level completes
+    /// before the next panel is factored.
+    #[default]
+    ForkJoin,
+    /// Right-looking factorization with a lookahead of one panel.
+    ///
+    /// While the trailing submatrix is updated with panel `k`, panel `k + 1` is updated and
+    /// factored concurrently, so that the (mostly sequential) panel factorization is hidden
+    /// behind the bulk of the work instead of stalling the other threads. The threads are split
+    /// between the two tasks in proportion to their amount of work.
+    Lookahead {
+        /// Width of each panel. A value of `0` lets the implementation pick a size.
+        ///
+        /// The QR decomposition ignores this value, and uses the block size of its Householder
+        /// factor as the panel width.
+        block_size: usize,
+    },
+}
+
+#[inline]
+pub(crate) fn lookahead_block_size(n: usize, block_size: usize) -> usize {
+    if block_size != 0 {
+        block_size
+    } else if n <= 1024 {
+        64
+    } else {
+        128
+    }
+}
 
 #[inline]
 pub(crate) fn col_stride<Unit: 'static>(nrows: usize) -> usize {
@@ -0,0 +1,805 @@
+//! Out-of-core factorizations, for dense matrices that are too large to fit in memory.
+//!
+//! The matrix is accessed through a [`TileStorage`], which splits it into square tiles that are
+//! read and written one at a time, e.g. from a file with [`FileStorage`]. The factorizations are
+//! left-looking: the matrix is processed one block column of tiles (panel) at a time, and the
+//! previously factored panels are streamed through memory to update the current one. The next
+//! panel is read while the current one is being applied, so that the storage accesses overlap with
+//! the computations.
+//!
+//! Three panels are kept in memory, so that the memory usage is about
+//! `3 * nrows * tile_size` scalars, independently of the number of columns. Each panel is read
+//! once for every panel that follows it, so larger tiles reduce the amount of data that is read
+//! from the storage.
+//!
+//! # Example
+//! ```
+//! use faer::{
+//!     linalg::out_of_core::{self, MemoryStorage},
+//!     Mat, Parallelism,
+//! };
+//!
+//! let n = 50;
+//! let a = Mat::<f64>::from_fn(n, n, |i, j| if i == j { n as f64 } else { 1.0 });
+//!
+//! // tiles of size 16, the last row and column of tiles are smaller
+//! let mut storage = MemoryStorage::new(a.clone(), 16);
+//! out_of_core::cholesky_in_place(&mut storage, Parallelism::None).unwrap();
+//!
+//! let l = Mat::<f64>::from_fn(n, n, |i, j| {
+//!     if i >= j {
+//!         storage.as_ref().read(i, j)
+//!     } else {
+//!         0.0
+//!     }
+//! });
+//! assert!((&l * l.adjoint() - &a).norm_max() < 1e-10);
+//! ```
+
+use crate::{
+    assert,
+    linalg::{
+        cholesky::llt::{compute as llt, CholeskyError},
+        householder,
+        matmul::{
+            matmul,
+            triangular::{self as tri, BlockStructure},
+        },
+        qr::no_pivoting::compute as qr,
+        triangular_solve,
+    },
+    mat::{MatMut, MatRef},
+    utils::{thread::join_raw, DivCeil},
+    ComplexField, Conj, Entity, Mat, Parallelism,
+};
+use dyn_stack::{GlobalPodBuffer, PodStack, StackReq};
+use reborrow::*;
+
+/// Storage of a matrix that is split into square tiles of size `tile_size × tile_size`.
+///
+/// The tile at the position `(tile_row, tile_col)` contains the elements whose row index is in
+/// `tile_row * tile_size..(tile_row + 1) * tile_size` and whose column index is in
+/// `tile_col * tile_size..(tile_col + 1) * tile_size`. The tiles in the last row and column of
+/// tiles are truncated to the dimensions of the matrix.
+///
+/// The tiles may be read concurrently by the factorizations, while they are not being written.
+pub trait TileStorage<E: Entity>: Sync {
+    /// Error returned when a tile can't be read or written.
+    type Error: Send;
+
+    /// Returns the number of rows of the matrix.
+    fn nrows(&self) -> usize;
+    /// Returns the number of columns of the matrix.
+    fn ncols(&self) -> usize;
+    /// Returns the number of rows and columns of the tiles.
+    fn tile_size(&self) -> usize;
+
+    /// Reads the tile at the given position into `dst`, whose dimensions are those of the tile.
+    fn read_tile(
+        &self,
+        tile_row: usize,
+        tile_col: usize,
+        dst: MatMut<'_, E>,
+    ) -> Result<(), Self::Error>;
+    /// Overwrites the tile at the given position with `src`, whose dimensions are those of the
+    /// tile.
+    fn write_tile(
+        &mut self,
+        tile_row: usize,
+        tile_col: usize,
+        src: MatRef<'_, E>,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Error returned by the out-of-core factorizations.
+#[derive(Copy, Clone, Debug)]
+pub enum OutOfCoreError<StorageError> {
+    /// The storage failed to read or write a tile.
+    Storage(StorageError),
+    /// The matrix is not numerically positive definite.
+    NotPositiveDefinite(CholeskyError),
+}
+
+impl<StorageError: core::fmt::Display> core::fmt::Display for OutOfCoreError<StorageError> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OutOfCoreError::Storage(err) => write!(f, "storage error: {err}"),
+            OutOfCoreError::NotPositiveDefinite(err) => core::fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<StorageError: core::fmt::Debug + core::fmt::Display> std::error::Error
+    for OutOfCoreError<StorageError>
+{
+}
+
+/// Tile storage backed by a matrix in memory.
+///
+/// This is mostly useful for testing, or for staging a matrix before it is written to a slower
+/// storage.
+#[derive(Clone, Debug)]
+pub struct MemoryStorage<E: Entity> {
+    mat: Mat<E>,
+    tile_size: usize,
+}
+
+impl<E: Entity> MemoryStorage<E> {
+    /// Returns a new storage containing `mat`, split into tiles of size `tile_size`.
+    ///
+    /// # Panics
+    /// Panics if `tile_size` is zero.
+    #[track_caller]
+    pub fn new(mat: Mat<E>, tile_size: usize) -> Self {
+        assert!(tile_size > 0);
+        Self { mat, tile_size }
+    }
+
+    /// Returns a view over the stored matrix.
+    #[inline]
+    pub fn as_ref(&self) -> MatRef<'_, E> {
+        self.mat.as_ref()
+    }
+
+    /// Returns the stored matrix.
+    #[inline]
+    pub fn into_inner(self) -> Mat<E> {
+        self.mat
+    }
+}
+
+impl<E: Entity> TileStorage<E> for MemoryStorage<E> {
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.mat.nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.mat.ncols()
+    }
+    #[inline]
+    fn tile_size(&self) -> usize {
+        self.tile_size
+    }
+
+    fn read_tile(
+        &self,
+        tile_row: usize,
+        tile_col: usize,
+        mut dst: MatMut<'_, E>,
+    ) -> Result<(), Self::Error> {
+        let nb = self.tile_size;
+        dst.copy_from(self.mat.as_ref().submatrix(
+            tile_row * nb,
+            tile_col * nb,
+            dst.nrows(),
+            dst.ncols(),
+        ));
+        Ok(())
+    }
+
+    fn write_tile(
+        &mut self,
+        tile_row: usize,
+        tile_col: usize,
+        src: MatRef<'_, E>,
+    ) -> Result<(), Self::Error> {
+        let nb = self.tile_size;
+        self.mat
+            .as_mut()
+            .submatrix_mut(tile_row * nb, tile_col * nb, src.nrows(), src.ncols())
+            .copy_from(src);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+pub use file::FileStorage;
+
+#[cfg(feature = "std")]
+mod file {
+    use super::*;
+    use core::marker::PhantomData;
+    use faer_entity::SimpleEntity;
+    use std::{
+        fs::{File, OpenOptions},
+        io::{self, Read, Seek, SeekFrom, Write},
+        path::Path,
+        sync::Mutex,
+    };
+
+    /// Tile storage backed by a file.
+    ///
+    /// The tiles are stored contiguously in column-major order of tiles, each tile being stored
+    /// in column-major order and padded to `tile_size × tile_size` elements, using the native
+    /// byte order.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[derive(Debug)]
+    pub struct FileStorage<E: SimpleEntity> {
+        file: Mutex<File>,
+        nrows: usize,
+        ncols: usize,
+        tile_size: usize,
+        __marker: PhantomData<E>,
+    }
+
+    impl<E: SimpleEntity> FileStorage<E> {
+        fn file_len(nrows: usize, ncols: usize, tile_size: usize) -> u64 {
+            let tiles = nrows.msrv_div_ceil(tile_size) * ncols.msrv_div_ceil(tile_size);
+            (tiles * tile_size * tile_size * core::mem::size_of::<E>()) as u64
+        }
+
+        fn tile_offset(&self, tile_row: usize, tile_col: usize) -> u64 {
+            let nb = self.tile_size;
+            let tile = tile_col * self.nrows.msrv_div_ceil(nb) + tile_row;
+            (tile * nb * nb * core::mem::size_of::<E>()) as u64
+        }
+
+        /// Creates a storage at the given path, for a matrix with the given dimensions whose
+        /// elements are initialized to zero. The file is truncated if it already exists.
+        ///
+        /// # Panics
+        /// Panics if `tile_size` is zero.
+        #[track_caller]
+        pub fn create(
+            path: impl AsRef<Path>,
+            nrows: usize,
+            ncols: usize,
+            tile_size: usize,
+        ) -> io::Result<Self> {
+            assert!(tile_size > 0);
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path)?;
+            file.set_len(Self::file_len(nrows, ncols, tile_size))?;
+            Ok(Self {
+                file: Mutex::new(file),
+                nrows,
+                ncols,
+                tile_size,
+                __marker: PhantomData,
+            })
+        }
+
+        /// Opens an existing storage at the given path, for a matrix with the given dimensions.
+        ///
+        /// # Panics
+        /// Panics if `tile_size` is zero.
+        #[track_caller]
+        pub fn open(
+            path: impl AsRef<Path>,
+            nrows: usize,
+            ncols: usize,
+            tile_size: usize,
+        ) -> io::Result<Self> {
+            assert!(tile_size > 0);
+            let file = OpenOptions::new().read(true).write(true).open(path)?;
+            if file.metadata()?.len() != Self::file_len(nrows, ncols, tile_size) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "the file size doesn't match the matrix dimensions",
+                ));
+            }
+            Ok(Self {
+                file: Mutex::new(file),
+                nrows,
+                ncols,
+                tile_size,
+                __marker: PhantomData,
+            })
+        }
+
+        /// Creates a storage at the given path, containing the values of `mat`. The file is
+        /// truncated if it already exists.
+        ///
+        /// # Panics
+        /// Panics if `tile_size` is zero.
+        #[track_caller]
+        pub fn from_mat(
+            path: impl AsRef<Path>,
+            mat: MatRef<'_, E>,
+            tile_size: usize,
+        ) -> io::Result<Self> {
+            let mut this = Self::create(path, mat.nrows(), mat.ncols(), tile_size)?;
+            let nb = tile_size;
+            for tile_col in 0..mat.ncols().msrv_div_ceil(nb) {
+                for tile_row in 0..mat.nrows().msrv_div_ceil(nb) {
+                    let (i, j) = (tile_row * nb, tile_col * nb);
+                    let h = Ord::min(nb, mat.nrows() - i);
+                    let w = Ord::min(nb, mat.ncols() - j);
+                    this.write_tile(tile_row, tile_col, mat.submatrix(i, j, h, w))?;
+                }
+            }
+            Ok(this)
+        }
+
+        /// Reads the whole matrix into memory.
+        pub fn to_mat(&self) -> io::Result<Mat<E>> {
+            let mut mat = Mat::<E>::zeros(self.nrows, self.ncols);
+            let nb = self.tile_size;
+            for tile_col in 0..self.ncols.msrv_div_ceil(nb) {
+                for tile_row in 0..self.nrows.msrv_div_ceil(nb) {
+                    let (i, j) = (tile_row * nb, tile_col * nb);
+                    let h = Ord::min(nb, self.nrows - i);
+                    let w = Ord::min(nb, self.ncols - j);
+                    self.read_tile(tile_row, tile_col, mat.as_mut().submatrix_mut(i, j, h, w))?;
+                }
+            }
+            Ok(mat)
+        }
+    }
+
+    impl<E: SimpleEntity> TileStorage<E> for FileStorage<E> {
+        type Error = io::Error;
+
+        #[inline]
+        fn nrows(&self) -> usize {
+            self.nrows
+        }
+        #[inline]
+        fn ncols(&self) -> usize {
+            self.ncols
+        }
+        #[inline]
+        fn tile_size(&self) -> usize {
+            self.tile_size
+        }
+
+        fn read_tile(
+            &self,
+            tile_row: usize,
+            tile_col: usize,
+            mut dst: MatMut<'_, E>,
+        ) -> io::Result<()> {
+            let nb = self.tile_size;
+            let mut buf = alloc::vec![unsafe { core::mem::zeroed::<E>() }; nb * nb];
+            {
+                let mut file = self.file.lock().unwrap();
+                file.seek(SeekFrom::Start(self.tile_offset(tile_row, tile_col)))?;
+                file.read_exact(bytemuck::cast_slice_mut(&mut buf))?;
+            }
+            let tile = crate::mat::from_column_major_slice::<E>(&buf, nb, nb);
+            dst.copy_from(tile.submatrix(0, 0, dst.nrows(), dst.ncols()));
+            Ok(())
+        }
+
+        fn write_tile(
+            &mut self,
+            tile_row: usize,
+            tile_col: usize,
+            src: MatRef<'_, E>,
+        ) -> io::Result<()> {
+            let nb = self.tile_size;
+            let mut buf = alloc::vec![unsafe { core::mem::zeroed::<E>() }; nb * nb];
+            crate::mat::from_column_major_slice_mut::<E>(&mut buf, nb, nb)
+                .submatrix_mut(0, 0, src.nrows(), src.ncols())
+                .copy_from(src);
+            let offset = self.tile_offset(tile_row, tile_col);
+            let file = self.file.get_mut().unwrap();
+            file.seek(SeekFrom::Start(offset))?;
+            file.write_all(bytemuck::cast_slice(&buf))
+        }
+    }
+}
+
+// reads the tiles `tile_row..` of the column of tiles `tile_col` into `dst`
+fn read_panel<E: Entity, S: TileStorage<E>>(
+    storage: &S,
+    tile_row: usize,
+    tile_col: usize,
+    mut dst: MatMut<'_, E>,
+) -> Result<(), S::Error> {
+    let nb = storage.tile_size();
+    let mut i = 0;
+    let mut tile = tile_row;
+    while i < dst.nrows() {
+        let h = Ord::min(nb, dst.nrows() - i);
+        storage.read_tile(tile, tile_col, dst.rb_mut().subrows_mut(i, h))?;
+        i += h;
+        tile += 1;
+    }
+    Ok(())
+}
+
+// writes `src` into the tiles `tile_row..` of the column of tiles `tile_col`
+fn write_panel<E: Entity, S: TileStorage<E>>(
+    storage: &mut S,
+    tile_row: usize,
+    tile_col: usize,
+    src: MatRef<'_, E>,
+) -> Result<(), S::Error> {
+    let nb = storage.tile_size();
+    let mut i = 0;
+    let mut tile = tile_row;
+    while i < src.nrows() {
+        let h = Ord::min(nb, src.nrows() - i);
+        storage.write_tile(tile, tile_col, src.subrows(i, h))?;
+        i += h;
+        tile += 1;
+    }
+    Ok(())
+}
+
+// calls `update(j, panel)` for `j` in `0..n_panels`, where `panel` contains the tiles
+// `first_tile_row(j)..` of the column of tiles `j`, while reading the next panel concurrently
+fn stream_panels<E: ComplexField, S: TileStorage<E>>(
+    storage: &S,
+    n_panels: usize,
+    first_tile_row: impl Fn(usize) -> usize,
+    buffers: &mut [Mat<E>; 2],
+    parallelism: Parallelism,
+    mut update: impl Send + FnMut(usize, MatRef<'_, E>),
+) -> Result<(), S::Error> {
+    if n_panels == 0 {
+        return Ok(());
+    }
+    let nb = storage.tile_size();
+    let m = storage.nrows();
+    let panel_rows = |j: usize| m - first_tile_row(j) * nb;
+
+    let [current, next] = buffers;
+    read_panel(
+        storage,
+        first_tile_row(0),
+        0,
+        current.as_mut().submatrix_mut(0, 0, panel_rows(0), nb),
+    )?;
+
+    for j in 0..n_panels {
+        let mut result = Ok(());
+        {
+            let panel = current.as_ref().submatrix(0, 0, panel_rows(j), nb);
+            let update = &mut update;
+            let result = &mut result;
+            let prefetch = if j + 1 < n_panels {
+                Some((
+                    first_tile_row(j + 1),
+                    next.as_mut().submatrix_mut(0, 0, panel_rows(j + 1), nb),
+                ))
+            } else {
+                None
+            };
+            join_raw(
+                |_| {
+                    if let Some((tile_row, dst)) = prefetch {
+                        *result = read_panel(storage, tile_row, j + 1, dst);
+                    }
+                },
+                // the update uses all the threads, since the read is mostly waiting on the
+                // storage
+                |_| update(j, panel),
+                parallelism,
+            );
+        }
+        result?;
+        core::mem::swap(current, next);
+    }
+    Ok(())
+}
+
+/// Computes the Cholesky factor $L$ of a Hermitian positive definite matrix $A$ such that
+/// $A = LL^H$, where the matrix is accessed through `storage`.
+///
+/// The lower triangular part of the matrix is overwritten by $L$, and the strictly upper
+/// triangular part is not accessed.
+///
+/// If the matrix is not numerically positive definite, or if the storage fails, an error is
+/// returned and the matrix is left in an unspecified state.
+///
+/// # Panics
+/// Panics if the matrix is not square, or if the tile size is zero.
+#[track_caller]
+pub fn cholesky_in_place<E: ComplexField, S: TileStorage<E>>(
+    storage: &mut S,
+    parallelism: Parallelism,
+) -> Result<(), OutOfCoreError<S::Error>> {
+    let n = storage.nrows();
+    let nb = storage.tile_size();
+    assert!(all(storage.ncols() == n, nb > 0));
+
+    let mut panel = Mat::<E>::zeros(n, nb);
+    let mut buffers = [Mat::<E>::zeros(n, nb), Mat::<E>::zeros(n, nb)];
+    let mut mem = GlobalPodBuffer::new(
+        llt::cholesky_in_place_req::<E>(nb, parallelism, Default::default()).unwrap(),
+    );
+
+    for k in 0..n.msrv_div_ceil(nb) {
+        let row = k * nb;
+        let width = Ord::min(nb, n - row);
+        let mut panel = panel.as_mut().submatrix_mut(0, 0, n - row, width);
+        read_panel(&*storage, k, k, panel.rb_mut()).map_err(OutOfCoreError::Storage)?;
+
+        // A[k.., k] -= L[k.., j] * L[k, j]^H
+        stream_panels(
+            &*storage,
+            k,
+            |_| k,
+            &mut buffers,
+            parallelism,
+            |_, l| {
+                let (l_top, l_bot) = l.split_at_row(width);
+                let (a_top, a_bot) = panel.rb_mut().split_at_row_mut(width);
+                tri::matmul(
+                    a_top,
+                    BlockStructure::TriangularLower,
+                    l_top,
+                    BlockStructure::Rectangular,
+                    l_top.adjoint(),
+                    BlockStructure::Rectangular,
+                    Some(E::faer_one()),
+                    E::faer_one().faer_neg(),
+                    parallelism,
+                );
+                matmul(
+                    a_bot,
+                    l_bot,
+                    l_top.adjoint(),
+                    Some(E::faer_one()),
+                    E::faer_one().faer_neg(),
+                    parallelism,
+                );
+            },
+        )
+        .map_err(OutOfCoreError::Storage)?;
+
+        {
+            let (mut l_top, mut l_bot) = panel.rb_mut().split_at_row_mut(width);
+            llt::cholesky_in_place(
+                l_top.rb_mut(),
+                Default::default(),
+                parallelism,
+                PodStack::new(&mut mem),
+                Default::default(),
+            )
+            .map_err(|err| {
+                OutOfCoreError::NotPositiveDefinite(CholeskyError {
+                    non_positive_definite_minor: row + err.non_positive_definite_minor,
+                })
+            })?;
+            // L[k+1.., k] = A[k+1.., k] * L[k, k]^-H
+            triangular_solve::solve_lower_triangular_in_place(
+                l_top.rb().conjugate(),
+                l_bot.rb_mut().transpose_mut(),
+                parallelism,
+            );
+        }
+
+        write_panel(storage, k, k, panel.rb()).map_err(OutOfCoreError::Storage)?;
+    }
+    Ok(())
+}
+
+/// Computes the QR decomposition of a rectangular matrix $A$, where the matrix is accessed
+/// through `storage`.
+///
+/// The matrix is overwritten by the factors in the same layout as
+/// [`qr_in_place`](crate::linalg::qr::no_pivoting::compute::qr_in_place): the upper
+/// trapezoidal part contains $R$, and the strictly lower trapezoidal part contains the
+/// Householder basis of $Q$. The returned matrix is the Householder factor of $Q$, whose block
+/// size is the minimum of the tile size and the dimensions of the matrix.
+///
+/// If the storage fails, an error is returned and the matrix is left in an unspecified state.
+///
+/// # Panics
+/// Panics if the tile size is zero.
+#[track_caller]
+pub fn qr_in_place<E: ComplexField, S: TileStorage<E>>(
+    storage: &mut S,
+    parallelism: Parallelism,
+) -> Result<Mat<E>, S::Error> {
+    let m = storage.nrows();
+    let n = storage.ncols();
+    let nb = storage.tile_size();
+    assert!(nb > 0);
+    let size = Ord::min(m, n);
+
+    let mut householder_factor = Mat::<E>::zeros(Ord::min(nb, size), size);
+    let mut panel = Mat::<E>::zeros(m, nb);
+    let mut buffers = [Mat::<E>::zeros(m, nb), Mat::<E>::zeros(m, nb)];
+    let mut mem = GlobalPodBuffer::new(
+        StackReq::try_any_of([
+            qr::qr_in_place_req::<E>(m, nb, nb, parallelism, Default::default()).unwrap(),
+            householder::apply_block_householder_sequence_transpose_on_the_left_in_place_req::<E>(
+                m, nb, nb,
+            )
+            .unwrap(),
+        ])
+        .unwrap(),
+    );
+    let mut stack = PodStack::new(&mut mem);
+
+    // number of panels that contain householder reflections
+    let n_factored = size.msrv_div_ceil(nb);
+
+    for k in 0..n.msrv_div_ceil(nb) {
+        let col = k * nb;
+        let width = Ord::min(nb, n - col);
+        let mut panel = panel.as_mut().submatrix_mut(0, 0, m, width);
+        read_panel(&*storage, 0, k, panel.rb_mut())?;
+
+        // A[.., k] = Q_j^H A[.., k]
+        {
+            let householder_factor = householder_factor.as_ref();
+            let stack = &mut stack;
+            stream_panels(
+                &*storage,
+                Ord::min(k, n_factored),
+                |j| j,
+                &mut buffers,
+                parallelism,
+                |j, basis| {
+                    let row = j * nb;
+                    let n_reflections = Ord::min(nb, size - row);
+                    householder::apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj(
+                        basis.subcols(0, n_reflections),
+                        householder_factor.submatrix(0, row, n_reflections, n_reflections),
+                        Conj::Yes,
+                        panel.rb_mut().subrows_mut(row, m - row),
+                        parallelism,
+                        stack.rb_mut(),
+                    );
+                },
+            )?;
+        }
+
+        if col < size {
+            let n_reflections = Ord::min(width, m - col);
+            qr::qr_in_place(
+                panel.rb_mut().subrows_mut(col, m - col),
+                householder_factor
+                    .as_mut()
+                    .submatrix_mut(0, col, n_reflections, n_reflections),
+                parallelism,
+                stack.rb_mut(),
+                Default::default(),
+            );
+        }
+
+        write_panel(storage, 0, k, panel.rb())?;
+    }
+    Ok(householder_factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::householder};
+
+    fn random_hpd(n: usize) -> Mat<c64> {
+        let a = Mat::<c64>::from_fn(n, n, |i, j| {
+            c64::new(
+                ((i * 7 + j * 3) % 11) as f64 - 5.0,
+                ((i + 2 * j) % 5) as f64,
+            )
+        });
+        let mut h = &a * a.adjoint();
+        for i in 0..n {
+            h.write(i, i, h.read(i, i) + c64::new(n as f64, 0.0));
+        }
+        h
+    }
+
+    #[test]
+    fn test_cholesky() {
+        for (n, nb) in [(0, 4), (1, 4), (13, 4), (16, 4), (20, 32), (37, 8)] {
+            let a = random_hpd(n);
+            let mut storage = MemoryStorage::new(a.clone(), nb);
+            cholesky_in_place(&mut storage, Parallelism::None).unwrap();
+
+            let factors = storage.into_inner();
+            let l = Mat::<c64>::from_fn(n, n, |i, j| {
+                if i >= j {
+                    factors.read(i, j)
+                } else {
+                    c64::new(0.0, 0.0)
+                }
+            });
+            assert!((&l * l.adjoint() - &a).norm_max() < 1e-9 * a.norm_max());
+
+            // the strictly upper triangular part is not accessed
+            for j in 0..n {
+                for i in 0..j {
+                    assert!(factors.read(i, j) == a.read(i, j));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        let n = 13;
+        let mut a = random_hpd(n);
+        a.write(9, 9, c64::new(-1e6, 0.0));
+        let mut storage = MemoryStorage::new(a, 4);
+        match cholesky_in_place(&mut storage, Parallelism::Rayon(2)) {
+            Err(OutOfCoreError::NotPositiveDefinite(err)) => {
+                assert!(err.non_positive_definite_minor == 10)
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_qr() {
+        for (m, n, nb) in [
+            (1, 3, 4),
+            (3, 1, 4),
+            (13, 13, 4),
+            (30, 11, 4),
+            (11, 30, 4),
+            (25, 19, 32),
+        ] {
+            let a = Mat::<c64>::from_fn(m, n, |i, j| {
+                c64::new(
+                    ((i * 7 + j * 3) % 11) as f64 - 5.0,
+                    ((i + 2 * j) % 5) as f64,
+                )
+            });
+            let mut storage = MemoryStorage::new(a.clone(), nb);
+            let householder_factor = qr_in_place(&mut storage, Parallelism::Rayon(2)).unwrap();
+            let factors = storage.into_inner();
+
+            // Q * R == A
+            let mut qr = Mat::<c64>::from_fn(m, n, |i, j| {
+                if i <= j {
+                    factors.read(i, j)
+                } else {
+                    c64::new(0.0, 0.0)
+                }
+            });
+            let size = Ord::min(m, n);
+            householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                factors.as_ref().subcols(0, size),
+                householder_factor.as_ref(),
+                Conj::No,
+                qr.as_mut(),
+                Parallelism::None,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    householder::apply_block_householder_sequence_on_the_left_in_place_req::<c64>(
+                        m,
+                        householder_factor.nrows(),
+                        n,
+                    )
+                    .unwrap(),
+                )),
+            );
+            assert!((&qr - &a).norm_max() < 1e-9);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_file_storage() {
+        let (n, nb) = (23, 8);
+        let a = random_hpd(n);
+        let path =
+            std::env::temp_dir().join(format!("faer_out_of_core_test_{}.bin", std::process::id()));
+
+        let mut storage = FileStorage::from_mat(&path, a.as_ref(), nb).unwrap();
+        assert!(storage.to_mat().unwrap() == a);
+        cholesky_in_place(&mut storage, Parallelism::None).unwrap();
+        drop(storage);
+
+        let storage = FileStorage::<c64>::open(&path, n, n, nb).unwrap();
+        let mut reference = MemoryStorage::new(a, nb);
+        cholesky_in_place(&mut reference, Parallelism::None).unwrap();
+        let factors = storage.to_mat().unwrap();
+        let target = reference.into_inner();
+        for j in 0..n {
+            for i in j..n {
+                assert!((factors.read(i, j) - target.read(i, j)).faer_abs() < 1e-12);
+            }
+        }
+
+        assert!(FileStorage::<c64>::open(&path, n + 1, n, nb).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,428 @@
+//! Reusable factorization plans.
+//!
+//! The low level factorizations in [`linalg`](crate::linalg) take their workspace as a
+//! [`PodStack`], whose size must be queried from the corresponding `_req` function, then
+//! allocated. When the same factorization is computed many times for matrices of the same
+//! dimensions, e.g. in the inner loop of a simulation, a plan can be created once for the given
+//! shape and parameters, and then executed repeatedly without recomputing the workspace
+//! requirements or allocating any memory.
+//!
+//! ```
+//! use faer::{linalg::plan::CholeskyPlan, mat, Parallelism};
+//!
+//! let mut plan = CholeskyPlan::<f64>::new(2, Parallelism::None, Default::default());
+//!
+//! for shift in [1.0, 2.0, 3.0] {
+//!     let mut a = mat![[4.0 + shift, 1.0], [1.0, 3.0 + shift]];
+//!     plan.execute(a.as_mut()).unwrap();
+//!     let l = a.as_ref();
+//!     assert!((l.read(0, 0) * l.read(0, 0) - (4.0 + shift)).abs() < 1e-12);
+//! }
+//! ```
+
+use crate::{
+    assert,
+    linalg::{
+        cholesky::llt::{
+            compute::{self as llt, LltInfo, LltParams, LltRegularization},
+            CholeskyError,
+        },
+        lu::partial_pivoting::compute::{self as lu, PartialPivLuComputeParams, PartialPivLuInfo},
+        qr::no_pivoting::compute::{self as qr, QrComputeParams},
+    },
+    mat::{MatMut, MatRef},
+    perm::PermRef,
+    ComplexField, Entity, Index, Mat, Parallelism,
+};
+use core::marker::PhantomData;
+use dyn_stack::{GlobalPodBuffer, PodStack};
+
+/// Plan for computing the Cholesky factorization of hermitian positive definite matrices of a
+/// fixed dimension, using [`llt::cholesky_in_place`].
+pub struct CholeskyPlan<E: ComplexField> {
+    dim: usize,
+    parallelism: Parallelism,
+    params: LltParams,
+    regularization: LltRegularization<E>,
+    mem: GlobalPodBuffer,
+}
+
+impl<E: ComplexField> CholeskyPlan<E> {
+    /// Creates a plan for factorizing matrices of dimension `dim×dim`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required workspace size overflows.
+    #[track_caller]
+    pub fn new(dim: usize, parallelism: Parallelism, params: LltParams) -> Self {
+        let mem = GlobalPodBuffer::new(
+            llt::cholesky_in_place_req::<E>(dim, parallelism, params).unwrap(),
+        );
+        Self {
+            dim,
+            parallelism,
+            params,
+            regularization: LltRegularization::default(),
+            mem,
+        }
+    }
+
+    /// Sets the dynamic regularization used by the subsequent executions of the plan.
+    #[inline]
+    pub fn with_regularization(self, regularization: LltRegularization<E>) -> Self {
+        Self {
+            regularization,
+            ..self
+        }
+    }
+
+    /// Returns the dimension of the matrices accepted by the plan.
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Computes the Cholesky factor of `matrix` in place. See [`llt::cholesky_in_place`] for more
+    /// details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not of dimension `self.dim()×self.dim()`.
+    #[track_caller]
+    pub fn execute(&mut self, matrix: MatMut<'_, E>) -> Result<LltInfo, CholeskyError> {
+        assert!(all(matrix.nrows() == self.dim, matrix.ncols() == self.dim));
+        llt::cholesky_in_place(
+            matrix,
+            self.regularization,
+            self.parallelism,
+            PodStack::new(&mut self.mem),
+            self.params,
+        )
+    }
+}
+
+/// Plan for computing the QR factorization of matrices of a fixed shape, using
+/// [`qr::qr_in_place`].
+///
+/// The plan owns the Householder factor that is filled by each execution.
+pub struct QrPlan<E: ComplexField> {
+    nrows: usize,
+    ncols: usize,
+    parallelism: Parallelism,
+    params: QrComputeParams,
+    householder_factor: Mat<E>,
+    mem: GlobalPodBuffer,
+}
+
+impl<E: ComplexField> QrPlan<E> {
+    /// Creates a plan for factorizing matrices of dimension `nrows×ncols`, with the block size
+    /// given by [`qr::recommended_blocksize`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required workspace size overflows.
+    #[track_caller]
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        parallelism: Parallelism,
+        params: QrComputeParams,
+    ) -> Self {
+        let blocksize = qr::recommended_blocksize::<E>(nrows, ncols);
+        Self::with_blocksize(nrows, ncols, blocksize, parallelism, params)
+    }
+
+    /// Creates a plan for factorizing matrices of dimension `nrows×ncols`, with the given block
+    /// size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `blocksize` is zero, or if the required workspace size overflows.
+    #[track_caller]
+    pub fn with_blocksize(
+        nrows: usize,
+        ncols: usize,
+        blocksize: usize,
+        parallelism: Parallelism,
+        params: QrComputeParams,
+    ) -> Self {
+        assert!(blocksize > 0);
+        let size = Ord::min(nrows, ncols);
+        let mem = GlobalPodBuffer::new(
+            qr::qr_in_place_req::<E>(nrows, ncols, blocksize, parallelism, params).unwrap(),
+        );
+        Self {
+            nrows,
+            ncols,
+            parallelism,
+            params,
+            householder_factor: Mat::zeros(blocksize, size),
+            mem,
+        }
+    }
+
+    /// Returns the number of rows of the matrices accepted by the plan.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns of the matrices accepted by the plan.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Computes the QR factorization of `matrix` in place, and returns a view over the
+    /// corresponding Householder factor. See [`qr::qr_in_place`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not of dimension `self.nrows()×self.ncols()`.
+    #[track_caller]
+    pub fn execute(&mut self, matrix: MatMut<'_, E>) -> MatRef<'_, E> {
+        assert!(all(
+            matrix.nrows() == self.nrows,
+            matrix.ncols() == self.ncols
+        ));
+        qr::qr_in_place(
+            matrix,
+            self.householder_factor.as_mut(),
+            self.parallelism,
+            PodStack::new(&mut self.mem),
+            self.params,
+        );
+        self.householder_factor.as_ref()
+    }
+
+    /// Returns a view over the Householder factor computed by the last execution of the plan.
+    #[inline]
+    pub fn householder_factor(&self) -> MatRef<'_, E> {
+        self.householder_factor.as_ref()
+    }
+}
+
+/// Plan for computing the LU factorization with partial pivoting of matrices of a fixed shape,
+/// using [`lu::lu_in_place`].
+///
+/// The plan owns the row permutation that is filled by each execution.
+pub struct PartialPivLuPlan<I: Index, E: Entity> {
+    nrows: usize,
+    ncols: usize,
+    parallelism: Parallelism,
+    params: PartialPivLuComputeParams,
+    perm: alloc::vec::Vec<I>,
+    perm_inv: alloc::vec::Vec<I>,
+    mem: GlobalPodBuffer,
+    __marker: PhantomData<E>,
+}
+
+impl<I: Index, E: ComplexField> PartialPivLuPlan<I, E> {
+    /// Creates a plan for factorizing matrices of dimension `nrows×ncols`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required workspace size overflows.
+    #[track_caller]
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        parallelism: Parallelism,
+        params: PartialPivLuComputeParams,
+    ) -> Self {
+        let mem = GlobalPodBuffer::new(
+            lu::lu_in_place_req::<I, E>(nrows, ncols, parallelism, params).unwrap(),
+        );
+        Self {
+            nrows,
+            ncols,
+            parallelism,
+            params,
+            perm: alloc::vec![I::truncate(0); nrows],
+            perm_inv: alloc::vec![I::truncate(0); nrows],
+            mem,
+            __marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of rows of the matrices accepted by the plan.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns of the matrices accepted by the plan.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Computes the LU factorization of `matrix` in place, and returns the factorization info
+    /// along with the row permutation. See [`lu::lu_in_place`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not of dimension `self.nrows()×self.ncols()`.
+    #[track_caller]
+    pub fn execute(&mut self, matrix: MatMut<'_, E>) -> (PartialPivLuInfo, PermRef<'_, I>) {
+        assert!(all(
+            matrix.nrows() == self.nrows,
+            matrix.ncols() == self.ncols
+        ));
+        lu::lu_in_place(
+            matrix,
+            &mut self.perm,
+            &mut self.perm_inv,
+            self.parallelism,
+            PodStack::new(&mut self.mem),
+            self.params,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::matmul::matmul};
+    use assert_approx_eq::assert_approx_eq;
+
+    type E = c64;
+
+    fn random_mat(nrows: usize, ncols: usize) -> Mat<E> {
+        Mat::from_fn(nrows, ncols, |_, _| E::new(rand::random(), rand::random()))
+    }
+
+    #[test]
+    fn test_cholesky_plan() {
+        let n = 47;
+        let mut plan = CholeskyPlan::<E>::new(n, Parallelism::Rayon(4), Default::default());
+        assert!(plan.dim() == n);
+
+        for _ in 0..3 {
+            let b = random_mat(n, n);
+            let mut a = Mat::<E>::zeros(n, n);
+            matmul(
+                a.as_mut(),
+                b.adjoint(),
+                b.as_ref(),
+                None,
+                E::faer_one(),
+                Parallelism::None,
+            );
+
+            let mut l = a.clone();
+            plan.execute(l.as_mut()).unwrap();
+
+            let mut expected = a.clone();
+            llt::cholesky_in_place(
+                expected.as_mut(),
+                Default::default(),
+                Parallelism::None,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    llt::cholesky_in_place_req::<E>(n, Parallelism::None, Default::default())
+                        .unwrap(),
+                )),
+                Default::default(),
+            )
+            .unwrap();
+
+            for j in 0..n {
+                for i in j..n {
+                    assert_approx_eq!(l.read(i, j), expected.read(i, j));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_plan() {
+        for (m, n) in [(50, 30), (30, 50), (64, 64)] {
+            let mut plan = QrPlan::<E>::new(m, n, Parallelism::Rayon(4), Default::default());
+            assert!(all(plan.nrows() == m, plan.ncols() == n));
+
+            for _ in 0..3 {
+                let a = random_mat(m, n);
+                let mut qr = a.clone();
+                plan.execute(qr.as_mut());
+
+                let mut expected = a.clone();
+                let blocksize = qr::recommended_blocksize::<E>(m, n);
+                let mut householder_factor = Mat::<E>::zeros(blocksize, Ord::min(m, n));
+                qr::qr_in_place(
+                    expected.as_mut(),
+                    householder_factor.as_mut(),
+                    Parallelism::None,
+                    PodStack::new(&mut GlobalPodBuffer::new(
+                        qr::qr_in_place_req::<E>(
+                            m,
+                            n,
+                            blocksize,
+                            Parallelism::None,
+                            Default::default(),
+                        )
+                        .unwrap(),
+                    )),
+                    Default::default(),
+                );
+
+                for j in 0..n {
+                    for i in 0..m {
+                        assert_approx_eq!(qr.read(i, j), expected.read(i, j));
+                    }
+                }
+                let factor = plan.householder_factor();
+                for j in 0..factor.ncols() {
+                    for i in 0..Ord::min(blocksize, j % blocksize + 1) {
+                        assert_approx_eq!(factor.read(i, j), householder_factor.read(i, j));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_plan() {
+        let n = 40;
+        let mut plan =
+            PartialPivLuPlan::<usize, E>::new(n, n, Parallelism::Rayon(4), Default::default());
+
+        for _ in 0..3 {
+            let a = random_mat(n, n);
+            let mut lu = a.clone();
+            let (_, perm) = plan.execute(lu.as_mut());
+
+            let l = Mat::from_fn(n, n, |i, j| {
+                if i > j {
+                    lu.read(i, j)
+                } else if i == j {
+                    E::faer_one()
+                } else {
+                    E::faer_zero()
+                }
+            });
+            let u = Mat::from_fn(n, n, |i, j| {
+                if i <= j {
+                    lu.read(i, j)
+                } else {
+                    E::faer_zero()
+                }
+            });
+            let mut reconstructed = Mat::<E>::zeros(n, n);
+            matmul(
+                reconstructed.as_mut(),
+                l.as_ref(),
+                u.as_ref(),
+                None,
+                E::faer_one(),
+                Parallelism::None,
+            );
+
+            let fwd = perm.arrays().0;
+            for j in 0..n {
+                for i in 0..n {
+                    assert_approx_eq!(reconstructed.read(i, j), a.read(fwd[i], j));
+                }
+            }
+        }
+    }
+}
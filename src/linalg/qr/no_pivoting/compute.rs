@@ -1,12 +1,17 @@
 use crate::{
     assert,
+    complex_native::c32,
     linalg::{
         householder::{
             self, apply_block_householder_transpose_on_the_left_in_place_with_conj,
-            upgrade_householder_factor,
+            apply_householder_on_the_left_c32_req, upgrade_householder_factor_with_params,
+            HouseholderBlockingParams,
         },
-        matmul::inner_prod::{self, inner_prod_with_conj_arch},
-        temp_mat_req,
+        matmul::{
+            inner_prod::{self, inner_prod_with_conj_arch},
+            split_c32::SPLIT_C32_MIN_NCOLS,
+        },
+        temp_mat_req, PanelScheduler,
     },
     unzipped,
     utils::{simd::*, slice::*},
@@ -19,7 +24,7 @@ use reborrow::*;
 fn qr_in_place_unblocked<E: ComplexField>(
     mut matrix: MatMut<'_, E>,
     mut householder_factor: MatMut<'_, E>,
-    _stack: PodStack<'_>,
+    mut stack: PodStack<'_>,
 ) {
     let m = matrix.nrows();
     let n = matrix.ncols();
@@ -47,7 +52,22 @@ fn qr_in_place_unblocked<E: ComplexField>(
 
         first_col_head.write(0, beta);
 
-        if row_stride == 1 {
+        if row_stride == 1
+            && coe::is_same::<E, c32>()
+            && last_cols.ncols() >= SPLIT_C32_MIN_NCOLS
+            && matches!(
+                apply_householder_on_the_left_c32_req::<E>(m - k),
+                Ok(req) if stack.can_hold(req)
+            )
+        {
+            householder::apply_householder_on_the_left_c32(
+                coe::coerce_static(tau_inv),
+                coe::coerce(first_col_tail.rb().as_2d()),
+                Conj::No,
+                coe::coerce(last_cols),
+                stack.rb_mut(),
+            );
+        } else if row_stride == 1 {
             struct TrailingColsUpdate<'a, E: ComplexField> {
                 tau_inv: E,
                 first_col_tail: MatRef<'a, E>,
@@ -196,6 +216,10 @@ pub struct QrComputeParams {
     /// At which size the parallelism should be disabled. `None` to automatically determine this
     /// threshold.
     pub disable_parallelism: Option<fn(nrows: usize, ncols: usize) -> bool>,
+    /// Block size schedule and recursion threshold used when computing the Householder factors.
+    pub blocking: HouseholderBlockingParams,
+    /// Scheduling strategy for the block factorizations and trailing updates.
+    pub scheduler: PanelScheduler,
 }
 
 impl QrComputeParams {
@@ -208,6 +232,49 @@ impl QrComputeParams {
     }
 }
 
+// factors a block of columns of width `blocksize` (or less, for the last block), and computes its
+// block householder factor, where `disable_blocking` is evaluated on the dimensions of the
+// remaining submatrix
+fn qr_in_place_panel<E: ComplexField>(
+    mut current_block: MatMut<'_, E>,
+    mut householder_factor: MatMut<'_, E>,
+    blocksize: usize,
+    disable_blocking: bool,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: QrComputeParams,
+) {
+    let (inner_blocksize, _) = params.blocking.normalize();
+    let prev_blocksize = if disable_blocking {
+        1
+    } else {
+        inner_blocksize(blocksize)
+    };
+    assert!(all(
+        prev_blocksize > 0,
+        prev_blocksize < blocksize,
+        blocksize % prev_blocksize == 0,
+    ));
+
+    qr_in_place_blocked(
+        current_block.rb_mut(),
+        householder_factor.rb_mut(),
+        prev_blocksize,
+        parallelism,
+        stack,
+        params,
+    );
+
+    upgrade_householder_factor_with_params(
+        householder_factor.rb_mut(),
+        current_block.rb(),
+        blocksize,
+        prev_blocksize,
+        parallelism,
+        params.blocking,
+    );
+}
+
 fn qr_in_place_blocked<E: ComplexField>(
     matrix: MatMut<'_, E>,
     householder_factor: MatMut<'_, E>,
@@ -253,33 +320,20 @@ fn qr_in_place_blocked<E: ComplexField>(
 
         let (mut current_block, mut trailing_cols) = matrix.rb_mut().split_at_col_mut(bs);
 
-        let prev_blocksize = if disable_blocking(m, n) || blocksize <= 4 || blocksize % 2 != 0 {
-            1
-        } else {
-            blocksize / 2
-        };
-
         if parallelism != Parallelism::None && disable_parallelism(m, n) {
             parallelism = Parallelism::None
         }
 
-        qr_in_place_blocked(
+        qr_in_place_panel(
             current_block.rb_mut(),
             householder_factor.rb_mut(),
-            prev_blocksize,
+            blocksize,
+            disable_blocking(m, n),
             parallelism,
             stack.rb_mut(),
             params,
         );
 
-        upgrade_householder_factor(
-            householder_factor.rb_mut(),
-            current_block.rb(),
-            blocksize,
-            prev_blocksize,
-            parallelism,
-        );
-
         if trailing_cols.ncols() > 0 {
             apply_block_householder_transpose_on_the_left_in_place_with_conj(
                 current_block.rb(),
@@ -295,6 +349,141 @@ fn qr_in_place_blocked<E: ComplexField>(
     }
 }
 
+// same as `qr_in_place_blocked`, but the next block is updated and factored while the rest of the
+// trailing columns are updated concurrently
+fn qr_in_place_lookahead<E: ComplexField>(
+    matrix: MatMut<'_, E>,
+    householder_factor: MatMut<'_, E>,
+    blocksize: usize,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: QrComputeParams,
+) {
+    let mut matrix = matrix;
+    let mut householder_factor = householder_factor;
+    let mut parallelism = parallelism;
+    let m = matrix.nrows();
+    let n = matrix.ncols();
+    let size = Ord::min(m, n);
+
+    let (disable_blocking, disable_parallelism) = params.normalize();
+
+    if parallelism != Parallelism::None && disable_parallelism(m, n) {
+        parallelism = Parallelism::None
+    }
+
+    // the concurrent update of the trailing columns gets its own workspace
+    let req = temp_mat_req::<E>(blocksize, n)
+        .and_then(|req| req.try_or(apply_householder_on_the_left_c32_req::<E>(m)?))
+        .unwrap();
+    let (trailing_stack, mut stack) =
+        stack.make_aligned_raw::<u8>(req.size_bytes(), req.align_bytes());
+    let mut trailing_stack = PodStack::new(trailing_stack);
+
+    let householder_is_full_matrix = householder_factor.nrows() == householder_factor.ncols();
+    let factor_row = |j: usize| if householder_is_full_matrix { j } else { 0 };
+
+    let bs = Ord::min(blocksize, size);
+    qr_in_place_panel(
+        matrix.rb_mut().subcols_mut(0, bs),
+        householder_factor.rb_mut().submatrix_mut(0, 0, bs, bs),
+        blocksize,
+        disable_blocking(m, n),
+        parallelism,
+        stack.rb_mut(),
+        params,
+    );
+
+    let mut j = 0;
+    while j + bs < size {
+        let j_next = j + bs;
+        let bs_next = Ord::min(blocksize, size - j_next);
+
+        if parallelism != Parallelism::None && disable_parallelism(m - j_next, n - j_next) {
+            parallelism = Parallelism::None
+        }
+        let next_disable_blocking = disable_blocking(m - j_next, n - j_next);
+
+        let (current_block, right) = matrix
+            .rb_mut()
+            .submatrix_mut(j, j, m - j, n - j)
+            .split_at_col_mut(bs);
+        let (next_block, trailing_cols) = right.split_at_col_mut(bs_next);
+        let current_block = current_block.into_const();
+
+        let (factor_left, factor_right) = householder_factor.rb_mut().split_at_col_mut(j_next);
+        let current_factor = factor_left.into_const().submatrix(factor_row(j), j, bs, bs);
+        let next_factor = factor_right.submatrix_mut(factor_row(j_next), 0, bs_next, bs_next);
+
+        // estimated flop counts of the two tasks, used to split the threads between them
+        let rows = (m - j) as f64;
+        let next_work = rows * bs_next as f64 * (bs + bs_next) as f64;
+        let trailing_work = rows * trailing_cols.ncols() as f64 * bs as f64;
+
+        {
+            let stack = stack.rb_mut();
+            let trailing_stack = trailing_stack.rb_mut();
+            crate::utils::thread::join_raw_weighted(
+                move |parallelism| {
+                    let mut next_block = next_block;
+                    let mut stack = stack;
+                    apply_block_householder_transpose_on_the_left_in_place_with_conj(
+                        current_block,
+                        current_factor,
+                        Conj::Yes,
+                        next_block.rb_mut(),
+                        parallelism,
+                        stack.rb_mut(),
+                    );
+                    qr_in_place_panel(
+                        next_block.subrows_mut(bs, m - j_next),
+                        next_factor,
+                        blocksize,
+                        next_disable_blocking,
+                        parallelism,
+                        stack,
+                        params,
+                    );
+                },
+                |parallelism| {
+                    if trailing_cols.ncols() > 0 {
+                        apply_block_householder_transpose_on_the_left_in_place_with_conj(
+                            current_block,
+                            current_factor,
+                            Conj::Yes,
+                            trailing_cols,
+                            parallelism,
+                            trailing_stack,
+                        );
+                    }
+                },
+                next_work,
+                trailing_work,
+                parallelism,
+            );
+        }
+
+        j = j_next;
+    }
+
+    // the last block still needs to be applied to the columns past `size`
+    let bs = size - j;
+    if n > size {
+        let (current_block, trailing_cols) = matrix
+            .rb_mut()
+            .submatrix_mut(j, j, m - j, n - j)
+            .split_at_col_mut(bs);
+        apply_block_householder_transpose_on_the_left_in_place_with_conj(
+            current_block.rb(),
+            householder_factor.rb().submatrix(factor_row(j), j, bs, bs),
+            Conj::Yes,
+            trailing_cols,
+            parallelism,
+            stack,
+        );
+    }
+}
+
 /// Computes the QR decomposition of a rectangular matrix $A$, into a unitary matrix $Q$,
 /// represented as a block Householder sequence, and an upper trapezoidal matrix $R$, such that
 /// $$A = QR.$$
@@ -327,6 +516,18 @@ pub fn qr_in_place<E: ComplexField>(
         householder_factor.ncols() == size,
     ));
 
+    #[cfg(feature = "instrument")]
+    let _scope = {
+        use crate::utils::instrument::*;
+        let (m, n) = (matrix.nrows() as f64, matrix.ncols() as f64);
+        let (big, small) = if m >= n { (m, n) } else { (n, m) };
+        Scope::new(
+            "qr",
+            flops::<E>(2.0 * big * small * small - 2.0 * small * small * small / 3.0),
+            bytes::<E>(2 * matrix.nrows() * matrix.ncols() + blocksize * size),
+        )
+    };
+
     #[cfg(feature = "perf-warn")]
     if matrix.row_stride().unsigned_abs() != 1 && crate::__perf_warn!(QR_WARN) {
         if matrix.col_stride().unsigned_abs() == 1 {
@@ -338,6 +539,15 @@ pub fn qr_in_place<E: ComplexField>(
 
     if blocksize == 1 {
         qr_in_place_unblocked(matrix, householder_factor.transpose_mut(), stack);
+    } else if let PanelScheduler::Lookahead { .. } = params.scheduler {
+        qr_in_place_lookahead(
+            matrix,
+            householder_factor,
+            blocksize,
+            parallelism,
+            stack,
+            params,
+        );
     } else {
         qr_in_place_blocked(
             matrix,
@@ -361,9 +571,12 @@ pub fn qr_in_place_req<E: Entity>(
     params: QrComputeParams,
 ) -> Result<StackReq, SizeOverflow> {
     let _ = parallelism;
-    let _ = nrows;
-    let _ = &params;
-    temp_mat_req::<E>(blocksize, ncols)
+    let req = temp_mat_req::<E>(blocksize, ncols)?
+        .try_or(apply_householder_on_the_left_c32_req::<E>(nrows)?)?;
+    match params.scheduler {
+        PanelScheduler::Lookahead { .. } if blocksize > 1 => StackReq::try_all_of([req, req]),
+        _ => Ok(req),
+    }
 }
 
 #[cfg(test)]
@@ -576,6 +789,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lookahead() {
+        for parallelism in [Parallelism::None, Parallelism::Rayon(8)] {
+            for (m, n, blocksize) in [
+                (7, 5, 2),
+                (5, 7, 2),
+                (20, 20, 4),
+                (37, 64, 8),
+                (64, 37, 8),
+                (100, 100, 16),
+                (150, 100, 32),
+            ] {
+                let mat_orig = Mat::from_fn(m, n, |_, _| random_value());
+                let size = m.min(n);
+
+                let mut fork_join = mat_orig.clone();
+                let mut fork_join_householder = Mat::zeros(blocksize, size);
+                qr_in_place(
+                    fork_join.as_mut(),
+                    fork_join_householder.as_mut(),
+                    parallelism,
+                    make_stack!(qr_in_place_req::<E>(
+                        m,
+                        n,
+                        blocksize,
+                        parallelism,
+                        Default::default(),
+                    )),
+                    Default::default(),
+                );
+
+                let mut params = QrComputeParams::default();
+                params.scheduler = PanelScheduler::Lookahead { block_size: 0 };
+                let mut lookahead = mat_orig.clone();
+                let mut lookahead_householder = Mat::zeros(blocksize, size);
+                qr_in_place(
+                    lookahead.as_mut(),
+                    lookahead_householder.as_mut(),
+                    parallelism,
+                    make_stack!(qr_in_place_req::<E>(m, n, blocksize, parallelism, params)),
+                    params,
+                );
+
+                for j in 0..n {
+                    for i in 0..m {
+                        assert_approx_eq!(lookahead.read(i, j), fork_join.read(i, j));
+                    }
+                }
+                for j in 0..size {
+                    for i in 0..blocksize {
+                        assert_approx_eq!(
+                            lookahead_householder.read(i, j),
+                            fork_join_householder.read(i, j)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_zero() {
         for parallelism in [Parallelism::None, Parallelism::Rayon(0)] {
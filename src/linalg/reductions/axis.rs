@@ -0,0 +1,278 @@
+//! Reductions of a matrix along its rows or columns.
+//!
+//! The `row_*` functions reduce each row of the matrix to a single value, and return a column
+//! with one element per row. The `col_*` functions reduce each column of the matrix, and return a
+//! row with one element per column.
+
+use crate::{
+    col::{Col, ColMut},
+    mat::MatRef,
+    row::Row,
+    utils::thread::{for_each_raw, par_split_indices, parallelism_degree},
+    ComplexField, Entity, Parallelism, RealField,
+};
+use reborrow::*;
+
+fn parallelism_count(nrows: usize, ncols: usize, parallelism: Parallelism) -> usize {
+    if ncols > 1 && nrows.saturating_mul(ncols) >= 128 * 128 {
+        Ord::min(parallelism_degree(parallelism), ncols)
+    } else {
+        1
+    }
+}
+
+// folds each column of `mat` into the corresponding element of `out`
+fn fold_cols_impl<E: Entity, T: Entity>(
+    mat: MatRef<'_, E>,
+    init: T,
+    f: &(impl Sync + Fn(T, E) -> T),
+    out: ColMut<'_, T>,
+    parallelism: Parallelism,
+) {
+    let m = mat.nrows();
+    let n = mat.ncols();
+    let par = parallelism_count(m, n, parallelism);
+    let out = out.into_const();
+
+    for_each_raw(
+        par,
+        |tid| {
+            let (col_start, ncols) = par_split_indices(n, tid, par);
+            let mat = mat.subcols(col_start, ncols);
+            let mut out = unsafe { out.subrows(col_start, ncols).const_cast() };
+
+            if mat.row_stride().unsigned_abs() <= mat.col_stride().unsigned_abs() {
+                for j in 0..ncols {
+                    let mut acc = init;
+                    for i in 0..m {
+                        acc = f(acc, mat.read(i, j));
+                    }
+                    out.write(j, acc);
+                }
+            } else {
+                // the rows are contiguous, so the columns are accumulated simultaneously
+                for j in 0..ncols {
+                    out.write(j, init);
+                }
+                for i in 0..m {
+                    for j in 0..ncols {
+                        out.write(j, f(out.read(j), mat.read(i, j)));
+                    }
+                }
+            }
+        },
+        parallelism,
+    );
+}
+
+// sums each column of `mat` into the corresponding element of `out`
+fn sum_cols_impl<E: ComplexField>(
+    mat: MatRef<'_, E>,
+    out: ColMut<'_, E>,
+    parallelism: Parallelism,
+) {
+    let m = mat.nrows();
+    let n = mat.ncols();
+
+    if m > 1 && mat.row_stride().unsigned_abs() <= mat.col_stride().unsigned_abs() {
+        let par = parallelism_count(m, n, parallelism);
+        let out = out.into_const();
+
+        for_each_raw(
+            par,
+            |tid| {
+                let (col_start, ncols) = par_split_indices(n, tid, par);
+                let mut out = unsafe { out.subrows(col_start, ncols).const_cast() };
+                for j in 0..ncols {
+                    let col = mat.col(col_start + j).as_2d();
+                    out.write(j, super::sum::sum(col));
+                }
+            },
+            parallelism,
+        );
+    } else {
+        fold_cols_impl(
+            mat,
+            E::faer_zero(),
+            &|acc: E, x: E| acc.faer_add(x),
+            out,
+            parallelism,
+        );
+    }
+}
+
+fn max<E: RealField>(acc: E, x: E) -> E {
+    if acc.faer_is_nan() || x > acc {
+        x
+    } else {
+        acc
+    }
+}
+
+fn min<E: RealField>(acc: E, x: E) -> E {
+    if acc.faer_is_nan() || x < acc {
+        x
+    } else {
+        acc
+    }
+}
+
+fn scale_by_inv_len<E: ComplexField>(mut values: ColMut<'_, E>, len: usize) {
+    let inv = E::Real::faer_from_f64(len as f64).faer_inv();
+    for i in 0..values.nrows() {
+        let x = values.read(i);
+        values.write(i, x.faer_scale_real(inv));
+    }
+}
+
+/// Folds each row of `mat` using `f`, starting from `init`, and returns a column containing the
+/// result for each row.
+///
+/// The elements of each row are visited in increasing column order.
+pub fn fold_rows<E: Entity, T: Entity>(
+    mat: MatRef<'_, E>,
+    init: T,
+    f: impl Sync + Fn(T, E) -> T,
+    parallelism: Parallelism,
+) -> Col<T> {
+    let mut out = Col::<T>::from_fn(mat.nrows(), |_| init);
+    fold_cols_impl(mat.transpose(), init, &f, out.as_mut(), parallelism);
+    out
+}
+
+/// Folds each column of `mat` using `f`, starting from `init`, and returns a row containing the
+/// result for each column.
+///
+/// The elements of each column are visited in increasing row order.
+pub fn fold_cols<E: Entity, T: Entity>(
+    mat: MatRef<'_, E>,
+    init: T,
+    f: impl Sync + Fn(T, E) -> T,
+    parallelism: Parallelism,
+) -> Row<T> {
+    let mut out = Row::<T>::from_fn(mat.ncols(), |_| init);
+    fold_cols_impl(mat, init, &f, out.as_mut().transpose_mut(), parallelism);
+    out
+}
+
+/// Returns a column containing the sum of each row of `mat`.
+pub fn row_sum<E: ComplexField>(mat: MatRef<'_, E>, parallelism: Parallelism) -> Col<E> {
+    let mut out = Col::<E>::zeros(mat.nrows());
+    sum_cols_impl(mat.transpose(), out.as_mut(), parallelism);
+    out
+}
+
+/// Returns a row containing the sum of each column of `mat`.
+pub fn col_sum<E: ComplexField>(mat: MatRef<'_, E>, parallelism: Parallelism) -> Row<E> {
+    let mut out = Row::<E>::zeros(mat.ncols());
+    sum_cols_impl(mat, out.as_mut().transpose_mut(), parallelism);
+    out
+}
+
+/// Returns a column containing the mean of each row of `mat`.
+///
+/// If `mat` has no columns, the means are NaN.
+pub fn row_mean<E: ComplexField>(mat: MatRef<'_, E>, parallelism: Parallelism) -> Col<E> {
+    let mut out = row_sum(mat, parallelism);
+    scale_by_inv_len(out.as_mut(), mat.ncols());
+    out
+}
+
+/// Returns a row containing the mean of each column of `mat`.
+///
+/// If `mat` has no rows, the means are NaN.
+pub fn col_mean<E: ComplexField>(mat: MatRef<'_, E>, parallelism: Parallelism) -> Row<E> {
+    let mut out = col_sum(mat, parallelism);
+    scale_by_inv_len(out.as_mut().transpose_mut(), mat.nrows());
+    out
+}
+
+/// Returns a column containing the maximum of each row of `mat`.
+///
+/// NaN values are ignored, unless all the elements of the row are NaN, or the row is empty, in
+/// which case the result is NaN.
+pub fn row_max<E: RealField>(mat: MatRef<'_, E>, parallelism: Parallelism) -> Col<E> {
+    fold_rows(mat, E::faer_nan(), max, parallelism)
+}
+
+/// Returns a column containing the minimum of each row of `mat`.
+///
+/// NaN values are ignored, unless all the elements of the row are NaN, or the row is empty, in
+/// which case the result is NaN.
+pub fn row_min<E: RealField>(mat: MatRef<'_, E>, parallelism: Parallelism) -> Col<E> {
+    fold_rows(mat, E::faer_nan(), min, parallelism)
+}
+
+/// Returns a row containing the maximum of each column of `mat`.
+///
+/// NaN values are ignored, unless all the elements of the column are NaN, or the column is empty,
+/// in which case the result is NaN.
+pub fn col_max<E: RealField>(mat: MatRef<'_, E>, parallelism: Parallelism) -> Row<E> {
+    fold_cols(mat, E::faer_nan(), max, parallelism)
+}
+
+/// Returns a row containing the minimum of each column of `mat`.
+///
+/// NaN values are ignored, unless all the elements of the column are NaN, or the column is empty,
+/// in which case the result is NaN.
+pub fn col_min<E: RealField>(mat: MatRef<'_, E>, parallelism: Parallelism) -> Row<E> {
+    fold_cols(mat, E::faer_nan(), min, parallelism)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, col, mat, row, Mat};
+
+    #[test]
+    fn test_axis_reductions() {
+        let a = mat![[1.0, 5.0, 3.0], [4.0, 2.0, 6.0f64]];
+
+        for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+            for a in [a.as_ref(), a.transpose().to_owned().as_ref().transpose()] {
+                assert!(row_sum(a, parallelism) == col![9.0, 12.0]);
+                assert!(col_sum(a, parallelism) == row![5.0, 7.0, 9.0]);
+                assert!(row_mean(a, parallelism) == col![3.0, 4.0]);
+                assert!(col_mean(a, parallelism) == row![2.5, 3.5, 4.5]);
+                assert!(row_max(a, parallelism) == col![5.0, 6.0]);
+                assert!(row_min(a, parallelism) == col![1.0, 2.0]);
+                assert!(col_max(a, parallelism) == row![4.0, 5.0, 6.0]);
+                assert!(col_min(a, parallelism) == row![1.0, 2.0, 3.0]);
+            }
+        }
+
+        let nan = f64::NAN;
+        let b = mat![[nan, 1.0], [nan, nan]];
+        assert!(row_max(b.as_ref(), Parallelism::None).read(0) == 1.0);
+        assert!(row_max(b.as_ref(), Parallelism::None).read(1).is_nan());
+        assert!(col_min(b.as_ref(), Parallelism::None).read(0).is_nan());
+        assert!(col_min(b.as_ref(), Parallelism::None).read(1) == 1.0);
+    }
+
+    #[test]
+    fn test_fold_large() {
+        let (m, n) = (300, 200);
+        let a = Mat::from_fn(m, n, |i, j| (i + 2 * j) as f64);
+
+        for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+            for a in [a.as_ref(), a.transpose().to_owned().as_ref().transpose()] {
+                let count = fold_cols(a, 0.0, |acc, _| acc + 1.0, parallelism);
+                assert!(count == Row::from_fn(n, |_| m as f64));
+
+                // checks the traversal order
+                let first = fold_rows(
+                    a,
+                    f64::NAN,
+                    |acc, x| if acc.is_nan() { x } else { acc },
+                    parallelism,
+                );
+                assert!(first == Col::from_fn(m, |i| i as f64));
+
+                let sum = row_sum(a, parallelism);
+                assert!(sum == Col::from_fn(m, |i| (n * i + n * (n - 1)) as f64));
+                let sum = col_sum(a, parallelism);
+                assert!(sum == Row::from_fn(n, |j| (m * (m - 1) / 2 + 2 * m * j) as f64));
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{mat::MatRef, Entity, NanPolicy, RealField};
+use core::cmp::Ordering;
+
+// returns the first element in column-major order for which no later element is `better`
+fn extremum_by<E: Entity>(
+    mat: MatRef<'_, E>,
+    mut better: impl FnMut(&E, &E) -> bool,
+) -> Option<(E, usize, usize)> {
+    if mat.nrows() == 0 || mat.ncols() == 0 {
+        return None;
+    }
+
+    let mut best = (mat.read(0, 0), 0, 0);
+    for j in 0..mat.ncols() {
+        for i in 0..mat.nrows() {
+            let x = mat.read(i, j);
+            if better(&x, &best.0) {
+                best = (x, i, j);
+            }
+        }
+    }
+    Some(best)
+}
+
+pub fn max_by<E: Entity>(
+    mat: MatRef<'_, E>,
+    mut compare: impl FnMut(&E, &E) -> Ordering,
+) -> Option<(E, usize, usize)> {
+    extremum_by(mat, |x, best| compare(x, best) == Ordering::Greater)
+}
+
+pub fn min_by<E: Entity>(
+    mat: MatRef<'_, E>,
+    mut compare: impl FnMut(&E, &E) -> Ordering,
+) -> Option<(E, usize, usize)> {
+    extremum_by(mat, |x, best| compare(x, best) == Ordering::Less)
+}
+
+pub fn argmax<E: RealField>(
+    mat: MatRef<'_, E>,
+    nan_policy: NanPolicy,
+) -> Option<(E, usize, usize)> {
+    match nan_policy {
+        NanPolicy::Ignore => extremum_by(mat, |x, best| {
+            (best.faer_is_nan() && !x.faer_is_nan()) || x > best
+        }),
+        NanPolicy::Propagate => extremum_by(mat, |x, best| {
+            !best.faer_is_nan() && (x.faer_is_nan() || x > best)
+        }),
+    }
+}
+
+pub fn argmin<E: RealField>(
+    mat: MatRef<'_, E>,
+    nan_policy: NanPolicy,
+) -> Option<(E, usize, usize)> {
+    match nan_policy {
+        NanPolicy::Ignore => extremum_by(mat, |x, best| {
+            (best.faer_is_nan() && !x.faer_is_nan()) || x < best
+        }),
+        NanPolicy::Propagate => extremum_by(mat, |x, best| {
+            !best.faer_is_nan() && (x.faer_is_nan() || x < best)
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert, col, mat, row, NanPolicy};
+
+    #[test]
+    fn test_argmax() {
+        let nan = f64::NAN;
+        let a = mat![[1.0, 7.0, nan], [7.0, -2.0, 3.0]];
+
+        assert!(a.as_ref().argmax() == Some((7.0, (1, 0))));
+        assert!(a.as_ref().argmin() == Some((-2.0, (1, 1))));
+
+        let (max, (i, j)) = a.as_ref().argmax_with_policy(NanPolicy::Propagate).unwrap();
+        assert!(max.is_nan() && (i, j) == (0, 2));
+        let (min, (i, j)) = a.as_ref().argmin_with_policy(NanPolicy::Propagate).unwrap();
+        assert!(min.is_nan() && (i, j) == (0, 2));
+
+        let b = mat![[nan, nan]];
+        let (max, (i, j)) = b.as_ref().argmax().unwrap();
+        assert!(max.is_nan() && (i, j) == (0, 0));
+        assert!(a.as_ref().get(.., ..0).argmax().is_none());
+
+        let c = col![3.0, -5.0, 4.0];
+        assert!(c.as_ref().argmax() == Some((4.0, 2)));
+        assert!(c.as_ref().min_by(|x, y| x.abs().total_cmp(&y.abs())) == Some((3.0, 0)));
+        let r = row![3.0, -5.0, 4.0];
+        assert!(r.as_ref().argmin() == Some((-5.0, 1)));
+        assert!(r.as_ref().max_by(|x, y| x.abs().total_cmp(&y.abs())) == Some((-5.0, 1)));
+    }
+}
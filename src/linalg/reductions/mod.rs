@@ -1,5 +1,7 @@
 const LINEAR_IMPL_THRESHOLD: usize = 128;
 
+pub mod axis;
+pub mod extrema;
 pub mod norm_l1;
 pub mod norm_l2;
 pub mod norm_max;
@@ -0,0 +1,532 @@
+//! Iterative refinement of the solution of a linear system.
+//!
+//! Given a factorization of a square matrix `A`, the solution `x` of `A * x = b` computed from it
+//! can be improved by solving for a correction from the residual `b - A * x`, similarly to
+//! LAPACK's `xGERFS`. The residual is computed in the working precision, which doesn't improve the
+//! accuracy of `x` beyond the conditioning of `A`, but makes the solution componentwise backward
+//! stable, i.e., the exact solution of a system whose elements are close to those of the original
+//! one.
+//!
+//! The residual can optionally be accumulated in extended precision (see
+//! [`ResidualPrecision::Extended`]), in which case the refinement also improves the forward error
+//! of `x`, up to the working precision for matrices that are not too ill-conditioned.
+
+use crate::{
+    assert,
+    col::*,
+    linalg::{
+        cond::{estimate_inverse_norm_1, estimate_rcond_1},
+        solvers::SpSolver,
+    },
+    mat::*,
+    utils::compensated::CompensatedSum,
+    ComplexField, Conjugate,
+};
+use reborrow::*;
+
+const MAX_ITER: usize = 5;
+
+/// Precision used to compute the residual during iterative refinement.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ResidualPrecision {
+    /// The residual is computed in the working precision.
+    #[default]
+    Working,
+    /// The residual is accumulated in twice the working precision with compensated arithmetic
+    /// (see [`CompensatedSum`]), then rounded to the working precision.
+    ///
+    /// This is more expensive than [`ResidualPrecision::Working`] by a constant factor, but allows
+    /// the refinement to reduce the forward error of the solution of ill-conditioned systems.
+    Extended,
+}
+
+// computes `r = b - A * x` in the given precision
+pub(crate) fn residual<E: ComplexField>(
+    r: ColMut<'_, E>,
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    x: ColRef<'_, E>,
+    b: ColRef<'_, impl Conjugate<Canonical = E>>,
+    precision: ResidualPrecision,
+) {
+    let mut r = r;
+    match precision {
+        ResidualPrecision::Working => {
+            r.copy_from(b);
+            r -= matrix * x;
+        }
+        ResidualPrecision::Extended => {
+            for i in 0..matrix.nrows() {
+                let mut acc = CompensatedSum::<E>::new();
+                acc.add(b.read(i).canonicalize());
+                for j in 0..matrix.ncols() {
+                    acc.add_mul(matrix.read(i, j).canonicalize().faer_neg(), x.read(j));
+                }
+                r.write(i, acc.value());
+            }
+        }
+    }
+}
+
+/// Error bounds of the solution of a linear system computed by [`solve_with_error_bounds`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ErrorBounds<R> {
+    /// Estimate of the reciprocal of the condition number of the matrix in the 1-norm, see
+    /// [`estimate_rcond_1`].
+    pub rcond: R,
+    /// Componentwise relative backward error of each column of the solution, see
+    /// [`RefinementInfo::backward_error`].
+    pub backward_error: alloc::vec::Vec<R>,
+    /// Estimated bound on the relative forward error `‖x - x_exact‖∞ / ‖x‖∞` of each column of
+    /// the solution.
+    pub forward_error: alloc::vec::Vec<R>,
+    /// Number of refinement steps that were performed, for each column.
+    pub iter_count: alloc::vec::Vec<usize>,
+}
+
+/// Information about the refinement of the solution of a linear system, for each column of the
+/// right-hand side.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RefinementInfo<R> {
+    /// Componentwise relative backward error of each column of the solution, i.e., the smallest
+    /// `ω` such that it is the exact solution of a system `(A + δA) * x = b + δb`, with
+    /// `|δA| <= ω |A|` and `|δb| <= ω |b|` elementwise.
+    pub backward_error: alloc::vec::Vec<R>,
+    /// Estimate of the relative error of each column of the solution in the maximum norm,
+    /// computed from the last correction.
+    pub forward_error: alloc::vec::Vec<R>,
+    /// Number of refinement steps that were performed, for each column.
+    pub iter_count: alloc::vec::Vec<usize>,
+}
+
+// componentwise relative backward error of `x`, given the residual `r = b - A * x`
+fn backward_error<E: ComplexField>(
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    x: ColRef<'_, E>,
+    b: ColRef<'_, impl Conjugate<Canonical = E>>,
+    r: ColRef<'_, E>,
+) -> E::Real {
+    let n = matrix.nrows();
+    let mut error = E::Real::faer_zero();
+    for i in 0..n {
+        // (|A| |x| + |b|)_i
+        let mut denom = b.read(i).canonicalize().faer_abs();
+        for j in 0..n {
+            denom = denom.faer_add(
+                matrix
+                    .read(i, j)
+                    .canonicalize()
+                    .faer_abs()
+                    .faer_mul(x.read(j).faer_abs()),
+            );
+        }
+
+        // the residual is exactly zero when the denominator is zero
+        if denom > E::Real::faer_zero() {
+            let ratio = r.read(i).faer_abs().faer_mul(denom.faer_inv());
+            if ratio > error {
+                error = ratio;
+            }
+        }
+    }
+    error
+}
+
+/// Solves the equation `matrix * X = rhs` using the factorization `solver` of the square matrix
+/// `matrix`, and refines the solution until its componentwise relative backward error is
+/// negligible or stops decreasing, with at most `5` refinement steps for each column.
+///
+/// Returns the solution along with the backward error and an estimate of the forward error of
+/// each of its columns.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::refinement::solve_with_refinement, mat};
+///
+/// let a = mat![[4.0, 1.0], [1.0, 3.0f64]];
+/// let b = mat![[1.0], [2.0f64]];
+///
+/// let (x, info) = solve_with_refinement(a.as_ref(), &a.partial_piv_lu(), b.as_ref());
+/// assert!((&a * &x - &b).norm_max() < 1e-15);
+/// assert!(info.backward_error[0] < 1e-15);
+/// ```
+///
+/// # Panics
+/// Panics if `matrix` is not square, or if `matrix`, `solver` and `rhs` don't have the same
+/// number of rows.
+#[track_caller]
+pub fn solve_with_refinement<E: ComplexField>(
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    solver: &impl SpSolver<E>,
+    rhs: MatRef<'_, impl Conjugate<Canonical = E>>,
+) -> (Mat<E>, RefinementInfo<E::Real>) {
+    solve_with_refinement_with_precision(matrix, solver, rhs, ResidualPrecision::Working)
+}
+
+/// Solves the equation `matrix * X = rhs` using the factorization `solver` of the square matrix
+/// `matrix`, similarly to [`solve_with_refinement`], computing the residuals in the given
+/// `precision`.
+///
+/// # Example
+/// ```
+/// use faer::{
+///     linalg::refinement::{solve_with_refinement_with_precision, ResidualPrecision},
+///     Mat,
+/// };
+///
+/// let n = 8;
+/// let a = Mat::<f64>::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+/// let b = Mat::<f64>::from_fn(n, 1, |_, _| 1.0);
+///
+/// let (x, info) = solve_with_refinement_with_precision(
+///     a.as_ref(),
+///     &a.partial_piv_lu(),
+///     b.as_ref(),
+///     ResidualPrecision::Extended,
+/// );
+/// assert!(info.backward_error[0] < 1e-15);
+/// ```
+///
+/// # Panics
+/// Panics if `matrix` is not square, or if `matrix`, `solver` and `rhs` don't have the same
+/// number of rows.
+#[track_caller]
+pub fn solve_with_refinement_with_precision<E: ComplexField>(
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    solver: &impl SpSolver<E>,
+    rhs: MatRef<'_, impl Conjugate<Canonical = E>>,
+    precision: ResidualPrecision,
+) -> (Mat<E>, RefinementInfo<E::Real>) {
+    assert!(all(
+        matrix.nrows() == matrix.ncols(),
+        solver.nrows() == matrix.nrows(),
+        solver.ncols() == matrix.ncols(),
+        rhs.nrows() == matrix.nrows(),
+    ));
+
+    let n = matrix.nrows();
+    let k = rhs.ncols();
+    let eps = E::Real::faer_epsilon();
+
+    let mut solution = solver.solve(rhs);
+    let mut backward = alloc::vec::Vec::with_capacity(k);
+    let mut forward = alloc::vec::Vec::with_capacity(k);
+    let mut iter_count = alloc::vec::Vec::with_capacity(k);
+
+    let mut r = Col::<E>::zeros(n);
+    for j in 0..k {
+        let b = rhs.col(j);
+        let mut last_error = E::Real::faer_zero();
+        let mut forward_error = E::Real::faer_zero();
+        let mut last_forward_error = E::Real::faer_zero();
+        let mut iter = 0;
+
+        let error = loop {
+            let x = solution.col(j);
+            residual(r.as_mut(), matrix, x, b, precision);
+            let error = backward_error(matrix, x, b, r.as_ref());
+
+            let done = match precision {
+                // stop once the backward error is negligible, or when it doesn't decrease enough
+                // for another step to be worthwhile
+                ResidualPrecision::Working => {
+                    error <= eps || (iter > 0 && error.faer_add(error) > last_error)
+                }
+                // the backward error is usually negligible after the first solve, so the
+                // refinement is driven by the size of the corrections instead
+                ResidualPrecision::Extended => {
+                    error == E::Real::faer_zero()
+                        || (iter > 0 && forward_error <= eps)
+                        || (iter > 1 && forward_error.faer_add(forward_error) > last_forward_error)
+                }
+            };
+            if done || iter == MAX_ITER {
+                break error;
+            }
+            last_error = error;
+            last_forward_error = forward_error;
+
+            solver.solve_in_place(r.as_mut());
+            let x_norm = x.norm_max();
+            let dx_norm = r.norm_max();
+            let mut x = solution.col_mut(j);
+            x += &r;
+            forward_error = if x_norm > E::Real::faer_zero() {
+                dx_norm.faer_mul(x_norm.faer_inv())
+            } else {
+                dx_norm
+            };
+            iter += 1;
+        };
+
+        backward.push(error);
+        forward.push(forward_error);
+        iter_count.push(iter);
+    }
+
+    (
+        solution,
+        RefinementInfo {
+            backward_error: backward,
+            forward_error: forward,
+            iter_count,
+        },
+    )
+}
+
+/// Solves the equation `matrix * X = rhs` using the factorization `solver` of the square matrix
+/// `matrix`, refines the solution with [`solve_with_refinement`], and returns it along with
+/// estimates of its backward and forward errors and of the condition number of `matrix`, similarly
+/// to LAPACK's expert drivers `xGESVX` and `xPOSVX`.
+///
+/// The forward error bound of each column `x` of the solution, with residual `r = b - A * x`, is
+/// computed as in LAPACK's `xGERFS`,
+/// $$\frac{\| |A^{-1}| (|r| + (n + 1) \varepsilon (|A| |x| + |b|)) \|_\infty}{\|x\|_\infty},$$
+/// where the norm in the numerator is estimated with [`estimate_inverse_norm_1`]. The bound
+/// accounts for the rounding errors in the computation of the residual, and is almost always an
+/// overestimate of the actual error.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::refinement::solve_with_error_bounds, mat, Mat};
+///
+/// let a = mat![[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0f64]];
+/// let x_exact = mat![[1.0], [-2.0], [3.0f64]];
+/// let b = &a * &x_exact;
+///
+/// let (x, bounds) = solve_with_error_bounds(a.as_ref(), &a.partial_piv_lu(), b.as_ref());
+/// assert!((bounds.rcond - 7.0 / 18.0).abs() < 1e-12);
+/// assert!(bounds.backward_error[0] < 1e-15);
+/// assert!((&x - &x_exact).norm_max() <= bounds.forward_error[0] * x.norm_max());
+/// ```
+///
+/// # Panics
+/// Panics if `matrix` is not square, or if `matrix`, `solver` and `rhs` don't have the same
+/// number of rows.
+#[track_caller]
+pub fn solve_with_error_bounds<E: ComplexField>(
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    solver: &impl SpSolver<E>,
+    rhs: MatRef<'_, impl Conjugate<Canonical = E>>,
+) -> (Mat<E>, ErrorBounds<E::Real>) {
+    let (solution, info) = solve_with_refinement(matrix, solver, rhs);
+
+    let n = matrix.nrows();
+    let k = rhs.ncols();
+    let zero = E::Real::faer_zero();
+
+    let mut norm_1 = zero;
+    for j in 0..n {
+        let mut sum = zero;
+        for i in 0..n {
+            sum = sum.faer_add(matrix.read(i, j).canonicalize().faer_abs());
+        }
+        if sum > norm_1 {
+            norm_1 = sum;
+        }
+    }
+    let rcond = estimate_rcond_1(solver, norm_1);
+
+    // accounts for the rounding errors in the computation of the residual
+    let safety = E::Real::faer_from_f64((n + 1) as f64).faer_mul(E::Real::faer_epsilon());
+
+    let mut forward = alloc::vec::Vec::with_capacity(k);
+    let mut r = Col::<E>::zeros(n);
+    let mut w = Col::<E::Real>::zeros(n);
+    for j in 0..k {
+        let x = solution.col(j);
+        let b = rhs.col(j);
+        residual(r.as_mut(), matrix, x, b, ResidualPrecision::Working);
+
+        for i in 0..n {
+            // (|A| |x| + |b|)_i
+            let mut denom = b.read(i).canonicalize().faer_abs();
+            for l in 0..n {
+                denom = denom.faer_add(
+                    matrix
+                        .read(i, l)
+                        .canonicalize()
+                        .faer_abs()
+                        .faer_mul(x.read(l).faer_abs()),
+                );
+            }
+            w.write(i, r.read(i).faer_abs().faer_add(safety.faer_mul(denom)));
+        }
+
+        let scale = |mut y: ColMut<'_, E>| {
+            for i in 0..n {
+                y.write(i, y.read(i).faer_scale_real(w.read(i)));
+            }
+        };
+
+        // ‖A⁻¹ diag(w)‖∞ = ‖diag(w) A⁻ᴴ‖₁
+        let error_norm = estimate_inverse_norm_1(
+            n,
+            |mut y: ColMut<'_, E>| {
+                solver.solve_conj_transpose_in_place(y.rb_mut());
+                scale(y);
+            },
+            |mut y: ColMut<'_, E>| {
+                scale(y.rb_mut());
+                solver.solve_in_place(y);
+            },
+        );
+
+        let x_norm = x.norm_max();
+        forward.push(if x_norm > zero {
+            error_norm.faer_mul(x_norm.faer_inv())
+        } else {
+            error_norm
+        });
+    }
+
+    (
+        solution,
+        ErrorBounds {
+            rcond,
+            backward_error: info.backward_error,
+            forward_error: forward,
+            iter_count: info.iter_count,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{complex_native::c64, Side};
+
+    #[test]
+    fn test_refinement() {
+        let n = 30;
+        let A = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+        let B = Mat::from_fn(n, 3, |_, _| c64::new(rand::random(), rand::random()));
+
+        let lu = A.partial_piv_lu();
+        let (X, info) = solve_with_refinement(A.as_ref(), &lu, B.as_ref());
+        assert!((&A * &X - &B).norm_max() < 1e-10);
+        for j in 0..3 {
+            assert!(info.backward_error[j] < 1e-14);
+            assert!(info.iter_count[j] <= MAX_ITER);
+        }
+
+        let (X, info) = A.full_piv_lu().solve_with_refinement(A.as_ref(), &B);
+        assert!((&A * &X - &B).norm_max() < 1e-10);
+        assert!(info.backward_error.iter().all(|&e| e < 1e-14));
+
+        let (X, info) = A.qr().solve_with_refinement(A.as_ref(), &B);
+        assert!((&A * &X - &B).norm_max() < 1e-10);
+        assert!(info.backward_error.iter().all(|&e| e < 1e-14));
+
+        let P = &A * A.adjoint();
+        let b = Col::from_fn(n, |i| B.read(i, 0));
+        let (x, info) = P
+            .cholesky(Side::Lower)
+            .unwrap()
+            .solve_with_refinement(P.as_ref(), &b);
+        assert!((&P * &x - &b).norm_max() < 1e-8);
+        assert!(all(x.nrows() == n, info.backward_error[0] < 1e-14));
+
+        // single column right-hand side
+        let (x, info) = lu.solve_with_refinement(A.as_ref(), B.col(0));
+        assert!(info.forward_error[0] < 1e-10);
+        assert!((&A * &x - B.col(0)).norm_max() < 1e-10);
+    }
+
+    #[test]
+    fn test_refinement_ill_conditioned() {
+        let n = 10;
+        // hilbert matrix
+        let A = Mat::<f64>::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let b = Mat::<f64>::from_fn(n, 1, |_, _| 1.0);
+        let lu = A.partial_piv_lu();
+
+        let (x, info) = solve_with_refinement(A.as_ref(), &lu, b.as_ref());
+        let r = &b - &A * &x;
+        assert!(info.backward_error[0] == backward_error(A.as_ref(), x.col(0), b.col(0), r.col(0)));
+        assert!(info.backward_error[0] < 1e-13);
+    }
+
+    #[test]
+    fn test_refinement_extended_precision() {
+        let n = 9;
+        // hilbert matrix, with a right-hand side such that the exact solution is known
+        let A = Mat::<f64>::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let x_exact = Mat::<f64>::from_fn(n, 1, |i, _| if i % 2 == 0 { 1.0 } else { -1.0 });
+        let b = &A * &x_exact;
+        let lu = A.partial_piv_lu();
+
+        let (x_working, _) = solve_with_refinement(A.as_ref(), &lu, b.as_ref());
+        let (x_extended, info) = solve_with_refinement_with_precision(
+            A.as_ref(),
+            &lu,
+            b.as_ref(),
+            ResidualPrecision::Extended,
+        );
+        assert!(info.iter_count[0] >= 1);
+        assert!(info.backward_error[0] < 1e-14);
+
+        let err_working = (&x_working - &x_exact).norm_max();
+        let err_extended = (&x_extended - &x_exact).norm_max();
+        assert!(err_extended <= err_working);
+        assert!(err_extended < 1e-10);
+
+        let A = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+        let B = Mat::from_fn(n, 2, |_, _| c64::new(rand::random(), rand::random()));
+        let (X, info) = solve_with_refinement_with_precision(
+            A.as_ref(),
+            &A.partial_piv_lu(),
+            B.as_ref(),
+            ResidualPrecision::Extended,
+        );
+        assert!((&A * &X - &B).norm_max() < 1e-10);
+        assert!(info.backward_error.iter().all(|&e| e < 1e-14));
+
+        let mut r = Col::<c64>::zeros(n);
+        residual(
+            r.as_mut(),
+            A.as_ref(),
+            X.col(0),
+            B.col(0),
+            ResidualPrecision::Extended,
+        );
+        assert!(r.norm_max() < 1e-13);
+    }
+
+    #[test]
+    fn test_error_bounds() {
+        let n = 9;
+        let A = Mat::<f64>::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let X_exact = Mat::<f64>::from_fn(n, 2, |i, j| (i + j) as f64 - 4.0);
+        let B = &A * &X_exact;
+
+        let (X, bounds) = solve_with_error_bounds(A.as_ref(), &A.partial_piv_lu(), B.as_ref());
+        let rcond = bounds.rcond;
+        assert!(all(rcond > 0.0, rcond < 1e-9));
+        for j in 0..2 {
+            let err = (X.col(j) - X_exact.col(j)).norm_max() / X.col(j).norm_max();
+            assert!(bounds.backward_error[j] < 1e-14);
+            assert!(err <= bounds.forward_error[j]);
+            // the bound is not much larger than `eps / rcond`
+            assert!(bounds.forward_error[j] < 1e3 * f64::EPSILON / rcond);
+        }
+
+        let A = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+        let P = &A * A.adjoint() + Mat::<c64>::identity(n, n);
+        let B = Mat::from_fn(n, 1, |_, _| c64::new(rand::random(), rand::random()));
+        let (X, bounds) = P
+            .cholesky(Side::Lower)
+            .unwrap()
+            .solve_with_error_bounds(P.as_ref(), &B);
+        assert!((&P * &X - &B).norm_max() < 1e-10);
+        assert!(all(
+            bounds.backward_error[0] < 1e-14,
+            bounds.forward_error[0] < 1e-10,
+            bounds.rcond > 0.0,
+        ));
+
+        let (_, bounds) = A.full_piv_lu().solve_with_error_bounds(A.as_ref(), &B);
+        assert!(bounds.forward_error[0] > 0.0);
+    }
+}
@@ -0,0 +1,257 @@
+//! Matrix equilibration.
+//!
+//! Equilibration computes diagonal scalings `Dr` and `Dc` such that the elements of `Dr * A * Dc`
+//! have a more uniform magnitude than those of `A`, which often improves the conditioning of the
+//! matrix, and the accuracy of the solution of a linear system computed from its factorization.
+//!
+//! Solving `A * x = b` then amounts to solving `(Dr * A * Dc) * y = Dr * b`, and recovering
+//! `x = Dc * y`.
+
+use crate::{col::*, mat::*, ComplexField, RealField};
+use reborrow::*;
+
+/// Method used to compute the scaling factors in [`equilibrate`].
+#[derive(Copy, Clone, Debug)]
+pub enum EquilibrationMethod<R: RealField> {
+    /// Ruiz iterations, which alternately divide each row and each column by the square root of
+    /// its largest absolute value, until all of them are within `tol` of one, or after
+    /// `max_iters` iterations. For symmetric matrices, the row and column scalings are equal.
+    Ruiz {
+        /// Maximum number of iterations.
+        max_iters: usize,
+        /// Tolerance on the distance of the largest absolute value of each row and column to one.
+        tol: R,
+    },
+    /// A single pass, similarly to LAPACK's `xGEEQU`, which scales each row so that its largest
+    /// absolute value is one, then each column of the resulting matrix.
+    MaxAbs,
+}
+
+impl<R: RealField> Default for EquilibrationMethod<R> {
+    #[inline]
+    fn default() -> Self {
+        Self::Ruiz {
+            max_iters: 20,
+            tol: R::faer_from_f64(1e-2),
+        }
+    }
+}
+
+/// Scaling factors computed by [`equilibrate`].
+#[derive(Clone, Debug)]
+pub struct Equilibration<R: RealField> {
+    /// Diagonal of the row scaling `Dr`.
+    pub row_scale: Col<R>,
+    /// Diagonal of the column scaling `Dc`.
+    pub col_scale: Col<R>,
+}
+
+// largest absolute values of the rows and columns of `matrix`
+fn row_col_max<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    mut row_max: ColMut<'_, E::Real>,
+    mut col_max: ColMut<'_, E::Real>,
+) {
+    row_max.fill_zero();
+    for j in 0..matrix.ncols() {
+        let mut max = E::Real::faer_zero();
+        for i in 0..matrix.nrows() {
+            let abs = matrix.read(i, j).faer_abs();
+            if abs > max {
+                max = abs;
+            }
+            if abs > row_max.read(i) {
+                row_max.write(i, abs);
+            }
+        }
+        col_max.write(j, max);
+    }
+}
+
+// multiplies the rows of `matrix` by `row_scale`, and its columns by `col_scale`
+fn scale<E: ComplexField>(
+    matrix: MatMut<'_, E>,
+    row_scale: ColRef<'_, E::Real>,
+    col_scale: ColRef<'_, E::Real>,
+) {
+    let mut matrix = matrix;
+    for j in 0..matrix.ncols() {
+        let c = col_scale.read(j);
+        for i in 0..matrix.nrows() {
+            let factor = row_scale.read(i).faer_mul(c);
+            matrix.write(i, j, matrix.read(i, j).faer_scale_real(factor));
+        }
+    }
+}
+
+// inverse of `value`, or one if it is zero
+fn safe_inv<R: RealField>(value: R) -> R {
+    if value == R::faer_zero() {
+        R::faer_one()
+    } else {
+        value.faer_inv()
+    }
+}
+
+/// Equilibrates `matrix` in place using the given `method`, i.e., overwrites it with
+/// `Dr * matrix * Dc`, and returns the diagonal scalings `Dr` and `Dc`.
+///
+/// Rows and columns that are entirely zero are left unscaled.
+///
+/// # Example
+/// ```
+/// use faer::{
+///     linalg::scaling::{equilibrate, EquilibrationMethod},
+///     mat,
+/// };
+///
+/// let mut a = mat![[1e6, 2e6], [3e-6, 1e-6f64]];
+/// let scaling = equilibrate(a.as_mut(), EquilibrationMethod::default());
+///
+/// // all the elements now have a magnitude close to one
+/// assert!(a.norm_max() <= 1.0 + 1e-2);
+/// assert!(a.norm_max() >= 1.0 - 1e-2);
+/// assert!(scaling.row_scale.nrows() == 2);
+/// ```
+#[track_caller]
+pub fn equilibrate<E: ComplexField>(
+    matrix: MatMut<'_, E>,
+    method: EquilibrationMethod<E::Real>,
+) -> Equilibration<E::Real> {
+    let mut matrix = matrix;
+    let m = matrix.nrows();
+    let n = matrix.ncols();
+
+    let mut row_scale = Col::<E::Real>::from_fn(m, |_| E::Real::faer_one());
+    let mut col_scale = Col::<E::Real>::from_fn(n, |_| E::Real::faer_one());
+    let mut row_max = Col::<E::Real>::zeros(m);
+    let mut col_max = Col::<E::Real>::zeros(n);
+
+    match method {
+        EquilibrationMethod::Ruiz { max_iters, tol } => {
+            let one = E::Real::faer_one();
+            let within_tol =
+                |max: E::Real| max == E::Real::faer_zero() || max.faer_sub(one).faer_abs() <= tol;
+
+            for _ in 0..max_iters {
+                row_col_max(matrix.rb(), row_max.as_mut(), col_max.as_mut());
+                let converged = (0..m).all(|i| within_tol(row_max.read(i)))
+                    && (0..n).all(|j| within_tol(col_max.read(j)));
+                if converged {
+                    break;
+                }
+
+                for i in 0..m {
+                    let r = safe_inv(row_max.read(i).faer_sqrt());
+                    row_max.write(i, r);
+                    row_scale.write(i, row_scale.read(i).faer_mul(r));
+                }
+                for j in 0..n {
+                    let c = safe_inv(col_max.read(j).faer_sqrt());
+                    col_max.write(j, c);
+                    col_scale.write(j, col_scale.read(j).faer_mul(c));
+                }
+                scale(matrix.rb_mut(), row_max.as_ref(), col_max.as_ref());
+            }
+        }
+        EquilibrationMethod::MaxAbs => {
+            row_col_max(matrix.rb(), row_max.as_mut(), col_max.as_mut());
+            for i in 0..m {
+                row_scale.write(i, safe_inv(row_max.read(i)));
+            }
+            scale(matrix.rb_mut(), row_scale.as_ref(), col_scale.as_ref());
+
+            row_col_max(matrix.rb(), row_max.as_mut(), col_max.as_mut());
+            for j in 0..n {
+                col_scale.write(j, safe_inv(col_max.read(j)));
+            }
+            row_max.fill(E::Real::faer_one());
+            scale(matrix.rb_mut(), row_max.as_ref(), col_scale.as_ref());
+        }
+    }
+
+    Equilibration {
+        row_scale,
+        col_scale,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::solvers::SpSolver};
+
+    fn badly_scaled(m: usize, n: usize) -> Mat<c64> {
+        Mat::from_fn(m, n, |i, j| {
+            let scale = 10.0f64.powi(i as i32 - 4) * 10.0f64.powi(3 - 2 * (j as i32 % 4));
+            c64::new(rand::random(), rand::random()) * scale
+        })
+    }
+
+    #[test]
+    fn test_equilibrate() {
+        for method in [
+            EquilibrationMethod::default(),
+            EquilibrationMethod::Ruiz {
+                max_iters: 100,
+                tol: 1e-8,
+            },
+            EquilibrationMethod::MaxAbs,
+        ] {
+            let A = badly_scaled(9, 7);
+            let mut B = A.clone();
+            let scaling = equilibrate(B.as_mut(), method);
+
+            // the returned scalings reproduce the equilibrated matrix
+            let expected = Mat::from_fn(9, 7, |i, j| {
+                A.read(i, j) * (scaling.row_scale.read(i) * scaling.col_scale.read(j))
+            });
+            assert!((&B - &expected).norm_max() < 1e-12);
+
+            let tol = match method {
+                EquilibrationMethod::Ruiz { tol, .. } => tol,
+                EquilibrationMethod::MaxAbs => 0.0,
+            };
+            for i in 0..9 {
+                let max = (0..7).map(|j| B.read(i, j).faer_abs()).fold(0.0, f64::max);
+                assert!(max <= 1.0 + tol + 1e-12);
+                if let EquilibrationMethod::Ruiz { .. } = method {
+                    assert!(max >= 1.0 - tol - 1e-12);
+                }
+            }
+            for j in 0..7 {
+                let max = (0..9).map(|i| B.read(i, j).faer_abs()).fold(0.0, f64::max);
+                assert!((max - 1.0).abs() <= tol + 1e-12);
+            }
+
+            // solving the equilibrated system gives the solution of the original one
+            let A = badly_scaled(6, 6);
+            let b = Col::from_fn(6, |_| c64::new(rand::random(), rand::random()));
+            let mut B = A.clone();
+            let scaling = equilibrate(B.as_mut(), method);
+            let scaled_b = Col::from_fn(6, |i| b.read(i) * scaling.row_scale.read(i));
+            let y = B.partial_piv_lu().solve(&scaled_b);
+            let x = Col::from_fn(6, |i| y.read(i) * scaling.col_scale.read(i));
+            assert!(
+                (&A * &x - &b).norm_max() < 1e-8 * (A.norm_max() * x.norm_max() + b.norm_max())
+            );
+        }
+    }
+
+    #[test]
+    fn test_equilibrate_zero() {
+        let mut A = Mat::<f64>::zeros(3, 2);
+        A.write(0, 0, 4.0);
+        let scaling = equilibrate(A.as_mut(), EquilibrationMethod::default());
+        assert!(A.read(0, 0) == 1.0);
+        assert!(scaling.row_scale.read(1) == 1.0);
+        assert!(scaling.col_scale.read(1) == 1.0);
+
+        let mut A = Mat::<f64>::zeros(0, 2);
+        let scaling = equilibrate(A.as_mut(), EquilibrationMethod::MaxAbs);
+        assert!(all(
+            scaling.row_scale.nrows() == 0,
+            scaling.col_scale.nrows() == 2
+        ));
+    }
+}
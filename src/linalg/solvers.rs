@@ -1,10 +1,12 @@
 use crate::{
     assert, col::*, diag::DiagRef, linalg::matmul::triangular::BlockStructure, mat::*,
-    perm::PermRef, *,
+    perm::PermRef, utils::scratch::ScratchBuffer, *,
 };
 use dyn_stack::*;
 use reborrow::*;
 
+pub mod mixed_precision;
+
 pub use crate::{
     linalg::cholesky::llt::CholeskyError,
     sparse::linalg::solvers::{SpSolver, SpSolverCore, SpSolverLstsq, SpSolverLstsqCore},
@@ -28,13 +30,41 @@ pub trait Solver<E: ComplexField>: SolverCore<E> + SpSolver<E> {}
 /// Dense solver that can compute the least squares solution of an overdetermined linear system.
 pub trait SolverLstsq<E: ComplexField>: SolverLstsqCore<E> + SpSolverLstsq<E> {}
 
+/// Factorization that can solve a linear system `A * X = B`, implemented by all the dense and
+/// sparse factorization types.
+///
+/// This trait is object-safe, and the methods of [`SpSolver`] are available on `dyn Solve<E>`, so
+/// that code holding a `Box<dyn Solve<E>>` can switch between factorizations at runtime without
+/// changing its call sites.
+pub trait Solve<E: ComplexField>: SpSolverCore<E> {}
+/// Factorization that can solve the transposed systems `Aᵀ * X = B` and `Aᴴ * X = B`.
+///
+/// Every factorization implementing [`Solve`] also supports transposed solves, so this trait is
+/// implemented whenever [`Solve`] is. It allows generic code to state that requirement
+/// explicitly.
+pub trait SolveTranspose<E: ComplexField>: Solve<E> {}
+/// Factorization that can compute the least squares solution of an overdetermined linear system.
+///
+/// This trait is object-safe, and the methods of [`SpSolverLstsq`] are available on
+/// `dyn SolveLstsq<E>`.
+pub trait SolveLstsq<E: ComplexField>: Solve<E> + SpSolverLstsqCore<E> {}
+
 const _: () = {
     fn __assert_object_safe<E: ComplexField>() {
         let _: Option<&dyn SolverCore<E>> = None;
         let _: Option<&dyn SolverLstsqCore<E>> = None;
+        let _: Option<&dyn Solve<E>> = None;
+        let _: Option<&dyn SolveTranspose<E>> = None;
+        let _: Option<&dyn SolveLstsq<E>> = None;
     }
 };
 
+impl<E: ComplexField, Dec: ?Sized + SpSolverCore<E>> Solve<E> for Dec {}
+
+impl<E: ComplexField, Dec: ?Sized + SpSolverCore<E>> SolveTranspose<E> for Dec {}
+
+impl<E: ComplexField, Dec: ?Sized + SpSolverLstsqCore<E>> SolveLstsq<E> for Dec {}
+
 impl<E: ComplexField, Dec: ?Sized + SolverLstsqCore<E>> SolverLstsq<E> for Dec {}
 
 impl<E: ComplexField, Dec: ?Sized + SolverCore<E>> Solver<E> for Dec {}
@@ -42,6 +72,8 @@ impl<E: ComplexField, Dec: ?Sized + SolverCore<E>> Solver<E> for Dec {}
 /// Cholesky decomposition.
 pub struct Cholesky<E: Entity> {
     factors: Mat<E>,
+    // the norm of the factorized matrix, stored as a real value embedded in `E`
+    norm: E,
 }
 
 /// Bunch-Kaufman decomposition.
@@ -58,6 +90,8 @@ pub struct PartialPivLu<E: Entity> {
     row_perm: alloc::vec::Vec<usize>,
     row_perm_inv: alloc::vec::Vec<usize>,
     n_transpositions: usize,
+    norm_1: E,
+    norm_inf: E,
 }
 /// LU decomposition with full pivoting.
 pub struct FullPivLu<E: Entity> {
@@ -67,19 +101,25 @@ pub struct FullPivLu<E: Entity> {
     col_perm: alloc::vec::Vec<usize>,
     col_perm_inv: alloc::vec::Vec<usize>,
     n_transpositions: usize,
+    norm_1: E,
+    norm_inf: E,
 }
 
 /// QR decomposition.
 pub struct Qr<E: Entity> {
     pub(crate) factors: Mat<E>,
     householder: Mat<E>,
+    norm_1: E,
+    norm_inf: E,
 }
 /// QR decomposition with column pivoting.
 pub struct ColPivQr<E: Entity> {
-    factors: Mat<E>,
+    pub(crate) factors: Mat<E>,
     householder: Mat<E>,
     col_perm: alloc::vec::Vec<usize>,
     col_perm_inv: alloc::vec::Vec<usize>,
+    norm_1: E,
+    norm_inf: E,
 }
 
 /// Singular value decomposition.
@@ -87,6 +127,8 @@ pub struct Svd<E: Entity> {
     s: Mat<E>,
     u: Mat<E>,
     v: Mat<E>,
+    norm_1: E,
+    norm_inf: E,
 }
 /// Thin singular value decomposition.
 pub struct ThinSvd<E: Entity> {
@@ -105,6 +147,152 @@ pub struct Eigendecomposition<E: Entity> {
     u: Mat<E>,
 }
 
+/// Method used to determine the numerical rank of a matrix. See [`MatRef::rank_with`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RankMethod {
+    /// The rank is determined from the singular values of the matrix. This is the most reliable
+    /// method.
+    Svd,
+    /// The rank is determined from the diagonal of the factor $R$ of the QR decomposition with
+    /// column pivoting. This is cheaper than the singular value decomposition, but may
+    /// overestimate the rank of some matrices.
+    ColPivQr,
+}
+
+/// Numerical rank of a matrix, along with the values it was determined from.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RankInfo<R> {
+    /// Numerical rank of the matrix.
+    pub rank: usize,
+    /// Values that were compared against the tolerance, i.e., the singular values of the matrix,
+    /// or the absolute values of the diagonal of $R$ for [`RankMethod::ColPivQr`].
+    pub values: alloc::vec::Vec<R>,
+    /// Column permutation of the QR decomposition, for [`RankMethod::ColPivQr`]. The first `rank`
+    /// columns of the permuted matrix span its numerical range.
+    pub col_perm: Option<crate::perm::Perm<usize>>,
+}
+
+// returns the number of leading `values` that are larger than `tol` times the first one
+pub(crate) fn numerical_rank<R: RealField>(values: &[R], tol: R) -> usize {
+    match values.first() {
+        None => 0,
+        Some(&max) => {
+            let threshold = tol.faer_mul(max);
+            values
+                .iter()
+                .take_while(|&&value| value > threshold && value > R::faer_zero())
+                .count()
+        }
+    }
+}
+
+// returns the 1-norm and the ∞-norm of `matrix`, i.e., the maximum absolute column and row sums,
+// embedded in `E`
+fn norm_1_and_inf<E: ComplexField>(matrix: MatRef<'_, E>) -> (E, E) {
+    let mut row_sums = alloc::vec![E::Real::faer_zero(); matrix.nrows()];
+    let mut norm_1 = E::Real::faer_zero();
+    for j in 0..matrix.ncols() {
+        let mut col_sum = E::Real::faer_zero();
+        for i in 0..matrix.nrows() {
+            let abs = matrix.read(i, j).faer_abs();
+            col_sum = col_sum.faer_add(abs);
+            row_sums[i] = row_sums[i].faer_add(abs);
+        }
+        if col_sum > norm_1 {
+            norm_1 = col_sum;
+        }
+    }
+
+    let mut norm_inf = E::Real::faer_zero();
+    for row_sum in row_sums {
+        if row_sum > norm_inf {
+            norm_inf = row_sum;
+        }
+    }
+    (E::faer_from_real(norm_1), E::faer_from_real(norm_inf))
+}
+
+// returns the 1-norm of the hermitian matrix whose lower triangular half is stored in `lower`
+fn hermitian_norm_1<E: ComplexField>(lower: MatRef<'_, E>) -> E::Real {
+    let n = lower.nrows();
+    let mut col_sums = alloc::vec![E::Real::faer_zero(); n];
+    for j in 0..n {
+        for i in j..n {
+            let abs = lower.read(i, j).faer_abs();
+            col_sums[j] = col_sums[j].faer_add(abs);
+            if i != j {
+                col_sums[i] = col_sums[i].faer_add(abs);
+            }
+        }
+    }
+
+    let mut norm = E::Real::faer_zero();
+    for col_sum in col_sums {
+        if col_sum > norm {
+            norm = col_sum;
+        }
+    }
+    norm
+}
+
+// returns the sign and the logarithm of the absolute value of the product of `factors`, where the
+// sign is a value of unit modulus, or `(0, -∞)` if one of the factors is zero
+#[cfg(feature = "std")]
+fn logdet_of_product<E: ComplexField>(factors: impl IntoIterator<Item = E>) -> (E, E::Real)
+where
+    E::Real: num_traits::Float,
+{
+    let mut sign = E::faer_one();
+    let mut log_abs = E::Real::faer_zero();
+    for factor in factors {
+        let abs = factor.faer_abs();
+        if abs == E::Real::faer_zero() {
+            return (E::faer_zero(), num_traits::Float::neg_infinity());
+        }
+        sign = sign.faer_mul(factor.faer_scale_real(abs.faer_inv()));
+        log_abs = log_abs.faer_add(num_traits::Float::ln(abs));
+    }
+    (sign, log_abs)
+}
+
+// solves the system with the factorization `solver` of `matrix`, and computes error bounds of the
+// solution
+#[track_caller]
+fn solve_with_error_bounds_impl<
+    E: ComplexField,
+    ViewE: Conjugate<Canonical = E>,
+    B: ColBatch<ViewE>,
+>(
+    solver: &impl SpSolver<E>,
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    rhs: B,
+) -> (B::Owned, crate::linalg::refinement::ErrorBounds<E::Real>) {
+    let (solution, bounds) =
+        crate::linalg::refinement::solve_with_error_bounds(matrix, solver, rhs.as_2d_ref());
+    let mut out = B::new_owned_zeros(solution.nrows(), solution.ncols());
+    out.as_2d_mut().copy_from(solution.as_ref());
+    (out, bounds)
+}
+
+// solves the system with the factorization `solver` of `matrix`, with iterative refinement
+#[track_caller]
+fn solve_with_refinement_impl<
+    E: ComplexField,
+    ViewE: Conjugate<Canonical = E>,
+    B: ColBatch<ViewE>,
+>(
+    solver: &impl SpSolver<E>,
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    rhs: B,
+) -> (B::Owned, crate::linalg::refinement::RefinementInfo<E::Real>) {
+    let (solution, info) =
+        crate::linalg::refinement::solve_with_refinement(matrix, solver, rhs.as_2d_ref());
+    let mut out = B::new_owned_zeros(solution.nrows(), solution.ncols());
+    out.as_2d_mut().copy_from(solution.as_ref());
+    (out, info)
+}
+
 impl<E: ComplexField> Cholesky<E> {
     /// Returns the Cholesky factorization of the input
     /// matrix, or an error if the matrix is not positive definite.
@@ -138,13 +326,16 @@ impl<E: ComplexField> Cholesky<E> {
             }
         }
 
-        let params = Default::default();
+        let norm = E::faer_from_real(hermitian_norm_1(factors.as_ref()));
+
+        let mut params = crate::linalg::cholesky::llt::compute::LltParams::default();
+        params.scheduler = crate::linalg::autotune::llt_scheduler::<E>(dim);
 
         crate::linalg::cholesky::llt::compute::cholesky_in_place(
             factors.as_mut(),
             Default::default(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::cholesky::llt::compute::cholesky_in_place_req::<E>(
                     dim,
                     parallelism,
@@ -154,7 +345,7 @@ impl<E: ComplexField> Cholesky<E> {
             )),
             params,
         )?;
-        Ok(Self { factors })
+        Ok(Self { factors, norm })
     }
 
     fn dim(&self) -> usize {
@@ -170,6 +361,64 @@ impl<E: ComplexField> Cholesky<E> {
             });
         factor
     }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the 1-norm. See [`estimate_rcond_1`](crate::linalg::cond::estimate_rcond_1).
+    pub fn rcond(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_1(self, self.norm.faer_real())
+    }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the ∞-norm, which is equal to [`Self::rcond`] since the matrix is Hermitian.
+    pub fn rcond_inf(&self) -> E::Real {
+        self.rcond()
+    }
+
+    /// Solves the equation `A * X = rhs`, where `A` is the factorized matrix, and refines the
+    /// solution using the residual computed from `matrix`, which must be equal to `A`.
+    ///
+    /// Both triangular halves of `matrix` are accessed.
+    /// See [`solve_with_refinement`](crate::linalg::refinement::solve_with_refinement).
+    #[track_caller]
+    pub fn solve_with_refinement<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+        rhs: B,
+    ) -> (B::Owned, crate::linalg::refinement::RefinementInfo<E::Real>) {
+        solve_with_refinement_impl(self, matrix, rhs)
+    }
+
+    /// Solves the equation `A * X = rhs`, where `A` is the factorized matrix, and returns the
+    /// refined solution along with estimates of its backward and forward errors. `matrix` must be
+    /// equal to `A`.
+    ///
+    /// Both triangular halves of `matrix` are accessed.
+    /// See [`solve_with_error_bounds`](crate::linalg::refinement::solve_with_error_bounds).
+    #[track_caller]
+    pub fn solve_with_error_bounds<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+        rhs: B,
+    ) -> (B::Owned, crate::linalg::refinement::ErrorBounds<E::Real>) {
+        solve_with_error_bounds_impl(self, matrix, rhs)
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant of the
+    /// factorized matrix, computed without forming the determinant itself, which could overflow or
+    /// underflow for large matrices.
+    ///
+    /// Since the matrix is positive definite, the sign is always one.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn logdet(&self) -> (E, E::Real)
+    where
+        E::Real: num_traits::Float,
+    {
+        let (sign, log_abs) = logdet_of_product(
+            (0..self.dim()).map(|i| E::faer_from_real(self.factors.read(i, i).faer_real())),
+        );
+        (sign, log_abs.faer_add(log_abs))
+    }
 }
 impl<E: ComplexField> SpSolverCore<E> for Cholesky<E> {
     #[track_caller]
@@ -182,7 +431,7 @@ impl<E: ComplexField> SpSolverCore<E> for Cholesky<E> {
             conj,
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::cholesky::llt::solve::solve_in_place_req::<E>(
                     self.dim(),
                     rhs_ncols,
@@ -215,7 +464,7 @@ impl<E: ComplexField> SolverCore<E> for Cholesky<E> {
             inv.as_mut(),
             self.factors.as_ref(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::cholesky::llt::inverse::invert_lower_req::<E>(
                     self.dim(),
                     parallelism,
@@ -241,7 +490,7 @@ impl<E: ComplexField> SolverCore<E> for Cholesky<E> {
             rec.as_mut(),
             self.factors.as_ref(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::cholesky::llt::reconstruct::reconstruct_lower_req::<E>(self.dim())
                     .unwrap(),
             )),
@@ -297,7 +546,7 @@ impl<E: ComplexField> Lblt<E> {
             &mut perm,
             &mut perm_inv,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::cholesky::bunch_kaufman::compute::cholesky_in_place_req::<usize, E>(
                     dim,
                     parallelism,
@@ -318,6 +567,48 @@ impl<E: ComplexField> Lblt<E> {
     fn dim(&self) -> usize {
         self.factors.nrows()
     }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant of the
+    /// factorized matrix. See [`Cholesky::logdet`].
+    ///
+    /// Since the matrix is Hermitian, its determinant is real, and the sign is either `-1`, `0` or
+    /// `1`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn logdet(&self) -> (E, E::Real)
+    where
+        E::Real: num_traits::Float,
+    {
+        // the diagonal blocks are stored as their inverses, so we compute the determinant of the
+        // inverse of the block diagonal factor. a zero pivot is stored as an infinite inverse
+        let n = self.dim();
+        let mut blocks = alloc::vec::Vec::with_capacity(n);
+        let mut i = 0;
+        while i < n {
+            let block = if self.subdiag.read(i, 0) == E::faer_zero() {
+                let ak = self.factors.read(i, i).faer_real();
+                i += 1;
+                ak
+            } else {
+                let ak = self.factors.read(i, i).faer_real();
+                let akp1 = self.factors.read(i + 1, i + 1).faer_real();
+                let akp1k = self.subdiag.read(i, 0).faer_abs2();
+                i += 2;
+                ak.faer_mul(akp1).faer_sub(akp1k)
+            };
+            if !num_traits::Float::is_finite(block) {
+                return (E::faer_zero(), num_traits::Float::neg_infinity());
+            }
+            blocks.push(block);
+        }
+
+        let (sign, log_abs) = logdet_of_product(blocks.into_iter().map(E::faer_from_real));
+        if sign == E::faer_zero() {
+            (sign, log_abs)
+        } else {
+            (sign, log_abs.faer_neg())
+        }
+    }
 }
 
 impl<E: ComplexField> SpSolverCore<E> for Lblt<E> {
@@ -333,7 +624,7 @@ impl<E: ComplexField> SpSolverCore<E> for Lblt<E> {
             unsafe { PermRef::new_unchecked(&self.perm, &self.perm_inv) },
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::cholesky::bunch_kaufman::solve::solve_in_place_req::<usize, E>(
                     self.dim(),
                     rhs_ncols,
@@ -468,8 +759,11 @@ impl<E: ComplexField> PartialPivLu<E> {
         let parallelism = get_global_parallelism();
 
         let mut factors = matrix.to_owned();
+        let (norm_1, norm_inf) = norm_1_and_inf(factors.as_ref());
 
-        let params = Default::default();
+        let mut params =
+            crate::linalg::lu::partial_pivoting::compute::PartialPivLuComputeParams::default();
+        params.scheduler = crate::linalg::autotune::lu_scheduler::<E>(dim, dim);
 
         let mut row_perm = alloc::vec![0usize; dim];
         let mut row_perm_inv = alloc::vec![0usize; dim];
@@ -479,7 +773,7 @@ impl<E: ComplexField> PartialPivLu<E> {
             &mut row_perm,
             &mut row_perm_inv,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::partial_pivoting::compute::lu_in_place_req::<usize, E>(
                     dim,
                     dim,
@@ -496,6 +790,8 @@ impl<E: ComplexField> PartialPivLu<E> {
             factors,
             row_perm,
             row_perm_inv,
+            norm_1,
+            norm_inf,
         }
     }
 
@@ -536,6 +832,76 @@ impl<E: ComplexField> PartialPivLu<E> {
             });
         factor
     }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the 1-norm. See [`estimate_rcond_1`](crate::linalg::cond::estimate_rcond_1).
+    #[track_caller]
+    pub fn rcond(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_1(self, self.norm_1.faer_real())
+    }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the ∞-norm. See [`estimate_rcond_inf`](crate::linalg::cond::estimate_rcond_inf).
+    #[track_caller]
+    pub fn rcond_inf(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_inf(self, self.norm_inf.faer_real())
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant of the
+    /// factorized matrix, computed without forming the determinant itself, which could overflow or
+    /// underflow for large matrices.
+    ///
+    /// For real matrices, the sign is either `-1`, `0` or `1`. For complex matrices, it is a value
+    /// of unit modulus `e^{iθ}`, where `θ` is the phase of the determinant, or zero if the matrix is
+    /// singular. In both cases, the determinant is equal to `sign * exp(log_abs)`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1e200, 0.0], [0.0, -1e200f64]];
+    /// let (sign, log_abs) = a.partial_piv_lu().logdet();
+    /// assert!(sign == -1.0);
+    /// assert!((log_abs - 400.0 * 10.0f64.ln()).abs() < 1e-10);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn logdet(&self) -> (E, E::Real)
+    where
+        E::Real: num_traits::Float,
+    {
+        let (sign, log_abs) = logdet_of_product((0..self.dim()).map(|i| self.factors.read(i, i)));
+        if self.n_transpositions % 2 == 0 {
+            (sign, log_abs)
+        } else {
+            (sign.faer_neg(), log_abs)
+        }
+    }
+
+    /// Solves the equation `A * X = rhs`, where `A` is the factorized matrix, and refines the
+    /// solution using the residual computed from `matrix`, which must be equal to `A`.
+    /// See [`solve_with_refinement`](crate::linalg::refinement::solve_with_refinement).
+    #[track_caller]
+    pub fn solve_with_refinement<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+        rhs: B,
+    ) -> (B::Owned, crate::linalg::refinement::RefinementInfo<E::Real>) {
+        solve_with_refinement_impl(self, matrix, rhs)
+    }
+
+    /// Solves the equation `A * X = rhs`, where `A` is the factorized matrix, and returns the
+    /// refined solution along with estimates of its backward and forward errors. `matrix` must be
+    /// equal to `A`.
+    /// See [`solve_with_error_bounds`](crate::linalg::refinement::solve_with_error_bounds).
+    #[track_caller]
+    pub fn solve_with_error_bounds<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+        rhs: B,
+    ) -> (B::Owned, crate::linalg::refinement::ErrorBounds<E::Real>) {
+        solve_with_error_bounds_impl(self, matrix, rhs)
+    }
 }
 impl<E: ComplexField> SpSolverCore<E> for PartialPivLu<E> {
     #[track_caller]
@@ -549,7 +915,7 @@ impl<E: ComplexField> SpSolverCore<E> for PartialPivLu<E> {
             self.row_permutation(),
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::partial_pivoting::solve::solve_in_place_req::<usize, E>(
                     self.dim(),
                     self.dim(),
@@ -573,7 +939,7 @@ impl<E: ComplexField> SpSolverCore<E> for PartialPivLu<E> {
             rhs,
             parallelism,
             PodStack::new(
-                &mut GlobalPodBuffer::new(
+                &mut ScratchBuffer::new(
                     crate::linalg::lu::partial_pivoting::solve::solve_transpose_in_place_req::<
                         usize,
                         E,
@@ -602,7 +968,7 @@ impl<E: ComplexField> SolverCore<E> for PartialPivLu<E> {
             self.factors.as_ref(),
             self.row_permutation(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::partial_pivoting::inverse::invert_req::<usize, E>(
                     self.dim(),
                     self.dim(),
@@ -624,7 +990,7 @@ impl<E: ComplexField> SolverCore<E> for PartialPivLu<E> {
             self.factors.as_ref(),
             self.row_permutation(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::partial_pivoting::reconstruct::reconstruct_req::<usize, E>(
                     self.dim(),
                     self.dim(),
@@ -651,6 +1017,7 @@ impl<E: ComplexField> FullPivLu<E> {
         let parallelism = get_global_parallelism();
 
         let mut factors = matrix.to_owned();
+        let (norm_1, norm_inf) = norm_1_and_inf(factors.as_ref());
 
         let params = Default::default();
 
@@ -666,7 +1033,7 @@ impl<E: ComplexField> FullPivLu<E> {
             &mut col_perm,
             &mut col_perm_inv,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::full_pivoting::compute::lu_in_place_req::<usize, E>(
                     m,
                     n,
@@ -685,6 +1052,8 @@ impl<E: ComplexField> FullPivLu<E> {
             col_perm,
             col_perm_inv,
             n_transpositions: n_transpositions.transposition_count,
+            norm_1,
+            norm_inf,
         }
     }
 
@@ -735,6 +1104,73 @@ impl<E: ComplexField> FullPivLu<E> {
             });
         factor
     }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the 1-norm. See [`estimate_rcond_1`](crate::linalg::cond::estimate_rcond_1).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_1(self, self.norm_1.faer_real())
+    }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the ∞-norm. See [`estimate_rcond_inf`](crate::linalg::cond::estimate_rcond_inf).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond_inf(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_inf(self, self.norm_inf.faer_real())
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant of the
+    /// factorized matrix. See [`PartialPivLu::logdet`].
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[track_caller]
+    pub fn logdet(&self) -> (E, E::Real)
+    where
+        E::Real: num_traits::Float,
+    {
+        assert!(self.factors.nrows() == self.factors.ncols());
+        let n = self.factors.nrows();
+        let (sign, log_abs) = logdet_of_product((0..n).map(|i| self.factors.read(i, i)));
+        if self.n_transpositions % 2 == 0 {
+            (sign, log_abs)
+        } else {
+            (sign.faer_neg(), log_abs)
+        }
+    }
+
+    /// Solves the equation `A * X = rhs`, where `A` is the factorized matrix, and refines the
+    /// solution using the residual computed from `matrix`, which must be equal to `A`.
+    /// See [`solve_with_refinement`](crate::linalg::refinement::solve_with_refinement).
+    #[track_caller]
+    pub fn solve_with_refinement<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+        rhs: B,
+    ) -> (B::Owned, crate::linalg::refinement::RefinementInfo<E::Real>) {
+        solve_with_refinement_impl(self, matrix, rhs)
+    }
+
+    /// Solves the equation `A * X = rhs`, where `A` is the factorized matrix, and returns the
+    /// refined solution along with estimates of its backward and forward errors. `matrix` must be
+    /// equal to `A`.
+    /// See [`solve_with_error_bounds`](crate::linalg::refinement::solve_with_error_bounds).
+    #[track_caller]
+    pub fn solve_with_error_bounds<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+        rhs: B,
+    ) -> (B::Owned, crate::linalg::refinement::ErrorBounds<E::Real>) {
+        solve_with_error_bounds_impl(self, matrix, rhs)
+    }
 }
 impl<E: ComplexField> SpSolverCore<E> for FullPivLu<E> {
     #[track_caller]
@@ -751,7 +1187,7 @@ impl<E: ComplexField> SpSolverCore<E> for FullPivLu<E> {
             self.col_permutation(),
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::full_pivoting::solve::solve_in_place_req::<usize, E>(
                     self.nrows(),
                     self.ncols(),
@@ -777,7 +1213,7 @@ impl<E: ComplexField> SpSolverCore<E> for FullPivLu<E> {
             self.col_permutation(),
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::full_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
                     self.nrows(),
                     self.ncols(),
@@ -813,7 +1249,7 @@ impl<E: ComplexField> SolverCore<E> for FullPivLu<E> {
             self.row_permutation(),
             self.col_permutation(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::full_pivoting::inverse::invert_req::<usize, E>(
                     dim,
                     dim,
@@ -836,7 +1272,7 @@ impl<E: ComplexField> SolverCore<E> for FullPivLu<E> {
             self.row_permutation(),
             self.col_permutation(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::lu::full_pivoting::reconstruct::reconstruct_req::<usize, E>(
                     self.nrows(),
                     self.ncols(),
@@ -861,9 +1297,9 @@ impl<E: ComplexField> Qr<E> {
         let ncols = matrix.ncols();
 
         let mut factors = matrix.to_owned();
+        let (norm_1, norm_inf) = norm_1_and_inf(factors.as_ref());
         let size = Ord::min(nrows, ncols);
-        let blocksize =
-            crate::linalg::qr::no_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
+        let blocksize = crate::linalg::autotune::qr_blocksize::<E>(nrows, ncols);
         let mut householder = Mat::<E>::zeros(blocksize, size);
 
         let params = Default::default();
@@ -872,7 +1308,7 @@ impl<E: ComplexField> Qr<E> {
             factors.as_mut(),
             householder.as_mut(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::no_pivoting::compute::qr_in_place_req::<E>(
                     nrows,
                     ncols,
@@ -888,6 +1324,8 @@ impl<E: ComplexField> Qr<E> {
         Self {
             factors,
             householder,
+            norm_1,
+            norm_inf,
         }
     }
 
@@ -946,7 +1384,7 @@ impl<E: ComplexField> Qr<E> {
                 Conj::No,
                 q.as_mut(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
+                PodStack::new(&mut ScratchBuffer::new(
                     crate::linalg::householder::apply_block_householder_sequence_on_the_left_in_place_req::<E>(
                         m,
                         householder.nrows(),
@@ -958,6 +1396,38 @@ impl<E: ComplexField> Qr<E> {
 
         q
     }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the 1-norm. See [`estimate_rcond_1`](crate::linalg::cond::estimate_rcond_1).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_1(self, self.norm_1.faer_real())
+    }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the ∞-norm. See [`estimate_rcond_inf`](crate::linalg::cond::estimate_rcond_inf).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond_inf(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_inf(self, self.norm_inf.faer_real())
+    }
+
+    /// Solves the equation `A * X = rhs`, where `A` is the factorized matrix, and refines the
+    /// solution using the residual computed from `matrix`, which must be equal to `A`.
+    /// See [`solve_with_refinement`](crate::linalg::refinement::solve_with_refinement).
+    #[track_caller]
+    pub fn solve_with_refinement<ViewE: Conjugate<Canonical = E>, B: ColBatch<ViewE>>(
+        &self,
+        matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+        rhs: B,
+    ) -> (B::Owned, crate::linalg::refinement::RefinementInfo<E::Real>) {
+        solve_with_refinement_impl(self, matrix, rhs)
+    }
 }
 impl<E: ComplexField> SpSolverCore<E> for Qr<E> {
     #[track_caller]
@@ -979,7 +1449,7 @@ impl<E: ComplexField> SpSolverCore<E> for Qr<E> {
             conj,
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::no_pivoting::solve::solve_transpose_in_place_req::<E>(
                     self.nrows(),
                     self.blocksize(),
@@ -1008,7 +1478,7 @@ impl<E: ComplexField> SolverCore<E> for Qr<E> {
             self.factors.as_ref(),
             self.householder.as_ref(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::no_pivoting::reconstruct::reconstruct_req::<E>(
                     self.nrows(),
                     self.ncols(),
@@ -1033,7 +1503,7 @@ impl<E: ComplexField> SolverCore<E> for Qr<E> {
             self.factors.as_ref(),
             self.householder.as_ref(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::no_pivoting::inverse::invert_req::<E>(
                     self.nrows(),
                     self.ncols(),
@@ -1060,7 +1530,7 @@ impl<E: ComplexField> SpSolverLstsqCore<E> for Qr<E> {
             conj,
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::no_pivoting::solve::solve_in_place_req::<E>(
                     self.nrows(),
                     self.blocksize(),
@@ -1085,9 +1555,9 @@ impl<E: ComplexField> ColPivQr<E> {
         let ncols = matrix.ncols();
 
         let mut factors = matrix.to_owned();
+        let (norm_1, norm_inf) = norm_1_and_inf(factors.as_ref());
         let size = Ord::min(nrows, ncols);
-        let blocksize =
-            crate::linalg::qr::col_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
+        let blocksize = crate::linalg::autotune::qr_blocksize::<E>(nrows, ncols);
         let mut householder = Mat::<E>::zeros(blocksize, size);
 
         let params = Default::default();
@@ -1101,7 +1571,7 @@ impl<E: ComplexField> ColPivQr<E> {
             &mut col_perm,
             &mut col_perm_inv,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::col_pivoting::compute::qr_in_place_req::<usize, E>(
                     nrows,
                     ncols,
@@ -1119,6 +1589,8 @@ impl<E: ComplexField> ColPivQr<E> {
             householder,
             col_perm,
             col_perm_inv,
+            norm_1,
+            norm_inf,
         }
     }
 
@@ -1164,6 +1636,26 @@ impl<E: ComplexField> ColPivQr<E> {
     pub fn compute_thin_q(&self) -> Mat<E> {
         Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), true)
     }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the 1-norm. See [`estimate_rcond_1`](crate::linalg::cond::estimate_rcond_1).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_1(self, self.norm_1.faer_real())
+    }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the ∞-norm. See [`estimate_rcond_inf`](crate::linalg::cond::estimate_rcond_inf).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond_inf(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_inf(self, self.norm_inf.faer_real())
+    }
 }
 impl<E: ComplexField> SpSolverCore<E> for ColPivQr<E> {
     #[track_caller]
@@ -1186,7 +1678,7 @@ impl<E: ComplexField> SpSolverCore<E> for ColPivQr<E> {
             conj,
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::col_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
                     self.nrows(),
                     self.blocksize(),
@@ -1216,7 +1708,7 @@ impl<E: ComplexField> SolverCore<E> for ColPivQr<E> {
             self.householder.as_ref(),
             self.col_permutation(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::col_pivoting::reconstruct::reconstruct_req::<usize, E>(
                     self.nrows(),
                     self.ncols(),
@@ -1242,7 +1734,7 @@ impl<E: ComplexField> SolverCore<E> for ColPivQr<E> {
             self.householder.as_ref(),
             self.col_permutation(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::col_pivoting::inverse::invert_req::<usize, E>(
                     self.nrows(),
                     self.ncols(),
@@ -1270,7 +1762,7 @@ impl<E: ComplexField> SpSolverLstsqCore<E> for ColPivQr<E> {
             conj,
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::qr::col_pivoting::solve::solve_in_place_req::<usize, E>(
                     self.nrows(),
                     self.blocksize(),
@@ -1309,7 +1801,7 @@ impl<E: ComplexField> Svd<E> {
             Some(u.as_mut()),
             Some(v.as_mut()),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::svd::compute_svd_req::<E>(
                     m,
                     n,
@@ -1328,7 +1820,14 @@ impl<E: ComplexField> Svd<E> {
             zipped!(v.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
         }
 
-        Self { s, u, v }
+        let (norm_1, norm_inf) = norm_1_and_inf(matrix);
+        Self {
+            s,
+            u,
+            v,
+            norm_1,
+            norm_inf,
+        }
     }
 
     /// Returns the SVD of the input matrix.
@@ -1361,6 +1860,26 @@ impl<E: ComplexField> Svd<E> {
             self.v(),
         )
     }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the 1-norm. See [`estimate_rcond_1`](crate::linalg::cond::estimate_rcond_1).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_1(self, self.norm_1.faer_real())
+    }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the ∞-norm. See [`estimate_rcond_inf`](crate::linalg::cond::estimate_rcond_inf).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond_inf(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_inf(self, self.norm_inf.faer_real())
+    }
 }
 
 fn div_by_s<E: ComplexField>(rhs: MatMut<'_, E>, s: MatRef<'_, E>) {
@@ -1488,6 +2007,26 @@ impl<E: ComplexField> ThinSvd<E> {
             self.v(),
         )
     }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the 1-norm. See [`estimate_rcond_1`](crate::linalg::cond::estimate_rcond_1).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_1(self, self.inner.norm_1.faer_real())
+    }
+
+    /// Returns an estimate of the reciprocal of the condition number of the factorized matrix in
+    /// the ∞-norm. See [`estimate_rcond_inf`](crate::linalg::cond::estimate_rcond_inf).
+    ///
+    /// # Panics
+    /// The function panics if the factorized matrix is not square.
+    #[track_caller]
+    pub fn rcond_inf(&self) -> E::Real {
+        crate::linalg::cond::estimate_rcond_inf(self, self.inner.norm_inf.faer_real())
+    }
 }
 impl<E: ComplexField> SpSolverCore<E> for ThinSvd<E> {
     fn nrows(&self) -> usize {
@@ -1545,7 +2084,7 @@ impl<E: ComplexField> SelfAdjointEigendecomposition<E> {
             s.as_mut(),
             Some(u.as_mut()),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::evd::compute_hermitian_evd_req::<E>(
                     dim,
                     crate::linalg::evd::ComputeVectors::Yes,
@@ -1687,7 +2226,7 @@ impl<E: ComplexField> Eigendecomposition<E> {
             s_im.as_mut(),
             None,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::evd::compute_evd_req::<E::Real>(
                     dim,
                     crate::linalg::evd::ComputeVectors::Yes,
@@ -1733,7 +2272,7 @@ impl<E: ComplexField> Eigendecomposition<E> {
             s.as_mut(),
             None,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::evd::compute_evd_req::<E>(
                     dim,
                     crate::linalg::evd::ComputeVectors::Yes,
@@ -1781,7 +2320,7 @@ impl<E: ComplexField> Eigendecomposition<E> {
             s_im.as_mut().as_2d_mut(),
             Some(u_real.as_mut()),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::evd::compute_evd_req::<E::Real>(
                     dim,
                     crate::linalg::evd::ComputeVectors::Yes,
@@ -1854,7 +2393,7 @@ impl<E: ComplexField> Eigendecomposition<E> {
             s.as_mut().as_2d_mut(),
             Some(u.as_mut()),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::evd::compute_evd_req::<E>(
                     dim,
                     crate::linalg::evd::ComputeVectors::Yes,
@@ -1894,6 +2433,110 @@ impl<E: ComplexField> Eigendecomposition<E> {
     }
 }
 
+// returns the schatten p-norm given the singular values, which are scaled by the largest one to
+// avoid overflow and underflow
+#[cfg(feature = "std")]
+fn schatten_from_singular_values<R: RealField + num_traits::Float>(s: &[R], p: R) -> R {
+    let max = s
+        .iter()
+        .fold(R::faer_zero(), |acc, &x| if x > acc { x } else { acc });
+    if max == R::faer_zero() || num_traits::Float::is_infinite(p) {
+        return max;
+    }
+
+    let inv = max.faer_inv();
+    let sum = s.iter().fold(R::faer_zero(), |acc, &x| {
+        acc.faer_add(num_traits::Float::powf(x.faer_mul(inv), p))
+    });
+    max.faer_mul(num_traits::Float::powf(sum, p.faer_inv()))
+}
+
+// returns the determinant of a matrix of size at most `4`, using a cofactor expansion
+fn small_determinant<E: ComplexField>(matrix: MatRef<'_, E>) -> E {
+    let a = |i: usize, j: usize| matrix.read(i, j);
+    // determinant of the 2×2 submatrix made of rows `i0, i1` and columns `j0, j1`
+    let minor = |i0: usize, i1: usize, j0: usize, j1: usize| {
+        a(i0, j0)
+            .faer_mul(a(i1, j1))
+            .faer_sub(a(i1, j0).faer_mul(a(i0, j1)))
+    };
+
+    match matrix.nrows() {
+        0 => E::faer_one(),
+        1 => a(0, 0),
+        2 => minor(0, 1, 0, 1),
+        3 => a(0, 0)
+            .faer_mul(minor(1, 2, 1, 2))
+            .faer_sub(a(0, 1).faer_mul(minor(1, 2, 0, 2)))
+            .faer_add(a(0, 2).faer_mul(minor(1, 2, 0, 1))),
+        4 => {
+            // laplace expansion along the first two rows
+            let top = [
+                minor(0, 1, 0, 1),
+                minor(0, 1, 0, 2),
+                minor(0, 1, 0, 3),
+                minor(0, 1, 1, 2),
+                minor(0, 1, 1, 3),
+                minor(0, 1, 2, 3),
+            ];
+            let bottom = [
+                minor(2, 3, 2, 3),
+                minor(2, 3, 1, 3),
+                minor(2, 3, 1, 2),
+                minor(2, 3, 0, 3),
+                minor(2, 3, 0, 2),
+                minor(2, 3, 0, 1),
+            ];
+            top[0]
+                .faer_mul(bottom[0])
+                .faer_sub(top[1].faer_mul(bottom[1]))
+                .faer_add(top[2].faer_mul(bottom[2]))
+                .faer_add(top[3].faer_mul(bottom[3]))
+                .faer_sub(top[4].faer_mul(bottom[4]))
+                .faer_add(top[5].faer_mul(bottom[5]))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// returns estimates of the `rank` largest singular values of `matrix`, using a randomized range
+// finder
+#[cfg(feature = "rand")]
+fn randomized_singular_values<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    rank: usize,
+    rng: &mut (impl ?Sized + rand::Rng),
+) -> alloc::vec::Vec<E::Real>
+where
+    rand_distr::StandardNormal: rand::distributions::Distribution<E>,
+{
+    use rand::distributions::Distribution;
+
+    let size = Ord::min(matrix.nrows(), matrix.ncols());
+    let rank = Ord::min(rank, size);
+    // oversampling improves the accuracy of the leading singular values
+    let sketch_size = Ord::min(rank + 10, size);
+
+    let omega: Mat<E> = crate::stats::StandardNormalMat {
+        nrows: matrix.ncols(),
+        ncols: sketch_size,
+    }
+    .sample(rng);
+    let mut y = matrix * omega.as_ref();
+
+    // power iterations make the sketch concentrate on the dominant singular vectors
+    for _ in 0..2 {
+        let q = y.qr().compute_thin_q();
+        let q = (matrix.adjoint() * q.as_ref()).qr().compute_thin_q();
+        y = matrix * q.as_ref();
+    }
+
+    let q = y.qr().compute_thin_q();
+    let mut s = (q.adjoint() * matrix).singular_values();
+    s.truncate(rank);
+    s
+}
+
 impl<E: Conjugate> MatRef<'_, E>
 where
     E::Canonical: ComplexField,
@@ -2097,9 +2740,18 @@ where
     }
 
     /// Returns the determinant of `self`.
+    ///
+    /// Matrices of size up to `4` use a cofactor expansion, and larger matrices use the LU
+    /// decomposition with partial pivoting.
+    ///
+    /// # Panics
+    /// The function panics if `self` is not square.
     #[track_caller]
     pub fn determinant(&self) -> E::Canonical {
         assert!(self.nrows() == self.ncols());
+        if self.nrows() <= 4 {
+            return small_determinant(self.canonicalize().0);
+        }
         let lu = self.partial_piv_lu();
         let mut det = E::Canonical::faer_one();
         for i in 0..self.nrows() {
@@ -2112,6 +2764,51 @@ where
         }
     }
 
+    /// Returns `self` raised to the integer power `k`, computed by repeated squaring.
+    ///
+    /// If `k` is negative, the inverse of `self` is computed from its LU decomposition with
+    /// partial pivoting, then raised to the power `-k`. If `k` is zero, the identity matrix is
+    /// returned.
+    ///
+    /// # Panics
+    /// The function panics if `self` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 1.0], [1.0, 0.0f64]];
+    ///
+    /// assert_eq!(a.powi(10), mat![[89.0, 55.0], [55.0, 34.0]]);
+    /// assert_eq!(a.powi(-1), mat![[0.0, 1.0], [1.0, -1.0]]);
+    /// ```
+    #[track_caller]
+    pub fn powi(&self, k: i32) -> Mat<E::Canonical> {
+        assert!(self.nrows() == self.ncols());
+        let n = self.nrows();
+
+        let mut base = if k < 0 {
+            self.partial_piv_lu().inverse()
+        } else {
+            self.to_owned()
+        };
+        let mut k = k.unsigned_abs();
+        let mut acc = None::<Mat<E::Canonical>>;
+        while k > 0 {
+            if k % 2 == 1 {
+                acc = Some(match acc {
+                    Some(acc) => &acc * &base,
+                    None => base.clone(),
+                });
+            }
+            k /= 2;
+            if k > 0 {
+                base = &base * &base;
+            }
+        }
+        acc.unwrap_or_else(|| Mat::identity(n, n))
+    }
+
     /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the provided
     /// side is accessed. The order of the eigenvalues is currently unspecified.
     #[track_caller]
@@ -2136,7 +2833,7 @@ where
             s.as_mut(),
             None,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::evd::compute_hermitian_evd_req::<E::Canonical>(
                     dim,
                     crate::linalg::evd::ComputeVectors::No,
@@ -2165,7 +2862,7 @@ where
             None,
             None,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 crate::linalg::svd::compute_svd_req::<E::Canonical>(
                     self.nrows(),
                     self.ncols(),
@@ -2182,6 +2879,252 @@ where
         (0..dim).map(|i| s.read(i, 0).faer_real()).collect()
     }
 
+    /// Returns the nuclear norm of `self`, i.e., the sum of its singular values.
+    #[track_caller]
+    pub fn norm_nuclear(&self) -> <E::Canonical as ComplexField>::Real {
+        self.singular_values().into_iter().fold(
+            <E::Canonical as ComplexField>::Real::faer_zero(),
+            |acc, x| acc.faer_add(x),
+        )
+    }
+
+    /// Returns the Schatten `p`-norm of `self`, i.e., the `p`-norm of the vector of its singular
+    /// values.
+    ///
+    /// For `p = 1`, this is the nuclear norm, for `p = 2`, the Frobenius norm, and for `p = ∞`, the
+    /// spectral norm.
+    ///
+    /// # Panics
+    /// The function panics if `p` is not positive.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[track_caller]
+    pub fn norm_schatten(
+        &self,
+        p: <E::Canonical as ComplexField>::Real,
+    ) -> <E::Canonical as ComplexField>::Real
+    where
+        <E::Canonical as ComplexField>::Real: num_traits::Float,
+    {
+        assert!(p > <E::Canonical as ComplexField>::Real::faer_zero());
+        schatten_from_singular_values(&self.singular_values(), p)
+    }
+
+    /// Returns an approximation of the Schatten `p`-norm of `self`, computed from estimates of its
+    /// `rank` largest singular values. See [`MatRef::norm_schatten`].
+    ///
+    /// The singular values are estimated by projecting `self` onto an approximation of its
+    /// dominant range, computed from a random sketch with a few power iterations, which is much
+    /// cheaper than a full SVD when `rank` is small compared to the dimensions of `self`.
+    ///
+    /// The result is a lower bound of the exact norm, which is accurate when the singular values
+    /// of `self` beyond the first `rank` ones are negligible.
+    ///
+    /// # Panics
+    /// The function panics if `p` is not positive.
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "rand"))))]
+    #[track_caller]
+    pub fn norm_schatten_randomized(
+        &self,
+        p: <E::Canonical as ComplexField>::Real,
+        rank: usize,
+        rng: &mut (impl ?Sized + rand::Rng),
+    ) -> <E::Canonical as ComplexField>::Real
+    where
+        <E::Canonical as ComplexField>::Real: num_traits::Float,
+        rand_distr::StandardNormal: rand::distributions::Distribution<E::Canonical>,
+    {
+        assert!(p > <E::Canonical as ComplexField>::Real::faer_zero());
+        let s = randomized_singular_values(self.canonicalize().0, rank, rng);
+        schatten_from_singular_values(&s, p)
+    }
+
+    /// Returns an estimate of the operator 2-norm of `self`, i.e., its largest singular value,
+    /// computed with the power iteration on `Aᴴ A`.
+    ///
+    /// The iteration stops once the relative change of the estimate between two consecutive steps
+    /// is at most `tol`, or after `max_iter` steps, each of which costs two matrix-vector products.
+    /// This is much cheaper than computing the singular values when only a rough value is needed.
+    /// The result is a lower bound of the exact norm.
+    ///
+    /// See also [`crate::linop::norm_est::norm_l2_est`] for general linear operators.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[3.0, 0.0], [0.0, -4.0f64]];
+    /// let norm = a.norm_l2_operator_est(1e-12, 100);
+    /// assert!((norm - 4.0).abs() < 1e-8);
+    /// ```
+    #[track_caller]
+    pub fn norm_l2_operator_est(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        max_iter: usize,
+    ) -> <E::Canonical as ComplexField>::Real {
+        let matrix = *self;
+        let parallelism = get_global_parallelism();
+        crate::linop::norm_est::norm_l2_est::<E::Canonical>(
+            matrix,
+            tol,
+            max_iter,
+            parallelism,
+            PodStack::new(&mut ScratchBuffer::new(
+                crate::linop::norm_est::norm_l2_est_req::<E::Canonical>(matrix, parallelism)
+                    .unwrap(),
+            )),
+        )
+    }
+
+    /// Returns the numerical rank of `self`, i.e., the number of singular values that are larger
+    /// than `tol` times the largest one.
+    ///
+    /// A common choice for `tol` is `max(nrows, ncols) * ε`, where `ε` is the machine epsilon.
+    /// See [`MatRef::rank_with`] for a cheaper alternative.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0f64]];
+    /// assert!(a.rank(1e-12) == 2);
+    /// ```
+    #[track_caller]
+    pub fn rank(&self, tol: <E::Canonical as ComplexField>::Real) -> usize {
+        numerical_rank(&self.singular_values(), tol)
+    }
+
+    /// Returns the numerical rank of `self`, determined with the given `method`, along with the
+    /// values it was determined from. The rank is the number of leading values that are larger
+    /// than `tol` times the first one.
+    #[track_caller]
+    pub fn rank_with(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        method: RankMethod,
+    ) -> RankInfo<<E::Canonical as ComplexField>::Real> {
+        match method {
+            RankMethod::Svd => {
+                let values = self.singular_values();
+                RankInfo {
+                    rank: numerical_rank(&values, tol),
+                    values,
+                    col_perm: None,
+                }
+            }
+            RankMethod::ColPivQr => {
+                let qr = self.col_piv_qr();
+                let size = Ord::min(self.nrows(), self.ncols());
+                let values: alloc::vec::Vec<_> = (0..size)
+                    .map(|i| qr.factors.read(i, i).faer_abs())
+                    .collect();
+                RankInfo {
+                    rank: numerical_rank(&values, tol),
+                    values,
+                    col_perm: Some(qr.col_permutation().to_owned()),
+                }
+            }
+        }
+    }
+
+    /// Returns an estimate of the numerical rank of `self`, computed from estimates of its
+    /// `max_rank` largest singular values, along with these estimates. See [`MatRef::rank`].
+    ///
+    /// The singular values are estimated with a randomized sketch, which is much cheaper than a
+    /// full SVD when `max_rank` is small compared to the dimensions of `self`. If the returned
+    /// rank is equal to `max_rank`, the actual rank may be larger.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[track_caller]
+    pub fn rank_est(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        max_rank: usize,
+        rng: &mut (impl ?Sized + rand::Rng),
+    ) -> RankInfo<<E::Canonical as ComplexField>::Real>
+    where
+        rand_distr::StandardNormal: rand::distributions::Distribution<E::Canonical>,
+    {
+        let values = randomized_singular_values(self.canonicalize().0, max_rank, rng);
+        RankInfo {
+            rank: numerical_rank(&values, tol),
+            values,
+            col_perm: None,
+        }
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of `self`, computed from its singular value
+    /// decomposition, where the singular values that are at most `tol` times the largest one are
+    /// treated as zero.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// // the second singular value is negligible, and is treated as zero
+    /// let a = mat![[1.0, 0.0], [0.0, 1e-20], [0.0, 0.0f64]];
+    /// let pinv = a.pinv(1e-12);
+    /// assert!((pinv - mat![[1.0, 0.0, 0.0], [0.0, 0.0, 0.0]]).norm_max() < 1e-12);
+    /// ```
+    #[track_caller]
+    pub fn pinv(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        let svd = self.thin_svd();
+        crate::linalg::svd::pseudo_inverse::compute_pseudoinverse_with_tolerance(
+            svd.s_diagonal(),
+            svd.u(),
+            svd.v(),
+            tol,
+        )
+    }
+
+    /// Returns the product of the Moore-Penrose pseudo-inverse of `self` with `rhs`, i.e., the
+    /// minimum norm solution of the least squares problem `min ‖self * X - rhs‖`, without forming
+    /// the pseudo-inverse. See [`MatRef::pinv`].
+    ///
+    /// # Panics
+    /// The function panics if `rhs` doesn't have the same number of rows as `self`.
+    #[track_caller]
+    pub fn pinv_solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        rhs: B,
+    ) -> B::Owned {
+        let rhs_view = rhs.as_2d_ref();
+        assert!(rhs_view.nrows() == self.nrows());
+        let mut out = B::new_owned_zeros(self.ncols(), rhs_view.ncols());
+        let svd = self.thin_svd();
+        crate::linalg::svd::pseudo_inverse::apply_pseudoinverse(
+            svd.s_diagonal(),
+            svd.u(),
+            svd.v(),
+            tol,
+            out.as_2d_mut(),
+            rhs_view,
+            get_global_parallelism(),
+        );
+        out
+    }
+
+    /// Returns the minimum norm solution of the least squares problem `min ‖self * X - rhs‖`. See
+    /// [`lstsq`](crate::linalg::lstsq) for more details, and for the residual norms and the
+    /// effective rank of `self`.
+    ///
+    /// # Panics
+    /// The function panics if `rhs` doesn't have the same number of rows as `self`.
+    #[track_caller]
+    pub fn lstsq<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        let rhs_view = rhs.as_2d_ref();
+        let solution = crate::linalg::lstsq(*self, rhs_view).solution;
+        let mut out = B::new_owned_zeros(solution.nrows(), solution.ncols());
+        out.as_2d_mut().copy_from(solution.as_ref());
+        out
+    }
+
     /// Returns the eigenvalues of `self`, as complex values. The order of the eigenvalues is
     /// currently unspecified.
     #[track_caller]
@@ -2375,6 +3318,12 @@ where
         self.as_ref().determinant()
     }
 
+    /// Returns `self` raised to the integer power `k`. See [`MatRef::powi`].
+    #[track_caller]
+    pub fn powi(&self, k: i32) -> Mat<E::Canonical> {
+        self.as_ref().powi(k)
+    }
+
     /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the provided
     /// side is accessed. The order of the eigenvalues is currently unspecified.
     #[track_caller]
@@ -2392,6 +3341,115 @@ where
         self.as_ref().singular_values()
     }
 
+    /// Returns the nuclear norm of `self`. See [`MatRef::norm_nuclear`].
+    #[track_caller]
+    pub fn norm_nuclear(&self) -> <E::Canonical as ComplexField>::Real {
+        self.as_ref().norm_nuclear()
+    }
+
+    /// Returns the Schatten `p`-norm of `self`. See [`MatRef::norm_schatten`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[track_caller]
+    pub fn norm_schatten(
+        &self,
+        p: <E::Canonical as ComplexField>::Real,
+    ) -> <E::Canonical as ComplexField>::Real
+    where
+        <E::Canonical as ComplexField>::Real: num_traits::Float,
+    {
+        self.as_ref().norm_schatten(p)
+    }
+
+    /// Returns an approximation of the Schatten `p`-norm of `self`. See
+    /// [`MatRef::norm_schatten_randomized`].
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "rand"))))]
+    #[track_caller]
+    pub fn norm_schatten_randomized(
+        &self,
+        p: <E::Canonical as ComplexField>::Real,
+        rank: usize,
+        rng: &mut (impl ?Sized + rand::Rng),
+    ) -> <E::Canonical as ComplexField>::Real
+    where
+        <E::Canonical as ComplexField>::Real: num_traits::Float,
+        rand_distr::StandardNormal: rand::distributions::Distribution<E::Canonical>,
+    {
+        self.as_ref().norm_schatten_randomized(p, rank, rng)
+    }
+
+    /// Returns an estimate of the operator 2-norm of `self`. See
+    /// [`MatRef::norm_l2_operator_est`].
+    #[track_caller]
+    pub fn norm_l2_operator_est(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        max_iter: usize,
+    ) -> <E::Canonical as ComplexField>::Real {
+        self.as_ref().norm_l2_operator_est(tol, max_iter)
+    }
+
+    /// Returns the numerical rank of `self`. See [`MatRef::rank`].
+    #[track_caller]
+    pub fn rank(&self, tol: <E::Canonical as ComplexField>::Real) -> usize {
+        self.as_ref().rank(tol)
+    }
+
+    /// Returns the numerical rank of `self`, determined with the given `method`. See
+    /// [`MatRef::rank_with`].
+    #[track_caller]
+    pub fn rank_with(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        method: RankMethod,
+    ) -> RankInfo<<E::Canonical as ComplexField>::Real> {
+        self.as_ref().rank_with(tol, method)
+    }
+
+    /// Returns an estimate of the numerical rank of `self`. See [`MatRef::rank_est`].
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[track_caller]
+    pub fn rank_est(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        max_rank: usize,
+        rng: &mut (impl ?Sized + rand::Rng),
+    ) -> RankInfo<<E::Canonical as ComplexField>::Real>
+    where
+        rand_distr::StandardNormal: rand::distributions::Distribution<E::Canonical>,
+    {
+        self.as_ref().rank_est(tol, max_rank, rng)
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of `self`. See [`MatRef::pinv`].
+    #[track_caller]
+    pub fn pinv(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        self.as_ref().pinv(tol)
+    }
+
+    /// Returns the product of the Moore-Penrose pseudo-inverse of `self` with `rhs`. See
+    /// [`MatRef::pinv_solve`].
+    #[track_caller]
+    pub fn pinv_solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        rhs: B,
+    ) -> B::Owned {
+        self.as_ref().pinv_solve(tol, rhs)
+    }
+
+    /// Returns the minimum norm solution of the least squares problem `min ‖self * X - rhs‖`. See
+    /// [`MatRef::lstsq`].
+    #[track_caller]
+    pub fn lstsq<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.as_ref().lstsq(rhs)
+    }
+
     /// Returns the eigenvalues of `self`, as complex values. The order of the eigenvalues is
     /// currently unspecified.
     #[track_caller]
@@ -2572,6 +3630,12 @@ where
         self.as_ref().determinant()
     }
 
+    /// Returns `self` raised to the integer power `k`. See [`MatRef::powi`].
+    #[track_caller]
+    pub fn powi(&self, k: i32) -> Mat<E::Canonical> {
+        self.as_ref().powi(k)
+    }
+
     /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the provided
     /// side is accessed. The order of the eigenvalues is currently unspecified.
     #[track_caller]
@@ -2589,6 +3653,115 @@ where
         self.as_ref().singular_values()
     }
 
+    /// Returns the nuclear norm of `self`. See [`MatRef::norm_nuclear`].
+    #[track_caller]
+    pub fn norm_nuclear(&self) -> <E::Canonical as ComplexField>::Real {
+        self.as_ref().norm_nuclear()
+    }
+
+    /// Returns the Schatten `p`-norm of `self`. See [`MatRef::norm_schatten`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[track_caller]
+    pub fn norm_schatten(
+        &self,
+        p: <E::Canonical as ComplexField>::Real,
+    ) -> <E::Canonical as ComplexField>::Real
+    where
+        <E::Canonical as ComplexField>::Real: num_traits::Float,
+    {
+        self.as_ref().norm_schatten(p)
+    }
+
+    /// Returns an approximation of the Schatten `p`-norm of `self`. See
+    /// [`MatRef::norm_schatten_randomized`].
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "rand"))))]
+    #[track_caller]
+    pub fn norm_schatten_randomized(
+        &self,
+        p: <E::Canonical as ComplexField>::Real,
+        rank: usize,
+        rng: &mut (impl ?Sized + rand::Rng),
+    ) -> <E::Canonical as ComplexField>::Real
+    where
+        <E::Canonical as ComplexField>::Real: num_traits::Float,
+        rand_distr::StandardNormal: rand::distributions::Distribution<E::Canonical>,
+    {
+        self.as_ref().norm_schatten_randomized(p, rank, rng)
+    }
+
+    /// Returns an estimate of the operator 2-norm of `self`. See
+    /// [`MatRef::norm_l2_operator_est`].
+    #[track_caller]
+    pub fn norm_l2_operator_est(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        max_iter: usize,
+    ) -> <E::Canonical as ComplexField>::Real {
+        self.as_ref().norm_l2_operator_est(tol, max_iter)
+    }
+
+    /// Returns the numerical rank of `self`. See [`MatRef::rank`].
+    #[track_caller]
+    pub fn rank(&self, tol: <E::Canonical as ComplexField>::Real) -> usize {
+        self.as_ref().rank(tol)
+    }
+
+    /// Returns the numerical rank of `self`, determined with the given `method`. See
+    /// [`MatRef::rank_with`].
+    #[track_caller]
+    pub fn rank_with(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        method: RankMethod,
+    ) -> RankInfo<<E::Canonical as ComplexField>::Real> {
+        self.as_ref().rank_with(tol, method)
+    }
+
+    /// Returns an estimate of the numerical rank of `self`. See [`MatRef::rank_est`].
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[track_caller]
+    pub fn rank_est(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        max_rank: usize,
+        rng: &mut (impl ?Sized + rand::Rng),
+    ) -> RankInfo<<E::Canonical as ComplexField>::Real>
+    where
+        rand_distr::StandardNormal: rand::distributions::Distribution<E::Canonical>,
+    {
+        self.as_ref().rank_est(tol, max_rank, rng)
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of `self`. See [`MatRef::pinv`].
+    #[track_caller]
+    pub fn pinv(&self, tol: <E::Canonical as ComplexField>::Real) -> Mat<E::Canonical> {
+        self.as_ref().pinv(tol)
+    }
+
+    /// Returns the product of the Moore-Penrose pseudo-inverse of `self` with `rhs`. See
+    /// [`MatRef::pinv_solve`].
+    #[track_caller]
+    pub fn pinv_solve<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+        rhs: B,
+    ) -> B::Owned {
+        self.as_ref().pinv_solve(tol, rhs)
+    }
+
+    /// Returns the minimum norm solution of the least squares problem `min ‖self * X - rhs‖`. See
+    /// [`MatRef::lstsq`].
+    #[track_caller]
+    pub fn lstsq<ViewE: Conjugate<Canonical = E::Canonical>, B: ColBatch<ViewE>>(
+        &self,
+        rhs: B,
+    ) -> B::Owned {
+        self.as_ref().lstsq(rhs)
+    }
+
     /// Returns the eigenvalues of `self`, as complex values. The order of the eigenvalues is
     /// currently unspecified.
     #[track_caller]
@@ -3101,4 +4274,357 @@ mod tests {
         let diff = (p * a * q.inverse()) - (l * u);
         assert!(diff.norm_max() < 1e-12);
     }
+
+    #[test]
+    fn test_powi() {
+        let n = 5;
+
+        // well conditioned, so that the inverse is accurate
+        let random = |i: usize, j: usize| {
+            let x = c64::new(rand::random(), rand::random()) * (1.0 / n as f64);
+            if i == j {
+                x + 1.0
+            } else {
+                x
+            }
+        };
+        let H = Mat::from_fn(n, n, random);
+
+        let mut expected = Mat::<c64>::identity(n, n);
+        for k in 0..8 {
+            assert_approx_eq(H.powi(k), &expected);
+            expected = &expected * &H;
+        }
+
+        let inv = H.partial_piv_lu().inverse();
+        assert_approx_eq(H.powi(-1), &inv);
+        assert_approx_eq(H.powi(-3), &inv * &inv * &inv);
+        assert_approx_eq(H.as_ref().powi(-2) * H.powi(2), Mat::<c64>::identity(n, n));
+    }
+
+    #[test]
+    fn test_schatten() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let rng = &mut StdRng::seed_from_u64(0);
+        let (m, n, rank) = (40, 30, 3);
+
+        // low rank matrix with singular values 1, 2 and 4
+        let u = Mat::<c64>::from_fn(m, m, |_, _| c64::new(rng.gen(), rng.gen()))
+            .qr()
+            .compute_thin_q();
+        let v = Mat::<c64>::from_fn(n, n, |_, _| c64::new(rng.gen(), rng.gen()))
+            .qr()
+            .compute_thin_q();
+        let s = Mat::<c64>::from_fn(rank, rank, |i, j| {
+            if i == j {
+                c64::new((1 << i) as f64, 0.0)
+            } else {
+                c64::new(0.0, 0.0)
+            }
+        });
+        let H = u.as_ref().subcols(0, rank) * &s * v.as_ref().subcols(0, rank).adjoint();
+
+        let approx_eq = |a: f64, b: f64| assert!((a - b).abs() < 1e-10);
+        approx_eq(H.norm_nuclear(), 7.0);
+        approx_eq(H.norm_schatten(1.0), 7.0);
+        approx_eq(H.norm_schatten(2.0), H.norm_l2());
+        approx_eq(H.norm_schatten(3.0), 73.0f64.powf(1.0 / 3.0));
+        approx_eq(H.norm_schatten(f64::INFINITY), 4.0);
+
+        approx_eq(H.norm_schatten_randomized(1.0, rank, rng), 7.0);
+        approx_eq(H.norm_schatten_randomized(2.0, rank + 5, rng), H.norm_l2());
+        // only the largest singular values are taken into account
+        approx_eq(H.norm_schatten_randomized(1.0, 1, rng), 4.0);
+
+        assert!(Mat::<f64>::zeros(3, 2).norm_schatten(1.5) == 0.0);
+    }
+
+    #[test]
+    fn test_rcond() {
+        let n = 7;
+        let H = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+        let P = H.as_ref() * H.adjoint();
+
+        let norm_1 = |m: MatRef<'_, c64>| m.col_iter().map(|col| col.norm_l1()).fold(0.0, f64::max);
+        let norm_inf = |m: MatRef<'_, c64>| norm_1(m.transpose());
+
+        let inv = H.partial_piv_lu().inverse();
+        let rcond_1 = 1.0 / (norm_1(H.as_ref()) * norm_1(inv.as_ref()));
+        let rcond_inf = 1.0 / (norm_inf(H.as_ref()) * norm_inf(inv.as_ref()));
+
+        // the estimate of the norm of the inverse is a lower bound, and is usually within a factor
+        // of 3 of the exact value
+        let check =
+            |est: f64, exact: f64| assert!(all(est >= exact * (1.0 - 1e-8), est <= 10.0 * exact));
+
+        for (est_1, est_inf) in [
+            (H.partial_piv_lu().rcond(), H.partial_piv_lu().rcond_inf()),
+            (H.full_piv_lu().rcond(), H.full_piv_lu().rcond_inf()),
+            (H.qr().rcond(), H.qr().rcond_inf()),
+            (H.col_piv_qr().rcond(), H.col_piv_qr().rcond_inf()),
+            (H.svd().rcond(), H.svd().rcond_inf()),
+            (H.thin_svd().rcond(), H.thin_svd().rcond_inf()),
+        ] {
+            check(est_1, rcond_1);
+            check(est_inf, rcond_inf);
+        }
+
+        let inv = P.partial_piv_lu().inverse();
+        let rcond = 1.0 / (norm_1(P.as_ref()) * norm_1(inv.as_ref()));
+        let llt = P.cholesky(Side::Lower).unwrap();
+        check(llt.rcond(), rcond);
+        assert!(llt.rcond_inf() == llt.rcond());
+        let llt = P.cholesky(Side::Upper).unwrap();
+        check(llt.rcond(), rcond);
+    }
+
+    #[test]
+    fn test_logdet() {
+        let n = 7;
+        let H = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+        let P = H.as_ref() * H.adjoint();
+
+        // det = sign * exp(log_abs)
+        let check = |(sign, log_abs): (c64, f64), det: c64| {
+            assert!((sign.abs() - 1.0).abs() < 1e-12);
+            assert!((sign * log_abs.exp() - det).abs() < 1e-8 * det.abs());
+        };
+
+        let det = H.determinant();
+        check(H.partial_piv_lu().logdet(), det);
+        check(H.full_piv_lu().logdet(), det);
+
+        let det = P.determinant();
+        check(P.cholesky(Side::Lower).unwrap().logdet(), det);
+        check(P.lblt(Side::Lower).logdet(), det);
+        check(P.lblt(Side::Upper).logdet(), det);
+        assert!(P.cholesky(Side::Lower).unwrap().logdet().0 == c64::new(1.0, 0.0));
+
+        // symmetric indefinite matrix, which requires 2×2 pivots
+        let A = Mat::<f64>::from_fn(n, n, |i, j| {
+            if i == j {
+                0.0
+            } else {
+                1.0 / (i + j + 1) as f64
+            }
+        });
+        let det = A.determinant();
+        let (sign, log_abs) = A.lblt(Side::Lower).logdet();
+        assert!(sign == det.signum());
+        assert!((log_abs - det.abs().ln()).abs() < 1e-8);
+        let (sign, log_abs) = A.partial_piv_lu().logdet();
+        assert!(sign == det.signum());
+        assert!((log_abs - det.abs().ln()).abs() < 1e-8);
+
+        // the logarithm doesn't overflow when the determinant does
+        let A = Mat::<f64>::from_fn(200, 200, |i, j| if i == j { 1e10 } else { 0.0 });
+        assert!(A.determinant() == f64::INFINITY);
+        let (sign, log_abs) = A.partial_piv_lu().logdet();
+        assert!(sign == 1.0);
+        assert!((log_abs - 2000.0 * 10.0f64.ln()).abs() < 1e-8);
+        let (sign, log_abs) = A.cholesky(Side::Lower).unwrap().logdet();
+        assert!(sign == 1.0);
+        assert!((log_abs - 2000.0 * 10.0f64.ln()).abs() < 1e-8);
+
+        let Z = Mat::<f64>::zeros(3, 3);
+        assert!(Z.partial_piv_lu().logdet() == (0.0, f64::NEG_INFINITY));
+        assert!(Z.full_piv_lu().logdet() == (0.0, f64::NEG_INFINITY));
+        assert!(Z.lblt(Side::Lower).logdet() == (0.0, f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_rank() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let ref mut rng = StdRng::seed_from_u64(0);
+        let m = 30;
+        let n = 20;
+        let r = 7;
+        let U = Mat::from_fn(m, r, |_, _| c64::new(rand::random(), rand::random()));
+        let V = Mat::from_fn(r, n, |_, _| c64::new(rand::random(), rand::random()));
+        let A = U.as_ref() * V.as_ref();
+        let tol = 1e-10;
+
+        assert!(A.rank(tol) == r);
+        assert!(A.adjoint().rank(tol) == r);
+
+        let svd = A.rank_with(tol, RankMethod::Svd);
+        assert!(svd.rank == r);
+        assert!(svd.values.len() == n);
+        assert!(svd.col_perm.is_none());
+
+        let qr = A.rank_with(tol, RankMethod::ColPivQr);
+        assert!(qr.rank == r);
+        assert!(qr.values.len() == n);
+        let perm = qr.col_perm.unwrap();
+        // the leading permuted columns span the range of the matrix
+        let mut cols = Mat::<c64>::zeros(m, r);
+        for j in 0..r {
+            cols.col_mut(j)
+                .copy_from(A.col(perm.as_ref().arrays().0[j]));
+        }
+        assert!(cols.rank(tol) == r);
+
+        let est = A.rank_est(tol, 10, rng);
+        assert!(est.rank == r);
+        assert!(est.values.len() == 10);
+        let est = A.rank_est(tol, 5, rng);
+        assert!(est.rank == 5);
+
+        assert!(Mat::<f64>::zeros(4, 3).rank(tol) == 0);
+        assert!(Mat::<f64>::zeros(0, 3).rank(tol) == 0);
+        assert!(Mat::<f64>::identity(4, 3).rank(tol) == 3);
+        assert!(
+            Mat::<f64>::zeros(4, 3)
+                .rank_with(tol, RankMethod::ColPivQr)
+                .rank
+                == 0
+        );
+    }
+
+    #[test]
+    fn test_pinv() {
+        let m = 9;
+        let n = 6;
+        let r = 4;
+        let U = Mat::from_fn(m, r, |_, _| c64::new(rand::random(), rand::random()));
+        let V = Mat::from_fn(r, n, |_, _| c64::new(rand::random(), rand::random()));
+        let A = U.as_ref() * V.as_ref();
+        let tol = 1e-10;
+
+        // penrose conditions
+        let P = A.pinv(tol);
+        assert!(all(P.nrows() == n, P.ncols() == m));
+        assert_approx_eq(&A * &P * &A, &A);
+        assert_approx_eq(&P * &A * &P, &P);
+        assert_approx_eq((&A * &P).adjoint().to_owned(), &A * &P);
+        assert_approx_eq((&P * &A).adjoint().to_owned(), &P * &A);
+
+        // full rank matrices have the usual inverse
+        let H = Mat::from_fn(5, 5, |_, _| c64::new(rand::random(), rand::random()));
+        assert_approx_eq(H.pinv(tol), H.partial_piv_lu().inverse());
+
+        let B = Mat::from_fn(m, 3, |_, _| c64::new(rand::random(), rand::random()));
+        assert_approx_eq(A.pinv_solve(tol, &B), &P * &B);
+        assert_approx_eq(A.pinv_solve(tol, B.adjoint()), &P * B.adjoint());
+        let b = Col::from_fn(m, |i| B.read(i, 0));
+        let x = A.pinv_solve(tol, &b);
+        assert_approx_eq(x.as_2d(), (&P * &b).as_2d());
+
+        let Z = Mat::<f64>::zeros(3, 2);
+        assert!(Z.pinv(tol) == Mat::<f64>::zeros(2, 3));
+        let z = Z.pinv_solve(tol, Col::<f64>::from_fn(3, |i| i as f64));
+        assert!(z == Col::<f64>::zeros(2));
+    }
+
+    #[test]
+    fn test_small_determinant() {
+        for n in 0..=6 {
+            let H = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+            let lu = H.partial_piv_lu();
+            let mut expected = c64::new(1.0, 0.0);
+            for i in 0..n {
+                expected = expected * lu.factors.read(i, i);
+            }
+            if lu.transposition_count() % 2 == 1 {
+                expected = -expected;
+            }
+            assert!((H.determinant() - expected).faer_abs() < 1e-10);
+            assert!((H.adjoint().determinant() - expected.conj()).faer_abs() < 1e-10);
+        }
+
+        assert!(mat![[2.0, 3.0], [1.0, 4.0f64]].determinant() == 5.0);
+        assert!(
+            mat![
+                [1.0, 2.0, 0.0, 0.0],
+                [3.0, 4.0, 0.0, 0.0],
+                [0.0, 0.0, 2.0, 0.0],
+                [0.0, 0.0, 0.0, 3.0f64],
+            ]
+            .determinant()
+                == -12.0
+        );
+    }
+
+    #[test]
+    fn test_dyn_solve() {
+        let n = 5;
+        let A = Mat::from_fn(n, n, |i, j| {
+            if i == j {
+                10.0
+            } else {
+                1.0 / (i + j + 1) as f64
+            }
+        });
+        let rhs = Mat::from_fn(n, 2, |i, j| (i + 3 * j) as f64 - 2.0);
+
+        let mut triplets = alloc::vec::Vec::new();
+        for j in 0..n {
+            for i in 0..n {
+                triplets.push((i, j, A.read(i, j)));
+            }
+        }
+        let A_sp =
+            crate::sparse::SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets)
+                .unwrap();
+
+        let solvers: alloc::vec::Vec<alloc::boxed::Box<dyn Solve<f64>>> = alloc::vec![
+            alloc::boxed::Box::new(A.cholesky(Side::Lower).unwrap()),
+            alloc::boxed::Box::new(A.lblt(Side::Lower)),
+            alloc::boxed::Box::new(A.partial_piv_lu()),
+            alloc::boxed::Box::new(A.full_piv_lu()),
+            alloc::boxed::Box::new(A.qr()),
+            alloc::boxed::Box::new(A.col_piv_qr()),
+            alloc::boxed::Box::new(A.svd()),
+            alloc::boxed::Box::new(A.thin_svd()),
+            alloc::boxed::Box::new(A.selfadjoint_eigendecomposition(Side::Lower)),
+            alloc::boxed::Box::new(A_sp.sp_cholesky(Side::Lower).unwrap()),
+            alloc::boxed::Box::new(A_sp.sp_lu().unwrap()),
+            alloc::boxed::Box::new(A_sp.sp_qr().unwrap()),
+        ];
+
+        for solver in &solvers {
+            let x = solver.solve(&rhs);
+            assert_approx_eq(&A * &x, &rhs);
+            let x = solver.solve_transpose(&rhs);
+            assert_approx_eq(A.transpose() * &x, &rhs);
+        }
+
+        fn solve_transpose_generic<S: ?Sized + SolveTranspose<f64>>(
+            solver: &S,
+            rhs: MatRef<'_, f64>,
+        ) -> Mat<f64> {
+            solver.solve_conj_transpose(rhs)
+        }
+        let x = solve_transpose_generic(&*solvers[0], rhs.as_ref());
+        assert_approx_eq(A.adjoint() * &x, &rhs);
+
+        let m = 8;
+        let B = Mat::from_fn(m, n, |i, j| A.read(i % n, j) + (i / n) as f64);
+        let rhs = Mat::from_fn(m, 1, |i, _| i as f64);
+        let mut triplets = alloc::vec::Vec::new();
+        for j in 0..n {
+            for i in 0..m {
+                triplets.push((i, j, B.read(i, j)));
+            }
+        }
+        let B_sp =
+            crate::sparse::SparseColMat::<usize, f64>::try_new_from_triplets(m, n, &triplets)
+                .unwrap();
+
+        let solvers: alloc::vec::Vec<alloc::boxed::Box<dyn SolveLstsq<f64>>> = alloc::vec![
+            alloc::boxed::Box::new(B.qr()),
+            alloc::boxed::Box::new(B.col_piv_qr()),
+            alloc::boxed::Box::new(B_sp.sp_qr().unwrap()),
+        ];
+        let expected = solvers[0].solve_lstsq(&rhs);
+        for solver in &solvers {
+            let x = solver.solve_lstsq(&rhs);
+            assert!(x.nrows() == n);
+            // the residual is orthogonal to the range of `B`
+            assert_approx_eq(B.adjoint() * (&B * &x - &rhs), Mat::<f64>::zeros(n, 1));
+            assert_approx_eq(&x, &expected);
+        }
+    }
 }
@@ -0,0 +1,172 @@
+//! Mixed precision linear system solver.
+//!
+//! The matrix is factorized in single precision, which is about twice as fast as in double
+//! precision for large matrices, and the solution is then refined to double precision accuracy
+//! using residuals computed in double precision, similarly to LAPACK's `DSGESV`.
+
+use crate::{assert, linalg::solvers::SpSolver, mat::*};
+
+const MAX_ITER: usize = 30;
+
+/// Information about the solution computed by [`solve`].
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct MixedPrecisionInfo {
+    /// Number of refinement steps that were performed.
+    pub iter_count: usize,
+    /// Whether the refinement failed to converge, or the matrix could not be represented in
+    /// single precision, in which case the system was solved with a factorization in double
+    /// precision.
+    pub fallback: bool,
+}
+
+// maximum absolute row sum
+fn norm_inf(matrix: MatRef<'_, f64>) -> f64 {
+    (0..matrix.nrows())
+        .map(|i| {
+            (0..matrix.ncols())
+                .map(|j| matrix.read(i, j).abs())
+                .sum::<f64>()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Solves the equation `matrix * X = rhs`, where `matrix` is a square matrix, using an LU
+/// decomposition with partial pivoting computed in single precision, followed by iterative
+/// refinement in double precision.
+///
+/// The refinement stops once the residual of each column `x` of the solution satisfies
+/// `‖rhs - matrix * x‖∞ <= √n ε ‖matrix‖∞ ‖x‖∞`. If that doesn't happen after `30` steps, or if the
+/// elements of `matrix` are too large to be represented in single precision, the system is solved
+/// with an LU decomposition in double precision instead.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::solvers::mixed_precision::solve, mat};
+///
+/// let a = mat![[4.0, 1.0], [1.0, 3.0f64]];
+/// let b = mat![[1.0], [2.0f64]];
+///
+/// let (x, info) = solve(a.as_ref(), b.as_ref());
+/// assert!(!info.fallback);
+/// assert!((&a * &x - &b).norm_max() < 1e-15);
+/// ```
+///
+/// # Panics
+/// Panics if `matrix` is not square, or if `matrix` and `rhs` don't have the same number of rows.
+#[track_caller]
+pub fn solve(matrix: MatRef<'_, f64>, rhs: MatRef<'_, f64>) -> (Mat<f64>, MixedPrecisionInfo) {
+    assert!(all(
+        matrix.nrows() == matrix.ncols(),
+        rhs.nrows() == matrix.nrows(),
+    ));
+
+    let n = matrix.nrows();
+    let k = rhs.ncols();
+
+    let fallback = |iter_count: usize| {
+        (
+            matrix.partial_piv_lu().solve(rhs),
+            MixedPrecisionInfo {
+                iter_count,
+                fallback: true,
+            },
+        )
+    };
+
+    let anorm = norm_inf(matrix);
+    let is_representable = |x: f64| !x.is_finite() || x.abs() <= f32::MAX as f64;
+    if !(0..n).all(|j| (0..n).all(|i| is_representable(matrix.read(i, j))))
+        || !(0..k).all(|j| (0..n).all(|i| is_representable(rhs.read(i, j))))
+    {
+        return fallback(0);
+    }
+
+    let lu = Mat::<f32>::from_fn(n, n, |i, j| matrix.read(i, j) as f32).partial_piv_lu();
+    // solves the system in single precision, with a right-hand side in double precision
+    let solve_f32 = |r: MatRef<'_, f64>| {
+        let mut r = Mat::<f32>::from_fn(r.nrows(), r.ncols(), |i, j| r.read(i, j) as f32);
+        lu.solve_in_place(r.as_mut());
+        Mat::<f64>::from_fn(r.nrows(), r.ncols(), |i, j| r.read(i, j) as f64)
+    };
+
+    let tol = f64::EPSILON * (n as f64).sqrt() * anorm;
+    let converged = |x: MatRef<'_, f64>, r: MatRef<'_, f64>| {
+        (0..k).all(|j| {
+            let x_norm = x.col(j).norm_max();
+            let r_norm = r.col(j).norm_max();
+            r_norm <= tol * x_norm
+        })
+    };
+
+    let mut x = solve_f32(rhs);
+    for iter in 0..=MAX_ITER {
+        let r = rhs - matrix * &x;
+        if !r.is_all_finite() || !x.is_all_finite() {
+            return fallback(iter);
+        }
+        if converged(x.as_ref(), r.as_ref()) {
+            return (
+                x,
+                MixedPrecisionInfo {
+                    iter_count: iter,
+                    fallback: false,
+                },
+            );
+        }
+        if iter == MAX_ITER {
+            break;
+        }
+        x += solve_f32(r.as_ref());
+    }
+
+    fallback(MAX_ITER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_precision() {
+        let n = 50;
+        let A = Mat::<f64>::from_fn(n, n, |i, j| {
+            if i == j {
+                n as f64
+            } else {
+                rand::random::<f64>() - 0.5
+            }
+        });
+        let B = Mat::<f64>::from_fn(n, 4, |_, _| rand::random());
+
+        let (X, info) = solve(A.as_ref(), B.as_ref());
+        assert!(!info.fallback);
+        assert!(info.iter_count > 0);
+        let expected = A.partial_piv_lu().solve(&B);
+        assert!((&X - &expected).norm_max() < 1e-12);
+        assert!(norm_inf((&A * &X - &B).as_ref()) <= 1e-13 * norm_inf(A.as_ref()) * X.norm_max());
+    }
+
+    #[test]
+    fn test_mixed_precision_fallback() {
+        let n = 10;
+        // elements that overflow in single precision
+        let A = Mat::<f64>::from_fn(n, n, |i, j| if i == j { 1e60 } else { 1.0 });
+        let B = Mat::<f64>::from_fn(n, 1, |_, _| 1.0);
+        let (X, info) = solve(A.as_ref(), B.as_ref());
+        assert!(info.fallback);
+        assert!(all(info.iter_count == 0, (&A * &X - &B).norm_max() < 1e-10));
+
+        // too ill-conditioned for the refinement to converge
+        let A = Mat::<f64>::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let (X, info) = solve(A.as_ref(), B.as_ref());
+        assert!(info.fallback);
+        assert!((&X - A.partial_piv_lu().solve(&B)).norm_max() == 0.0);
+
+        let (X, info) = solve(
+            Mat::<f64>::zeros(0, 0).as_ref(),
+            Mat::<f64>::zeros(0, 2).as_ref(),
+        );
+        assert!(all(X.nrows() == 0, X.ncols() == 2, !info.fallback));
+    }
+}
@@ -0,0 +1,107 @@
+//! Orthonormal bases of the fundamental subspaces of a matrix.
+//!
+//! The bases are computed from the singular value decomposition of the matrix, whose numerical
+//! rank is the number of singular values that are larger than `tol` times the largest one, as in
+//! [`MatRef::rank`].
+
+use crate::{linalg::solvers::numerical_rank, mat::*, ComplexField, Conjugate};
+
+fn svd_and_rank<E: ComplexField>(
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    tol: E::Real,
+) -> (crate::linalg::solvers::Svd<E>, usize) {
+    let svd = crate::linalg::solvers::Svd::new(matrix);
+    let s = svd.s_diagonal();
+    let values: alloc::vec::Vec<_> = (0..s.nrows()).map(|i| s.read(i).faer_real()).collect();
+    let rank = numerical_rank(&values, tol);
+    (svd, rank)
+}
+
+/// Returns a matrix whose columns form an orthonormal basis of the null space of `matrix`, i.e.,
+/// of the vectors `x` such that `matrix * x` is numerically zero.
+///
+/// For an `m×n` matrix of numerical rank `r`, the result is an `n×(n - r)` matrix.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::subspace::null_space, mat};
+///
+/// let a = mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0f64]];
+/// let n = null_space(a.as_ref(), 1e-12);
+/// assert!(n.ncols() == 2);
+/// assert!((&a * &n).norm_max() < 1e-12);
+/// ```
+#[track_caller]
+pub fn null_space<E: ComplexField>(
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    tol: E::Real,
+) -> Mat<E> {
+    let (svd, rank) = svd_and_rank(matrix, tol);
+    let n = matrix.ncols();
+    svd.v().subcols(rank, n - rank).to_owned()
+}
+
+/// Returns a matrix whose columns form an orthonormal basis of the range of `matrix`, i.e., of
+/// the space spanned by its columns.
+///
+/// For an `m×n` matrix of numerical rank `r`, the result is an `m×r` matrix.
+///
+/// # Example
+/// ```
+/// use faer::{linalg::subspace::range, mat};
+///
+/// let a = mat![[1.0, 2.0], [2.0, 4.0], [0.0, 0.0f64]];
+/// let q = range(a.as_ref(), 1e-12);
+/// assert!(q.ncols() == 1);
+/// // the columns of `a` are unchanged by the projection onto the range
+/// assert!((&q * q.adjoint() * &a - &a).norm_max() < 1e-12);
+/// ```
+#[track_caller]
+pub fn range<E: ComplexField>(
+    matrix: MatRef<'_, impl Conjugate<Canonical = E>>,
+    tol: E::Real,
+) -> Mat<E> {
+    let (svd, rank) = svd_and_rank(matrix, tol);
+    svd.u().subcols(0, rank).to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+
+    #[test]
+    fn test_subspaces() {
+        let m = 12;
+        let n = 9;
+        let r = 4;
+        let U = Mat::from_fn(m, r, |_, _| c64::new(rand::random(), rand::random()));
+        let V = Mat::from_fn(r, n, |_, _| c64::new(rand::random(), rand::random()));
+        let A = &U * &V;
+        let tol = 1e-10;
+
+        let N = null_space(A.as_ref(), tol);
+        assert!(all(N.nrows() == n, N.ncols() == n - r));
+        assert!((&A * &N).norm_max() < 1e-10);
+        assert!((N.adjoint() * &N - Mat::<c64>::identity(n - r, n - r)).norm_max() < 1e-10);
+
+        let Q = range(A.as_ref(), tol);
+        assert!(all(Q.nrows() == m, Q.ncols() == r));
+        assert!((&Q * Q.adjoint() * &A - &A).norm_max() < 1e-10);
+        assert!((&Q * Q.adjoint() * &U - &U).norm_max() < 1e-10);
+        assert!((Q.adjoint() * &Q - Mat::<c64>::identity(r, r)).norm_max() < 1e-10);
+
+        // the null space of the adjoint is the orthogonal complement of the range
+        let N = null_space(A.adjoint(), tol);
+        assert!(all(N.nrows() == m, N.ncols() == m - r));
+        assert!((Q.adjoint() * &N).norm_max() < 1e-10);
+
+        let Z = Mat::<f64>::zeros(3, 2);
+        assert!(null_space(Z.as_ref(), tol).ncols() == 2);
+        assert!(range(Z.as_ref(), tol).ncols() == 0);
+
+        let I = Mat::<f64>::identity(3, 3);
+        assert!(null_space(I.as_ref(), tol).ncols() == 0);
+        assert!(range(I.as_ref(), tol).ncols() == 3);
+    }
+}
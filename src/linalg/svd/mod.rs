@@ -16,7 +16,7 @@ use crate::{
         householder::{
             apply_block_householder_sequence_on_the_left_in_place_req,
             apply_block_householder_sequence_on_the_left_in_place_with_conj,
-            upgrade_householder_factor,
+            upgrade_householder_factor_from_coeffs, HouseholderBlockingParams,
         },
         qr as faer_qr, temp_mat_req, temp_mat_uninit,
         zip::Diag,
@@ -38,6 +38,7 @@ pub mod bidiag_real_svd;
 #[doc(hidden)]
 pub mod jacobi;
 pub(crate) mod pseudo_inverse;
+pub mod update;
 
 const JACOBI_FALLBACK_THRESHOLD: usize = 4;
 const BIDIAG_QR_FALLBACK_THRESHOLD: usize = 128;
@@ -183,6 +184,7 @@ fn compute_real_svd_small<E: RealField>(
     zero_threshold: E,
     parallelism: Parallelism,
     stack: PodStack<'_>,
+    blocking: HouseholderBlockingParams,
 ) {
     let mut u = u;
     let mut v = v;
@@ -231,7 +233,10 @@ fn compute_real_svd_small<E: RealField>(
             householder.rb_mut(),
             parallelism,
             stack.rb_mut(),
-            Default::default(),
+            faer_qr::no_pivoting::compute::QrComputeParams {
+                blocking,
+                ..Default::default()
+            },
         );
         zipped!(r.rb_mut())
             .for_each_triangular_lower(Diag::Skip, |unzipped!(mut dst)| dst.write(E::faer_zero()));
@@ -425,6 +430,7 @@ fn compute_svd_big<E: ComplexField>(
     zero_threshold: E::Real,
     parallelism: Parallelism,
     stack: PodStack<'_>,
+    blocking: HouseholderBlockingParams,
 ) {
     let mut stack = stack;
 
@@ -479,7 +485,7 @@ fn compute_svd_big<E: ComplexField>(
         for j in 0..bs {
             householder.write(j, j, householder.read(0, j));
         }
-        upgrade_householder_factor(householder, essentials, bs, 1, parallelism);
+        upgrade_householder_factor_from_coeffs(householder, essentials, parallelism, blocking);
         j_base += bs;
     }
     let mut j_base = 0;
@@ -491,7 +497,7 @@ fn compute_svd_big<E: ComplexField>(
         for j in 0..bs {
             householder.write(j, j, householder.read(0, j));
         }
-        upgrade_householder_factor(householder, essentials, bs, 1, parallelism);
+        upgrade_householder_factor_from_coeffs(householder, essentials, parallelism, blocking);
         j_base += bs;
     }
 
@@ -578,7 +584,11 @@ fn compute_svd_big<E: ComplexField>(
 /// SVD tuning parameters.
 #[derive(Default, Copy, Clone)]
 #[non_exhaustive]
-pub struct SvdParams {}
+pub struct SvdParams {
+    /// Block size schedule and recursion threshold used when computing the Householder factors
+    /// of the QR decomposition and the bidiagonalization.
+    pub blocking: HouseholderBlockingParams,
+}
 
 /// Computes the size and alignment of required workspace for performing a singular value
 /// decomposition. $U$ and $V$ may be computed fully, partially, or not computed at all.
@@ -767,6 +777,15 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
         return;
     }
 
+    #[cfg(feature = "lapack")]
+    let (s, u, v) = {
+        let (mut s, mut u, mut v) = (s, u, v);
+        if crate::linalg::lapack::svd(matrix, s.rb_mut(), u.rb_mut(), v.rb_mut()) {
+            return;
+        }
+        (s, u, v)
+    };
+
     let mut u = u;
     let mut v = v;
     let mut matrix = matrix;
@@ -793,8 +812,6 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
         return;
     }
 
-    let _ = params;
-
     if m as f64 / n as f64 <= 11.0 / 6.0 {
         squareish_svd(
             matrix,
@@ -805,6 +822,7 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
             zero_threshold,
             parallelism,
             stack,
+            params.blocking,
         );
     } else {
         // do a qr first, then do the svd
@@ -827,7 +845,10 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
                 householder.rb_mut(),
                 parallelism,
                 stack.rb_mut(),
-                Default::default(),
+                faer_qr::no_pivoting::compute::QrComputeParams {
+                    blocking: params.blocking,
+                    ..Default::default()
+                },
             );
             zipped!(r.rb_mut()).for_each_triangular_lower(Diag::Skip, |unzipped!(mut dst)| {
                 dst.write(E::faer_zero())
@@ -847,6 +868,7 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
                 zero_threshold,
                 parallelism,
                 stack,
+                params.blocking,
             );
         }
 
@@ -898,6 +920,7 @@ fn squareish_svd<E: ComplexField>(
     zero_threshold: E::Real,
     parallelism: Parallelism,
     stack: PodStack,
+    blocking: HouseholderBlockingParams,
 ) {
     let size = matrix.ncols();
     if coe::is_same::<E, E::Real>() {
@@ -911,6 +934,7 @@ fn squareish_svd<E: ComplexField>(
                 coe::coerce_static(zero_threshold),
                 parallelism,
                 stack,
+                blocking,
             );
         } else {
             compute_svd_big::<E::Real>(
@@ -923,6 +947,7 @@ fn squareish_svd<E: ComplexField>(
                 coe::coerce_static(zero_threshold),
                 parallelism,
                 stack,
+                blocking,
             );
         }
     } else {
@@ -936,6 +961,7 @@ fn squareish_svd<E: ComplexField>(
             coe::coerce_static(zero_threshold),
             parallelism,
             stack,
+            blocking,
         );
     }
 }
@@ -987,6 +1013,7 @@ mod tests {
                     bidiag_real_svd_req::<f64>,
                     Parallelism::None,
                 )),
+                Default::default(),
             );
 
             let reconstructed = &u * &s * v.transpose();
@@ -1033,6 +1060,7 @@ mod tests {
                     bidiag_real_svd_req::<f64>,
                     Parallelism::None,
                 )),
+                Default::default(),
             );
 
             let reconstructed = &u * &s * v.transpose();
@@ -1076,6 +1104,7 @@ mod tests {
                     bidiag_real_svd_req::<f64>,
                     Parallelism::None,
                 )),
+                Default::default(),
             );
 
             let reconstructed = &u * &s * v.transpose();
@@ -1117,6 +1146,7 @@ mod tests {
                     ComputeVectors::Full,
                     Parallelism::None,
                 )),
+                Default::default(),
             );
 
             let reconstructed = &u * &s * v.transpose();
@@ -1172,6 +1202,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_blocking_params() {
+        let params = SvdParams {
+            blocking: HouseholderBlockingParams {
+                inner_blocksize: Some(|bs| if bs % 4 == 0 { bs / 4 } else { 1 }),
+                recursion_threshold: Some(2),
+            },
+        };
+
+        for (m, n) in [(50, 50), (100, 60), (60, 100), (300, 40)] {
+            let mat = Mat::from_fn(m, n, |_, _| rand::random::<f64>());
+            let size = m.min(n);
+
+            let mut s = Mat::zeros(m, n);
+            let mut u = Mat::zeros(m, m);
+            let mut v = Mat::zeros(n, n);
+
+            compute_svd(
+                mat.as_ref(),
+                s.as_mut()
+                    .submatrix_mut(0, 0, size, size)
+                    .diagonal_mut()
+                    .column_vector_mut()
+                    .as_2d_mut(),
+                Some(u.as_mut()),
+                Some(v.as_mut()),
+                Parallelism::None,
+                make_stack!(compute_svd_req::<f64>(
+                    m,
+                    n,
+                    ComputeVectors::Full,
+                    ComputeVectors::Full,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            let reconstructed = &u * &s * v.transpose();
+
+            for j in 0..n {
+                for i in 0..m {
+                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                }
+            }
+
+            let mat = Mat::from_fn(m, n, |_, _| c64::new(rand::random(), rand::random()));
+
+            let mut s = Mat::zeros(m, n);
+            let mut u = Mat::zeros(m, m);
+            let mut v = Mat::zeros(n, n);
+
+            compute_svd(
+                mat.as_ref(),
+                s.as_mut()
+                    .submatrix_mut(0, 0, size, size)
+                    .diagonal_mut()
+                    .column_vector_mut()
+                    .as_2d_mut(),
+                Some(u.as_mut()),
+                Some(v.as_mut()),
+                Parallelism::None,
+                make_stack!(compute_svd_req::<c64>(
+                    m,
+                    n,
+                    ComputeVectors::Full,
+                    ComputeVectors::Full,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            let reconstructed = &u * &s * v.adjoint();
+
+            for j in 0..n {
+                for i in 0..m {
+                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_real_f32() {
         for m in 0..20 {
@@ -1546,6 +1659,7 @@ mod tests {
                     bidiag_cplx_svd_req::<f64>,
                     Parallelism::None,
                 )),
+                Default::default(),
             );
 
             let reconstructed = &u * &s * v.transpose();
@@ -1589,6 +1703,7 @@ mod tests {
                     bidiag_cplx_svd_req::<f64>,
                     Parallelism::None,
                 )),
+                Default::default(),
             );
 
             let reconstructed = &u * &s * v.transpose();
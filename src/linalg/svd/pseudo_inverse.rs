@@ -1,7 +1,7 @@
 //! Computes the pseudo inverse; see:
 //! https://en.wikipedia.org//wiki/Singular_value_decomposition#Pseudoinverse
 
-use crate::prelude::*;
+use crate::{prelude::*, Conjugate, Parallelism};
 use faer_entity::{ComplexField, RealField};
 
 /// See: <https://en.wikipedia.org//wiki/Singular_value_decomposition#Pseudoinverse>
@@ -10,22 +10,78 @@ pub(crate) fn compute_pseudoinverse<E: ComplexField>(
     u: MatRef<'_, E>,
     v: MatRef<'_, E>,
 ) -> Mat<E> {
+    let epsilon = E::Real::faer_epsilon().faer_scale_power_of_two(E::Real::faer_from_f64(8.0));
+    compute_pseudoinverse_with_tolerance(s, u, v, epsilon)
+}
+
+// number of singular values that are larger than `tol` times the largest one
+fn pseudoinverse_rank<E: ComplexField>(s: ColRef<'_, E>, tol: E::Real) -> usize {
     if s.nrows() == 0 {
-        return Mat::zeros(v.nrows(), u.nrows());
+        return 0;
     }
 
-    let epsilon = E::Real::faer_epsilon().faer_scale_power_of_two(E::Real::faer_from_f64(8.0));
-
     let s_max = s.read(0).faer_real();
-    let sv_tolerance = epsilon.faer_mul(s_max);
+    let sv_tolerance = tol.faer_mul(s_max);
 
     let mut r = 0usize;
     while r < s.nrows() && s.read(r).faer_real() > sv_tolerance {
         r += 1;
     }
+    r
+}
+
+/// Same as [`compute_pseudoinverse`], except that the singular values that are at most `tol` times
+/// the largest one are treated as zero.
+pub(crate) fn compute_pseudoinverse_with_tolerance<E: ComplexField>(
+    s: ColRef<'_, E>,
+    u: MatRef<'_, E>,
+    v: MatRef<'_, E>,
+    tol: E::Real,
+) -> Mat<E> {
+    let r = pseudoinverse_rank(s, tol);
+    if r == 0 {
+        return Mat::zeros(v.nrows(), u.nrows());
+    }
 
     let s_inv =
         zipped!(s.get(..r)).map(|unzipped!(s)| E::faer_from_real(s.read().faer_real().faer_inv()));
 
     (v.get(.., ..r) * s_inv.as_ref().column_vector_as_diagonal()) * u.get(.., ..r).adjoint()
 }
+
+/// Stores the product of the pseudo inverse with `rhs` in `out`, without forming the pseudo
+/// inverse. The singular values that are at most `tol` times the largest one are treated as zero.
+#[track_caller]
+pub(crate) fn apply_pseudoinverse<E: ComplexField, ViewE: Conjugate<Canonical = E>>(
+    s: ColRef<'_, E>,
+    u: MatRef<'_, E>,
+    v: MatRef<'_, E>,
+    tol: E::Real,
+    out: MatMut<'_, E>,
+    rhs: MatRef<'_, ViewE>,
+    parallelism: Parallelism,
+) {
+    let mut out = out;
+    let r = pseudoinverse_rank(s, tol);
+    if r == 0 {
+        out.fill_zero();
+        return;
+    }
+
+    // V_r * S_r⁻¹ * U_rᴴ * rhs, from right to left
+    let mut tmp = u.get(.., ..r).adjoint() * rhs;
+    for i in 0..r {
+        let s_inv = s.read(i).faer_real().faer_inv();
+        for j in 0..tmp.ncols() {
+            tmp.write(i, j, tmp.read(i, j).faer_scale_real(s_inv));
+        }
+    }
+    crate::linalg::matmul::matmul(
+        out,
+        v.get(.., ..r),
+        tmp.as_ref(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+}
@@ -0,0 +1,283 @@
+//! Updates of a thin SVD after a low-rank modification of the matrix, using Brand's algorithm.
+//!
+//! Given the thin SVD `A = U S Vᴴ` of a matrix of rank `r`, where `U` and `V` have `r` orthonormal
+//! columns, the SVD of the modified matrix is obtained from the SVD of a small matrix of size
+//! `r + k`, where `k` is the rank of the modification, instead of being recomputed from scratch.
+//!
+//! Singular values that are negligible compared to the largest one, i.e., at most
+//! `max(nrows, ncols) * ε` times its value, are dropped from the result, along with their singular
+//! vectors.
+
+use crate::{assert, col::*, mat::*, ComplexField};
+
+// returns the components of `x` along the columns of `u`, and the component orthogonal to them
+fn project<E: ComplexField>(u: MatRef<'_, E>, x: MatRef<'_, E>) -> (Mat<E>, Mat<E>) {
+    let mut coeffs = u.adjoint() * x;
+    let mut residual = x - u * &coeffs;
+    // a second orthogonalization pass guards against the loss of orthogonality due to
+    // cancellation
+    let correction = u.adjoint() * &residual;
+    residual -= u * &correction;
+    coeffs += correction;
+    (coeffs, residual)
+}
+
+// computes the thin SVD of `middle`, and returns `left * U'`, `S'` and `right * V'`, without the
+// negligible singular values
+fn rotate<E: ComplexField>(
+    middle: MatRef<'_, E>,
+    left: MatRef<'_, E>,
+    right: MatRef<'_, E>,
+) -> (Mat<E>, Col<E>, Mat<E>) {
+    let svd = middle.thin_svd();
+    let s = svd.s_diagonal();
+
+    let dim = Ord::max(left.nrows(), right.nrows());
+    let tol = E::Real::faer_epsilon().faer_mul(E::Real::faer_from_f64(dim as f64));
+    let mut rank = 0;
+    if s.nrows() > 0 {
+        let threshold = tol.faer_mul(s.read(0).faer_real());
+        while rank < s.nrows() && s.read(rank).faer_real() > threshold {
+            rank += 1;
+        }
+    }
+
+    (
+        left * svd.u().subcols(0, rank),
+        s.subrows(0, rank).to_owned(),
+        right * svd.v().subcols(0, rank),
+    )
+}
+
+/// Returns the thin SVD of the matrix `[A, cols]`, obtained by appending the columns `cols` to
+/// the matrix `A` whose thin SVD is `u * diag(s) * vᴴ`.
+///
+/// The result has at most `s.nrows() + cols.ncols()` singular values, in nonincreasing order.
+///
+/// # Panics
+/// Panics if the dimensions of `u`, `s`, `v` and `cols` are not compatible.
+#[track_caller]
+pub fn append_cols<E: ComplexField>(
+    u: MatRef<'_, E>,
+    s: ColRef<'_, E>,
+    v: MatRef<'_, E>,
+    cols: MatRef<'_, E>,
+) -> (Mat<E>, Col<E>, Mat<E>) {
+    let m = u.nrows();
+    let n = v.nrows();
+    let r = s.nrows();
+    let c = cols.ncols();
+    assert!(all(u.ncols() == r, v.ncols() == r, cols.nrows() == m,));
+
+    // [A, cols] = [u, J] * [[S, L], [0, K]] * [[v, 0], [0, I]]ᴴ
+    let (L, H) = project(u, cols);
+    let qr = H.qr();
+    let J = qr.compute_thin_q();
+    let K = qr.compute_thin_r();
+    let p = J.ncols();
+
+    let middle = Mat::<E>::from_fn(r + p, r + c, |i, j| match (i < r, j < r) {
+        (true, true) => {
+            if i == j {
+                s.read(i)
+            } else {
+                E::faer_zero()
+            }
+        }
+        (true, false) => L.read(i, j - r),
+        (false, true) => E::faer_zero(),
+        (false, false) => K.read(i - r, j - r),
+    });
+    let left = Mat::<E>::from_fn(m, r + p, |i, j| {
+        if j < r {
+            u.read(i, j)
+        } else {
+            J.read(i, j - r)
+        }
+    });
+    let right = Mat::<E>::from_fn(n + c, r + c, |i, j| match (i < n, j < r) {
+        (true, true) => v.read(i, j),
+        (false, false) => {
+            if i - n == j - r {
+                E::faer_one()
+            } else {
+                E::faer_zero()
+            }
+        }
+        _ => E::faer_zero(),
+    });
+
+    rotate(middle.as_ref(), left.as_ref(), right.as_ref())
+}
+
+/// Returns the thin SVD of the matrix `A + a * bᴴ`, where the thin SVD of `A` is
+/// `u * diag(s) * vᴴ`.
+///
+/// The result has at most `s.nrows() + 1` singular values, in nonincreasing order.
+///
+/// # Panics
+/// Panics if the dimensions of `u`, `s`, `v`, `a` and `b` are not compatible.
+#[track_caller]
+pub fn rank_one<E: ComplexField>(
+    u: MatRef<'_, E>,
+    s: ColRef<'_, E>,
+    v: MatRef<'_, E>,
+    a: ColRef<'_, E>,
+    b: ColRef<'_, E>,
+) -> (Mat<E>, Col<E>, Mat<E>) {
+    let r = s.nrows();
+    assert!(all(
+        u.ncols() == r,
+        v.ncols() == r,
+        a.nrows() == u.nrows(),
+        b.nrows() == v.nrows(),
+    ));
+
+    // returns the components of `x` along the columns of `basis`, the norm of the orthogonal
+    // component, and the basis extended with the normalized orthogonal component
+    let extend = |basis: MatRef<'_, E>, x: ColRef<'_, E>| {
+        let (coeffs, residual) = project(basis, x.as_2d());
+        let norm = residual.norm_l2();
+        let scale = if norm == E::Real::faer_zero() {
+            E::Real::faer_zero()
+        } else {
+            norm.faer_inv()
+        };
+        let extended = Mat::<E>::from_fn(basis.nrows(), r + 1, |i, j| {
+            if j < r {
+                basis.read(i, j)
+            } else {
+                residual.read(i, 0).faer_scale_real(scale)
+            }
+        });
+        (coeffs, norm, extended)
+    };
+
+    let (ma, norm_a, left) = extend(u, a);
+    let (mb, norm_b, right) = extend(v, b);
+
+    // [[S, 0], [0, 0]] + [ma; ‖pa‖] * [mb; ‖pb‖]ᴴ
+    let coeff_a = |i: usize| {
+        if i < r {
+            ma.read(i, 0)
+        } else {
+            E::faer_from_real(norm_a)
+        }
+    };
+    let coeff_b = |j: usize| {
+        if j < r {
+            mb.read(j, 0)
+        } else {
+            E::faer_from_real(norm_b)
+        }
+    };
+    let middle = Mat::<E>::from_fn(r + 1, r + 1, |i, j| {
+        let diag = if i == j && i < r {
+            s.read(i)
+        } else {
+            E::faer_zero()
+        };
+        diag.faer_add(coeff_a(i).faer_mul(coeff_b(j).faer_conj()))
+    });
+
+    rotate(middle.as_ref(), left.as_ref(), right.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_native::c64;
+
+    fn random(m: usize, n: usize) -> Mat<c64> {
+        Mat::from_fn(m, n, |_, _| c64::new(rand::random(), rand::random()))
+    }
+
+    fn thin_svd(A: MatRef<'_, c64>, rank: usize) -> (Mat<c64>, Col<c64>, Mat<c64>) {
+        let svd = A.thin_svd();
+        (
+            svd.u().subcols(0, rank).to_owned(),
+            svd.s_diagonal().subrows(0, rank).to_owned(),
+            svd.v().subcols(0, rank).to_owned(),
+        )
+    }
+
+    #[track_caller]
+    fn check(A: MatRef<'_, c64>, (u, s, v): (Mat<c64>, Col<c64>, Mat<c64>), rank: usize) {
+        assert!(all(u.ncols() == rank, s.nrows() == rank, v.ncols() == rank));
+        let reconstructed = u.as_ref() * s.as_ref().column_vector_as_diagonal() * v.adjoint();
+        assert!((reconstructed - A).norm_max() < 1e-10);
+        assert!((u.adjoint() * &u - Mat::<c64>::identity(rank, rank)).norm_max() < 1e-10);
+        assert!((v.adjoint() * &v - Mat::<c64>::identity(rank, rank)).norm_max() < 1e-10);
+
+        let expected = A.singular_values();
+        for i in 0..rank {
+            assert!((s.read(i).re - expected[i]).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_append_cols() {
+        let m = 15;
+        let n = 8;
+        let r = 3;
+        let A = random(m, r) * random(r, n);
+        let svd = thin_svd(A.as_ref(), r);
+
+        let C = random(m, 2);
+        let updated = append_cols(svd.0.as_ref(), svd.1.as_ref(), svd.2.as_ref(), C.as_ref());
+        let AC = crate::concat![[A, C]];
+        check(AC.as_ref(), updated, r + 2);
+
+        // columns in the range of `A` don't increase the rank
+        let C = &A * random(n, 2);
+        let updated = append_cols(svd.0.as_ref(), svd.1.as_ref(), svd.2.as_ref(), C.as_ref());
+        let AC = crate::concat![[A, C]];
+        check(AC.as_ref(), updated, r);
+    }
+
+    #[test]
+    fn test_rank_one() {
+        let m = 12;
+        let n = 9;
+        let r = 4;
+        let A = random(m, r) * random(r, n);
+        let svd = thin_svd(A.as_ref(), r);
+
+        let a = random(m, 1);
+        let b = random(n, 1);
+        let updated = rank_one(
+            svd.0.as_ref(),
+            svd.1.as_ref(),
+            svd.2.as_ref(),
+            a.col(0),
+            b.col(0),
+        );
+        check((&A + &a * b.adjoint()).as_ref(), updated, r + 1);
+
+        // a modification within the row and column spaces of `A`
+        let a = &svd.0 * random(r, 1);
+        let b = &svd.2 * random(r, 1);
+        let updated = rank_one(
+            svd.0.as_ref(),
+            svd.1.as_ref(),
+            svd.2.as_ref(),
+            a.col(0),
+            b.col(0),
+        );
+        check((&A + &a * b.adjoint()).as_ref(), updated, r);
+
+        // starting from the empty matrix
+        let Z = Mat::<c64>::zeros(m, 0);
+        let W = Mat::<c64>::zeros(n, 0);
+        let a = random(m, 1);
+        let b = random(n, 1);
+        let updated = rank_one(
+            Z.as_ref(),
+            Col::zeros(0).as_ref(),
+            W.as_ref(),
+            a.col(0),
+            b.col(0),
+        );
+        check((&a * b.adjoint()).as_ref(), updated, 1);
+    }
+}
@@ -0,0 +1,257 @@
+//! Triangular matrix views.
+//!
+//! [`TriLower`] and [`TriUpper`] wrap a [`MatRef`] and encode in their type which half of the
+//! matrix is accessed, and whether the diagonal is implicitly equal to `1.0`. This allows calling
+//! [`solve_triangular_in_place`](crate::linalg::triangular_solve::solve_triangular_in_place) and
+//! [`invert_triangular`](crate::linalg::triangular_inverse::invert_triangular) without passing the
+//! structure separately, as well as computing products with rectangular matrices in place.
+
+use crate::{
+    assert,
+    linalg::{
+        matmul::triangular::{triangular_x_mat_in_place, BlockStructure},
+        triangular_inverse::invert_triangular,
+        triangular_solve::solve_triangular_in_place,
+    },
+    ComplexField, Conjugate, Entity, Mat, MatMut, MatRef, Parallelism,
+};
+
+/// Lower triangular view over a square matrix. Its strictly upper triangular part is never
+/// accessed.
+///
+/// If `UNIT` is `true`, the diagonal is not accessed either, and is assumed to be equal to `1.0`.
+pub struct TriLower<'a, E: Entity, const UNIT: bool = false> {
+    inner: MatRef<'a, E>,
+}
+
+/// Upper triangular view over a square matrix. Its strictly lower triangular part is never
+/// accessed.
+///
+/// If `UNIT` is `true`, the diagonal is not accessed either, and is assumed to be equal to `1.0`.
+pub struct TriUpper<'a, E: Entity, const UNIT: bool = false> {
+    inner: MatRef<'a, E>,
+}
+
+/// Lower triangular view with an implicit unit diagonal.
+pub type UnitTriLower<'a, E> = TriLower<'a, E, true>;
+/// Upper triangular view with an implicit unit diagonal.
+pub type UnitTriUpper<'a, E> = TriUpper<'a, E, true>;
+
+mod seal {
+    pub trait Seal {}
+}
+
+/// Matrix view whose triangular structure is known at compile time.
+///
+/// This trait is sealed, and is only implemented by [`TriLower`] and [`TriUpper`].
+pub trait TriangularMatRef<'a, E: Entity>: Copy + seal::Seal {
+    /// Returns the part of the matrix that is accessed.
+    fn structure(&self) -> BlockStructure;
+    /// Returns the underlying matrix view.
+    fn as_mat_ref(&self) -> MatRef<'a, E>;
+}
+
+macro_rules! impl_tri {
+    ($ty: ident, $transposed: ident, $structure: ident, $unit_structure: ident) => {
+        impl<E: Entity, const UNIT: bool> Clone for $ty<'_, E, UNIT> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<E: Entity, const UNIT: bool> Copy for $ty<'_, E, UNIT> {}
+
+        impl<E: Entity, const UNIT: bool> seal::Seal for $ty<'_, E, UNIT> {}
+
+        impl<'a, E: Entity, const UNIT: bool> TriangularMatRef<'a, E> for $ty<'a, E, UNIT> {
+            #[inline(always)]
+            fn structure(&self) -> BlockStructure {
+                if UNIT {
+                    BlockStructure::$unit_structure
+                } else {
+                    BlockStructure::$structure
+                }
+            }
+
+            #[inline(always)]
+            fn as_mat_ref(&self) -> MatRef<'a, E> {
+                self.inner
+            }
+        }
+
+        impl<'a, E: Entity, const UNIT: bool> $ty<'a, E, UNIT> {
+            /// Wraps `mat` in a triangular view.
+            ///
+            /// # Panics
+            /// Panics if `mat` is not square.
+            #[track_caller]
+            #[inline]
+            pub fn new(mat: MatRef<'a, E>) -> Self {
+                assert!(mat.nrows() == mat.ncols());
+                Self { inner: mat }
+            }
+
+            /// Returns the dimension of the matrix.
+            #[inline(always)]
+            pub fn dim(&self) -> usize {
+                self.inner.nrows()
+            }
+
+            /// Returns the underlying matrix view.
+            #[inline(always)]
+            pub fn as_mat_ref(&self) -> MatRef<'a, E> {
+                self.inner
+            }
+
+            /// Returns the part of the matrix that is accessed.
+            #[inline(always)]
+            pub fn structure(&self) -> BlockStructure {
+                <Self as TriangularMatRef<'a, E>>::structure(self)
+            }
+
+            /// Returns a view over the transpose of `self`.
+            #[inline(always)]
+            pub fn transpose(self) -> $transposed<'a, E, UNIT> {
+                $transposed {
+                    inner: self.inner.transpose(),
+                }
+            }
+        }
+
+        impl<'a, E: Conjugate, const UNIT: bool> $ty<'a, E, UNIT>
+        where
+            E::Canonical: ComplexField,
+        {
+            /// Computes the solution of `self×X = rhs`, and stores the result in `rhs`.
+            ///
+            /// # Panics
+            /// Panics if `rhs.nrows() != self.dim()`.
+            #[track_caller]
+            #[inline]
+            pub fn solve_in_place(self, rhs: MatMut<'_, E::Canonical>, parallelism: Parallelism) {
+                solve_triangular_in_place(self, rhs, parallelism)
+            }
+
+            /// Computes `self×rhs`, and stores the result in `rhs`.
+            ///
+            /// # Panics
+            /// Panics if `rhs.nrows() != self.dim()`.
+            #[track_caller]
+            #[inline]
+            pub fn mul_in_place(self, rhs: MatMut<'_, E::Canonical>, parallelism: Parallelism) {
+                triangular_x_mat_in_place(
+                    rhs,
+                    self.inner,
+                    self.structure(),
+                    E::Canonical::faer_one(),
+                    parallelism,
+                )
+            }
+        }
+
+        impl<'a, E: ComplexField, const UNIT: bool> $ty<'a, E, UNIT> {
+            /// Returns the inverse of `self`, which has the same triangular structure.
+            #[track_caller]
+            pub fn inverse(self, parallelism: Parallelism) -> Mat<E> {
+                let n = self.dim();
+                let mut inv = Mat::<E>::zeros(n, n);
+                invert_triangular(inv.as_mut(), self, parallelism);
+                if UNIT {
+                    for i in 0..n {
+                        inv.write(i, i, E::faer_one());
+                    }
+                }
+                inv
+            }
+        }
+    };
+}
+
+impl_tri!(TriLower, TriUpper, TriangularLower, UnitTriangularLower);
+impl_tri!(TriUpper, TriLower, TriangularUpper, UnitTriangularUpper);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, linalg::zip::Diag, unzipped, zipped};
+    use rand::prelude::*;
+
+    fn random_mat(rng: &mut StdRng, n: usize) -> Mat<c64> {
+        Mat::from_fn(n, n, |i, j| {
+            if i == j {
+                c64::new(2.0 + rng.gen::<f64>(), rng.gen())
+            } else {
+                c64::new(rng.gen(), rng.gen())
+            }
+        })
+    }
+
+    fn lower_part(mat: MatRef<'_, c64>, unit: bool) -> Mat<c64> {
+        let mut out = Mat::<c64>::zeros(mat.nrows(), mat.ncols());
+        zipped!(out.as_mut(), mat).for_each_triangular_lower(
+            if unit { Diag::Skip } else { Diag::Include },
+            |unzipped!(mut dst, src)| dst.write(src.read()),
+        );
+        if unit {
+            for i in 0..mat.nrows() {
+                out.write(i, i, c64::new(1.0, 0.0));
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_triangular_views() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let parallelism = Parallelism::Rayon(4);
+
+        for n in [0, 1, 2, 5, 33] {
+            let a = random_mat(rng, n);
+            let rhs = Mat::<c64>::from_fn(n, 3, |_, _| c64::new(rng.gen(), rng.gen()));
+
+            for unit in [false, true] {
+                let lower = lower_part(a.as_ref(), unit);
+                let upper = lower.transpose().to_owned();
+
+                let check = |dense: &Mat<c64>, sol: &Mat<c64>, inv: &Mat<c64>| {
+                    assert!((dense * sol - &rhs).norm_max() < 1e-10);
+                    assert!((dense * inv - Mat::<c64>::identity(n, n)).norm_max() < 1e-10);
+                };
+
+                let (mut sol_l, mut sol_u) = (rhs.clone(), rhs.clone());
+                let (inv_l, inv_u) = if unit {
+                    let l = UnitTriLower::new(a.as_ref());
+                    let u = l.transpose();
+                    assert!(l.structure() == BlockStructure::UnitTriangularLower);
+                    assert!(u.structure() == BlockStructure::UnitTriangularUpper);
+                    l.solve_in_place(sol_l.as_mut(), parallelism);
+                    u.solve_in_place(sol_u.as_mut(), parallelism);
+                    (l.inverse(parallelism), u.inverse(parallelism))
+                } else {
+                    let l = TriLower::<_>::new(a.as_ref());
+                    let u = TriUpper::<_>::new(a.transpose());
+                    assert!(l.structure() == BlockStructure::TriangularLower);
+                    assert!(u.structure() == BlockStructure::TriangularUpper);
+                    l.solve_in_place(sol_l.as_mut(), parallelism);
+                    u.solve_in_place(sol_u.as_mut(), parallelism);
+                    (l.inverse(parallelism), u.inverse(parallelism))
+                };
+
+                check(&lower, &sol_l, &inv_l);
+                check(&upper, &sol_u, &inv_u);
+
+                let (mut prod_l, mut prod_u) = (rhs.clone(), rhs.clone());
+                if unit {
+                    UnitTriLower::new(a.as_ref()).mul_in_place(prod_l.as_mut(), parallelism);
+                    UnitTriUpper::new(a.transpose()).mul_in_place(prod_u.as_mut(), parallelism);
+                } else {
+                    TriLower::<_>::new(a.as_ref()).mul_in_place(prod_l.as_mut(), parallelism);
+                    TriUpper::<_>::new(a.transpose()).mul_in_place(prod_u.as_mut(), parallelism);
+                }
+                assert!((&prod_l - &lower * &rhs).norm_max() < 1e-10);
+                assert!((&prod_u - &upper * &rhs).norm_max() < 1e-10);
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
     assert,
     linalg::{
         matmul::triangular::{self, BlockStructure},
+        triangular::TriangularMatRef,
         triangular_solve as solve,
     },
     utils::thread::join_raw,
@@ -211,6 +212,33 @@ pub fn invert_upper_triangular<E: ComplexField>(
     )
 }
 
+/// Computes the inverse of the triangular matrix `src` and stores the triangular part of the
+/// result to `dst`. The diagonal of `dst` is not written to if `src` has an implicit unit
+/// diagonal.
+///
+/// The triangular structure of `src`, and whether its diagonal is implicitly equal to `1.0`, is
+/// encoded in its type. See [`TriLower`](crate::linalg::triangular::TriLower) and
+/// [`TriUpper`](crate::linalg::triangular::TriUpper).
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have mismatching dimensions.
+#[track_caller]
+pub fn invert_triangular<'a, E: ComplexField>(
+    dst: MatMut<'_, E>,
+    src: impl TriangularMatRef<'a, E>,
+    parallelism: Parallelism,
+) {
+    let tri = src.as_mat_ref();
+    match src.structure() {
+        BlockStructure::TriangularLower => invert_lower_triangular(dst, tri, parallelism),
+        BlockStructure::UnitTriangularLower => invert_unit_lower_triangular(dst, tri, parallelism),
+        BlockStructure::TriangularUpper => invert_upper_triangular(dst, tri, parallelism),
+        BlockStructure::UnitTriangularUpper => invert_unit_upper_triangular(dst, tri, parallelism),
+        _ => unreachable!(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,11 @@
 //! Triangular solve module.
 
 use crate::{
-    assert, debug_assert, unzipped, utils::thread::join_raw, zipped, ComplexField, Conj, Conjugate,
-    MatMut, MatRef, Parallelism,
+    assert, debug_assert,
+    linalg::{matmul::triangular::BlockStructure, triangular::TriangularMatRef},
+    unzipped,
+    utils::thread::join_raw,
+    zipped, ComplexField, Conj, Conjugate, MatMut, MatRef, Parallelism,
 };
 use faer_entity::SimdCtx;
 use reborrow::*;
@@ -307,6 +310,17 @@ pub fn solve_lower_triangular_in_place_with_conj<E: ComplexField>(
         rhs.nrows() == triangular_lower.ncols(),
     ));
 
+    #[cfg(feature = "instrument")]
+    let _scope = {
+        use crate::utils::instrument::*;
+        let (n, k) = (triangular_lower.nrows(), rhs.ncols());
+        Scope::new(
+            "solve_lower_triangular",
+            flops::<E>(n as f64 * n as f64 * k as f64),
+            bytes::<E>(n * (n + 1) / 2 + 2 * n * k),
+        )
+    };
+
     unsafe {
         solve_lower_triangular_in_place_unchecked(triangular_lower, conj_lhs, rhs, parallelism);
     }
@@ -393,6 +407,17 @@ pub fn solve_upper_triangular_in_place_with_conj<E: ComplexField>(
         rhs.nrows() == triangular_upper.ncols(),
     ));
 
+    #[cfg(feature = "instrument")]
+    let _scope = {
+        use crate::utils::instrument::*;
+        let (n, k) = (triangular_upper.nrows(), rhs.ncols());
+        Scope::new(
+            "solve_upper_triangular",
+            flops::<E>(n as f64 * n as f64 * k as f64),
+            bytes::<E>(n * (n + 1) / 2 + 2 * n * k),
+        )
+    };
+
     unsafe {
         solve_upper_triangular_in_place_unchecked(triangular_upper, conj_lhs, rhs, parallelism);
     }
@@ -479,6 +504,17 @@ pub fn solve_unit_lower_triangular_in_place_with_conj<E: ComplexField>(
         rhs.nrows() == triangular_lower.ncols(),
     ));
 
+    #[cfg(feature = "instrument")]
+    let _scope = {
+        use crate::utils::instrument::*;
+        let (n, k) = (triangular_lower.nrows(), rhs.ncols());
+        Scope::new(
+            "solve_unit_lower_triangular",
+            flops::<E>(n as f64 * n as f64 * k as f64),
+            bytes::<E>(n * (n + 1) / 2 + 2 * n * k),
+        )
+    };
+
     unsafe {
         solve_unit_lower_triangular_in_place_unchecked(
             triangular_lower,
@@ -568,6 +604,17 @@ pub fn solve_unit_upper_triangular_in_place_with_conj<E: ComplexField>(
         rhs.nrows() == triangular_upper.ncols(),
     ));
 
+    #[cfg(feature = "instrument")]
+    let _scope = {
+        use crate::utils::instrument::*;
+        let (n, k) = (triangular_upper.nrows(), rhs.ncols());
+        Scope::new(
+            "solve_unit_upper_triangular",
+            flops::<E>(n as f64 * n as f64 * k as f64),
+            bytes::<E>(n * (n + 1) / 2 + 2 * n * k),
+        )
+    };
+
     unsafe {
         solve_unit_upper_triangular_in_place_unchecked(
             triangular_upper,
@@ -594,6 +641,32 @@ pub fn solve_unit_upper_triangular_in_place<E: ComplexField, TriE: Conjugate<Can
     solve_unit_upper_triangular_in_place_with_conj(tri, conj, rhs, parallelism)
 }
 
+/// Computes the solution of `triangular×X = rhs`, and stores the result in `rhs`.
+///
+/// The triangular structure of `triangular`, and whether its diagonal is implicitly equal to
+/// `1.0`, is encoded in its type. See [`TriLower`](crate::linalg::triangular::TriLower) and
+/// [`TriUpper`](crate::linalg::triangular::TriUpper).
+#[track_caller]
+#[inline]
+pub fn solve_triangular_in_place<'a, E: ComplexField, TriE: Conjugate<Canonical = E>>(
+    triangular: impl TriangularMatRef<'a, TriE>,
+    rhs: MatMut<'_, E>,
+    parallelism: Parallelism,
+) {
+    let tri = triangular.as_mat_ref();
+    match triangular.structure() {
+        BlockStructure::TriangularLower => solve_lower_triangular_in_place(tri, rhs, parallelism),
+        BlockStructure::UnitTriangularLower => {
+            solve_unit_lower_triangular_in_place(tri, rhs, parallelism)
+        }
+        BlockStructure::TriangularUpper => solve_upper_triangular_in_place(tri, rhs, parallelism),
+        BlockStructure::UnitTriangularUpper => {
+            solve_unit_upper_triangular_in_place(tri, rhs, parallelism)
+        }
+        _ => unreachable!(),
+    }
+}
+
 /// # Safety
 ///
 /// Same as [`solve_unit_lower_triangular_in_place`], except that panics become undefined behavior.
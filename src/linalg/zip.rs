@@ -2596,4 +2596,48 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_zip_broadcast() {
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f64]];
+        let col = col![10.0, 20.0];
+        let row = row![100.0, 200.0, 300.0];
+        let scalar = 1000.0;
+
+        let target = Mat::from_fn(2, 3, |i, j| {
+            a.read(i, j) + col.read(i) + row.read(j) + scalar
+        });
+
+        for transpose in [false, true] {
+            let mut out = Mat::<f64>::zeros(2, 3);
+            let mut out_t = Mat::<f64>::zeros(3, 2);
+            let dst = if transpose {
+                out_t.as_mut().transpose_mut()
+            } else {
+                out.as_mut()
+            };
+
+            zipped!(
+                dst,
+                a.as_ref(),
+                col.as_ref().repeat_cols(3),
+                row.as_ref().repeat_rows(2),
+                crate::mat::from_repeated_ref(&scalar, 2, 3),
+            )
+            .for_each(|unzipped!(mut dst, a, col, row, scalar)| {
+                dst.write(a.read() + col.read() + row.read() + scalar.read())
+            });
+
+            if transpose {
+                assert!(out_t.as_ref().transpose() == target.as_ref());
+            } else {
+                assert!(out == target);
+            }
+        }
+
+        let mut a = a;
+        zipped!(a.as_mut(), row.as_ref().repeat_rows(2))
+            .for_each_triangular_lower(Diag::Include, |unzipped!(mut a, row)| a.write(row.read()));
+        assert!(a == mat![[100.0, 2.0, 3.0], [100.0, 200.0, 6.0]]);
+    }
 }
@@ -0,0 +1,249 @@
+use crate::{
+    assert,
+    linalg::{temp_mat_req, temp_mat_uninit},
+    linop::{BiLinOp, LinOp},
+    ComplexField, MatMut, MatRef, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+/// Kronecker product `A ⊗ B` of two linear operators, applied without forming the product.
+///
+/// The product is applied to a vector `x` through the identity `(A ⊗ B) vec(X) = vec(B X Aᵀ)`,
+/// where `vec` stacks the columns of a matrix. Applying it to a vector costs one application of `B`
+/// to a matrix with `A.ncols()` columns, and one application of `A` to a matrix with `B.nrows()`
+/// columns.
+#[derive(Copy, Clone, Debug)]
+pub struct KronOp<A, B>(pub A, pub B);
+
+fn kron_apply_req<E: ComplexField>(
+    (a_nrows, a_ncols): (usize, usize),
+    (b_nrows, b_ncols): (usize, usize),
+    a_req: StackReq,
+    b_req: StackReq,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<E>(b_ncols, a_ncols)?,
+        temp_mat_req::<E>(b_nrows, a_ncols)?,
+        temp_mat_req::<E>(a_nrows, b_nrows)?,
+        a_req.try_or(b_req)?,
+    ])
+}
+
+// `apply_a` and `apply_b` apply operators with the given dimensions, which may be the transposes
+// of the original ones
+#[track_caller]
+fn kron_apply<E: ComplexField>(
+    out: MatMut<'_, E>,
+    rhs: MatRef<'_, E>,
+    (a_nrows, a_ncols): (usize, usize),
+    (b_nrows, b_ncols): (usize, usize),
+    apply_a: impl Fn(MatMut<'_, E>, MatRef<'_, E>, PodStack<'_>),
+    apply_b: impl Fn(MatMut<'_, E>, MatRef<'_, E>, PodStack<'_>),
+    stack: PodStack<'_>,
+) {
+    assert!(all(
+        out.nrows() == a_nrows * b_nrows,
+        rhs.nrows() == a_ncols * b_ncols,
+        out.ncols() == rhs.ncols(),
+    ));
+
+    let mut out = out;
+    let (mut x, stack) = temp_mat_uninit::<E>(b_ncols, a_ncols, stack);
+    let (mut y, stack) = temp_mat_uninit::<E>(b_nrows, a_ncols, stack);
+    let (mut z, mut stack) = temp_mat_uninit::<E>(a_nrows, b_nrows, stack);
+
+    for k in 0..rhs.ncols() {
+        for j in 0..a_ncols {
+            for i in 0..b_ncols {
+                x.write(i, j, rhs.read(i + b_ncols * j, k));
+            }
+        }
+
+        apply_b(y.rb_mut(), x.rb(), stack.rb_mut());
+        apply_a(z.rb_mut(), y.rb().transpose(), stack.rb_mut());
+
+        for j in 0..a_nrows {
+            for i in 0..b_nrows {
+                out.write(i + b_nrows * j, k, z.read(j, i));
+            }
+        }
+    }
+}
+
+impl<E: ComplexField, A: LinOp<E>, B: LinOp<E>> LinOp<E> for KronOp<A, B> {
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        _ = rhs_ncols;
+        let Self(a, b) = self;
+        kron_apply_req::<E>(
+            (a.nrows(), a.ncols()),
+            (b.nrows(), b.ncols()),
+            a.apply_req(b.nrows(), parallelism)?,
+            b.apply_req(a.ncols(), parallelism)?,
+        )
+    }
+
+    fn nrows(&self) -> usize {
+        self.0.nrows() * self.1.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.0.ncols() * self.1.ncols()
+    }
+
+    #[track_caller]
+    fn apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let Self(a, b) = self;
+        kron_apply(
+            out,
+            rhs,
+            (a.nrows(), a.ncols()),
+            (b.nrows(), b.ncols()),
+            |out, rhs, stack| a.apply(out, rhs, parallelism, stack),
+            |out, rhs, stack| b.apply(out, rhs, parallelism, stack),
+            stack,
+        )
+    }
+
+    #[track_caller]
+    fn conj_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let Self(a, b) = self;
+        kron_apply(
+            out,
+            rhs,
+            (a.nrows(), a.ncols()),
+            (b.nrows(), b.ncols()),
+            |out, rhs, stack| a.conj_apply(out, rhs, parallelism, stack),
+            |out, rhs, stack| b.conj_apply(out, rhs, parallelism, stack),
+            stack,
+        )
+    }
+}
+
+impl<E: ComplexField, A: BiLinOp<E>, B: BiLinOp<E>> BiLinOp<E> for KronOp<A, B> {
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        _ = rhs_ncols;
+        let Self(a, b) = self;
+        kron_apply_req::<E>(
+            (a.ncols(), a.nrows()),
+            (b.ncols(), b.nrows()),
+            a.transpose_apply_req(b.ncols(), parallelism)?,
+            b.transpose_apply_req(a.nrows(), parallelism)?,
+        )
+    }
+
+    #[track_caller]
+    fn transpose_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let Self(a, b) = self;
+        kron_apply(
+            out,
+            rhs,
+            (a.ncols(), a.nrows()),
+            (b.ncols(), b.nrows()),
+            |out, rhs, stack| a.transpose_apply(out, rhs, parallelism, stack),
+            |out, rhs, stack| b.transpose_apply(out, rhs, parallelism, stack),
+            stack,
+        )
+    }
+
+    #[track_caller]
+    fn adjoint_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let Self(a, b) = self;
+        kron_apply(
+            out,
+            rhs,
+            (a.ncols(), a.nrows()),
+            (b.ncols(), b.nrows()),
+            |out, rhs, stack| a.adjoint_apply(out, rhs, parallelism, stack),
+            |out, rhs, stack| b.adjoint_apply(out, rhs, parallelism, stack),
+            stack,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Mat};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_kron_op() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let parallelism = Parallelism::Rayon(4);
+        let mut random =
+            |m: usize, n: usize| Mat::<c64>::from_fn(m, n, |_, _| c64::new(rng.gen(), rng.gen()));
+
+        let a = random(3, 4);
+        let b = random(5, 2);
+        let dense = a.kron(&b);
+        let op: &dyn BiLinOp<c64> = &KronOp(a.as_ref(), b.as_ref());
+        assert!(all(op.nrows() == 15, op.ncols() == 8));
+
+        let rhs = random(8, 3);
+        let mut out = Mat::<c64>::zeros(15, 3);
+        op.apply(
+            out.as_mut(),
+            rhs.as_ref(),
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                op.apply_req(3, parallelism).unwrap(),
+            )),
+        );
+        assert!((&out - &dense * &rhs).norm_max() < 1e-10);
+        op.conj_apply(
+            out.as_mut(),
+            rhs.as_ref(),
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                op.apply_req(3, parallelism).unwrap(),
+            )),
+        );
+        assert!((&out - dense.conjugate() * &rhs).norm_max() < 1e-10);
+
+        let rhs = random(15, 3);
+        let mut out = Mat::<c64>::zeros(8, 3);
+        op.adjoint_apply(
+            out.as_mut(),
+            rhs.as_ref(),
+            parallelism,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                op.transpose_apply_req(3, parallelism).unwrap(),
+            )),
+        );
+        assert!((&out - dense.adjoint() * &rhs).norm_max() < 1e-10);
+    }
+}
@@ -13,6 +13,12 @@ pub mod conjugate_gradient;
 #[allow(missing_docs)]
 pub mod lsmr;
 
+/// Lazy Kronecker product operator.
+pub mod kron;
+
+/// Operator norm estimation.
+pub mod norm_est;
+
 mod linop_impl;
 
 /// Specifies whether the initial guess should be assumed to be zero or not.
@@ -0,0 +1,178 @@
+use crate::{
+    linalg::{temp_mat_req, temp_mat_uninit},
+    linop::BiLinOp,
+    unzipped, zipped, ComplexField, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+/// Computes the size and alignment of the workspace required to estimate the operator 2-norm of
+/// `mat` with [`norm_l2_est`].
+pub fn norm_l2_est_req<E: ComplexField>(
+    mat: impl BiLinOp<E>,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    fn implementation<E: ComplexField>(
+        A: &dyn BiLinOp<E>,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let m = A.nrows();
+        let n = A.ncols();
+        StackReq::try_all_of([
+            temp_mat_req::<E>(n, 1)?,
+            temp_mat_req::<E>(m, 1)?,
+            StackReq::try_any_of([
+                A.apply_req(1, parallelism)?,
+                A.transpose_apply_req(1, parallelism)?,
+            ])?,
+        ])
+    }
+    implementation(&mat, parallelism)
+}
+
+/// Estimates the operator 2-norm of `mat`, i.e., its largest singular value, using the power
+/// iteration on `Aᴴ A`.
+///
+/// The iteration stops once the relative change of the estimate between two consecutive steps is
+/// at most `tol`, or after `max_iter` steps. Each step applies `mat` and its adjoint once.
+///
+/// The estimate is a lower bound of the exact norm. It converges slowly when the two largest
+/// singular values are close, but a loose tolerance is usually enough to get within a few percent
+/// of the exact value.
+#[track_caller]
+pub fn norm_l2_est<E: ComplexField>(
+    mat: impl BiLinOp<E>,
+    tol: E::Real,
+    max_iter: usize,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> E::Real {
+    fn implementation<E: ComplexField>(
+        A: &dyn BiLinOp<E>,
+        tol: E::Real,
+        max_iter: usize,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) -> E::Real {
+        let m = A.nrows();
+        let n = A.ncols();
+        let zero = E::Real::faer_zero();
+        if m == 0 || n == 0 {
+            return zero;
+        }
+
+        let (mut x, stack) = temp_mat_uninit::<E>(n, 1, stack);
+        let (mut y, mut stack) = temp_mat_uninit::<E>(m, 1, stack);
+
+        // deterministic starting vector, with unequal weights so that it is unlikely to be
+        // orthogonal to the leading right singular vector
+        for j in 0..n {
+            x.write(
+                j,
+                0,
+                E::faer_from_real(E::Real::faer_from_f64(1.0 / (j + 1) as f64)),
+            );
+        }
+        let norm = x.rb().norm_l2();
+        zipped!(x.rb_mut())
+            .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(norm.faer_inv())));
+
+        let mut est = zero;
+        for _ in 0..max_iter {
+            A.apply(y.rb_mut(), x.rb(), parallelism, stack.rb_mut());
+            let est_old = est;
+            // ‖A x‖ for a unit vector x
+            est = y.rb().norm_l2();
+            if est == zero {
+                break;
+            }
+
+            A.adjoint_apply(x.rb_mut(), y.rb(), parallelism, stack.rb_mut());
+            let norm = x.rb().norm_l2();
+            if norm == zero {
+                break;
+            }
+            zipped!(x.rb_mut())
+                .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(norm.faer_inv())));
+
+            if est.faer_sub(est_old).faer_abs() <= tol.faer_mul(est) {
+                break;
+            }
+        }
+        est
+    }
+    implementation(&mat, tol, max_iter, parallelism, stack)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, mat::Mat};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_norm_l2_est() {
+        let ref mut rng = StdRng::seed_from_u64(0);
+        let m = 60;
+        let n = 40;
+        let A: Mat<c64> = crate::stats::StandardNormalMat { nrows: m, ncols: n }.sample(rng);
+        let exact = A.singular_values()[0];
+
+        let mut mem = GlobalPodBuffer::new(norm_l2_est_req(A.as_ref(), Parallelism::None).unwrap());
+        let est = norm_l2_est(
+            A.as_ref(),
+            1e-12,
+            1000,
+            Parallelism::None,
+            PodStack::new(&mut mem),
+        );
+        assert!(est <= exact * (1.0 + 1e-12));
+        assert!(est >= exact * (1.0 - 1e-6));
+
+        // a single step gives a lower bound
+        let est = norm_l2_est(
+            A.as_ref(),
+            0.0,
+            1,
+            Parallelism::None,
+            PodStack::new(&mut mem),
+        );
+        assert!(est <= exact * (1.0 + 1e-12));
+        assert!(est > 0.0);
+    }
+
+    #[test]
+    fn test_norm_l2_est_degenerate() {
+        let mut mem = GlobalPodBuffer::new(StackReq::new::<f64>(64));
+        let zero = Mat::<f64>::zeros(4, 3);
+        let est = norm_l2_est(
+            zero.as_ref(),
+            1e-8,
+            10,
+            Parallelism::None,
+            PodStack::new(&mut mem),
+        );
+        assert!(est == 0.0);
+
+        let empty = Mat::<f64>::zeros(0, 3);
+        let est = norm_l2_est(
+            empty.as_ref(),
+            1e-8,
+            10,
+            Parallelism::None,
+            PodStack::new(&mut mem),
+        );
+        assert!(est == 0.0);
+
+        let diag = Mat::<f64>::from_fn(3, 3, |i, j| if i == j { [1.0, -5.0, 2.0][i] } else { 0.0 });
+        let est = norm_l2_est(
+            diag.as_ref(),
+            1e-14,
+            1000,
+            Parallelism::None,
+            PodStack::new(&mut mem),
+        );
+        assert!((est - 5.0).abs() < 1e-10);
+    }
+}
@@ -752,6 +752,46 @@ impl<'a, E: Entity> MatMut<'a, E> {
         implementation(self.rb_mut(), other.as_mat_ref())
     }
 
+    /// Copies the values from `slice` into `self`, with the slice being interpreted in
+    /// column-major format, so that the first `nrows` values of the slice go in the first column
+    /// of the matrix, the next `nrows` values in the second column, and so on.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `self.nrows() * self.ncols() == slice.len()`
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, Mat};
+    ///
+    /// let mut a = Mat::<f64>::zeros(3, 2);
+    /// a.as_mut().copy_from_col_major_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    ///
+    /// assert_eq!(a, mat![[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+    /// ```
+    #[track_caller]
+    pub fn copy_from_col_major_slice(&mut self, slice: GroupFor<E, &[E::Unit]>) {
+        let src = from_column_major_slice::<E>(slice, self.nrows(), self.ncols());
+        zipped!(self.rb_mut(), src).for_each(
+            #[inline(always)]
+            |unzipped!(mut dst, src)| dst.write(src.read()),
+        );
+    }
+
+    /// Copies the values from `slice` into `self`, with the slice being interpreted in row-major
+    /// format, so that the first `ncols` values of the slice go in the first row of the matrix,
+    /// the next `ncols` values in the second row, and so on.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `self.nrows() * self.ncols() == slice.len()`
+    #[track_caller]
+    pub fn copy_from_row_major_slice(&mut self, slice: GroupFor<E, &[E::Unit]>) {
+        self.rb_mut()
+            .transpose_mut()
+            .copy_from_col_major_slice(slice)
+    }
+
     /// Fills the elements of `self` with zeros.
     #[track_caller]
     pub fn fill_zero(&mut self)
@@ -773,6 +813,278 @@ impl<'a, E: Entity> MatMut<'a, E> {
         );
     }
 
+    /// Divides each column of `self` by its norm, as specified by `norm`, with columns whose norm
+    /// is zero being handled according to `zero_policy`. See [`ColMut::normalize_with`].
+    ///
+    /// # Panics
+    /// The function panics if the norm of any column is zero and `zero_policy` is
+    /// [`ZeroNormPolicy::Panic`].
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, Norm, ZeroNormPolicy};
+    ///
+    /// let mut a = mat![[3.0, 0.0, 1.0], [4.0, 0.0, -1.0f64]];
+    /// a.as_mut().normalize_cols(Norm::Max, ZeroNormPolicy::Keep);
+    ///
+    /// assert_eq!(a, mat![[0.75, 0.0, 1.0], [1.0, 0.0, -1.0]]);
+    /// ```
+    #[track_caller]
+    pub fn normalize_cols(&mut self, norm: Norm, zero_policy: ZeroNormPolicy)
+    where
+        E: ComplexField,
+    {
+        for j in 0..self.ncols() {
+            self.rb_mut().col_mut(j).normalize_with(norm, zero_policy);
+        }
+    }
+
+    /// Divides each row of `self` by its norm, as specified by `norm`, with rows whose norm is
+    /// zero being handled according to `zero_policy`. See [`ColMut::normalize_with`].
+    ///
+    /// # Panics
+    /// The function panics if the norm of any row is zero and `zero_policy` is
+    /// [`ZeroNormPolicy::Panic`].
+    #[track_caller]
+    pub fn normalize_rows(&mut self, norm: Norm, zero_policy: ZeroNormPolicy)
+    where
+        E: ComplexField,
+    {
+        self.rb_mut()
+            .transpose_mut()
+            .normalize_cols(norm, zero_policy)
+    }
+
+    /// Overwrites the elements of `self` at the positions `(i, j)` for which `mask(i, j)` is
+    /// `true` with copies of `constant`.
+    #[track_caller]
+    pub fn fill_where(&mut self, mask: impl FnMut(usize, usize) -> bool, constant: E) {
+        let mut mask = mask;
+        zipped!((*self).rb_mut()).for_each_with_index(
+            #[inline(always)]
+            |i, j, unzipped!(mut x)| {
+                if mask(i, j) {
+                    x.write(constant)
+                }
+            },
+        );
+    }
+
+    /// Copies the values from `other` into `self`, at the positions `(i, j)` for which
+    /// `mask(i, j)` is `true`. The other elements of `self` are left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `self.nrows() == other.nrows()`.
+    /// * `self.ncols() == other.ncols()`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let mut a = mat![[1.0, -2.0], [-3.0, 4.0f64]];
+    /// let b = mat![[5.0, 6.0], [7.0, 8.0f64]];
+    ///
+    /// let negative = a.clone();
+    /// a.as_mut().assign_where(|i, j| negative.read(i, j) < 0.0, &b);
+    ///
+    /// assert_eq!(a, mat![[1.0, 6.0], [7.0, 4.0]]);
+    /// ```
+    #[track_caller]
+    pub fn assign_where<ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        mask: impl FnMut(usize, usize) -> bool,
+        other: impl AsMatRef<ViewE>,
+    ) {
+        #[track_caller]
+        #[inline(always)]
+        fn implementation<E: Entity, ViewE: Conjugate<Canonical = E>>(
+            this: MatMut<'_, E>,
+            mut mask: impl FnMut(usize, usize) -> bool,
+            other: MatRef<'_, ViewE>,
+        ) {
+            zipped!(this, other).for_each_with_index(|i, j, unzipped!(mut dst, src)| {
+                if mask(i, j) {
+                    dst.write(src.read().canonicalize())
+                }
+            });
+        }
+        implementation(self.rb_mut(), mask, other.as_mat_ref())
+    }
+
+    /// Fills the elements of `self` above the `offset`-th diagonal with zeros, that is the
+    /// elements at the positions `(i, j)` such that `j - i > offset`.
+    ///
+    /// An offset of zero refers to the main diagonal, a positive offset to a diagonal above it,
+    /// and a negative offset to a diagonal below it.
+    #[track_caller]
+    pub fn zero_above_diag(&mut self, offset: isize)
+    where
+        E: ComplexField,
+    {
+        let nrows = self.nrows() as isize;
+        for j in 0..self.ncols() {
+            let end = (j as isize).saturating_sub(offset).clamp(0, nrows) as usize;
+            self.rb_mut().col_mut(j).subrows_mut(0, end).fill_zero();
+        }
+    }
+
+    /// Fills the elements of `self` below the `offset`-th diagonal with zeros, that is the
+    /// elements at the positions `(i, j)` such that `j - i < offset`.
+    ///
+    /// An offset of zero refers to the main diagonal, a positive offset to a diagonal above it,
+    /// and a negative offset to a diagonal below it.
+    #[track_caller]
+    pub fn zero_below_diag(&mut self, offset: isize)
+    where
+        E: ComplexField,
+    {
+        let nrows = self.nrows() as isize;
+        for j in 0..self.ncols() {
+            let start = (j as isize)
+                .saturating_sub(offset)
+                .saturating_add(1)
+                .clamp(0, nrows) as usize;
+            self.rb_mut()
+                .col_mut(j)
+                .subrows_mut(start, nrows as usize - start)
+                .fill_zero();
+        }
+    }
+
+    /// Overwrites the strictly upper triangular part of `self` with the adjoint of its strictly
+    /// lower triangular part, so that `self` becomes hermitian. The diagonal is left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if `self` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let mut a = mat![[1.0, 0.0], [2.0, 3.0f64]];
+    /// a.as_mut().symmetrize_lower();
+    ///
+    /// assert_eq!(a, mat![[1.0, 2.0], [2.0, 3.0]]);
+    /// ```
+    #[track_caller]
+    pub fn symmetrize_lower(&mut self)
+    where
+        E: ComplexField,
+    {
+        assert!(self.nrows() == self.ncols());
+        let n = self.nrows();
+        for j in 0..n {
+            for i in j + 1..n {
+                unsafe { self.write_unchecked(j, i, self.read_unchecked(i, j).faer_conj()) };
+            }
+        }
+    }
+
+    /// Overwrites the strictly lower triangular part of `self` with the adjoint of its strictly
+    /// upper triangular part, so that `self` becomes hermitian. The diagonal is left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if `self` is not square.
+    #[track_caller]
+    pub fn symmetrize_upper(&mut self)
+    where
+        E: ComplexField,
+    {
+        self.rb_mut().transpose_mut().symmetrize_lower()
+    }
+
+    /// Copies the `k`-th row of `other` into the row of `self` at index `row_indices[k]`.
+    ///
+    /// If an index is repeated, the row of `self` at that index contains the last row of `other`
+    /// that was assigned to it.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `other.nrows() == row_indices.len()`.
+    /// * `self.ncols() == other.ncols()`.
+    /// * all the indices are less than `self.nrows()`.
+    #[track_caller]
+    pub fn scatter_rows<I: crate::Index, ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        row_indices: &[I],
+        other: impl AsMatRef<ViewE>,
+    ) {
+        let other = other.as_mat_ref();
+        assert!(all(
+            other.nrows() == row_indices.len(),
+            self.ncols() == other.ncols(),
+        ));
+        for (k, &i) in row_indices.iter().enumerate() {
+            (*self).rb_mut().row_mut(i.zx()).copy_from(other.row(k));
+        }
+    }
+
+    /// Copies the `k`-th column of `other` into the column of `self` at index `col_indices[k]`.
+    ///
+    /// If an index is repeated, the column of `self` at that index contains the last column of
+    /// `other` that was assigned to it.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `other.ncols() == col_indices.len()`.
+    /// * `self.nrows() == other.nrows()`.
+    /// * all the indices are less than `self.ncols()`.
+    #[track_caller]
+    pub fn scatter_cols<I: crate::Index, ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        col_indices: &[I],
+        other: impl AsMatRef<ViewE>,
+    ) {
+        (*self)
+            .rb_mut()
+            .transpose_mut()
+            .scatter_rows(col_indices, other.as_mat_ref().transpose())
+    }
+
+    /// Permutes the rows of `self` in place, so that the column at index `col_idx` is sorted in
+    /// ascending order.
+    ///
+    /// The sort is stable, and the rows whose key is `NaN` are placed at the end.
+    ///
+    /// # Panics
+    /// The function panics if `col_idx >= self.ncols()`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let mut a = mat![[3.0, 1.0], [1.0, 2.0], [2.0, 3.0f64]];
+    /// a.as_mut().sort_rows_by_col(0);
+    ///
+    /// assert_eq!(a, mat![[1.0, 2.0], [2.0, 3.0], [3.0, 1.0]]);
+    /// ```
+    #[track_caller]
+    pub fn sort_rows_by_col(&mut self, col_idx: usize)
+    where
+        E: RealField,
+    {
+        let perm = self.rb().col(col_idx).argsort();
+        crate::perm::cycle_permute_rows((*self).rb_mut(), perm.as_ref());
+    }
+
+    /// Permutes the rows of `self` in place, so that the column at index `col_idx` is sorted in
+    /// ascending order.
+    ///
+    /// The sort is not stable, so the relative order of rows with equal keys is unspecified, and
+    /// the rows whose key is `NaN` are placed at the end.
+    ///
+    /// # Panics
+    /// The function panics if `col_idx >= self.ncols()`.
+    #[track_caller]
+    pub fn sort_rows_by_col_unstable(&mut self, col_idx: usize)
+    where
+        E: RealField,
+    {
+        let perm = self.rb().col(col_idx).argsort_unstable();
+        crate::perm::cycle_permute_rows((*self).rb_mut(), perm.as_ref());
+    }
+
     /// Returns a view over the transpose of `self`.
     ///
     /// # Example
@@ -1416,6 +1728,101 @@ impl<'a, E: Entity> MatMut<'a, E> {
         unsafe { self.into_const().col(col_idx).const_cast() }
     }
 
+    /// Returns a view over the same elements with dimensions `(nrows, ncols)`, where the elements
+    /// are read in column-major order, or `None` if the memory layout of `self` does not allow
+    /// it without copying. See [`MatRef::try_reshape`].
+    ///
+    /// # Panics
+    /// The function panics if `nrows * ncols != self.nrows() * self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn try_reshape(self, nrows: usize, ncols: usize) -> Option<MatRef<'a, E>> {
+        self.into_const().try_reshape(nrows, ncols)
+    }
+
+    /// Returns a mutable view over the same elements with dimensions `(nrows, ncols)`, where the
+    /// elements are read in column-major order, or `None` if the memory layout of `self` does not
+    /// allow it without copying. See [`MatRef::try_reshape`].
+    ///
+    /// # Panics
+    /// The function panics if `nrows * ncols != self.nrows() * self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn try_reshape_mut(self, nrows: usize, ncols: usize) -> Option<Self> {
+        self.into_const()
+            .try_reshape(nrows, ncols)
+            .map(|reshaped| unsafe { reshaped.const_cast() })
+    }
+
+    /// Returns a view over the same elements with dimensions `(nrows, ncols)`, where the elements
+    /// are read in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if `nrows * ncols != self.nrows() * self.ncols()`, or if the memory
+    /// layout of `self` does not allow reshaping without copying. See [`MatRef::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn reshape(self, nrows: usize, ncols: usize) -> MatRef<'a, E> {
+        self.into_const().reshape(nrows, ncols)
+    }
+
+    /// Returns a mutable view over the same elements with dimensions `(nrows, ncols)`, where the
+    /// elements are read in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if `nrows * ncols != self.nrows() * self.ncols()`, or if the memory
+    /// layout of `self` does not allow reshaping without copying. See [`MatRef::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn reshape_mut(self, nrows: usize, ncols: usize) -> Self {
+        unsafe { self.into_const().reshape(nrows, ncols).const_cast() }
+    }
+
+    /// Returns a view over the elements of `self` as a column vector, in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if the memory layout of `self` does not allow it without copying. See
+    /// [`MatRef::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn into_col(self) -> ColRef<'a, E> {
+        self.into_const().into_col()
+    }
+
+    /// Returns a mutable view over the elements of `self` as a column vector, in column-major
+    /// order.
+    ///
+    /// # Panics
+    /// The function panics if the memory layout of `self` does not allow it without copying. See
+    /// [`MatRef::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn into_col_mut(self) -> ColMut<'a, E> {
+        unsafe { self.into_const().into_col().const_cast() }
+    }
+
+    /// Returns a view over the elements of `self` as a row vector, in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if the memory layout of `self` does not allow it without copying. See
+    /// [`MatRef::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn into_row(self) -> RowRef<'a, E> {
+        self.into_const().into_row()
+    }
+
+    /// Returns a mutable view over the elements of `self` as a row vector, in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if the memory layout of `self` does not allow it without copying. See
+    /// [`MatRef::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn into_row_mut(self) -> RowMut<'a, E> {
+        unsafe { self.into_const().into_row().const_cast() }
+    }
+
     /// Returns views over the columns at the given indices.
     ///
     /// # Panics
@@ -1478,6 +1885,20 @@ impl<'a, E: Entity> MatMut<'a, E> {
         }
     }
 
+    /// Returns a view over the `offset`-th diagonal of the matrix. See
+    /// [`MatRef::diagonal_offset`].
+    #[inline]
+    pub fn diagonal_offset(self, offset: isize) -> ColRef<'a, E> {
+        self.into_const().diagonal_offset(offset)
+    }
+
+    /// Returns a mutable view over the `offset`-th diagonal of the matrix. See
+    /// [`MatRef::diagonal_offset`].
+    #[inline]
+    pub fn diagonal_offset_mut(self, offset: isize) -> ColMut<'a, E> {
+        unsafe { self.into_const().diagonal_offset(offset).const_cast() }
+    }
+
     /// Returns an owning [`Mat`] of the data
     #[inline]
     pub fn to_owned(&self) -> Mat<E::Canonical>
@@ -1487,6 +1908,132 @@ impl<'a, E: Entity> MatMut<'a, E> {
         self.rb().to_owned()
     }
 
+    /// Copies the values of `self` into `slice` in column-major format. See
+    /// [`MatRef::write_to_col_major_slice`].
+    #[track_caller]
+    pub fn write_to_col_major_slice(&self, slice: GroupFor<E, &mut [E::Unit]>) {
+        self.rb().write_to_col_major_slice(slice)
+    }
+
+    /// Copies the values of `self` into `slice` in row-major format. See
+    /// [`MatRef::write_to_row_major_slice`].
+    #[track_caller]
+    pub fn write_to_row_major_slice(&self, slice: GroupFor<E, &mut [E::Unit]>) {
+        self.rb().write_to_row_major_slice(slice)
+    }
+
+    /// Returns a new matrix whose `k`-th row is the row of `self` at index `row_indices[k]`.
+    /// See [`MatRef::select_rows`].
+    #[track_caller]
+    pub fn select_rows<I: crate::Index>(&self, row_indices: &[I]) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.rb().select_rows(row_indices)
+    }
+
+    /// Returns a new matrix whose `k`-th column is the column of `self` at index
+    /// `col_indices[k]`. See [`MatRef::select_cols`].
+    #[track_caller]
+    pub fn select_cols<I: crate::Index>(&self, col_indices: &[I]) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.rb().select_cols(col_indices)
+    }
+
+    /// Returns a new matrix containing `self` tiled `nrows` times vertically and `ncols` times
+    /// horizontally. See [`MatRef::repeat`].
+    #[doc(alias = "tile")]
+    #[track_caller]
+    pub fn repeat(&self, nrows: usize, ncols: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.rb().repeat(nrows, ncols)
+    }
+
+    /// Returns a new matrix containing the elements of `self` on and below the `offset`-th
+    /// diagonal, with the other elements set to zero. See [`MatRef::tril`].
+    #[track_caller]
+    pub fn tril(&self, offset: isize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().tril(offset)
+    }
+
+    /// Returns a new matrix containing the elements of `self` on and above the `offset`-th
+    /// diagonal, with the other elements set to zero. See [`MatRef::triu`].
+    #[track_caller]
+    pub fn triu(&self, offset: isize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().triu(offset)
+    }
+
+    /// Returns the hermitian part of `self`, `(A + A^H) / 2`. See [`MatRef::hermitian_part`].
+    #[track_caller]
+    pub fn hermitian_part(&self) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().hermitian_part()
+    }
+
+    /// Returns the skew-hermitian part of `self`, `(A - A^H) / 2`. See [`MatRef::skew_part`].
+    #[track_caller]
+    pub fn skew_part(&self) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().skew_part()
+    }
+
+    /// Copies `self` into a newly allocated sparse matrix in column-major format, dropping the
+    /// entries whose absolute value is less than or equal to `tol`.
+    ///
+    /// The number of retained entries can be queried with
+    /// [`SparseColMat::compute_nnz`](crate::sparse::SparseColMat::compute_nnz). A negative `tol`
+    /// retains all the entries.
+    #[inline]
+    pub fn to_sparse<I: crate::Index>(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Result<crate::sparse::SparseColMat<I, E::Canonical>, crate::sparse::FaerError>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().to_sparse(tol)
+    }
+
+    /// Applies `f` to each element of `self` and collects the results into a new matrix, whose
+    /// scalar type may differ from that of `self`.
+    #[inline]
+    pub fn map<U: Entity>(&self, f: impl FnMut(E) -> U) -> Mat<U> {
+        self.rb().map(f)
+    }
+
+    /// Applies `f` to each element of `self` along with its row and column indices, and collects
+    /// the results into a new matrix, whose scalar type may differ from that of `self`.
+    #[inline]
+    pub fn map_with_index<U: Entity>(&self, f: impl FnMut(usize, usize, E) -> U) -> Mat<U> {
+        self.rb().map_with_index(f)
+    }
+
+    /// Returns a column containing the elements of `self` at the positions `(i, j)` for which
+    /// `mask(i, j)` is `true`, in column-major order.
+    #[inline]
+    pub fn select_where(&self, mask: impl FnMut(usize, usize) -> bool) -> Col<E> {
+        self.rb().select_where(mask)
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -1550,6 +2097,109 @@ impl<'a, E: Entity> MatMut<'a, E> {
         self.rb().sum()
     }
 
+    /// Returns the trace of `self`, which is the sum of its diagonal elements. See
+    /// [`MatRef::trace`].
+    #[inline]
+    pub fn trace(&self) -> E
+    where
+        E: ComplexField,
+    {
+        self.rb().trace()
+    }
+
+    /// Returns the product of the diagonal elements of `self`. See [`MatRef::diag_product`].
+    #[inline]
+    pub fn diag_product(&self) -> E
+    where
+        E: ComplexField,
+    {
+        self.rb().diag_product()
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the product of the
+    /// diagonal elements of `self`. See [`MatRef::diag_log_product`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn diag_log_product(&self) -> (E, E)
+    where
+        E: RealField + num_traits::Float,
+    {
+        self.rb().diag_log_product()
+    }
+
+    /// Returns a column containing the sum of each row of `self`.
+    #[inline]
+    pub fn row_sum(&self) -> Col<E>
+    where
+        E: ComplexField,
+    {
+        self.rb().row_sum()
+    }
+
+    /// Returns a row containing the sum of each column of `self`.
+    #[inline]
+    pub fn col_sum(&self) -> Row<E>
+    where
+        E: ComplexField,
+    {
+        self.rb().col_sum()
+    }
+
+    /// Returns a column containing the mean of each row of `self`.
+    #[inline]
+    pub fn row_mean(&self) -> Col<E>
+    where
+        E: ComplexField,
+    {
+        self.rb().row_mean()
+    }
+
+    /// Returns a row containing the mean of each column of `self`.
+    #[inline]
+    pub fn col_mean(&self) -> Row<E>
+    where
+        E: ComplexField,
+    {
+        self.rb().col_mean()
+    }
+
+    /// Returns a column containing the maximum of each row of `self`, ignoring NaN values.
+    #[inline]
+    pub fn row_max(&self) -> Col<E>
+    where
+        E: RealField,
+    {
+        self.rb().row_max()
+    }
+
+    /// Returns a column containing the minimum of each row of `self`, ignoring NaN values.
+    #[inline]
+    pub fn row_min(&self) -> Col<E>
+    where
+        E: RealField,
+    {
+        self.rb().row_min()
+    }
+
+    /// Returns a row containing the maximum of each column of `self`, ignoring NaN values.
+    #[inline]
+    pub fn col_max(&self) -> Row<E>
+    where
+        E: RealField,
+    {
+        self.rb().col_max()
+    }
+
+    /// Returns a row containing the minimum of each column of `self`, ignoring NaN values.
+    #[inline]
+    pub fn col_min(&self) -> Row<E>
+    where
+        E: RealField,
+    {
+        self.rb().col_min()
+    }
+
     /// Kroneckor product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -1581,6 +2231,96 @@ impl<'a, E: Entity> MatMut<'a, E> {
         self
     }
 
+    /// Returns an iterator over the columns of this matrix.
+    #[inline]
+    pub fn col_iter(
+        self,
+    ) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = ColRef<'a, E>> {
+        self.into_const().col_iter()
+    }
+
+    /// Returns an iterator over the mutable columns of this matrix.
+    #[inline]
+    pub fn col_iter_mut(
+        self,
+    ) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = ColMut<'a, E>> {
+        self.into_const()
+            .col_iter()
+            .map(|col| unsafe { col.const_cast() })
+    }
+
+    /// Returns an iterator over the rows of this matrix.
+    #[inline]
+    pub fn row_iter(
+        self,
+    ) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = RowRef<'a, E>> {
+        self.into_const().row_iter()
+    }
+
+    /// Returns an iterator over the mutable rows of this matrix.
+    #[inline]
+    pub fn row_iter_mut(
+        self,
+    ) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = RowMut<'a, E>> {
+        self.into_const()
+            .row_iter()
+            .map(|row| unsafe { row.const_cast() })
+    }
+
+    /// Returns a parallel iterator over the columns of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = ColRef<'a, E>> {
+        self.into_const().par_col_iter()
+    }
+
+    /// Returns a parallel iterator over the mutable columns of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter_mut(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = ColMut<'a, E>> {
+        use rayon::prelude::*;
+        self.into_const()
+            .par_col_iter()
+            .map(|col| unsafe { col.const_cast() })
+    }
+
+    /// Returns a parallel iterator over the rows of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = RowRef<'a, E>> {
+        self.into_const().par_row_iter()
+    }
+
+    /// Returns a parallel iterator over the mutable rows of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter_mut(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = RowMut<'a, E>> {
+        use rayon::prelude::*;
+        self.into_const()
+            .par_row_iter()
+            .map(|row| unsafe { row.const_cast() })
+    }
+
     /// Returns an iterator that provides successive chunks of the columns of this matrix, with
     /// each having at most `chunk_size` columns.
     ///
@@ -1718,6 +2458,83 @@ impl<'a, E: Entity> MatMut<'a, E> {
             .par_row_chunks(chunk_size)
             .map(|chunk| unsafe { chunk.const_cast() })
     }
+
+    /// Returns an iterator over the non-overlapping tiles of this matrix, with each having at most
+    /// `block_nrows` rows and `block_ncols` columns. See [`MatRef::tiles`].
+    #[inline]
+    #[track_caller]
+    pub fn tiles(
+        self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl 'a + DoubleEndedIterator<Item = MatRef<'a, E>> {
+        self.into_const().tiles(block_nrows, block_ncols)
+    }
+
+    /// Returns an iterator over the non-overlapping mutable tiles of this matrix, with each having
+    /// at most `block_nrows` rows and `block_ncols` columns. See [`MatRef::tiles`].
+    ///
+    /// # Example
+    /// ```
+    /// use faer::Mat;
+    ///
+    /// let mut a = Mat::<f64>::zeros(4, 4);
+    /// for (k, mut tile) in a.as_mut().tiles_mut(2, 2).enumerate() {
+    ///     tile.fill(k as f64);
+    /// }
+    ///
+    /// assert_eq!(a.read(1, 0), 0.0);
+    /// assert_eq!(a.read(3, 0), 1.0);
+    /// assert_eq!(a.read(0, 3), 2.0);
+    /// assert_eq!(a.read(2, 2), 3.0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn tiles_mut(
+        self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl 'a + DoubleEndedIterator<Item = MatMut<'a, E>> {
+        self.into_const()
+            .tiles(block_nrows, block_ncols)
+            .map(|tile| unsafe { tile.const_cast() })
+    }
+
+    /// Returns a parallel iterator over the non-overlapping tiles of this matrix, with each
+    /// having at most `block_nrows` rows and `block_ncols` columns. See [`MatRef::par_tiles`].
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    #[track_caller]
+    pub fn par_tiles(
+        self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = MatRef<'a, E>> {
+        self.into_const().par_tiles(block_nrows, block_ncols)
+    }
+
+    /// Returns a parallel iterator over the non-overlapping mutable tiles of this matrix, with
+    /// each having at most `block_nrows` rows and `block_ncols` columns. See
+    /// [`MatRef::par_tiles`].
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    #[track_caller]
+    pub fn par_tiles_mut(
+        self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = MatMut<'a, E>> {
+        use rayon::prelude::*;
+        self.into_const()
+            .par_tiles(block_nrows, block_ncols)
+            .map(|tile| unsafe { tile.const_cast() })
+    }
 }
 
 impl<'a, E: RealField> MatMut<'a, num_complex::Complex<E>> {
@@ -1942,6 +2759,12 @@ impl<'a, E: Entity> core::fmt::Debug for MatMut<'a, E> {
     }
 }
 
+impl<E: Entity + core::fmt::Display> core::fmt::Display for MatMut<'_, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.as_ref(), f)
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<(usize, usize)> for MatMut<'_, E> {
     type Output = E;
 
@@ -101,6 +101,126 @@ impl<E: Entity> Mat<E> {
         this
     }
 
+    /// Returns a new matrix with dimensions `(nrows, ncols)`, filled with the values yielded by
+    /// `iter` in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if `iter` does not yield exactly `nrows * ncols` values, or if the
+    /// total capacity in bytes exceeds `isize::MAX`.
+    #[track_caller]
+    pub fn from_col_major_iter(
+        nrows: usize,
+        ncols: usize,
+        iter: impl IntoIterator<Item = E>,
+    ) -> Self {
+        let mut iter = iter.into_iter();
+        // `from_fn` fills the matrix one column at a time
+        let this = Self::from_fn(nrows, ncols, |_, _| {
+            iter.next()
+                .expect("the iterator yielded fewer than `nrows * ncols` values")
+        });
+        assert!(iter.next().is_none());
+        this
+    }
+
+    /// Returns a new matrix with dimensions `(nrows, ncols)`, filled with the values yielded by
+    /// `iter` in row-major order.
+    ///
+    /// # Panics
+    /// The function panics if `iter` does not yield exactly `nrows * ncols` values, or if the
+    /// total capacity in bytes exceeds `isize::MAX`.
+    #[track_caller]
+    pub fn from_row_major_iter(
+        nrows: usize,
+        ncols: usize,
+        iter: impl IntoIterator<Item = E>,
+    ) -> Self {
+        let mut iter = iter.into_iter();
+        let mut this = Self::zeros(nrows, ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                let value = iter
+                    .next()
+                    .expect("the iterator yielded fewer than `nrows * ncols` values");
+                this.write(i, j, value);
+            }
+        }
+        assert!(iter.next().is_none());
+        this
+    }
+
+    /// Returns a new matrix whose columns are the values yielded by `cols`, which may be columns
+    /// or column views.
+    ///
+    /// If `cols` is empty, the returned matrix has no rows and no columns.
+    ///
+    /// # Panics
+    /// The function panics if the columns don't all have the same number of rows.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat, Mat};
+    ///
+    /// let samples = [col![1.0, 2.0], col![3.0, 4.0], col![5.0, 6.0f64]];
+    /// let a = Mat::from_cols(&samples);
+    ///
+    /// assert_eq!(a, mat![[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+    /// ```
+    #[track_caller]
+    pub fn from_cols<C: AsColRef<E>>(cols: impl IntoIterator<Item = C>) -> Self {
+        let mut iter = cols.into_iter().peekable();
+        let nrows = iter.peek().map(|col| col.as_col_ref().nrows()).unwrap_or(0);
+
+        let mut this = Self::with_capacity(nrows, iter.size_hint().0);
+        this.resize_with(nrows, 0, |_, _| unreachable!());
+        for col in iter {
+            let col = col.as_col_ref();
+            assert!(col.nrows() == nrows);
+            let ncols = this.ncols();
+            if ncols == this.col_capacity() {
+                this.reserve_exact(nrows, Ord::max(2 * ncols, 4));
+            }
+            this.resize_with(nrows, ncols + 1, |i, _| col.read(i));
+        }
+        this
+    }
+
+    /// Returns a new matrix whose rows are the values yielded by `rows`, which may be rows or
+    /// row views.
+    ///
+    /// If `rows` is empty, the returned matrix has no rows and no columns.
+    ///
+    /// # Panics
+    /// The function panics if the rows don't all have the same number of columns.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, row, Mat};
+    ///
+    /// let samples = [row![1.0, 2.0], row![3.0, 4.0], row![5.0, 6.0f64]];
+    /// let a = Mat::from_rows(samples.iter().map(|r| r.as_ref()));
+    ///
+    /// assert_eq!(a, mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// ```
+    #[track_caller]
+    pub fn from_rows<R: AsRowRef<E>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut iter = rows.into_iter().peekable();
+        let ncols = iter.peek().map(|row| row.as_row_ref().ncols()).unwrap_or(0);
+
+        let mut this = Self::with_capacity(iter.size_hint().0, ncols);
+        this.resize_with(0, ncols, |_, _| unreachable!());
+        for row in iter {
+            let row = row.as_row_ref();
+            assert!(row.ncols() == ncols);
+            let nrows = this.nrows();
+            if nrows == this.row_capacity() {
+                this.reserve_exact(Ord::max(2 * nrows, 4), ncols);
+            }
+            this.resize_with(nrows + 1, ncols, |_, j| row.read(j));
+        }
+        this
+    }
+
     /// Returns a new matrix with dimensions `(nrows, ncols)`, filled with zeros.
     ///
     /// # Panics
@@ -110,6 +230,46 @@ impl<E: Entity> Mat<E> {
         Self::from_fn(nrows, ncols, |_, _| unsafe { core::mem::zeroed() })
     }
 
+    /// Returns a new matrix with dimensions `(nrows, ncols)`, filled with zeros.
+    ///
+    /// The columns are split between the threads in the same way as in the parallel kernels, and
+    /// each thread initializes its own block of columns, so that on NUMA systems, the memory pages
+    /// are placed on the node of the thread that first writes to them. See
+    /// [`numa`](crate::utils::numa) for more details.
+    ///
+    /// # Panics
+    /// The function panics if the total capacity in bytes exceeds `isize::MAX`.
+    pub fn zeros_with_parallelism(
+        nrows: usize,
+        ncols: usize,
+        parallelism: crate::Parallelism,
+    ) -> Self {
+        use crate::utils::thread::{for_each_raw, par_split_indices, parallelism_degree};
+
+        let mut this = Self::with_capacity(nrows, ncols);
+        // SAFETY: the dimensions are within the capacity, and all the elements are initialized
+        // below, before the matrix is returned
+        unsafe { this.set_dims(nrows, ncols) };
+
+        let n_threads = Ord::min(parallelism_degree(parallelism), Ord::max(ncols, 1));
+        let mat = this.as_mut().into_const();
+        for_each_raw(
+            n_threads,
+            |tid| {
+                let (col_start, ncols) = par_split_indices(mat.ncols(), tid, n_threads);
+                // SAFETY: the blocks of columns are disjoint
+                let mut block = unsafe { mat.subcols(col_start, ncols).const_cast() };
+                for j in 0..ncols {
+                    for i in 0..block.nrows() {
+                        block.write(i, j, unsafe { core::mem::zeroed() });
+                    }
+                }
+            },
+            parallelism,
+        );
+        this
+    }
+
     /// Returns a new matrix with dimensions `(nrows, ncols)`, filled with ones.
     ///
     /// # Panics
@@ -154,6 +314,87 @@ impl<E: Entity> Mat<E> {
         matrix
     }
 
+    /// Returns a new square matrix with dimension `dim`, filled with zeros, except the `offset`-th
+    /// diagonal which is filled with ones.
+    ///
+    /// The main diagonal has offset `0`, the diagonals above it have positive offsets, and the
+    /// diagonals below it have negative offsets. If `offset.unsigned_abs() >= dim`, the matrix is
+    /// filled with zeros.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, Mat};
+    ///
+    /// let shift = Mat::<f64>::eye_offset(3, 1);
+    /// assert_eq!(shift, mat![[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+    /// ```
+    #[inline]
+    #[doc(alias = "eye")]
+    pub fn eye_offset(dim: usize, offset: isize) -> Self
+    where
+        E: ComplexField,
+    {
+        let mut matrix = Self::zeros(dim, dim);
+        let k = offset.unsigned_abs();
+        for i in 0..dim.saturating_sub(k) {
+            if offset >= 0 {
+                matrix.write(i, i + k, E::faer_one());
+            } else {
+                matrix.write(i + k, i, E::faer_one());
+            }
+        }
+        matrix
+    }
+
+    /// Returns a new square banded matrix built from its diagonals, where each element of `diags`
+    /// is a pair `(offset, values)` giving the offset of the diagonal and its values, using the
+    /// same convention as [`Mat::eye_offset`]. The elements outside the given diagonals are zero,
+    /// and the values of repeated diagonals are summed.
+    ///
+    /// The dimension of the matrix is `values.nrows() + offset.unsigned_abs()`, which must be the
+    /// same for all the diagonals.
+    ///
+    /// # Panics
+    /// The function panics if the diagonals imply different dimensions.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat, Mat};
+    ///
+    /// let lower = col![-1.0, -1.0];
+    /// let diag = col![2.0, 2.0, 2.0];
+    /// let upper = col![-1.0, -1.0];
+    ///
+    /// let a = Mat::from_diags(&[(-1, lower.as_ref()), (0, diag.as_ref()), (1, upper.as_ref())]);
+    /// assert_eq!(
+    ///     a,
+    ///     mat![[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]],
+    /// );
+    /// ```
+    #[track_caller]
+    #[doc(alias = "diags")]
+    pub fn from_diags(diags: &[(isize, ColRef<'_, E>)]) -> Self
+    where
+        E: ComplexField,
+    {
+        let dim = diags
+            .first()
+            .map(|(offset, values)| values.nrows() + offset.unsigned_abs())
+            .unwrap_or(0);
+
+        let mut matrix = Self::zeros(dim, dim);
+        for &(offset, values) in diags {
+            let k = offset.unsigned_abs();
+            assert!(values.nrows() + k == dim);
+            for i in 0..values.nrows() {
+                let (row, col) = if offset >= 0 { (i, i + k) } else { (i + k, i) };
+                let x = matrix.read(row, col);
+                matrix.write(row, col, x.faer_add(values.read(i)));
+            }
+        }
+        matrix
+    }
+
     /// Returns the number of rows of the matrix.
     #[inline(always)]
     pub fn nrows(&self) -> usize {
@@ -207,6 +448,69 @@ impl<E: Entity> Mat<E> {
         self.col_capacity
     }
 
+    /// Decomposes the matrix into its raw components, without deallocating its storage.
+    ///
+    /// Returns the pointer to the data of the matrix, its number of rows and columns, and its row
+    /// and column capacities, in that order. The column stride of the data is equal to the row
+    /// capacity.
+    ///
+    /// After calling this function, the caller is responsible for the memory previously managed
+    /// by the matrix. The only way to release it is to convert it back into a `Mat` with
+    /// [`Mat::from_raw_parts`], and let the destructor run.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, Mat};
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+    /// let (ptr, nrows, ncols, row_cap, col_cap) = a.into_raw_parts();
+    ///
+    /// let a = unsafe { Mat::<f64>::from_raw_parts(ptr, nrows, ncols, row_cap, col_cap) };
+    /// assert_eq!(a, mat![[1.0, 2.0], [3.0, 4.0]]);
+    /// ```
+    #[inline]
+    pub fn into_raw_parts(self) -> (GroupFor<E, *mut E::Unit>, usize, usize, usize, usize) {
+        let mut this = ManuallyDrop::new(self);
+        (
+            this.as_ptr_mut(),
+            this.nrows(),
+            this.ncols(),
+            this.row_capacity(),
+            this.col_capacity(),
+        )
+    }
+
+    /// Creates a `Mat` from its raw components, as returned by [`Mat::into_raw_parts`].
+    ///
+    /// # Safety
+    /// The behavior is undefined if any of the following conditions are violated:
+    /// * `ptr`, `row_capacity` and `col_capacity` must have been obtained from a previous call to
+    /// [`Mat::into_raw_parts`], with the same entity type `E`.
+    /// * `nrows <= row_capacity` and `ncols <= col_capacity`.
+    /// * The first `nrows` elements of each of the first `ncols` columns must be initialized,
+    /// where column `j` starts at `ptr + j * row_capacity`.
+    /// * The storage must not be owned by any other `Mat`.
+    #[inline]
+    pub unsafe fn from_raw_parts(
+        ptr: GroupFor<E, *mut E::Unit>,
+        nrows: usize,
+        ncols: usize,
+        row_capacity: usize,
+        col_capacity: usize,
+    ) -> Self {
+        debug_assert!(all(nrows <= row_capacity, ncols <= col_capacity));
+        Self {
+            inner: MatOwnImpl {
+                ptr: into_copy::<E, _>(E::faer_map(ptr, |ptr| NonNull::new_unchecked(ptr))),
+                nrows,
+                ncols,
+            },
+            row_capacity,
+            col_capacity,
+            __marker: PhantomData,
+        }
+    }
+
     /// Returns the offset between the first elements of two successive rows in the matrix.
     /// Always returns `1` since the matrix is column major.
     #[inline]
@@ -460,6 +764,22 @@ impl<E: Entity> Mat<E> {
         }
     }
 
+    /// Resizes the matrix in-place so that the new dimensions are `(new_nrows, new_ncols)`.
+    /// The existing elements in the top-left block are kept, and new elements are set to `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let mut m = mat![[1.0, 2.0], [3.0, 4.0]];
+    /// m.resize(3, 1, 0.0);
+    /// assert_eq!(m, mat![[1.0], [3.0], [0.0]]);
+    /// ```
+    #[inline]
+    pub fn resize(&mut self, new_nrows: usize, new_ncols: usize, value: E) {
+        self.resize_with(new_nrows, new_ncols, |_, _| value);
+    }
+
     /// Truncates the matrix so that its new dimensions are `new_nrows` and `new_ncols`.  
     /// Both of the new dimensions must be smaller than or equal to the current dimensions.
     ///
@@ -472,6 +792,269 @@ impl<E: Entity> Mat<E> {
         self.resize_with(new_nrows, new_ncols, |_, _| unreachable!());
     }
 
+    /// Inserts `col` into the matrix at index `col_idx`, shifting the columns after it to the
+    /// right.
+    ///
+    /// If the matrix has no columns, its number of rows is set to the number of rows of `col`.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `col_idx <= self.ncols()`.
+    /// * `col.nrows() == self.nrows()`, if `self.ncols() > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat};
+    ///
+    /// let mut a = mat![[1.0, 3.0], [4.0, 6.0f64]];
+    /// a.insert_col(1, col![2.0, 5.0]);
+    ///
+    /// assert_eq!(a, mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// ```
+    #[track_caller]
+    pub fn insert_col<ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        col_idx: usize,
+        col: impl AsColRef<ViewE>,
+    ) {
+        let col = col.as_col_ref();
+        let ncols = self.ncols();
+        assert!(col_idx <= ncols);
+        if ncols == 0 {
+            self.resize_with(col.nrows(), 0, |_, _| unreachable!());
+        }
+        let nrows = self.nrows();
+        assert!(col.nrows() == nrows);
+
+        if ncols == self.col_capacity() {
+            self.reserve_exact(nrows, Ord::max(2 * ncols, 4));
+        }
+
+        let ptr = self.as_ptr_mut();
+        let col_stride = self.col_stride();
+        for j in (col_idx..ncols).rev() {
+            // SAFETY: columns `j` and `j + 1` are within the allocation since we reserved enough
+            // space, and they don't overlap since the column stride is at least `nrows`
+            E::faer_map(E::faer_copy(&ptr), |ptr| unsafe {
+                let src = ptr.wrapping_offset(j as isize * col_stride);
+                core::ptr::copy_nonoverlapping(src, src.wrapping_offset(col_stride), nrows)
+            });
+        }
+        self.inner.ncols = ncols + 1;
+
+        for i in 0..nrows {
+            unsafe { self.write_unchecked(i, col_idx, col.read_unchecked(i).canonicalize()) };
+        }
+    }
+
+    /// Removes the column at index `col_idx` from the matrix and returns it, shifting the columns
+    /// after it to the left.
+    ///
+    /// # Panics
+    /// The function panics if `col_idx >= self.ncols()`.
+    #[track_caller]
+    pub fn remove_col(&mut self, col_idx: usize) -> Col<E> {
+        let ncols = self.ncols();
+        assert!(col_idx < ncols);
+        let nrows = self.nrows();
+        let removed = Col::from_fn(nrows, |i| unsafe { self.read_unchecked(i, col_idx) });
+
+        let ptr = self.as_ptr_mut();
+        let col_stride = self.col_stride();
+        for j in col_idx + 1..ncols {
+            // SAFETY: columns `j - 1` and `j` are within the allocation, and they don't overlap
+            // since the column stride is at least `nrows`
+            E::faer_map(E::faer_copy(&ptr), |ptr| unsafe {
+                let src = ptr.wrapping_offset(j as isize * col_stride);
+                core::ptr::copy_nonoverlapping(src, src.wrapping_offset(-col_stride), nrows)
+            });
+        }
+        self.inner.ncols = ncols - 1;
+
+        removed
+    }
+
+    /// Appends `col` to the end of the matrix.
+    ///
+    /// If the matrix has no columns, its number of rows is set to the number of rows of `col`.
+    /// The spare column capacity of the matrix is used when available, and is otherwise grown
+    /// geometrically, so that repeatedly pushing columns takes amortized linear time.
+    ///
+    /// # Panics
+    /// The function panics if `col.nrows() != self.nrows()` and `self.ncols() > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat, Mat};
+    ///
+    /// let mut a = Mat::<f64>::with_capacity(2, 3);
+    /// a.push_col(col![1.0, 2.0]);
+    /// a.push_col(col![3.0, 4.0]);
+    ///
+    /// assert_eq!(a, mat![[1.0, 3.0], [2.0, 4.0]]);
+    /// assert_eq!(a.pop_col(), Some(col![3.0, 4.0]));
+    /// ```
+    #[track_caller]
+    pub fn push_col<ViewE: Conjugate<Canonical = E>>(&mut self, col: impl AsColRef<ViewE>) {
+        self.insert_col(self.ncols(), col)
+    }
+
+    /// Appends the columns of `cols` to the end of the matrix.
+    ///
+    /// If the matrix has no columns, its number of rows is set to the number of rows of `cols`.
+    /// See [`Mat::push_col`] for details on how the capacity is grown.
+    ///
+    /// # Panics
+    /// The function panics if `cols.nrows() != self.nrows()` and `self.ncols() > 0`.
+    #[track_caller]
+    pub fn push_cols<ViewE: Conjugate<Canonical = E>>(&mut self, cols: impl AsMatRef<ViewE>) {
+        let cols = cols.as_mat_ref();
+        let ncols = self.ncols();
+        if ncols == 0 {
+            self.resize_with(cols.nrows(), 0, |_, _| unreachable!());
+        }
+        let nrows = self.nrows();
+        assert!(cols.nrows() == nrows);
+
+        let new_ncols = ncols + cols.ncols();
+        if new_ncols > self.col_capacity() {
+            self.reserve_exact(nrows, Ord::max(new_ncols, 2 * ncols));
+        }
+
+        // the new columns are only written to before being read
+        self.inner.ncols = new_ncols;
+        self.as_mut()
+            .subcols_mut(ncols, cols.ncols())
+            .copy_from(cols);
+    }
+
+    /// Removes the last column of the matrix and returns it, or `None` if the matrix has no
+    /// columns. The capacity of the matrix is left unchanged.
+    #[inline]
+    pub fn pop_col(&mut self) -> Option<Col<E>> {
+        let ncols = self.ncols();
+        if ncols == 0 {
+            None
+        } else {
+            Some(self.remove_col(ncols - 1))
+        }
+    }
+
+    /// Inserts `row` into the matrix at index `row_idx`, shifting the rows after it downwards.
+    ///
+    /// If the matrix has no rows, its number of columns is set to the number of columns of `row`.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `row_idx <= self.nrows()`.
+    /// * `row.ncols() == self.ncols()`, if `self.nrows() > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, row};
+    ///
+    /// let mut a = mat![[1.0, 2.0], [5.0, 6.0f64]];
+    /// a.insert_row(1, row![3.0, 4.0]);
+    ///
+    /// assert_eq!(a, mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// ```
+    #[track_caller]
+    pub fn insert_row<ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        row_idx: usize,
+        row: impl AsRowRef<ViewE>,
+    ) {
+        let row = row.as_row_ref();
+        let nrows = self.nrows();
+        assert!(row_idx <= nrows);
+        if nrows == 0 {
+            self.resize_with(0, row.ncols(), |_, _| unreachable!());
+        }
+        let ncols = self.ncols();
+        assert!(row.ncols() == ncols);
+
+        if nrows == self.row_capacity() {
+            self.reserve_exact(Ord::max(2 * nrows, 4), ncols);
+        }
+
+        let ptr = self.as_ptr_mut();
+        let col_stride = self.col_stride();
+        for j in 0..ncols {
+            // SAFETY: the row capacity is at least `nrows + 1`, so the shifted elements stay in
+            // the same column
+            E::faer_map(E::faer_copy(&ptr), |ptr| unsafe {
+                let src = ptr.wrapping_offset(j as isize * col_stride + row_idx as isize);
+                core::ptr::copy(src, src.add(1), nrows - row_idx)
+            });
+        }
+        self.inner.nrows = nrows + 1;
+
+        for j in 0..ncols {
+            unsafe { self.write_unchecked(row_idx, j, row.read_unchecked(j).canonicalize()) };
+        }
+    }
+
+    /// Removes the row at index `row_idx` from the matrix and returns it, shifting the rows after
+    /// it upwards.
+    ///
+    /// # Panics
+    /// The function panics if `row_idx >= self.nrows()`.
+    #[track_caller]
+    pub fn remove_row(&mut self, row_idx: usize) -> Row<E> {
+        let nrows = self.nrows();
+        assert!(row_idx < nrows);
+        let ncols = self.ncols();
+        let removed = Row::from_fn(ncols, |j| unsafe { self.read_unchecked(row_idx, j) });
+
+        let ptr = self.as_ptr_mut();
+        let col_stride = self.col_stride();
+        for j in 0..ncols {
+            // SAFETY: the shifted elements are within column `j`
+            E::faer_map(E::faer_copy(&ptr), |ptr| unsafe {
+                let dst = ptr.wrapping_offset(j as isize * col_stride + row_idx as isize);
+                core::ptr::copy(dst.add(1), dst, nrows - row_idx - 1)
+            });
+        }
+        self.inner.nrows = nrows - 1;
+
+        removed
+    }
+
+    /// Appends `row` to the end of the matrix.
+    ///
+    /// If the matrix has no rows, its number of columns is set to the number of columns of `row`.
+    /// The row capacity of the matrix is grown geometrically when it is exhausted, so that
+    /// repeatedly pushing rows takes amortized linear time.
+    ///
+    /// # Panics
+    /// The function panics if `row.ncols() != self.ncols()` and `self.nrows() > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{mat, row, Mat};
+    ///
+    /// let mut a = Mat::<f64>::new();
+    /// a.push_row(row![1.0, 2.0]);
+    /// a.push_row(row![3.0, 4.0]);
+    ///
+    /// assert_eq!(a, mat![[1.0, 2.0], [3.0, 4.0]]);
+    /// ```
+    #[track_caller]
+    pub fn push_row<ViewE: Conjugate<Canonical = E>>(&mut self, row: impl AsRowRef<ViewE>) {
+        self.insert_row(self.nrows(), row)
+    }
+
+    /// Truncates the matrix so that its new number of rows is `new_nrows`, keeping the number of
+    /// columns and the capacity unchanged.
+    ///
+    /// # Panics
+    /// The function panics if `new_nrows > self.nrows()`.
+    #[inline]
+    #[track_caller]
+    pub fn truncate_rows(&mut self, new_nrows: usize) {
+        self.truncate(new_nrows, self.ncols())
+    }
+
     /// Returns a reference to a slice over the column at the given index.
     #[inline]
     #[track_caller]
@@ -968,21 +1551,193 @@ impl<E: Entity> Mat<E> {
         implementation(self, other.as_mat_ref());
     }
 
-    /// Fills the elements of `self` with zeros.
-    #[inline(always)]
+    /// Fills the elements of `self` with zeros.
+    #[inline(always)]
+    #[track_caller]
+    pub fn fill_zero(&mut self)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().fill_zero()
+    }
+
+    /// Fills the elements of `self` with copies of `constant`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn fill(&mut self, constant: E) {
+        self.as_mut().fill(constant)
+    }
+
+    /// Copies the values from `slice` into `self`, with the slice being interpreted in
+    /// column-major format. See [`MatMut::copy_from_col_major_slice`].
+    #[track_caller]
+    pub fn copy_from_col_major_slice(&mut self, slice: GroupFor<E, &[E::Unit]>) {
+        self.as_mut().copy_from_col_major_slice(slice)
+    }
+
+    /// Copies the values from `slice` into `self`, with the slice being interpreted in row-major
+    /// format. See [`MatMut::copy_from_row_major_slice`].
+    #[track_caller]
+    pub fn copy_from_row_major_slice(&mut self, slice: GroupFor<E, &[E::Unit]>) {
+        self.as_mut().copy_from_row_major_slice(slice)
+    }
+
+    /// Divides each column of `self` by its norm, as specified by `norm`. See
+    /// [`MatMut::normalize_cols`].
+    #[track_caller]
+    pub fn normalize_cols(&mut self, norm: Norm, zero_policy: ZeroNormPolicy)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().normalize_cols(norm, zero_policy)
+    }
+
+    /// Divides each row of `self` by its norm, as specified by `norm`. See
+    /// [`MatMut::normalize_rows`].
+    #[track_caller]
+    pub fn normalize_rows(&mut self, norm: Norm, zero_policy: ZeroNormPolicy)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().normalize_rows(norm, zero_policy)
+    }
+
+    /// Overwrites the elements of `self` at the positions `(i, j)` for which `mask(i, j)` is
+    /// `true` with copies of `constant`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn fill_where(&mut self, mask: impl FnMut(usize, usize) -> bool, constant: E) {
+        self.as_mut().fill_where(mask, constant)
+    }
+
+    /// Copies the values from `other` into `self`, at the positions `(i, j)` for which
+    /// `mask(i, j)` is `true`. The other elements of `self` are left unchanged.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `self.nrows() == other.nrows()`.
+    /// * `self.ncols() == other.ncols()`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn assign_where<ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        mask: impl FnMut(usize, usize) -> bool,
+        other: impl AsMatRef<ViewE>,
+    ) {
+        self.as_mut().assign_where(mask, other)
+    }
+
+    /// Fills the elements of `self` above the `offset`-th diagonal with zeros. See
+    /// [`MatMut::zero_above_diag`].
+    #[track_caller]
+    pub fn zero_above_diag(&mut self, offset: isize)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().zero_above_diag(offset)
+    }
+
+    /// Fills the elements of `self` below the `offset`-th diagonal with zeros. See
+    /// [`MatMut::zero_below_diag`].
+    #[track_caller]
+    pub fn zero_below_diag(&mut self, offset: isize)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().zero_below_diag(offset)
+    }
+
+    /// Overwrites the strictly upper triangular part of `self` with the adjoint of its strictly
+    /// lower triangular part. See [`MatMut::symmetrize_lower`].
+    #[track_caller]
+    pub fn symmetrize_lower(&mut self)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().symmetrize_lower()
+    }
+
+    /// Overwrites the strictly lower triangular part of `self` with the adjoint of its strictly
+    /// upper triangular part. See [`MatMut::symmetrize_upper`].
+    #[track_caller]
+    pub fn symmetrize_upper(&mut self)
+    where
+        E: ComplexField,
+    {
+        self.as_mut().symmetrize_upper()
+    }
+
+    /// Copies the `k`-th row of `other` into the row of `self` at index `row_indices[k]`. See
+    /// [`MatMut::scatter_rows`].
+    #[inline(always)]
+    #[track_caller]
+    pub fn scatter_rows<I: crate::Index, ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        row_indices: &[I],
+        other: impl AsMatRef<ViewE>,
+    ) {
+        self.as_mut().scatter_rows(row_indices, other)
+    }
+
+    /// Copies the `k`-th column of `other` into the column of `self` at index `col_indices[k]`.
+    /// See [`MatMut::scatter_cols`].
+    #[inline(always)]
+    #[track_caller]
+    pub fn scatter_cols<I: crate::Index, ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        col_indices: &[I],
+        other: impl AsMatRef<ViewE>,
+    ) {
+        self.as_mut().scatter_cols(col_indices, other)
+    }
+
+    /// Permutes the rows of `self` in place, so that the column at index `col_idx` is sorted in
+    /// ascending order. See [`MatMut::sort_rows_by_col`].
+    #[inline]
     #[track_caller]
-    pub fn fill_zero(&mut self)
+    pub fn sort_rows_by_col(&mut self, col_idx: usize)
     where
-        E: ComplexField,
+        E: RealField,
     {
-        self.as_mut().fill_zero()
+        self.as_mut().sort_rows_by_col(col_idx)
     }
 
-    /// Fills the elements of `self` with copies of `constant`.
-    #[inline(always)]
+    /// Permutes the rows of `self` in place, so that the column at index `col_idx` is sorted in
+    /// ascending order. See [`MatMut::sort_rows_by_col_unstable`].
+    #[inline]
     #[track_caller]
-    pub fn fill(&mut self, constant: E) {
-        self.as_mut().fill(constant)
+    pub fn sort_rows_by_col_unstable(&mut self, col_idx: usize)
+    where
+        E: RealField,
+    {
+        self.as_mut().sort_rows_by_col_unstable(col_idx)
+    }
+
+    /// Returns a view over the elements of `self` with dimensions `(nrows, ncols)`, where the
+    /// elements are read in column-major order, or `None` if the memory layout of `self` does not
+    /// allow it without copying. See [`MatRef::try_reshape`].
+    ///
+    /// Since the columns of a matrix may be padded, this only succeeds if `self` has a single row
+    /// or column, or if its column stride is equal to its number of rows.
+    ///
+    /// # Panics
+    /// The function panics if `nrows * ncols != self.nrows() * self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn try_reshape(&self, nrows: usize, ncols: usize) -> Option<MatRef<'_, E>> {
+        self.as_ref().try_reshape(nrows, ncols)
+    }
+
+    /// Returns a mutable view over the elements of `self` with dimensions `(nrows, ncols)`, where
+    /// the elements are read in column-major order, or `None` if the memory layout of `self` does
+    /// not allow it without copying. See [`Mat::try_reshape`].
+    ///
+    /// # Panics
+    /// The function panics if `nrows * ncols != self.nrows() * self.ncols()`.
+    #[track_caller]
+    #[inline]
+    pub fn try_reshape_mut(&mut self, nrows: usize, ncols: usize) -> Option<MatMut<'_, E>> {
+        self.as_mut().try_reshape_mut(nrows, ncols)
     }
 
     /// Returns a view over the transpose of `self`.
@@ -1632,6 +2387,20 @@ impl<E: Entity> Mat<E> {
         self.as_mut().diagonal_mut()
     }
 
+    /// Returns a view over the `offset`-th diagonal of the matrix. See
+    /// [`MatRef::diagonal_offset`].
+    #[inline]
+    pub fn diagonal_offset(&self, offset: isize) -> ColRef<'_, E> {
+        self.as_ref().diagonal_offset(offset)
+    }
+
+    /// Returns a mutable view over the `offset`-th diagonal of the matrix. See
+    /// [`MatRef::diagonal_offset`].
+    #[inline]
+    pub fn diagonal_offset_mut(&mut self, offset: isize) -> ColMut<'_, E> {
+        self.as_mut().diagonal_offset_mut(offset)
+    }
+
     /// Returns an owning [`Mat`] of the data
     #[inline]
     pub fn to_owned(&self) -> Mat<E::Canonical>
@@ -1641,6 +2410,132 @@ impl<E: Entity> Mat<E> {
         self.as_ref().to_owned()
     }
 
+    /// Copies the values of `self` into `slice` in column-major format. See
+    /// [`MatRef::write_to_col_major_slice`].
+    #[track_caller]
+    pub fn write_to_col_major_slice(&self, slice: GroupFor<E, &mut [E::Unit]>) {
+        self.as_ref().write_to_col_major_slice(slice)
+    }
+
+    /// Copies the values of `self` into `slice` in row-major format. See
+    /// [`MatRef::write_to_row_major_slice`].
+    #[track_caller]
+    pub fn write_to_row_major_slice(&self, slice: GroupFor<E, &mut [E::Unit]>) {
+        self.as_ref().write_to_row_major_slice(slice)
+    }
+
+    /// Returns a new matrix whose `k`-th row is the row of `self` at index `row_indices[k]`.
+    /// See [`MatRef::select_rows`].
+    #[track_caller]
+    pub fn select_rows<I: crate::Index>(&self, row_indices: &[I]) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().select_rows(row_indices)
+    }
+
+    /// Returns a new matrix whose `k`-th column is the column of `self` at index
+    /// `col_indices[k]`. See [`MatRef::select_cols`].
+    #[track_caller]
+    pub fn select_cols<I: crate::Index>(&self, col_indices: &[I]) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().select_cols(col_indices)
+    }
+
+    /// Returns a new matrix containing `self` tiled `nrows` times vertically and `ncols` times
+    /// horizontally. See [`MatRef::repeat`].
+    #[doc(alias = "tile")]
+    #[track_caller]
+    pub fn repeat(&self, nrows: usize, ncols: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        self.as_ref().repeat(nrows, ncols)
+    }
+
+    /// Returns a new matrix containing the elements of `self` on and below the `offset`-th
+    /// diagonal, with the other elements set to zero. See [`MatRef::tril`].
+    #[track_caller]
+    pub fn tril(&self, offset: isize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().tril(offset)
+    }
+
+    /// Returns a new matrix containing the elements of `self` on and above the `offset`-th
+    /// diagonal, with the other elements set to zero. See [`MatRef::triu`].
+    #[track_caller]
+    pub fn triu(&self, offset: isize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().triu(offset)
+    }
+
+    /// Returns the hermitian part of `self`, `(A + A^H) / 2`. See [`MatRef::hermitian_part`].
+    #[track_caller]
+    pub fn hermitian_part(&self) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().hermitian_part()
+    }
+
+    /// Returns the skew-hermitian part of `self`, `(A - A^H) / 2`. See [`MatRef::skew_part`].
+    #[track_caller]
+    pub fn skew_part(&self) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().skew_part()
+    }
+
+    /// Copies `self` into a newly allocated sparse matrix in column-major format, dropping the
+    /// entries whose absolute value is less than or equal to `tol`.
+    ///
+    /// The number of retained entries can be queried with
+    /// [`SparseColMat::compute_nnz`](crate::sparse::SparseColMat::compute_nnz). A negative `tol`
+    /// retains all the entries.
+    #[inline]
+    pub fn to_sparse<I: crate::Index>(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Result<crate::sparse::SparseColMat<I, E::Canonical>, crate::sparse::FaerError>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().to_sparse(tol)
+    }
+
+    /// Applies `f` to each element of `self` and collects the results into a new matrix, whose
+    /// scalar type may differ from that of `self`.
+    #[inline]
+    pub fn map<U: Entity>(&self, f: impl FnMut(E) -> U) -> Mat<U> {
+        self.as_ref().map(f)
+    }
+
+    /// Applies `f` to each element of `self` along with its row and column indices, and collects
+    /// the results into a new matrix, whose scalar type may differ from that of `self`.
+    #[inline]
+    pub fn map_with_index<U: Entity>(&self, f: impl FnMut(usize, usize, E) -> U) -> Mat<U> {
+        self.as_ref().map_with_index(f)
+    }
+
+    /// Returns a column containing the elements of `self` at the positions `(i, j)` for which
+    /// `mask(i, j)` is `true`, in column-major order.
+    #[inline]
+    pub fn select_where(&self, mask: impl FnMut(usize, usize) -> bool) -> Col<E> {
+        self.as_ref().select_where(mask)
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -1704,6 +2599,109 @@ impl<E: Entity> Mat<E> {
         crate::linalg::reductions::sum::sum((*self).as_ref())
     }
 
+    /// Returns the trace of `self`, which is the sum of its diagonal elements. See
+    /// [`MatRef::trace`].
+    #[inline]
+    pub fn trace(&self) -> E
+    where
+        E: ComplexField,
+    {
+        self.as_ref().trace()
+    }
+
+    /// Returns the product of the diagonal elements of `self`. See [`MatRef::diag_product`].
+    #[inline]
+    pub fn diag_product(&self) -> E
+    where
+        E: ComplexField,
+    {
+        self.as_ref().diag_product()
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the product of the
+    /// diagonal elements of `self`. See [`MatRef::diag_log_product`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn diag_log_product(&self) -> (E, E)
+    where
+        E: RealField + num_traits::Float,
+    {
+        self.as_ref().diag_log_product()
+    }
+
+    /// Returns a column containing the sum of each row of `self`.
+    #[inline]
+    pub fn row_sum(&self) -> Col<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().row_sum()
+    }
+
+    /// Returns a row containing the sum of each column of `self`.
+    #[inline]
+    pub fn col_sum(&self) -> Row<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().col_sum()
+    }
+
+    /// Returns a column containing the mean of each row of `self`.
+    #[inline]
+    pub fn row_mean(&self) -> Col<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().row_mean()
+    }
+
+    /// Returns a row containing the mean of each column of `self`.
+    #[inline]
+    pub fn col_mean(&self) -> Row<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().col_mean()
+    }
+
+    /// Returns a column containing the maximum of each row of `self`, ignoring NaN values.
+    #[inline]
+    pub fn row_max(&self) -> Col<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().row_max()
+    }
+
+    /// Returns a column containing the minimum of each row of `self`, ignoring NaN values.
+    #[inline]
+    pub fn row_min(&self) -> Col<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().row_min()
+    }
+
+    /// Returns a row containing the maximum of each column of `self`, ignoring NaN values.
+    #[inline]
+    pub fn col_max(&self) -> Row<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().col_max()
+    }
+
+    /// Returns a row containing the minimum of each column of `self`, ignoring NaN values.
+    #[inline]
+    pub fn col_min(&self) -> Row<E>
+    where
+        E: RealField,
+    {
+        self.as_ref().col_min()
+    }
+
     /// Kroneckor product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -1717,6 +2715,86 @@ impl<E: Entity> Mat<E> {
         self.as_2d_ref().kron(rhs)
     }
 
+    /// Returns an iterator over the columns of this matrix.
+    #[inline]
+    pub fn col_iter(
+        &self,
+    ) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = ColRef<'_, E>> {
+        self.as_ref().col_iter()
+    }
+
+    /// Returns an iterator over the mutable columns of this matrix.
+    #[inline]
+    pub fn col_iter_mut(
+        &mut self,
+    ) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = ColMut<'_, E>> {
+        self.as_mut().col_iter_mut()
+    }
+
+    /// Returns an iterator over the rows of this matrix.
+    #[inline]
+    pub fn row_iter(
+        &self,
+    ) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = RowRef<'_, E>> {
+        self.as_ref().row_iter()
+    }
+
+    /// Returns an iterator over the mutable rows of this matrix.
+    #[inline]
+    pub fn row_iter_mut(
+        &mut self,
+    ) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = RowMut<'_, E>> {
+        self.as_mut().row_iter_mut()
+    }
+
+    /// Returns a parallel iterator over the columns of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter(
+        &self,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = ColRef<'_, E>> {
+        self.as_ref().par_col_iter()
+    }
+
+    /// Returns a parallel iterator over the mutable columns of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter_mut(
+        &mut self,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = ColMut<'_, E>> {
+        self.as_mut().par_col_iter_mut()
+    }
+
+    /// Returns a parallel iterator over the rows of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter(
+        &self,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = RowRef<'_, E>> {
+        self.as_ref().par_row_iter()
+    }
+
+    /// Returns a parallel iterator over the mutable rows of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter_mut(
+        &mut self,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = RowMut<'_, E>> {
+        self.as_mut().par_row_iter_mut()
+    }
+
     /// Returns an iterator that provides successive chunks of the columns of a view over this
     /// matrix, with each having at most `chunk_size` columns.
     ///
@@ -1845,6 +2923,65 @@ impl<E: Entity> Mat<E> {
         self.as_mut().par_row_chunks_mut(chunk_size)
     }
 
+    /// Returns an iterator over the non-overlapping tiles of a view over this matrix, with each
+    /// having at most `block_nrows` rows and `block_ncols` columns. See [`MatRef::tiles`].
+    #[inline]
+    #[track_caller]
+    pub fn tiles(
+        &self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl '_ + DoubleEndedIterator<Item = MatRef<'_, E>> {
+        self.as_ref().tiles(block_nrows, block_ncols)
+    }
+
+    /// Returns an iterator over the non-overlapping tiles of a mutable view over this matrix,
+    /// with each having at most `block_nrows` rows and `block_ncols` columns. See
+    /// [`MatRef::tiles`].
+    #[inline]
+    #[track_caller]
+    pub fn tiles_mut(
+        &mut self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl '_ + DoubleEndedIterator<Item = MatMut<'_, E>> {
+        self.as_mut().tiles_mut(block_nrows, block_ncols)
+    }
+
+    /// Returns a parallel iterator over the non-overlapping tiles of a view over this matrix,
+    /// with each having at most `block_nrows` rows and `block_ncols` columns. See
+    /// [`MatRef::par_tiles`].
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    #[track_caller]
+    pub fn par_tiles(
+        &self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = MatRef<'_, E>> {
+        self.as_ref().par_tiles(block_nrows, block_ncols)
+    }
+
+    /// Returns a parallel iterator over the non-overlapping tiles of a mutable view over this
+    /// matrix, with each having at most `block_nrows` rows and `block_ncols` columns. See
+    /// [`MatRef::par_tiles`].
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    #[track_caller]
+    pub fn par_tiles_mut(
+        &mut self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl '_ + rayon::iter::IndexedParallelIterator<Item = MatMut<'_, E>> {
+        self.as_mut().par_tiles_mut(block_nrows, block_ncols)
+    }
+
     #[track_caller]
     #[inline(always)]
     #[doc(hidden)]
@@ -1881,6 +3018,28 @@ impl<E: Entity> Default for Mat<E> {
     }
 }
 
+/// Collects the columns of a matrix.
+///
+/// # Panics
+/// Panics if the columns don't all have the same number of rows.
+impl<E: Entity> FromIterator<Col<E>> for Mat<E> {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = Col<E>>>(iter: I) -> Self {
+        Self::from_cols(iter)
+    }
+}
+
+/// Collects the rows of a matrix.
+///
+/// # Panics
+/// Panics if the rows don't all have the same number of columns.
+impl<E: Entity> FromIterator<Row<E>> for Mat<E> {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = Row<E>>>(iter: I) -> Self {
+        Self::from_rows(iter)
+    }
+}
+
 impl<E: Entity> Clone for Mat<E> {
     fn clone(&self) -> Self {
         let this = self.as_ref();
@@ -1926,6 +3085,12 @@ impl<E: Entity> core::fmt::Debug for Mat<E> {
     }
 }
 
+impl<E: Entity + core::fmt::Display> core::fmt::Display for Mat<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.as_ref(), f)
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<(usize, usize)> for Mat<E> {
     type Output = E;
 
@@ -833,6 +833,97 @@ impl<'a, E: Entity> MatRef<'a, E> {
         unsafe { self.col_unchecked(col_idx) }
     }
 
+    /// Returns a view over the same elements with dimensions `(nrows, ncols)`, where the elements
+    /// are read in column-major order, or `None` if the memory layout of `self` does not allow
+    /// it without copying.
+    ///
+    /// This is possible if `self` has a single row or column, or if its column stride is equal to
+    /// `self.nrows() * self.row_stride()`, which is the case for contiguous column-major views.
+    ///
+    /// # Panics
+    /// The function panics if `nrows * ncols != self.nrows() * self.ncols()`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let data = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+    /// let view = faer::mat::from_column_major_slice::<f64>(&data, 2, 3);
+    /// assert_eq!(view, mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///
+    /// let reshaped = view.try_reshape(3, 2).unwrap();
+    /// assert_eq!(reshaped, mat![[1.0, 5.0], [4.0, 3.0], [2.0, 6.0]]);
+    ///
+    /// // the elements of the transpose are not evenly spaced in column-major order
+    /// assert!(view.transpose().try_reshape(2, 3).is_none());
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn try_reshape(self, nrows: usize, ncols: usize) -> Option<Self> {
+        let (m, n) = (self.nrows(), self.ncols());
+        assert!(usize::checked_mul(nrows, ncols) == usize::checked_mul(m, n));
+
+        let row_stride = self.row_stride();
+        let col_stride = self.col_stride();
+        let stride = if m * n == 0 {
+            1
+        } else if n == 1 {
+            row_stride
+        } else if m == 1 {
+            col_stride
+        } else if (m as isize).checked_mul(row_stride) == Some(col_stride) {
+            row_stride
+        } else {
+            return None;
+        };
+
+        Some(unsafe {
+            Self::__from_raw_parts(
+                self.as_ptr(),
+                nrows,
+                ncols,
+                stride,
+                stride.wrapping_mul(nrows as isize),
+            )
+        })
+    }
+
+    /// Returns a view over the same elements with dimensions `(nrows, ncols)`, where the elements
+    /// are read in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if `nrows * ncols != self.nrows() * self.ncols()`, or if the memory
+    /// layout of `self` does not allow reshaping without copying. See [`Self::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn reshape(self, nrows: usize, ncols: usize) -> Self {
+        let reshaped = self.try_reshape(nrows, ncols);
+        assert!(reshaped.is_some());
+        reshaped.unwrap()
+    }
+
+    /// Returns a view over the elements of `self` as a column vector, in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if the memory layout of `self` does not allow it without copying. See
+    /// [`Self::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn into_col(self) -> ColRef<'a, E> {
+        self.reshape(self.nrows() * self.ncols(), 1).col(0)
+    }
+
+    /// Returns a view over the elements of `self` as a row vector, in column-major order.
+    ///
+    /// # Panics
+    /// The function panics if the memory layout of `self` does not allow it without copying. See
+    /// [`Self::try_reshape`].
+    #[track_caller]
+    #[inline]
+    pub fn into_row(self) -> RowRef<'a, E> {
+        self.reshape(1, self.nrows() * self.ncols()).row(0)
+    }
+
     /// Given a matrix with a single column, returns an object that interprets
     /// the column as a diagonal matrix, whose diagonal elements are values in the column.
     #[track_caller]
@@ -855,6 +946,43 @@ impl<'a, E: Entity> MatRef<'a, E> {
         }
     }
 
+    /// Returns a view over the `offset`-th diagonal of the matrix, which contains the elements
+    /// at the positions `(i, j)` such that `j - i == offset`.
+    ///
+    /// An offset of zero refers to the main diagonal, a positive offset to a diagonal above it,
+    /// and a negative offset to a diagonal below it. If the diagonal lies outside the matrix, the
+    /// returned view is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat};
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0f64]];
+    ///
+    /// assert_eq!(a.as_ref().diagonal_offset(1), col![2.0, 6.0]);
+    /// assert_eq!(a.as_ref().diagonal_offset(-1), col![4.0]);
+    /// assert_eq!(a.as_ref().diagonal_offset(3).nrows(), 0);
+    /// ```
+    #[inline]
+    pub fn diagonal_offset(self, offset: isize) -> ColRef<'a, E> {
+        let (row, col) = if offset >= 0 {
+            (0, offset.unsigned_abs())
+        } else {
+            (offset.unsigned_abs(), 0)
+        };
+        let size = Ord::min(
+            self.nrows().saturating_sub(row),
+            self.ncols().saturating_sub(col),
+        );
+        let ptr = if size == 0 {
+            self.as_ptr()
+        } else {
+            self.ptr_at(row, col)
+        };
+        let stride = self.row_stride().wrapping_add(self.col_stride());
+        unsafe { crate::col::from_raw_parts(ptr, size, stride) }
+    }
+
     /// Returns an owning [`Mat`] of the data.
     #[inline]
     pub fn to_owned(&self) -> Mat<E::Canonical>
@@ -871,6 +999,320 @@ impl<'a, E: Entity> MatRef<'a, E> {
         mat
     }
 
+    /// Copies the values of `self` into `slice` in column-major format, so that the first
+    /// `nrows` values of the slice are filled with the first column of the matrix, the next
+    /// `nrows` values with the second column, and so on.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `self.nrows() * self.ncols() == slice.len()`
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0f64]];
+    /// let mut buf = [0.0; 6];
+    /// a.as_ref().write_to_col_major_slice(&mut buf);
+    ///
+    /// assert_eq!(buf, [1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+    /// ```
+    #[track_caller]
+    pub fn write_to_col_major_slice(&self, slice: GroupFor<E, &mut [E::Unit]>) {
+        let dst = from_column_major_slice_mut::<E>(slice, self.nrows(), self.ncols());
+        zipped!(dst, *self).for_each(
+            #[inline(always)]
+            |unzipped!(mut dst, src)| dst.write(src.read()),
+        );
+    }
+
+    /// Copies the values of `self` into `slice` in row-major format, so that the first `ncols`
+    /// values of the slice are filled with the first row of the matrix, the next `ncols` values
+    /// with the second row, and so on.
+    ///
+    /// # Panics
+    /// The function panics if any of the following conditions are violated:
+    /// * `self.nrows() * self.ncols() == slice.len()`
+    #[track_caller]
+    pub fn write_to_row_major_slice(&self, slice: GroupFor<E, &mut [E::Unit]>) {
+        self.transpose().write_to_col_major_slice(slice)
+    }
+
+    /// Returns a new matrix whose `k`-th row is the row of `self` at index `row_indices[k]`.
+    ///
+    /// The indices may be repeated and given in any order.
+    ///
+    /// # Panics
+    /// The function panics if any of the indices is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0f64]];
+    /// let b = a.as_ref().select_rows(&[2usize, 0, 2]);
+    ///
+    /// assert_eq!(b, mat![[5.0, 6.0], [1.0, 2.0], [5.0, 6.0]]);
+    /// ```
+    #[track_caller]
+    pub fn select_rows<I: crate::Index>(&self, row_indices: &[I]) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let nrows = self.nrows();
+        for &i in row_indices {
+            assert!(i.zx() < nrows);
+        }
+        Mat::from_fn(row_indices.len(), self.ncols(), |i, j| unsafe {
+            self.read_unchecked(row_indices[i].zx(), j).canonicalize()
+        })
+    }
+
+    /// Returns a new matrix whose `k`-th column is the column of `self` at index
+    /// `col_indices[k]`.
+    ///
+    /// The indices may be repeated and given in any order.
+    ///
+    /// # Panics
+    /// The function panics if any of the indices is out of bounds.
+    #[track_caller]
+    pub fn select_cols<I: crate::Index>(&self, col_indices: &[I]) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let ncols = self.ncols();
+        for &j in col_indices {
+            assert!(j.zx() < ncols);
+        }
+        Mat::from_fn(self.nrows(), col_indices.len(), |i, j| unsafe {
+            self.read_unchecked(i, col_indices[j].zx()).canonicalize()
+        })
+    }
+
+    /// Returns a new matrix containing `self` tiled `nrows` times vertically and `ncols` times
+    /// horizontally.
+    ///
+    /// A column or row can be repeated without copying the data using [`ColRef::repeat_cols`]
+    /// and [`RowRef::repeat_rows`].
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+    /// let b = a.as_ref().repeat(2, 3);
+    ///
+    /// assert_eq!(
+    ///     b,
+    ///     mat![
+    ///         [1.0, 2.0, 1.0, 2.0, 1.0, 2.0],
+    ///         [3.0, 4.0, 3.0, 4.0, 3.0, 4.0],
+    ///         [1.0, 2.0, 1.0, 2.0, 1.0, 2.0],
+    ///         [3.0, 4.0, 3.0, 4.0, 3.0, 4.0],
+    ///     ]
+    /// );
+    /// ```
+    #[doc(alias = "tile")]
+    #[track_caller]
+    pub fn repeat(&self, nrows: usize, ncols: usize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+    {
+        let m = self.nrows();
+        let n = self.ncols();
+        Mat::from_fn(
+            m.checked_mul(nrows).unwrap(),
+            n.checked_mul(ncols).unwrap(),
+            |i, j| unsafe { self.read_unchecked(i % m, j % n).canonicalize() },
+        )
+    }
+
+    /// Returns a new matrix containing the elements of `self` on and below the `offset`-th
+    /// diagonal, that is the elements at the positions `(i, j)` such that `j - i <= offset`. The
+    /// other elements are set to zero.
+    ///
+    /// An offset of zero refers to the main diagonal, a positive offset to a diagonal above it,
+    /// and a negative offset to a diagonal below it.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0f64]];
+    ///
+    /// assert_eq!(
+    ///     a.as_ref().tril(0),
+    ///     mat![[1.0, 0.0, 0.0], [4.0, 5.0, 0.0], [7.0, 8.0, 9.0]],
+    /// );
+    /// assert_eq!(
+    ///     a.as_ref().tril(-1),
+    ///     mat![[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [7.0, 8.0, 0.0]],
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn tril(&self, offset: isize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        let mut mat = self.to_owned();
+        mat.zero_above_diag(offset);
+        mat
+    }
+
+    /// Returns a new matrix containing the elements of `self` on and above the `offset`-th
+    /// diagonal, that is the elements at the positions `(i, j)` such that `j - i >= offset`. The
+    /// other elements are set to zero.
+    ///
+    /// An offset of zero refers to the main diagonal, a positive offset to a diagonal above it,
+    /// and a negative offset to a diagonal below it.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0f64]];
+    ///
+    /// assert_eq!(
+    ///     a.as_ref().triu(1),
+    ///     mat![[0.0, 2.0, 3.0], [0.0, 0.0, 6.0], [0.0, 0.0, 0.0]],
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn triu(&self, offset: isize) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        let mut mat = self.to_owned();
+        mat.zero_below_diag(offset);
+        mat
+    }
+
+    /// Returns the hermitian part of `self`, `(A + A^H) / 2`.
+    ///
+    /// # Panics
+    /// The function panics if `self` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[1.0, 2.0], [4.0, 3.0f64]];
+    ///
+    /// assert_eq!(a.as_ref().hermitian_part(), mat![[1.0, 3.0], [3.0, 3.0]]);
+    /// assert_eq!(a.as_ref().skew_part(), mat![[0.0, -1.0], [1.0, 0.0]]);
+    /// ```
+    #[track_caller]
+    pub fn hermitian_part(&self) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        assert!(self.nrows() == self.ncols());
+        let half = <E::Canonical as ComplexField>::Real::faer_from_f64(0.5);
+        Mat::from_fn(self.nrows(), self.ncols(), |i, j| unsafe {
+            let x = self.read_unchecked(i, j).canonicalize();
+            let y = self.read_unchecked(j, i).canonicalize().faer_conj();
+            x.faer_add(y).faer_scale_real(half)
+        })
+    }
+
+    /// Returns the skew-hermitian part of `self`, `(A - A^H) / 2`.
+    ///
+    /// # Panics
+    /// The function panics if `self` is not square.
+    #[track_caller]
+    pub fn skew_part(&self) -> Mat<E::Canonical>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        assert!(self.nrows() == self.ncols());
+        let half = <E::Canonical as ComplexField>::Real::faer_from_f64(0.5);
+        Mat::from_fn(self.nrows(), self.ncols(), |i, j| unsafe {
+            let x = self.read_unchecked(i, j).canonicalize();
+            let y = self.read_unchecked(j, i).canonicalize().faer_conj();
+            x.faer_sub(y).faer_scale_real(half)
+        })
+    }
+
+    /// Copies `self` into a newly allocated sparse matrix in column-major format, dropping the
+    /// entries whose absolute value is less than or equal to `tol`.
+    ///
+    /// The number of retained entries can be queried with
+    /// [`SparseColMat::compute_nnz`](crate::sparse::SparseColMat::compute_nnz). A negative `tol`
+    /// retains all the entries.
+    #[inline]
+    pub fn to_sparse<I: crate::Index>(
+        &self,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Result<crate::sparse::SparseColMat<I, E::Canonical>, crate::sparse::FaerError>
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        crate::sparse::dense_to_sparse(*self, tol)
+    }
+
+    /// Applies `f` to each element of `self` and collects the results into a new matrix, whose
+    /// scalar type may differ from that of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{complex_native::c64, mat};
+    ///
+    /// let a = mat![[1.0, -2.0], [-3.0, 4.0f64]];
+    /// let b = a.as_ref().map(|x| c64::new(x.abs(), x));
+    ///
+    /// assert_eq!(b.read(0, 1), c64::new(2.0, -2.0));
+    /// assert_eq!(b.read(1, 0), c64::new(3.0, -3.0));
+    /// ```
+    #[inline]
+    pub fn map<U: Entity>(&self, f: impl FnMut(E) -> U) -> Mat<U> {
+        let mut f = f;
+        zipped!((*self).rb()).map(|unzipped!(x)| f(x.read()))
+    }
+
+    /// Applies `f` to each element of `self` along with its row and column indices, and collects
+    /// the results into a new matrix, whose scalar type may differ from that of `self`.
+    #[inline]
+    pub fn map_with_index<U: Entity>(&self, f: impl FnMut(usize, usize, E) -> U) -> Mat<U> {
+        let mut f = f;
+        zipped!((*self).rb()).map_with_index(|i, j, unzipped!(x)| f(i, j, x.read()))
+    }
+
+    /// Returns a column containing the elements of `self` at the positions `(i, j)` for which
+    /// `mask(i, j)` is `true`, in column-major order.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat};
+    ///
+    /// let a = mat![[1.0, -2.0], [-3.0, 4.0f64]];
+    /// let negative = a.as_ref().select_where(|i, j| a.read(i, j) < 0.0);
+    ///
+    /// assert_eq!(negative, col![-3.0, -2.0]);
+    /// ```
+    #[inline]
+    pub fn select_where(&self, mask: impl FnMut(usize, usize) -> bool) -> Col<E> {
+        let mut mask = mask;
+        let mut out = Col::<E>::new();
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                if mask(i, j) {
+                    let len = out.nrows();
+                    if len == out.row_capacity() {
+                        out.reserve_exact(Ord::max(2 * len, 4));
+                    }
+                    let value = self.read(i, j);
+                    out.resize_with(len + 1, |_| value);
+                }
+            }
+        }
+        out
+    }
+
     /// Returns `true` if any of the elements is NaN, otherwise returns `false`.
     #[inline]
     pub fn has_nan(&self) -> bool
@@ -943,6 +1385,243 @@ impl<'a, E: Entity> MatRef<'a, E> {
         crate::linalg::reductions::sum::sum((*self).rb())
     }
 
+    /// Returns the trace of `self`, which is the sum of its diagonal elements.
+    ///
+    /// If `self` is not square, the sum is taken over the elements `(i, i)` for
+    /// `i < min(nrows, ncols)`.
+    #[inline]
+    pub fn trace(&self) -> E
+    where
+        E: ComplexField,
+    {
+        (*self).rb().diagonal().sum()
+    }
+
+    /// Returns the product of the diagonal elements of `self`.
+    ///
+    /// If `self` is not square, the product is taken over the elements `(i, i)` for
+    /// `i < min(nrows, ncols)`.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::mat;
+    ///
+    /// let a = mat![[2.0, 1.0, 0.0], [0.0, -3.0, 5.0], [1.0, 0.0, 4.0f64]];
+    ///
+    /// assert_eq!(a.as_ref().trace(), 3.0);
+    /// assert_eq!(a.as_ref().diag_product(), -24.0);
+    ///
+    /// let (sign, log) = a.as_ref().diag_log_product();
+    /// assert_eq!(sign, -1.0);
+    /// assert!((log - 24.0f64.ln()).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn diag_product(&self) -> E
+    where
+        E: ComplexField,
+    {
+        (*self).rb().diagonal().prod()
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the product of the
+    /// diagonal elements of `self`, which doesn't overflow or underflow for large matrices.
+    ///
+    /// The sign is `1`, `-1`, or `0` if any of the diagonal elements is zero, in which case the
+    /// logarithm is `-inf`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn diag_log_product(&self) -> (E, E)
+    where
+        E: RealField + num_traits::Float,
+    {
+        let diag = self.diagonal().column_vector();
+        let mut sign = E::faer_one();
+        let mut log = E::faer_zero();
+        for i in 0..diag.nrows() {
+            let d = diag.read(i);
+            if d < E::faer_zero() {
+                sign = sign.faer_neg();
+            } else if d == E::faer_zero() {
+                sign = E::faer_zero();
+            }
+            log = log.faer_add(num_traits::Float::ln(num_traits::Float::abs(d)));
+        }
+        (sign, log)
+    }
+
+    /// Returns a column containing the sum of each row of `self`.
+    #[inline]
+    pub fn row_sum(&self) -> Col<E>
+    where
+        E: ComplexField,
+    {
+        crate::linalg::reductions::axis::row_sum((*self).rb(), crate::get_global_parallelism())
+    }
+
+    /// Returns a row containing the sum of each column of `self`.
+    #[inline]
+    pub fn col_sum(&self) -> Row<E>
+    where
+        E: ComplexField,
+    {
+        crate::linalg::reductions::axis::col_sum((*self).rb(), crate::get_global_parallelism())
+    }
+
+    /// Returns a column containing the mean of each row of `self`.
+    #[inline]
+    pub fn row_mean(&self) -> Col<E>
+    where
+        E: ComplexField,
+    {
+        crate::linalg::reductions::axis::row_mean((*self).rb(), crate::get_global_parallelism())
+    }
+
+    /// Returns a row containing the mean of each column of `self`.
+    #[inline]
+    pub fn col_mean(&self) -> Row<E>
+    where
+        E: ComplexField,
+    {
+        crate::linalg::reductions::axis::col_mean((*self).rb(), crate::get_global_parallelism())
+    }
+
+    /// Returns a column containing the maximum of each row of `self`, ignoring NaN values.
+    #[inline]
+    pub fn row_max(&self) -> Col<E>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::axis::row_max((*self).rb(), crate::get_global_parallelism())
+    }
+
+    /// Returns a column containing the minimum of each row of `self`, ignoring NaN values.
+    #[inline]
+    pub fn row_min(&self) -> Col<E>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::axis::row_min((*self).rb(), crate::get_global_parallelism())
+    }
+
+    /// Returns a row containing the maximum of each column of `self`, ignoring NaN values.
+    #[inline]
+    pub fn col_max(&self) -> Row<E>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::axis::col_max((*self).rb(), crate::get_global_parallelism())
+    }
+
+    /// Returns a row containing the minimum of each column of `self`, ignoring NaN values.
+    #[inline]
+    pub fn col_min(&self) -> Row<E>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::axis::col_min((*self).rb(), crate::get_global_parallelism())
+    }
+
+    /// Folds each row of `self` using `f`, starting from `init`, and returns a column containing
+    /// the result for each row.
+    ///
+    /// The elements of each row are visited in increasing column order.
+    #[inline]
+    pub fn fold_rows<T: Entity>(&self, init: T, f: impl Sync + Fn(T, E) -> T) -> Col<T> {
+        crate::linalg::reductions::axis::fold_rows(
+            (*self).rb(),
+            init,
+            f,
+            crate::get_global_parallelism(),
+        )
+    }
+
+    /// Folds each column of `self` using `f`, starting from `init`, and returns a row containing
+    /// the result for each column.
+    ///
+    /// The elements of each column are visited in increasing row order.
+    #[inline]
+    pub fn fold_cols<T: Entity>(&self, init: T, f: impl Sync + Fn(T, E) -> T) -> Row<T> {
+        crate::linalg::reductions::axis::fold_cols(
+            (*self).rb(),
+            init,
+            f,
+            crate::get_global_parallelism(),
+        )
+    }
+
+    /// Returns the maximum of `self` along with its row and column indices, or `None` if `self` is
+    /// empty.
+    ///
+    /// NaN values are ignored, unless all the elements are NaN. Ties are resolved in favor of the
+    /// first element in column-major order.
+    #[inline]
+    pub fn argmax(&self) -> Option<(E, (usize, usize))>
+    where
+        E: RealField,
+    {
+        self.argmax_with_policy(crate::NanPolicy::Ignore)
+    }
+
+    /// Returns the maximum of `self` along with its row and column indices, or `None` if `self` is
+    /// empty, handling NaN values according to `nan_policy`.
+    #[inline]
+    pub fn argmax_with_policy(&self, nan_policy: crate::NanPolicy) -> Option<(E, (usize, usize))>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::extrema::argmax((*self).rb(), nan_policy)
+            .map(|(value, i, j)| (value, (i, j)))
+    }
+
+    /// Returns the minimum of `self` along with its row and column indices, or `None` if `self` is
+    /// empty.
+    ///
+    /// NaN values are ignored, unless all the elements are NaN. Ties are resolved in favor of the
+    /// first element in column-major order.
+    #[inline]
+    pub fn argmin(&self) -> Option<(E, (usize, usize))>
+    where
+        E: RealField,
+    {
+        self.argmin_with_policy(crate::NanPolicy::Ignore)
+    }
+
+    /// Returns the minimum of `self` along with its row and column indices, or `None` if `self` is
+    /// empty, handling NaN values according to `nan_policy`.
+    #[inline]
+    pub fn argmin_with_policy(&self, nan_policy: crate::NanPolicy) -> Option<(E, (usize, usize))>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::extrema::argmin((*self).rb(), nan_policy)
+            .map(|(value, i, j)| (value, (i, j)))
+    }
+
+    /// Returns the maximum of `self` with respect to `compare`, along with its row and column
+    /// indices, or `None` if `self` is empty. Ties are resolved in favor of the first element in
+    /// column-major order.
+    #[inline]
+    pub fn max_by(
+        &self,
+        compare: impl FnMut(&E, &E) -> core::cmp::Ordering,
+    ) -> Option<(E, (usize, usize))> {
+        crate::linalg::reductions::extrema::max_by((*self).rb(), compare)
+            .map(|(value, i, j)| (value, (i, j)))
+    }
+
+    /// Returns the minimum of `self` with respect to `compare`, along with its row and column
+    /// indices, or `None` if `self` is empty. Ties are resolved in favor of the first element in
+    /// column-major order.
+    #[inline]
+    pub fn min_by(
+        &self,
+        compare: impl FnMut(&E, &E) -> core::cmp::Ordering,
+    ) -> Option<(E, (usize, usize))> {
+        crate::linalg::reductions::extrema::min_by((*self).rb(), compare)
+            .map(|(value, i, j)| (value, (i, j)))
+    }
+
     /// Kroneckor product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -980,6 +1659,64 @@ impl<'a, E: Entity> MatRef<'a, E> {
         }
     }
 
+    /// Returns an iterator over the columns of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::{col, mat};
+    ///
+    /// let a = mat![[1.0, 2.0], [3.0, 4.0f64]];
+    ///
+    /// let mut cols = a.as_ref().col_iter();
+    /// assert_eq!(cols.next().unwrap(), col![1.0, 3.0]);
+    /// assert_eq!(cols.next().unwrap(), col![2.0, 4.0]);
+    /// assert!(cols.next().is_none());
+    /// ```
+    #[inline]
+    pub fn col_iter(
+        self,
+    ) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = ColRef<'a, E>> {
+        (0..self.ncols()).map(move |j| unsafe { self.col_unchecked(j) })
+    }
+
+    /// Returns an iterator over the rows of this matrix.
+    #[inline]
+    pub fn row_iter(
+        self,
+    ) -> impl 'a + ExactSizeIterator + DoubleEndedIterator<Item = RowRef<'a, E>> {
+        (0..self.nrows()).map(move |i| unsafe { self.row_unchecked(i) })
+    }
+
+    /// Returns a parallel iterator over the columns of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_col_iter(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = ColRef<'a, E>> {
+        use rayon::prelude::*;
+        (0..self.ncols())
+            .into_par_iter()
+            .map(move |j| unsafe { self.col_unchecked(j) })
+    }
+
+    /// Returns a parallel iterator over the rows of this matrix.
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    pub fn par_row_iter(
+        self,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = RowRef<'a, E>> {
+        use rayon::prelude::*;
+        (0..self.nrows())
+            .into_par_iter()
+            .map(move |i| unsafe { self.row_unchecked(i) })
+    }
+
     /// Returns an iterator that provides successive chunks of the columns of this matrix, with
     /// each having at most `chunk_size` columns.
     ///
@@ -1061,6 +1798,91 @@ impl<'a, E: Entity> MatRef<'a, E> {
             .par_col_chunks(chunk_size)
             .map(|chunk| chunk.transpose())
     }
+
+    /// Returns an iterator over the non-overlapping tiles of this matrix, with each having at most
+    /// `block_nrows` rows and `block_ncols` columns.
+    ///
+    /// The tiles are yielded in column-major order, so that the tile at block index `(i, j)` is
+    /// the `(i + j * tile_nrows)`-th one, where `tile_nrows` is the number of tiles in each column
+    /// of tiles. Tiles on the last row or column of tiles may be smaller than the requested size.
+    ///
+    /// # Example
+    /// ```
+    /// use faer::Mat;
+    ///
+    /// let a = Mat::from_fn(5, 4, |i, j| (i + j) as f64);
+    ///
+    /// let mut sum = 0.0;
+    /// for tile in a.as_ref().tiles(2, 3) {
+    ///     assert!(tile.nrows() <= 2 && tile.ncols() <= 3);
+    ///     sum += tile.sum();
+    /// }
+    /// assert_eq!(sum, a.sum());
+    /// assert_eq!(a.as_ref().tiles(2, 3).count(), 6);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn tiles(
+        self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl 'a + DoubleEndedIterator<Item = MatRef<'a, E>> {
+        assert!(all(block_nrows > 0, block_ncols > 0));
+        let tile_nrows = self.nrows().msrv_div_ceil(block_nrows);
+        let tile_ncols = self.ncols().msrv_div_ceil(block_ncols);
+        (0..tile_nrows * tile_ncols).map(move |tile_idx| {
+            self.tile(
+                block_nrows,
+                block_ncols,
+                tile_idx % tile_nrows,
+                tile_idx / tile_nrows,
+            )
+        })
+    }
+
+    /// Returns a parallel iterator over the non-overlapping tiles of this matrix, with each
+    /// having at most `block_nrows` rows and `block_ncols` columns.
+    ///
+    /// The tiles are yielded in the same order as [`MatRef::tiles`].
+    ///
+    /// Only available with the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    #[track_caller]
+    pub fn par_tiles(
+        self,
+        block_nrows: usize,
+        block_ncols: usize,
+    ) -> impl 'a + rayon::iter::IndexedParallelIterator<Item = MatRef<'a, E>> {
+        use rayon::prelude::*;
+
+        assert!(all(block_nrows > 0, block_ncols > 0));
+        let tile_nrows = self.nrows().msrv_div_ceil(block_nrows);
+        let tile_ncols = self.ncols().msrv_div_ceil(block_ncols);
+        (0..tile_nrows * tile_ncols)
+            .into_par_iter()
+            .map(move |tile_idx| {
+                self.tile(
+                    block_nrows,
+                    block_ncols,
+                    tile_idx % tile_nrows,
+                    tile_idx / tile_nrows,
+                )
+            })
+    }
+
+    #[inline]
+    fn tile(self, block_nrows: usize, block_ncols: usize, i: usize, j: usize) -> MatRef<'a, E> {
+        let row_start = i * block_nrows;
+        let col_start = j * block_ncols;
+        self.submatrix(
+            row_start,
+            col_start,
+            Ord::min(block_nrows, self.nrows() - row_start),
+            Ord::min(block_ncols, self.ncols() - col_start),
+        )
+    }
 }
 
 impl<'a, E: RealField> MatRef<'a, num_complex::Complex<E>> {
@@ -1294,6 +2116,14 @@ impl<'a, E: Entity> core::fmt::Debug for MatRef<'a, E> {
     }
 }
 
+/// Prints the dimensions and scalar type, followed by the elements. Large matrices are summarized
+/// by printing only their first and last rows and columns.
+impl<E: Entity + core::fmt::Display> core::fmt::Display for MatRef<'_, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::utils::display::fmt_summary(f, "Mat", &[self.nrows(), self.ncols()], *self)
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<(usize, usize)> for MatRef<'_, E> {
     type Output = E;
 
@@ -1,4 +1,4 @@
-use crate::{assert, col::*, row::*, utils::slice::*, Conj};
+use crate::{assert, col::*, row::*, utils::slice::*, Conj, Norm, ZeroNormPolicy};
 use coe::Coerce;
 use core::{marker::PhantomData, ptr::NonNull};
 use faer_entity::*;
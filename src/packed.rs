@@ -0,0 +1,342 @@
+use crate::{
+    assert,
+    col::{Col, ColRef},
+    linalg::cholesky::llt::CholeskyError,
+    mat::{Mat, MatMut, MatRef},
+    ComplexField, Conjugate, Entity, RealField, Side,
+};
+use reborrow::*;
+
+#[inline(always)]
+fn packed_len(n: usize) -> usize {
+    n * (n + 1) / 2
+}
+
+// offset of the entry at `(i, j)` with `i >= j` in the column-major packed lower triangle
+#[inline(always)]
+fn packed_idx(n: usize, i: usize, j: usize) -> usize {
+    debug_assert!(i >= j && i < n);
+    j * n - j * (j + 1) / 2 + i
+}
+
+/// Hermitian matrix stored in packed format. Only the lower triangular half is stored, column by
+/// column, which requires `n * (n + 1) / 2` elements instead of `n * n`.
+#[derive(Clone, Debug)]
+pub struct PackedHermitian<E: Entity> {
+    dim: usize,
+    data: Col<E>,
+}
+
+/// Cholesky factor of a Hermitian positive definite matrix, stored in packed format.
+#[derive(Clone, Debug)]
+pub struct PackedCholesky<E: Entity> {
+    dim: usize,
+    factor: Col<E>,
+}
+
+impl<E: Entity> PackedHermitian<E> {
+    /// Creates a packed Hermitian matrix of dimension `dim` from the column-major packed lower
+    /// triangle `data`.
+    ///
+    /// # Panics
+    /// Panics if `data.nrows() != dim * (dim + 1) / 2`.
+    #[track_caller]
+    pub fn from_packed(dim: usize, data: Col<E>) -> Self {
+        assert!(data.nrows() == packed_len(dim));
+        Self { dim, data }
+    }
+
+    /// Returns the dimension of the matrix.
+    #[inline(always)]
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns a view over the column-major packed lower triangle.
+    #[inline(always)]
+    pub fn packed_data(&self) -> ColRef<'_, E> {
+        self.data.as_ref()
+    }
+
+    /// Returns the column-major packed lower triangle.
+    #[inline(always)]
+    pub fn into_packed(self) -> Col<E> {
+        self.data
+    }
+}
+
+impl<E: ComplexField> PackedHermitian<E> {
+    /// Returns a new packed Hermitian matrix of dimension `dim`, filled with zeros.
+    pub fn zeros(dim: usize) -> Self {
+        Self {
+            dim,
+            data: Col::zeros(packed_len(dim)),
+        }
+    }
+
+    /// Returns the packed representation of `matrix`.
+    ///
+    /// The matrix is interpreted as Hermitian, but only the provided side is accessed.
+    ///
+    /// # Panics
+    /// Panics if `matrix` is not square.
+    #[track_caller]
+    pub fn from_mat<ViewE: Conjugate<Canonical = E>>(
+        matrix: MatRef<'_, ViewE>,
+        side: Side,
+    ) -> Self {
+        assert!(matrix.nrows() == matrix.ncols());
+        let n = matrix.nrows();
+        let mut data = Col::<E>::zeros(packed_len(n));
+        for j in 0..n {
+            for i in j..n {
+                let val = match side {
+                    Side::Lower => matrix.read(i, j).canonicalize(),
+                    Side::Upper => matrix.read(j, i).canonicalize().faer_conj(),
+                };
+                data.write(packed_idx(n, i, j), val);
+            }
+        }
+        Self { dim: n, data }
+    }
+
+    /// Returns the full Hermitian matrix as a dense matrix.
+    pub fn to_mat(&self) -> Mat<E> {
+        let n = self.dim;
+        Mat::from_fn(n, n, |i, j| self.read(i, j))
+    }
+
+    /// Reads the value at the given indices.
+    ///
+    /// # Panics
+    /// Panics if `row >= self.dim()` or `col >= self.dim()`.
+    #[track_caller]
+    pub fn read(&self, row: usize, col: usize) -> E {
+        let n = self.dim;
+        assert!(all(row < n, col < n));
+        if row >= col {
+            self.data.read(packed_idx(n, row, col))
+        } else {
+            self.data.read(packed_idx(n, col, row)).faer_conj()
+        }
+    }
+
+    /// Writes the value to the given indices. The mirrored entry is implicitly set to the
+    /// conjugate of `value`.
+    ///
+    /// # Panics
+    /// Panics if `row >= self.dim()` or `col >= self.dim()`.
+    #[track_caller]
+    pub fn write(&mut self, row: usize, col: usize, value: E) {
+        let n = self.dim;
+        assert!(all(row < n, col < n));
+        if row >= col {
+            self.data.write(packed_idx(n, row, col), value)
+        } else {
+            self.data.write(packed_idx(n, col, row), value.faer_conj())
+        }
+    }
+
+    /// Performs the rank-k update `self += alpha * x * x^H`.
+    ///
+    /// # Panics
+    /// Panics if `x.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn rank_k_update<ViewE: Conjugate<Canonical = E>>(
+        &mut self,
+        alpha: E::Real,
+        x: MatRef<'_, ViewE>,
+    ) {
+        let n = self.dim;
+        assert!(x.nrows() == n);
+        for k in 0..x.ncols() {
+            let x = x.col(k);
+            for j in 0..n {
+                let x_j = x.read(j).canonicalize().faer_conj().faer_scale_real(alpha);
+                if x_j == E::faer_zero() {
+                    continue;
+                }
+                for i in j..n {
+                    let idx = packed_idx(n, i, j);
+                    self.data.write(
+                        idx,
+                        self.data
+                            .read(idx)
+                            .faer_add(x.read(i).canonicalize().faer_mul(x_j)),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns the Cholesky factorization of `self`, or an error if the matrix is not positive
+    /// definite.
+    ///
+    /// The factorization is such that $A = LL^H$, where $L$ is lower triangular.
+    pub fn cholesky(&self) -> Result<PackedCholesky<E>, CholeskyError> {
+        self.clone().into_cholesky()
+    }
+
+    /// Returns the Cholesky factorization of `self`, reusing its storage, or an error if the
+    /// matrix is not positive definite.
+    ///
+    /// The factorization is such that $A = LL^H$, where $L$ is lower triangular.
+    pub fn into_cholesky(self) -> Result<PackedCholesky<E>, CholeskyError> {
+        let n = self.dim;
+        let mut a = self.data;
+
+        for j in 0..n {
+            let jj = packed_idx(n, j, j);
+            let d = a.read(jj).faer_real();
+            if !(d > E::Real::faer_zero()) {
+                return Err(CholeskyError {
+                    non_positive_definite_minor: j + 1,
+                });
+            }
+            let l = d.faer_sqrt();
+            let inv = l.faer_inv();
+            a.write(jj, E::faer_from_real(l));
+
+            for i in j + 1..n {
+                let idx = jj + (i - j);
+                a.write(idx, a.read(idx).faer_scale_real(inv));
+            }
+
+            for k in j + 1..n {
+                let l_kj = a.read(jj + (k - j)).faer_conj();
+                let kk = packed_idx(n, k, k);
+                for i in k..n {
+                    let idx = kk + (i - k);
+                    let l_ij = a.read(jj + (i - j));
+                    a.write(idx, a.read(idx).faer_sub(l_ij.faer_mul(l_kj)));
+                }
+            }
+        }
+
+        Ok(PackedCholesky { dim: n, factor: a })
+    }
+}
+
+impl<E: Entity> PackedCholesky<E> {
+    /// Returns the dimension of the factorized matrix.
+    #[inline(always)]
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns a view over the column-major packed Cholesky factor.
+    #[inline(always)]
+    pub fn packed_factor(&self) -> ColRef<'_, E> {
+        self.factor.as_ref()
+    }
+}
+
+impl<E: ComplexField> PackedCholesky<E> {
+    /// Returns the Cholesky factor as a dense lower triangular matrix.
+    pub fn compute_l(&self) -> Mat<E> {
+        let n = self.dim;
+        Mat::from_fn(n, n, |i, j| {
+            if i >= j {
+                self.factor.read(packed_idx(n, i, j))
+            } else {
+                E::faer_zero()
+            }
+        })
+    }
+
+    /// Solves the equation `A * X = rhs`, where `A` is the factorized matrix, and stores the
+    /// result in `rhs`.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve_in_place(&self, rhs: MatMut<'_, E>) {
+        let n = self.dim;
+        assert!(rhs.nrows() == n);
+        let l = &self.factor;
+
+        let mut rhs = rhs;
+        for k in 0..rhs.ncols() {
+            let mut b = rhs.rb_mut().col_mut(k);
+
+            // solve L y = b
+            for j in 0..n {
+                let jj = packed_idx(n, j, j);
+                let b_j = b.read(j).faer_scale_real(l.read(jj).faer_real().faer_inv());
+                b.write(j, b_j);
+                for i in j + 1..n {
+                    b.write(i, b.read(i).faer_sub(l.read(jj + (i - j)).faer_mul(b_j)));
+                }
+            }
+
+            // solve L^H x = y
+            for j in (0..n).rev() {
+                let jj = packed_idx(n, j, j);
+                let mut acc = b.read(j);
+                for i in j + 1..n {
+                    acc = acc.faer_sub(l.read(jj + (i - j)).faer_conj().faer_mul(b.read(i)));
+                }
+                b.write(j, acc.faer_scale_real(l.read(jj).faer_real().faer_inv()));
+            }
+        }
+    }
+
+    /// Returns the solution of the equation `A * X = rhs`, where `A` is the factorized matrix.
+    #[track_caller]
+    pub fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: MatRef<'_, ViewE>) -> Mat<E> {
+        let mut out = rhs.to_owned();
+        self.solve_in_place(out.as_mut());
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+    use rand::prelude::*;
+
+    #[test]
+    fn test_packed_cholesky() {
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        for n in [0, 1, 2, 7, 40] {
+            let x = Mat::<c64>::from_fn(n, n + 3, |_, _| c64::new(rng.gen(), rng.gen()));
+
+            // build `x * x^H + I` through the rank-k update kernel
+            let mut packed = PackedHermitian::<c64>::zeros(n);
+            for i in 0..n {
+                packed.write(i, i, c64::new(1.0, 0.0));
+            }
+            packed.rank_k_update(1.0, x.as_ref());
+            assert!(packed.packed_data().nrows() == n * (n + 1) / 2);
+
+            let a = x.as_ref() * x.adjoint() + Mat::<c64>::identity(n, n);
+            let dense = packed.to_mat();
+            assert!((&dense - &a).norm_max() < 1e-10);
+            for side in [Side::Lower, Side::Upper] {
+                let from_dense = PackedHermitian::from_mat(a.as_ref(), side);
+                assert!((from_dense.to_mat() - &a).norm_max() < 1e-10);
+            }
+
+            let llt = packed.cholesky().unwrap();
+            let l = llt.compute_l();
+            assert!((&l * l.adjoint() - &a).norm_max() < 1e-10);
+
+            let rhs = Mat::<c64>::from_fn(n, 2, |_, _| c64::new(rng.gen(), rng.gen()));
+            let sol = llt.solve(rhs.as_ref());
+            assert!((&a * &sol - &rhs).norm_max() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_packed_cholesky_not_positive_definite() {
+        let mut packed = PackedHermitian::<f64>::zeros(3);
+        packed.write(0, 0, 1.0);
+        packed.write(1, 0, 2.0);
+        packed.write(1, 1, 1.0);
+        packed.write(2, 2, 1.0);
+        let err = packed.into_cholesky().unwrap_err();
+        assert!(err.non_positive_definite_minor == 2);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{assert, col::*, linalg::temp_mat_uninit, mat::*, row::*, utils::constrained, *};
+use crate::{assert, col::*, debug_assert, mat::*, row::*, utils::constrained, *};
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use reborrow::*;
 
@@ -160,8 +160,6 @@ mod permref;
 pub use permown::Perm;
 pub use permref::PermRef;
 
-use self::linalg::temp_mat_req;
-
 /// Computes a permutation of the columns of the source matrix using the given permutation, and
 /// stores the result in the destination matrix.
 ///
@@ -248,7 +246,8 @@ pub fn permute_rows_in_place_req<I: Index, E: Entity>(
     nrows: usize,
     ncols: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    temp_mat_req::<E>(nrows, ncols)
+    let _ = ncols;
+    StackReq::try_new::<u8>(nrows)
 }
 
 /// Computes the size and alignment of required workspace for applying a column permutation to a
@@ -257,12 +256,16 @@ pub fn permute_cols_in_place_req<I: Index, E: Entity>(
     nrows: usize,
     ncols: usize,
 ) -> Result<StackReq, SizeOverflow> {
-    temp_mat_req::<E>(nrows, ncols)
+    let _ = nrows;
+    StackReq::try_new::<u8>(ncols)
 }
 
 /// Computes a permutation of the rows of the matrix using the given permutation, and
 /// stores the result in the same matrix.
 ///
+/// The rows are moved by following the cycles of the permutation, so that no copy of the matrix
+/// is made. The workspace is only used to keep track of the rows that were already visited.
+///
 /// # Panics
 ///
 /// - Panics if the size of the permutation doesn't match the number of rows of the matrix.
@@ -273,17 +276,20 @@ pub fn permute_rows_in_place<I: Index, E: ComplexField>(
     perm_indices: PermRef<'_, I>,
     stack: PodStack<'_>,
 ) {
-    #[inline]
     #[track_caller]
     fn implementation<E: ComplexField, I: Index>(
         matrix: MatMut<'_, E>,
         perm_indices: PermRef<'_, I>,
         stack: PodStack<'_>,
     ) {
-        let mut matrix = matrix;
-        let (mut tmp, _) = temp_mat_uninit::<E>(matrix.nrows(), matrix.ncols(), stack);
-        tmp.rb_mut().copy_from(matrix.rb());
-        permute_rows(matrix.rb_mut(), tmp.rb(), perm_indices);
+        let n = matrix.nrows();
+        assert!(perm_indices.len() == n);
+        if matrix.ncols() == 0 {
+            return;
+        }
+
+        let (visited, _) = stack.make_with(n, |_| 0u8);
+        cycle_permute_rows_impl(matrix, perm_indices.arrays().0, visited);
     }
 
     implementation(matrix, perm_indices.canonicalized(), stack)
@@ -292,6 +298,8 @@ pub fn permute_rows_in_place<I: Index, E: ComplexField>(
 /// Computes a permutation of the columns of the matrix using the given permutation, and
 /// stores the result in the same matrix.
 ///
+/// See [`permute_rows_in_place`] for more details.
+///
 /// # Panics
 ///
 /// - Panics if the size of the permutation doesn't match the number of columns of the matrix.
@@ -302,18 +310,159 @@ pub fn permute_cols_in_place<I: Index, E: ComplexField>(
     perm_indices: PermRef<'_, I>,
     stack: PodStack<'_>,
 ) {
-    #[inline]
+    permute_rows_in_place(matrix.transpose_mut(), perm_indices, stack)
+}
+
+/// Applies the row permutation `fwd` to `matrix` by following its cycles, using `visited` to skip
+/// the rows that were already moved.
+fn cycle_permute_rows_impl<I: Index, E: ComplexField>(
+    matrix: MatMut<'_, E>,
+    fwd: &[I],
+    visited: &mut [u8],
+) {
+    let n = matrix.nrows();
+    debug_assert!(all(fwd.len() == n, visited.len() == n));
+
+    let mut matrix = matrix;
+    for start in 0..n {
+        if visited[start] != 0 {
+            continue;
+        }
+        visited[start] = 1;
+
+        // each swap moves one row to its final position, and carries the row originally at
+        // `start` along the cycle
+        let mut i = start;
+        loop {
+            let next = fwd[i].zx();
+            if next == start {
+                break;
+            }
+            visited[next] = 1;
+            swap_rows_idx(matrix.rb_mut(), i, next);
+            i = next;
+        }
+    }
+}
+
+/// Computes a permutation of the rows of the matrix using the given permutation, and stores the
+/// result in the same matrix, so that the `i`-th row of the output is the `perm[i]`-th row of the
+/// input.
+///
+/// Unlike [`permute_rows_in_place`], this function takes no workspace argument, and allocates
+/// `n` bytes internally to keep track of the visited rows. The rows are moved by following the
+/// cycles of the permutation, which requires `n - c` row swaps, where `c` is the number of cycles.
+///
+/// # Panics
+///
+/// - Panics if the size of the permutation doesn't match the number of rows of the matrix.
+#[track_caller]
+pub fn cycle_permute_rows<I: Index, E: ComplexField>(matrix: MatMut<'_, E>, perm: PermRef<'_, I>) {
     #[track_caller]
-    fn implementation<I: Index, E: ComplexField>(
-        matrix: MatMut<'_, E>,
-        perm_indices: PermRef<'_, I>,
-        stack: PodStack<'_>,
-    ) {
-        let mut matrix = matrix;
-        let (mut tmp, _) = temp_mat_uninit::<E>(matrix.nrows(), matrix.ncols(), stack);
-        tmp.rb_mut().copy_from(matrix.rb());
-        permute_cols(matrix.rb_mut(), tmp.rb(), perm_indices);
+    fn implementation<I: Index, E: ComplexField>(matrix: MatMut<'_, E>, perm: PermRef<'_, I>) {
+        let n = matrix.nrows();
+        assert!(perm.len() == n);
+        let mut visited = alloc::vec![0u8; n];
+        cycle_permute_rows_impl(matrix, perm.arrays().0, &mut visited);
     }
 
-    implementation(matrix, perm_indices.canonicalized(), stack)
+    implementation(matrix, perm.canonicalized())
+}
+
+/// Computes a permutation of the columns of the matrix using the given permutation, and stores
+/// the result in the same matrix, so that the `j`-th column of the output is the `perm[j]`-th
+/// column of the input.
+///
+/// See [`cycle_permute_rows`] for more details.
+///
+/// # Panics
+///
+/// - Panics if the size of the permutation doesn't match the number of columns of the matrix.
+#[track_caller]
+pub fn cycle_permute_cols<I: Index, E: ComplexField>(matrix: MatMut<'_, E>, perm: PermRef<'_, I>) {
+    cycle_permute_rows(matrix.transpose_mut(), perm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, sparse::SparseColMat};
+
+    #[test]
+    fn test_perm_ops() {
+        let p = Perm::<usize>::new_checked(
+            alloc::vec![2, 0, 4, 1, 3, 5].into_boxed_slice(),
+            alloc::vec![1, 3, 0, 4, 2, 5].into_boxed_slice(),
+        );
+        let q = Perm::<usize>::from_argsort(col![3.0, -1.0, 2.5, f64::NAN, 0.0, 7.0].as_ref());
+        assert!(q.as_ref().arrays().0 == [1, 4, 2, 0, 5, 3]);
+
+        let pq = p.as_ref().compose(q.as_ref());
+        assert!(pq.as_ref().arrays().0 == (&p * &q).as_ref().arrays().0);
+        let id = p.as_ref().compose(p.inverse());
+        assert!(id.as_ref().arrays().0 == Perm::<usize>::identity(6).as_ref().arrays().0);
+
+        let a = Mat::<f64>::from_fn(6, 4, |i, j| (10 * i + j) as f64);
+        let mut b = a.clone();
+        cycle_permute_rows(b.as_mut(), p.as_ref());
+        assert!(b == p.as_ref() * &a);
+        let mut b = a.transpose().to_owned();
+        cycle_permute_cols(b.as_mut(), p.as_ref());
+        assert!(b == a.transpose() * p.inverse());
+
+        let mut mem = dyn_stack::GlobalPodBuffer::new(
+            permute_rows_in_place_req::<usize, f64>(6, 4)
+                .unwrap()
+                .try_or(permute_cols_in_place_req::<usize, f64>(4, 6).unwrap())
+                .unwrap(),
+        );
+        let mut b = a.clone();
+        permute_rows_in_place(b.as_mut(), p.as_ref(), PodStack::new(&mut mem));
+        assert!(b == p.as_ref() * &a);
+        let mut b = a.transpose().to_owned();
+        permute_cols_in_place(b.as_mut(), p.as_ref(), PodStack::new(&mut mem));
+        assert!(b == a.transpose() * p.inverse());
+
+        let sparse: SparseColMat<usize, f64> = p.to_sparse().unwrap();
+        assert!(sparse.to_dense() * &a == p.as_ref() * &a);
+        let p2 = Perm::try_from_sparse(sparse.as_ref()).unwrap();
+        assert!(p2.as_ref().arrays() == p.as_ref().arrays());
+
+        let not_perm =
+            SparseColMat::<usize, f64>::try_new_from_triplets(2, 2, &[(0, 0, 1.0), (0, 1, 1.0)])
+                .unwrap();
+        assert!(Perm::try_from_sparse(not_perm.as_ref()).is_none());
+    }
+
+    #[test]
+    fn test_cycle_permute_single_cycle() {
+        let n = 1000;
+        let fwd: alloc::vec::Vec<usize> = (0..n).map(|k| (k + 1) % n).collect();
+        let inv: alloc::vec::Vec<usize> = (0..n).map(|k| (k + n - 1) % n).collect();
+        let p = Perm::<usize>::new_checked(fwd.into_boxed_slice(), inv.into_boxed_slice());
+
+        let a = Mat::<f64>::from_fn(n, 3, |i, j| (3 * i + j) as f64);
+        let mut b = a.clone();
+        cycle_permute_rows(b.as_mut(), p.as_ref());
+        assert!(b == p.as_ref() * &a);
+        let mut b = a.transpose().to_owned();
+        cycle_permute_cols(b.as_mut(), p.as_ref());
+        assert!(b == a.transpose() * p.inverse());
+    }
+
+    #[test]
+    fn test_sort_rows() {
+        let x = col![2.0, f64::NAN, -1.0, 2.0, 0.5];
+        assert!(x.argsort().as_ref().arrays().0 == [2, 4, 0, 3, 1]);
+        let p = x.argsort_unstable();
+        let sorted = Col::<f64>::from_fn(5, |i| x.read(p.as_ref().arrays().0[i]));
+        assert!(sorted.as_ref().subrows(0, 4) == col![-1.0, 0.5, 2.0, 2.0].as_ref());
+        assert!(sorted.read(4).is_nan());
+
+        let mut a = mat![[2.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.0, 3.0]];
+        a.sort_rows_by_col(0);
+        assert!(a == mat![[0.0, 3.0], [1.0, 1.0], [2.0, 0.0], [2.0, 2.0]]);
+        a.sort_rows_by_col_unstable(1);
+        assert!(a == mat![[2.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.0, 3.0]]);
+    }
 }
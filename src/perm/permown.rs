@@ -1,5 +1,9 @@
 use super::*;
-use crate::assert;
+use crate::{
+    assert,
+    sparse::{FaerError, SparseColMat, SparseColMatRef, SymbolicSparseColMat},
+    utils::{slice::SliceGroup, vec::VecGroup},
+};
 
 /// Permutation matrix.
 #[derive(Debug, Clone)]
@@ -64,6 +68,12 @@ impl<I: Index> Perm<I> {
         self.forward.len()
     }
 
+    /// Returns a view over the inverse permutation.
+    #[inline]
+    pub fn inverse(&self) -> PermRef<'_, I> {
+        self.as_ref().inverse()
+    }
+
     /// Returns the inverse permutation.
     #[inline]
     pub fn into_inverse(self) -> Self {
@@ -72,4 +82,137 @@ impl<I: Index> Perm<I> {
             inverse: self.forward,
         }
     }
+
+    /// Returns the identity permutation of dimension `dim`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dim > I::Signed::MAX`.
+    #[track_caller]
+    pub fn identity(dim: usize) -> Self {
+        assert!(dim <= I::Signed::MAX.zx());
+        let id: alloc::boxed::Box<[I]> = (0..dim).map(I::truncate).collect();
+        Self {
+            forward: id.clone(),
+            inverse: id,
+        }
+    }
+
+    /// Returns the permutation that sorts `values` in ascending order, so that the `i`-th element
+    /// of the sorted sequence is `values[perm[i]]`, where `perm` is the forward array of the
+    /// returned permutation.
+    ///
+    /// The sort is stable, and `NaN` values are placed at the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values.nrows() > I::Signed::MAX`.
+    #[track_caller]
+    pub fn from_argsort<E: RealField>(values: ColRef<'_, E>) -> Self {
+        Self::from_argsort_impl(values, true)
+    }
+
+    /// Returns the permutation that sorts `values` in ascending order, so that the `i`-th element
+    /// of the sorted sequence is `values[perm[i]]`, where `perm` is the forward array of the
+    /// returned permutation.
+    ///
+    /// The sort is not stable, so the relative order of equal values is unspecified, and `NaN`
+    /// values are placed at the end. This is usually faster than [`Perm::from_argsort`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values.nrows() > I::Signed::MAX`.
+    #[track_caller]
+    pub fn from_argsort_unstable<E: RealField>(values: ColRef<'_, E>) -> Self {
+        Self::from_argsort_impl(values, false)
+    }
+
+    #[track_caller]
+    fn from_argsort_impl<E: RealField>(values: ColRef<'_, E>, stable: bool) -> Self {
+        let n = values.nrows();
+        assert!(n <= I::Signed::MAX.zx());
+
+        #[allow(clippy::eq_op)]
+        let is_nan = |x: E| x != x;
+        let compare = |&a: &I, &b: &I| {
+            let (a, b) = (values.read(a.zx()), values.read(b.zx()));
+            match a.partial_cmp(&b) {
+                Some(ordering) => ordering,
+                None => is_nan(a).cmp(&is_nan(b)),
+            }
+        };
+
+        let mut forward: alloc::boxed::Box<[I]> = (0..n).map(I::truncate).collect();
+        if stable {
+            forward.sort_by(compare);
+        } else {
+            forward.sort_unstable_by(compare);
+        }
+
+        let mut inverse = forward.clone();
+        for (i, &p) in forward.iter().enumerate() {
+            inverse[p.zx()] = I::truncate(i);
+        }
+        Self { forward, inverse }
+    }
+
+    /// Returns the permutation matrix as a sparse matrix, such that multiplying it by a matrix
+    /// `A` is equivalent to `self * A`.
+    pub fn to_sparse<E: ComplexField>(&self) -> Result<SparseColMat<I, E>, FaerError> {
+        let n = self.len();
+        let mut col_ptrs = alloc::vec::Vec::new();
+        let mut row_indices = alloc::vec::Vec::new();
+        let mut values = VecGroup::<E>::new();
+        col_ptrs
+            .try_reserve_exact(n + 1)
+            .map_err(|_| FaerError::OutOfMemory)?;
+        row_indices
+            .try_reserve_exact(n)
+            .map_err(|_| FaerError::OutOfMemory)?;
+        values
+            .try_reserve_exact(n)
+            .map_err(|_| FaerError::OutOfMemory)?;
+
+        // the `j`-th column holds a one at row `inverse[j]`
+        col_ptrs.extend((0..n + 1).map(I::truncate));
+        row_indices.extend_from_slice(&self.inverse);
+        values.resize(n, E::faer_one().faer_into_units());
+
+        Ok(SparseColMat::new(
+            SymbolicSparseColMat::new_checked(n, n, col_ptrs, None, row_indices),
+            values.into_inner(),
+        ))
+    }
+
+    /// Returns the permutation represented by the sparse matrix `matrix`, or `None` if it is not a
+    /// permutation matrix, i.e., if it is not square or does not contain exactly one explicitly
+    /// stored entry equal to one in each row and each column.
+    pub fn try_from_sparse<E: ComplexField>(matrix: SparseColMatRef<'_, I, E>) -> Option<Self> {
+        let n = matrix.nrows();
+        if matrix.ncols() != n {
+            return None;
+        }
+
+        let mut inverse: alloc::boxed::Box<[I]> = (0..n).map(|_| I::truncate(0)).collect();
+        let mut seen = alloc::vec![false; n];
+        for j in 0..n {
+            let rows = matrix.row_indices_of_col_raw(j);
+            let values = SliceGroup::<'_, E>::new(matrix.values_of_col(j));
+            if rows.len() != 1 || values.read(0) != E::faer_one() {
+                return None;
+            }
+            let i = rows[0].zx();
+            if seen[i] {
+                return None;
+            }
+            seen[i] = true;
+            inverse[j] = I::truncate(i);
+        }
+
+        let mut forward = inverse.clone();
+        for (j, &i) in inverse.iter().enumerate() {
+            forward[i.zx()] = I::truncate(j);
+        }
+        Some(Self { forward, inverse })
+    }
 }
@@ -117,6 +117,27 @@ impl<'a, I: Index> PermRef<'a, I> {
         }
     }
 
+    /// Returns the composition `self * rhs`, which applies `rhs` first and `self` second when
+    /// multiplying a matrix from the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` don't have the same dimension.
+    #[inline]
+    #[track_caller]
+    pub fn compose(self, rhs: PermRef<'_, I>) -> Perm<I> {
+        self * rhs
+    }
+
+    /// Copies `self` into a newly allocated permutation.
+    #[inline]
+    pub fn to_owned(self) -> Perm<I> {
+        Perm {
+            forward: self.forward.into(),
+            inverse: self.inverse.into(),
+        }
+    }
+
     /// Cast the permutation to the fixed width index type.
     #[inline(always)]
     pub fn canonicalized(self) -> PermRef<'a, I::FixedWidth> {
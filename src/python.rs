@@ -0,0 +1,227 @@
+//! Helpers for writing Python extensions with [`pyo3`](https://pyo3.rs).
+//!
+//! Objects implementing the Python buffer protocol, such as numpy arrays, can be viewed as faer
+//! matrices and vectors without copying. The element type of the buffer is checked against the
+//! requested scalar type when the [`PyBuffer`] is acquired, and the byte strides of the buffer are
+//! converted to element strides, so that both C-ordered and Fortran-ordered arrays, as well as
+//! sliced arrays, are supported. Results can be converted back to numpy arrays with
+//! [`mat_to_numpy`] and [`col_to_numpy`].
+//!
+//! ```no_run
+//! use faer::python::{mat_from_buffer, mat_to_numpy};
+//! use pyo3::{buffer::PyBuffer, prelude::*};
+//!
+//! #[pyfunction]
+//! fn transpose<'py>(py: Python<'py>, a: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+//!     let buffer = PyBuffer::<f64>::get_bound(a)?;
+//!     // the buffer isn't modified while we hold the GIL
+//!     let a = unsafe { mat_from_buffer(&buffer)? };
+//!     mat_to_numpy(py, a.transpose())
+//! }
+//! ```
+
+use crate::{
+    col::{self, ColRef},
+    mat::{self, MatMut, MatRef},
+};
+use faer_entity::SimpleEntity;
+use pyo3::{
+    buffer::{Element, PyBuffer},
+    exceptions::{PyBufferError, PyValueError},
+    prelude::*,
+    types::IntoPyDict,
+};
+
+/// Scalar type that can be read from and written to Python buffers.
+pub trait PyElement: SimpleEntity + Element {
+    /// Name of the corresponding numpy dtype.
+    const DTYPE: &'static str;
+}
+
+impl PyElement for f32 {
+    const DTYPE: &'static str = "float32";
+}
+impl PyElement for f64 {
+    const DTYPE: &'static str = "float64";
+}
+
+// converts byte strides to element strides
+fn element_strides<E>(strides: &[isize]) -> PyResult<[isize; 2]> {
+    let size = core::mem::size_of::<E>() as isize;
+    let mut out = [0isize; 2];
+    for (out, &stride) in out.iter_mut().zip(strides) {
+        if stride % size != 0 {
+            return Err(PyBufferError::new_err(
+                "buffer strides must be a multiple of the item size",
+            ));
+        }
+        *out = stride / size;
+    }
+    Ok(out)
+}
+
+fn check_ndim(ndim: usize, expected: usize) -> PyResult<()> {
+    if ndim == expected {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(alloc::format!(
+            "expected a buffer with {expected} dimension(s), found {ndim}"
+        )))
+    }
+}
+
+/// Returns a view over the two-dimensional `buffer`.
+///
+/// An error is returned if the buffer doesn't have two dimensions, or if its strides are not a
+/// multiple of the item size.
+///
+/// # Safety
+/// The memory of the buffer must not be mutated while the returned view is alive, neither from
+/// Rust nor from Python. In particular, Python code that may write to the underlying object must
+/// not run, e.g. by releasing the GIL or calling back into Python.
+pub unsafe fn mat_from_buffer<E: PyElement>(buffer: &PyBuffer<E>) -> PyResult<MatRef<'_, E>> {
+    check_ndim(buffer.dimensions(), 2)?;
+    let [row_stride, col_stride] = element_strides::<E>(buffer.strides())?;
+    let shape = buffer.shape();
+    Ok(mat::from_raw_parts(
+        buffer.buf_ptr() as *const E,
+        shape[0],
+        shape[1],
+        row_stride,
+        col_stride,
+    ))
+}
+
+/// Returns a mutable view over the two-dimensional `buffer`.
+///
+/// An error is returned if the buffer is read-only, if it doesn't have two dimensions, or if its
+/// strides are not a multiple of the item size.
+///
+/// # Safety
+/// No other view over the memory of the buffer may be accessed while the returned view is alive,
+/// neither from Rust nor from Python. The buffer must not contain overlapping elements, e.g. due
+/// to a zero stride.
+pub unsafe fn mat_mut_from_buffer<E: PyElement>(buffer: &PyBuffer<E>) -> PyResult<MatMut<'_, E>> {
+    if buffer.readonly() {
+        return Err(PyBufferError::new_err("buffer is read-only"));
+    }
+    check_ndim(buffer.dimensions(), 2)?;
+    let [row_stride, col_stride] = element_strides::<E>(buffer.strides())?;
+    let shape = buffer.shape();
+    Ok(mat::from_raw_parts_mut(
+        buffer.buf_ptr() as *mut E,
+        shape[0],
+        shape[1],
+        row_stride,
+        col_stride,
+    ))
+}
+
+/// Returns a view over the one-dimensional `buffer`.
+///
+/// An error is returned if the buffer doesn't have one dimension, or if its stride is not a
+/// multiple of the item size.
+///
+/// # Safety
+/// See [`mat_from_buffer`].
+pub unsafe fn col_from_buffer<E: PyElement>(buffer: &PyBuffer<E>) -> PyResult<ColRef<'_, E>> {
+    check_ndim(buffer.dimensions(), 1)?;
+    let [row_stride, _] = element_strides::<E>(buffer.strides())?;
+    Ok(col::from_raw_parts(
+        buffer.buf_ptr() as *const E,
+        buffer.shape()[0],
+        row_stride,
+    ))
+}
+
+fn numpy_empty<'py, E: PyElement>(py: Python<'py>, shape: &[usize]) -> PyResult<Bound<'py, PyAny>> {
+    let kwargs = [("dtype", E::DTYPE), ("order", "F")].into_py_dict_bound(py);
+    py.import_bound("numpy")?.call_method(
+        "empty",
+        (pyo3::types::PyTuple::new_bound(py, shape),),
+        Some(&kwargs),
+    )
+}
+
+/// Copies `mat` into a new Fortran-ordered numpy array.
+pub fn mat_to_numpy<'py, E: PyElement>(
+    py: Python<'py>,
+    mat: MatRef<'_, E>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let array = numpy_empty::<E>(py, &[mat.nrows(), mat.ncols()])?;
+    let buffer = PyBuffer::<E>::get_bound(&array)?;
+    // the array was just created, so no other view over it exists
+    unsafe { mat_mut_from_buffer(&buffer)? }.copy_from(mat);
+    Ok(array)
+}
+
+/// Copies `col` into a new one-dimensional numpy array.
+pub fn col_to_numpy<'py, E: PyElement>(
+    py: Python<'py>,
+    col: ColRef<'_, E>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let array = numpy_empty::<E>(py, &[col.nrows()])?;
+    let buffer = PyBuffer::<E>::get_bound(&array)?;
+    let [row_stride, _] = element_strides::<E>(buffer.strides())?;
+    // the array was just created, so no other view over it exists
+    unsafe {
+        col::from_raw_parts_mut(buffer.buf_ptr() as *mut E, col.nrows(), row_stride).copy_from(col);
+    }
+    Ok(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert;
+
+    #[test]
+    fn test_strides() {
+        assert!(element_strides::<f64>(&[8, 24]).unwrap() == [1, 3]);
+        assert!(element_strides::<f64>(&[-16]).unwrap() == [-2, 0]);
+        assert!(element_strides::<f32>(&[6, 4]).is_err());
+    }
+
+    // embeds an interpreter, which needs a python installation at link and run time
+    #[test]
+    #[cfg(feature = "pyo3-auto-initialize")]
+    fn test_buffer_round_trip() {
+        Python::with_gil(|py| {
+            let locals = pyo3::types::PyDict::new_bound(py);
+            py.run_bound(
+                "import array\n\
+                 data = array.array('d', range(6))\n\
+                 view = memoryview(data).cast('B').cast('d', [2, 3])\n\
+                 readonly = view.toreadonly()\n",
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let get = |name: &str| locals.get_item(name).unwrap().unwrap();
+
+            let data = PyBuffer::<f64>::get_bound(&get("data")).unwrap();
+            let view = PyBuffer::<f64>::get_bound(&get("view")).unwrap();
+            let readonly = PyBuffer::<f64>::get_bound(&get("readonly")).unwrap();
+
+            // no python code runs while the views are alive
+            let col = unsafe { col_from_buffer(&data) }.unwrap();
+            assert!(col == crate::col![0.0, 1.0, 2.0, 3.0, 4.0, 5.0].as_ref());
+            let mat = unsafe { mat_from_buffer(&view) }.unwrap();
+            assert!(mat == crate::mat![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]].as_ref());
+            assert!(unsafe { mat_from_buffer(&data) }.is_err());
+            assert!(unsafe { mat_mut_from_buffer(&readonly) }.is_err());
+
+            let mut mat = unsafe { mat_mut_from_buffer(&view) }.unwrap();
+            mat.write(1, 0, -3.0);
+            mat.write(0, 2, -2.0);
+
+            drop((data, view, readonly));
+            let values: alloc::vec::Vec<f64> = py
+                .eval_bound("list(data)", None, Some(&locals))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(values == [0.0, 1.0, -2.0, -3.0, 4.0, 5.0]);
+        });
+    }
+}
@@ -651,6 +651,38 @@ impl<'a, E: Entity> RowMut<'a, E> {
         self.rb().as_2d().sum()
     }
 
+    /// Returns the dot product of `self` and `rhs`, without conjugating either of the operands.
+    /// See [`RowRef::dot`].
+    #[doc(alias = "dotu")]
+    #[inline]
+    #[track_caller]
+    pub fn dot<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().dot(rhs)
+    }
+
+    /// Returns the inner product of `self` and `rhs`, `sum(conj(self[j]) * rhs[j])`. See
+    /// [`RowRef::dotc`].
+    #[doc(alias = "vdot")]
+    #[inline]
+    #[track_caller]
+    pub fn dotc<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.rb().dotc(rhs)
+    }
+
     /// Kroneckor product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -786,6 +818,12 @@ impl<'a, E: Entity> core::fmt::Debug for RowMut<'a, E> {
     }
 }
 
+impl<E: Entity + core::fmt::Display> core::fmt::Display for RowMut<'_, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.as_ref(), f)
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<usize> for RowMut<'_, E> {
     type Output = E;
 
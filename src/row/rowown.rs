@@ -171,6 +171,14 @@ impl<E: Entity> Row<E> {
         self.as_mut().as_2d_mut()
     }
 
+    /// Returns a view over a matrix containing `self` repeated `nrows` times, without copying the
+    /// data. See [`RowRef::repeat_rows`].
+    #[doc(alias = "broadcast")]
+    #[inline(always)]
+    pub fn repeat_rows(&self, nrows: usize) -> MatRef<'_, E> {
+        self.as_ref().repeat_rows(nrows)
+    }
+
     /// Returns raw pointers to the element at the given index.
     #[inline(always)]
     pub fn ptr_at(&self, col: usize) -> GroupFor<E, *const E::Unit> {
@@ -396,6 +404,13 @@ impl<E: Entity> Row<E> {
         }
     }
 
+    /// Resizes the vector in-place so that the new number of columns is `new_ncols`.
+    /// The existing elements are kept, and new elements are set to `value`.
+    #[inline]
+    pub fn resize(&mut self, new_ncols: usize, value: E) {
+        self.resize_with(new_ncols, |_| value);
+    }
+
     /// Truncates the matrix so that its new number of columns is `new_ncols`.  
     /// The new dimension must be smaller than the current dimension of the vector.
     ///
@@ -863,6 +878,38 @@ impl<E: Entity> Row<E> {
         self.as_ref().as_2d().sum()
     }
 
+    /// Returns the dot product of `self` and `rhs`, without conjugating either of the operands.
+    /// See [`RowRef::dot`].
+    #[doc(alias = "dotu")]
+    #[inline]
+    #[track_caller]
+    pub fn dot<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().dot(rhs)
+    }
+
+    /// Returns the inner product of `self` and `rhs`, `sum(conj(self[j]) * rhs[j])`. See
+    /// [`RowRef::dotc`].
+    #[doc(alias = "vdot")]
+    #[inline]
+    #[track_caller]
+    pub fn dotc<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.as_ref().dotc(rhs)
+    }
+
     /// Kroneckor product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -935,6 +982,12 @@ impl<E: Entity> core::fmt::Debug for Row<E> {
     }
 }
 
+impl<E: Entity + core::fmt::Display> core::fmt::Display for Row<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.as_ref(), f)
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<usize> for Row<E> {
     type Output = E;
 
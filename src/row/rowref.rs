@@ -107,6 +107,16 @@ impl<'a, E: Entity> RowRef<'a, E> {
         unsafe { crate::mat::from_raw_parts(self.as_ptr(), 1, ncols, isize::MAX, col_stride) }
     }
 
+    /// Returns a view over a matrix containing `self` repeated `nrows` times, without copying the
+    /// data.
+    ///
+    /// This can be used to broadcast a row against a matrix in [`zipped!`](crate::zipped).
+    #[doc(alias = "broadcast")]
+    #[inline(always)]
+    pub fn repeat_rows(self, nrows: usize) -> MatRef<'a, E> {
+        crate::mat::from_repeated_row(self, nrows)
+    }
+
     /// Returns raw pointers to the element at the given index.
     #[inline(always)]
     pub fn ptr_at(self, col: usize) -> GroupFor<E, *const E::Unit> {
@@ -455,6 +465,108 @@ impl<'a, E: Entity> RowRef<'a, E> {
         self.as_2d().sum()
     }
 
+    /// Returns the dot product of `self` and `rhs`, `sum(self[j] * rhs[j])`, without conjugating
+    /// either of the operands.
+    ///
+    /// For complex vectors, the inner product that conjugates `self` is computed by
+    /// [`RowRef::dotc`].
+    ///
+    /// # Panics
+    /// The function panics if `self.ncols() != rhs.ncols()`.
+    #[doc(alias = "dotu")]
+    #[inline]
+    #[track_caller]
+    pub fn dot<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.transpose().dot(rhs.as_row_ref().transpose())
+    }
+
+    /// Returns the inner product of `self` and `rhs`, `sum(conj(self[j]) * rhs[j])`.
+    ///
+    /// # Panics
+    /// The function panics if `self.ncols() != rhs.ncols()`.
+    #[doc(alias = "vdot")]
+    #[inline]
+    #[track_caller]
+    pub fn dotc<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsRowRef<ViewE>,
+    ) -> E::Canonical
+    where
+        E: Conjugate,
+        E::Canonical: ComplexField,
+    {
+        self.transpose().dotc(rhs.as_row_ref().transpose())
+    }
+
+    /// Returns the maximum of `self` along with its index, or `None` if `self` is empty.
+    ///
+    /// NaN values are ignored, unless all the elements are NaN. Ties are resolved in favor of the
+    /// element with the smallest index.
+    #[inline]
+    pub fn argmax(&self) -> Option<(E, usize)>
+    where
+        E: RealField,
+    {
+        self.argmax_with_policy(crate::NanPolicy::Ignore)
+    }
+
+    /// Returns the maximum of `self` along with its index, or `None` if `self` is empty, handling
+    /// NaN values according to `nan_policy`.
+    #[inline]
+    pub fn argmax_with_policy(&self, nan_policy: crate::NanPolicy) -> Option<(E, usize)>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::extrema::argmax((*self).as_2d(), nan_policy)
+            .map(|(value, _, j)| (value, j))
+    }
+
+    /// Returns the minimum of `self` along with its index, or `None` if `self` is empty.
+    ///
+    /// NaN values are ignored, unless all the elements are NaN. Ties are resolved in favor of the
+    /// element with the smallest index.
+    #[inline]
+    pub fn argmin(&self) -> Option<(E, usize)>
+    where
+        E: RealField,
+    {
+        self.argmin_with_policy(crate::NanPolicy::Ignore)
+    }
+
+    /// Returns the minimum of `self` along with its index, or `None` if `self` is empty, handling
+    /// NaN values according to `nan_policy`.
+    #[inline]
+    pub fn argmin_with_policy(&self, nan_policy: crate::NanPolicy) -> Option<(E, usize)>
+    where
+        E: RealField,
+    {
+        crate::linalg::reductions::extrema::argmin((*self).as_2d(), nan_policy)
+            .map(|(value, _, j)| (value, j))
+    }
+
+    /// Returns the maximum of `self` with respect to `compare`, along with its index, or `None`
+    /// if `self` is empty. Ties are resolved in favor of the element with the smallest index.
+    #[inline]
+    pub fn max_by(&self, compare: impl FnMut(&E, &E) -> core::cmp::Ordering) -> Option<(E, usize)> {
+        crate::linalg::reductions::extrema::max_by((*self).as_2d(), compare)
+            .map(|(value, _, j)| (value, j))
+    }
+
+    /// Returns the minimum of `self` with respect to `compare`, along with its index, or `None`
+    /// if `self` is empty. Ties are resolved in favor of the element with the smallest index.
+    #[inline]
+    pub fn min_by(&self, compare: impl FnMut(&E, &E) -> core::cmp::Ordering) -> Option<(E, usize)> {
+        crate::linalg::reductions::extrema::min_by((*self).as_2d(), compare)
+            .map(|(value, _, j)| (value, j))
+    }
+
     /// Kroneckor product of `self` and `rhs`.
     ///
     /// This is an allocating operation; see [`faer::linalg::kron`](crate::linalg::kron) for the
@@ -557,6 +669,12 @@ impl<'a, E: Entity> core::fmt::Debug for RowRef<'a, E> {
     }
 }
 
+impl<E: Entity + core::fmt::Display> core::fmt::Display for RowRef<'_, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::utils::display::fmt_summary(f, "Row", &[self.ncols()], self.as_2d())
+    }
+}
+
 impl<E: SimpleEntity> core::ops::Index<usize> for RowRef<'_, E> {
     type Output = E;
 
@@ -0,0 +1,301 @@
+//! Serde implementations for Col, Row, Diag and Perm
+
+use faer_entity::Entity;
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{check_version, VERSION};
+use crate::{
+    col::{Col, ColMut, ColRef},
+    diag::{Diag, DiagMut, DiagRef},
+    perm::{Perm, PermRef},
+    row::{Row, RowMut, RowRef},
+    Index, SignedIndex,
+};
+
+struct ElementsSerializer<'a, E: Entity>(ColRef<'a, E>);
+
+impl<'a, E: Entity> Serialize for ElementsSerializer<'a, E>
+where
+    E: Serialize,
+{
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.collect_seq((0..self.0.nrows()).map(|i| self.0.read(i)))
+    }
+}
+
+fn serialize_vector<S: Serializer, E: Entity + Serialize>(
+    s: S,
+    name: &'static str,
+    dim: &'static str,
+    values: ColRef<'_, E>,
+) -> Result<S::Ok, S::Error> {
+    let mut structure = s.serialize_struct(name, 3)?;
+    structure.serialize_field("version", &VERSION)?;
+    structure.serialize_field(dim, &values.nrows())?;
+    structure.serialize_field("data", &ElementsSerializer(values))?;
+    structure.end()
+}
+
+fn check_length<E: Error>(len: usize, expected: usize) -> Result<(), E> {
+    if len == expected {
+        Ok(())
+    } else {
+        Err(E::invalid_length(
+            len,
+            &format!("{} elements", expected).as_str(),
+        ))
+    }
+}
+
+macro_rules! impl_serialize {
+    ($name: literal, $dim: literal, $($ty: ty => $to_col: expr),* $(,)?) => {$(
+        impl<E: Entity> Serialize for $ty
+        where
+            E: Serialize,
+        {
+            fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let to_col: fn(&Self) -> ColRef<'_, E> = $to_col;
+                serialize_vector(s, $name, $dim, to_col(self))
+            }
+        }
+    )*};
+}
+
+impl_serialize!(
+    "Col",
+    "nrows",
+    ColRef<'_, E> => |this| *this,
+    ColMut<'_, E> => |this| this.as_ref(),
+    Col<E> => |this| this.as_ref(),
+);
+impl_serialize!(
+    "Row",
+    "ncols",
+    RowRef<'_, E> => |this| this.transpose(),
+    RowMut<'_, E> => |this| this.as_ref().transpose(),
+    Row<E> => |this| this.as_ref().transpose(),
+);
+impl_serialize!(
+    "Diag",
+    "dim",
+    DiagRef<'_, E> => |this| this.column_vector(),
+    DiagMut<'_, E> => |this| this.as_ref().column_vector(),
+    Diag<E> => |this| this.column_vector(),
+);
+
+impl<'a, E: Entity> Deserialize<'a> for Col<E>
+where
+    E: Deserialize<'a>,
+{
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Col")]
+        struct Raw<E> {
+            version: u32,
+            nrows: usize,
+            data: Vec<E>,
+        }
+
+        let raw = Raw::<E>::deserialize(d)?;
+        check_version::<D::Error>(raw.version)?;
+        check_length::<D::Error>(raw.data.len(), raw.nrows)?;
+        Ok(Col::from_fn(raw.nrows, |i| raw.data[i]))
+    }
+}
+
+impl<'a, E: Entity> Deserialize<'a> for Row<E>
+where
+    E: Deserialize<'a>,
+{
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Row")]
+        struct Raw<E> {
+            version: u32,
+            ncols: usize,
+            data: Vec<E>,
+        }
+
+        let raw = Raw::<E>::deserialize(d)?;
+        check_version::<D::Error>(raw.version)?;
+        check_length::<D::Error>(raw.data.len(), raw.ncols)?;
+        Ok(Row::from_fn(raw.ncols, |j| raw.data[j]))
+    }
+}
+
+impl<'a, E: Entity> Deserialize<'a> for Diag<E>
+where
+    E: Deserialize<'a>,
+{
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Diag")]
+        struct Raw<E> {
+            version: u32,
+            dim: usize,
+            data: Vec<E>,
+        }
+
+        let raw = Raw::<E>::deserialize(d)?;
+        check_version::<D::Error>(raw.version)?;
+        check_length::<D::Error>(raw.data.len(), raw.dim)?;
+        Ok(Diag {
+            inner: Col::from_fn(raw.dim, |i| raw.data[i]),
+        })
+    }
+}
+
+impl<I: Index> Serialize for PermRef<'_, I>
+where
+    I: Serialize,
+{
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut structure = s.serialize_struct("Perm", 2)?;
+        structure.serialize_field("version", &VERSION)?;
+        structure.serialize_field("forward", self.arrays().0)?;
+        structure.end()
+    }
+}
+
+impl<I: Index> Serialize for Perm<I>
+where
+    I: Serialize,
+{
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_ref().serialize(s)
+    }
+}
+
+impl<'a, I: Index> Deserialize<'a> for Perm<I>
+where
+    I: Deserialize<'a>,
+{
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Perm")]
+        struct Raw<I> {
+            version: u32,
+            forward: Vec<I>,
+        }
+
+        let raw = Raw::<I>::deserialize(d)?;
+        check_version::<D::Error>(raw.version)?;
+
+        let n = raw.forward.len();
+        if n > I::Signed::MAX.zx() {
+            return Err(D::Error::custom(
+                "permutation dimension exceeds the maximum index value",
+            ));
+        }
+        let mut inverse = alloc::vec![I::truncate(n); n];
+        for (i, &p) in raw.forward.iter().enumerate() {
+            let p = p.zx();
+            if p >= n || inverse[p].zx() != n {
+                return Err(D::Error::custom(
+                    "`forward` must contain each index in `0..n` exactly once",
+                ));
+            }
+            inverse[p] = I::truncate(i);
+        }
+
+        Ok(Perm::new_checked(
+            raw.forward.into_boxed_slice(),
+            inverse.into_boxed_slice(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, col, row};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[test]
+    fn vector_serialization() {
+        let tokens = |name: &'static str, dim: &'static str| {
+            [
+                Token::Struct { name, len: 3 },
+                Token::Str("version"),
+                Token::U32(1),
+                Token::Str(dim),
+                Token::U64(3),
+                Token::Str("data"),
+                Token::Seq { len: Some(3) },
+                Token::F64(1.0),
+                Token::F64(2.0),
+                Token::F64(3.0),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ]
+        };
+
+        assert_tokens(&col![1.0, 2.0, 3.0], &tokens("Col", "nrows"));
+        assert_tokens(&row![1.0, 2.0, 3.0], &tokens("Row", "ncols"));
+
+        let diag = col![1.0, 2.0, 3.0].column_vector_into_diagonal();
+        let json = serde_json::to_string(&diag).unwrap();
+        let deserialized = serde_json::from_str::<Diag<f64>>(&json).unwrap();
+        assert!(deserialized.column_vector() == diag.column_vector());
+        serde_test::assert_ser_tokens(&diag, &tokens("Diag", "dim"));
+
+        assert_de_tokens_error::<Col<f64>>(
+            &[
+                Token::Struct {
+                    name: "Col",
+                    len: 3,
+                },
+                Token::Str("version"),
+                Token::U32(1),
+                Token::Str("nrows"),
+                Token::U64(2),
+                Token::Str("data"),
+                Token::Seq { len: Some(1) },
+                Token::F64(1.0),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+            "invalid length 1, expected 2 elements",
+        );
+    }
+
+    #[test]
+    fn perm_serialization() {
+        let perm = Perm::<u32>::new_checked(
+            alloc::vec![2, 0, 1].into_boxed_slice(),
+            alloc::vec![1, 2, 0].into_boxed_slice(),
+        );
+        let json = serde_json::to_string(&perm).unwrap();
+        assert!(json == r#"{"version":1,"forward":[2,0,1]}"#);
+        let deserialized = serde_json::from_str::<Perm<u32>>(&json).unwrap();
+        assert!(deserialized.as_ref().arrays() == perm.as_ref().arrays());
+
+        assert!(serde_json::from_str::<Perm<u32>>(r#"{"version":1,"forward":[0,0,1]}"#).is_err());
+        assert!(serde_json::from_str::<Perm<u32>>(r#"{"version":1,"forward":[0,3,1]}"#).is_err());
+        assert!(serde_json::from_str::<Perm<u32>>(r#"{"version":2,"forward":[0]}"#).is_err());
+    }
+}
@@ -9,6 +9,7 @@ use serde::{
     Deserialize, Serialize, Serializer,
 };
 
+use super::{check_version, VERSION};
 use crate::{Mat, MatMut, MatRef};
 
 impl<E: Entity> Serialize for MatRef<'_, E>
@@ -30,8 +31,8 @@ where
                 S: Serializer,
             {
                 let mut seq = s.serialize_seq(Some(self.0.nrows() * self.0.ncols()))?;
-                for i in 0..self.0.nrows() {
-                    for j in 0..self.0.ncols() {
+                for j in 0..self.0.ncols() {
+                    for i in 0..self.0.nrows() {
                         seq.serialize_element(&self.0.read(i, j))?;
                     }
                 }
@@ -39,7 +40,8 @@ where
             }
         }
 
-        let mut structure = s.serialize_struct("Mat", 3)?;
+        let mut structure = s.serialize_struct("Mat", 4)?;
+        structure.serialize_field("version", &VERSION)?;
         structure.serialize_field("nrows", &self.nrows())?;
         structure.serialize_field("ncols", &self.ncols())?;
         structure.serialize_field("data", &MatSequenceSerializer(*self))?;
@@ -82,124 +84,175 @@ where
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "lowercase")]
         enum Field {
+            Version,
             Nrows,
             Ncols,
             Data,
         }
-        const FIELDS: &'static [&'static str] = &["nrows", "ncols", "data"];
-        struct MatVisitor<E: Entity>(PhantomData<E>);
-        impl<'a, E: Entity + Deserialize<'a>> Visitor<'a> for MatVisitor<E> {
-            type Value = Mat<E>;
+        const FIELDS: &'static [&'static str] = &["version", "nrows", "ncols", "data"];
+        enum MatrixOrVec<E: Entity> {
+            Matrix(Mat<E>),
+            Vec(Vec<E>),
+        }
+        impl<E: Entity> MatrixOrVec<E> {
+            // data without a version field was written in row-major order
+            fn into_mat<Err: serde::de::Error>(
+                self,
+                nrows: usize,
+                ncols: usize,
+                row_major: bool,
+            ) -> Result<Mat<E>, Err> {
+                match self {
+                    MatrixOrVec::Matrix(m) => Ok(m),
+                    MatrixOrVec::Vec(v) => {
+                        let expected_length = nrows * ncols;
+                        if v.len() != expected_length {
+                            return Err(Err::invalid_length(
+                                v.len(),
+                                &format!("{} elements", expected_length).as_str(),
+                            ));
+                        }
+                        Ok(if row_major {
+                            Mat::from_fn(nrows, ncols, |i, j| v[i * ncols + j])
+                        } else {
+                            Mat::from_fn(nrows, ncols, |i, j| v[i + j * nrows])
+                        })
+                    }
+                }
+            }
+        }
+        struct MatrixOrVecDeserializer<'a, E: Entity + Deserialize<'a>> {
+            marker: PhantomData<&'a E>,
+            nrows: Option<usize>,
+            ncols: Option<usize>,
+            versioned: bool,
+        }
+        impl<'a, E: Entity + Deserialize<'a>> MatrixOrVecDeserializer<'a, E> {
+            fn new(nrows: Option<usize>, ncols: Option<usize>, versioned: bool) -> Self {
+                Self {
+                    marker: PhantomData,
+                    nrows,
+                    ncols,
+                    versioned,
+                }
+            }
+        }
+        impl<'a, E: Entity> DeserializeSeed<'a> for MatrixOrVecDeserializer<'a, E>
+        where
+            E: Deserialize<'a>,
+        {
+            type Value = MatrixOrVec<E>;
+
+            fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: serde::Deserializer<'a>,
+            {
+                deserializer.deserialize_seq(self)
+            }
+        }
+        impl<'a, E: Entity> Visitor<'a> for MatrixOrVecDeserializer<'a, E>
+        where
+            E: Deserialize<'a>,
+        {
+            type Value = MatrixOrVec<E>;
 
             fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
-                formatter.write_str("a faer matrix")
+                formatter.write_str("a sequence")
             }
 
-            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
             where
-                A: serde::de::MapAccess<'a>,
+                A: SeqAccess<'a>,
             {
-                enum MatrixOrVec<E: Entity> {
-                    Matrix(Mat<E>),
-                    Vec(Vec<E>),
-                }
-                impl<E: Entity> MatrixOrVec<E> {
-                    fn into_mat(self, nrows: usize, ncols: usize) -> Mat<E> {
-                        match self {
-                            MatrixOrVec::Matrix(m) => m,
-                            MatrixOrVec::Vec(v) => {
-                                Mat::from_fn(nrows, ncols, |i, j| v[i * ncols + j])
-                            }
+                // the layout is only known if the version field was seen before the data
+                match (self.ncols, self.nrows, self.versioned) {
+                    (Some(ncols), Some(nrows), true) => {
+                        let mut data = Mat::<E>::with_capacity(nrows, ncols);
+                        unsafe {
+                            data.set_dims(nrows, ncols);
+                        }
+                        let expected_length = nrows * ncols;
+                        for i in 0..expected_length {
+                            let el = seq.next_element::<E>()?.ok_or_else(|| {
+                                serde::de::Error::invalid_length(
+                                    i,
+                                    &format!("{} elements", expected_length).as_str(),
+                                )
+                            })?;
+                            data.write(i % nrows, i / nrows, el);
                         }
+                        let mut additional = 0usize;
+                        while let Some(_) = seq.next_element::<E>()? {
+                            additional += 1;
+                        }
+                        if additional > 0 {
+                            return Err(serde::de::Error::invalid_length(
+                                additional + expected_length,
+                                &format!("{} elements", expected_length).as_str(),
+                            ));
+                        }
+                        Ok(MatrixOrVec::Matrix(data))
                     }
-                }
-                struct MatrixOrVecDeserializer<'a, E: Entity + Deserialize<'a>> {
-                    marker: PhantomData<&'a E>,
-                    nrows: Option<usize>,
-                    ncols: Option<usize>,
-                }
-                impl<'a, E: Entity + Deserialize<'a>> MatrixOrVecDeserializer<'a, E> {
-                    fn new(nrows: Option<usize>, ncols: Option<usize>) -> Self {
-                        Self {
-                            marker: PhantomData,
-                            nrows,
-                            ncols,
+                    _ => {
+                        let mut data = Vec::new();
+                        while let Some(el) = seq.next_element::<E>()? {
+                            data.push(el);
                         }
+                        Ok(MatrixOrVec::Vec(data))
                     }
                 }
-                impl<'a, E: Entity> DeserializeSeed<'a> for MatrixOrVecDeserializer<'a, E>
-                where
-                    E: Deserialize<'a>,
-                {
-                    type Value = MatrixOrVec<E>;
+            }
+        }
+        struct MatVisitor<E: Entity>(PhantomData<E>);
+        impl<'a, E: Entity + Deserialize<'a>> Visitor<'a> for MatVisitor<E> {
+            type Value = Mat<E>;
 
-                    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
-                    where
-                        D: serde::Deserializer<'a>,
-                    {
-                        deserializer.deserialize_seq(self)
-                    }
-                }
-                impl<'a, E: Entity> Visitor<'a> for MatrixOrVecDeserializer<'a, E>
-                where
-                    E: Deserialize<'a>,
-                {
-                    type Value = MatrixOrVec<E>;
+            fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
+                formatter.write_str("a faer matrix")
+            }
 
-                    fn expecting(
-                        &self,
-                        formatter: &mut alloc::fmt::Formatter,
-                    ) -> alloc::fmt::Result {
-                        formatter.write_str("a sequence")
-                    }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'a>,
+            {
+                let version: u32 = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                check_version::<A::Error>(version)?;
+                let nrows: usize = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                let ncols: usize = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
+                let data = seq
+                    .next_element_seed(MatrixOrVecDeserializer::<E>::new(
+                        Some(nrows),
+                        Some(ncols),
+                        true,
+                    ))?
+                    .ok_or_else(|| serde::de::Error::invalid_length(3, &self))?;
+                data.into_mat::<A::Error>(nrows, ncols, false)
+            }
 
-                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-                    where
-                        A: SeqAccess<'a>,
-                    {
-                        match (self.ncols, self.nrows) {
-                            (Some(ncols), Some(nrows)) => {
-                                let mut data = Mat::<E>::with_capacity(nrows, ncols);
-                                unsafe {
-                                    data.set_dims(nrows, ncols);
-                                }
-                                let expected_length = nrows * ncols;
-                                for i in 0..expected_length {
-                                    let el = seq.next_element::<E>()?.ok_or_else(|| {
-                                        serde::de::Error::invalid_length(
-                                            i,
-                                            &format!("{} elements", expected_length).as_str(),
-                                        )
-                                    })?;
-                                    data.write(i / ncols, i % ncols, el);
-                                }
-                                let mut additional = 0usize;
-                                while let Some(_) = seq.next_element::<E>()? {
-                                    additional += 1;
-                                }
-                                if additional > 0 {
-                                    return Err(serde::de::Error::invalid_length(
-                                        additional + expected_length,
-                                        &format!("{} elements", expected_length).as_str(),
-                                    ));
-                                }
-                                Ok(MatrixOrVec::Matrix(data))
-                            }
-                            _ => {
-                                let mut data = Vec::new();
-                                while let Some(el) = seq.next_element::<E>()? {
-                                    data.push(el);
-                                }
-                                Ok(MatrixOrVec::Vec(data))
-                            }
-                        }
-                    }
-                }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'a>,
+            {
+                let mut version = None;
                 let mut nrows = None;
                 let mut ncols = None;
                 let mut data: Option<MatrixOrVec<E>> = None;
                 while let Some(key) = map.next_key()? {
                     match key {
+                        Field::Version => {
+                            if version.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            let value = map.next_value()?;
+                            check_version::<A::Error>(value)?;
+                            version = Some(value);
+                        }
                         Field::Nrows => {
                             if nrows.is_some() {
                                 return Err(serde::de::Error::duplicate_field("nrows"));
@@ -219,8 +272,9 @@ where
                                 return Err(serde::de::Error::duplicate_field("data"));
                             }
                             data = Some(map.next_value_seed(MatrixOrVecDeserializer::<E>::new(
-                                nrows.clone(),
-                                ncols.clone(),
+                                nrows,
+                                ncols,
+                                version.is_some(),
                             ))?);
                         }
                     }
@@ -229,7 +283,7 @@ where
                 let ncols = ncols.ok_or_else(|| serde::de::Error::missing_field("ncols"))?;
                 let data = data
                     .ok_or_else(|| serde::de::Error::missing_field("data"))?
-                    .into_mat(nrows, ncols);
+                    .into_mat::<A::Error>(nrows, ncols, version.is_none())?;
                 Ok(data)
             }
         }
@@ -240,7 +294,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
     #[test]
     fn matrix_serialization_normal() {
         let value = Mat::from_fn(3, 4, |i, j| (i + (j * 10)) as f64);
@@ -249,8 +303,10 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Mat",
-                    len: 3,
+                    len: 4,
                 },
+                Token::Str("version"),
+                Token::U32(1),
                 Token::Str("nrows"),
                 Token::U64(3),
                 Token::Str("ncols"),
@@ -258,16 +314,16 @@ mod tests {
                 Token::Str("data"),
                 Token::Seq { len: Some(12) },
                 Token::F64(0.0),
-                Token::F64(10.0),
-                Token::F64(20.0),
-                Token::F64(30.0),
                 Token::F64(1.0),
-                Token::F64(11.0),
-                Token::F64(21.0),
-                Token::F64(31.0),
                 Token::F64(2.0),
+                Token::F64(10.0),
+                Token::F64(11.0),
                 Token::F64(12.0),
+                Token::F64(20.0),
+                Token::F64(21.0),
                 Token::F64(22.0),
+                Token::F64(30.0),
+                Token::F64(31.0),
                 Token::F64(32.0),
                 Token::SeqEnd,
                 Token::StructEnd,
@@ -283,8 +339,10 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Mat",
-                    len: 3,
+                    len: 4,
                 },
+                Token::Str("version"),
+                Token::U32(1),
                 Token::Str("nrows"),
                 Token::U64(12),
                 Token::Str("ncols"),
@@ -317,8 +375,10 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Mat",
-                    len: 3,
+                    len: 4,
                 },
+                Token::Str("version"),
+                Token::U32(1),
                 Token::Str("nrows"),
                 Token::U64(1),
                 Token::Str("ncols"),
@@ -351,8 +411,10 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Mat",
-                    len: 3,
+                    len: 4,
                 },
+                Token::Str("version"),
+                Token::U32(1),
                 Token::Str("nrows"),
                 Token::U64(0),
                 Token::Str("ncols"),
@@ -371,8 +433,10 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Mat",
-                    len: 3,
+                    len: 4,
                 },
+                Token::Str("version"),
+                Token::U32(1),
                 Token::Str("nrows"),
                 Token::U64(3),
                 Token::Str("ncols"),
@@ -400,8 +464,10 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Mat",
-                    len: 3,
+                    len: 4,
                 },
+                Token::Str("version"),
+                Token::U32(1),
                 Token::Str("nrows"),
                 Token::U64(3),
                 Token::Str("ncols"),
@@ -427,4 +493,124 @@ mod tests {
             "invalid length 14, expected 12 elements",
         )
     }
+
+    #[test]
+    fn matrix_deserialization_unversioned() {
+        // data written before the layout was versioned is in row-major order
+        assert_de_tokens(
+            &Mat::from_fn(2, 3, |i, j| (i + (j * 10)) as f64),
+            &[
+                Token::Struct {
+                    name: "Mat",
+                    len: 3,
+                },
+                Token::Str("nrows"),
+                Token::U64(2),
+                Token::Str("ncols"),
+                Token::U64(3),
+                Token::Str("data"),
+                Token::Seq { len: Some(6) },
+                Token::F64(0.0),
+                Token::F64(10.0),
+                Token::F64(20.0),
+                Token::F64(1.0),
+                Token::F64(11.0),
+                Token::F64(21.0),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+        assert_de_tokens_error::<Mat<f64>>(
+            &[
+                Token::Struct {
+                    name: "Mat",
+                    len: 3,
+                },
+                Token::Str("nrows"),
+                Token::U64(2),
+                Token::Str("ncols"),
+                Token::U64(3),
+                Token::Str("data"),
+                Token::Seq { len: Some(1) },
+                Token::F64(0.0),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+            "invalid length 1, expected 6 elements",
+        );
+    }
+
+    #[test]
+    fn matrix_serialization_errors_version() {
+        assert_de_tokens_error::<Mat<f64>>(
+            &[
+                Token::Struct {
+                    name: "Mat",
+                    len: 4,
+                },
+                Token::Str("version"),
+                Token::U32(2),
+            ],
+            "unsupported serialization format version 2, expected 1",
+        )
+    }
+
+    #[test]
+    fn matrix_deserialization_seq() {
+        assert_de_tokens(
+            &Mat::from_fn(2, 3, |i, j| (i + (j * 10)) as f64),
+            &[
+                Token::Seq { len: Some(4) },
+                Token::U32(1),
+                Token::U64(2),
+                Token::U64(3),
+                Token::Seq { len: Some(6) },
+                Token::F64(0.0),
+                Token::F64(1.0),
+                Token::F64(10.0),
+                Token::F64(11.0),
+                Token::F64(20.0),
+                Token::F64(21.0),
+                Token::SeqEnd,
+                Token::SeqEnd,
+            ],
+        );
+        assert_de_tokens_error::<Mat<f64>>(
+            &[
+                Token::Seq { len: Some(4) },
+                Token::U32(1),
+                Token::U64(2),
+                Token::SeqEnd,
+            ],
+            "invalid length 2, expected a faer matrix",
+        );
+    }
+
+    #[test]
+    fn matrix_serialization_bincode() {
+        for (nrows, ncols) in [(0, 0), (3, 4), (12, 1), (1, 12)] {
+            let value = Mat::from_fn(nrows, ncols, |i, j| (i + (j * 10)) as f64);
+            let bytes = bincode::serialize(&value).unwrap();
+            assert_eq!(bincode::deserialize::<Mat<f64>>(&bytes).unwrap(), value);
+        }
+
+        // the version is checked for non self-describing formats too
+        let bytes = bincode::serialize(&(2u32, 0usize, 0usize, Vec::<f64>::new())).unwrap();
+        assert!(bincode::deserialize::<Mat<f64>>(&bytes).is_err());
+    }
+
+    #[test]
+    fn matrix_serialization_json() {
+        let value = Mat::from_fn(3, 2, |i, j| (i + (j * 10)) as f64);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            r#"{"version":1,"nrows":3,"ncols":2,"data":[0.0,1.0,2.0,10.0,11.0,12.0]}"#
+        );
+        assert_eq!(serde_json::from_str::<Mat<f64>>(&json).unwrap(), value);
+
+        // fields may come in any order
+        let json = r#"{"data":[0.0,1.0,2.0,10.0,11.0,12.0],"ncols":2,"nrows":3,"version":1}"#;
+        assert_eq!(serde_json::from_str::<Mat<f64>>(json).unwrap(), value);
+    }
 }
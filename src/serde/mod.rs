@@ -1 +1,16 @@
+mod dense;
 mod mat;
+mod sparse;
+
+/// Version of the serialized layout of the dense types.
+const VERSION: u32 = 1;
+
+fn check_version<E: serde::de::Error>(version: u32) -> Result<(), E> {
+    if version == VERSION {
+        Ok(())
+    } else {
+        Err(E::custom(format_args!(
+            "unsupported serialization format version {version}, expected {VERSION}"
+        )))
+    }
+}
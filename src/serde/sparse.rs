@@ -0,0 +1,367 @@
+//! Serde implementations for SparseColMat and SymbolicSparseColMat
+
+use faer_entity::Entity;
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    sparse::{windows2, SparseColMat, SymbolicSparseColMat},
+    utils::{slice::SliceGroup, vec::VecGroup},
+    Index, SignedIndex,
+};
+
+fn check_symbolic<I: Index>(
+    nrows: usize,
+    ncols: usize,
+    col_ptrs: &[I],
+    nnz_per_col: Option<&[I]>,
+    row_indices: &[I],
+) -> Result<(), &'static str> {
+    if nrows > I::Signed::MAX.zx() || ncols > I::Signed::MAX.zx() {
+        return Err("matrix dimensions exceed the maximum index value");
+    }
+    if col_ptrs.len() != ncols + 1 {
+        return Err("`col_ptrs` must have length `ncols + 1`");
+    }
+    for &[c, c_next] in windows2(col_ptrs) {
+        if c > c_next {
+            return Err("`col_ptrs` must be non-decreasing");
+        }
+    }
+    if col_ptrs[ncols].zx() > row_indices.len() {
+        return Err("`col_ptrs` must be in bounds of `row_indices`");
+    }
+    if let Some(nnz_per_col) = nnz_per_col {
+        if nnz_per_col.len() != ncols {
+            return Err("`nnz_per_col` must have length `ncols`");
+        }
+    }
+
+    for (j, &[c, c_next]) in windows2(col_ptrs).enumerate() {
+        let len = match nnz_per_col {
+            Some(nnz_per_col) => {
+                let nnz_j = nnz_per_col[j];
+                if nnz_j > c_next - c {
+                    return Err("`nnz_per_col[j]` must not exceed the size of the column range");
+                }
+                nnz_j.zx()
+            }
+            None => (c_next - c).zx(),
+        };
+        for &i in &row_indices[c.zx()..c.zx() + len] {
+            if i.zx() >= nrows {
+                return Err("row indices must be less than `nrows`");
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<I: Index> Serialize for SymbolicSparseColMat<I>
+where
+    I: Serialize,
+{
+    fn serialize<S>(&self, s: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        let mut structure = s.serialize_struct("SymbolicSparseColMat", 5)?;
+        structure.serialize_field("nrows", &self.nrows)?;
+        structure.serialize_field("ncols", &self.ncols)?;
+        structure.serialize_field("col_ptrs", &*self.col_ptr)?;
+        structure.serialize_field("nnz_per_col", &self.col_nnz.as_deref())?;
+        structure.serialize_field("row_indices", &*self.row_ind)?;
+        structure.end()
+    }
+}
+
+impl<'a, I: Index> Deserialize<'a> for SymbolicSparseColMat<I>
+where
+    I: Deserialize<'a>,
+{
+    fn deserialize<D>(d: D) -> Result<Self, <D as Deserializer<'a>>::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "SymbolicSparseColMat")]
+        struct Raw<I> {
+            nrows: usize,
+            ncols: usize,
+            col_ptrs: Vec<I>,
+            nnz_per_col: Option<Vec<I>>,
+            row_indices: Vec<I>,
+        }
+
+        let raw = Raw::<I>::deserialize(d)?;
+        check_symbolic(
+            raw.nrows,
+            raw.ncols,
+            &raw.col_ptrs,
+            raw.nnz_per_col.as_deref(),
+            &raw.row_indices,
+        )
+        .map_err(D::Error::custom)?;
+
+        Ok(SymbolicSparseColMat {
+            nrows: raw.nrows,
+            ncols: raw.ncols,
+            col_ptr: raw.col_ptrs,
+            col_nnz: raw.nnz_per_col,
+            row_ind: raw.row_indices,
+        })
+    }
+}
+
+impl<I: Index, E: Entity> Serialize for SparseColMat<I, E>
+where
+    I: Serialize,
+    E: Serialize,
+{
+    fn serialize<S>(&self, s: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        struct ValuesSerializer<'a, E: Entity>(SliceGroup<'a, E>);
+
+        impl<'a, E: Entity> Serialize for ValuesSerializer<'a, E>
+        where
+            E: Serialize,
+        {
+            fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                s.collect_seq((0..self.0.len()).map(|idx| self.0.read(idx)))
+            }
+        }
+
+        let symbolic = &self.symbolic;
+        let mut structure = s.serialize_struct("SparseColMat", 6)?;
+        structure.serialize_field("nrows", &symbolic.nrows)?;
+        structure.serialize_field("ncols", &symbolic.ncols)?;
+        structure.serialize_field("col_ptrs", &*symbolic.col_ptr)?;
+        structure.serialize_field("nnz_per_col", &symbolic.col_nnz.as_deref())?;
+        structure.serialize_field("row_indices", &*symbolic.row_ind)?;
+        structure.serialize_field("values", &ValuesSerializer(self.values.as_slice()))?;
+        structure.end()
+    }
+}
+
+impl<'a, I: Index, E: Entity> Deserialize<'a> for SparseColMat<I, E>
+where
+    I: Deserialize<'a>,
+    E: Deserialize<'a>,
+{
+    fn deserialize<D>(d: D) -> Result<Self, <D as Deserializer<'a>>::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "SparseColMat")]
+        struct Raw<I, E> {
+            nrows: usize,
+            ncols: usize,
+            col_ptrs: Vec<I>,
+            nnz_per_col: Option<Vec<I>>,
+            row_indices: Vec<I>,
+            values: Vec<E>,
+        }
+
+        let raw = Raw::<I, E>::deserialize(d)?;
+        check_symbolic(
+            raw.nrows,
+            raw.ncols,
+            &raw.col_ptrs,
+            raw.nnz_per_col.as_deref(),
+            &raw.row_indices,
+        )
+        .map_err(D::Error::custom)?;
+        if raw.values.len() != raw.row_indices.len() {
+            return Err(D::Error::custom(
+                "`values` must have the same length as `row_indices`",
+            ));
+        }
+
+        let mut values = VecGroup::<E>::new();
+        values.reserve_exact(raw.values.len());
+        for value in raw.values {
+            values.push(value.faer_into_units());
+        }
+
+        Ok(SparseColMat {
+            symbolic: SymbolicSparseColMat {
+                nrows: raw.nrows,
+                ncols: raw.ncols,
+                col_ptr: raw.col_ptrs,
+                col_nnz: raw.nnz_per_col,
+                row_ind: raw.row_indices,
+            },
+            values,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mat;
+    use serde_test::{assert_de_tokens_error, assert_ser_tokens, Token};
+
+    #[test]
+    fn sparse_serialization_normal() {
+        let value = SparseColMat::<usize, f64>::try_new_from_triplets(
+            3,
+            2,
+            &[(0, 0, 1.0), (2, 0, 2.0), (1, 1, 3.0)],
+        )
+        .unwrap();
+        assert_ser_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "SparseColMat",
+                    len: 6,
+                },
+                Token::Str("nrows"),
+                Token::U64(3),
+                Token::Str("ncols"),
+                Token::U64(2),
+                Token::Str("col_ptrs"),
+                Token::Seq { len: Some(3) },
+                Token::U64(0),
+                Token::U64(2),
+                Token::U64(3),
+                Token::SeqEnd,
+                Token::Str("nnz_per_col"),
+                Token::None,
+                Token::Str("row_indices"),
+                Token::Seq { len: Some(3) },
+                Token::U64(0),
+                Token::U64(2),
+                Token::U64(1),
+                Token::SeqEnd,
+                Token::Str("values"),
+                Token::Seq { len: Some(3) },
+                Token::F64(1.0),
+                Token::F64(2.0),
+                Token::F64(3.0),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+        assert_ser_tokens(
+            value.symbolic(),
+            &[
+                Token::Struct {
+                    name: "SymbolicSparseColMat",
+                    len: 5,
+                },
+                Token::Str("nrows"),
+                Token::U64(3),
+                Token::Str("ncols"),
+                Token::U64(2),
+                Token::Str("col_ptrs"),
+                Token::Seq { len: Some(3) },
+                Token::U64(0),
+                Token::U64(2),
+                Token::U64(3),
+                Token::SeqEnd,
+                Token::Str("nnz_per_col"),
+                Token::None,
+                Token::Str("row_indices"),
+                Token::Seq { len: Some(3) },
+                Token::U64(0),
+                Token::U64(2),
+                Token::U64(1),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn sparse_serialization_roundtrip() {
+        let value = SparseColMat::<u32, f64>::try_new_from_triplets(
+            4,
+            3,
+            &[(0, 0, 1.0), (3, 0, -2.0), (1, 2, 3.5), (2, 2, 4.0)],
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&value).unwrap();
+        let deserialized: SparseColMat<u32, f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.col_ptrs(), value.col_ptrs());
+        assert_eq!(deserialized.row_indices(), value.row_indices());
+        assert_eq!(deserialized.to_dense(), value.to_dense());
+
+        let json = serde_json::to_string(value.symbolic()).unwrap();
+        let deserialized: SymbolicSparseColMat<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.col_ptrs(), value.col_ptrs());
+        assert_eq!(deserialized.row_indices(), value.row_indices());
+
+        let empty = SparseColMat::<u32, f64>::try_new_from_triplets(0, 0, &[]).unwrap();
+        let json = serde_json::to_string(&empty).unwrap();
+        let deserialized: SparseColMat<u32, f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.to_dense(), Mat::<f64>::new());
+    }
+
+    #[test]
+    fn sparse_serialization_errors_invalid_pattern() {
+        assert_de_tokens_error::<SparseColMat<usize, f64>>(
+            &[
+                Token::Struct {
+                    name: "SparseColMat",
+                    len: 6,
+                },
+                Token::Str("nrows"),
+                Token::U64(2),
+                Token::Str("ncols"),
+                Token::U64(1),
+                Token::Str("col_ptrs"),
+                Token::Seq { len: Some(2) },
+                Token::U64(0),
+                Token::U64(1),
+                Token::SeqEnd,
+                Token::Str("nnz_per_col"),
+                Token::None,
+                Token::Str("row_indices"),
+                Token::Seq { len: Some(1) },
+                Token::U64(2),
+                Token::SeqEnd,
+                Token::Str("values"),
+                Token::Seq { len: Some(1) },
+                Token::F64(1.0),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+            "row indices must be less than `nrows`",
+        );
+        assert_de_tokens_error::<SymbolicSparseColMat<usize>>(
+            &[
+                Token::Struct {
+                    name: "SymbolicSparseColMat",
+                    len: 5,
+                },
+                Token::Str("nrows"),
+                Token::U64(2),
+                Token::Str("ncols"),
+                Token::U64(2),
+                Token::Str("col_ptrs"),
+                Token::Seq { len: Some(3) },
+                Token::U64(0),
+                Token::U64(2),
+                Token::U64(1),
+                Token::SeqEnd,
+                Token::Str("nnz_per_col"),
+                Token::None,
+                Token::Str("row_indices"),
+                Token::Seq { len: Some(2) },
+                Token::U64(0),
+                Token::U64(1),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+            "`col_ptrs` must be non-decreasing",
+        );
+    }
+}
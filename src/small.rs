@@ -0,0 +1,656 @@
+//! Matrices and column vectors whose dimensions are known at compile time.
+//!
+//! [`MatN`] and [`ColN`] store their elements inline in column-major order, so they can live on
+//! the stack and be copied around freely, which avoids the heap allocation required by [`Mat`] in
+//! tight loops, such as the ones that appear in geometry or robotics code. They can be viewed as
+//! [`MatRef`]/[`ColRef`] to be used with the rest of the library.
+//!
+//! The arithmetic kernels are written with loops over the compile time dimensions, which the
+//! compiler fully unrolls for small sizes. The inverse uses closed form expressions for dimensions
+//! `2`, `3` and `4`.
+
+use crate::{
+    assert,
+    col::{ColMut, ColRef},
+    mat::{Mat, MatMut, MatRef},
+    ComplexField, SimpleEntity,
+};
+use core::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// Matrix with `M` rows and `N` columns, stored inline in column-major order.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MatN<E: SimpleEntity, const M: usize, const N: usize> {
+    cols: [[E; M]; N],
+}
+
+/// Column vector with `M` rows, stored inline.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ColN<E: SimpleEntity, const M: usize> {
+    data: [E; M],
+}
+
+impl<E: SimpleEntity, const M: usize, const N: usize> MatN<E, M, N> {
+    /// Creates a matrix from its rows, so that the literal `[[a, b], [c, d]]` corresponds to the
+    /// matrix with first row `[a, b]` and second row `[c, d]`.
+    #[inline]
+    pub fn from_rows(rows: [[E; N]; M]) -> Self {
+        Self::from_fn(|i, j| rows[i][j])
+    }
+
+    /// Creates a matrix from its columns.
+    #[inline]
+    pub fn from_cols(cols: [[E; M]; N]) -> Self {
+        Self { cols }
+    }
+
+    /// Creates a matrix whose entry at `(i, j)` is `f(i, j)`.
+    #[inline]
+    pub fn from_fn(f: impl Fn(usize, usize) -> E) -> Self {
+        Self {
+            cols: core::array::from_fn(|j| core::array::from_fn(|i| f(i, j))),
+        }
+    }
+
+    /// Copies the contents of `mat` into a new matrix.
+    ///
+    /// # Panics
+    /// Panics if `mat.nrows() != M` or `mat.ncols() != N`.
+    #[inline]
+    #[track_caller]
+    pub fn from_mat_ref(mat: MatRef<'_, E>) -> Self {
+        assert!(all(mat.nrows() == M, mat.ncols() == N));
+        Self::from_fn(|i, j| mat.read(i, j))
+    }
+
+    /// Returns the number of rows of the matrix.
+    #[inline(always)]
+    pub fn nrows(&self) -> usize {
+        M
+    }
+
+    /// Returns the number of columns of the matrix.
+    #[inline(always)]
+    pub fn ncols(&self) -> usize {
+        N
+    }
+
+    /// Returns the columns of the matrix.
+    #[inline(always)]
+    pub fn into_cols(self) -> [[E; M]; N] {
+        self.cols
+    }
+
+    /// Reads the value at the given indices.
+    ///
+    /// # Panics
+    /// Panics if `row >= M` or `col >= N`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn read(&self, row: usize, col: usize) -> E {
+        self.cols[col][row]
+    }
+
+    /// Writes the value to the given indices.
+    ///
+    /// # Panics
+    /// Panics if `row >= M` or `col >= N`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn write(&mut self, row: usize, col: usize, value: E) {
+        self.cols[col][row] = value;
+    }
+
+    /// Returns a view over the matrix.
+    #[inline]
+    pub fn as_ref(&self) -> MatRef<'_, E> {
+        // SAFETY: `[[E; M]; N]` has the same layout as `[E; M * N]`
+        let data = unsafe { core::slice::from_raw_parts(self.cols.as_ptr() as *const E, M * N) };
+        crate::mat::from_column_major_slice::<E>(data, M, N)
+    }
+
+    /// Returns a mutable view over the matrix.
+    #[inline]
+    pub fn as_mut(&mut self) -> MatMut<'_, E> {
+        // SAFETY: `[[E; M]; N]` has the same layout as `[E; M * N]`
+        let data =
+            unsafe { core::slice::from_raw_parts_mut(self.cols.as_mut_ptr() as *mut E, M * N) };
+        crate::mat::from_column_major_slice_mut::<E>(data, M, N)
+    }
+
+    /// Returns a view over the `j`-th column of the matrix.
+    ///
+    /// # Panics
+    /// Panics if `j >= N`.
+    #[inline]
+    #[track_caller]
+    pub fn col(&self, j: usize) -> ColN<E, M> {
+        ColN { data: self.cols[j] }
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> MatN<E, N, M> {
+        MatN::from_fn(|i, j| self.read(j, i))
+    }
+
+    /// Copies the matrix into a heap allocated matrix.
+    #[inline]
+    pub fn to_mat(&self) -> Mat<E>
+    where
+        E: ComplexField,
+    {
+        self.as_ref().to_owned()
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize, const N: usize> MatN<E, M, N> {
+    /// Returns a matrix filled with zeros.
+    #[inline]
+    pub fn zeros() -> Self {
+        Self {
+            cols: [[E::faer_zero(); M]; N],
+        }
+    }
+
+    /// Returns a matrix with ones on the diagonal and zeros elsewhere.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_fn(|i, j| {
+            if i == j {
+                E::faer_one()
+            } else {
+                E::faer_zero()
+            }
+        })
+    }
+
+    /// Returns the adjoint (conjugate transpose) of `self`.
+    #[inline]
+    pub fn adjoint(&self) -> MatN<E, N, M> {
+        MatN::from_fn(|i, j| self.read(j, i).faer_conj())
+    }
+
+    /// Returns `self` with each element multiplied by `factor`.
+    #[inline]
+    pub fn scale(&self, factor: E) -> Self {
+        Self::from_fn(|i, j| self.read(i, j).faer_mul(factor))
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const N: usize> MatN<E, N, N> {
+    /// Returns the determinant of `self`.
+    pub fn determinant(&self) -> E {
+        let a = |i: usize, j: usize| self.read(i, j);
+        match N {
+            0 => E::faer_one(),
+            1 => a(0, 0),
+            2 => a(0, 0)
+                .faer_mul(a(1, 1))
+                .faer_sub(a(0, 1).faer_mul(a(1, 0))),
+            3 => (0..3).fold(E::faer_zero(), |acc, j| {
+                acc.faer_add(a(0, j).faer_mul(cofactor3(a, 0, j)))
+            }),
+            _ => {
+                let mut lu = *self;
+                let (det, _) = lu_in_place(&mut lu);
+                det
+            }
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// If `self` is singular, the result contains non-finite values.
+    pub fn inverse(&self) -> Self {
+        let a = |i: usize, j: usize| self.read(i, j);
+        match N {
+            0 => *self,
+            1 => Self::from_fn(|_, _| a(0, 0).faer_inv()),
+            2 => {
+                let inv_det = self.determinant().faer_inv();
+                Self::from_rows_slice(&[a(1, 1), a(0, 1).faer_neg(), a(1, 0).faer_neg(), a(0, 0)])
+                    .scale(inv_det)
+            }
+            3 => {
+                // the inverse is the transposed matrix of cofactors divided by the determinant
+                let inv_det = self.determinant().faer_inv();
+                Self::from_fn(|i, j| cofactor3(a, j, i).faer_mul(inv_det))
+            }
+            4 => Self::from_rows_slice(&inverse4(a)),
+            _ => {
+                let mut lu = *self;
+                let (_, perm) = lu_in_place(&mut lu);
+                let mut inv = Self::zeros();
+                for i in 0..N {
+                    inv.write(i, perm[i], E::faer_one());
+                }
+                lu_solve(&lu, &mut inv.cols);
+                inv
+            }
+        }
+    }
+
+    /// Returns the solution of the equation `self * X = rhs`.
+    ///
+    /// If `self` is singular, the solution contains non-finite values.
+    pub fn solve<const K: usize>(&self, rhs: &MatN<E, N, K>) -> MatN<E, N, K> {
+        if N <= 4 {
+            self.inverse() * *rhs
+        } else {
+            let mut lu = *self;
+            let (_, perm) = lu_in_place(&mut lu);
+            let mut sol = MatN::<E, N, K>::from_fn(|i, j| rhs.read(perm[i], j));
+            lu_solve(&lu, &mut sol.cols);
+            sol
+        }
+    }
+
+    // `data` holds `N * N` elements in row-major order
+    #[inline(always)]
+    fn from_rows_slice(data: &[E]) -> Self {
+        Self::from_fn(|i, j| data[i * N + j])
+    }
+}
+
+// signed cofactor of the entry at `(i, j)` of a 3×3 matrix
+#[inline(always)]
+fn cofactor3<E: ComplexField>(a: impl Fn(usize, usize) -> E, i: usize, j: usize) -> E {
+    let (i0, i1) = ((i + 1) % 3, (i + 2) % 3);
+    let (j0, j1) = ((j + 1) % 3, (j + 2) % 3);
+    // the cyclic ordering of the remaining indices takes care of the sign
+    a(i0, j0)
+        .faer_mul(a(i1, j1))
+        .faer_sub(a(i0, j1).faer_mul(a(i1, j0)))
+}
+
+// returns the inverse of a 4×4 matrix in row-major order, using the expansion of the determinant
+// in terms of the 2×2 minors of the first two rows and the last two rows
+#[inline(always)]
+fn inverse4<E: ComplexField>(a: impl Fn(usize, usize) -> E) -> [E; 16] {
+    let minor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+        a(r0, c0)
+            .faer_mul(a(r1, c1))
+            .faer_sub(a(r1, c0).faer_mul(a(r0, c1)))
+    };
+    let s0 = minor(0, 1, 0, 1);
+    let s1 = minor(0, 1, 0, 2);
+    let s2 = minor(0, 1, 0, 3);
+    let s3 = minor(0, 1, 1, 2);
+    let s4 = minor(0, 1, 1, 3);
+    let s5 = minor(0, 1, 2, 3);
+    let c0 = minor(2, 3, 0, 1);
+    let c1 = minor(2, 3, 0, 2);
+    let c2 = minor(2, 3, 0, 3);
+    let c3 = minor(2, 3, 1, 2);
+    let c4 = minor(2, 3, 1, 3);
+    let c5 = minor(2, 3, 2, 3);
+
+    let det = s0
+        .faer_mul(c5)
+        .faer_sub(s1.faer_mul(c4))
+        .faer_add(s2.faer_mul(c3))
+        .faer_add(s3.faer_mul(c2))
+        .faer_sub(s4.faer_mul(c1))
+        .faer_add(s5.faer_mul(c0));
+    let inv_det = det.faer_inv();
+
+    // `x * p - y * q + z * r`
+    let comb = |x: E, p: E, y: E, q: E, z: E, r: E| {
+        x.faer_mul(p)
+            .faer_sub(y.faer_mul(q))
+            .faer_add(z.faer_mul(r))
+            .faer_mul(inv_det)
+    };
+    let neg = |x: E| x.faer_neg();
+
+    [
+        comb(a(1, 1), c5, a(1, 2), c4, a(1, 3), c3),
+        neg(comb(a(0, 1), c5, a(0, 2), c4, a(0, 3), c3)),
+        comb(a(3, 1), s5, a(3, 2), s4, a(3, 3), s3),
+        neg(comb(a(2, 1), s5, a(2, 2), s4, a(2, 3), s3)),
+        neg(comb(a(1, 0), c5, a(1, 2), c2, a(1, 3), c1)),
+        comb(a(0, 0), c5, a(0, 2), c2, a(0, 3), c1),
+        neg(comb(a(3, 0), s5, a(3, 2), s2, a(3, 3), s1)),
+        comb(a(2, 0), s5, a(2, 2), s2, a(2, 3), s1),
+        comb(a(1, 0), c4, a(1, 1), c2, a(1, 3), c0),
+        neg(comb(a(0, 0), c4, a(0, 1), c2, a(0, 3), c0)),
+        comb(a(3, 0), s4, a(3, 1), s2, a(3, 3), s0),
+        neg(comb(a(2, 0), s4, a(2, 1), s2, a(2, 3), s0)),
+        neg(comb(a(1, 0), c3, a(1, 1), c1, a(1, 2), c0)),
+        comb(a(0, 0), c3, a(0, 1), c1, a(0, 2), c0),
+        neg(comb(a(3, 0), s3, a(3, 1), s1, a(3, 2), s0)),
+        comb(a(2, 0), s3, a(2, 1), s1, a(2, 2), s0),
+    ]
+}
+
+// computes the LU factorization with partial pivoting `P * A = L * U` in place, and returns the
+// determinant of `A` along with the row permutation, such that the `i`-th row of `P * A` is the
+// `perm[i]`-th row of `A`
+fn lu_in_place<E: ComplexField + SimpleEntity, const N: usize>(
+    a: &mut MatN<E, N, N>,
+) -> (E, [usize; N]) {
+    let mut perm: [usize; N] = core::array::from_fn(|i| i);
+    let mut det = E::faer_one();
+
+    for k in 0..N {
+        let mut pivot = k;
+        let mut pivot_abs = a.read(k, k).faer_abs();
+        for i in k + 1..N {
+            let abs = a.read(i, k).faer_abs();
+            if abs > pivot_abs {
+                pivot = i;
+                pivot_abs = abs;
+            }
+        }
+        if pivot != k {
+            perm.swap(k, pivot);
+            for j in 0..N {
+                a.cols[j].swap(k, pivot);
+            }
+            det = det.faer_neg();
+        }
+
+        let d = a.read(k, k);
+        det = det.faer_mul(d);
+        let inv = d.faer_inv();
+        for i in k + 1..N {
+            a.write(i, k, a.read(i, k).faer_mul(inv));
+        }
+        for j in k + 1..N {
+            let u_kj = a.read(k, j);
+            for i in k + 1..N {
+                a.write(i, j, a.read(i, j).faer_sub(a.read(i, k).faer_mul(u_kj)));
+            }
+        }
+    }
+
+    (det, perm)
+}
+
+// solves `L * U * X = rhs` in place, where `lu` holds the unit lower triangular factor `L` and the
+// upper triangular factor `U`
+fn lu_solve<E: ComplexField + SimpleEntity, const N: usize, const K: usize>(
+    lu: &MatN<E, N, N>,
+    rhs: &mut [[E; N]; K],
+) {
+    for x in rhs.iter_mut() {
+        for j in 0..N {
+            for i in j + 1..N {
+                x[i] = x[i].faer_sub(lu.read(i, j).faer_mul(x[j]));
+            }
+        }
+        for j in (0..N).rev() {
+            x[j] = x[j].faer_mul(lu.read(j, j).faer_inv());
+            for i in 0..j {
+                x[i] = x[i].faer_sub(lu.read(i, j).faer_mul(x[j]));
+            }
+        }
+    }
+}
+
+impl<E: SimpleEntity, const M: usize> ColN<E, M> {
+    /// Creates a column vector from its elements.
+    #[inline(always)]
+    pub fn new(data: [E; M]) -> Self {
+        Self { data }
+    }
+
+    /// Creates a column vector whose `i`-th element is `f(i)`.
+    #[inline]
+    pub fn from_fn(f: impl Fn(usize) -> E) -> Self {
+        Self {
+            data: core::array::from_fn(f),
+        }
+    }
+
+    /// Copies the contents of `col` into a new column vector.
+    ///
+    /// # Panics
+    /// Panics if `col.nrows() != M`.
+    #[inline]
+    #[track_caller]
+    pub fn from_col_ref(col: ColRef<'_, E>) -> Self {
+        assert!(col.nrows() == M);
+        Self::from_fn(|i| col.read(i))
+    }
+
+    /// Returns the number of rows of the column vector.
+    #[inline(always)]
+    pub fn nrows(&self) -> usize {
+        M
+    }
+
+    /// Returns the elements of the column vector.
+    #[inline(always)]
+    pub fn into_array(self) -> [E; M] {
+        self.data
+    }
+
+    /// Reads the value at the given index.
+    ///
+    /// # Panics
+    /// Panics if `row >= M`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn read(&self, row: usize) -> E {
+        self.data[row]
+    }
+
+    /// Writes the value to the given index.
+    ///
+    /// # Panics
+    /// Panics if `row >= M`.
+    #[inline(always)]
+    #[track_caller]
+    pub fn write(&mut self, row: usize, value: E) {
+        self.data[row] = value;
+    }
+
+    /// Returns a view over the column vector.
+    #[inline]
+    pub fn as_ref(&self) -> ColRef<'_, E> {
+        crate::col::from_slice::<E>(&self.data)
+    }
+
+    /// Returns a mutable view over the column vector.
+    #[inline]
+    pub fn as_mut(&mut self) -> ColMut<'_, E> {
+        crate::col::from_slice_mut::<E>(&mut self.data)
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize> ColN<E, M> {
+    /// Returns a column vector filled with zeros.
+    #[inline]
+    pub fn zeros() -> Self {
+        Self {
+            data: [E::faer_zero(); M],
+        }
+    }
+
+    /// Returns the dot product `self^H * rhs`.
+    #[inline]
+    pub fn dot(&self, rhs: &Self) -> E {
+        (0..M).fold(E::faer_zero(), |acc, i| {
+            acc.faer_add(self.data[i].faer_conj().faer_mul(rhs.data[i]))
+        })
+    }
+
+    /// Returns the Euclidean norm of the column vector.
+    #[inline]
+    pub fn norm_l2(&self) -> E::Real {
+        (0..M)
+            .fold(E::Real::faer_zero(), |acc, i| {
+                acc.faer_add(self.data[i].faer_abs2())
+            })
+            .faer_sqrt()
+    }
+}
+
+impl<E: SimpleEntity, const M: usize, const N: usize> Index<(usize, usize)> for MatN<E, M, N> {
+    type Output = E;
+
+    #[inline(always)]
+    #[track_caller]
+    fn index(&self, (row, col): (usize, usize)) -> &E {
+        &self.cols[col][row]
+    }
+}
+
+impl<E: SimpleEntity, const M: usize, const N: usize> IndexMut<(usize, usize)> for MatN<E, M, N> {
+    #[inline(always)]
+    #[track_caller]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut E {
+        &mut self.cols[col][row]
+    }
+}
+
+impl<E: SimpleEntity, const M: usize> Index<usize> for ColN<E, M> {
+    type Output = E;
+
+    #[inline(always)]
+    #[track_caller]
+    fn index(&self, row: usize) -> &E {
+        &self.data[row]
+    }
+}
+
+impl<E: SimpleEntity, const M: usize> IndexMut<usize> for ColN<E, M> {
+    #[inline(always)]
+    #[track_caller]
+    fn index_mut(&mut self, row: usize) -> &mut E {
+        &mut self.data[row]
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize, const N: usize> Add for MatN<E, M, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_fn(|i, j| self.read(i, j).faer_add(rhs.read(i, j)))
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize, const N: usize> Sub for MatN<E, M, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_fn(|i, j| self.read(i, j).faer_sub(rhs.read(i, j)))
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize, const N: usize> Neg for MatN<E, M, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_fn(|i, j| self.read(i, j).faer_neg())
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize, const K: usize, const N: usize>
+    Mul<MatN<E, K, N>> for MatN<E, M, K>
+{
+    type Output = MatN<E, M, N>;
+
+    #[inline]
+    fn mul(self, rhs: MatN<E, K, N>) -> Self::Output {
+        MatN::from_fn(|i, j| {
+            (0..K).fold(E::faer_zero(), |acc, k| {
+                acc.faer_add(self.read(i, k).faer_mul(rhs.read(k, j)))
+            })
+        })
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize, const N: usize> Mul<ColN<E, N>>
+    for MatN<E, M, N>
+{
+    type Output = ColN<E, M>;
+
+    #[inline]
+    fn mul(self, rhs: ColN<E, N>) -> Self::Output {
+        ColN::from_fn(|i| {
+            (0..N).fold(E::faer_zero(), |acc, k| {
+                acc.faer_add(self.read(i, k).faer_mul(rhs.read(k)))
+            })
+        })
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize> Add for ColN<E, M> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_fn(|i| self.read(i).faer_add(rhs.read(i)))
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize> Sub for ColN<E, M> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_fn(|i| self.read(i).faer_sub(rhs.read(i)))
+    }
+}
+
+impl<E: ComplexField + SimpleEntity, const M: usize> Neg for ColN<E, M> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_fn(|i| self.read(i).faer_neg())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+    use rand::prelude::*;
+
+    fn check_square<const N: usize>(rng: &mut StdRng) {
+        let a = MatN::<c64, N, N>::from_fn(|i, j| {
+            c64::new(rng.gen::<f64>() + if i == j { 2.0 } else { 0.0 }, rng.gen())
+        });
+        let b = MatN::<c64, N, 3>::from_fn(|_, _| c64::new(rng.gen(), rng.gen()));
+        let dense = a.to_mat();
+
+        let id = Mat::<c64>::identity(N, N);
+        assert!((a.inverse().as_ref() * &dense - &id).norm_max() < 1e-10);
+        assert!((&dense * a.solve(&b).as_ref() - b.as_ref()).norm_max() < 1e-10);
+        assert!((a.determinant() - dense.determinant()).faer_abs() < 1e-10);
+        assert!(((a * b).as_ref() - &dense * b.as_ref()).norm_max() < 1e-10);
+    }
+
+    #[test]
+    fn test_small_square() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        check_square::<1>(rng);
+        check_square::<2>(rng);
+        check_square::<3>(rng);
+        check_square::<4>(rng);
+        check_square::<6>(rng);
+    }
+
+    #[test]
+    fn test_small_views() {
+        let mut a = MatN::<f64, 2, 3>::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert!(a[(1, 0)] == 4.0);
+        assert!(a.as_ref() == crate::mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        a.as_mut().write(0, 2, -1.0);
+        assert!(a.read(0, 2) == -1.0);
+        assert!(MatN::<f64, 2, 3>::from_mat_ref(a.as_ref()) == a);
+        assert!(a.transpose().transpose() == a);
+
+        let x = ColN::new([1.0, 0.0, 2.0]);
+        let y = a * x;
+        assert!(y.as_ref() == crate::col![-1.0, 16.0]);
+        assert!(x.dot(&x) == 5.0);
+        assert!(x.norm_l2() == 5.0f64.sqrt());
+    }
+}
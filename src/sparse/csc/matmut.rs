@@ -323,6 +323,33 @@ impl<'a, I: Index, E: Entity> SparseColMatMut<'a, I, E> {
         self.symbolic.compute_nnz()
     }
 
+    /// Returns the maximum of the absolute values of the entries of `self`.
+    #[inline]
+    pub fn norm_max(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        crate::sparse::linalg::reductions::norm_max(self.rb(), get_global_parallelism())
+    }
+
+    /// Returns the sum of the absolute values of the entries of `self`.
+    #[inline]
+    pub fn norm_l1(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        crate::sparse::linalg::reductions::norm_l1(self.rb(), get_global_parallelism())
+    }
+
+    /// Returns the Frobenius norm of `self`.
+    #[inline]
+    pub fn norm_l2(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        crate::sparse::linalg::reductions::norm_l2(self.rb(), get_global_parallelism())
+    }
+
     /// Returns the column pointers.
     #[inline]
     pub fn col_ptrs(&self) -> &'a [I] {
@@ -313,6 +313,33 @@ impl<I: Index, E: Entity> SparseRowMat<I, E> {
         self.symbolic.compute_nnz()
     }
 
+    /// Returns the maximum of the absolute values of the entries of `self`.
+    #[inline]
+    pub fn norm_max(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        crate::sparse::linalg::reductions::norm_max(self.transpose(), get_global_parallelism())
+    }
+
+    /// Returns the sum of the absolute values of the entries of `self`.
+    #[inline]
+    pub fn norm_l1(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        crate::sparse::linalg::reductions::norm_l1(self.transpose(), get_global_parallelism())
+    }
+
+    /// Returns the Frobenius norm of `self`.
+    #[inline]
+    pub fn norm_l2(&self) -> E::Real
+    where
+        E: ComplexField,
+    {
+        crate::sparse::linalg::reductions::norm_l2(self.transpose(), get_global_parallelism())
+    }
+
     /// Returns the column pointers.
     #[inline]
     pub fn row_ptrs(&self) -> &'_ [I] {
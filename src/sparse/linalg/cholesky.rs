@@ -1249,6 +1249,347 @@ pub mod simplicial {
         .unwrap()
     }
 
+    fn merge_sorted_indices<I: Index>(dst: &mut [I], lhs: &[I], rhs: &[I]) -> usize {
+        let mut i = 0;
+        let mut j = 0;
+        let mut k = 0;
+        while i < lhs.len() && j < rhs.len() {
+            let l = lhs[i];
+            let r = rhs[j];
+            dst[k] = if l.zx() < r.zx() {
+                i += 1;
+                l
+            } else if r.zx() < l.zx() {
+                j += 1;
+                r
+            } else {
+                i += 1;
+                j += 1;
+                l
+            };
+            k += 1;
+        }
+        for &l in &lhs[i..] {
+            dst[k] = l;
+            k += 1;
+        }
+        for &r in &rhs[j..] {
+            dst[k] = r;
+            k += 1;
+        }
+        k
+    }
+
+    fn updown_initial_pattern<I: Index>(
+        pattern: &mut [I],
+        w: SymbolicSparseColMatRef<'_, I>,
+        visited: &mut [I::Signed],
+    ) -> usize {
+        let none = I::Signed::truncate(NONE);
+        mem::fill_none(visited);
+        let mut len = 0;
+        for &i in w.row_indices_of_col_raw(0) {
+            let i_ = i.zx();
+            if visited[i_] == none {
+                visited[i_] = I::Signed::truncate(0);
+                pattern[len] = i;
+                len += 1;
+            }
+        }
+        pattern[..len].sort_unstable();
+        len
+    }
+
+    /// Computes the size and alignment of the workspace required to compute the symbolic
+    /// structure of a simplicial Cholesky factor of dimension `n` after a rank-1 update or
+    /// downdate.
+    pub fn factorize_simplicial_symbolic_updown_req<I: Index>(
+        n: usize,
+    ) -> Result<StackReq, SizeOverflow> {
+        let n_req = StackReq::try_new::<I>(n)?;
+        StackReq::try_all_of([n_req, n_req, n_req])
+    }
+
+    /// Computes the symbolic structure of the Cholesky factor of $A + \alpha w w^H$, given the
+    /// symbolic structure of the Cholesky factor of $A$, where `w` is a sparse column vector.
+    ///
+    /// Only the columns along the path from the first nonzero row of `w` to the root of the
+    /// elimination tree are affected. If the existing structure already contains the structure of
+    /// the updated factor, `None` is returned and the existing structure can be used as is.
+    /// Otherwise, the numeric values can be moved to the new structure with
+    /// [`extend_simplicial_numeric`].
+    ///
+    /// # Panics
+    /// Panics if `w` doesn't have the same number of rows as the factor, or if it has more than
+    /// one column.
+    pub fn factorize_simplicial_symbolic_updown<I: Index>(
+        symbolic: &SymbolicSimplicialCholesky<I>,
+        w: SymbolicSparseColMatRef<'_, I>,
+        stack: PodStack<'_>,
+    ) -> Result<Option<SymbolicSimplicialCholesky<I>>, FaerError> {
+        let n = symbolic.nrows();
+        assert!(w.nrows() == n);
+        assert!(w.ncols() == 1);
+
+        let L_col_ptrs = &*symbolic.col_ptrs;
+        let L_row_indices = &*symbolic.row_indices;
+
+        let (visited, stack) = stack.make_raw::<I::Signed>(n);
+        let (pattern, stack) = stack.make_raw::<I>(n);
+        let (merged, _) = stack.make_raw::<I>(n);
+
+        // each column on the path gains the pattern of the update vector at that step, which is
+        // itself the off-diagonal pattern of the previously updated column
+        let mut growth = 0usize;
+        let mut len = updown_initial_pattern(pattern, w, visited);
+        while len > 0 {
+            let j = pattern[0].zx();
+            let old = &L_row_indices[L_col_ptrs[j].zx()..L_col_ptrs[j + 1].zx()];
+            let merged_len = merge_sorted_indices(merged, old, &pattern[..len]);
+            growth += merged_len - old.len();
+
+            len = merged_len - 1;
+            pattern[..len].copy_from_slice(&merged[1..merged_len]);
+        }
+
+        if growth == 0 {
+            return Ok(None);
+        }
+
+        let l_nnz = L_row_indices
+            .len()
+            .checked_add(growth)
+            .ok_or(FaerError::IndexOverflow)?;
+        if l_nnz > I::Signed::MAX.zx() {
+            return Err(FaerError::IndexOverflow);
+        }
+
+        let mut new_col_ptrs = try_zeroed::<I>(n + 1)?;
+        let mut new_row_indices = try_zeroed::<I>(l_nnz)?;
+        let mut new_etree = try_collect(symbolic.etree.iter().copied())?;
+
+        let mut len = updown_initial_pattern(pattern, w, visited);
+        let mut next = if len > 0 { pattern[0].zx() } else { n };
+        let mut pos = 0usize;
+        for j in 0..n {
+            new_col_ptrs[j] = I::truncate(pos);
+            let old = &L_row_indices[L_col_ptrs[j].zx()..L_col_ptrs[j + 1].zx()];
+            if j == next {
+                let merged_len =
+                    merge_sorted_indices(&mut new_row_indices[pos..], old, &pattern[..len]);
+
+                len = merged_len - 1;
+                pattern[..len].copy_from_slice(&new_row_indices[pos + 1..pos + merged_len]);
+                if len > 0 {
+                    next = pattern[0].zx();
+                    new_etree[j] = pattern[0];
+                } else {
+                    next = n;
+                    new_etree[j] = I::truncate(NONE);
+                }
+                pos += merged_len;
+            } else {
+                new_row_indices[pos..pos + old.len()].copy_from_slice(old);
+                pos += old.len();
+            }
+        }
+        new_col_ptrs[n] = I::truncate(pos);
+        debug_assert!(pos == l_nnz);
+
+        Ok(Some(SymbolicSimplicialCholesky {
+            dimension: n,
+            col_ptrs: new_col_ptrs,
+            row_indices: new_row_indices,
+            etree: new_etree,
+        }))
+    }
+
+    /// Copies the numeric values of a simplicial Cholesky factor with structure `symbolic` to a
+    /// factor with the extended structure `new_symbolic`, filling the new entries with zeros.
+    ///
+    /// # Panics
+    /// Panics if the factors don't have the same dimension.
+    /// The structure of `new_symbolic` must contain the structure of `symbolic`, e.g., as computed
+    /// by [`factorize_simplicial_symbolic_updown`]. Otherwise, the behavior is unspecified and
+    /// panics may occur.
+    pub fn extend_simplicial_numeric<I: Index, E: Entity>(
+        new_L_values: GroupFor<E, &mut [E::Unit]>,
+        new_symbolic: &SymbolicSimplicialCholesky<I>,
+        L_values: GroupFor<E, &[E::Unit]>,
+        symbolic: &SymbolicSimplicialCholesky<I>,
+    ) {
+        let n = symbolic.nrows();
+        assert!(new_symbolic.nrows() == n);
+
+        let mut new_L_values = SliceGroupMut::<'_, E>::new(new_L_values);
+        let L_values = SliceGroup::<'_, E>::new(L_values);
+        assert!(new_L_values.len() == new_symbolic.len_values());
+        assert!(L_values.len() == symbolic.len_values());
+
+        for j in 0..n {
+            let mut old_pos = symbolic.col_ptrs[j].zx();
+            let old_end = symbolic.col_ptrs[j + 1].zx();
+            let new_range = new_symbolic.col_ptrs[j].zx()..new_symbolic.col_ptrs[j + 1].zx();
+
+            for new_pos in new_range {
+                let i = new_symbolic.row_indices[new_pos];
+                if old_pos < old_end && symbolic.row_indices[old_pos] == i {
+                    new_L_values.write(new_pos, L_values.read(old_pos));
+                    old_pos += 1;
+                } else {
+                    new_L_values.write(new_pos, E::faer_zero());
+                }
+            }
+            assert!(old_pos == old_end);
+        }
+    }
+
+    fn updown_simplicial_numeric<I: Index, E: ComplexField>(
+        L_values: GroupFor<E, &mut [E::Unit]>,
+        kind: FactorizationKind,
+        w: SparseColMatRef<'_, I, E>,
+        alpha: E::Real,
+        symbolic: &SymbolicSimplicialCholesky<I>,
+        stack: PodStack<'_>,
+    ) -> Result<(), CholeskyError> {
+        let n = symbolic.nrows();
+        assert!(w.nrows() == n);
+        assert!(w.ncols() == 1);
+
+        let L_col_ptrs = &*symbolic.col_ptrs;
+        let L_row_indices = &*symbolic.row_indices;
+        let mut L_values = SliceGroupMut::<'_, E>::new(L_values);
+        assert!(L_values.len() == L_row_indices.len());
+
+        let (mut x, _) = crate::sparse::linalg::make_raw::<E>(n, stack);
+        x.fill_zero();
+
+        let mut first = n;
+        for (i, wi) in zip(
+            w.row_indices_of_col(0),
+            SliceGroup::<'_, E>::new(w.values_of_col(0)).into_ref_iter(),
+        ) {
+            x.write(i, x.read(i).faer_add(wi.read()));
+            first = Ord::min(first, i);
+        }
+
+        // Fletcher-Powell update along the path of the elimination tree, starting at the first
+        // nonzero row of w
+        let one = E::Real::faer_one();
+        let mut alpha = alpha;
+        let mut j = first;
+        while j < n {
+            let start = L_col_ptrs[j].zx();
+            let end = L_col_ptrs[j + 1].zx();
+
+            let p = x.read(j);
+            x.write(j, E::faer_zero());
+
+            let (d, scale) = match kind {
+                FactorizationKind::Llt => {
+                    let l = L_values.read(start).faer_real();
+                    (l.faer_mul(l), l)
+                }
+                FactorizationKind::Ldlt => (L_values.read(start).faer_real(), one),
+            };
+
+            let alpha_conj_p = p.faer_conj().faer_scale_real(alpha);
+            let new_d = d.faer_add(alpha.faer_mul(p.faer_abs2()));
+            let new_scale = match kind {
+                FactorizationKind::Llt => {
+                    if new_d <= E::Real::faer_zero() {
+                        return Err(CholeskyError {
+                            non_positive_definite_minor: j + 1,
+                        });
+                    }
+                    let new_scale = new_d.faer_sqrt();
+                    L_values.write(start, E::faer_from_real(new_scale));
+                    new_scale
+                }
+                FactorizationKind::Ldlt => {
+                    L_values.write(start, E::faer_from_real(new_d));
+                    one
+                }
+            };
+
+            let beta = alpha_conj_p.faer_scale_real(new_d.faer_inv());
+            alpha = alpha.faer_sub(new_d.faer_mul(beta.faer_abs2()));
+
+            let scale_inv = scale.faer_inv();
+            for idx in start + 1..end {
+                let i = L_row_indices[idx].zx();
+                let lij = L_values.read(idx).faer_scale_real(scale_inv);
+                let xi = x.read(i).faer_sub(p.faer_mul(lij));
+                x.write(i, xi);
+                let lij = lij.faer_add(beta.faer_mul(xi));
+                L_values.write(idx, lij.faer_scale_real(new_scale));
+            }
+
+            j = if end > start + 1 {
+                L_row_indices[start + 1].zx()
+            } else {
+                n
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Computes the size and alignment of the workspace required to perform a rank-1 update or
+    /// downdate of a simplicial Cholesky factor of dimension `n`.
+    pub fn updown_simplicial_numeric_req<I: Index, E: Entity>(
+        n: usize,
+    ) -> Result<StackReq, SizeOverflow> {
+        make_raw_req::<E>(n)
+    }
+
+    /// Updates the Cholesky LLT factor of $A$ stored in `L_values` so that it becomes the factor
+    /// of $A + \alpha w w^H$, where `w` is a sparse column vector.
+    ///
+    /// A positive `alpha` corresponds to an update, and a negative one to a downdate.
+    ///
+    /// # Panics
+    /// Panics if `w` doesn't have the same number of rows as the factor, or if it has more than
+    /// one column.
+    /// The symbolic structure must contain the structure of the updated factor, e.g., as computed
+    /// by [`factorize_simplicial_symbolic_updown`]. Otherwise, the behavior is unspecified and
+    /// panics may occur.
+    ///
+    /// # Errors
+    /// Returns an error if the updated matrix is not positive definite, in which case the contents
+    /// of `L_values` are unspecified.
+    pub fn updown_simplicial_numeric_llt<I: Index, E: ComplexField>(
+        L_values: GroupFor<E, &mut [E::Unit]>,
+        w: SparseColMatRef<'_, I, E>,
+        alpha: E::Real,
+        symbolic: &SymbolicSimplicialCholesky<I>,
+        stack: PodStack<'_>,
+    ) -> Result<(), CholeskyError> {
+        updown_simplicial_numeric(L_values, FactorizationKind::Llt, w, alpha, symbolic, stack)
+    }
+
+    /// Updates the Cholesky LDLT factor of $A$ stored in `L_values` so that it becomes the factor
+    /// of $A + \alpha w w^H$, where `w` is a sparse column vector.
+    ///
+    /// A positive `alpha` corresponds to an update, and a negative one to a downdate.
+    ///
+    /// # Panics
+    /// Panics if `w` doesn't have the same number of rows as the factor, or if it has more than
+    /// one column.
+    /// The symbolic structure must contain the structure of the updated factor, e.g., as computed
+    /// by [`factorize_simplicial_symbolic_updown`]. Otherwise, the behavior is unspecified and
+    /// panics may occur.
+    pub fn updown_simplicial_numeric_ldlt<I: Index, E: ComplexField>(
+        L_values: GroupFor<E, &mut [E::Unit]>,
+        w: SparseColMatRef<'_, I, E>,
+        alpha: E::Real,
+        symbolic: &SymbolicSimplicialCholesky<I>,
+        stack: PodStack<'_>,
+    ) {
+        updown_simplicial_numeric(L_values, FactorizationKind::Ldlt, w, alpha, symbolic, stack)
+            .unwrap()
+    }
+
     impl<'a, I: Index, E: Entity> SimplicialLltRef<'a, I, E> {
         /// Creates a new Cholesky LLT factor from the symbolic part and numerical values.
         ///
@@ -6012,6 +6353,292 @@ pub(crate) mod tests {
         });
     }
 
+    fn test_simplicial_updown<I: Index>() {
+        type E = Complex<Double<f64>>;
+        let truncate = I::truncate;
+
+        let (_, col_ptr, row_ind, values) = SMALL;
+
+        let mut gen = rand::rngs::StdRng::seed_from_u64(0);
+        let mut complexify = |e: E| {
+            let i = E::faer_one().faer_neg().faer_sqrt();
+            if e == E::faer_from_f64(1.0) {
+                e.faer_add(i.faer_mul(E::faer_from_f64(gen.gen())))
+            } else {
+                e
+            }
+        };
+
+        let n = col_ptr.len() - 1;
+        let nnz = values.len();
+        let col_ptr = &*col_ptr.iter().copied().map(truncate).collect::<Vec<_>>();
+        let row_ind = &*row_ind.iter().copied().map(truncate).collect::<Vec<_>>();
+        let values_mat =
+            crate::Mat::<E>::from_fn(nnz, 1, |i, _| complexify(E::faer_from_f64(values[i])));
+        let values = values_mat.col_as_slice(0);
+
+        let A = SparseColMatRef::<'_, I, E>::new(
+            SymbolicSparseColMatRef::new_unsorted_checked(n, n, col_ptr, None, row_ind),
+            values,
+        );
+
+        let w_col_ptr = &[truncate(0), truncate(3)];
+        let w_row_ind = &[truncate(n - 1), truncate(0), truncate(n / 2)];
+        let w_values = crate::Mat::<E>::from_fn(3, 1, |i, _| {
+            E::faer_from_f64(0.5 * (i + 1) as f64).faer_add(complexify(E::faer_one()))
+        });
+        let w = SparseColMatRef::<'_, I, E>::new(
+            SymbolicSparseColMatRef::new_unsorted_checked(n, 1, w_col_ptr, None, w_row_ind),
+            w_values.col_as_slice(0),
+        );
+
+        let zero = truncate(0);
+        let mut etree = vec![zero.to_signed(); n];
+        let mut col_count = vec![zero; n];
+        ghost::with_size(n, |N| {
+            let A = ghost::SparseColMatRef::new(A, N, N);
+            let etree = ghost_prefactorize_symbolic_cholesky(
+                Array::from_mut(&mut etree, N),
+                Array::from_mut(&mut col_count, N),
+                *A,
+                PodStack::new(&mut GlobalPodBuffer::new(StackReq::new::<I>(5 * n))),
+            );
+
+            let symbolic = simplicial::ghost_factorize_simplicial_symbolic_cholesky(
+                *A,
+                etree,
+                Array::from_ref(&col_count, N),
+                PodStack::new(&mut GlobalPodBuffer::new(StackReq::new::<I>(20 * n))),
+            )
+            .unwrap();
+
+            let mut values = crate::Mat::<E>::zeros(symbolic.len_values(), 1);
+
+            simplicial::factorize_simplicial_numeric_ldlt::<I, E>(
+                values.col_as_slice_mut(0),
+                A.into_inner(),
+                Default::default(),
+                &symbolic,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    simplicial::factorize_simplicial_numeric_ldlt_req::<I, E>(n).unwrap(),
+                )),
+            );
+
+            let new_symbolic = simplicial::factorize_simplicial_symbolic_updown(
+                &symbolic,
+                w.symbolic(),
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    simplicial::factorize_simplicial_symbolic_updown_req::<I>(n).unwrap(),
+                )),
+            )
+            .unwrap()
+            .unwrap();
+            assert!(new_symbolic.len_values() > symbolic.len_values());
+
+            let mut new_values = crate::Mat::<E>::zeros(new_symbolic.len_values(), 1);
+            simplicial::extend_simplicial_numeric::<I, E>(
+                new_values.col_as_slice_mut(0),
+                &new_symbolic,
+                values.col_as_slice(0),
+                &symbolic,
+            );
+
+            let mut A = sparse_to_dense(A.into_inner());
+            for j in 0..n {
+                for i in j + 1..n {
+                    A.write(i, j, A.read(j, i).faer_conj());
+                }
+            }
+            let w_dense = sparse_to_dense(w);
+            let mut A_update = A.clone();
+            for j in 0..n {
+                for i in 0..n {
+                    let wij = w_dense.read(i, 0).faer_mul(w_dense.read(j, 0).faer_conj());
+                    A_update.write(i, j, A_update.read(i, j).faer_add(wij));
+                }
+            }
+
+            let one = <E as ComplexField>::Real::faer_one();
+            let mut mem =
+                GlobalPodBuffer::new(simplicial::updown_simplicial_numeric_req::<I, E>(n).unwrap());
+
+            for (alpha, target) in [(one, &A_update), (one.faer_neg(), &A)] {
+                simplicial::updown_simplicial_numeric_ldlt::<I, E>(
+                    new_values.col_as_slice_mut(0),
+                    w,
+                    alpha,
+                    &new_symbolic,
+                    PodStack::new(&mut mem),
+                );
+
+                let err = reconstruct_from_simplicial_ldlt::<I, E>(
+                    &new_symbolic,
+                    new_values.col_as_slice(0),
+                ) - target;
+
+                let mut max = <E as ComplexField>::Real::faer_zero();
+                for j in 0..n {
+                    for i in 0..n {
+                        let x = err.read(i, j).faer_abs();
+                        max = if max > x { max } else { x }
+                    }
+                }
+                assert!(max < <E as ComplexField>::Real::faer_from_f64(1e-25));
+            }
+        });
+    }
+
+    fn test_simplicial_updown_llt<I: Index>() {
+        type E = Complex<Double<f64>>;
+        let truncate = I::truncate;
+
+        let (_, col_ptr, row_ind, values) = SMALL;
+
+        let mut gen = rand::rngs::StdRng::seed_from_u64(0);
+        let mut complexify = |e: E| {
+            let i = E::faer_one().faer_neg().faer_sqrt();
+            if e == E::faer_from_f64(1.0) {
+                e.faer_add(i.faer_mul(E::faer_from_f64(gen.gen())))
+            } else {
+                e
+            }
+        };
+
+        let n = col_ptr.len() - 1;
+        let nnz = values.len();
+        let col_ptr = &*col_ptr.iter().copied().map(truncate).collect::<Vec<_>>();
+        let row_ind = &*row_ind.iter().copied().map(truncate).collect::<Vec<_>>();
+        let values_mat =
+            crate::Mat::<E>::from_fn(nnz, 1, |i, _| complexify(E::faer_from_f64(values[i])));
+        let values = values_mat.col_as_slice(0);
+
+        let A = SparseColMatRef::<'_, I, E>::new(
+            SymbolicSparseColMatRef::new_unsorted_checked(n, n, col_ptr, None, row_ind),
+            values,
+        );
+
+        let w_col_ptr = &[truncate(0), truncate(3)];
+        let w_row_ind = &[truncate(n - 1), truncate(0), truncate(n / 2)];
+        let w_values = crate::Mat::<E>::from_fn(3, 1, |i, _| {
+            E::faer_from_f64(0.5 * (i + 1) as f64).faer_add(complexify(E::faer_one()))
+        });
+        let w = SparseColMatRef::<'_, I, E>::new(
+            SymbolicSparseColMatRef::new_unsorted_checked(n, 1, w_col_ptr, None, w_row_ind),
+            w_values.col_as_slice(0),
+        );
+
+        let zero = truncate(0);
+        let mut etree = vec![zero.to_signed(); n];
+        let mut col_count = vec![zero; n];
+        ghost::with_size(n, |N| {
+            let A = ghost::SparseColMatRef::new(A, N, N);
+            let etree = ghost_prefactorize_symbolic_cholesky(
+                Array::from_mut(&mut etree, N),
+                Array::from_mut(&mut col_count, N),
+                *A,
+                PodStack::new(&mut GlobalPodBuffer::new(StackReq::new::<I>(5 * n))),
+            );
+
+            let symbolic = simplicial::ghost_factorize_simplicial_symbolic_cholesky(
+                *A,
+                etree,
+                Array::from_ref(&col_count, N),
+                PodStack::new(&mut GlobalPodBuffer::new(StackReq::new::<I>(20 * n))),
+            )
+            .unwrap();
+
+            let mut values = crate::Mat::<E>::zeros(symbolic.len_values(), 1);
+
+            simplicial::factorize_simplicial_numeric_llt::<I, E>(
+                values.col_as_slice_mut(0),
+                A.into_inner(),
+                Default::default(),
+                &symbolic,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    simplicial::factorize_simplicial_numeric_llt_req::<I, E>(n).unwrap(),
+                )),
+            )
+            .unwrap();
+
+            let new_symbolic = simplicial::factorize_simplicial_symbolic_updown(
+                &symbolic,
+                w.symbolic(),
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    simplicial::factorize_simplicial_symbolic_updown_req::<I>(n).unwrap(),
+                )),
+            )
+            .unwrap()
+            .unwrap();
+            assert!(new_symbolic.len_values() > symbolic.len_values());
+
+            let mut new_values = crate::Mat::<E>::zeros(new_symbolic.len_values(), 1);
+            simplicial::extend_simplicial_numeric::<I, E>(
+                new_values.col_as_slice_mut(0),
+                &new_symbolic,
+                values.col_as_slice(0),
+                &symbolic,
+            );
+
+            let mut A = sparse_to_dense(A.into_inner());
+            for j in 0..n {
+                for i in j + 1..n {
+                    A.write(i, j, A.read(j, i).faer_conj());
+                }
+            }
+            let w_dense = sparse_to_dense(w);
+            let mut A_update = A.clone();
+            for j in 0..n {
+                for i in 0..n {
+                    let wij = w_dense.read(i, 0).faer_mul(w_dense.read(j, 0).faer_conj());
+                    A_update.write(i, j, A_update.read(i, j).faer_add(wij));
+                }
+            }
+
+            let one = <E as ComplexField>::Real::faer_one();
+            let mut mem =
+                GlobalPodBuffer::new(simplicial::updown_simplicial_numeric_req::<I, E>(n).unwrap());
+
+            for (alpha, target) in [(one, &A_update), (one.faer_neg(), &A)] {
+                simplicial::updown_simplicial_numeric_llt::<I, E>(
+                    new_values.col_as_slice_mut(0),
+                    w,
+                    alpha,
+                    &new_symbolic,
+                    PodStack::new(&mut mem),
+                )
+                .unwrap();
+
+                let err = reconstruct_from_simplicial_llt::<I, E>(
+                    &new_symbolic,
+                    new_values.col_as_slice(0),
+                ) - target;
+
+                let mut max = <E as ComplexField>::Real::faer_zero();
+                for j in 0..n {
+                    for i in 0..n {
+                        let x = err.read(i, j).faer_abs();
+                        max = if max > x { max } else { x }
+                    }
+                }
+                assert!(max < <E as ComplexField>::Real::faer_from_f64(1e-25));
+            }
+
+            // a downdate that makes the matrix indefinite is reported as an error
+            let err = simplicial::updown_simplicial_numeric_llt::<I, E>(
+                new_values.col_as_slice_mut(0),
+                w,
+                <E as ComplexField>::Real::faer_from_f64(-1e6),
+                &new_symbolic,
+                PodStack::new(&mut mem),
+            )
+            .unwrap_err();
+            assert!(all(
+                err.non_positive_definite_minor > 0,
+                err.non_positive_definite_minor <= n,
+            ));
+        });
+    }
+
     fn test_solver_llt<I: Index>() {
         type E = Complex<Double<f64>>;
         let truncate = I::truncate;
@@ -6610,6 +7237,8 @@ pub(crate) mod tests {
     monomorphize_test!(test_supernodal_intranode_bk_1, u32);
     monomorphize_test!(test_supernodal_intranode_bk_2, u32);
     monomorphize_test!(test_simplicial, u32);
+    monomorphize_test!(test_simplicial_updown, u32);
+    monomorphize_test!(test_simplicial_updown_llt, u32);
     monomorphize_test!(test_solver_llt, u32);
     monomorphize_test!(test_solver_ldlt, u32);
     monomorphize_test!(test_solver_intranode_bk, u32);
@@ -174,6 +174,8 @@ pub mod cholesky;
 pub mod lu;
 pub mod qr;
 
+pub mod reductions;
+
 /// Sparse LU error.
 #[derive(Copy, Clone, Debug)]
 pub enum LuError {
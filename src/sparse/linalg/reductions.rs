@@ -0,0 +1,284 @@
+//! Norms and statistics of sparse matrices, computed directly from the stored entries.
+//!
+//! # Note
+//! All the functions in this module allow unsorted matrices. Duplicate entries are treated as
+//! separate entries by the norms and nonzero counts, while they are accumulated by the sums.
+
+use super::*;
+use crate::{
+    assert,
+    col::from_slice,
+    linalg::{temp_mat_req, temp_mat_zeroed},
+    utils::thread::{for_each_raw, par_split_indices, parallelism_degree},
+    Col, ColMut, ComplexField, Parallelism, RealField, RowMut,
+};
+use dyn_stack::{SizeOverflow, StackReq};
+
+fn parallelism_count<I: Index>(
+    mat: SymbolicSparseColMatRef<'_, I>,
+    parallelism: Parallelism,
+) -> usize {
+    if mat.ncols() > 1 && mat.compute_nnz() >= 128 * 128 {
+        Ord::min(parallelism_degree(parallelism), mat.ncols())
+    } else {
+        1
+    }
+}
+
+// computes `sqrt(a^2 + b^2)` for nonnegative `a` and `b`, avoiding intermediate overflow
+fn hypot<E: RealField>(a: E, b: E) -> E {
+    let (big, small) = if a >= b { (a, b) } else { (b, a) };
+    if big == E::faer_zero() {
+        E::faer_zero()
+    } else {
+        let ratio = small.faer_div(big);
+        big.faer_mul(E::faer_one().faer_add(ratio.faer_mul(ratio)).faer_sqrt())
+    }
+}
+
+fn reduce_cols<I: Index, E: ComplexField>(
+    mat: SparseColMatRef<'_, I, E>,
+    parallelism: Parallelism,
+    col_op: impl Sync + Fn(E::Real, crate::col::ColRef<'_, E>) -> E::Real,
+    combine: impl Sync + Fn(E::Real, E::Real) -> E::Real,
+) -> E::Real {
+    let n = mat.ncols();
+    let par = parallelism_count(mat.symbolic(), parallelism);
+
+    let partials = Col::<E::Real>::zeros(par);
+    {
+        let partials = partials.as_ref();
+        for_each_raw(
+            par,
+            |tid| {
+                let (col_start, ncols) = par_split_indices(n, tid, par);
+                let mut acc = E::Real::faer_zero();
+                for j in col_start..col_start + ncols {
+                    acc = col_op(acc, from_slice::<E>(mat.values_of_col(j)));
+                }
+                unsafe { partials.const_cast() }.write(tid, acc);
+            },
+            parallelism,
+        );
+    }
+
+    let mut acc = E::Real::faer_zero();
+    for tid in 0..par {
+        acc = combine(acc, partials.read(tid));
+    }
+    acc
+}
+
+/// Returns the maximum of the absolute values of the entries of `mat`.
+pub fn norm_max<I: Index, E: ComplexField>(
+    mat: SparseColMatRef<'_, I, E>,
+    parallelism: Parallelism,
+) -> E::Real {
+    let max = |a: E::Real, b: E::Real| if a >= b { a } else { b };
+    reduce_cols(mat, parallelism, |acc, col| max(acc, col.norm_max()), max)
+}
+
+/// Returns the sum of the absolute values of the entries of `mat`.
+pub fn norm_l1<I: Index, E: ComplexField>(
+    mat: SparseColMatRef<'_, I, E>,
+    parallelism: Parallelism,
+) -> E::Real {
+    reduce_cols(
+        mat,
+        parallelism,
+        |acc, col| acc.faer_add(col.norm_l1()),
+        |a, b| a.faer_add(b),
+    )
+}
+
+/// Returns the Frobenius norm of `mat`.
+pub fn norm_l2<I: Index, E: ComplexField>(
+    mat: SparseColMatRef<'_, I, E>,
+    parallelism: Parallelism,
+) -> E::Real {
+    reduce_cols(
+        mat,
+        parallelism,
+        |acc, col| hypot(acc, col.norm_l2()),
+        hypot,
+    )
+}
+
+/// Computes the number of stored entries in each column of `mat`, and stores the result in
+/// `out`.
+///
+/// # Panics
+/// Panics if `out.len() != mat.ncols()`.
+#[track_caller]
+pub fn col_nnz_counts<I: Index>(out: &mut [usize], mat: SymbolicSparseColMatRef<'_, I>) {
+    assert!(out.len() == mat.ncols());
+    for (j, out) in out.iter_mut().enumerate() {
+        *out = mat.col_range(j).len();
+    }
+}
+
+/// Computes the number of stored entries in each row of `mat`, and stores the result in `out`.
+///
+/// # Panics
+/// Panics if `out.len() != mat.nrows()`.
+#[track_caller]
+pub fn row_nnz_counts<I: Index>(out: &mut [usize], mat: SymbolicSparseColMatRef<'_, I>) {
+    assert!(out.len() == mat.nrows());
+    out.fill(0);
+    for j in 0..mat.ncols() {
+        for i in mat.row_indices_of_col(j) {
+            out[i] += 1;
+        }
+    }
+}
+
+/// Computes the sum of each column of `mat`, and stores the result in `out`.
+///
+/// # Panics
+/// Panics if `out.ncols() != mat.ncols()`.
+#[track_caller]
+pub fn col_sums<I: Index, E: ComplexField>(
+    out: RowMut<'_, E>,
+    mat: SparseColMatRef<'_, I, E>,
+    parallelism: Parallelism,
+) {
+    assert!(out.ncols() == mat.ncols());
+    let n = mat.ncols();
+    let par = parallelism_count(mat.symbolic(), parallelism);
+
+    let out = out.into_const();
+    for_each_raw(
+        par,
+        |tid| {
+            let (col_start, ncols) = par_split_indices(n, tid, par);
+            let mut out = unsafe { out.const_cast() };
+            for j in col_start..col_start + ncols {
+                out.write(j, from_slice::<E>(mat.values_of_col(j)).sum());
+            }
+        },
+        parallelism,
+    );
+}
+
+/// Computes the size and alignment of the workspace required to compute the row sums of a
+/// sparse matrix with `nrows` rows.
+pub fn row_sums_req<E: Entity>(
+    nrows: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    temp_mat_req::<E>(nrows, parallelism_degree(parallelism))
+}
+
+/// Computes the sum of each row of `mat`, and stores the result in `out`.
+///
+/// # Panics
+/// Panics if `out.nrows() != mat.nrows()`.
+/// Panics if the provided memory in `stack` is insufficient (see [`row_sums_req`]).
+#[track_caller]
+pub fn row_sums<I: Index, E: ComplexField>(
+    out: ColMut<'_, E>,
+    mat: SparseColMatRef<'_, I, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    assert!(out.nrows() == mat.nrows());
+    let m = mat.nrows();
+    let n = mat.ncols();
+    let par = parallelism_count(mat.symbolic(), parallelism);
+
+    let (work, _) = temp_mat_zeroed::<E>(m, par, stack);
+    let work = work.into_const();
+
+    for_each_raw(
+        par,
+        |tid| {
+            let (col_start, ncols) = par_split_indices(n, tid, par);
+            let mut work = unsafe { work.col(tid).const_cast() };
+            for j in col_start..col_start + ncols {
+                for (i, val) in zip(
+                    mat.row_indices_of_col(j),
+                    SliceGroup::<'_, E>::new(mat.values_of_col(j)).into_ref_iter(),
+                ) {
+                    work.write(i, work.read(i).faer_add(val.read()));
+                }
+            }
+        },
+        parallelism,
+    );
+
+    let mut out = out;
+    out.fill_zero();
+    for tid in 0..par {
+        out += work.col(tid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Mat, Row};
+    use rand::prelude::*;
+
+    macro_rules! make_stack {
+        ($req: expr $(,)?) => {
+            ::dyn_stack::PodStack::new(&mut ::dyn_stack::GlobalPodBuffer::new($req.unwrap()))
+        };
+    }
+
+    #[test]
+    fn test_reductions() {
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        for (m, n) in [(0, 0), (1, 1), (7, 3), (200, 300)] {
+            let mut triplets = Vec::new();
+            let mut dense = Mat::<c64>::zeros(m, n);
+            for j in 0..n {
+                for i in 0..m {
+                    if rng.gen_bool(0.3) {
+                        let val = c64::new(rng.gen(), rng.gen());
+                        triplets.push((i, j, val));
+                        dense.write(i, j, val);
+                    }
+                }
+            }
+            let sparse =
+                SparseColMat::<usize, c64>::try_new_from_triplets(m, n, &triplets).unwrap();
+            let sparse = sparse.as_ref();
+
+            for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+                assert!((norm_max(sparse, parallelism) - dense.norm_max()).abs() < 1e-12);
+                assert!((norm_l1(sparse, parallelism) - dense.norm_l1()).abs() < 1e-9);
+                assert!((norm_l2(sparse, parallelism) - dense.norm_l2()).abs() < 1e-10);
+
+                let mut col_sum = Row::<c64>::zeros(n);
+                col_sums(col_sum.as_mut(), sparse, parallelism);
+                for j in 0..n {
+                    assert!((col_sum.read(j) - dense.col(j).sum()).faer_abs() < 1e-12);
+                }
+
+                let mut row_sum = Col::<c64>::zeros(m);
+                row_sums(
+                    row_sum.as_mut(),
+                    sparse,
+                    parallelism,
+                    make_stack!(row_sums_req::<c64>(m, parallelism)),
+                );
+                for i in 0..m {
+                    assert!((row_sum.read(i) - dense.row(i).sum()).faer_abs() < 1e-12);
+                }
+            }
+
+            let mut col_count = vec![0usize; n];
+            let mut row_count = vec![0usize; m];
+            col_nnz_counts(&mut col_count, sparse.symbolic());
+            row_nnz_counts(&mut row_count, sparse.symbolic());
+            for j in 0..n {
+                assert!(col_count[j] == triplets.iter().filter(|&&(_, col, _)| col == j).count());
+            }
+            for i in 0..m {
+                assert!(row_count[i] == triplets.iter().filter(|&&(row, _, _)| row == i).count());
+            }
+            assert!(col_count.iter().sum::<usize>() == triplets.len());
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use super::*;
 use crate::{
     col::{ColBatch, ColBatchMut},
+    linalg::refinement::ResidualPrecision,
     mat::{As2D, As2DMut},
+    utils::{compensated::CompensatedSum, scratch::ScratchBuffer},
 };
 
 /// Object-safe base for [`SpSolver`]
@@ -321,7 +323,7 @@ impl<I: Index, E: ComplexField> Cholesky<I, E> {
             side,
             Default::default(),
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 symbolic
                     .inner
                     .factorize_numeric_llt_req::<E>(parallelism)
@@ -358,7 +360,7 @@ impl<I: Index, E: ComplexField> Qr<I, E> {
             values.as_slice_mut().into_inner(),
             mat,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 symbolic
                     .inner
                     .factorize_numeric_qr_req::<E>(parallelism)
@@ -387,7 +389,7 @@ impl<I: Index, E: ComplexField> Lu<I, E> {
             &mut numeric,
             mat,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 symbolic
                     .inner
                     .factorize_numeric_lu_req::<E>(parallelism)
@@ -420,7 +422,7 @@ impl<I: Index, E: ComplexField> SpSolverCore<E> for Cholesky<I, E> {
             conj,
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 self.symbolic
                     .inner
                     .solve_in_place_req::<E>(rhs_ncols)
@@ -441,7 +443,7 @@ impl<I: Index, E: ComplexField> SpSolverCore<E> for Cholesky<I, E> {
             conj.compose(Conj::Yes),
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 self.symbolic
                     .inner
                     .solve_in_place_req::<E>(rhs_ncols)
@@ -492,7 +494,7 @@ impl<I: Index, E: ComplexField> SpSolverLstsqCore<E> for Qr<I, E> {
             conj,
             rhs,
             parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
+            PodStack::new(&mut ScratchBuffer::new(
                 self.symbolic
                     .inner
                     .solve_in_place_req::<E>(rhs_ncols, parallelism)
@@ -521,7 +523,7 @@ impl<I: Index, E: ComplexField> SpSolverCore<E> for Lu<I, E> {
                 conj,
                 rhs,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
+                PodStack::new(&mut ScratchBuffer::new(
                     self.symbolic
                         .inner
                         .solve_in_place_req::<E>(rhs_ncols, parallelism)
@@ -539,7 +541,7 @@ impl<I: Index, E: ComplexField> SpSolverCore<E> for Lu<I, E> {
                 conj,
                 rhs,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
+                PodStack::new(&mut ScratchBuffer::new(
                     self.symbolic
                         .inner
                         .solve_in_place_req::<E>(rhs_ncols, parallelism)
@@ -549,6 +551,226 @@ impl<I: Index, E: ComplexField> SpSolverCore<E> for Lu<I, E> {
     }
 }
 
+/// Parameters for [`solve_in_place_with_refinement`].
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct RefinementParams<E: ComplexField> {
+    /// Maximum number of refinement steps performed after the initial solve.
+    pub max_iters: usize,
+    /// Componentwise backward error below which the refinement stops.
+    pub tolerance: E::Real,
+    /// Precision used to compute the residuals. Defaults to [`ResidualPrecision::Working`].
+    ///
+    /// With [`ResidualPrecision::Extended`], the backward error usually falls below the default
+    /// `tolerance` after the first solve. Setting `tolerance` to zero makes the refinement continue
+    /// while it improves the solution, which reduces its forward error for ill-conditioned
+    /// matrices.
+    pub residual_precision: ResidualPrecision,
+}
+
+/// Information about the solution computed by [`solve_in_place_with_refinement`].
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct RefinementInfo<E: ComplexField> {
+    /// Componentwise backward error of the computed solution, maximized over the right-hand
+    /// sides.
+    pub backward_error: E::Real,
+    /// Number of refinement steps that were performed.
+    pub iter_count: usize,
+}
+
+impl<E: ComplexField> Default for RefinementParams<E> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_iters: 2,
+            tolerance: E::Real::faer_epsilon(),
+            residual_precision: ResidualPrecision::Working,
+        }
+    }
+}
+
+/// Computes the residual `rhs - mat * sol` into `residual`, and returns the componentwise
+/// backward error `max_ij |residual_ij| / (|mat| |sol| + |rhs|)_ij`.
+fn residual_and_backward_error<I: Index, E: ComplexField>(
+    residual: MatMut<'_, E>,
+    mat: SparseColMatRef<'_, I, E>,
+    sol: MatRef<'_, E>,
+    rhs: MatRef<'_, E>,
+    denom: &mut Col<E::Real>,
+    precision: ResidualPrecision,
+) -> E::Real {
+    let mut residual = residual;
+    match precision {
+        ResidualPrecision::Working => {
+            residual.copy_from(rhs);
+            super::matmul::sparse_dense_matmul(
+                residual.rb_mut(),
+                mat,
+                sol,
+                Some(E::faer_one()),
+                E::faer_one().faer_neg(),
+                Parallelism::None,
+            );
+        }
+        ResidualPrecision::Extended => {
+            let mut acc = alloc::vec![CompensatedSum::<E>::new(); mat.nrows()];
+            for j in 0..sol.ncols() {
+                for i in 0..mat.nrows() {
+                    acc[i] = CompensatedSum::new();
+                    acc[i].add(rhs.read(i, j));
+                }
+                for k in 0..mat.ncols() {
+                    let x_kj = sol.read(k, j);
+                    for (i, a_ik) in zip(
+                        mat.row_indices_of_col(k),
+                        SliceGroup::<'_, E>::new(mat.values_of_col(k)).into_ref_iter(),
+                    ) {
+                        acc[i].add_mul(a_ik.read().faer_neg(), x_kj);
+                    }
+                }
+                for i in 0..mat.nrows() {
+                    residual.write(i, j, acc[i].value());
+                }
+            }
+        }
+    }
+
+    let zero = E::Real::faer_zero();
+    let inf = zero.faer_inv();
+    let mut berr = zero;
+    for j in 0..sol.ncols() {
+        denom.fill_zero();
+        for k in 0..mat.ncols() {
+            let x_kj = sol.read(k, j).faer_abs();
+            for (i, a_ik) in zip(
+                mat.row_indices_of_col(k),
+                SliceGroup::<'_, E>::new(mat.values_of_col(k)).into_ref_iter(),
+            ) {
+                denom.write(i, denom.read(i).faer_add(a_ik.read().faer_abs().faer_mul(x_kj)));
+            }
+        }
+        for i in 0..mat.nrows() {
+            let d = denom.read(i).faer_add(rhs.read(i, j).faer_abs());
+            let r = residual.read(i, j).faer_abs();
+            let err = if d > zero {
+                r.faer_div(d)
+            } else if r > zero {
+                inf
+            } else {
+                zero
+            };
+            if err > berr {
+                berr = err;
+            }
+        }
+    }
+    berr
+}
+
+/// Solves the equation `mat * X = rhs` using the factorization `solver` of `mat`, followed by
+/// steps of iterative refinement, and stores the result in `rhs`.
+///
+/// Residuals are computed using the original sparse matrix `mat`, and the refinement stops once
+/// the componentwise backward error falls below `params.tolerance`, once `params.max_iters` steps
+/// have been performed, or once a step fails to halve the backward error.
+///
+/// # Note
+/// For symmetric factorizations such as [`Cholesky`], `mat` must contain both the lower and
+/// upper triangular parts of the matrix.
+#[track_caller]
+pub fn solve_in_place_with_refinement<
+    I: Index,
+    E: ComplexField,
+    S: ?Sized + SpSolverCore<E>,
+>(
+    solver: &S,
+    mat: SparseColMatRef<'_, I, E>,
+    rhs: impl ColBatchMut<E>,
+    params: RefinementParams<E>,
+) -> RefinementInfo<E> {
+    let mut rhs = rhs;
+    let mut x = rhs.as_2d_mut();
+    assert!(all(
+        solver.nrows() == mat.nrows(),
+        solver.ncols() == mat.ncols(),
+        mat.nrows() == mat.ncols(),
+        x.nrows() == mat.nrows(),
+    ));
+
+    let b = x.rb().to_owned();
+    let mut residual = Mat::<E>::zeros(x.nrows(), x.ncols());
+    let mut denom = Col::<E::Real>::zeros(mat.nrows());
+
+    solver.solve_in_place_with_conj_impl(x.rb_mut(), Conj::No);
+    let mut berr = residual_and_backward_error(
+        residual.as_mut(),
+        mat,
+        x.rb(),
+        b.as_ref(),
+        &mut denom,
+        params.residual_precision,
+    );
+
+    let mut iter_count = 0;
+    while iter_count < params.max_iters && berr > params.tolerance {
+        solver.solve_in_place_with_conj_impl(residual.as_mut(), Conj::No);
+        zipped!(x.rb_mut(), residual.as_ref())
+            .for_each(|unzipped!(mut x, dx)| x.write(x.read().faer_add(dx.read())));
+        iter_count += 1;
+
+        let prev_berr = berr;
+        berr = residual_and_backward_error(
+            residual.as_mut(),
+            mat,
+            x.rb(),
+            b.as_ref(),
+            &mut denom,
+            params.residual_precision,
+        );
+        if berr.faer_add(berr) > prev_berr {
+            break;
+        }
+    }
+
+    RefinementInfo {
+        backward_error: berr,
+        iter_count,
+    }
+}
+
+impl<I: Index, E: ComplexField> Cholesky<I, E> {
+    /// Solves the equation `mat * X = rhs` with iterative refinement, and stores the result in
+    /// `rhs`. `mat` must be the full matrix used to compute `self`.
+    ///
+    /// See [`solve_in_place_with_refinement`] for more details.
+    #[track_caller]
+    pub fn solve_in_place_with_refinement(
+        &self,
+        mat: SparseColMatRef<'_, I, E>,
+        rhs: impl ColBatchMut<E>,
+        params: RefinementParams<E>,
+    ) -> RefinementInfo<E> {
+        solve_in_place_with_refinement(self, mat, rhs, params)
+    }
+}
+
+impl<I: Index, E: ComplexField> Lu<I, E> {
+    /// Solves the equation `mat * X = rhs` with iterative refinement, and stores the result in
+    /// `rhs`. `mat` must be the matrix used to compute `self`.
+    ///
+    /// See [`solve_in_place_with_refinement`] for more details.
+    #[track_caller]
+    pub fn solve_in_place_with_refinement(
+        &self,
+        mat: SparseColMatRef<'_, I, E>,
+        rhs: impl ColBatchMut<E>,
+        params: RefinementParams<E>,
+    ) -> RefinementInfo<E> {
+        solve_in_place_with_refinement(self, mat, rhs, params)
+    }
+}
+
 impl<I: Index, E: ComplexField> SparseColMatRef<'_, I, E> {
     /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
     /// stores the result in `rhs`.
@@ -973,3 +1195,51 @@ impl<I: Index, E: ComplexField> SparseRowMat<I, E> {
         self.as_ref().sp_qr()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert;
+
+    #[test]
+    fn test_solve_with_refinement() {
+        let n = 4;
+        let a = SparseColMat::<usize, f64>::try_new_from_triplets(
+            n,
+            n,
+            &[
+                (0, 0, 4.0),
+                (1, 0, 1.0),
+                (3, 0, 2.0),
+                (0, 1, 1.0),
+                (1, 1, 5.0),
+                (2, 1, 1.0),
+                (1, 2, 2.0),
+                (2, 2, 6.0),
+                (3, 2, 1.0),
+                (0, 3, 2.0),
+                (2, 3, 1.0),
+                (3, 3, 7.0),
+            ],
+        )
+        .unwrap();
+        let b = Mat::<f64>::from_fn(n, 2, |i, j| (i + 2 * j) as f64 + 1.0);
+
+        let lu = a.sp_lu().unwrap();
+        let mut x = b.clone();
+        let info = lu.solve_in_place_with_refinement(a.as_ref(), x.as_mut(), Default::default());
+
+        assert!(info.backward_error <= f64::EPSILON * 4.0);
+        assert!((a.to_dense() * &x - &b).norm_max() < 1e-12);
+
+        let mut params = RefinementParams::<f64>::default();
+        params.residual_precision = ResidualPrecision::Extended;
+        params.tolerance = 0.0;
+        let mut y = b.clone();
+        let info = lu.solve_in_place_with_refinement(a.as_ref(), y.as_mut(), params);
+
+        assert!(info.backward_error <= f64::EPSILON * 4.0);
+        assert!(info.iter_count <= params.max_iters);
+        assert!((&y - &x).norm_max() < 1e-12);
+    }
+}
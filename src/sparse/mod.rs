@@ -192,6 +192,61 @@ fn try_collect<I: IntoIterator>(iter: I) -> Result<alloc::vec::Vec<I::Item>, Fae
     Ok(v)
 }
 
+#[track_caller]
+pub(crate) fn dense_to_sparse<I: Index, E: Conjugate>(
+    mat: MatRef<'_, E>,
+    tol: <E::Canonical as ComplexField>::Real,
+) -> Result<SparseColMat<I, E::Canonical>, FaerError>
+where
+    E::Canonical: ComplexField,
+{
+    let m = mat.nrows();
+    let n = mat.ncols();
+    if Ord::max(m, n) > I::Signed::MAX.zx() {
+        return Err(FaerError::IndexOverflow);
+    }
+
+    // NaN entries are kept
+    let keep = |val: E::Canonical| !(val.faer_abs() <= tol);
+
+    let mut col_ptrs = try_zeroed::<I>(n + 1)?;
+    let mut nnz = 0usize;
+    for j in 0..n {
+        for i in 0..m {
+            if keep(mat.read(i, j).canonicalize()) {
+                nnz += 1;
+            }
+        }
+        if nnz > I::Signed::MAX.zx() {
+            return Err(FaerError::IndexOverflow);
+        }
+        col_ptrs[j + 1] = I::truncate(nnz);
+    }
+
+    let mut row_indices = try_zeroed::<I>(nnz)?;
+    let mut values = VecGroup::<E::Canonical>::new();
+    values
+        .try_reserve_exact(nnz)
+        .map_err(|_| FaerError::OutOfMemory)?;
+
+    let mut pos = 0usize;
+    for j in 0..n {
+        for i in 0..m {
+            let val = mat.read(i, j).canonicalize();
+            if keep(val) {
+                row_indices[pos] = I::truncate(i);
+                values.push(val.faer_into_units());
+                pos += 1;
+            }
+        }
+    }
+
+    Ok(SparseColMat::new(
+        unsafe { SymbolicSparseColMat::new_unchecked(m, n, col_ptrs, None, row_indices) },
+        values.into_inner(),
+    ))
+}
+
 /// The order values should be read in, when constructing/filling from indices and values.
 ///
 /// Allows separately creating the symbolic structure and filling the numerical values.
@@ -1649,4 +1704,36 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_dense_sparse_roundtrip() {
+        let dense = crate::mat![
+            [1.0, 0.0, 1e-12, 0.0],
+            [0.0, -2.0, 0.0, 3.0],
+            [f64::NAN, 0.0, -1e-12, 4.0f64],
+        ];
+
+        let sparse = dense.to_sparse::<usize>(1e-10).unwrap();
+        assert!(sparse.compute_nnz() == 5);
+        assert!(sparse.col_ptrs() == &[0, 2, 3, 3, 5]);
+        assert!(sparse.row_indices() == &[0, 2, 1, 1, 2]);
+
+        let roundtrip = sparse.to_dense();
+        for j in 0..4 {
+            for i in 0..3 {
+                let expected = dense.read(i, j);
+                let actual = roundtrip.read(i, j);
+                if expected.is_nan() {
+                    assert!(actual.is_nan());
+                } else if expected.abs() > 1e-10 {
+                    assert!(actual == expected);
+                } else {
+                    assert!(actual == 0.0);
+                }
+            }
+        }
+
+        let sparse = dense.as_ref().to_sparse::<u32>(-1.0).unwrap();
+        assert!(sparse.compute_nnz() == 12);
+    }
 }
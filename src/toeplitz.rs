@@ -0,0 +1,886 @@
+use crate::{
+    assert,
+    circulant::Circulant,
+    col::{Col, ColRef},
+    get_global_parallelism,
+    linop::{
+        conjugate_gradient::{
+            conjugate_gradient, conjugate_gradient_req, CgError, CgInfo, CgParams,
+        },
+        BiLinOp, LinOp, Precond,
+    },
+    mat::{Mat, MatMut, MatRef},
+    row::{Row, RowRef},
+    utils::{
+        fft::{
+            circulant_apply, circulant_apply_req, cmul, fft_in_place, fft_req, from_parts,
+            CirculantOp,
+        },
+        scratch::ScratchBuffer,
+    },
+    ComplexField, Conjugate, Parallelism, RealField,
+};
+use alloc::vec::Vec;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// This error signifies that the Levinson recursion could not be carried out because a leading
+/// principal submatrix of the Toeplitz matrix is singular.
+#[derive(Debug, Clone, Copy)]
+pub struct LevinsonError {
+    /// The dimension of the first singular square top-left corner of the matrix.
+    pub singular_leading_minor: usize,
+}
+
+impl core::fmt::Display for LevinsonError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for LevinsonError {}
+
+/// Toeplitz matrix, whose entries are constant along each diagonal. The matrix is stored as its
+/// first column and first row, where the entry at `(i, j)` is `first_col[i - j]` if `i >= j`,
+/// and `first_row[j - i]` otherwise.
+///
+/// Products with the matrix are computed in `O(n log n)` operations, by embedding it in a
+/// circulant matrix that is diagonalized with the fast Fourier transform.
+#[derive(Clone, Debug)]
+pub struct Toeplitz<E: ComplexField> {
+    first_col: Col<E>,
+    first_row: Row<E>,
+    // eigenvalues of the circulant embedding, of power of two dimension at least `2 * n - 1`
+    spectrum_re: alloc::vec::Vec<E::Real>,
+    spectrum_im: alloc::vec::Vec<E::Real>,
+}
+
+fn fft_spectrum<E: ComplexField>(
+    first_col: impl Fn(usize) -> E,
+    len: usize,
+) -> (alloc::vec::Vec<E::Real>, alloc::vec::Vec<E::Real>) {
+    let mut re: alloc::vec::Vec<E::Real> = (0..len).map(|i| first_col(i).faer_real()).collect();
+    let mut im: alloc::vec::Vec<E::Real> = (0..len).map(|i| first_col(i).faer_imag()).collect();
+    fft_in_place(
+        &mut re,
+        &mut im,
+        false,
+        PodStack::new(&mut ScratchBuffer::new(fft_req::<E::Real>(len).unwrap())),
+    );
+    (re, im)
+}
+
+/// Transfer matrix of a block of steps of the Levinson recursion. Its entries are polynomials,
+/// stored in increasing degree order.
+type Transfer<E> = [[Vec<E>; 2]; 2];
+
+// below this length, the polynomial products are computed directly instead of with the fft
+const DIRECT_CONV_LEN: usize = 64;
+
+// computes `theta * v` modulo `z^len - 1` for each pair of polynomials `v` in `vecs`, where `len`
+// is a power of two no smaller than the lengths of all the inputs
+fn cyclic_apply<E: ComplexField>(
+    theta: &Transfer<E>,
+    vecs: [[&[E]; 2]; 2],
+    len: usize,
+) -> [[Vec<E>; 2]; 2] {
+    if len <= DIRECT_CONV_LEN {
+        return vecs.map(|v| {
+            [0, 1].map(|i| {
+                let mut out = alloc::vec![E::faer_zero(); len];
+                for j in 0..2 {
+                    for (p, &a) in theta[i][j].iter().enumerate() {
+                        for (q, &b) in v[j].iter().enumerate() {
+                            let k = (p + q) % len;
+                            out[k] = out[k].faer_add(a.faer_mul(b));
+                        }
+                    }
+                }
+                out
+            })
+        });
+    }
+
+    let fft = |p: &[E]| {
+        let mut re = alloc::vec![E::Real::faer_zero(); len];
+        let mut im = alloc::vec![E::Real::faer_zero(); len];
+        for (k, &x) in p.iter().enumerate() {
+            re[k] = x.faer_real();
+            im[k] = x.faer_imag();
+        }
+        // power of two transforms don't need any workspace
+        fft_in_place(&mut re, &mut im, false, PodStack::new(&mut []));
+        (re, im)
+    };
+
+    let theta = [0, 1].map(|i| [0, 1].map(|j| fft(&theta[i][j])));
+    let scale = E::Real::faer_from_f64(len as f64).faer_inv();
+    vecs.map(|v| {
+        let v = [fft(v[0]), fft(v[1])];
+        [0, 1].map(|i| {
+            let mut re = alloc::vec![E::Real::faer_zero(); len];
+            let mut im = alloc::vec![E::Real::faer_zero(); len];
+            for j in 0..2 {
+                let (a, b) = (&theta[i][j], &v[j]);
+                for k in 0..len {
+                    let (xr, xi) = cmul(a.0[k], a.1[k], b.0[k], b.1[k]);
+                    re[k] = re[k].faer_add(xr);
+                    im[k] = im[k].faer_add(xi);
+                }
+            }
+            fft_in_place(&mut re, &mut im, true, PodStack::new(&mut []));
+            (0..len)
+                .map(|k| from_parts(re[k].faer_mul(scale), im[k].faer_mul(scale)))
+                .collect::<Vec<E>>()
+        })
+    })
+}
+
+/// Runs the steps of the Levinson recursion that grow the leading submatrix from dimension `s` to
+/// `s + m`, where `m = uf.len()`, and returns their transfer matrix, which maps the forward and
+/// backward vectors `f` and `b` of dimension `s` to those of dimension `s + m`.
+///
+/// The products of the matrix, extended to the infinite Toeplitz operator, with `f` and `b` are
+/// only known on a window of entries: `uf[k]` and `ub[k]` are their entries `s + k`, and `lf[k]`
+/// and `lb[k]` their entries `-1 - k`. The transfer matrix of the first half of the steps gives
+/// the windows of the second half through polynomial products, so that the recursion takes
+/// `O(m log² m)` operations.
+fn levinson_transfer<E: ComplexField>(
+    uf: &[E],
+    ub: &[E],
+    lf: &[E],
+    lb: &[E],
+    s: usize,
+) -> Result<Transfer<E>, LevinsonError> {
+    let m = uf.len();
+    if m == 1 {
+        let (ef, eb) = (uf[0], lb[0]);
+        let denom = E::faer_one().faer_sub(ef.faer_mul(eb));
+        if denom == E::faer_zero() {
+            return Err(LevinsonError {
+                singular_leading_minor: s + 1,
+            });
+        }
+        let inv = denom.faer_inv();
+        let zero = E::faer_zero();
+        // `f <- (f - ef * z b) / denom` and `b <- (z b - eb * f) / denom`
+        return Ok([
+            [
+                alloc::vec![inv],
+                alloc::vec![zero, ef.faer_mul(inv).faer_neg()],
+            ],
+            [
+                alloc::vec![eb.faer_mul(inv).faer_neg()],
+                alloc::vec![zero, inv],
+            ],
+        ]);
+    }
+
+    let h = m / 2;
+    let first = levinson_transfer(&uf[..h], &ub[..h], &lf[..h], &lb[..h], s)?;
+
+    // the lower windows are reversed, so that the correlations become convolutions. the wrapped
+    // around coefficients only affect the first `h` entries of the results, which aren't needed
+    let lf_rev: Vec<E> = lf.iter().rev().copied().collect();
+    let lb_rev: Vec<E> = lb.iter().rev().copied().collect();
+    let [[uf, ub], [lf, lb]] = cyclic_apply(
+        &first,
+        [[uf, ub], [&lf_rev[..], &lb_rev[..]]],
+        m.next_power_of_two(),
+    );
+    let lower = |l: &[E]| (0..m - h).map(|k| l[m - 1 - k]).collect::<Vec<E>>();
+    let second = levinson_transfer(&uf[h..m], &ub[h..m], &lower(&lf), &lower(&lb), s + h)?;
+
+    let [[mut t00, mut t10], [mut t01, mut t11]] = cyclic_apply(
+        &second,
+        [
+            [&first[0][0][..], &first[1][0][..]],
+            [&first[0][1][..], &first[1][1][..]],
+        ],
+        (m + 1).next_power_of_two(),
+    );
+    for t in [&mut t00, &mut t01, &mut t10, &mut t11] {
+        t.truncate(m + 1);
+    }
+    Ok([[t00, t01], [t10, t11]])
+}
+
+impl<E: ComplexField> Toeplitz<E> {
+    /// Creates a Toeplitz matrix from its first column and first row. The first element of
+    /// `first_row` is ignored, and the diagonal is taken from `first_col` instead.
+    ///
+    /// # Panics
+    /// Panics if `first_col.nrows() != first_row.ncols()`.
+    #[track_caller]
+    pub fn new(first_col: Col<E>, first_row: Row<E>) -> Self {
+        let n = first_col.nrows();
+        assert!(first_row.ncols() == n);
+
+        let m = if n == 0 {
+            0
+        } else {
+            (2 * n - 1).next_power_of_two()
+        };
+        let (spectrum_re, spectrum_im) = fft_spectrum(
+            |i| {
+                if i < n {
+                    first_col.read(i)
+                } else if i > m - n {
+                    first_row.read(m - i)
+                } else {
+                    E::faer_zero()
+                }
+            },
+            m,
+        );
+
+        Self {
+            first_col,
+            first_row,
+            spectrum_re,
+            spectrum_im,
+        }
+    }
+
+    /// Creates a Hermitian Toeplitz matrix from its first column, such as an autocorrelation
+    /// matrix. The imaginary part of the first element is ignored.
+    pub fn new_hermitian(first_col: Col<E>) -> Self {
+        let n = first_col.nrows();
+        let mut first_col = first_col;
+        if n > 0 {
+            first_col.write(0, E::faer_from_real(first_col.read(0).faer_real()));
+        }
+        let first_row = Row::from_fn(n, |j| first_col.read(j).faer_conj());
+        Self::new(first_col, first_row)
+    }
+
+    /// Returns the dimension of the matrix.
+    #[inline(always)]
+    pub fn dim(&self) -> usize {
+        self.first_col.nrows()
+    }
+
+    /// Returns a view over the first column of the matrix.
+    #[inline(always)]
+    pub fn first_col(&self) -> ColRef<'_, E> {
+        self.first_col.as_ref()
+    }
+
+    /// Returns a view over the first row of the matrix. Its first element is not used.
+    #[inline(always)]
+    pub fn first_row(&self) -> RowRef<'_, E> {
+        self.first_row.as_ref()
+    }
+
+    /// Reads the value at the given indices.
+    ///
+    /// # Panics
+    /// Panics if `row >= self.dim()` or `col >= self.dim()`.
+    #[inline]
+    #[track_caller]
+    pub fn read(&self, row: usize, col: usize) -> E {
+        assert!(all(row < self.dim(), col < self.dim()));
+        if row >= col {
+            self.first_col.read(row - col)
+        } else {
+            self.first_row.read(col - row)
+        }
+    }
+
+    /// Returns the matrix as a dense matrix.
+    pub fn to_mat(&self) -> Mat<E> {
+        let n = self.dim();
+        Mat::from_fn(n, n, |i, j| self.read(i, j))
+    }
+
+    fn apply_impl(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        op: CirculantOp,
+        stack: PodStack<'_>,
+    ) {
+        let n = self.dim();
+        assert!(all(
+            out.nrows() == n,
+            rhs.nrows() == n,
+            out.ncols() == rhs.ncols(),
+        ));
+        circulant_apply(
+            out,
+            rhs,
+            &self.spectrum_re,
+            &self.spectrum_im,
+            op,
+            false,
+            stack,
+        );
+    }
+
+    /// Solves the equation `self * X = rhs` with the Levinson recursion, in `O(n^2)` operations.
+    ///
+    /// The recursion requires all the leading principal submatrices of `self` to be nonsingular,
+    /// which is the case for Hermitian positive definite matrices. It may be inaccurate for
+    /// matrices that are not well conditioned.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve_levinson<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        rhs: MatRef<'_, ViewE>,
+    ) -> Result<Mat<E>, LevinsonError> {
+        let n = self.dim();
+        assert!(rhs.nrows() == n);
+        let k = rhs.ncols();
+
+        let c = self.first_col.as_ref();
+        let r = self.first_row.as_ref();
+
+        let mut x = Mat::<E>::zeros(n, k);
+        if n == 0 {
+            return Ok(x);
+        }
+
+        let t0 = c.read(0);
+        if t0 == E::faer_zero() {
+            return Err(LevinsonError {
+                singular_leading_minor: 1,
+            });
+        }
+
+        // `f` and `b` satisfy `T_s f = e_1` and `T_s b = e_s`, for the leading submatrix `T_s`
+        let mut f = Col::<E>::zeros(n);
+        let mut b = Col::<E>::zeros(n);
+        let mut f_next = Col::<E>::zeros(n);
+        let mut b_next = Col::<E>::zeros(n);
+
+        let inv_t0 = t0.faer_inv();
+        f.write(0, inv_t0);
+        b.write(0, inv_t0);
+        for q in 0..k {
+            x.write(0, q, rhs.read(0, q).canonicalize().faer_mul(inv_t0));
+        }
+
+        for s in 1..n {
+            let mut ef = E::faer_zero();
+            let mut eb = E::faer_zero();
+            for j in 0..s {
+                ef = ef.faer_add(c.read(s - j).faer_mul(f.read(j)));
+                eb = eb.faer_add(r.read(j + 1).faer_mul(b.read(j)));
+            }
+
+            let denom = E::faer_one().faer_sub(ef.faer_mul(eb));
+            if denom == E::faer_zero() {
+                return Err(LevinsonError {
+                    singular_leading_minor: s + 1,
+                });
+            }
+            let inv = denom.faer_inv();
+
+            for i in 0..s + 1 {
+                let f_i = if i < s { f.read(i) } else { E::faer_zero() };
+                let b_i = if i > 0 { b.read(i - 1) } else { E::faer_zero() };
+                f_next.write(i, f_i.faer_sub(ef.faer_mul(b_i)).faer_mul(inv));
+                b_next.write(i, b_i.faer_sub(eb.faer_mul(f_i)).faer_mul(inv));
+            }
+            core::mem::swap(&mut f, &mut f_next);
+            core::mem::swap(&mut b, &mut b_next);
+
+            for q in 0..k {
+                let mut ex = E::faer_zero();
+                for j in 0..s {
+                    ex = ex.faer_add(c.read(s - j).faer_mul(x.read(j, q)));
+                }
+                let delta = rhs.read(s, q).canonicalize().faer_sub(ex);
+                for i in 0..s + 1 {
+                    x.write(i, q, x.read(i, q).faer_add(delta.faer_mul(b.read(i))));
+                }
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Solves the equation `self * X = rhs` with a superfast solver, in `O(n log² n)` operations,
+    /// plus `O(n log n)` operations per column of `rhs`.
+    ///
+    /// The first and last columns of the inverse are computed with a divide and conquer
+    /// formulation of the Levinson recursion, where the polynomial products are computed with the
+    /// fast Fourier transform. The solution is then obtained from the Gohberg–Semencul formula,
+    /// which expresses the inverse in terms of products of triangular Toeplitz matrices.
+    ///
+    /// Like [`Self::solve_levinson`], this requires all the leading principal submatrices of
+    /// `self` to be nonsingular. Superfast solvers are less stable than the Levinson recursion, and
+    /// should only be used for well conditioned matrices.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    #[track_caller]
+    pub fn solve_superfast<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        rhs: MatRef<'_, ViewE>,
+    ) -> Result<Mat<E>, LevinsonError> {
+        let n = self.dim();
+        assert!(rhs.nrows() == n);
+        let k = rhs.ncols();
+        if n == 0 {
+            return Ok(Mat::zeros(0, k));
+        }
+
+        let c = self.first_col.as_ref();
+        let r = self.first_row.as_ref();
+        let t0 = c.read(0);
+        if t0 == E::faer_zero() {
+            return Err(LevinsonError {
+                singular_leading_minor: 1,
+            });
+        }
+        let inv_t0 = t0.faer_inv();
+
+        // `f` and `b` are the first and last columns of the inverse
+        let (f, b) = if n == 1 {
+            (alloc::vec![inv_t0], alloc::vec![inv_t0])
+        } else {
+            // products of the matrix with `f = b = 1 / t0`, of dimension one
+            let upper: Vec<E> = (1..n).map(|k| c.read(k).faer_mul(inv_t0)).collect();
+            let lower: Vec<E> = (1..n).map(|k| r.read(k).faer_mul(inv_t0)).collect();
+            let theta = levinson_transfer(&upper, &upper, &lower, &lower, 1)?;
+            let column = |row: &[Vec<E>; 2]| {
+                let coeff = |p: &Vec<E>, k: usize| p.get(k).copied().unwrap_or(E::faer_zero());
+                (0..n)
+                    .map(|k| {
+                        coeff(&row[0], k)
+                            .faer_add(coeff(&row[1], k))
+                            .faer_mul(inv_t0)
+                    })
+                    .collect::<Vec<E>>()
+            };
+            (column(&theta[0]), column(&theta[1]))
+        };
+
+        // `inv(T) = (L(f) U(J b) - L(Z b) U(Z J f)) / f[0]`, where `L(x)` and `U(x)` are the
+        // lower and upper triangular Toeplitz matrices with first column and row `x`, `J` reverses
+        // a vector and `Z` shifts it down by one
+        let zero = E::faer_zero();
+        let l0 = Toeplitz::new(Col::from_fn(n, |i| f[i]), Row::zeros(n));
+        let u0 = Toeplitz::new(
+            Col::from_fn(n, |i| if i == 0 { b[n - 1] } else { zero }),
+            Row::from_fn(n, |j| b[n - 1 - j]),
+        );
+        let l1 = Toeplitz::new(
+            Col::from_fn(n, |i| if i == 0 { zero } else { b[i - 1] }),
+            Row::zeros(n),
+        );
+        let u1 = Toeplitz::new(
+            Col::zeros(n),
+            Row::from_fn(n, |j| if j == 0 { zero } else { f[n - j] }),
+        );
+
+        let rhs = rhs.to_owned();
+        let x0 = &l0 * (&u0 * rhs.as_ref()).as_ref();
+        let x1 = &l1 * (&u1 * rhs.as_ref()).as_ref();
+        let inv_f0 = f[0].faer_inv();
+        Ok(Mat::from_fn(n, k, |i, j| {
+            x0.read(i, j).faer_sub(x1.read(i, j)).faer_mul(inv_f0)
+        }))
+    }
+
+    /// Solves the equation `self * X = rhs` iteratively, where `self` must be Hermitian positive
+    /// definite.
+    ///
+    /// The system is solved with the preconditioned conjugate gradient method, using T. Chan's
+    /// optimal circulant preconditioner. Each iteration costs `O(n log n)` operations, and the
+    /// number of iterations is typically small and independent of `n` for well-behaved
+    /// generating functions, such as those arising from autocorrelation sequences. For general
+    /// Toeplitz matrices, use [`Self::solve_superfast`] or [`Self::solve_levinson`] instead.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.dim()`.
+    ///
+    /// # Errors
+    /// Returns [`CgError::NonPositiveDefiniteOperator`] if `self` is not Hermitian with a positive
+    /// diagonal, e.g. if it wasn't created with [`Self::new_hermitian`] or an exactly conjugate
+    /// first row, or if the iteration detects that it is not positive definite.
+    #[track_caller]
+    pub fn solve_hpd_cg<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        rhs: MatRef<'_, ViewE>,
+        params: CgParams<E>,
+    ) -> Result<(Mat<E>, CgInfo<E>), CgError<E>> {
+        let n = self.dim();
+        assert!(rhs.nrows() == n);
+
+        if !self.is_hermitian_with_positive_diagonal() {
+            return Err(CgError::NonPositiveDefiniteOperator);
+        }
+
+        let rhs = rhs.to_owned();
+        let mut out = Mat::<E>::zeros(n, rhs.ncols());
+        let precond = ChanPrecond::new(self);
+        let parallelism = get_global_parallelism();
+
+        let info = conjugate_gradient(
+            out.as_mut(),
+            &precond,
+            self,
+            rhs.as_ref(),
+            params,
+            parallelism,
+            PodStack::new(&mut ScratchBuffer::new(
+                conjugate_gradient_req(&precond, self, rhs.ncols(), parallelism).unwrap(),
+            )),
+        )?;
+        Ok((out, info))
+    }
+
+    fn is_hermitian_with_positive_diagonal(&self) -> bool {
+        let n = self.dim();
+        if n == 0 {
+            return true;
+        }
+        let diag = self.first_col.read(0);
+        diag.faer_imag() == E::Real::faer_zero()
+            && diag.faer_real() > E::Real::faer_zero()
+            && (1..n).all(|k| self.first_row.read(k) == self.first_col.read(k).faer_conj())
+    }
+}
+
+/// T. Chan's optimal circulant preconditioner, which minimizes the Frobenius distance to the
+/// Toeplitz matrix among all circulant matrices. Applying it solves a system with that circulant
+/// matrix.
+#[derive(Debug)]
+struct ChanPrecond<E: ComplexField> {
+    inner: Circulant<E>,
+}
+
+impl<E: ComplexField> ChanPrecond<E> {
+    fn new(toeplitz: &Toeplitz<E>) -> Self {
+        let n = toeplitz.dim();
+        let inv_n = E::Real::faer_from_f64(n as f64).faer_inv();
+        let first_col = Col::from_fn(n, |k| {
+            let lower = toeplitz
+                .first_col
+                .read(k)
+                .faer_scale_real(E::Real::faer_from_f64((n - k) as f64));
+            let upper = if k == 0 {
+                E::faer_zero()
+            } else {
+                toeplitz
+                    .first_row
+                    .read(n - k)
+                    .faer_scale_real(E::Real::faer_from_f64(k as f64))
+            };
+            lower.faer_add(upper).faer_scale_real(inv_n)
+        });
+        Self {
+            inner: Circulant::new(first_col),
+        }
+    }
+}
+
+impl<E: ComplexField> LinOp<E> for ChanPrecond<E> {
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.inner.apply_req(rhs_ncols, parallelism)
+    }
+
+    fn nrows(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn ncols(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.inner
+            .apply_impl(out, rhs, CirculantOp::Identity, true, stack);
+    }
+
+    fn conj_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.inner
+            .apply_impl(out, rhs, CirculantOp::Conj, true, stack);
+    }
+}
+
+impl<E: ComplexField> Precond<E> for ChanPrecond<E> {}
+
+impl<E: ComplexField> LinOp<E> for Toeplitz<E> {
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        _ = rhs_ncols;
+        _ = parallelism;
+        circulant_apply_req::<E::Real>(self.spectrum_re.len())
+    }
+
+    fn nrows(&self) -> usize {
+        self.dim()
+    }
+
+    fn ncols(&self) -> usize {
+        self.dim()
+    }
+
+    #[track_caller]
+    fn apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.apply_impl(out, rhs, CirculantOp::Identity, stack)
+    }
+
+    #[track_caller]
+    fn conj_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.apply_impl(out, rhs, CirculantOp::Conj, stack)
+    }
+}
+
+impl<E: ComplexField> BiLinOp<E> for Toeplitz<E> {
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.apply_req(rhs_ncols, parallelism)
+    }
+
+    #[track_caller]
+    fn transpose_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.apply_impl(out, rhs, CirculantOp::Transpose, stack)
+    }
+
+    #[track_caller]
+    fn adjoint_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        _ = parallelism;
+        self.apply_impl(out, rhs, CirculantOp::Adjoint, stack)
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<MatRef<'_, E>> for &Toeplitz<E> {
+    type Output = Mat<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: MatRef<'_, E>) -> Self::Output {
+        let parallelism = get_global_parallelism();
+        let mut out = Mat::<E>::zeros(self.dim(), rhs.ncols());
+        self.apply(
+            out.as_mut(),
+            rhs,
+            parallelism,
+            PodStack::new(&mut ScratchBuffer::new(
+                self.apply_req(rhs.ncols(), parallelism).unwrap(),
+            )),
+        );
+        out
+    }
+}
+
+impl<E: ComplexField> core::ops::Mul<ColRef<'_, E>> for &Toeplitz<E> {
+    type Output = Col<E>;
+
+    #[track_caller]
+    fn mul(self, rhs: ColRef<'_, E>) -> Self::Output {
+        let out = self * rhs.as_2d();
+        Col::from_fn(out.nrows(), |i| out.read(i, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::prelude::*;
+
+    fn random_toeplitz(rng: &mut StdRng, n: usize) -> Toeplitz<c64> {
+        Toeplitz::new(
+            Col::from_fn(n, |i| {
+                c64::new(if i == 0 { n as f64 } else { rng.gen() }, rng.gen())
+            }),
+            Row::from_fn(n, |_| c64::new(rng.gen(), rng.gen())),
+        )
+    }
+
+    #[test]
+    fn test_toeplitz_apply() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let parallelism = Parallelism::Rayon(4);
+        for n in [0, 1, 2, 3, 10, 33] {
+            let t = random_toeplitz(rng, n);
+            let dense = t.to_mat();
+            let rhs = Mat::<c64>::from_fn(n, 3, |_, _| c64::new(rng.gen(), rng.gen()));
+
+            assert!((&t * rhs.as_ref() - &dense * &rhs).norm_max() < 1e-10);
+
+            let req = t.transpose_apply_req(3, parallelism).unwrap();
+            let mut out = Mat::<c64>::zeros(n, 3);
+            t.conj_apply(
+                out.as_mut(),
+                rhs.as_ref(),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(req)),
+            );
+            assert!((&out - dense.conjugate() * &rhs).norm_max() < 1e-10);
+            t.transpose_apply(
+                out.as_mut(),
+                rhs.as_ref(),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(req)),
+            );
+            assert!((&out - dense.transpose() * &rhs).norm_max() < 1e-10);
+            t.adjoint_apply(
+                out.as_mut(),
+                rhs.as_ref(),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(req)),
+            );
+            assert!((&out - dense.adjoint() * &rhs).norm_max() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_toeplitz_levinson() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        for n in [0, 1, 2, 3, 10, 50] {
+            let t = random_toeplitz(rng, n);
+            let dense = t.to_mat();
+            let rhs = Mat::<c64>::from_fn(n, 3, |_, _| c64::new(rng.gen(), rng.gen()));
+            let sol = t.solve_levinson(rhs.as_ref()).unwrap();
+            assert!((&dense * &sol - &rhs).norm_max() < 1e-10);
+        }
+
+        let singular = Toeplitz::new(
+            Col::from_fn(3, |i| [0.0, 1.0, 2.0][i]),
+            Row::from_fn(3, |j| [0.0, 1.0, 2.0][j]),
+        );
+        let err = singular.solve_levinson(Mat::<f64>::zeros(3, 1).as_ref());
+        assert!(err.unwrap_err().singular_leading_minor == 1);
+    }
+
+    #[test]
+    fn test_toeplitz_solve_superfast() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        // the larger sizes go through the fft products
+        for n in [0, 1, 2, 3, 10, 50, 129, 300] {
+            let t = random_toeplitz(rng, n);
+            let dense = t.to_mat();
+            let rhs = Mat::<c64>::from_fn(n, 3, |_, _| c64::new(rng.gen(), rng.gen()));
+            let sol = t.solve_superfast(rhs.as_ref()).unwrap();
+            assert!((&dense * &sol - &rhs).norm_max() < 1e-9);
+
+            let sol_levinson = t.solve_levinson(rhs.as_ref()).unwrap();
+            assert!((&sol - &sol_levinson).norm_max() < 1e-9);
+        }
+
+        // autocorrelation of an AR(1) process
+        let n = 2000;
+        let t = Toeplitz::new_hermitian(Col::from_fn(n, |i| 0.5f64.powi(i as i32)));
+        let rhs = Mat::<f64>::from_fn(n, 2, |i, j| (i + j) as f64 / n as f64);
+        let sol = t.solve_superfast(rhs.as_ref()).unwrap();
+        assert!((&t * sol.as_ref() - &rhs).norm_max() < 1e-10);
+
+        let singular = Toeplitz::new(
+            Col::from_fn(3, |i| [0.0, 1.0, 2.0][i]),
+            Row::from_fn(3, |j| [0.0, 1.0, 2.0][j]),
+        );
+        let err = singular.solve_superfast(Mat::<f64>::zeros(3, 1).as_ref());
+        assert!(err.unwrap_err().singular_leading_minor == 1);
+
+        // the leading 2x2 submatrix is singular, the matrix itself isn't
+        let singular_minor = Toeplitz::new(
+            Col::from_fn(3, |i| [1.0, 1.0, 0.0][i]),
+            Row::from_fn(3, |j| [1.0, 1.0, 2.0][j]),
+        );
+        let err = singular_minor.solve_superfast(Mat::<f64>::zeros(3, 1).as_ref());
+        assert!(err.unwrap_err().singular_leading_minor == 2);
+    }
+
+    #[test]
+    fn test_toeplitz_solve_hpd_cg() {
+        for n in [0, 1, 5, 100, 1000] {
+            // autocorrelation of an AR(1) process, which is symmetric positive definite
+            let t = Toeplitz::new_hermitian(Col::from_fn(n, |i| 0.5f64.powi(i as i32)));
+            let rhs = Mat::<f64>::from_fn(n, 2, |i, j| (i + j) as f64 / n as f64);
+
+            let (sol, info) = t.solve_hpd_cg(rhs.as_ref(), CgParams::default()).unwrap();
+            assert!((&t * sol.as_ref() - &rhs).norm_max() < 1e-10);
+            assert!(info.iter_count < 30);
+
+            let sol_levinson = t.solve_levinson(rhs.as_ref()).unwrap();
+            assert!((&sol - &sol_levinson).norm_max() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_toeplitz_solve_hpd_cg_errors() {
+        let rhs = Mat::<f64>::from_fn(3, 1, |i, _| i as f64);
+
+        // not Hermitian
+        let t = Toeplitz::new(
+            Col::from_fn(3, |i| [2.0, 0.5, 0.25][i]),
+            Row::from_fn(3, |j| [2.0, 0.25, 0.0][j]),
+        );
+        assert!(matches!(
+            t.solve_hpd_cg(rhs.as_ref(), CgParams::default()),
+            Err(CgError::NonPositiveDefiniteOperator)
+        ));
+
+        // non positive diagonal
+        let t = Toeplitz::new_hermitian(Col::from_fn(3, |i| [-2.0, 0.5, 0.25][i]));
+        assert!(matches!(
+            t.solve_hpd_cg(rhs.as_ref(), CgParams::default()),
+            Err(CgError::NonPositiveDefiniteOperator)
+        ));
+
+        // Hermitian but indefinite
+        let t = Toeplitz::new_hermitian(Col::from_fn(3, |i| [1.0, 2.0, 0.0][i]));
+        assert!(t.solve_hpd_cg(rhs.as_ref(), CgParams::default()).is_err());
+    }
+}
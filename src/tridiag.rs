@@ -0,0 +1,310 @@
+use crate::{
+    assert,
+    col::{Col, ColMut, ColRef},
+    linalg::evd::tridiag_qr_algorithm::compute_tridiag_real_evd_qr_algorithm,
+    mat::{Mat, MatMut, MatRef},
+    ComplexField, Conjugate, Entity, RealField,
+};
+use reborrow::*;
+
+/// Tridiagonal matrix, stored as its subdiagonal, diagonal and superdiagonal.
+#[derive(Clone, Debug)]
+pub struct Tridiag<E: Entity> {
+    sub: Col<E>,
+    diag: Col<E>,
+    sup: Col<E>,
+}
+
+impl<E: Entity> Tridiag<E> {
+    /// Creates a tridiagonal matrix from its subdiagonal, diagonal and superdiagonal.
+    ///
+    /// # Panics
+    /// Panics if `sub` or `sup` don't have exactly one less element than `diag`, or if `diag` is
+    /// empty and `sub` or `sup` is not.
+    #[track_caller]
+    pub fn new(sub: Col<E>, diag: Col<E>, sup: Col<E>) -> Self {
+        let n = diag.nrows();
+        assert!(all(
+            sub.nrows() == n.saturating_sub(1),
+            sup.nrows() == n.saturating_sub(1),
+        ));
+        Self { sub, diag, sup }
+    }
+
+    /// Returns the number of rows of the matrix.
+    #[inline(always)]
+    pub fn nrows(&self) -> usize {
+        self.diag.nrows()
+    }
+
+    /// Returns the number of columns of the matrix.
+    #[inline(always)]
+    pub fn ncols(&self) -> usize {
+        self.diag.nrows()
+    }
+
+    /// Returns a view over the subdiagonal.
+    #[inline(always)]
+    pub fn sub_diagonal(&self) -> ColRef<'_, E> {
+        self.sub.as_ref()
+    }
+
+    /// Returns a view over the diagonal.
+    #[inline(always)]
+    pub fn diagonal(&self) -> ColRef<'_, E> {
+        self.diag.as_ref()
+    }
+
+    /// Returns a view over the superdiagonal.
+    #[inline(always)]
+    pub fn super_diagonal(&self) -> ColRef<'_, E> {
+        self.sup.as_ref()
+    }
+
+    /// Returns a mutable view over the subdiagonal.
+    #[inline(always)]
+    pub fn sub_diagonal_mut(&mut self) -> ColMut<'_, E> {
+        self.sub.as_mut()
+    }
+
+    /// Returns a mutable view over the diagonal.
+    #[inline(always)]
+    pub fn diagonal_mut(&mut self) -> ColMut<'_, E> {
+        self.diag.as_mut()
+    }
+
+    /// Returns a mutable view over the superdiagonal.
+    #[inline(always)]
+    pub fn super_diagonal_mut(&mut self) -> ColMut<'_, E> {
+        self.sup.as_mut()
+    }
+
+    /// Returns the subdiagonal, diagonal and superdiagonal of the matrix.
+    #[inline(always)]
+    pub fn into_parts(self) -> (Col<E>, Col<E>, Col<E>) {
+        (self.sub, self.diag, self.sup)
+    }
+}
+
+impl<E: ComplexField> Tridiag<E> {
+    /// Returns a new `n×n` tridiagonal matrix filled with zeros.
+    pub fn zeros(n: usize) -> Self {
+        let off = n.saturating_sub(1);
+        Self {
+            sub: Col::zeros(off),
+            diag: Col::zeros(n),
+            sup: Col::zeros(off),
+        }
+    }
+
+    /// Returns the tridiagonal part of `mat`. Entries outside the three central diagonals are
+    /// ignored.
+    ///
+    /// # Panics
+    /// Panics if `mat` is not square.
+    #[track_caller]
+    pub fn from_mat<ViewE: Conjugate<Canonical = E>>(mat: MatRef<'_, ViewE>) -> Self {
+        assert!(mat.nrows() == mat.ncols());
+        let n = mat.nrows();
+        let off = n.saturating_sub(1);
+        Self {
+            sub: Col::from_fn(off, |i| mat.read(i + 1, i).canonicalize()),
+            diag: Col::from_fn(n, |i| mat.read(i, i).canonicalize()),
+            sup: Col::from_fn(off, |i| mat.read(i, i + 1).canonicalize()),
+        }
+    }
+
+    /// Returns the matrix as a dense matrix.
+    pub fn to_mat(&self) -> Mat<E> {
+        let n = self.nrows();
+        let mut mat = Mat::zeros(n, n);
+        for i in 0..n {
+            mat.write(i, i, self.diag.read(i));
+        }
+        for i in 0..n.saturating_sub(1) {
+            mat.write(i + 1, i, self.sub.read(i));
+            mat.write(i, i + 1, self.sup.read(i));
+        }
+        mat
+    }
+
+    /// Solves the equation `self * X = rhs`, and stores the result in `rhs`.
+    ///
+    /// The system is solved by Gaussian elimination with partial pivoting, which remains stable
+    /// for matrices that are not diagonally dominant. If `self` is singular, the solution
+    /// contains non-finite values.
+    ///
+    /// # Panics
+    /// Panics if `rhs.nrows() != self.nrows()`.
+    #[track_caller]
+    pub fn solve_in_place(&self, rhs: MatMut<'_, E>) {
+        let n = self.nrows();
+        assert!(rhs.nrows() == n);
+
+        let mut rhs = rhs;
+        let k = rhs.ncols();
+        if n == 0 {
+            return;
+        }
+
+        let mut d = self.diag.clone();
+        let mut du = self.sup.clone();
+        let mut du2 = Col::<E>::zeros(n.saturating_sub(2));
+        let mut fact = Col::<E>::zeros(n - 1);
+        let mut swapped = alloc::vec![false; n - 1];
+
+        // LU factorization with row interchanges. `du2` holds the fill-in on the second
+        // superdiagonal caused by the interchanges.
+        for i in 0..n - 1 {
+            let dl_i = self.sub.read(i);
+            let d_i = d.read(i);
+            if d_i.faer_abs() >= dl_i.faer_abs() {
+                let f = dl_i.faer_mul(d_i.faer_inv());
+                fact.write(i, f);
+                d.write(i + 1, d.read(i + 1).faer_sub(f.faer_mul(du.read(i))));
+            } else {
+                let f = d_i.faer_mul(dl_i.faer_inv());
+                fact.write(i, f);
+                swapped[i] = true;
+
+                let d_next = d.read(i + 1);
+                d.write(i, dl_i);
+                d.write(i + 1, du.read(i).faer_sub(f.faer_mul(d_next)));
+                if i + 2 < n {
+                    let du_next = du.read(i + 1);
+                    du2.write(i, du_next);
+                    du.write(i + 1, f.faer_mul(du_next).faer_neg());
+                }
+                du.write(i, d_next);
+            }
+        }
+
+        for j in 0..k {
+            let mut b = rhs.rb_mut().col_mut(j);
+
+            // forward substitution with the unit lower factor
+            for i in 0..n - 1 {
+                let f = fact.read(i);
+                if swapped[i] {
+                    let b_i = b.read(i);
+                    let b_next = b.read(i + 1);
+                    b.write(i, b_next);
+                    b.write(i + 1, b_i.faer_sub(f.faer_mul(b_next)));
+                } else {
+                    b.write(i + 1, b.read(i + 1).faer_sub(f.faer_mul(b.read(i))));
+                }
+            }
+
+            // back substitution with the upper factor
+            for i in (0..n).rev() {
+                let mut acc = b.read(i);
+                if i + 1 < n {
+                    acc = acc.faer_sub(du.read(i).faer_mul(b.read(i + 1)));
+                }
+                if i + 2 < n {
+                    acc = acc.faer_sub(du2.read(i).faer_mul(b.read(i + 2)));
+                }
+                b.write(i, acc.faer_mul(d.read(i).faer_inv()));
+            }
+        }
+    }
+
+    /// Returns the solution of the equation `self * X = rhs`.
+    ///
+    /// See [`Self::solve_in_place`].
+    #[track_caller]
+    pub fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: MatRef<'_, ViewE>) -> Mat<E> {
+        let mut out = rhs.to_owned();
+        self.solve_in_place(out.as_mut());
+        out
+    }
+
+    /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the diagonal and the
+    /// subdiagonal are accessed. The order of the eigenvalues is currently unspecified.
+    pub fn selfadjoint_eigenvalues(&self) -> alloc::vec::Vec<E::Real> {
+        let n = self.nrows();
+        let mut diag: alloc::vec::Vec<E::Real> =
+            (0..n).map(|i| self.diag.read(i).faer_real()).collect();
+        // a hermitian tridiagonal matrix is unitarily similar to the real symmetric one whose
+        // offdiagonal entries are the absolute values of the original ones
+        let mut offdiag: alloc::vec::Vec<E::Real> = (0..n.saturating_sub(1))
+            .map(|i| self.sub.read(i).faer_abs())
+            .collect();
+
+        compute_tridiag_real_evd_qr_algorithm(
+            &mut diag,
+            &mut offdiag,
+            None,
+            E::Real::faer_epsilon(),
+            E::Real::faer_zero_threshold(),
+        );
+        diag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64, Side};
+    use rand::prelude::*;
+
+    fn random_tridiag(n: usize) -> Tridiag<c64> {
+        let mut rng = StdRng::seed_from_u64(n as u64);
+        let mut gen = || c64::new(rng.gen(), rng.gen());
+        Tridiag::new(
+            Col::from_fn(n.saturating_sub(1), |_| gen()),
+            Col::from_fn(n, |_| gen()),
+            Col::from_fn(n.saturating_sub(1), |_| gen()),
+        )
+    }
+
+    #[test]
+    fn test_tridiag_roundtrip() {
+        for n in [0, 1, 2, 5] {
+            let trid = random_tridiag(n);
+            let mat = trid.to_mat();
+            let back = Tridiag::from_mat(mat.as_ref());
+            assert!(back.to_mat() == mat);
+        }
+    }
+
+    #[test]
+    fn test_tridiag_solve() {
+        for n in [0, 1, 2, 3, 10, 50] {
+            let mut trid = random_tridiag(n);
+            // force row interchanges by making the diagonal small compared to the subdiagonal
+            for i in 0..n / 2 {
+                let d = trid.diagonal().read(2 * i);
+                trid.diagonal_mut().write(2 * i, d.faer_scale_real(1e-3));
+            }
+            let mat = trid.to_mat();
+
+            let rhs = Mat::<c64>::from_fn(n, 3, |i, j| c64::new(i as f64, j as f64 + 1.0));
+            let sol = trid.solve(rhs.as_ref());
+            let err = &mat * &sol - &rhs;
+            assert!(err.norm_max() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_tridiag_eigenvalues() {
+        for n in [0, 1, 2, 10, 40] {
+            let trid = random_tridiag(n);
+            let mut herm = trid.to_mat();
+            for j in 0..n {
+                herm.write(j, j, c64::new(herm.read(j, j).re, 0.0));
+                for i in j + 1..n {
+                    herm.write(j, i, herm.read(i, j).faer_conj());
+                }
+            }
+
+            let mut actual = Tridiag::from_mat(herm.as_ref()).selfadjoint_eigenvalues();
+            let mut expected = herm.selfadjoint_eigenvalues(Side::Lower);
+            actual.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            for (a, e) in core::iter::zip(actual, expected) {
+                assert!((a - e).abs() < 1e-10);
+            }
+        }
+    }
+}
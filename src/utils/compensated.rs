@@ -0,0 +1,174 @@
+use crate::{ComplexField, RealField};
+
+/// Returns the factor used to split a floating point number into two halves whose products are
+/// exact, i.e., `2^ceil(p / 2) + 1` where `p` is the number of bits of the significand.
+fn split_factor<R: RealField>() -> R {
+    let two = R::faer_from_f64(2.0);
+    // `2 / eps == 2^p`
+    let target = two.faer_mul(R::faer_epsilon().faer_inv());
+    let mut factor = R::faer_one();
+    while factor.faer_mul(factor) < target {
+        factor = factor.faer_mul(two);
+    }
+    factor.faer_add(R::faer_one())
+}
+
+/// Splits `value` into `(hi, lo)` such that `hi + lo == value`, where each component of `hi` and
+/// `lo` has at most half the bits of the significand.
+#[inline]
+fn split<E: ComplexField>(value: E, factor: E::Real) -> (E, E) {
+    let c = value.faer_scale_real(factor);
+    let hi = c.faer_sub(c.faer_sub(value));
+    let lo = value.faer_sub(hi);
+    (hi, lo)
+}
+
+/// Returns `(s, e)` such that `s` is the floating point sum of `a` and `b`, and `s + e == a + b`
+/// exactly, componentwise for complex numbers.
+///
+/// The result is exact unless an overflow occurs.
+#[inline]
+pub fn two_sum<E: ComplexField>(a: E, b: E) -> (E, E) {
+    let s = a.faer_add(b);
+    let bb = s.faer_sub(a);
+    let e = a.faer_sub(s.faer_sub(bb)).faer_add(b.faer_sub(bb));
+    (s, e)
+}
+
+/// Accumulator that computes sums and dot products as if using twice the working precision, by
+/// keeping track of the rounding errors of each operation.
+///
+/// The value is represented as an unevaluated sum `hi + lo` of two floating point numbers, in the
+/// style of double-double arithmetic. Products are split into exact partial products with
+/// Dekker's algorithm, so that no fused multiply-add is required, and complex numbers are handled
+/// by treating their real and imaginary parts separately.
+///
+/// The result is as accurate as if it had been computed in twice the working precision and then
+/// rounded, unless an overflow or an underflow occurs.
+///
+/// # Example
+/// ```
+/// use faer::utils::compensated::CompensatedSum;
+///
+/// let mut acc = CompensatedSum::<f64>::new();
+/// acc.add(1.0);
+/// acc.add(1e-20);
+/// acc.add(-1.0);
+/// assert!(acc.value() == 1e-20);
+///
+/// // (1 + 2^-30)^2 - 1 - 2^-29 == 2^-60
+/// let x = 1.0 + 2.0f64.powi(-30);
+/// let mut acc = CompensatedSum::<f64>::new();
+/// acc.add_mul(x, x);
+/// acc.add(-1.0);
+/// acc.add(-(2.0f64.powi(-29)));
+/// assert!(acc.value() == 2.0f64.powi(-60));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct CompensatedSum<E: ComplexField> {
+    hi: E,
+    lo: E,
+    factor: E::Real,
+}
+
+impl<E: ComplexField> CompensatedSum<E> {
+    /// Returns a new accumulator with the value zero.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            hi: E::faer_zero(),
+            lo: E::faer_zero(),
+            factor: split_factor::<E::Real>(),
+        }
+    }
+
+    /// Adds `value` to the accumulator.
+    #[inline]
+    pub fn add(&mut self, value: E) {
+        let (hi, e) = two_sum(self.hi, value);
+        self.hi = hi;
+        self.lo = self.lo.faer_add(e);
+    }
+
+    /// Adds the product `lhs * rhs` to the accumulator.
+    #[inline]
+    pub fn add_mul(&mut self, lhs: E, rhs: E) {
+        let (rhs_hi, rhs_lo) = split(rhs, self.factor);
+
+        // the product of a purely real or purely imaginary number by a complex number doesn't
+        // mix the components, so the products of the halves are computed exactly
+        let re = E::faer_from_real(lhs.faer_real());
+        let im = lhs.faer_sub(re);
+        for part in [re, im] {
+            if part == E::faer_zero() {
+                continue;
+            }
+            let (hi, lo) = split(part, self.factor);
+            self.add(hi.faer_mul(rhs_hi));
+            self.add(hi.faer_mul(rhs_lo));
+            self.add(lo.faer_mul(rhs_hi));
+            self.add(lo.faer_mul(rhs_lo));
+        }
+    }
+
+    /// Returns the value of the accumulator, rounded to the working precision.
+    #[inline]
+    pub fn value(&self) -> E {
+        self.hi.faer_add(self.lo)
+    }
+
+    /// Returns the leading and trailing parts `(hi, lo)` of the value of the accumulator.
+    #[inline]
+    pub fn parts(&self) -> (E, E) {
+        (self.hi, self.lo)
+    }
+}
+
+impl<E: ComplexField> Default for CompensatedSum<E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+
+    #[test]
+    fn test_split_factor() {
+        assert!(split_factor::<f64>() == 134217729.0);
+        assert!(split_factor::<f32>() == 4097.0);
+    }
+
+    #[test]
+    fn test_two_sum() {
+        let (s, e) = two_sum(1.0f64, 1e-20);
+        assert!(all(s == 1.0, e == 1e-20));
+
+        let (s, e) = two_sum(c64::new(1.0, 1e-20), c64::new(1e-20, 1.0));
+        assert!(all(s == c64::new(1.0, 1.0), e == c64::new(1e-20, 1e-20)));
+    }
+
+    #[test]
+    fn test_compensated_mul() {
+        let t = 2.0f64.powi(-30);
+        let u = 2.0f64.powi(-31);
+
+        // (1 + t) * (1 - t) - 1 == -t^2
+        let mut acc = CompensatedSum::<f64>::new();
+        acc.add_mul(1.0 + t, 1.0 - t);
+        acc.add(-1.0);
+        assert!(acc.value() == -t * t);
+        assert!((1.0 + t) * (1.0 - t) - 1.0 == 0.0);
+
+        // (1 + t + iu) * (1 - t + iu) - 1 == -t^2 - u^2 + 2iu
+        let a = c64::new(1.0 + t, u);
+        let b = c64::new(1.0 - t, u);
+        let mut acc = CompensatedSum::<c64>::new();
+        acc.add_mul(a, b);
+        acc.add(c64::new(-1.0, 0.0));
+        assert!(acc.value() == c64::new(-t * t - u * u, 2.0 * u));
+    }
+}
@@ -0,0 +1,147 @@
+//! Summarized formatting of matrices and vectors, used by their `Display` implementations.
+//!
+//! Matrices with more than [`THRESHOLD`] elements only have their first and last
+//! [`EDGE_ITEMS`] rows and columns printed, with the rest replaced by an ellipsis, so that
+//! formatting a very large matrix stays cheap.
+
+use crate::{mat::MatRef, Entity};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+/// Number of elements above which the output is summarized.
+pub(crate) const THRESHOLD: usize = 1000;
+/// Number of rows and columns printed at each end of a summarized dimension.
+pub(crate) const EDGE_ITEMS: usize = 3;
+
+const ELLIPSIS: &str = "…";
+
+// indices that are printed along a dimension of size `n`, where `None` stands for the elided part
+fn printed(n: usize, summarize: bool) -> Vec<Option<usize>> {
+    if summarize && n > 2 * EDGE_ITEMS {
+        (0..EDGE_ITEMS)
+            .map(Some)
+            .chain(core::iter::once(None))
+            .chain((n - EDGE_ITEMS..n).map(Some))
+            .collect()
+    } else {
+        (0..n).map(Some).collect()
+    }
+}
+
+fn type_name<E>() -> &'static str {
+    let name = core::any::type_name::<E>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Writes a header with the kind, scalar type and dimensions of `mat`, followed by its (possibly
+/// summarized) elements. Vectors are written on a single line.
+pub(crate) fn fmt_summary<E: Entity + fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    kind: &str,
+    dims: &[usize],
+    mat: MatRef<'_, E>,
+) -> fmt::Result {
+    write!(f, "{kind}<{}> (", type_name::<E>())?;
+    for (k, dim) in dims.iter().enumerate() {
+        if k > 0 {
+            f.write_str(" × ")?;
+        }
+        write!(f, "{dim}")?;
+    }
+    f.write_str(")\n")?;
+
+    let is_vector = dims.len() == 1;
+    let summarize = mat.nrows().saturating_mul(mat.ncols()) > THRESHOLD;
+    let rows = printed(mat.nrows(), summarize);
+    let cols = printed(mat.ncols(), summarize);
+
+    let precision = f.precision();
+    let cells = rows
+        .iter()
+        .map(|&i| {
+            i.map(|i| {
+                cols.iter()
+                    .map(|&j| match (j, precision) {
+                        (None, _) => String::from(ELLIPSIS),
+                        (Some(j), Some(p)) => format!("{:.*}", p, mat.read(i, j)),
+                        (Some(j), None) => format!("{}", mat.read(i, j)),
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<Vec<_>>();
+    let width = cells
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|cell| cell.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    if !is_vector {
+        f.write_str("[")?;
+    }
+    if rows.is_empty() {
+        f.write_str("[]")?;
+    }
+    for (k, row) in cells.iter().enumerate() {
+        if k > 0 {
+            f.write_str(",\n ")?;
+        }
+        match row {
+            None => f.write_str(ELLIPSIS)?,
+            Some(row) => {
+                f.write_str("[")?;
+                for (l, cell) in row.iter().enumerate() {
+                    if l > 0 {
+                        f.write_str(", ")?;
+                    }
+                    let padding = width - cell.chars().count();
+                    write!(f, "{:padding$}{cell}", "")?;
+                }
+                f.write_str("]")?;
+            }
+        }
+    }
+    if !is_vector {
+        f.write_str("]")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert, col, mat, Col, Mat};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn test_small() {
+        let a = mat![[1.0, -2.5], [3.0, 4.0f64]];
+        assert!(a.to_string() == "Mat<f64> (2 × 2)\n[[   1, -2.5],\n [   3,    4]]");
+        assert!(format!("{:.1}", a.as_ref()) == "Mat<f64> (2 × 2)\n[[ 1.0, -2.5],\n [ 3.0,  4.0]]");
+
+        let x = col![1.0, 2.0f32];
+        assert!(x.to_string() == "Col<f32> (2)\n[1, 2]");
+        assert!(x.transpose().to_string() == "Row<f32> (2)\n[1, 2]");
+
+        let empty = Mat::<f64>::zeros(0, 3);
+        assert!(empty.to_string() == "Mat<f64> (0 × 3)\n[[]]");
+    }
+
+    #[test]
+    fn test_summary() {
+        let a = Mat::<f64>::from_fn(10_000, 100, |i, j| (i + j) as f64);
+        let s = a.to_string();
+        assert!(s.lines().count() == 8);
+        assert!(s.starts_with("Mat<f64> (10000 × 100)\n[[    0,     1,     2,     …,    97,"));
+        assert!(s.lines().nth(4) == Some(" …,"));
+        assert!(s.ends_with("10096, 10097, 10098]]"));
+
+        // small matrices are printed in full even with a large dimension
+        let b = Mat::<f64>::zeros(100, 1);
+        assert!(b.to_string().lines().count() == 101);
+
+        let x = Col::<f64>::from_fn(2000, |i| i as f64);
+        assert!(x.to_string() == "Col<f64> (2000)\n[   0,    1,    2,    …, 1997, 1998, 1999]");
+    }
+}
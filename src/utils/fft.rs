@@ -0,0 +1,274 @@
+//! Internal discrete Fourier transform over split real/imaginary storage.
+//!
+//! Power of two lengths use an iterative radix-2 transform, other lengths are handled with
+//! Bluestein's algorithm on top of it. The twiddle factors are computed in `f64` precision.
+
+use crate::{assert, ComplexField, MatMut, MatRef, RealField};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+const TAU: f64 = 2.0 * core::f64::consts::PI;
+
+#[inline(always)]
+pub(crate) fn cmul<R: RealField>(ar: R, ai: R, br: R, bi: R) -> (R, R) {
+    (
+        ar.faer_mul(br).faer_sub(ai.faer_mul(bi)),
+        ar.faer_mul(bi).faer_add(ai.faer_mul(br)),
+    )
+}
+
+// `exp(sign * 2πi * num / den)`, with `sign = 1` for the inverse transform, and `-1` otherwise
+#[inline(always)]
+fn twiddle<R: RealField>(num: usize, den: usize, inverse: bool) -> (R, R) {
+    let angle = TAU * (num as f64 / den as f64);
+    let (sin, cos) = (libm::sin(angle), libm::cos(angle));
+    (
+        R::faer_from_f64(cos),
+        R::faer_from_f64(if inverse { sin } else { -sin }),
+    )
+}
+
+fn fft_pow2<R: RealField>(re: &mut [R], im: &mut [R], inverse: bool) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two());
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        for k in 0..half {
+            let (wr, wi) = twiddle::<R>(k, len, inverse);
+            let mut start = 0;
+            while start < n {
+                let (a, b) = (start + k, start + k + half);
+                let (vr, vi) = cmul(re[b], im[b], wr, wi);
+                let (ur, ui) = (re[a], im[a]);
+                re[a] = ur.faer_add(vr);
+                im[a] = ui.faer_add(vi);
+                re[b] = ur.faer_sub(vr);
+                im[b] = ui.faer_sub(vi);
+                start += len;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// Computes the size and alignment of the workspace required by [`fft_in_place`] for a
+/// transform of length `n`.
+pub(crate) fn fft_req<R: RealField>(n: usize) -> Result<StackReq, SizeOverflow> {
+    if n.is_power_of_two() || n <= 1 {
+        return Ok(StackReq::empty());
+    }
+    let m = (2 * n - 1).next_power_of_two();
+    let buf = StackReq::try_new::<R>(m)?;
+    StackReq::try_all_of([buf, buf, buf, buf])
+}
+
+/// Computes the unnormalized discrete Fourier transform of the sequence `re + i * im` in place,
+/// or its inverse if `inverse` is `true`. The inverse transform is not scaled by `1 / n`.
+pub(crate) fn fft_in_place<R: RealField>(
+    re: &mut [R],
+    im: &mut [R],
+    inverse: bool,
+    stack: PodStack<'_>,
+) {
+    let n = re.len();
+    debug_assert!(im.len() == n);
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        fft_pow2(re, im, inverse);
+        return;
+    }
+
+    // bluestein: `jk = (j² + k² - (k - j)²) / 2`, which turns the transform into a convolution
+    // that can be computed with power of two transforms
+    let m = (2 * n - 1).next_power_of_two();
+    let chirp = |k: usize| {
+        twiddle::<R>(
+            ((k as u128 * k as u128) % (2 * n) as u128) as usize,
+            2 * n,
+            inverse,
+        )
+    };
+
+    let (a_re, stack) = stack.make_with(m, |_| R::faer_zero());
+    let (a_im, stack) = stack.make_with(m, |_| R::faer_zero());
+    let (b_re, stack) = stack.make_with(m, |_| R::faer_zero());
+    let (b_im, _) = stack.make_with(m, |_| R::faer_zero());
+
+    for k in 0..n {
+        let (wr, wi) = chirp(k);
+        (a_re[k], a_im[k]) = cmul(re[k], im[k], wr, wi);
+        b_re[k] = wr;
+        b_im[k] = wi.faer_neg();
+        if k > 0 {
+            b_re[m - k] = wr;
+            b_im[m - k] = wi.faer_neg();
+        }
+    }
+
+    fft_pow2(a_re, a_im, false);
+    fft_pow2(b_re, b_im, false);
+    for k in 0..m {
+        (a_re[k], a_im[k]) = cmul(a_re[k], a_im[k], b_re[k], b_im[k]);
+    }
+    fft_pow2(a_re, a_im, true);
+
+    let scale = R::faer_from_f64(m as f64).faer_inv();
+    for k in 0..n {
+        let (wr, wi) = chirp(k);
+        let (xr, xi) = cmul(a_re[k], a_im[k], wr, wi);
+        re[k] = xr.faer_mul(scale);
+        im[k] = xi.faer_mul(scale);
+    }
+}
+
+/// Operation applied to a circulant matrix before multiplying it by a vector.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum CirculantOp {
+    Identity,
+    Conj,
+    Transpose,
+    Adjoint,
+}
+
+/// Computes the size and alignment of the workspace required by [`circulant_apply`] for a
+/// circulant matrix of dimension `m`.
+pub(crate) fn circulant_apply_req<R: RealField>(m: usize) -> Result<StackReq, SizeOverflow> {
+    let buf = StackReq::try_new::<R>(m)?;
+    StackReq::try_all_of([buf, buf, fft_req::<R>(m)?])
+}
+
+/// Multiplies the columns of `rhs`, padded with zeros up to the dimension `m` of the circulant
+/// matrix `C` whose eigenvalues are `spectrum_re + i * spectrum_im`, by `op(C)` (or by its inverse
+/// if `inverse` is `true`), and stores the leading `out.nrows()` rows of the result in `out`.
+///
+/// The eigenvalues are the (unnormalized) discrete Fourier transform of the first column of `C`.
+pub(crate) fn circulant_apply<E: ComplexField>(
+    out: MatMut<'_, E>,
+    rhs: MatRef<'_, E>,
+    spectrum_re: &[E::Real],
+    spectrum_im: &[E::Real],
+    op: CirculantOp,
+    inverse: bool,
+    stack: PodStack<'_>,
+) {
+    let m = spectrum_re.len();
+    assert!(all(
+        spectrum_im.len() == m,
+        rhs.nrows() <= m,
+        out.nrows() <= m,
+        out.ncols() == rhs.ncols(),
+    ));
+    if m == 0 {
+        return;
+    }
+
+    let mut out = out;
+    let (re, stack) = stack.make_with(m, |_| E::Real::faer_zero());
+    let (im, mut stack) = stack.make_with(m, |_| E::Real::faer_zero());
+    let scale = E::Real::faer_from_f64(m as f64).faer_inv();
+
+    for j in 0..rhs.ncols() {
+        re.fill(E::Real::faer_zero());
+        im.fill(E::Real::faer_zero());
+        for i in 0..rhs.nrows() {
+            let x = rhs.read(i, j);
+            re[i] = x.faer_real();
+            im[i] = x.faer_imag();
+        }
+
+        fft_in_place(re, im, false, stack.rb_mut());
+        for q in 0..m {
+            let mirror = if q == 0 { 0 } else { m - q };
+            let (sr, si) = match op {
+                CirculantOp::Identity => (spectrum_re[q], spectrum_im[q]),
+                CirculantOp::Adjoint => (spectrum_re[q], spectrum_im[q].faer_neg()),
+                CirculantOp::Transpose => (spectrum_re[mirror], spectrum_im[mirror]),
+                CirculantOp::Conj => (spectrum_re[mirror], spectrum_im[mirror].faer_neg()),
+            };
+            let (sr, si) = if inverse {
+                let inv_abs2 = sr.faer_abs2().faer_add(si.faer_abs2()).faer_inv();
+                (sr.faer_mul(inv_abs2), si.faer_neg().faer_mul(inv_abs2))
+            } else {
+                (sr, si)
+            };
+            (re[q], im[q]) = cmul(re[q], im[q], sr, si);
+        }
+        fft_in_place(re, im, true, stack.rb_mut());
+
+        for i in 0..out.nrows() {
+            out.write(
+                i,
+                j,
+                from_parts(re[i].faer_mul(scale), im[i].faer_mul(scale)),
+            );
+        }
+    }
+}
+
+/// Returns the value `re + i * im`. The imaginary part is discarded if `E` is a real type.
+#[inline]
+pub(crate) fn from_parts<E: ComplexField>(re: E::Real, im: E::Real) -> E {
+    if coe::is_same::<E, E::Real>() {
+        E::faer_from_real(re)
+    } else {
+        let i = E::faer_from_real(E::Real::faer_one().faer_neg()).faer_sqrt();
+        E::faer_from_real(re).faer_add(i.faer_scale_real(im))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, complex_native::c64};
+    use dyn_stack::GlobalPodBuffer;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_fft() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        for n in [1, 2, 3, 7, 8, 12, 64, 100] {
+            let x: Vec<c64> = (0..n).map(|_| c64::new(rng.gen(), rng.gen())).collect();
+            let mut re: Vec<f64> = x.iter().map(|x| x.re).collect();
+            let mut im: Vec<f64> = x.iter().map(|x| x.im).collect();
+
+            let mut mem = GlobalPodBuffer::new(fft_req::<f64>(n).unwrap());
+            fft_in_place(&mut re, &mut im, false, PodStack::new(&mut mem));
+
+            for k in 0..n {
+                let mut expected = c64::new(0.0, 0.0);
+                for (j, &x) in x.iter().enumerate() {
+                    let angle = -TAU * ((j * k) % n) as f64 / n as f64;
+                    expected += x * c64::new(angle.cos(), angle.sin());
+                }
+                assert!((c64::new(re[k], im[k]) - expected).faer_abs() < 1e-10);
+            }
+
+            fft_in_place(&mut re, &mut im, true, PodStack::new(&mut mem));
+            for k in 0..n {
+                let actual = c64::new(re[k], im[k]) * c64::new(1.0 / n as f64, 0.0);
+                assert!((actual - x[k]).faer_abs() < 1e-12);
+            }
+
+            let z: c64 = from_parts(re[0], im[0]);
+            assert!(z == c64::new(re[0], im[0]));
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! When the `instrument` feature is enabled, each call to one of the instrumented operations
+//! (matrix multiplication, triangular solves, and the Cholesky, LU and QR factorizations)
+//! produces a [`Record`] containing the operation name, its nominal number of floating point
+//! operations and bytes accessed, and its wall clock duration.
+//!
+//! The records can be observed in two ways:
+//! - a global callback, set with [`set_callback`], is called with each record as soon as the
+//! operation completes, from the thread that ran it,
+//! - the records produced by the current thread are accumulated per operation name, and can be
+//! retrieved with [`take_report`].
+//!
+//! Operations that are called from within another one (e.g. the matrix multiplications performed
+//! during a Cholesky factorization) are recorded as well, with a nonzero [`Record::depth`]. The
+//! counts of an operation include those of the operations it calls, so the entries of a report
+//! shouldn't be summed together. Operations that are run by the worker threads of a parallel
+//! algorithm are reported to the global callback, but are accumulated in the report of the worker
+//! thread, not in that of the caller.
+//!
+//! The flop counts are the usual leading order estimates, where complex additions and
+//! multiplications count as 2 and 6 real operations. The byte counts are the minimum amount of
+//! memory that has to be read or written, i.e., each input and output element counted once.
+//!
+//! ```
+//! use faer::{utils::instrument, Mat};
+//!
+//! instrument::take_report();
+//! let a = Mat::<f64>::identity(64, 64);
+//! let _ = &a * &a;
+//!
+//! let report = instrument::take_report();
+//! let matmul = report.get("matmul").unwrap();
+//! assert!(matmul.calls == 1);
+//! assert!(matmul.flops == 2 * 64 * 64 * 64);
+//! ```
+
+use crate::ComplexField;
+use alloc::vec::Vec;
+use core::{cell::RefCell, fmt, time::Duration};
+use std::{sync::RwLock, time::Instant};
+
+/// Measurements of a single call to an instrumented operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Record {
+    /// Name of the operation.
+    pub name: &'static str,
+    /// Number of instrumented operations that were running on the current thread when this one
+    /// was called.
+    pub depth: usize,
+    /// Nominal number of real floating point operations.
+    pub flops: u64,
+    /// Nominal number of bytes read or written.
+    pub bytes: u64,
+    /// Wall clock duration of the operation.
+    pub duration: Duration,
+}
+
+/// Accumulated measurements of all the calls to an instrumented operation.
+#[derive(Clone, Debug)]
+pub struct Summary {
+    /// Name of the operation.
+    pub name: &'static str,
+    /// Number of calls.
+    pub calls: u64,
+    /// Total nominal number of real floating point operations.
+    pub flops: u64,
+    /// Total nominal number of bytes read or written.
+    pub bytes: u64,
+    /// Total wall clock duration.
+    pub duration: Duration,
+}
+
+impl Summary {
+    /// Returns the achieved rate in floating point operations per second.
+    #[inline]
+    pub fn flops_per_second(&self) -> f64 {
+        self.flops as f64 / self.duration.as_secs_f64()
+    }
+}
+
+/// Accumulated measurements of the instrumented operations called from a thread, in the order in
+/// which they first completed.
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// Per-operation measurements.
+    pub summaries: Vec<Summary>,
+}
+
+impl Report {
+    /// Returns the measurements of the operation with the given name, if it was called.
+    pub fn get(&self, name: &str) -> Option<&Summary> {
+        self.summaries.iter().find(|summary| summary.name == name)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<24} {:>10} {:>14} {:>14} {:>14} {:>10}",
+            "operation", "calls", "flops", "bytes", "time (s)", "GFLOP/s"
+        )?;
+        for summary in &self.summaries {
+            writeln!(
+                f,
+                "{:<24} {:>10} {:>14} {:>14} {:>14.6} {:>10.3}",
+                summary.name,
+                summary.calls,
+                summary.flops,
+                summary.bytes,
+                summary.duration.as_secs_f64(),
+                summary.flops_per_second() * 1e-9,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+struct State {
+    depth: usize,
+    report: Report,
+}
+
+std::thread_local! {
+    static STATE: RefCell<State> = const {
+        RefCell::new(State {
+            depth: 0,
+            report: Report {
+                summaries: Vec::new(),
+            },
+        })
+    };
+}
+
+static CALLBACK: RwLock<Option<fn(&Record)>> = RwLock::new(None);
+
+/// Sets the function that is called with the record of each instrumented operation, or removes it
+/// if `callback` is `None`.
+pub fn set_callback(callback: Option<fn(&Record)>) {
+    *CALLBACK.write().unwrap() = callback;
+}
+
+/// Returns the measurements accumulated on the current thread since the last call to this
+/// function, and resets them.
+pub fn take_report() -> Report {
+    STATE.with(|state| core::mem::take(&mut state.borrow_mut().report))
+}
+
+/// Nominal number of real floating point operations corresponding to `real_flops` operations on
+/// values of type `E`.
+#[inline]
+pub(crate) fn flops<E: ComplexField>(real_flops: f64) -> u64 {
+    // a complex fused multiply-add costs 4 real multiplications and 4 real additions
+    if coe::is_same::<E, E::Real>() {
+        real_flops as u64
+    } else {
+        (4.0 * real_flops) as u64
+    }
+}
+
+/// Nominal number of bytes occupied by `count` values of type `E`.
+#[inline]
+pub(crate) fn bytes<E: ComplexField>(count: usize) -> u64 {
+    (count as u64).saturating_mul(core::mem::size_of::<E>() as u64)
+}
+
+/// Measures an operation from its creation until it is dropped.
+pub(crate) struct Scope {
+    name: &'static str,
+    depth: usize,
+    flops: u64,
+    bytes: u64,
+    start: Instant,
+}
+
+impl Scope {
+    #[inline]
+    pub(crate) fn new(name: &'static str, flops: u64, bytes: u64) -> Self {
+        let depth = STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.depth += 1;
+            state.depth - 1
+        });
+        Self {
+            name,
+            depth,
+            flops,
+            bytes,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let record = Record {
+            name: self.name,
+            depth: self.depth,
+            flops: self.flops,
+            bytes: self.bytes,
+            duration: self.start.elapsed(),
+        };
+
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.depth -= 1;
+            let summaries = &mut state.report.summaries;
+            let summary = match summaries.iter().position(|s| s.name == record.name) {
+                Some(idx) => &mut summaries[idx],
+                None => {
+                    summaries.push(Summary {
+                        name: record.name,
+                        calls: 0,
+                        flops: 0,
+                        bytes: 0,
+                        duration: Duration::ZERO,
+                    });
+                    summaries.last_mut().unwrap()
+                }
+            };
+            summary.calls += 1;
+            summary.flops += record.flops;
+            summary.bytes += record.bytes;
+            summary.duration += record.duration;
+        });
+
+        if let Some(callback) = *CALLBACK.read().unwrap() {
+            callback(&record);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, linalg::cholesky::llt::compute as llt, Mat, Parallelism};
+    use dyn_stack::PodStack;
+
+    #[test]
+    fn test_report() {
+        take_report();
+
+        let n = 100;
+        let mut a = Mat::<f64>::from_fn(n, n, |i, j| if i == j { n as f64 } else { 1.0 });
+        llt::cholesky_in_place(
+            a.as_mut(),
+            Default::default(),
+            Parallelism::None,
+            PodStack::new(&mut []),
+            Default::default(),
+        )
+        .unwrap();
+
+        let report = take_report();
+        let cholesky = report.get("cholesky").unwrap();
+        assert!(cholesky.calls == 1);
+        assert!(cholesky.flops == (n * n * n / 3) as u64);
+        assert!(cholesky.bytes == (n * (n + 1) * 8) as u64);
+        assert!(report.get("solve_lower_triangular").is_some());
+
+        assert!(take_report().summaries.is_empty());
+    }
+
+    #[test]
+    fn test_complex_flops() {
+        assert!(flops::<f64>(10.0) == 10);
+        assert!(flops::<crate::complex_native::c64>(10.0) == 40);
+    }
+}
@@ -45,8 +45,48 @@ impl DivCeil for usize {
     }
 }
 
+/// Returns the coordinate matrices `(X, Y)` of the grid spanned by `x` and `y`, both with
+/// dimensions `(y.nrows(), x.nrows())`, such that `X[(i, j)] == x[j]` and `Y[(i, j)] == y[i]`.
+///
+/// This matches the default `"xy"` indexing of numpy's `meshgrid`.
+///
+/// # Example
+/// ```
+/// use faer::{col, mat, utils::meshgrid};
+///
+/// let x = col![1.0, 2.0, 3.0];
+/// let y = col![4.0, 5.0];
+/// let (xx, yy) = meshgrid(x.as_ref(), y.as_ref());
+///
+/// assert_eq!(xx, mat![[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]]);
+/// assert_eq!(yy, mat![[4.0, 4.0, 4.0], [5.0, 5.0, 5.0]]);
+/// ```
+pub fn meshgrid<E: crate::Entity>(
+    x: crate::ColRef<'_, E>,
+    y: crate::ColRef<'_, E>,
+) -> (crate::Mat<E>, crate::Mat<E>) {
+    let (m, n) = (y.nrows(), x.nrows());
+    (
+        crate::Mat::from_fn(m, n, |_, j| x.read(j)),
+        crate::Mat::from_fn(m, n, |i, _| y.read(i)),
+    )
+}
+
+pub(crate) mod display;
+
 /// Index and matrix types with compile time checks, whichh can replace bound checks at runtime.
 pub mod constrained;
+/// Compensated arithmetic, for accumulating sums and dot products in extended precision.
+pub mod compensated;
+pub(crate) mod fft;
+/// Flop, memory traffic and timing counters for the high level operations.
+#[cfg(feature = "instrument")]
+#[cfg_attr(docsrs, doc(cfg(feature = "instrument")))]
+pub mod instrument;
+/// Memory placement of large matrices on NUMA systems.
+pub mod numa;
+/// Per-thread cache of the workspace memory used by the high level APIs.
+pub mod scratch;
 /// Simd operations for a specific type satisfying [`ComplexField`](crate::ComplexField).
 pub mod simd;
 /// Slice types for [entities](crate::Entity).
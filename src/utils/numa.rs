@@ -0,0 +1,394 @@
+//! On NUMA systems (e.g. servers with several sockets), each memory page is located on one node,
+//! and is accessed faster by the threads running on that node. Operating systems usually place a
+//! page on the node of the thread that first writes to it.
+//!
+//! By default, the matrices are initialized by the thread that allocates them, so that all of
+//! their pages end up on a single node and the threads running on the other nodes only have remote
+//! access to them. Large matrices can instead be created with
+//! [`Mat::zeros_with_parallelism`](crate::Mat::zeros_with_parallelism), which initializes their
+//! columns in parallel, or with [`NumaMat::zeros`], which takes an explicit [`NumaPolicy`]:
+//! - [`NumaPolicy::FirstTouch`] initializes the matrix in parallel, with the columns split between
+//! the threads in the same way as in the parallel kernels (see
+//! [`par_split_indices`](crate::utils::thread::par_split_indices)). The pages of each block of
+//! columns are then placed on the node of the thread that processes it.
+//! - [`NumaPolicy::Interleave`] spreads the pages of the matrix round-robin over all the nodes,
+//! which balances the memory bandwidth when the access pattern isn't known in advance.
+//! - [`NumaPolicy::Partitioned`] splits the columns of the matrix into one contiguous block per
+//! node, and binds the pages of each block to its node, in the same order as the blocks of columns
+//! of the parallel kernels.
+//!
+//! faer doesn't change the cpu affinity of the threads that run its tasks, since the rayon worker
+//! threads are shared with the rest of the process. To keep the tasks on the nodes that own their
+//! data, run them on a dedicated thread pool whose threads are pinned by the caller, e.g. in the
+//! [`start_handler`](rayon::ThreadPoolBuilder::start_handler) of the pool, with
+//! [`with_thread_pool`](crate::utils::thread::with_thread_pool).
+//!
+//! The [`Interleave`](NumaPolicy::Interleave) and [`Partitioned`](NumaPolicy::Partitioned)
+//! policies are only implemented on Linux, where the matrix owns a private anonymous mapping that
+//! is bound with `mbind` before any of its pages is touched. On other systems, or if the mapping
+//! can't be created, they behave like [`NumaPolicy::FirstTouch`]. Placement failures, e.g. due to
+//! a restricted environment, are ignored.
+
+use crate::{
+    mat::{MatMut, MatRef},
+    Mat, Parallelism, SimpleEntity,
+};
+
+/// Placement policy of the memory of a [`NumaMat`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NumaPolicy {
+    /// The matrix is initialized by the calling thread.
+    #[default]
+    Default,
+    /// The matrix is initialized in parallel, so that each page is placed on the node of the
+    /// thread that first writes to it.
+    FirstTouch,
+    /// The pages of the matrix are interleaved over all the nodes.
+    Interleave,
+    /// The columns of the matrix are split into one block per node, and each block is bound to
+    /// its node.
+    Partitioned,
+}
+
+/// Returns the number of NUMA nodes of the system, or one if it can't be determined.
+pub fn node_count() -> usize {
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    {
+        Ord::max(sys::topology().len(), 1)
+    }
+    #[cfg(not(all(feature = "std", target_os = "linux")))]
+    {
+        1
+    }
+}
+
+/// Owning column-major matrix whose memory is placed on the NUMA nodes according to a
+/// [`NumaPolicy`].
+///
+/// The matrix can't be resized. Use [`NumaMat::as_ref`] and [`NumaMat::as_mut`] to pass it to the
+/// faer kernels.
+pub struct NumaMat<E: SimpleEntity> {
+    nrows: usize,
+    ncols: usize,
+    storage: Storage<E>,
+}
+
+enum Storage<E: SimpleEntity> {
+    Heap(Mat<E>),
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    Mapped(sys::Mapping),
+}
+
+// SAFETY: the mapping is owned by the matrix, and is only accessed through `&self` and
+// `&mut self`, like the memory of a `Mat`
+unsafe impl<E: SimpleEntity + Send> Send for NumaMat<E> {}
+unsafe impl<E: SimpleEntity + Sync> Sync for NumaMat<E> {}
+
+impl<E: SimpleEntity> NumaMat<E> {
+    /// Returns a new matrix with dimensions `(nrows, ncols)`, filled with zeros, whose memory is
+    /// placed according to `policy`.
+    ///
+    /// With [`NumaPolicy::FirstTouch`], the columns are initialized using `parallelism`, see
+    /// [`Mat::zeros_with_parallelism`].
+    ///
+    /// # Panics
+    /// The function panics if the total capacity in bytes exceeds `isize::MAX`.
+    pub fn zeros(nrows: usize, ncols: usize, policy: NumaPolicy, parallelism: Parallelism) -> Self {
+        #[cfg(all(feature = "std", target_os = "linux"))]
+        if matches!(policy, NumaPolicy::Interleave | NumaPolicy::Partitioned)
+            && nrows > 0
+            && ncols > 0
+        {
+            let col_bytes = nrows
+                .checked_mul(core::mem::size_of::<E>())
+                .expect("the capacity in bytes must not exceed isize::MAX");
+            let len = col_bytes
+                .checked_mul(ncols)
+                .filter(|&len| len <= isize::MAX as usize)
+                .expect("the capacity in bytes must not exceed isize::MAX");
+            // the pages of an anonymous mapping are zero-filled on first access
+            if let Some(mapping) = sys::Mapping::new(len, col_bytes, ncols, policy) {
+                return Self {
+                    nrows,
+                    ncols,
+                    storage: Storage::Mapped(mapping),
+                };
+            }
+        }
+
+        let mat = match policy {
+            NumaPolicy::Default => Mat::zeros(nrows, ncols),
+            _ => Mat::zeros_with_parallelism(nrows, ncols, parallelism),
+        };
+        Self {
+            nrows,
+            ncols,
+            storage: Storage::Heap(mat),
+        }
+    }
+
+    /// Returns the number of rows of the matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns of the matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns a view over the matrix.
+    #[inline]
+    pub fn as_ref(&self) -> MatRef<'_, E> {
+        match &self.storage {
+            Storage::Heap(mat) => mat.as_ref(),
+            #[cfg(all(feature = "std", target_os = "linux"))]
+            Storage::Mapped(mapping) => unsafe {
+                crate::mat::from_raw_parts(
+                    mapping.as_ptr() as *const E,
+                    self.nrows,
+                    self.ncols,
+                    1,
+                    self.nrows as isize,
+                )
+            },
+        }
+    }
+
+    /// Returns a mutable view over the matrix.
+    #[inline]
+    pub fn as_mut(&mut self) -> MatMut<'_, E> {
+        match &mut self.storage {
+            Storage::Heap(mat) => mat.as_mut(),
+            #[cfg(all(feature = "std", target_os = "linux"))]
+            Storage::Mapped(mapping) => unsafe {
+                crate::mat::from_raw_parts_mut(
+                    mapping.as_ptr() as *mut E,
+                    self.nrows,
+                    self.ncols,
+                    1,
+                    self.nrows as isize,
+                )
+            },
+        }
+    }
+}
+
+impl<E: SimpleEntity + core::fmt::Debug> core::fmt::Debug for NumaMat<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+#[cfg(all(feature = "std", target_os = "linux"))]
+mod sys {
+    use super::*;
+    use crate::utils::thread::par_split_indices;
+    use alloc::vec::Vec;
+    use core::ffi::{c_int, c_ulong};
+    use std::sync::Mutex;
+
+    // from <linux/mempolicy.h>
+    const MPOL_BIND: c_int = 2;
+    const MPOL_INTERLEAVE: c_int = 3;
+
+    // large enough for 1024 nodes
+    const MASK_WORDS: usize = 16;
+    type Mask = [c_ulong; MASK_WORDS];
+    const WORD_BITS: usize = c_ulong::BITS as usize;
+
+    pub(super) struct Node {
+        id: usize,
+    }
+
+    // parses lists such as `0-3,8,10-11`
+    pub(super) fn parse_list(list: &str) -> Option<Vec<usize>> {
+        let mut out = Vec::new();
+        for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+            let (start, end) = match range.split_once('-') {
+                Some((start, end)) => (start.parse().ok()?, end.parse().ok()?),
+                None => {
+                    let idx = range.parse().ok()?;
+                    (idx, idx)
+                }
+            };
+            out.extend(start..=end);
+        }
+        Some(out)
+    }
+
+    fn to_mask(indices: impl IntoIterator<Item = usize>) -> Mask {
+        let mut mask = [0; MASK_WORDS];
+        for idx in indices {
+            if idx < MASK_WORDS * WORD_BITS {
+                mask[idx / WORD_BITS] |= 1 << (idx % WORD_BITS);
+            }
+        }
+        mask
+    }
+
+    fn read_topology() -> Vec<Node> {
+        let read = |path: &str| std::fs::read_to_string(path).ok();
+        let nodes = read("/sys/devices/system/node/online")
+            .and_then(|list| parse_list(&list))
+            .unwrap_or_default();
+        nodes
+            .into_iter()
+            .filter_map(|id| {
+                let cpus = read(&format!("/sys/devices/system/node/node{id}/cpulist"))?;
+                let cpus = parse_list(&cpus)?;
+                (!cpus.is_empty()).then_some(Node { id })
+            })
+            .collect()
+    }
+
+    // nodes without cpus are excluded
+    pub(super) fn topology() -> &'static [Node] {
+        static TOPOLOGY: Mutex<Option<&'static [Node]>> = Mutex::new(None);
+        let mut topology = TOPOLOGY.lock().unwrap_or_else(|e| e.into_inner());
+        *topology.get_or_insert_with(|| Vec::leak(read_topology()))
+    }
+
+    pub(super) struct Mapping {
+        ptr: *mut u8,
+        len: usize,
+    }
+
+    impl Mapping {
+        // creates a private anonymous mapping of `len` bytes, and binds it according to `policy`
+        // before any of its pages is touched
+        pub(super) fn new(
+            len: usize,
+            col_bytes: usize,
+            ncols: usize,
+            policy: NumaPolicy,
+        ) -> Option<Self> {
+            let ptr = unsafe {
+                libc::mmap(
+                    core::ptr::null_mut(),
+                    len,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                    -1,
+                    0,
+                )
+            };
+            if ptr == libc::MAP_FAILED {
+                return None;
+            }
+            let this = Self {
+                ptr: ptr as *mut u8,
+                len,
+            };
+            this.bind(col_bytes, ncols, policy);
+            Some(this)
+        }
+
+        #[inline]
+        pub(super) fn as_ptr(&self) -> *mut u8 {
+            self.ptr
+        }
+
+        fn bind(&self, col_bytes: usize, ncols: usize, policy: NumaPolicy) {
+            let nodes = topology();
+            if nodes.len() <= 1 {
+                return;
+            }
+            let page_size = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+                size if size > 0 => size as usize,
+                _ => return,
+            };
+
+            // the mapping is page aligned, and the pages that straddle two blocks are bound to
+            // the node of the first one
+            let mbind = |col_start: usize, ncols: usize, mode: c_int, mask: &Mask| {
+                let start = (col_start * col_bytes + page_size - 1) / page_size * page_size;
+                let end = Ord::min(
+                    ((col_start + ncols) * col_bytes + page_size - 1) / page_size * page_size,
+                    self.len,
+                );
+                if start < end {
+                    unsafe {
+                        libc::syscall(
+                            libc::SYS_mbind,
+                            self.ptr.add(start),
+                            (end - start) as c_ulong,
+                            mode as c_ulong,
+                            mask.as_ptr(),
+                            (MASK_WORDS * WORD_BITS + 1) as c_ulong,
+                            0 as c_ulong,
+                        );
+                    }
+                }
+            };
+
+            match policy {
+                NumaPolicy::Interleave => {
+                    let mask = to_mask(nodes.iter().map(|node| node.id));
+                    mbind(0, ncols, MPOL_INTERLEAVE, &mask);
+                }
+                NumaPolicy::Partitioned => {
+                    for (k, node) in nodes.iter().enumerate() {
+                        let (col_start, ncols) = par_split_indices(ncols, k, nodes.len());
+                        mbind(col_start, ncols, MPOL_BIND, &to_mask([node.id]));
+                    }
+                }
+                NumaPolicy::Default | NumaPolicy::FirstTouch => {}
+            }
+        }
+    }
+
+    impl Drop for Mapping {
+        fn drop(&mut self) {
+            unsafe { libc::munmap(self.ptr as *mut libc::c_void, self.len) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert;
+
+    #[test]
+    fn test_zeros_with_parallelism() {
+        for (m, n) in [(0, 0), (3, 0), (0, 5), (17, 13), (1, 100), (1024, 600)] {
+            for parallelism in [Parallelism::None, Parallelism::Rayon(4)] {
+                let mat = Mat::<f64>::zeros_with_parallelism(m, n, parallelism);
+                assert!(mat == Mat::<f64>::from_fn(m, n, |_, _| 0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_numa_mat() {
+        for policy in [
+            NumaPolicy::Default,
+            NumaPolicy::FirstTouch,
+            NumaPolicy::Interleave,
+            NumaPolicy::Partitioned,
+        ] {
+            for (m, n) in [(0, 5), (3, 0), (17, 13), (1024, 600)] {
+                let mut mat = NumaMat::<f64>::zeros(m, n, policy, Parallelism::Rayon(4));
+                assert!(all(mat.nrows() == m, mat.ncols() == n));
+                assert!(mat.as_ref() == Mat::<f64>::zeros(m, n));
+                if m > 0 && n > 0 {
+                    mat.as_mut().write(m - 1, n - 1, 1.0);
+                    mat.as_mut().write(0, 0, 2.0);
+                    assert!(mat.as_ref().norm_max() == 2.0);
+                    assert!(mat.as_ref().read(m - 1, n - 1) == 1.0);
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    #[test]
+    fn test_sys() {
+        assert!(sys::parse_list("0-3,8,10-11\n").unwrap() == [0, 1, 2, 3, 8, 10, 11]);
+        assert!(sys::parse_list("").unwrap().is_empty());
+        assert!(sys::parse_list("a-b").is_none());
+    }
+}
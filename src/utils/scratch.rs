@@ -0,0 +1,208 @@
+//! The high level APIs (e.g. the `solve` methods of the decompositions in
+//! [`solvers`](crate::linalg::solvers)) don't take a [`PodStack`](dyn_stack::PodStack) parameter,
+//! and need to obtain their workspace memory internally. Instead of allocating a new buffer for
+//! each call, they use a [`ScratchBuffer`], which reuses a buffer cached by the current thread when
+//! it is large enough. This avoids pressure on the allocator when such functions are called many
+//! times in a loop.
+//!
+//! The cached buffer grows to the largest workspace requested on the thread, up to
+//! [`MAX_CACHED_BYTES`], and is kept until the thread exits or [`release`] is called. Larger
+//! workspaces are allocated separately and freed when they are dropped, so that a single large
+//! call doesn't pin its memory for the lifetime of the thread. When the `std` feature is disabled,
+//! the buffers are not cached.
+
+use dyn_stack::{GlobalPodBuffer, StackReq};
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static POOL: core::cell::Cell<Option<(StackReq, GlobalPodBuffer)>> = const {
+        core::cell::Cell::new(None)
+    };
+}
+
+/// Workspace memory buffer, taken from the cache of the current thread if possible, and returned
+/// to it when dropped.
+///
+/// The buffer dereferences to a byte slice that can be passed to
+/// [`PodStack::new`](dyn_stack::PodStack::new).
+///
+/// If a scratch buffer is created while another one is alive on the same thread (e.g. when a high
+/// level function calls another one), the new buffer is allocated separately. When both are
+/// dropped, the larger one is kept in the cache.
+pub struct ScratchBuffer {
+    req: StackReq,
+    buf: Option<GlobalPodBuffer>,
+}
+
+/// Size in bytes above which the workspace buffers are not cached.
+pub const MAX_CACHED_BYTES: usize = 1 << 26;
+
+#[cfg(feature = "std")]
+fn cacheable(req: StackReq) -> bool {
+    req.size_bytes() <= MAX_CACHED_BYTES
+}
+
+#[cfg(feature = "std")]
+fn fits(cached: StackReq, req: StackReq) -> bool {
+    cached.size_bytes() >= req.size_bytes() && cached.align_bytes() >= req.align_bytes()
+}
+
+impl ScratchBuffer {
+    /// Returns a buffer that satisfies the memory requirement `req`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocation fails.
+    pub fn new(req: StackReq) -> Self {
+        #[cfg(feature = "std")]
+        if cacheable(req) {
+            let cached = POOL.try_with(|pool| pool.take()).ok().flatten();
+            if let Some((cached_req, buf)) = cached {
+                if fits(cached_req, req) {
+                    return Self {
+                        req: cached_req,
+                        buf: Some(buf),
+                    };
+                }
+                // free the smaller buffer before allocating the new one
+                drop(buf);
+                let req = StackReq::try_any_of([cached_req, req]).unwrap();
+                return Self {
+                    req,
+                    buf: Some(GlobalPodBuffer::new(req)),
+                };
+            }
+        }
+        Self {
+            req,
+            buf: Some(GlobalPodBuffer::new(req)),
+        }
+    }
+}
+
+impl core::ops::Deref for ScratchBuffer {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.buf.as_ref().unwrap()
+    }
+}
+
+impl core::ops::DerefMut for ScratchBuffer {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buf.as_mut().unwrap()
+    }
+}
+
+impl Drop for ScratchBuffer {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        if let Some(buf) = self.buf.take().filter(|_| cacheable(self.req)) {
+            let req = self.req;
+            let _ = POOL.try_with(|pool| {
+                let keep = match pool.take() {
+                    Some((cached_req, cached)) if fits(cached_req, req) => (cached_req, cached),
+                    _ => (req, buf),
+                };
+                pool.set(Some(keep));
+            });
+        }
+    }
+}
+
+/// Frees the workspace buffer cached by the current thread, if any.
+pub fn release() {
+    #[cfg(feature = "std")]
+    let _ = POOL.try_with(|pool| drop(pool.take()));
+}
+
+/// Returns the memory requirement satisfied by the workspace buffer cached by the current thread,
+/// or `None` if there is none.
+pub fn cached_req() -> Option<StackReq> {
+    #[cfg(feature = "std")]
+    {
+        POOL.try_with(|pool| {
+            let cached = pool.take();
+            let req = cached.as_ref().map(|(req, _)| *req);
+            pool.set(cached);
+            req
+        })
+        .ok()
+        .flatten()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        None
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{assert, linalg::solvers::SpSolver, Mat};
+    use dyn_stack::PodStack;
+
+    #[test]
+    fn test_reuse() {
+        release();
+        assert!(cached_req().is_none());
+
+        let req = StackReq::new::<f64>(1000);
+        let ptr = {
+            let mut buf = ScratchBuffer::new(req);
+            let stack = PodStack::new(&mut buf);
+            let (slice, _) = stack.make_raw::<f64>(1000);
+            slice.as_ptr()
+        };
+        assert!(cached_req().unwrap().size_bytes() >= req.size_bytes());
+
+        // smaller requests reuse the cached buffer
+        {
+            let mut buf = ScratchBuffer::new(StackReq::new::<f64>(10));
+            assert!(buf.as_mut_ptr() as *const f64 == ptr);
+        }
+
+        // nested requests allocate a separate buffer, and the larger one is kept
+        {
+            let _outer = ScratchBuffer::new(StackReq::new::<f64>(10));
+            let inner = ScratchBuffer::new(StackReq::new::<f64>(10));
+            assert!(inner.as_ptr() as *const f64 != ptr);
+        }
+        assert!(cached_req().unwrap().size_bytes() >= req.size_bytes());
+
+        // larger requests grow the cached buffer
+        drop(ScratchBuffer::new(StackReq::new::<f64>(5000)));
+        assert!(cached_req().unwrap().size_bytes() >= 5000 * 8);
+
+        // requests above the limit are neither cached, nor evict the cached buffer
+        let cached = cached_req().unwrap().size_bytes();
+        drop(ScratchBuffer::new(StackReq::new::<u8>(
+            MAX_CACHED_BYTES + 1,
+        )));
+        assert!(cached_req().unwrap().size_bytes() == cached);
+
+        release();
+        assert!(cached_req().is_none());
+    }
+
+    #[test]
+    fn test_solver() {
+        release();
+        let n = 50;
+        let a = Mat::<f64>::from_fn(n, n, |i, j| {
+            if i == j {
+                10.0
+            } else {
+                1.0 / (1 + i + j) as f64
+            }
+        });
+        let b = Mat::<f64>::from_fn(n, 3, |i, j| (i + j) as f64);
+
+        let lu = a.partial_piv_lu();
+        let x = lu.solve(&b);
+        assert!(cached_req().is_some());
+        assert!(x == lu.solve(&b));
+    }
+}
@@ -16,17 +16,70 @@ pub fn join_raw(
         match parallelism {
             Parallelism::None => (op_a(parallelism), op_b(parallelism)),
             #[cfg(feature = "rayon")]
-            Parallelism::Rayon(n_threads) => {
+            Parallelism::Rayon(_) => {
+                let n_threads = parallelism_degree(parallelism);
                 if n_threads == 1 {
                     (op_a(Parallelism::None), op_b(Parallelism::None))
                 } else {
-                    let n_threads = if n_threads > 0 {
-                        n_threads
+                    let parallelism = Parallelism::Rayon(n_threads - n_threads / 2);
+                    let max = max_threads();
+                    rayon::join(
+                        || with_max_threads(max, || op_a(parallelism)),
+                        || with_max_threads(max, || op_b(parallelism)),
+                    )
+                }
+            }
+        };
+    }
+    let mut op_a = Some(op_a);
+    let mut op_b = Some(op_b);
+    implementation(
+        &mut |parallelism| (op_a.take().unwrap())(parallelism),
+        &mut |parallelism| (op_b.take().unwrap())(parallelism),
+        parallelism,
+    )
+}
+
+/// Executes the two operations, possibly in parallel, while splitting the amount of parallelism
+/// between the two in proportion to their estimated amount of work, `work_a` and `work_b`.
+///
+/// Each operation gets at least one thread.
+#[inline]
+pub fn join_raw_weighted(
+    op_a: impl Send + FnOnce(Parallelism),
+    op_b: impl Send + FnOnce(Parallelism),
+    work_a: f64,
+    work_b: f64,
+    parallelism: Parallelism,
+) {
+    fn implementation(
+        op_a: &mut (dyn Send + FnMut(Parallelism)),
+        op_b: &mut (dyn Send + FnMut(Parallelism)),
+        work_a: f64,
+        work_b: f64,
+        parallelism: Parallelism,
+    ) {
+        match parallelism {
+            Parallelism::None => (op_a(parallelism), op_b(parallelism)),
+            #[cfg(feature = "rayon")]
+            Parallelism::Rayon(_) => {
+                let n_threads = parallelism_degree(parallelism);
+                if n_threads == 1 {
+                    (op_a(Parallelism::None), op_b(Parallelism::None))
+                } else {
+                    let total = work_a + work_b;
+                    let threads_a = if total > 0.0 {
+                        (n_threads as f64 * work_a / total).round() as usize
                     } else {
-                        rayon::current_num_threads()
+                        n_threads / 2
                     };
-                    let parallelism = Parallelism::Rayon(n_threads - n_threads / 2);
-                    rayon::join(|| op_a(parallelism), || op_b(parallelism))
+                    let threads_a = threads_a.clamp(1, n_threads - 1);
+                    let threads_b = n_threads - threads_a;
+                    let max = max_threads();
+                    rayon::join(
+                        || with_max_threads(max, || op_a(Parallelism::Rayon(threads_a))),
+                        || with_max_threads(max, || op_b(Parallelism::Rayon(threads_b))),
+                    )
                 }
             }
         };
@@ -36,6 +89,8 @@ pub fn join_raw(
     implementation(
         &mut |parallelism| (op_a.take().unwrap())(parallelism),
         &mut |parallelism| (op_b.take().unwrap())(parallelism),
+        work_a,
+        work_b,
         parallelism,
     )
 }
@@ -57,25 +112,59 @@ pub fn for_each_raw(n_tasks: usize, op: impl Send + Sync + Fn(usize), parallelis
         match parallelism {
             Parallelism::None => (0..n_tasks).for_each(op),
             #[cfg(feature = "rayon")]
-            Parallelism::Rayon(n_threads) => {
-                let n_threads = if n_threads > 0 {
-                    n_threads
-                } else {
-                    rayon::current_num_threads()
-                };
+            Parallelism::Rayon(_) => {
+                let n_threads = parallelism_degree(parallelism);
 
                 use rayon::prelude::*;
                 let min_len = n_tasks / n_threads;
+                let max = max_threads();
                 (0..n_tasks)
                     .into_par_iter()
                     .with_min_len(min_len)
-                    .for_each(op);
+                    .for_each(|idx| with_max_threads(max, || op(idx)));
             }
         }
     }
     implementation(n_tasks, &op, parallelism);
 }
 
+/// Runs `op` on the rayon thread pool `pool`, passing it a [`Parallelism`] value that uses all the
+/// threads of the pool, and returns its result.
+///
+/// The parallel operations in faer, including [`join_raw`], [`for_each_raw`] and the matrix
+/// multiplication kernels, run on the rayon thread pool that is active on the calling thread, and
+/// `Parallelism::Rayon(0)` refers to the number of threads of that pool. Calling faer from `op`
+/// therefore pins its parallelism to `pool`, isolating it from the global rayon pool and from
+/// other workloads. This also applies to the high-level API, which uses the global parallelism
+/// settings (see [`get_global_parallelism`](crate::get_global_parallelism)).
+///
+/// # Example
+/// ```
+/// use faer::{linalg::matmul::matmul, utils::thread::with_thread_pool, Mat};
+///
+/// let pool = rayon::ThreadPoolBuilder::new()
+///     .num_threads(2)
+///     .build()
+///     .unwrap();
+///
+/// let a = Mat::<f64>::from_fn(64, 64, |i, j| (i + j) as f64);
+/// let b = with_thread_pool(&pool, |parallelism| {
+///     let mut b = Mat::<f64>::zeros(64, 64);
+///     matmul(b.as_mut(), a.as_ref(), a.as_ref(), None, 1.0, parallelism);
+///     b
+/// });
+/// assert!(b == &a * &a);
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[inline]
+pub fn with_thread_pool<R: Send>(
+    pool: &rayon::ThreadPool,
+    op: impl Send + FnOnce(Parallelism) -> R,
+) -> R {
+    pool.install(|| op(Parallelism::Rayon(pool.current_num_threads())))
+}
+
 /// Unsafe [`Send`] and [`Sync`] pointer type.
 pub struct Ptr<T>(pub *mut T);
 unsafe impl<T> Send for Ptr<T> {}
@@ -89,15 +178,90 @@ impl<T> Clone for Ptr<T> {
 }
 
 /// The amount of threads that should ideally execute an operation with the given parallelism.
+///
+/// This is at most the limit set with [`with_max_threads`], if any.
 #[inline]
 pub fn parallelism_degree(parallelism: Parallelism) -> usize {
     match parallelism {
         Parallelism::None => 1,
         #[cfg(feature = "rayon")]
-        Parallelism::Rayon(0) => rayon::current_num_threads(),
-        #[cfg(feature = "rayon")]
-        Parallelism::Rayon(n_threads) => n_threads,
+        Parallelism::Rayon(n_threads) => {
+            let n_threads = if n_threads > 0 {
+                n_threads
+            } else {
+                rayon::current_num_threads()
+            };
+            Ord::min(n_threads, max_threads())
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+std::thread_local! {
+    static MAX_THREADS: core::cell::Cell<usize> = core::cell::Cell::new(usize::MAX);
+}
+
+/// Returns the maximum number of threads that faer uses for the parallel operations that start on
+/// the current thread, as set by [`with_max_threads`], or `usize::MAX` if there is no limit.
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[inline]
+pub fn max_threads() -> usize {
+    MAX_THREADS.with(|max| max.get())
+}
+
+/// Runs `op` while limiting the number of threads that faer uses for the parallel operations that
+/// start on the current thread to `max_threads`, and returns its result. A limit of `0` is treated
+/// as `1`, which makes the operations sequential.
+///
+/// faer splits the threads of a parallel operation between its subtasks, so the limit also bounds
+/// the total number of threads used by nested operations, e.g., a parallel factorization calling a
+/// parallel matrix multiplication. This prevents oversubscription when faer is itself called from
+/// a rayon parallel iterator, where each task would otherwise try to use all the threads of the
+/// pool.
+///
+/// The limit applies to the current thread, and is carried over to the tasks that [`join_raw`],
+/// [`join_raw_weighted`] and [`for_each_raw`] start from it, including the ones that run on other
+/// rayon worker threads, so that the nested operations see the same limit. Operations that start
+/// on other threads, e.g. from a raw `rayon::join` or parallel iterator in `op`, don't inherit it.
+/// The previous limit is restored once `op` returns.
+///
+/// The limit bounds the number of tasks that faer splits an operation into, not the size of the
+/// rayon thread pool, so the tasks may still run on any of its worker threads. To restrict the
+/// threads themselves, use [`with_thread_pool`] with a dedicated pool.
+///
+/// # Example
+/// ```
+/// use faer::{prelude::*, utils::thread::with_max_threads, Mat};
+/// use rayon::prelude::*;
+///
+/// let matrices: Vec<Mat<f64>> = (0..8)
+///     .map(|k| Mat::from_fn(32, 32, |i, j| if i == j { 10.0 + k as f64 } else { 1.0 }))
+///     .collect();
+///
+/// // each task factorizes its matrix sequentially, the parallelism comes from the iterator
+/// let inverses: Vec<Mat<f64>> = matrices
+///     .par_iter()
+///     .map(|a| with_max_threads(1, || a.partial_piv_lu().inverse()))
+///     .collect();
+///
+/// for (a, inv) in matrices.iter().zip(&inverses) {
+///     assert!((a * inv - Mat::<f64>::identity(32, 32)).norm_max() < 1e-12);
+/// }
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn with_max_threads<R>(max_threads: usize, op: impl FnOnce() -> R) -> R {
+    struct Restore(usize);
+    impl Drop for Restore {
+        #[inline]
+        fn drop(&mut self) {
+            MAX_THREADS.with(|max| max.set(self.0));
+        }
     }
+
+    let _restore = Restore(MAX_THREADS.with(|max| max.replace(Ord::max(max_threads, 1))));
+    op()
 }
 
 /// Returns the start and length of a subsegment of `0..n`, split between `chunk_count` consumers,
@@ -122,3 +286,151 @@ pub fn par_split_indices(n: usize, idx: usize, chunk_count: usize) -> (usize, us
     let end = idx_to_col_start(idx + 1);
     (start, end - start)
 }
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use super::*;
+    use crate::assert;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_with_thread_pool() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap();
+
+        let count = AtomicUsize::new(0);
+        let parallelism = with_thread_pool(&pool, |parallelism| {
+            assert!(parallelism_degree(Parallelism::Rayon(0)) == 3);
+            for_each_raw(
+                64,
+                |_| {
+                    assert!(pool.current_thread_index().is_some());
+                    count.fetch_add(1, Ordering::Relaxed);
+                },
+                parallelism,
+            );
+            join_raw(
+                |_| assert!(pool.current_thread_index().is_some()),
+                |_| assert!(pool.current_thread_index().is_some()),
+                parallelism,
+            );
+            parallelism
+        });
+        assert!(parallelism == Parallelism::Rayon(3));
+        assert!(count.load(Ordering::Relaxed) == 64);
+    }
+
+    #[test]
+    fn test_with_max_threads() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+
+        pool.install(|| {
+            assert!(max_threads() == usize::MAX);
+            assert!(parallelism_degree(Parallelism::Rayon(0)) == 4);
+
+            with_max_threads(2, || {
+                assert!(parallelism_degree(Parallelism::Rayon(0)) == 2);
+                assert!(parallelism_degree(Parallelism::Rayon(8)) == 2);
+                assert!(parallelism_degree(Parallelism::Rayon(1)) == 1);
+
+                with_max_threads(0, || {
+                    assert!(max_threads() == 1);
+                    // runs both operations sequentially on the current thread
+                    let index = pool.current_thread_index();
+                    join_raw(
+                        |parallelism| {
+                            assert!(parallelism == Parallelism::None);
+                            assert!(pool.current_thread_index() == index);
+                        },
+                        |parallelism| {
+                            assert!(parallelism == Parallelism::None);
+                            assert!(pool.current_thread_index() == index);
+                        },
+                        Parallelism::Rayon(0),
+                    );
+                });
+                assert!(max_threads() == 2);
+            });
+            assert!(max_threads() == usize::MAX);
+        });
+    }
+
+    #[test]
+    fn test_with_max_threads_in_parallel_region() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+
+        let count = AtomicUsize::new(0);
+        pool.install(|| {
+            with_max_threads(2, || {
+                // the limit is visible from the tasks, whichever worker thread runs them
+                for_each_raw(
+                    64,
+                    |_| {
+                        assert!(max_threads() == 2);
+                        assert!(parallelism_degree(Parallelism::Rayon(0)) == 2);
+                        count.fetch_add(1, Ordering::Relaxed);
+                    },
+                    Parallelism::Rayon(0),
+                );
+                join_raw(
+                    |parallelism| {
+                        assert!(max_threads() == 2);
+                        assert!(parallelism == Parallelism::Rayon(1));
+                        // nested operations resolve `Rayon(0)` with the same limit
+                        for_each_raw(
+                            8,
+                            |_| assert!(parallelism_degree(Parallelism::Rayon(0)) == 2),
+                            Parallelism::Rayon(0),
+                        );
+                    },
+                    |_| assert!(max_threads() == 2),
+                    Parallelism::Rayon(0),
+                );
+                join_raw_weighted(
+                    |_| assert!(max_threads() == 2),
+                    |_| assert!(max_threads() == 2),
+                    1.0,
+                    1.0,
+                    Parallelism::Rayon(0),
+                );
+            });
+            assert!(max_threads() == usize::MAX);
+        });
+        assert!(count.load(Ordering::Relaxed) == 64);
+    }
+
+    #[test]
+    fn test_join_raw_weighted() {
+        let degree = |parallelism| parallelism_degree(parallelism);
+        join_raw_weighted(
+            |parallelism| assert!(degree(parallelism) == 2),
+            |parallelism| assert!(degree(parallelism) == 6),
+            1.0,
+            3.0,
+            Parallelism::Rayon(8),
+        );
+        // each operation gets at least one thread
+        join_raw_weighted(
+            |parallelism| assert!(degree(parallelism) == 1),
+            |parallelism| assert!(degree(parallelism) == 7),
+            0.0,
+            1e9,
+            Parallelism::Rayon(8),
+        );
+        join_raw_weighted(
+            |parallelism| assert!(parallelism == Parallelism::None),
+            |parallelism| assert!(parallelism == Parallelism::None),
+            1.0,
+            3.0,
+            Parallelism::None,
+        );
+    }
+}